@@ -0,0 +1 @@
+import _ from 'lodash';
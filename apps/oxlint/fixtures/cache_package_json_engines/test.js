@@ -0,0 +1 @@
+Object.groupBy([], () => 1);
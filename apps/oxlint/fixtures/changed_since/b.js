@@ -0,0 +1,2 @@
+debugger;
+let x = 1;
@@ -0,0 +1 @@
+import './e.js';
@@ -0,0 +1 @@
+import './d.js';
@@ -0,0 +1,2 @@
+import _ from 'lodash';
+import { test } from 'vitest';
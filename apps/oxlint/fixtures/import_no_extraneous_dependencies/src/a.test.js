@@ -0,0 +1 @@
+import { test } from 'vitest';
@@ -0,0 +1 @@
+export const fromRequire = 1;
@@ -0,0 +1,2 @@
+export const formatDate = (date) => date.toISOString();
+export const formatTime = (date) => date.toTimeString();
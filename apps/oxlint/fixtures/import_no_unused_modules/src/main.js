@@ -0,0 +1,12 @@
+import { used } from './used.js';
+import App from './App.vue';
+import * as ns from './namespace.js';
+import './side-effect.js';
+import './public/api.js';
+export { reexported } from './reexport.js';
+export * from './star.js';
+
+const cjs = require('./cjs.js');
+import('./dynamic.js');
+
+console.log(used, App, ns, cjs);
@@ -0,0 +1 @@
+export const api = 1;
@@ -0,0 +1,3 @@
+import { unused } from './used.js';
+
+export const orphan = unused;
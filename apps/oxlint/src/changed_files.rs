@@ -0,0 +1,231 @@
+use std::{
+    ffi::OsStr,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use cow_utils::CowUtils;
+use rustc_hash::FxHashMap;
+
+use oxc_diagnostics::Error;
+
+/// Files which differ from a git ref, including uncommitted and untracked files, with the lines
+/// which were added or modified in them.
+///
+/// Changes are read from the local repository with the `git` executable.
+#[derive(Debug, Default)]
+pub struct ChangedFiles {
+    /// Keyed by path relative to the working directory, with `/` separators.
+    /// `None` means that all lines are new, e.g. for untracked files.
+    files: FxHashMap<String, Option<Vec<RangeInclusive<usize>>>>,
+    cwd: PathBuf,
+}
+
+impl ChangedFiles {
+    /// Collect the files in the repository containing `cwd` which changed since `git_ref`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if `cwd` is not in a git repository, or `git_ref` does not exist.
+    pub fn load(cwd: &Path, git_ref: &str) -> Result<Self, String> {
+        let error =
+            |message: &str| format!("Failed to get files changed since `{git_ref}`:\n{message}\n");
+
+        let root = git(cwd, &["rev-parse", "--show-toplevel"]).map_err(|err| error(&err))?;
+        let root = PathBuf::from(root.trim_end());
+        let canonical_cwd = cwd.canonicalize().map_err(|err| error(&err.to_string()))?;
+
+        let names = git_diff(cwd, git_ref, &["--name-only", "-z", "--diff-filter=d"])
+            .map_err(|err| error(&err))?;
+        let diff = git_diff(cwd, git_ref, &["--no-color", "--no-ext-diff", "--unified=0"])
+            .map_err(|err| error(&err))?;
+        let untracked =
+            git(cwd, &["ls-files", "-z", "--others", "--exclude-standard", "--full-name"])
+                .map_err(|err| error(&err))?;
+
+        let mut changed_files = Self { files: FxHashMap::default(), cwd: cwd.to_path_buf() };
+        let key = |path: &str| {
+            let path = root.join(path);
+            let path = path.strip_prefix(&canonical_cwd).unwrap_or(&path).to_string_lossy();
+            path.cow_replace('\\', "/").into_owned()
+        };
+        for path in names.split_terminator('\0') {
+            changed_files.files.insert(key(path), Some(vec![]));
+        }
+
+        // Lines starting with `+++ ` are only file headers before the first hunk of a file.
+        // Within hunks, they are added lines.
+        let mut in_header = false;
+        let mut lines = None;
+        for line in diff.lines() {
+            if line.starts_with("diff ") {
+                in_header = true;
+                lines = None;
+            } else if in_header && let Some(path) = line.strip_prefix("+++ ") {
+                // The path of deleted files is `/dev/null`
+                lines = parse_header_path(path).and_then(|path| {
+                    changed_files.files.get_mut(&key(&path)).and_then(Option::as_mut)
+                });
+            } else if let Some(hunk) = line.strip_prefix("@@ ") {
+                in_header = false;
+                if let Some(lines) = lines.as_mut()
+                    && let Some(range) = parse_hunk_header(hunk)
+                {
+                    lines.push(range);
+                }
+            }
+        }
+        for path in untracked.split_terminator('\0') {
+            changed_files.files.insert(key(path), None);
+        }
+
+        Ok(changed_files)
+    }
+
+    /// Whether the file at `path` changed.
+    pub fn contains(&self, path: &OsStr) -> bool {
+        let path = Path::new(path);
+        let path = path.strip_prefix(&self.cwd).unwrap_or(path).to_string_lossy();
+        self.files.contains_key(path.cow_replace('\\', "/").as_ref())
+    }
+
+    /// Remove diagnostics which are not on a changed line.
+    /// Diagnostics without a location are kept.
+    pub fn filter(&self, diagnostics: Vec<Vec<Error>>) -> Vec<Vec<Error>> {
+        diagnostics
+            .into_iter()
+            .map(|errors| {
+                errors.into_iter().filter(|error| self.is_on_changed_line(error)).collect()
+            })
+            .collect()
+    }
+
+    fn is_on_changed_line(&self, error: &Error) -> bool {
+        let (Some(source), Some(labels)) = (error.source_code(), error.labels()) else {
+            return true;
+        };
+        let labels = labels.collect::<Vec<_>>();
+        let Some(label) = labels.iter().find(|label| label.primary()).or(labels.first()) else {
+            return true;
+        };
+        let Ok(contents) = source.read_span(label.inner(), 0, 0) else {
+            return true;
+        };
+        let Some(name) = contents.name() else {
+            return true;
+        };
+        match self.files.get(name) {
+            Some(Some(ranges)) => {
+                let line = contents.line() + 1;
+                ranges.iter().any(|range| range.contains(&line))
+            }
+            Some(None) => true,
+            None => false,
+        }
+    }
+}
+
+fn git(cwd: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(["-c", "core.quotePath=false"])
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .map_err(|err| err.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim_end().to_string());
+    }
+    String::from_utf8(output.stdout).map_err(|err| err.to_string())
+}
+
+/// Run `git diff <args> <git_ref> --`, with paths and their prefixes in file headers which do not
+/// depend on the user's git config. Renamed files are listed as added files.
+fn git_diff(cwd: &Path, git_ref: &str, args: &[&str]) -> Result<String, String> {
+    let mut diff_args =
+        vec!["diff", "--no-renames", "--no-relative", "--src-prefix=a/", "--dst-prefix=b/"];
+    diff_args.extend(args);
+    diff_args.extend([git_ref, "--"]);
+    git(cwd, &diff_args)
+}
+
+/// The path of a `+++ b/<path>` file header, without the `b/` prefix.
+///
+/// Git appends a tab to paths containing spaces, and quotes paths containing special characters
+/// like C string literals, e.g. `"b/a\"b.js"`.
+fn parse_header_path(path: &str) -> Option<String> {
+    let path = path.strip_suffix('\t').unwrap_or(path);
+    let path = match path.strip_prefix('"').and_then(|path| path.strip_suffix('"')) {
+        Some(quoted) => unquote(quoted),
+        None => path.to_string(),
+    };
+    path.strip_prefix("b/").map(ToString::to_string)
+}
+
+/// Unescape a path quoted by git, which escapes bytes as octal numbers, e.g. `\303\274`.
+fn unquote(quoted: &str) -> String {
+    let mut bytes = Vec::with_capacity(quoted.len());
+    let mut iter = quoted.bytes().peekable();
+    while let Some(byte) = iter.next() {
+        if byte != b'\\' {
+            bytes.push(byte);
+            continue;
+        }
+        let Some(escaped) = iter.next() else { break };
+        let unescaped = match escaped {
+            b'a' => 0x07,
+            b'b' => 0x08,
+            b't' => b'\t',
+            b'n' => b'\n',
+            b'v' => 0x0b,
+            b'f' => 0x0c,
+            b'r' => b'\r',
+            b'0'..=b'7' => {
+                let mut value = escaped - b'0';
+                for _ in 0..2 {
+                    if let Some(digit) = iter.next_if(|digit| matches!(digit, b'0'..=b'7')) {
+                        value = value.wrapping_mul(8).wrapping_add(digit - b'0');
+                    }
+                }
+                value
+            }
+            other => other,
+        };
+        bytes.push(unescaped);
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Lines added by a hunk, from its header, e.g. `-1,2 +3,4 @@`.
+/// Returns `None` if the hunk only removes lines.
+fn parse_hunk_header(hunk: &str) -> Option<RangeInclusive<usize>> {
+    let new_range = hunk.split(' ').find_map(|part| part.strip_prefix('+'))?;
+    let (start, count) = match new_range.split_once(',') {
+        Some((start, count)) => (start.parse::<usize>().ok()?, count.parse::<usize>().ok()?),
+        None => (new_range.parse::<usize>().ok()?, 1),
+    };
+    (count > 0).then(|| start..=start + count - 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_header_path, parse_hunk_header};
+
+    #[test]
+    fn hunk_header() {
+        assert_eq!(parse_hunk_header("-1,2 +3,4 @@"), Some(3..=6));
+        assert_eq!(parse_hunk_header("-1 +3 @@ fn foo() {"), Some(3..=3));
+        assert_eq!(parse_hunk_header("-5,2 +4,0 @@"), None);
+        assert_eq!(parse_hunk_header("-0,0 +1,10 @@"), Some(1..=10));
+    }
+
+    #[test]
+    fn header_path() {
+        assert_eq!(parse_header_path("b/src/a.js").as_deref(), Some("src/a.js"));
+        assert_eq!(parse_header_path("b/a b.js\t").as_deref(), Some("a b.js"));
+        assert_eq!(parse_header_path(r#""b/a\"b.js""#).as_deref(), Some(r#"a"b.js"#));
+        assert_eq!(parse_header_path(r#""b/a\tb\\c.js""#).as_deref(), Some("a\tb\\c.js"));
+        assert_eq!(parse_header_path(r#""b/\303\274.js""#).as_deref(), Some("ü.js"));
+        assert_eq!(parse_header_path("/dev/null"), None);
+    }
+}
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use bpaf::Bpaf;
 use oxc_linter::{AllowWarnDeny, FixKind, LintPlugins};
@@ -58,9 +58,22 @@ pub struct LintCommand {
     #[bpaf(switch, hide_usage)]
     pub type_check: bool,
 
+    /// Also lint JavaScript and TypeScript code blocks in Markdown (`.md`) and MDX (`.mdx`) files
+    #[bpaf(switch, hide_usage)]
+    pub markdown: bool,
+
     #[bpaf(external)]
     pub inline_config_options: InlineConfigOptions,
 
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
+    #[bpaf(external)]
+    pub suppression_options: SuppressionOptions,
+
+    #[bpaf(external)]
+    pub changed_options: ChangedOptions,
+
     /// Single file, single path or list of paths
     #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE))]
     pub paths: Vec<PathBuf>,
@@ -243,9 +256,18 @@ pub struct WarningOptions {
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
     /// Use a specific output format. Possible values:
-    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `stylish`, `unix`
+    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `sarif`, `stylish`, `unix`
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
+
+    /// Print the time spent in the slowest rules and files, and in each phase of linting.
+    /// Also enabled by the `TIMING` environment variable, which can be `all` or a number of rows to print.
+    #[bpaf(switch, hide_usage)]
+    pub timing: bool,
+
+    /// Write the time spent in each rule and file as JSON to the given file.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub timing_json: Option<PathBuf>,
 }
 
 /// Enable/Disable Plugins
@@ -434,6 +456,63 @@ pub struct InlineConfigOptions {
     pub report_unused_directives: ReportUnusedDirectives,
 }
 
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint files which changed since the last run, and reuse the results of other files.
+    /// Has no effect with `--type-aware`, `--report-unused-disable-directives`, JS plugins, or the
+    /// `unusedExports` and `unusedFiles` options of `import/no-unused-modules`.
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path to the cache file. If a directory is given, `.oxlintcache` is created in it.
+    #[bpaf(argument("PATH"), fallback(PathBuf::from(DEFAULT_CACHE_FILE_NAME)), hide_usage)]
+    pub cache_location: PathBuf,
+}
+
+const DEFAULT_CACHE_FILE_NAME: &str = ".oxlintcache";
+
+impl CacheOptions {
+    /// Path to the cache file, relative to `cwd` if `--cache-location` is relative.
+    pub fn cache_file(&self, cwd: &Path) -> PathBuf {
+        let location = cwd.join(&self.cache_location);
+        let is_dir = location.is_dir()
+            || self.cache_location.as_os_str().to_string_lossy().ends_with(['/', '\\']);
+        if is_dir { location.join(DEFAULT_CACHE_FILE_NAME) } else { location }
+    }
+}
+
+/// Bulk Suppressions
+#[derive(Debug, Clone, Bpaf)]
+pub struct SuppressionOptions {
+    /// Record all current errors in the suppressions file, so that they are no longer reported.
+    #[bpaf(switch, hide_usage)]
+    pub suppress_all: bool,
+
+    /// Remove suppressions from the suppressions file which no longer occur.
+    #[bpaf(switch, hide_usage)]
+    pub prune_suppressions: bool,
+
+    /// Path to the suppressions file.
+    #[bpaf(argument("PATH"), fallback(PathBuf::from(DEFAULT_SUPPRESSIONS_FILE_NAME)), hide_usage)]
+    pub suppressions_location: PathBuf,
+}
+
+const DEFAULT_SUPPRESSIONS_FILE_NAME: &str = "oxlint-suppressions.json";
+
+/// Changed Files
+#[derive(Debug, Clone, Bpaf)]
+pub struct ChangedOptions {
+    /// Only lint files which differ from the given git ref, including uncommitted and untracked files.
+    /// Files they import are still read by cross-module rules.
+    #[bpaf(argument("REF"), hide_usage)]
+    pub changed_since: Option<String>,
+
+    /// Only report diagnostics on lines which were added or modified since the `--changed-since` ref.
+    #[bpaf(switch, hide_usage)]
+    pub changed_lines_only: bool,
+}
+
 #[cfg(test)]
 mod plugins {
     use oxc_linter::LintPlugins;
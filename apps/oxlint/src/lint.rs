@@ -3,7 +3,7 @@ use std::{
     ffi::OsStr,
     io::{ErrorKind, Write},
     path::{Path, PathBuf, absolute},
-    sync::Arc,
+    sync::{Arc, mpsc},
     time::Instant,
 };
 
@@ -14,15 +14,19 @@ use rustc_hash::{FxBuildHasher, FxHashMap};
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
 use oxc_linter::{
     AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, ExternalLinter, ExternalPluginStore,
-    InvalidFilterKind, LintFilter, LintOptions, LintRunner, LintServiceOptions, Linter, Oxlintrc,
+    FixKind, InvalidFilterKind, LINTABLE_EXTENSIONS, LintFilter, LintOptions, LintRunner,
+    LintServiceOptions, LintTimings, Linter, MARKDOWN_EXTENSIONS, Oxlintrc,
 };
 
 use crate::{
     DEFAULT_OXLINTRC_NAME,
+    changed_files::ChangedFiles,
     cli::{CliRunResult, LintCommand, MiscOptions, ReportUnusedDirectives, WarningOptions},
     config_loader::{ConfigLoadError, ConfigLoader, discover_configs_in_ancestors},
-    output_formatter::{LintCommandInfo, OutputFormatter},
-    walk::Walk,
+    output_formatter::{LintCommandInfo, OutputFormat, OutputFormatter},
+    suppressions::Suppressions,
+    timing::{timing_rows, timing_tables, write_timing_json},
+    walk::{Extensions, Walk},
 };
 use oxc_linter::LintIgnoreMatcher;
 
@@ -59,6 +63,10 @@ impl CliRunner {
             misc_options,
             disable_nested_config,
             inline_config_options,
+            cache_options,
+            suppression_options,
+            changed_options,
+            markdown,
             ..
         } = self.options;
 
@@ -167,7 +175,12 @@ impl CliRunner {
             paths.push(self.cwd.clone());
         }
 
-        let walker = Walk::new(&paths, &ignore_options, override_builder);
+        let mut walker = Walk::new(&paths, &ignore_options, override_builder);
+        if markdown {
+            walker = walker.with_extensions(Extensions(
+                LINTABLE_EXTENSIONS.iter().chain(MARKDOWN_EXTENSIONS).copied().collect(),
+            ));
+        }
         let mut paths = walker.paths();
 
         // NAPI tests build `oxlint` with `testing` feature enabled.
@@ -270,6 +283,60 @@ impl CliRunner {
             || nested_configs.values().any(|config| config.plugins().has_import());
         let mut options = LintServiceOptions::new(self.cwd).with_cross_module(use_cross_module);
 
+        // Type-aware rules need disable directives of all files, which are not cached.
+        if cache_options.cache && !self.options.type_aware {
+            let cache_file = cache_options.cache_file(options.cwd());
+            options = options.with_cache(cache_file, env!("CARGO_PKG_VERSION"));
+        }
+
+        // SARIF output includes fixes, which are reported instead of applied unless fixing is enabled,
+        // and diagnostics suppressed by disable directives.
+        let is_sarif = format_str == OutputFormat::Sarif;
+        options = options.with_report_fixes(is_sarif);
+        let fix_kind = if is_sarif && !fix_options.is_enabled() {
+            options = options.with_fix_dry_run(true);
+            FixKind::All
+        } else {
+            fix_options.fix_kind()
+        };
+
+        // With bulk suppressions, diagnostics are collected and filtered before they are reported.
+        let suppressions_file = options.cwd().join(&suppression_options.suppressions_location);
+        let update_suppressions =
+            suppression_options.suppress_all || suppression_options.prune_suppressions;
+        let mut suppressions = if update_suppressions || suppressions_file.is_file() {
+            match Suppressions::load(suppressions_file) {
+                Ok(suppressions) => Some(suppressions),
+                Err(err) => {
+                    print_and_flush_stdout(stdout, &err);
+                    return CliRunResult::InvalidOptionConfig;
+                }
+            }
+        } else {
+            None
+        };
+        let cwd = options.cwd().to_path_buf();
+
+        let changed_files = match &changed_options.changed_since {
+            Some(git_ref) => match ChangedFiles::load(&cwd, git_ref) {
+                Ok(changed_files) => Some(changed_files),
+                Err(err) => {
+                    print_and_flush_stdout(stdout, &err);
+                    return CliRunResult::InvalidOptionConfig;
+                }
+            },
+            None if changed_options.changed_lines_only => {
+                print_and_flush_stdout(
+                    stdout,
+                    "The `--changed-lines-only` option requires `--changed-since`.\n",
+                );
+                return CliRunResult::InvalidOptionConfig;
+            }
+            None => None,
+        };
+        // Diagnostics are filtered by changed lines before they are reported.
+        let changed_lines = changed_files.as_ref().filter(|_| changed_options.changed_lines_only);
+
         let report_unused_directives = match inline_config_options.report_unused_directives {
             ReportUnusedDirectives::WithoutSeverity(true) => Some(AllowWarnDeny::Warn),
             ReportUnusedDirectives::WithSeverity(Some(severity)) => Some(severity),
@@ -295,12 +362,32 @@ impl CliRunner {
         let files_to_lint = paths
             .into_iter()
             .filter(|path| !ignore_matcher.should_ignore(Path::new(path)))
+            .filter(|path| changed_files.as_ref().is_none_or(|files| files.contains(path)))
             .collect::<Vec<Arc<OsStr>>>();
 
         let has_external_linter = external_linter.is_some();
-        let linter = Linter::new(LintOptions::default(), config_store, external_linter)
-            .with_fix(fix_options.fix_kind())
-            .with_report_unused_directives(report_unused_directives);
+        let mut linter = Linter::new(LintOptions::default(), config_store, external_linter)
+            .with_fix(fix_kind)
+            .with_report_unused_directives(report_unused_directives)
+            .with_report_suppressed(is_sarif);
+
+        // Results which depend on the importers of a module depend on every other file.
+        if cache_options.cache && use_cross_module && linter.uses_importers() {
+            #[expect(clippy::print_stderr)]
+            {
+                eprintln!(
+                    "`--cache` has no effect when `import/no-unused-modules` reports unused exports or files."
+                );
+            }
+        }
+
+        let output_options = &self.options.output_options;
+        let timing_rows = timing_rows(output_options.timing, env::var("TIMING").ok().as_deref());
+        let timings = (timing_rows.is_some() || output_options.timing_json.is_some())
+            .then(|| Arc::new(LintTimings::new()));
+        if let Some(timings) = &timings {
+            linter = linter.with_timings(Arc::clone(timings));
+        }
 
         let number_of_files = files_to_lint.len();
         let tsconfig = basic_options.tsconfig;
@@ -368,9 +455,13 @@ impl CliRunner {
             None
         };
 
-        match lint_runner.lint_files(&files_to_lint, tx_error.clone(), file_system) {
+        let filter_diagnostics = suppressions.is_some() || changed_lines.is_some();
+        let (tx_filtered, rx_filtered) = mpsc::channel();
+        let tx_lint = if filter_diagnostics { tx_filtered } else { tx_error.clone() };
+
+        match lint_runner.lint_files(&files_to_lint, tx_lint.clone(), file_system) {
             Ok(lint_runner) => {
-                lint_runner.report_unused_directives(report_unused_directives, &tx_error);
+                lint_runner.report_unused_directives(report_unused_directives, &tx_lint);
             }
             Err(err) => {
                 print_and_flush_stdout(stdout, &err);
@@ -378,6 +469,39 @@ impl CliRunner {
             }
         }
 
+        drop(tx_lint);
+
+        if filter_diagnostics {
+            let mut diagnostics = rx_filtered.iter().collect::<Vec<_>>();
+            if let Some(suppressions) = &mut suppressions {
+                if suppression_options.suppress_all {
+                    suppressions.suppress_all(&diagnostics);
+                }
+                if suppression_options.prune_suppressions {
+                    let linted_files = files_to_lint
+                        .iter()
+                        .map(|path| {
+                            let path = Path::new(path);
+                            let path = path.strip_prefix(&cwd).unwrap_or(path).to_string_lossy();
+                            path.cow_replace('\\', "/").into_owned()
+                        })
+                        .collect();
+                    suppressions.prune(&diagnostics, &linted_files, &cwd);
+                }
+                if update_suppressions && let Err(err) = suppressions.save() {
+                    print_and_flush_stdout(stdout, &err);
+                    return CliRunResult::InvalidOptionConfig;
+                }
+                diagnostics = suppressions.filter(diagnostics);
+            }
+            if let Some(changed_lines) = changed_lines {
+                diagnostics = changed_lines.filter(diagnostics);
+            }
+            for errors in diagnostics {
+                tx_error.send(errors).unwrap();
+            }
+        }
+
         drop(tx_error);
 
         let diagnostic_result = diagnostic_service.run(stdout);
@@ -391,6 +515,21 @@ impl CliRunner {
             print_and_flush_stdout(stdout, &end);
         }
 
+        if let Some(timings) = &timings {
+            if let Some(rows) = timing_rows {
+                print_and_flush_stdout(
+                    stdout,
+                    &format!("\n{}", timing_tables(timings, rows, &cwd)),
+                );
+            }
+            if let Some(path) = &self.options.output_options.timing_json
+                && let Err(err) = write_timing_json(timings, path, &cwd)
+            {
+                print_and_flush_stdout(stdout, &err);
+                return CliRunResult::InvalidOptionConfig;
+            }
+        }
+
         if diagnostic_result.errors_count() > 0 {
             CliRunResult::LintFoundErrors
         } else if warning_options.deny_warnings && diagnostic_result.warnings_count() > 0 {
@@ -556,7 +695,10 @@ mod test {
     use std::{fs, path::PathBuf};
 
     use super::CliRunner;
-    use crate::{DEFAULT_OXLINTRC_NAME, tester::Tester};
+    use crate::{
+        DEFAULT_OXLINTRC_NAME,
+        tester::{FixtureGuard, Tester},
+    };
     use oxc_linter::rules::RULES;
 
     // lints the full directory of fixtures,
@@ -858,6 +1000,21 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn lint_markdown_file() {
+        // Markdown files are not linted without `--markdown`
+        let args_1 = &["fixtures/markdown"];
+        let args_2 = &["--markdown", "fixtures/markdown/README.md"];
+        let args_3 = &["--markdown", "fixtures/markdown/component.mdx"];
+        Tester::new().test_and_snapshot_multiple(&[args_1, args_2, args_3]);
+    }
+
+    #[test]
+    fn lint_markdown_file_with_overrides() {
+        let args = &["--markdown", "-c", "fixtures/markdown/overrides.json", "fixtures/markdown"];
+        Tester::new().test_and_snapshot(args);
+    }
+
     #[test]
     fn test_tsconfig_option() {
         // passed
@@ -900,6 +1057,23 @@ mod test {
             .test_and_snapshot_multiple(&[args_1, args_2]);
     }
 
+    #[test]
+    fn test_import_no_unused_modules() {
+        let args = &["-c", ".oxlintrc.json", "src"];
+        Tester::new().with_cwd("fixtures/import_no_unused_modules".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_import_no_extraneous_dependencies() {
+        // `packageDir` and globs are relative to the directory of the config file
+        let args = &[
+            "-c",
+            "import_no_extraneous_dependencies/.oxlintrc.json",
+            "import_no_extraneous_dependencies/src",
+        ];
+        Tester::new().with_cwd("fixtures".into()).test_and_snapshot(args);
+    }
+
     #[test]
     fn test_fix() {
         Tester::test_fix("fixtures/fix_argument/fix.js", "debugger\n", "\n");
@@ -910,6 +1084,229 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_cache() {
+        let dir = PathBuf::from("fixtures/cache");
+        let file = dir.join("test.js");
+        let cache_file = dir.join(".oxlintcache");
+        let _ = fs::remove_file(&cache_file);
+        let _guard = FixtureGuard::new(&dir);
+        let tester = Tester::new().with_cwd(dir);
+
+        // Results are reused from the cache
+        let args = &["--cache", "test.js"];
+        tester.test_and_snapshot_multiple(&[args, args]);
+        assert!(cache_file.is_file());
+
+        // Changed files and changed config are linted again
+        fs::write(&file, "debugger;\ndebugger;\n").unwrap();
+        tester.test_and_snapshot_multiple(&[args, &["--cache", "-D", "no-debugger", "test.js"]]);
+
+        tester.test(&["--cache", "--fix", "test.js"]);
+        assert_eq!(fs::read_to_string(&file).unwrap(), "\n\n");
+    }
+
+    #[test]
+    fn test_cache_cross_module() {
+        let dir = PathBuf::from("fixtures/cache_cross_module");
+        let cache_file = dir.join(".oxlintcache");
+        let _ = fs::remove_file(&cache_file);
+        let _guard = FixtureGuard::new(&dir);
+        let tester = Tester::new().with_cwd(dir.clone());
+
+        let args = &["--cache", "--import-plugin", "-A", "all", "-D", "import/no-cycle", "a.js"];
+        tester.test_and_snapshot(args);
+        assert!(cache_file.is_file());
+
+        // `a.js` is linted again when a module it imports indirectly changes
+        fs::write(dir.join("c.js"), "import './a.js';\n").unwrap();
+        tester.test_and_snapshot_multiple(&[args, args]);
+    }
+
+    #[test]
+    fn test_cache_import_no_unused_modules() {
+        // Results depending on the importers of modules are not cached
+        let dir = PathBuf::from("fixtures/import_no_unused_modules");
+        let cache_file = dir.join(".oxlintcache");
+        let _ = fs::remove_file(&cache_file);
+        let _guard = FixtureGuard::new(&dir);
+        Tester::new().with_cwd(dir).test(&["--cache", "-c", ".oxlintrc.json", "src"]);
+        assert!(!cache_file.exists());
+    }
+
+    #[test]
+    fn test_cache_package_json() {
+        let dir = PathBuf::from("fixtures/cache_package_json");
+        let _ = fs::remove_file(dir.join(".oxlintcache"));
+        let _guard = FixtureGuard::new(&dir);
+        let tester = Tester::new().with_cwd(dir.clone());
+
+        let args = &[
+            "--cache",
+            "--import-plugin",
+            "-A",
+            "all",
+            "-D",
+            "import/no-extraneous-dependencies",
+            "test.js",
+        ];
+        tester.test_and_snapshot(args);
+
+        // `test.js` is linted again when the `package.json` read by rules changes
+        fs::write(
+            dir.join("package.json"),
+            "{\n  \"dependencies\": { \"lodash\": \"^4.0.0\" }\n}\n",
+        )
+        .unwrap();
+        tester.test_and_snapshot_multiple(&[args, args]);
+    }
+
+    #[test]
+    fn test_cache_package_json_engines() {
+        let dir = PathBuf::from("fixtures/cache_package_json_engines");
+        let _ = fs::remove_file(dir.join(".oxlintcache"));
+        let _guard = FixtureGuard::new(&dir);
+        let tester = Tester::new().with_cwd(dir.clone());
+
+        let args = &[
+            "--cache",
+            "--node-plugin",
+            "-A",
+            "all",
+            "-D",
+            "node/no-unsupported-features-es-builtins",
+            "test.js",
+        ];
+        tester.test_and_snapshot(args);
+
+        // `test.js` is linted again when `engines` in `package.json` changes
+        fs::write(dir.join("package.json"), "{\n  \"engines\": { \"node\": \">=22\" }\n}\n")
+            .unwrap();
+        tester.test_and_snapshot_multiple(&[args, args]);
+    }
+
+    #[test]
+    fn test_bulk_suppressions() {
+        let dir = PathBuf::from("fixtures/bulk_suppressions");
+        let file = dir.join("test.js");
+        let suppressions_file = dir.join("oxlint-suppressions.json");
+        let _ = fs::remove_file(&suppressions_file);
+        let _guard = FixtureGuard::new(&dir);
+        let tester = Tester::new().with_cwd(dir);
+        let suppressed_count = || {
+            let suppressions: serde_json::Value =
+                serde_json::from_str(&fs::read_to_string(&suppressions_file).unwrap()).unwrap();
+            suppressions["test.js"]["eslint(no-debugger)"]["count"].clone()
+        };
+
+        let args = &["-D", "no-debugger", "test.js"];
+        let prune_args = &["-D", "no-debugger", "--prune-suppressions", "test.js"];
+        tester.test_and_snapshot_multiple(&[
+            args,
+            &["-D", "no-debugger", "--suppress-all", "test.js"],
+            args,
+        ]);
+        assert_eq!(suppressed_count(), 2);
+
+        // All errors are reported once there are more than suppressed
+        fs::write(&file, "debugger;\ndebugger;\ndebugger;\n").unwrap();
+        tester.test_and_snapshot(args);
+
+        // Pruning reduces the suppressed count as errors are fixed
+        fs::write(&file, "debugger;\n").unwrap();
+        tester.test_and_snapshot_multiple(&[prune_args, args]);
+        assert_eq!(suppressed_count(), 1);
+
+        // Warnings are not suppressed
+        fs::write(&file, "debugger;\ndebugger;\n").unwrap();
+        tester.test_and_snapshot_multiple(&[args, &["test.js"]]);
+
+        fs::write(&file, "").unwrap();
+        tester.test(prune_args);
+        assert_eq!(fs::read_to_string(&suppressions_file).unwrap(), "{}\n");
+    }
+
+    #[test]
+    fn test_changed_since() {
+        let dir = PathBuf::from("fixtures/changed_since");
+        let _ = fs::remove_dir_all(dir.join(".git"));
+        let _guard = FixtureGuard::new(&dir);
+        // Do not depend on the git config of the machine running the tests
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(args)
+                .current_dir(&dir)
+                .env("GIT_CONFIG_GLOBAL", "/dev/null")
+                .env("GIT_CONFIG_NOSYSTEM", "1")
+                .env("GIT_AUTHOR_NAME", "oxc")
+                .env("GIT_AUTHOR_EMAIL", "oxc@example.com")
+                .env("GIT_AUTHOR_DATE", "2025-01-01T00:00:00Z")
+                .env("GIT_COMMITTER_NAME", "oxc")
+                .env("GIT_COMMITTER_EMAIL", "oxc@example.com")
+                .env("GIT_COMMITTER_DATE", "2025-01-01T00:00:00Z")
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success());
+        };
+        git(&["init", "-q"]);
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "initial"]);
+        let tester = Tester::new().with_cwd(dir.clone());
+
+        let args = &["-A", "all", "-D", "no-debugger", "--changed-since", "HEAD"];
+        let lines_args =
+            &["-A", "all", "-D", "no-debugger", "--changed-since", "HEAD", "--changed-lines-only"];
+        let cycle_args =
+            &["-A", "all", "--import-plugin", "-D", "import/no-cycle", "--changed-since", "HEAD"];
+        tester.test_and_snapshot_multiple(&[args, cycle_args]);
+
+        // Modified and untracked files are linted, and only diagnostics on changed lines are
+        // reported with `--changed-lines-only`. Paths with spaces are followed by a tab in file
+        // headers, and unchanged modules are still in the module graph.
+        fs::write(dir.join("b.js"), "debugger;\ndebugger;\n").unwrap();
+        fs::write(dir.join("a b.js"), "debugger;\ndebugger;\n").unwrap();
+        fs::write(dir.join("c.js"), "debugger;\n").unwrap();
+        fs::write(dir.join("d.js"), "import './e.js';\nexport {};\n").unwrap();
+        tester.test_and_snapshot_multiple(&[args, lines_args, cycle_args]);
+
+        // Paths in diffs do not depend on the user's git config
+        git(&["config", "diff.noprefix", "true"]);
+        git(&["config", "diff.mnemonicPrefix", "true"]);
+        git(&["config", "diff.relative", "true"]);
+        tester.test_and_snapshot(lines_args);
+
+        tester.test_and_snapshot_multiple(&[
+            &["--changed-since", "does-not-exist"],
+            &["--changed-lines-only"],
+        ]);
+    }
+
+    #[test]
+    fn test_timing() {
+        let dir = PathBuf::from("fixtures/timing");
+        let timing_file = dir.join("timing.json");
+        let _ = fs::remove_file(&timing_file);
+        let _guard = FixtureGuard::new(&dir);
+        let tester = Tester::new().with_cwd(dir);
+
+        let args = &["-A", "all", "-D", "no-debugger", "--timing-json", "timing.json", "test.js"];
+        tester.test_and_snapshot(args);
+        let timings: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&timing_file).unwrap()).unwrap();
+        assert_eq!(timings["rules"].as_array().unwrap().len(), 1);
+        assert_eq!(timings["rules"][0]["rule"], "eslint/no-debugger");
+        assert!(timings["rules"][0]["run"].is_f64());
+        assert!(timings["phases"]["parse"].is_f64());
+        assert_eq!(timings["files"][0]["path"], "test.js");
+
+        // The timing table is not snapshotted because its durations vary between runs
+        let output = tester.test_output(&["-A", "all", "-D", "no-debugger", "--timing", "test.js"]);
+        assert!(output.lines().any(|line| line.starts_with("eslint/no-debugger")));
+        assert!(output.lines().any(|line| line.starts_with("module graph")));
+        assert!(output.lines().any(|line| line.starts_with("test.js")));
+    }
+
     #[test]
     fn test_print_config_ban_all_rules() {
         let args = &["-A", "all", "--print-config"];
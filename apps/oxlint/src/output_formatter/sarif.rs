@@ -0,0 +1,486 @@
+use std::{borrow::Cow, path::Path};
+
+use cow_utils::CowUtils;
+use percent_encoding::AsciiSet;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use oxc_diagnostics::{
+    Error, OxcDiagnostic, Severity, SourceSpan,
+    reporter::{DiagnosticReporter, DiagnosticResult},
+};
+use oxc_linter::{RuleCategory, WEBSITE_BASE_RULES_URL, plugin_name_to_prefix, rules::RULES};
+
+use crate::output_formatter::InternalFormatter;
+
+#[derive(Debug, Default)]
+pub struct SarifOutputFormatter;
+
+impl InternalFormatter for SarifOutputFormatter {
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(SarifReporter::default())
+    }
+}
+
+/// Renders reports as a SARIF 2.1.0 log.
+///
+/// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+///
+/// Files are reported with URIs relative to the `%SRCROOT%` base, which is the directory oxlint was
+/// run from. Columns are counted in UTF-16 code units. Diagnostics which were suppressed by a disable
+/// directive are reported with an `inSource` suppression.
+///
+/// Note that, due to syntactic restrictions of JSON, this reporter waits until all
+/// diagnostics have been reported before writing them to the output stream.
+#[derive(Default)]
+struct SarifReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for SarifReporter {
+    fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+        Some(format_sarif(&self.diagnostics))
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun<'a>; 1],
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun<'a> {
+    tool: SarifTool,
+    results: Vec<SarifResult<'a>>,
+    column_kind: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<SarifRuleProperties>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRuleProperties {
+    category: RuleCategory,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifMessage<'a>,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix<'a>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suppressions: Vec<SarifSuppression>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage<'a> {
+    text: Cow<'a, str>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactLocation {
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<&'static str>,
+}
+
+/// Base of relative artifact URIs.
+const SRCROOT: &str = "%SRCROOT%";
+
+const URI_PATH_ASCII_SET: AsciiSet =
+    // RFC3986 allows only alphanumeric characters, `-`, `.`, `_`, and `~` in the path.
+    percent_encoding::NON_ALPHANUMERIC
+        .remove(b'-')
+        .remove(b'.')
+        .remove(b'_')
+        .remove(b'~')
+        .remove(b'/');
+
+impl SarifArtifactLocation {
+    /// Convert the display path of a file, which is relative to the working directory unless
+    /// the file is outside of it, to a SARIF artifact location.
+    fn new(path: &str) -> Self {
+        if path.starts_with("file://") {
+            return Self { uri: path.to_string(), uri_base_id: None };
+        }
+        let is_absolute = path.starts_with('/') || Path::new(path).is_absolute();
+        let path = path.cow_replace('\\', "/");
+        let encoded = percent_encoding::utf8_percent_encode(&path, &URI_PATH_ASCII_SET);
+        if is_absolute {
+            let separator = if path.starts_with('/') { "" } else { "/" };
+            Self { uri: format!("file://{separator}{encoded}"), uri_base_id: None }
+        } else {
+            Self { uri: encoded.to_string(), uri_base_id: Some(SRCROOT) }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<SarifMessage<'a>>,
+    artifact_changes: [SarifArtifactChange<'a>; 1],
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange<'a> {
+    artifact_location: SarifArtifactLocation,
+    replacements: [SarifReplacement<'a>; 1],
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement<'a> {
+    deleted_region: SarifRegion,
+    inserted_content: SarifMessage<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifSuppression {
+    kind: &'static str,
+}
+
+/// Metadata of a rule built into oxlint.
+struct RuleInfo {
+    help_uri: String,
+    category: RuleCategory,
+}
+
+/// Metadata of built-in rules, keyed by the error codes of their diagnostics, e.g. `eslint(no-debugger)`.
+fn built_in_rules() -> FxHashMap<String, RuleInfo> {
+    RULES
+        .iter()
+        .map(|rule| {
+            let id = format!("{}({})", plugin_name_to_prefix(rule.plugin_name()), rule.name());
+            let help_uri =
+                format!("{WEBSITE_BASE_RULES_URL}/{}/{}.html", rule.plugin_name(), rule.name());
+            (id, RuleInfo { help_uri, category: rule.category() })
+        })
+        .collect()
+}
+
+fn format_sarif(diagnostics: &[Error]) -> String {
+    let built_in_rules = built_in_rules();
+    let mut rules: Vec<SarifRule> = vec![];
+    let mut rule_indexes = FxHashMap::<String, usize>::default();
+    // Files usually have several diagnostics, so their lines are only indexed once.
+    let mut line_indexes = FxHashMap::<String, LineIndex>::default();
+
+    let results = diagnostics
+        .iter()
+        .map(|error| {
+            let diagnostic = OxcDiagnostic::from_error(error);
+
+            let rule_id = error.code().map(|code| code.to_string());
+            let rule_index = rule_id.as_ref().map(|id| {
+                *rule_indexes.entry(id.clone()).or_insert_with(|| {
+                    let info = built_in_rules.get(id);
+                    rules.push(SarifRule {
+                        id: id.clone(),
+                        help_uri: info
+                            .map(|info| info.help_uri.clone())
+                            .or_else(|| error.url().map(|url| url.to_string())),
+                        properties: info
+                            .map(|info| SarifRuleProperties { category: info.category }),
+                    });
+                    rules.len() - 1
+                })
+            });
+
+            let level = match error.severity() {
+                Some(Severity::Error) | None => "error",
+                Some(Severity::Warning) => "warning",
+                Some(Severity::Advice) => "note",
+            };
+
+            let source = error.source_code();
+            let name = source.and_then(source_name).unwrap_or_default();
+            let artifact_location = SarifArtifactLocation::new(&name);
+            let line_index = line_indexes.entry(name).or_insert_with(|| {
+                LineIndex::new(source.and_then(source_text).unwrap_or_default())
+            });
+
+            let primary_span = error.labels().and_then(|labels| {
+                let labels = labels.collect::<Vec<_>>();
+                let label = labels.iter().find(|label| label.primary()).or(labels.first())?;
+                Some(*label.inner())
+            });
+
+            let fixes = diagnostic
+                .map(|diagnostic| {
+                    diagnostic
+                        .fixes
+                        .iter()
+                        .map(|fix| SarifFix {
+                            description: fix
+                                .message
+                                .as_deref()
+                                .map(|text| SarifMessage { text: text.into() }),
+                            artifact_changes: [SarifArtifactChange {
+                                artifact_location: artifact_location.clone(),
+                                replacements: [SarifReplacement {
+                                    deleted_region: line_index.region(fix.span),
+                                    inserted_content: SarifMessage {
+                                        text: fix.content.as_str().into(),
+                                    },
+                                }],
+                            }],
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let suppressions = if diagnostic.is_some_and(|diagnostic| diagnostic.suppressed) {
+                vec![SarifSuppression { kind: "inSource" }]
+            } else {
+                vec![]
+            };
+
+            SarifResult {
+                rule_id,
+                rule_index,
+                level,
+                message: SarifMessage { text: error.to_string().into() },
+                locations: vec![SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location,
+                        region: primary_span.map(|span| line_index.region(span)),
+                    },
+                }],
+                fixes,
+                suppressions,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let log = SarifLog {
+        schema: "https://json.schemastore.org/sarif-2.1.0.json",
+        version: "2.1.0",
+        runs: [SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver { name: "oxlint", information_uri: "https://oxc.rs", rules },
+            },
+            results,
+            column_kind: "utf16CodeUnits",
+        }],
+    };
+
+    serde_json::to_string_pretty(&log).expect("Failed to serialize")
+}
+
+/// Read the file name of `source`.
+fn source_name(source: &dyn miette::SourceCode) -> Option<String> {
+    let contents = source.read_span(&SourceSpan::from((0, 0)), 0, 0).ok()?;
+    contents.name().map(ToString::to_string)
+}
+
+/// Read the full text of `source`.
+fn source_text(source: &dyn miette::SourceCode) -> Option<&str> {
+    // Reading an empty span with unlimited context lines after it returns the whole source.
+    let contents = source.read_span(&SourceSpan::from((0, 0)), 0, usize::MAX).ok()?;
+    std::str::from_utf8(contents.data()).ok()
+}
+
+/// Byte offsets of the start of each line in a source text.
+struct LineIndex<'a> {
+    source_text: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    fn new(source_text: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source_text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Self { source_text, line_starts }
+    }
+
+    /// Convert a span in bytes to a region with 1-based lines, and 1-based columns in UTF-16 code units.
+    fn region(&self, span: SourceSpan) -> SarifRegion {
+        let (start_line, start_column) = self.position(span.offset());
+        let (end_line, end_column) = self.position(span.offset() + span.len());
+        SarifRegion { start_line, start_column, end_line, end_column }
+    }
+
+    fn position(&self, offset: usize) -> (usize, usize) {
+        let offset = self.source_text.floor_char_boundary(offset);
+        // `line_starts[0]` is always `0`, so the line is at least 1
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let column = self.source_text[line_start..offset].encode_utf16().count() + 1;
+        (line, column)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{
+        DiagnosticFix, DiagnosticService, OxcDiagnostic,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+    };
+    use oxc_span::Span;
+
+    use super::SarifReporter;
+
+    #[test]
+    fn reporter() {
+        let mut reporter = SarifReporter::default();
+
+        let source_text = "let a = 1;\nlet 𝒶 = \"€\"; debugger;";
+        let debugger = Span::new(29, 38);
+        assert_eq!(debugger.source_text(source_text), "debugger;");
+        let diagnostics = vec![
+            OxcDiagnostic::warn("`debugger` statement is not allowed")
+                .with_label(debugger)
+                .with_error_code("eslint", "no-debugger")
+                .with_fixes(vec![DiagnosticFix {
+                    message: Some("Remove the debugger statement".into()),
+                    span: (debugger.start as usize, debugger.size() as usize).into(),
+                    content: String::new(),
+                }]),
+            OxcDiagnostic::error("Unexpected var")
+                .with_label(Span::new(0, 3))
+                .with_error_code("eslint", "no-var")
+                .with_suppressed(true),
+        ];
+        for error in DiagnosticService::wrap_diagnostics("", "test.js", source_text, diagnostics) {
+            assert!(reporter.render_error(error).is_none());
+        }
+
+        let result = reporter.finish(&DiagnosticResult::default()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(json["version"], "2.1.0");
+        let run = &json["runs"][0];
+        assert_eq!(run["columnKind"], "utf16CodeUnits");
+
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0]["id"], "eslint(no-debugger)");
+        assert_eq!(
+            rules[0]["helpUri"],
+            "https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html"
+        );
+        assert_eq!(rules[0]["properties"]["category"], "correctness");
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+
+        let result = &results[0];
+        assert_eq!(result["ruleId"], "eslint(no-debugger)");
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(result["level"], "warning");
+        assert_eq!(result["message"]["text"], "`debugger` statement is not allowed");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "test.js");
+        assert_eq!(location["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+        // `𝒶` is 2 UTF-16 code units, `€` is 1.
+        let region = &location["region"];
+        assert_eq!(region["startLine"], 2);
+        assert_eq!(region["startColumn"], 15);
+        assert_eq!(region["endLine"], 2);
+        assert_eq!(region["endColumn"], 24);
+        let fix = &result["fixes"][0];
+        assert_eq!(fix["description"]["text"], "Remove the debugger statement");
+        let replacement = &fix["artifactChanges"][0]["replacements"][0];
+        assert_eq!(replacement["deletedRegion"], *region);
+        assert_eq!(replacement["insertedContent"]["text"], "");
+        assert!(result.get("suppressions").is_none());
+
+        let result = &results[1];
+        assert_eq!(result["ruleIndex"], 1);
+        assert_eq!(result["level"], "error");
+        assert_eq!(result["suppressions"][0]["kind"], "inSource");
+    }
+
+    #[test]
+    fn artifact_location() {
+        let mut reporter = SarifReporter::default();
+        let diagnostics = vec![OxcDiagnostic::warn("warning").with_label(Span::new(0, 1))];
+        for error in
+            DiagnosticService::wrap_diagnostics("", "src/my dir/100%.js", "a", diagnostics.clone())
+                .into_iter()
+                .chain(DiagnosticService::wrap_diagnostics(
+                    "/project",
+                    "/other/a b.js",
+                    "a",
+                    diagnostics,
+                ))
+        {
+            assert!(reporter.render_error(error).is_none());
+        }
+
+        let result = reporter.finish(&DiagnosticResult::default()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let results = json["runs"][0]["results"].as_array().unwrap();
+
+        let location = &results[0]["locations"][0]["physicalLocation"]["artifactLocation"];
+        assert_eq!(location["uri"], "src/my%20dir/100%25.js");
+        assert_eq!(location["uriBaseId"], "%SRCROOT%");
+
+        let location = &results[1]["locations"][0]["physicalLocation"]["artifactLocation"];
+        assert_eq!(location["uri"], "file:///other/a%20b.js");
+        assert!(location.get("uriBaseId").is_none());
+    }
+}
@@ -0,0 +1,136 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+
+use oxc_diagnostics::{Error, OxcDiagnostic, Severity};
+
+/// Number of suppressed violations of a rule in a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct SuppressionCount {
+    count: usize,
+}
+
+/// Suppressions of existing errors, keyed by file path relative to the working directory, then by
+/// rule code, e.g. `eslint(no-debugger)`.
+///
+/// Errors of a rule in a file are not reported as long as there are no more of them than
+/// recorded. If there are more, all of them are reported, because there is no way to tell which
+/// of them are new.
+///
+/// Similar to [ESLint's bulk suppressions](https://eslint.org/docs/latest/use/suppressions).
+#[derive(Debug, Default)]
+pub struct Suppressions {
+    path: PathBuf,
+    entries: BTreeMap<String, BTreeMap<String, SuppressionCount>>,
+}
+
+impl Suppressions {
+    /// Load the suppressions file at `path`. An empty set of suppressions is returned if the
+    /// file does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the file cannot be read or parsed.
+    pub fn load(path: PathBuf) -> Result<Self, String> {
+        let entries = match fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|err| {
+                format!("Failed to parse suppressions file {}:\n{err}\n", path.display())
+            })?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::default(),
+            Err(err) => {
+                return Err(format!(
+                    "Failed to read suppressions file {}:\n{err}\n",
+                    path.display()
+                ));
+            }
+        };
+        Ok(Self { path, entries })
+    }
+
+    /// Write the suppressions to the file they were loaded from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the file cannot be written.
+    pub fn save(&self) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self.entries).expect("Failed to serialize");
+        fs::write(&self.path, json + "\n").map_err(|err| {
+            format!("Failed to write suppressions file {}:\n{err}\n", self.path.display())
+        })
+    }
+
+    /// Record the errors in `diagnostics`, replacing the counts of the affected rules.
+    pub fn suppress_all(&mut self, diagnostics: &[Vec<Error>]) {
+        for ((file, rule), count) in count_errors(diagnostics) {
+            self.entries.entry(file).or_default().insert(rule, SuppressionCount { count });
+        }
+    }
+
+    /// Reduce the counts of suppressions in `linted_files` to the number of errors in
+    /// `diagnostics`, and remove suppressions of files which no longer exist.
+    pub fn prune(
+        &mut self,
+        diagnostics: &[Vec<Error>],
+        linted_files: &FxHashSet<String>,
+        cwd: &Path,
+    ) {
+        let counts = count_errors(diagnostics);
+        self.entries.retain(|file, rules| {
+            if !linted_files.contains(file) {
+                return cwd.join(file).exists();
+            }
+            rules.retain(|rule, suppression| {
+                let count = counts.get(&(file.clone(), rule.clone())).copied().unwrap_or(0);
+                suppression.count = suppression.count.min(count);
+                suppression.count > 0
+            });
+            !rules.is_empty()
+        });
+    }
+
+    /// Remove suppressed errors from `diagnostics`.
+    pub fn filter(&self, diagnostics: Vec<Vec<Error>>) -> Vec<Vec<Error>> {
+        let counts = count_errors(&diagnostics);
+        let is_suppressed = |error: &Error| {
+            error_key(error).is_some_and(|key| {
+                self.entries
+                    .get(&key.0)
+                    .and_then(|rules| rules.get(&key.1))
+                    .is_some_and(|suppression| counts[&key] <= suppression.count)
+            })
+        };
+        diagnostics
+            .into_iter()
+            .map(|errors| errors.into_iter().filter(|error| !is_suppressed(error)).collect())
+            .collect()
+    }
+}
+
+/// File path and rule code of an error which can be suppressed.
+/// Only errors reported by rules can be suppressed, warnings are never suppressed.
+/// Errors which are already suppressed by disable directives are ignored.
+fn error_key(error: &Error) -> Option<(String, String)> {
+    if !matches!(error.severity(), Some(Severity::Error) | None)
+        || OxcDiagnostic::from_error(error).is_some_and(|diagnostic| diagnostic.suppressed)
+    {
+        return None;
+    }
+    let rule = error.code()?.to_string();
+    let source = error.source_code()?;
+    let contents = source.read_span(&(0, 0).into(), 0, 0).ok()?;
+    let file = contents.name()?.to_string();
+    Some((file, rule))
+}
+
+fn count_errors(diagnostics: &[Vec<Error>]) -> FxHashMap<(String, String), usize> {
+    let mut counts = FxHashMap::default();
+    for key in diagnostics.iter().flatten().filter_map(error_key) {
+        *counts.entry(key).or_default() += 1;
+    }
+    counts
+}
@@ -1,4 +1,7 @@
-use std::{env, path::PathBuf};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
 
 use cow_utils::CowUtils;
 use lazy_regex::Regex;
@@ -135,3 +138,57 @@ impl Tester {
         });
     }
 }
+
+/// Restores a fixture directory when dropped, for tests which edit fixtures.
+///
+/// The contents of all files in the directory are recorded when the guard is created. When the
+/// guard is dropped, even if the test panics, the files are written back and files or directories
+/// created in the meantime are removed.
+pub struct FixtureGuard {
+    dir: PathBuf,
+    files: Vec<(PathBuf, Vec<u8>)>,
+}
+
+impl FixtureGuard {
+    pub fn new(dir: &Path) -> Self {
+        let mut files = Vec::new();
+        Self::read_files(dir, &mut files);
+        Self { dir: dir.to_path_buf(), files }
+    }
+
+    fn read_files(dir: &Path, files: &mut Vec<(PathBuf, Vec<u8>)>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                Self::read_files(&path, files);
+            } else {
+                let content = fs::read(&path).unwrap();
+                files.push((path, content));
+            }
+        }
+    }
+
+    fn remove_new_files(&self, dir: &Path) {
+        let Ok(entries) = fs::read_dir(dir) else { return };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !self.files.iter().any(|(file, _)| file.starts_with(&path)) {
+                let _ =
+                    if path.is_dir() { fs::remove_dir_all(&path) } else { fs::remove_file(&path) };
+            } else if path.is_dir() {
+                self.remove_new_files(&path);
+            }
+        }
+    }
+}
+
+impl Drop for FixtureGuard {
+    fn drop(&mut self) {
+        self.remove_new_files(&self.dir);
+        for (path, content) in &self.files {
+            if fs::read(path).ok().as_ref() != Some(content) {
+                let _ = fs::write(path, content);
+            }
+        }
+    }
+}
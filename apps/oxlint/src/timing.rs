@@ -0,0 +1,238 @@
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use cow_utils::CowUtils;
+use serde::Serialize;
+
+use oxc_linter::{FileTiming, LintTimings, RuleTimingEntry};
+
+/// Number of rows printed per table, unless changed with the `TIMING` environment variable.
+const DEFAULT_ROWS: usize = 10;
+
+/// Number of rows to print per timing table, or `None` if timing is not enabled.
+///
+/// Like ESLint, timing is also enabled by setting the `TIMING` environment variable, which can be
+/// `all` to print all rows, or a number of rows.
+pub fn timing_rows(enabled: bool, env_value: Option<&str>) -> Option<usize> {
+    match env_value {
+        Some(value) if value.eq_ignore_ascii_case("all") => Some(usize::MAX),
+        Some(value) => Some(value.parse().map_or(DEFAULT_ROWS, |rows| DEFAULT_ROWS.max(rows))),
+        None => enabled.then_some(DEFAULT_ROWS),
+    }
+}
+
+/// Tables of the slowest rules, the time spent in each phase, and the slowest files.
+pub fn timing_tables(timings: &LintTimings, rows: usize, cwd: &Path) -> String {
+    let rules = timings.rules();
+    let rules_total = rules.iter().map(|rule| rule.timing.total()).sum();
+    let rule_rows: Vec<_> = rules
+        .iter()
+        .take(rows)
+        .map(|rule| {
+            let total = rule.timing.total();
+            vec![rule_name(rule), millis(total), relative(total, rules_total)]
+        })
+        .collect();
+
+    let phases = timings.phases();
+    let phases_total = phases.total();
+    let phase_rows: Vec<_> = [
+        ("parse", phases.parse),
+        ("semantic", phases.semantic),
+        ("module graph", phases.module_graph),
+        ("lint", phases.lint),
+    ]
+    .into_iter()
+    .map(|(name, time)| vec![name.to_string(), millis(time), relative(time, phases_total)])
+    .collect();
+
+    let file_rows: Vec<_> = timings
+        .files()
+        .into_iter()
+        .take(rows)
+        .map(|(path, timing)| {
+            vec![
+                relative_path(&path, cwd),
+                millis(timing.parse),
+                millis(timing.semantic),
+                millis(timing.module_graph),
+                millis(timing.lint),
+                millis(timing.total()),
+            ]
+        })
+        .collect();
+
+    let mut output = String::new();
+    output.push_str(&table(&["Rule", "Time (ms)", "Relative"], &rule_rows));
+    output.push('\n');
+    output.push_str(&table(&["Phase", "Time (ms)", "Relative"], &phase_rows));
+    output.push('\n');
+    output.push_str(&table(
+        &["File", "Parse (ms)", "Semantic (ms)", "Module graph (ms)", "Lint (ms)", "Total (ms)"],
+        &file_rows,
+    ));
+    output
+}
+
+/// Write all timings as JSON to `path`, e.g. for tracking them in CI.
+///
+/// # Errors
+///
+/// Returns an error message if the file cannot be written.
+pub fn write_timing_json(timings: &LintTimings, path: &Path, cwd: &Path) -> Result<(), String> {
+    let json = TimingJson {
+        rules: timings
+            .rules()
+            .iter()
+            .map(|rule| RuleTimingJson {
+                rule: rule_name(rule),
+                run: rule.timing.run.as_secs_f64() * 1000.0,
+                run_once: rule.timing.run_once.as_secs_f64() * 1000.0,
+                run_on_jest_node: rule.timing.run_on_jest_node.as_secs_f64() * 1000.0,
+                total: rule.timing.total().as_secs_f64() * 1000.0,
+            })
+            .collect(),
+        phases: PhaseTimingJson::from(timings.phases()),
+        files: timings
+            .files()
+            .into_iter()
+            .map(|(path, timing)| FileTimingJson {
+                path: relative_path(&path, cwd),
+                timing: PhaseTimingJson::from(timing),
+            })
+            .collect(),
+    };
+    let json = serde_json::to_string_pretty(&json).expect("Failed to serialize");
+    let path = if path.is_relative() { cwd.join(path) } else { PathBuf::from(path) };
+    fs::write(&path, json + "\n")
+        .map_err(|err| format!("Failed to write timings to {}:\n{err}\n", path.display()))
+}
+
+/// All durations are in milliseconds.
+#[derive(Debug, Serialize)]
+struct TimingJson {
+    rules: Vec<RuleTimingJson>,
+    phases: PhaseTimingJson,
+    files: Vec<FileTimingJson>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RuleTimingJson {
+    rule: String,
+    run: f64,
+    run_once: f64,
+    run_on_jest_node: f64,
+    total: f64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhaseTimingJson {
+    parse: f64,
+    semantic: f64,
+    module_graph: f64,
+    lint: f64,
+    total: f64,
+}
+
+impl From<FileTiming> for PhaseTimingJson {
+    fn from(timing: FileTiming) -> Self {
+        Self {
+            parse: timing.parse.as_secs_f64() * 1000.0,
+            semantic: timing.semantic.as_secs_f64() * 1000.0,
+            module_graph: timing.module_graph.as_secs_f64() * 1000.0,
+            lint: timing.lint.as_secs_f64() * 1000.0,
+            total: timing.total().as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct FileTimingJson {
+    path: String,
+    #[serde(flatten)]
+    timing: PhaseTimingJson,
+}
+
+fn rule_name(rule: &RuleTimingEntry) -> String {
+    format!("{}/{}", rule.plugin_name, rule.rule_name)
+}
+
+fn relative_path(path: &Path, cwd: &Path) -> String {
+    let path = path.strip_prefix(cwd).unwrap_or(path).to_string_lossy();
+    path.cow_replace('\\', "/").into_owned()
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+fn relative(duration: Duration, total: Duration) -> String {
+    if total.is_zero() {
+        return "0.0%".to_string();
+    }
+    format!("{:.1}%", duration.as_secs_f64() * 100.0 / total.as_secs_f64())
+}
+
+/// Markdown table with the first column aligned left and other columns aligned right, like ESLint's.
+fn table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let widths = headers
+        .iter()
+        .enumerate()
+        .map(|(i, header)| {
+            rows.iter().map(|row| row[i].len()).chain([header.len()]).max().unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let mut output = String::new();
+    let mut write_row = |cells: &mut dyn Iterator<Item = &str>| {
+        for (i, cell) in cells.enumerate() {
+            if i > 0 {
+                output.push_str(" | ");
+            }
+            if i == 0 {
+                let _ = write!(output, "{cell:<width$}", width = widths[i]);
+            } else {
+                let _ = write!(output, "{cell:>width$}", width = widths[i]);
+            }
+        }
+        output.push('\n');
+    };
+    write_row(&mut headers.iter().copied());
+    let separators = widths
+        .iter()
+        .enumerate()
+        .map(|(i, width)| {
+            if i == 0 {
+                format!(":{}", "-".repeat(width - 1))
+            } else {
+                format!("{}:", "-".repeat(width - 1))
+            }
+        })
+        .collect::<Vec<_>>();
+    write_row(&mut separators.iter().map(String::as_str));
+    for row in rows {
+        write_row(&mut row.iter().map(String::as_str));
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::timing_rows;
+
+    #[test]
+    fn rows() {
+        assert_eq!(timing_rows(false, None), None);
+        assert_eq!(timing_rows(true, None), Some(10));
+        assert_eq!(timing_rows(false, Some("1")), Some(10));
+        assert_eq!(timing_rows(false, Some("50")), Some(50));
+        assert_eq!(timing_rows(false, Some("ALL")), Some(usize::MAX));
+        assert_eq!(timing_rows(true, Some("invalid")), Some(10));
+    }
+}
@@ -116,7 +116,6 @@ impl Walk {
         receiver.into_iter().flatten().collect()
     }
 
-    #[cfg_attr(not(test), expect(dead_code))]
     pub fn with_extensions(mut self, extensions: Extensions) -> Self {
         self.extensions = extensions;
         self
@@ -9,6 +9,7 @@ use oxc_mangler::{MangleOptions, Mangler, ManglerReturn};
 use oxc_minifier::{CompressOptions, Compressor};
 use oxc_parser::{ParseOptions, Parser, ParserReturn};
 use oxc_semantic::{Scoping, SemanticBuilder, SemanticBuilderReturn};
+use oxc_sourcemap::SourceMap;
 use oxc_span::SourceType;
 use oxc_transformer::{TransformOptions, Transformer, TransformerReturn};
 use oxc_transformer_plugins::{
@@ -58,6 +59,14 @@ pub trait CompilerInterface {
         false
     }
 
+    /// Source map of the source text, when it was generated by an upstream tool.
+    ///
+    /// The emitted source map is composed with it, so that it points at the original sources.
+    /// Only used when [`CompilerInterface::enable_sourcemap`] is `true`.
+    fn input_source_map(&self) -> Option<SourceMap> {
+        None
+    }
+
     fn parse_options(&self) -> ParseOptions {
         ParseOptions::default()
     }
@@ -283,14 +292,18 @@ pub trait CompilerInterface {
         let mut options = options;
         if self.enable_sourcemap() {
             options.source_map_path = Some(source_path.to_path_buf());
+            if let Some(input_source_map) = self.input_source_map() {
+                options.input_source_map = Some(input_source_map);
+            }
         }
-        let (scoping, class_private_mappings) = mangler_return
-            .map(|m| (Some(m.scoping), Some(m.class_private_mappings)))
+        let (scoping, class_private_mappings, property_mappings) = mangler_return
+            .map(|m| (Some(m.scoping), Some(m.class_private_mappings), Some(m.property_mappings)))
             .unwrap_or_default();
         Codegen::new()
             .with_options(options)
             .with_scoping(scoping)
             .with_private_member_mappings(class_private_mappings)
+            .with_property_mappings(property_mappings)
             .build(program)
     }
 }
@@ -114,6 +114,15 @@ pub mod codegen {
     pub use oxc_codegen::*;
 }
 
+#[cfg(feature = "codegen")]
+pub mod sourcemap {
+    //! Source map types, used for input and output source maps of codegen.
+    //!
+    //! See the [`oxc_sourcemap` crate documentation](oxc_sourcemap) for more information.
+    #[doc(inline)]
+    pub use oxc_sourcemap::*;
+}
+
 #[cfg(feature = "isolated_declarations")]
 pub mod isolated_declarations {
     //! `.d.ts` emit for Isolated Declarations.
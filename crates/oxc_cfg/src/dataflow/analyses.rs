@@ -0,0 +1,286 @@
+use oxc_syntax::{node::NodeId, symbol::SymbolId};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::{Analysis, DataflowResults, Direction};
+use crate::BlockNodeId;
+
+/// Whether an [`Access`] reads or writes its symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccessKind {
+    Read,
+    Write,
+}
+
+/// A read or write of a symbol. Accesses which both read and write a symbol, like `x += 1`, are
+/// represented as a read followed by a write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Access {
+    pub symbol_id: SymbolId,
+    /// The node performing the access, e.g. an `IdentifierReference` or a `VariableDeclarator`.
+    pub node_id: NodeId,
+    pub kind: AccessKind,
+}
+
+impl Access {
+    pub fn is_read(&self) -> bool {
+        self.kind == AccessKind::Read
+    }
+
+    pub fn is_write(&self) -> bool {
+        self.kind == AccessKind::Write
+    }
+}
+
+/// The accesses of each basic block, in evaluation order.
+#[derive(Debug, Default)]
+pub struct BlockAccesses {
+    accesses: FxHashMap<BlockNodeId, Vec<Access>>,
+}
+
+impl BlockAccesses {
+    /// Appends `access` to the accesses of `block`.
+    pub fn push(&mut self, block: BlockNodeId, access: Access) {
+        self.accesses.entry(block).or_default().push(access);
+    }
+
+    /// The accesses of `block`, in evaluation order.
+    pub fn get(&self, block: BlockNodeId) -> &[Access] {
+        self.accesses.get(&block).map_or(&[], Vec::as_slice)
+    }
+
+    /// All blocks with at least one access, along with their accesses.
+    pub fn iter(&self) -> impl Iterator<Item = (BlockNodeId, &[Access])> {
+        self.accesses.iter().map(|(&block, accesses)| (block, accesses.as_slice()))
+    }
+}
+
+/// A write of a symbol, as tracked by [`ReachingDefinitions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Definition {
+    pub symbol_id: SymbolId,
+    pub node_id: NodeId,
+}
+
+/// An [`Analysis`] whose blocks are a sequence of [`Access`]es.
+pub trait AccessAnalysis: Analysis {
+    fn accesses(&self) -> &BlockAccesses;
+
+    /// Applies the effect of a single access to `state`.
+    fn apply(&self, state: &mut Self::Domain, access: &Access);
+
+    /// Calls `visit` for each access of `block` in the direction of the analysis, along with the
+    /// state right before the access is applied. For forward analyses, this is the state before
+    /// the access in program order, and for backward analyses, the state after it.
+    fn for_each_access<F>(
+        &self,
+        results: &DataflowResults<Self::Domain>,
+        block: BlockNodeId,
+        visit: F,
+    ) where
+        F: FnMut(&Access, &Self::Domain),
+    {
+        let state = match Self::DIRECTION {
+            Direction::Forward => results.entry(block),
+            Direction::Backward => results.exit(block),
+        };
+        let Some(state) = state else { return };
+        let handler = results.exceptional(block).filter(|_| Self::DIRECTION == Direction::Backward);
+        let mut checkpoint = |state: &mut Self::Domain| {
+            if let Some(handler) = handler {
+                self.join(state, handler);
+            }
+        };
+        walk(self, block, &mut state.clone(), &mut checkpoint, visit);
+    }
+}
+
+/// Applies the accesses of `block` to `state` in the direction of the analysis. Any access can
+/// throw, so `checkpoint` is called with the state before each of them in program order.
+fn walk<A, F>(
+    analysis: &A,
+    block: BlockNodeId,
+    state: &mut A::Domain,
+    checkpoint: &mut dyn FnMut(&mut A::Domain),
+    mut visit: F,
+) where
+    A: AccessAnalysis + ?Sized,
+    F: FnMut(&Access, &A::Domain),
+{
+    let accesses = analysis.accesses().get(block);
+    match A::DIRECTION {
+        Direction::Forward => {
+            for access in accesses {
+                checkpoint(state);
+                visit(access, state);
+                analysis.apply(state, access);
+            }
+        }
+        Direction::Backward => {
+            for access in accesses.iter().rev() {
+                visit(access, state);
+                analysis.apply(state, access);
+                checkpoint(state);
+            }
+        }
+    }
+}
+
+/// Computes the writes which may reach each program point without being overwritten.
+///
+/// ```js
+/// let x = 1; // reaches `log(x)` when `a` is falsy
+/// if (a) x = 2; // reaches `log(x)` when `a` is truthy
+/// log(x);
+/// ```
+pub struct ReachingDefinitions<'a> {
+    accesses: &'a BlockAccesses,
+}
+
+impl<'a> ReachingDefinitions<'a> {
+    pub fn new(accesses: &'a BlockAccesses) -> Self {
+        Self { accesses }
+    }
+}
+
+impl Analysis for ReachingDefinitions<'_> {
+    type Domain = FxHashSet<Definition>;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn boundary(&self) -> Self::Domain {
+        FxHashSet::default()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.extend(other.iter().copied());
+    }
+
+    fn transfer(
+        &self,
+        block: BlockNodeId,
+        state: &mut Self::Domain,
+        checkpoint: &mut dyn FnMut(&mut Self::Domain),
+    ) {
+        walk(self, block, state, checkpoint, |_, _| {});
+    }
+}
+
+impl AccessAnalysis for ReachingDefinitions<'_> {
+    fn accesses(&self) -> &BlockAccesses {
+        self.accesses
+    }
+
+    fn apply(&self, state: &mut Self::Domain, access: &Access) {
+        if access.is_write() {
+            state.retain(|definition| definition.symbol_id != access.symbol_id);
+            state.insert(Definition { symbol_id: access.symbol_id, node_id: access.node_id });
+        }
+    }
+}
+
+/// Computes the symbols whose current value may still be read, i.e. which are read on some path
+/// before being written again.
+///
+/// ```js
+/// let x = 1; // `x` is not live after this write, as it is always overwritten
+/// x = 2;
+/// log(x);
+/// ```
+pub struct LiveVariables<'a> {
+    accesses: &'a BlockAccesses,
+}
+
+impl<'a> LiveVariables<'a> {
+    pub fn new(accesses: &'a BlockAccesses) -> Self {
+        Self { accesses }
+    }
+}
+
+impl Analysis for LiveVariables<'_> {
+    type Domain = FxHashSet<SymbolId>;
+
+    const DIRECTION: Direction = Direction::Backward;
+
+    fn boundary(&self) -> Self::Domain {
+        FxHashSet::default()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.extend(other.iter().copied());
+    }
+
+    fn transfer(
+        &self,
+        block: BlockNodeId,
+        state: &mut Self::Domain,
+        checkpoint: &mut dyn FnMut(&mut Self::Domain),
+    ) {
+        walk(self, block, state, checkpoint, |_, _| {});
+    }
+}
+
+impl AccessAnalysis for LiveVariables<'_> {
+    fn accesses(&self) -> &BlockAccesses {
+        self.accesses
+    }
+
+    fn apply(&self, state: &mut Self::Domain, access: &Access) {
+        match access.kind {
+            AccessKind::Read => state.insert(access.symbol_id),
+            AccessKind::Write => state.remove(&access.symbol_id),
+        };
+    }
+}
+
+/// Computes the symbols which are written on every path to each program point.
+///
+/// ```js
+/// let x;
+/// if (a) x = 1;
+/// else x = 2;
+/// log(x); // `x` is definitely assigned here
+/// ```
+pub struct DefiniteAssignment<'a> {
+    accesses: &'a BlockAccesses,
+}
+
+impl<'a> DefiniteAssignment<'a> {
+    pub fn new(accesses: &'a BlockAccesses) -> Self {
+        Self { accesses }
+    }
+}
+
+impl Analysis for DefiniteAssignment<'_> {
+    type Domain = FxHashSet<SymbolId>;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn boundary(&self) -> Self::Domain {
+        FxHashSet::default()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.retain(|symbol_id| other.contains(symbol_id));
+    }
+
+    fn transfer(
+        &self,
+        block: BlockNodeId,
+        state: &mut Self::Domain,
+        checkpoint: &mut dyn FnMut(&mut Self::Domain),
+    ) {
+        walk(self, block, state, checkpoint, |_, _| {});
+    }
+}
+
+impl AccessAnalysis for DefiniteAssignment<'_> {
+    fn accesses(&self) -> &BlockAccesses {
+        self.accesses
+    }
+
+    fn apply(&self, state: &mut Self::Domain, access: &Access) {
+        if access.is_write() {
+            state.insert(access.symbol_id);
+        }
+    }
+}
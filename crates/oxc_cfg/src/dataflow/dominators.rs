@@ -0,0 +1,141 @@
+use rustc_hash::FxHashMap;
+
+use super::{Region, postorder};
+use crate::{BlockNodeId, ControlFlowGraph};
+
+/// The dominator or post-dominator tree of the blocks reachable from a start block.
+///
+/// A block `a` dominates `b` if every path from the start block to `b` goes through `a`, and
+/// post-dominates `b` if every path from `b` to an exit of the region goes through `a`. Edges are
+/// filtered with [`is_dataflow_edge`](super::is_dataflow_edge).
+#[derive(Debug)]
+pub struct DominatorTree {
+    /// Block -> immediate (post-)dominator, `None` for roots of the tree.
+    immediate: FxHashMap<BlockNodeId, Option<BlockNodeId>>,
+}
+
+impl DominatorTree {
+    /// Computes the dominator tree rooted at `start`.
+    pub fn dominators(cfg: &ControlFlowGraph, start: BlockNodeId) -> Self {
+        let region = Region::new(cfg, start);
+        let immediate = immediate_dominators(0, &region.successors, &region.predecessors);
+        let immediate = immediate
+            .into_iter()
+            .enumerate()
+            .map(|(i, idom)| {
+                (region.blocks[i], idom.filter(|&idom| idom != i).map(|idom| region.blocks[idom]))
+            })
+            .collect();
+        Self { immediate }
+    }
+
+    /// Computes the post-dominator tree of the blocks reachable from `start`.
+    ///
+    /// Blocks without successors, such as returns and throws, are the roots of the tree. Blocks
+    /// which never reach one of them, e.g. in infinite loops, are not part of the tree.
+    pub fn post_dominators(cfg: &ControlFlowGraph, start: BlockNodeId) -> Self {
+        let region = Region::new(cfg, start);
+        // Solve on the reversed graph, with a virtual root succeeding every exit.
+        let root = region.blocks.len();
+        let mut successors = region.predecessors;
+        let mut predecessors = region.successors;
+        let exits = (0..root).filter(|&i| predecessors[i].is_empty()).collect();
+        for predecessors in predecessors.iter_mut().filter(|predecessors| predecessors.is_empty()) {
+            predecessors.push(root);
+        }
+        successors.push(exits);
+        predecessors.push(vec![]);
+
+        let immediate = immediate_dominators(root, &successors, &predecessors);
+        let immediate = region
+            .blocks
+            .iter()
+            .zip(immediate)
+            .filter_map(|(&block, idom)| {
+                idom.map(|idom| (block, (idom != root).then(|| region.blocks[idom])))
+            })
+            .collect();
+        Self { immediate }
+    }
+
+    /// Whether `block` is part of the tree.
+    pub fn contains(&self, block: BlockNodeId) -> bool {
+        self.immediate.contains_key(&block)
+    }
+
+    /// The closest block which strictly (post-)dominates `block`.
+    ///
+    /// Returns `None` for roots of the tree and blocks which are not part of it.
+    pub fn immediate_dominator(&self, block: BlockNodeId) -> Option<BlockNodeId> {
+        self.immediate.get(&block).copied().flatten()
+    }
+
+    /// Whether `a` (post-)dominates `b`. Every block in the tree dominates itself.
+    pub fn dominates(&self, a: BlockNodeId, b: BlockNodeId) -> bool {
+        self.contains(b) && self.dominators_of(b).any(|block| block == a)
+    }
+
+    /// Whether `a` (post-)dominates `b` and is not `b`.
+    pub fn strictly_dominates(&self, a: BlockNodeId, b: BlockNodeId) -> bool {
+        a != b && self.dominates(a, b)
+    }
+
+    /// `block` followed by all blocks (post-)dominating it, from the closest to the root.
+    pub fn dominators_of(&self, block: BlockNodeId) -> impl Iterator<Item = BlockNodeId> + '_ {
+        std::iter::successors(self.contains(block).then_some(block), |&block| {
+            self.immediate_dominator(block)
+        })
+    }
+}
+
+/// Computes the immediate dominator of each node reachable from `root`, with `root` being its own
+/// immediate dominator. Nodes unreachable from `root` have none.
+///
+/// Cooper, Harvey, and Kennedy, "A Simple, Fast Dominance Algorithm".
+fn immediate_dominators(
+    root: usize,
+    successors: &[Vec<usize>],
+    predecessors: &[Vec<usize>],
+) -> Vec<Option<usize>> {
+    let postorder = postorder(root, |node| successors[node].clone());
+    let mut postorder_number = vec![usize::MAX; successors.len()];
+    for (number, &node) in postorder.iter().enumerate() {
+        postorder_number[node] = number;
+    }
+
+    let intersect = |immediate: &[Option<usize>], mut a: usize, mut b: usize| {
+        while a != b {
+            while postorder_number[a] < postorder_number[b] {
+                a = immediate[a].unwrap_or(root);
+            }
+            while postorder_number[b] < postorder_number[a] {
+                b = immediate[b].unwrap_or(root);
+            }
+        }
+        a
+    };
+
+    let mut immediate = vec![None; successors.len()];
+    immediate[root] = Some(root);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &node in postorder.iter().rev().filter(|&&node| node != root) {
+            let mut new_immediate = None;
+            for &predecessor in &predecessors[node] {
+                if immediate[predecessor].is_none() {
+                    continue;
+                }
+                new_immediate = Some(match new_immediate {
+                    Some(current) => intersect(&immediate, predecessor, current),
+                    None => predecessor,
+                });
+            }
+            if new_immediate.is_some() && immediate[node] != new_immediate {
+                immediate[node] = new_immediate;
+                changed = true;
+            }
+        }
+    }
+    immediate
+}
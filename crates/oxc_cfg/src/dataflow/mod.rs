@@ -0,0 +1,311 @@
+//! Dataflow analysis over the basic blocks of a [`ControlFlowGraph`].
+//!
+//! An analysis implements [`Analysis`] and is run with [`solve`], which computes the state at the
+//! entry and exit of every basic block reachable from a start block, usually the first block of
+//! a function or of the program. Nested functions are not part of the analyzed region, as they
+//! are only connected through [`EdgeType::NewFunction`] edges.
+//!
+//! Ready-made analyses of variable accesses are provided in [`ReachingDefinitions`],
+//! [`LiveVariables`] and [`DefiniteAssignment`].
+
+mod analyses;
+mod dominators;
+
+use std::{collections::VecDeque, hash::Hash};
+
+use petgraph::{Direction as GraphDirection, visit::EdgeRef};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{BlockNodeId, ControlFlowGraph, EdgeType, ErrorEdgeKind};
+
+pub use analyses::{
+    Access, AccessAnalysis, AccessKind, BlockAccesses, DefiniteAssignment, Definition,
+    LiveVariables, ReachingDefinitions,
+};
+pub use dominators::DominatorTree;
+
+/// The direction in which an [`Analysis`] propagates its state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// From the start block towards the exits, e.g. reaching definitions.
+    Forward,
+    /// From the exits towards the start block, e.g. live variables.
+    Backward,
+}
+
+/// A dataflow analysis, solved with [`solve`].
+///
+/// The analysis must be monotone, i.e. [`Analysis::join`] and [`Analysis::transfer`] must never
+/// move a state back to a previous value, or solving it may not terminate.
+pub trait Analysis {
+    /// The state tracked at each program point.
+    type Domain: Clone + PartialEq;
+
+    const DIRECTION: Direction;
+
+    /// The state at the boundary of the analyzed region: at the entry of the start block for
+    /// forward analyses, and at the exit of blocks without successors for backward analyses.
+    fn boundary(&self) -> Self::Domain;
+
+    /// Merges the state flowing in from another block into `state`.
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain);
+
+    /// Applies the effect of `block` to `state`. The state is the one at the entry of the block
+    /// for forward analyses, and the one at the exit of the block for backward analyses.
+    ///
+    /// Inside a `try` statement, an exception can move control to a `catch` or `finally` block
+    /// at any point of `block`. `checkpoint` should be called with the state at each such point
+    /// between the instructions of the block: forward analyses propagate the join of these states
+    /// to the `catch` or `finally` block, and backward analyses have the state at the entry of the
+    /// `catch` or `finally` block joined into them.
+    fn transfer(
+        &self,
+        block: BlockNodeId,
+        state: &mut Self::Domain,
+        checkpoint: &mut dyn FnMut(&mut Self::Domain),
+    );
+}
+
+/// The states computed by [`solve`].
+#[derive(Debug)]
+pub struct DataflowResults<D> {
+    index: FxHashMap<BlockNodeId, usize>,
+    entry: Vec<Option<D>>,
+    exit: Vec<Option<D>>,
+    exceptional: Vec<Option<D>>,
+}
+
+impl<D> DataflowResults<D> {
+    /// The state before the first instruction of `block`, in program order.
+    ///
+    /// Returns `None` if `block` is not reachable from the start block.
+    pub fn entry(&self, block: BlockNodeId) -> Option<&D> {
+        self.index.get(&block).and_then(|&i| self.entry[i].as_ref())
+    }
+
+    /// The state after the last instruction of `block`, in program order.
+    ///
+    /// Returns `None` if `block` is not reachable from the start block.
+    pub fn exit(&self, block: BlockNodeId) -> Option<&D> {
+        self.index.get(&block).and_then(|&i| self.exit[i].as_ref())
+    }
+
+    /// For blocks inside a `try` statement, the join of the states at the points where control
+    /// can move to a `catch` or `finally` block for forward analyses, and the join of the states
+    /// at the entry of these blocks for backward analyses.
+    ///
+    /// Returns `None` if `block` can not throw into a `catch` or `finally` block.
+    pub fn exceptional(&self, block: BlockNodeId) -> Option<&D> {
+        self.index.get(&block).and_then(|&i| self.exceptional[i].as_ref())
+    }
+
+    /// All blocks reachable from the start block.
+    pub fn blocks(&self) -> impl Iterator<Item = BlockNodeId> + '_ {
+        self.index.keys().copied()
+    }
+}
+
+/// Solves `analysis` for all blocks reachable from `start` with a worklist algorithm.
+///
+/// Blocks which never reach an exit, e.g. the blocks of an infinite loop, are solved starting
+/// from [`Analysis::boundary`] in backward analyses.
+pub fn solve<A: Analysis>(
+    cfg: &ControlFlowGraph,
+    start: BlockNodeId,
+    analysis: &A,
+) -> DataflowResults<A::Domain> {
+    let region = Region::new(cfg, start);
+    let len = region.blocks.len();
+    let forward = A::DIRECTION == Direction::Forward;
+    let (inputs, outputs) = if forward {
+        (&region.predecessors, &region.successors)
+    } else {
+        (&region.successors, &region.predecessors)
+    };
+    // Visit blocks in reverse postorder for forward analyses and in postorder for backward ones,
+    // so that most blocks are visited after the blocks flowing into them.
+    let order: Vec<usize> = if forward { (0..len).collect() } else { (0..len).rev().collect() };
+
+    // States flowing into and out of each block, in the direction of the analysis.
+    let mut input: Vec<Option<A::Domain>> = vec![None; len];
+    let mut output: Vec<Option<A::Domain>> = vec![None; len];
+    let mut exceptional: Vec<Option<A::Domain>> = vec![None; len];
+    let mut is_boundary: Vec<bool> =
+        (0..len).map(|i| if forward { i == 0 } else { inputs[i].is_empty() }).collect();
+    let mut queued = vec![true; len];
+    let mut worklist: VecDeque<usize> = order.iter().copied().collect();
+
+    let join = |state: &mut Option<A::Domain>, other: &A::Domain| match state {
+        Some(state) => analysis.join(state, other),
+        None => *state = Some(other.clone()),
+    };
+
+    loop {
+        while let Some(i) = worklist.pop_front() {
+            queued[i] = false;
+
+            let mut state = is_boundary[i].then(|| analysis.boundary());
+            for &j in &inputs[i] {
+                // Exceptional edges carry the states of all points which can throw.
+                let other = if forward && region.exceptional_successors[j].contains(&i) {
+                    &exceptional[j]
+                } else {
+                    &output[j]
+                };
+                if let Some(other) = other {
+                    join(&mut state, other);
+                }
+            }
+            // Nothing flows into this block yet, it is visited again once something does.
+            let Some(state) = state else { continue };
+
+            let mut next = state.clone();
+            let mut next_exceptional = None;
+            if region.exceptional_successors[i].is_empty() {
+                analysis.transfer(region.blocks[i], &mut next, &mut |_| {});
+            } else if forward {
+                join(&mut next_exceptional, &state);
+                analysis.transfer(region.blocks[i], &mut next, &mut |state| {
+                    join(&mut next_exceptional, state);
+                });
+                join(&mut next_exceptional, &next);
+            } else {
+                for &j in &region.exceptional_successors[i] {
+                    if let Some(other) = &output[j] {
+                        join(&mut next_exceptional, other);
+                    }
+                }
+                if let Some(handler) = &next_exceptional {
+                    analysis.transfer(region.blocks[i], &mut next, &mut |state| {
+                        analysis.join(state, handler);
+                    });
+                    analysis.join(&mut next, handler);
+                } else {
+                    analysis.transfer(region.blocks[i], &mut next, &mut |_| {});
+                }
+            }
+
+            input[i] = Some(state);
+            let changed = output[i].as_ref() != Some(&next)
+                || (forward && exceptional[i] != next_exceptional);
+            output[i] = Some(next);
+            exceptional[i] = next_exceptional;
+            if changed {
+                for &j in &outputs[i] {
+                    if !queued[j] {
+                        queued[j] = true;
+                        worklist.push_back(j);
+                    }
+                }
+            }
+        }
+
+        // Only backward analyses can have blocks left, which never reach an exit.
+        let Some(&i) = order.iter().find(|&&i| output[i].is_none()) else { break };
+        is_boundary[i] = true;
+        queued[i] = true;
+        worklist.push_back(i);
+    }
+
+    let (entry, exit) = if forward { (input, output) } else { (output, input) };
+    DataflowResults { index: region.index, entry, exit, exceptional }
+}
+
+/// Whether the analyses follow `edge`.
+///
+/// Edges into nested functions and unreachable code are skipped, as well as the implicit error
+/// edges connecting every block to the error harness of its function, which would otherwise make
+/// each block a possible exit of the function.
+pub fn is_dataflow_edge(edge: &EdgeType) -> bool {
+    !matches!(
+        edge,
+        EdgeType::NewFunction | EdgeType::Unreachable | EdgeType::Error(ErrorEdgeKind::Implicit)
+    )
+}
+
+/// Whether control can move along `edge` at any point of its source block, rather than only after
+/// its last instruction.
+fn is_exceptional_edge(edge: &EdgeType) -> bool {
+    matches!(edge, EdgeType::Error(ErrorEdgeKind::Explicit) | EdgeType::Finalize)
+}
+
+/// The blocks reachable from a start block, in reverse postorder, so the start block is first.
+struct Region {
+    blocks: Vec<BlockNodeId>,
+    index: FxHashMap<BlockNodeId, usize>,
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+    /// Successors of each block which are connected through an exceptional edge.
+    exceptional_successors: Vec<Vec<usize>>,
+}
+
+impl Region {
+    fn new(cfg: &ControlFlowGraph, start: BlockNodeId) -> Self {
+        let successors_of = |block: BlockNodeId| {
+            let mut successors = cfg
+                .graph
+                .edges_directed(block, GraphDirection::Outgoing)
+                .filter(|edge| is_dataflow_edge(edge.weight()))
+                .map(|edge| edge.target())
+                .collect::<Vec<_>>();
+            // Blocks can be connected by more than one edge, e.g. a normal and a finalize edge.
+            successors.sort_unstable();
+            successors.dedup();
+            successors
+        };
+
+        let mut blocks = postorder(start, successors_of);
+        blocks.reverse();
+        let index: FxHashMap<BlockNodeId, usize> =
+            blocks.iter().enumerate().map(|(i, &block)| (block, i)).collect();
+
+        let successors: Vec<Vec<usize>> = blocks
+            .iter()
+            .map(|&block| successors_of(block).iter().map(|target| index[target]).collect())
+            .collect();
+        let mut predecessors = vec![vec![]; blocks.len()];
+        for (i, targets) in successors.iter().enumerate() {
+            for &j in targets {
+                predecessors[j].push(i);
+            }
+        }
+        let exceptional_successors = blocks
+            .iter()
+            .map(|&block| {
+                let mut successors = cfg
+                    .graph
+                    .edges_directed(block, GraphDirection::Outgoing)
+                    .filter(|edge| is_exceptional_edge(edge.weight()))
+                    .map(|edge| index[&edge.target()])
+                    .collect::<Vec<_>>();
+                successors.sort_unstable();
+                successors.dedup();
+                successors
+            })
+            .collect();
+
+        Self { blocks, index, successors, predecessors, exceptional_successors }
+    }
+}
+
+/// The nodes reachable from `root` in depth-first postorder.
+fn postorder<N, F>(root: N, successors: F) -> Vec<N>
+where
+    N: Copy + Eq + Hash,
+    F: Fn(N) -> Vec<N>,
+{
+    let mut visited = FxHashSet::default();
+    let mut postorder = vec![];
+    visited.insert(root);
+    let mut stack = vec![(root, successors(root).into_iter())];
+    while let Some((node, successors_iter)) = stack.last_mut() {
+        let node = *node;
+        if let Some(next) = successors_iter.find(|&next| visited.insert(next)) {
+            stack.push((next, successors(next).into_iter()));
+        } else {
+            postorder.push(node);
+            stack.pop();
+        }
+    }
+    postorder
+}
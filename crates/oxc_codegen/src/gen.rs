@@ -1428,7 +1428,14 @@ impl GenExpr for ComputedMemberExpression<'_> {
             p.print_str("?.");
         }
         p.print_ascii_byte(b'[');
-        self.expression.print_expr(p, Precedence::Lowest, Context::empty());
+        // `obj["foo"]` is only printed as a property name if the property is mangled
+        if let Expression::StringLiteral(s) = &self.expression
+            && p.get_property_name(&s.value).is_some()
+        {
+            p.print_property_string_literal(s);
+        } else {
+            self.expression.print_expr(p, Precedence::Lowest, Context::empty());
+        }
         p.print_ascii_byte(b']');
     }
 }
@@ -1443,7 +1450,7 @@ impl GenExpr for StaticMemberExpression<'_> {
             p.print_hard_space();
         }
         p.print_ascii_byte(b'.');
-        self.property.print(p, ctx);
+        p.print_property_name(&self.property);
     }
 }
 
@@ -1660,7 +1667,7 @@ impl Gen for ObjectProperty<'_> {
             if key.name == "__proto__" {
                 shorthand = self.shorthand;
             } else if let Expression::Identifier(ident) = self.value.without_parentheses()
-                && key.name == p.get_identifier_reference_name(ident)
+                && p.is_property_name_equal(&key.name, p.get_identifier_reference_name(ident))
             {
                 shorthand = true;
             }
@@ -1696,9 +1703,9 @@ impl Gen for ObjectProperty<'_> {
 impl Gen for PropertyKey<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
-            Self::StaticIdentifier(ident) => ident.print(p, ctx),
+            Self::StaticIdentifier(ident) => p.print_property_name(ident),
             Self::PrivateIdentifier(ident) => ident.print(p, ctx),
-            Self::StringLiteral(s) => p.print_string_literal(s, /* allow_backtick */ false),
+            Self::StringLiteral(s) => p.print_property_string_literal(s),
             _ => self.to_expression().print_expr(p, Precedence::Comma, Context::empty()),
         }
     }
@@ -2026,11 +2033,12 @@ impl Gen for AssignmentTargetProperty<'_> {
 impl Gen for AssignmentTargetPropertyIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         let ident_name = p.get_identifier_reference_name(&self.binding);
-        if ident_name == self.binding.name.as_str() {
+        if p.is_property_name_equal(&self.binding.name, ident_name) {
             self.binding.print(p, ctx);
         } else {
             // `({x: a} = y);`
-            p.print_str(self.binding.name.as_str());
+            let key = p.get_property_name(&self.binding.name);
+            p.print_str(key.as_deref().unwrap_or(&self.binding.name));
             p.print_colon();
             p.print_soft_space();
             p.print_str(ident_name);
@@ -2054,7 +2062,9 @@ impl Gen for AssignmentTargetPropertyProperty<'_> {
             let value_name =
                 self.binding.identifier().map(|id| p.get_identifier_reference_name(id));
             match (key_name, value_name) {
-                (Some(key_name), Some(value_name)) => key_name == value_name,
+                (Some(key_name), Some(value_name)) => {
+                    p.is_property_name_equal(key_name, value_name)
+                }
                 _ => false,
             }
         } else {
@@ -2063,7 +2073,7 @@ impl Gen for AssignmentTargetPropertyProperty<'_> {
         if !omit_key {
             match &self.name {
                 PropertyKey::StaticIdentifier(ident) => {
-                    ident.print(p, ctx);
+                    p.print_property_name(ident);
                 }
                 PropertyKey::PrivateIdentifier(ident) => {
                     ident.print(p, ctx);
@@ -2072,7 +2082,7 @@ impl Gen for AssignmentTargetPropertyProperty<'_> {
                     if self.computed {
                         p.print_ascii_byte(b'[');
                     }
-                    p.print_string_literal(s, /* allow_backtick */ false);
+                    p.print_property_string_literal(s);
                     if self.computed {
                         p.print_ascii_byte(b']');
                     }
@@ -2872,13 +2882,16 @@ impl Gen for BindingProperty<'_> {
         if let PropertyKey::StaticIdentifier(key) = &self.key {
             match &self.value {
                 BindingPattern::BindingIdentifier(ident)
-                    if key.name == p.get_binding_identifier_name(ident) =>
+                    if p.is_property_name_equal(
+                        &key.name,
+                        p.get_binding_identifier_name(ident),
+                    ) =>
                 {
                     shorthand = true;
                 }
                 BindingPattern::AssignmentPattern(assignment_pattern) => {
                     if let BindingPattern::BindingIdentifier(ident) = &assignment_pattern.left
-                        && key.name == p.get_binding_identifier_name(ident)
+                        && p.is_property_name_equal(&key.name, p.get_binding_identifier_name(ident))
                     {
                         shorthand = true;
                     }
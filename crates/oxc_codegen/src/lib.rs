@@ -91,6 +91,9 @@ pub struct Codegen<'a> {
     /// Private member name mappings for mangling
     private_member_mappings: Option<IndexVec<ClassId, FxHashMap<String, CompactStr>>>,
 
+    /// Property name mappings for mangling
+    property_mappings: Option<FxHashMap<String, CompactStr>>,
+
     /// Output Code
     code: CodeBuffer,
 
@@ -159,6 +162,7 @@ impl<'a> Codegen<'a> {
             source_text: None,
             scoping: None,
             private_member_mappings: None,
+            property_mappings: None,
             code: CodeBuffer::default(),
             needs_semicolon: false,
             need_space_before_dot: 0,
@@ -219,9 +223,23 @@ impl<'a> Codegen<'a> {
         self
     }
 
+    /// Set property name mappings for mangling.
+    ///
+    /// This allows renaming of object and class properties like `obj.foo` -> `obj.a`.
+    /// The map is keyed by original property names.
+    #[must_use]
+    pub fn with_property_mappings(
+        mut self,
+        mappings: Option<FxHashMap<String, CompactStr>>,
+    ) -> Self {
+        self.property_mappings = mappings;
+        self
+    }
+
     /// Print a [`Program`] into a string of source code.
     ///
     /// A source map will be generated if [`CodegenOptions::source_map_path`] is set.
+    /// It is composed with [`CodegenOptions::input_source_map`] if provided.
     #[must_use]
     pub fn build(mut self, program: &Program<'a>) -> CodegenReturn {
         self.quote = if self.options.single_quote { Quote::Single } else { Quote::Double };
@@ -237,7 +255,13 @@ impl<'a> Codegen<'a> {
         let legal_comments = self.handle_eof_linked_or_external_comments(program);
         let code = self.code.into_string();
         #[cfg(feature = "sourcemap")]
-        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
+        let map = self.sourcemap_builder.map(|builder| {
+            let map = builder.into_sourcemap();
+            match &self.options.input_source_map {
+                Some(input_source_map) => SourcemapBuilder::remap(&map, input_source_map),
+                None => map,
+            }
+        });
         CodegenReturn {
             code,
             #[cfg(feature = "sourcemap")]
@@ -749,6 +773,42 @@ impl<'a> Codegen<'a> {
         ident.name.as_str()
     }
 
+    fn get_property_name(&self, name: &str) -> Option<CompactStr> {
+        self.property_mappings.as_ref()?.get(name).cloned()
+    }
+
+    /// Whether property `key` is printed as `name`, so `{ key: name }` can be printed as `{ name }`.
+    fn is_property_name_equal(&self, key: &str, name: &str) -> bool {
+        match self.get_property_name(key) {
+            Some(mangled) => mangled.as_str() == name,
+            None => key == name,
+        }
+    }
+
+    /// Print name of a property, which is mangled if there is a mapping for it.
+    fn print_property_name(&mut self, ident: &IdentifierName<'_>) {
+        if let Some(mangled) = self.get_property_name(&ident.name) {
+            self.print_space_before_identifier();
+            self.add_source_mapping_for_name(ident.span, &ident.name);
+            self.print_str(mangled.as_str());
+        } else {
+            ident.print(self, Context::empty());
+        }
+    }
+
+    /// Print a string literal which is used as a property name, e.g. `obj["foo"]`.
+    fn print_property_string_literal(&mut self, s: &StringLiteral<'_>) {
+        if let Some(mangled) = self.get_property_name(&s.value) {
+            self.add_source_mapping(s.span);
+            // Mangled names are identifiers, so never need escaping
+            self.quote.print(self);
+            self.print_str(mangled.as_str());
+            self.quote.print(self);
+        } else {
+            self.print_string_literal(s, /* allow_backtick */ false);
+        }
+    }
+
     fn print_space_before_operator(&mut self, next: Operator) {
         if self.prev_op_end != self.code.len() {
             return;
@@ -29,6 +29,19 @@ pub struct CodegenOptions {
     /// Default is `None` - no sourcemap is produced.
     pub source_map_path: Option<PathBuf>,
 
+    /// Input source map.
+    ///
+    /// When the source text handed to the parser was itself generated by another tool,
+    /// pass that tool's source map here. Every emitted mapping is remapped through it,
+    /// so the returned sourcemap points at the original sources (including their
+    /// `sourcesContent` and names).
+    ///
+    /// Has no effect unless [`CodegenOptions::source_map_path`] is set.
+    ///
+    /// Default is `None`.
+    #[cfg(feature = "sourcemap")]
+    pub input_source_map: Option<oxc_sourcemap::SourceMap>,
+
     /// Indentation character.
     ///
     /// Default is [`IndentChar::Tab`].
@@ -52,6 +65,8 @@ impl Default for CodegenOptions {
             minify: false,
             comments: CommentOptions::default(),
             source_map_path: None,
+            #[cfg(feature = "sourcemap")]
+            input_source_map: None,
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
             initial_indent: 0,
@@ -67,6 +82,8 @@ impl CodegenOptions {
             minify: true,
             comments: CommentOptions::disabled(),
             source_map_path: None,
+            #[cfg(feature = "sourcemap")]
+            input_source_map: None,
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
             initial_indent: 0,
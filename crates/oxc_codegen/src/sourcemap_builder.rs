@@ -1,6 +1,7 @@
-use std::path::Path;
+use std::{path::Path, sync::Arc};
 
 use oxc_index::{IndexVec, define_nonmax_u32_index_type};
+use oxc_sourcemap::{SourceMap, Token};
 use oxc_span::Span;
 use oxc_syntax::line_terminator::{LS, LS_LAST_2_BYTES, LS_OR_PS_FIRST_BYTE, PS, PS_LAST_2_BYTES};
 
@@ -117,6 +118,91 @@ impl<'a> SourcemapBuilder<'a> {
         self.last_position = Some(position);
     }
 
+    /// Compose `sourcemap` (generated code -> parsed source text) with `input_source_map`
+    /// (parsed source text -> original sources).
+    ///
+    /// Every token is looked up in `input_source_map` by its original position,
+    /// and rewritten to point at the original source which produced it.
+    /// Tokens without a corresponding mapping in `input_source_map` are dropped,
+    /// as they do not correspond to any original source.
+    ///
+    /// Name of the returned token is the name from `input_source_map` if it has one
+    /// (the name in the original source), otherwise the name recorded by codegen.
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn remap(sourcemap: &SourceMap, input_source_map: &SourceMap) -> SourceMap {
+        let lookup_table = input_source_map.generate_lookup_table();
+
+        let input_sources_len = input_source_map.get_sources().count();
+        // Maps source ID in `input_source_map` to source ID in the returned sourcemap.
+        // Only sources which are referenced by a remapped token are included.
+        let mut source_ids: Vec<Option<u32>> = vec![None; input_sources_len];
+        let mut sources: Vec<Arc<str>> = vec![];
+        let mut source_contents: Vec<Option<Arc<str>>> = vec![];
+
+        let mut name_ids = rustc_hash::FxHashMap::<Arc<str>, u32>::default();
+        let mut names: Vec<Arc<str>> = vec![];
+        let mut add_name = |name: &Arc<str>| -> u32 {
+            *name_ids.entry(Arc::clone(name)).or_insert_with(|| {
+                names.push(Arc::clone(name));
+                (names.len() - 1) as u32
+            })
+        };
+
+        let mut tokens = vec![];
+        for token in sourcemap.get_tokens() {
+            let Some(original) = input_source_map.lookup_token(
+                &lookup_table,
+                token.get_src_line(),
+                token.get_src_col(),
+            ) else {
+                continue;
+            };
+            let Some(input_source_id) = original.get_source_id() else { continue };
+            let Some(slot) = source_ids.get_mut(input_source_id as usize) else { continue };
+            let source_id = *slot.get_or_insert_with(|| {
+                sources.push(Arc::clone(input_source_map.get_source(input_source_id).unwrap()));
+                source_contents
+                    .push(input_source_map.get_source_content(input_source_id).map(Arc::clone));
+                (sources.len() - 1) as u32
+            });
+
+            let name = original
+                .get_name_id()
+                .and_then(|id| input_source_map.get_name(id))
+                .or_else(|| token.get_name_id().and_then(|id| sourcemap.get_name(id)));
+            let name_id = name.map(&mut add_name);
+
+            tokens.push(Token::new(
+                token.get_dst_line(),
+                token.get_dst_col(),
+                original.get_src_line(),
+                original.get_src_col(),
+                Some(source_id),
+                name_id,
+            ));
+        }
+
+        let mut remapped = SourceMap::new(
+            sourcemap.get_file().map(Arc::clone),
+            names,
+            input_source_map.get_source_root().map(ToString::to_string),
+            sources,
+            source_contents,
+            tokens.into_boxed_slice(),
+            None,
+        );
+        if let Some(ignore_list) = input_source_map.get_x_google_ignore_list() {
+            let ignore_list = ignore_list
+                .iter()
+                .filter_map(|&id| source_ids.get(id as usize).copied().flatten())
+                .collect::<Vec<_>>();
+            if !ignore_list.is_empty() {
+                remapped.set_x_google_ignore_list(ignore_list);
+            }
+        }
+        remapped
+    }
+
     #[expect(clippy::cast_possible_truncation)]
     fn search_original_line_and_column(&mut self, position: u32) -> (u32, u32) {
         let original_line = self.search_original_line(position);
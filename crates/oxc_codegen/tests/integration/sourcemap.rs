@@ -89,6 +89,98 @@ fn no_invalid_tokens_beyond_source() {
     }
 }
 
+/// Output sourcemap is composed with the input sourcemap, so tokens point at the original source.
+#[test]
+fn input_source_map() {
+    // First pass: `original.js` -> `intermediate.js`, as an upstream tool would do.
+    let original = "const   answer   =\n  42;\nconsole.log(answer)";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, original, SourceType::mjs()).parse();
+    assert!(ret.errors.is_empty());
+    let first = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some(PathBuf::from("original.js")),
+            ..Default::default()
+        })
+        .build(&ret.program);
+    let intermediate = first.code;
+
+    // Second pass: `intermediate.js` -> minified output, with the first sourcemap as input.
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &intermediate, SourceType::mjs()).parse();
+    assert!(ret.errors.is_empty());
+    let second = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some(PathBuf::from("intermediate.js")),
+            input_source_map: first.map,
+            ..CodegenOptions::minify()
+        })
+        .build(&ret.program);
+    let map = second.map.unwrap();
+
+    assert_eq!(map.get_sources().map(AsRef::as_ref).collect::<Vec<&str>>(), ["original.js"]);
+    assert_eq!(map.get_source_content(0).map(AsRef::as_ref), Some(original));
+
+    let original_lines = original.lines().collect::<Vec<_>>();
+    let output_lines = second.code.lines().collect::<Vec<_>>();
+    let mut identifiers = vec![];
+    for token in map.get_tokens() {
+        assert_eq!(token.get_source_id(), Some(0));
+        let output = &output_lines[token.get_dst_line() as usize][token.get_dst_col() as usize..];
+        let source = &original_lines[token.get_src_line() as usize][token.get_src_col() as usize..];
+        let word = |s: &str| {
+            s.split(|c: char| !c.is_ascii_alphanumeric()).next().unwrap_or_default().to_string()
+        };
+        if !word(output).is_empty() {
+            assert_eq!(word(output), word(source), "output `{output}` maps to `{source}`");
+            identifiers.push((word(output), token.get_src_line(), token.get_src_col()));
+        }
+    }
+    for expected in [("answer", 0, 8), ("42", 1, 2), ("console", 2, 0)] {
+        assert!(
+            identifiers.iter().any(|(word, line, col)| (word.as_str(), *line, *col) == expected),
+            "missing mapping for {expected:?} in {identifiers:?}"
+        );
+    }
+}
+
+/// Names recorded in the input sourcemap (e.g. from a renaming tool) are carried over.
+#[test]
+fn input_source_map_names() {
+    let original = "function longName() {}\nlongName();";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, original, SourceType::mjs()).parse();
+    let mut program = ret.program;
+    // Simulate an upstream tool renaming `longName` to `a`.
+    let first = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some(PathBuf::from("original.js")),
+            ..Default::default()
+        })
+        .with_scoping(Some({
+            let mut scoping =
+                oxc_semantic::SemanticBuilder::new().build(&program).semantic.into_scoping();
+            let symbol_id = scoping.symbol_ids().next().unwrap();
+            scoping.set_symbol_name(symbol_id, "a");
+            scoping
+        }))
+        .build(&program);
+    assert_eq!(first.code, "function a() {}\na();\n");
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &first.code, SourceType::mjs()).parse();
+    program = ret.program;
+    let second = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some(PathBuf::from("intermediate.js")),
+            input_source_map: first.map,
+            ..Default::default()
+        })
+        .build(&program);
+    let map = second.map.unwrap();
+    assert_eq!(map.get_names().map(AsRef::as_ref).collect::<Vec<&str>>(), ["longName"]);
+}
+
 #[test]
 #[cfg(all(not(target_endian = "big"), target_pointer_width = "64"))] // we run big endian tests on docker that does not have node installed; skip 32-bit as well
 fn stacktrace_is_correct() {
@@ -64,7 +64,9 @@ pub type Severity = miette::Severity;
 pub type Result<T> = std::result::Result<T, OxcDiagnostic>;
 
 use miette::{Diagnostic, SourceCode};
-pub use miette::{GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource};
+pub use miette::{GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource, SourceSpan};
+
+use crate::service::DiagnosticWithSource;
 
 /// Describes an error or warning that occurred.
 ///
@@ -123,6 +125,25 @@ pub struct OxcDiagnosticInner {
     pub severity: Severity,
     pub code: OxcCode,
     pub url: Option<Cow<'static, str>>,
+    /// Changes to the source code which resolve the problem.
+    ///
+    /// These are not rendered by [miette], but reporters may output them.
+    pub fixes: Vec<DiagnosticFix>,
+    /// The problem was suppressed, e.g. by a disable comment.
+    ///
+    /// [`DiagnosticService`] does not count suppressed diagnostics as warnings or errors.
+    pub suppressed: bool,
+}
+
+/// A change to the source code which resolves an [`OxcDiagnostic`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DiagnosticFix {
+    /// Description of the change.
+    pub message: Option<Cow<'static, str>>,
+    /// The replaced range of the source code, in bytes.
+    pub span: SourceSpan,
+    /// The text which replaces `span`.
+    pub content: String,
 }
 
 impl Display for OxcDiagnostic {
@@ -188,6 +209,8 @@ impl OxcDiagnostic {
                 severity: Severity::Error,
                 code: OxcCode::default(),
                 url: None,
+                fixes: Vec::new(),
+                suppressed: false,
             }),
         }
     }
@@ -203,6 +226,8 @@ impl OxcDiagnostic {
                 severity: Severity::Warning,
                 code: OxcCode::default(),
                 url: None,
+                fixes: Vec::new(),
+                suppressed: false,
             }),
         }
     }
@@ -362,6 +387,28 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Add changes to the source code which resolve this diagnostic.
+    pub fn with_fixes(mut self, fixes: Vec<DiagnosticFix>) -> Self {
+        self.inner.fixes = fixes;
+        self
+    }
+
+    /// Mark this diagnostic as suppressed, e.g. by a disable comment.
+    pub fn with_suppressed(mut self, suppressed: bool) -> Self {
+        self.inner.suppressed = suppressed;
+        self
+    }
+
+    /// Get the [`OxcDiagnostic`] an [`Error`] was created from.
+    ///
+    /// Returns `None` if `error` was not created from an [`OxcDiagnostic`], or if source code was
+    /// attached other than with [`DiagnosticService::wrap_diagnostics`].
+    pub fn from_error(error: &Error) -> Option<&Self> {
+        error
+            .downcast_ref::<Self>()
+            .or_else(|| error.downcast_ref::<DiagnosticWithSource>().map(|d| &d.diagnostic))
+    }
+
     /// Add source code to this diagnostic and convert it into an [`Error`].
     ///
     /// You should use a [`NamedSource`] if you have a file name as well as the source code.
@@ -1,11 +1,13 @@
 use std::{
     borrow::Cow,
+    fmt::{self, Display},
     io::{ErrorKind, Write},
     path::{Path, PathBuf},
     sync::{Arc, mpsc},
 };
 
 use cow_utils::CowUtils;
+use miette::{Diagnostic, LabeledSpan, SourceCode};
 use percent_encoding::AsciiSet;
 #[cfg(not(windows))]
 use std::fs::canonicalize as strict_canonicalize;
@@ -138,7 +140,9 @@ impl DiagnosticService {
         let source = Arc::new(NamedSource::new(path_display, source_text.to_owned()));
         diagnostics
             .into_iter()
-            .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
+            .map(|diagnostic| {
+                Error::new(DiagnosticWithSource { diagnostic, source_code: Arc::clone(&source) })
+            })
             .collect()
     }
 
@@ -161,16 +165,18 @@ impl DiagnosticService {
                 let severity = diagnostic.severity();
                 let is_warning = severity == Some(Severity::Warning);
                 let is_error = severity == Some(Severity::Error) || severity.is_none();
+                let is_suppressed =
+                    OxcDiagnostic::from_error(&diagnostic).is_some_and(|d| d.suppressed);
                 if is_warning || is_error {
-                    if is_warning {
+                    if is_warning && !is_suppressed {
                         warnings_count += 1;
                     }
-                    if is_error {
+                    if is_error && !is_suppressed {
                         errors_count += 1;
                     }
                     // The --quiet flag follows ESLint's --quiet behavior as documented here: https://eslint.org/docs/latest/use/command-line-interface#--quiet
                     // Note that it does not disable ALL diagnostics, only Warning diagnostics
-                    else if self.quiet {
+                    if is_warning && self.quiet {
                         continue;
                     }
                 }
@@ -259,6 +265,55 @@ const ASCII_SET: AsciiSet =
         // we do not want path separators to be percent-encoded
         .remove(b'/');
 
+/// An [`OxcDiagnostic`] with the source code it refers to.
+///
+/// Unlike [`OxcDiagnostic::with_source_code`], this keeps the [`OxcDiagnostic`] accessible through
+/// [`OxcDiagnostic::from_error`], so that reporters can read data which is not part of
+/// [`Diagnostic`], such as fixes.
+#[derive(Debug)]
+pub struct DiagnosticWithSource {
+    pub diagnostic: OxcDiagnostic,
+    source_code: Arc<NamedSource<String>>,
+}
+
+impl Display for DiagnosticWithSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.diagnostic.fmt(f)
+    }
+}
+
+impl std::error::Error for DiagnosticWithSource {}
+
+impl Diagnostic for DiagnosticWithSource {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn note<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.note()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.source_code)
+    }
+}
+
 fn from_file_path<A: AsRef<Path>>(path: A) -> Option<String> {
     let path = path.as_ref();
 
@@ -1,4 +1,5 @@
 use std::{
+    iter,
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -320,6 +321,19 @@ impl ConfigStore {
         self.base.base.config.plugins
     }
 
+    /// Whether a rule matching `predicate` is enabled in any configuration, including nested
+    /// configurations and overrides.
+    pub(crate) fn is_any_rule_enabled(&self, predicate: impl Fn(&RuleEnum) -> bool) -> bool {
+        iter::once(&self.base).chain(self.nested_configs.values()).any(|config| {
+            config
+                .base
+                .rules
+                .iter()
+                .chain(config.overrides.iter().flat_map(|o| &o.rules.builtin_rules))
+                .any(|(rule, severity)| severity.is_warn_deny() && predicate(rule))
+        })
+    }
+
     pub(crate) fn get_related_config(&self, path: &Path) -> &Config {
         if self.nested_configs.is_empty() {
             &self.base
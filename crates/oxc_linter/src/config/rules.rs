@@ -1,5 +1,6 @@
 use std::{borrow::Cow, fmt};
 
+use cow_utils::CowUtils;
 use itertools::Itertools;
 use rustc_hash::FxHashMap;
 use schemars::{JsonSchema, r#gen::SchemaGenerator, schema::Schema};
@@ -340,10 +341,20 @@ pub(super) fn unalias_plugin_name(plugin_name: &str, rule_name: &str) -> (String
         "react-hooks" => ("react", rule_name),
         // For backwards compatibility, deepscan rules reside in the oxc plugin.
         "deepscan" => ("oxc", rule_name),
+        // eslint-plugin-n is the maintained fork of eslint-plugin-node.
+        "n" => ("node", rule_name),
         _ => (plugin_name, rule_name),
     };
 
-    (oxlint_plugin_name.to_string(), rule_name.to_string())
+    // eslint-plugin-n nests some rules under a path, e.g. `no-unsupported-features/es-syntax`,
+    // which is `no-unsupported-features-es-syntax` here.
+    let rule_name = if oxlint_plugin_name == "node" {
+        rule_name.cow_replace('/', "-")
+    } else {
+        Cow::Borrowed(rule_name)
+    };
+
+    (oxlint_plugin_name.to_string(), rule_name.into_owned())
 }
 
 fn parse_rule_value(
@@ -637,6 +648,25 @@ mod test {
         assert!(r2.severity.is_warn_deny());
     }
 
+    #[test]
+    fn test_parse_rules_with_nested_node_rule_names() {
+        let rules = OxlintRules::deserialize(&json!({
+            "node/no-unsupported-features/es-syntax": "error",
+            "n/no-unsupported-features/node-builtins": "warn",
+        }))
+        .unwrap();
+
+        let mut rules_iter = rules.rules.iter();
+
+        let r1 = rules_iter.next().unwrap();
+        assert_eq!(r1.rule_name, "no-unsupported-features-es-syntax");
+        assert_eq!(r1.plugin_name, "node");
+
+        let r2 = rules_iter.next().unwrap();
+        assert_eq!(r2.rule_name, "no-unsupported-features-node-builtins");
+        assert_eq!(r2.plugin_name, "node");
+    }
+
     #[test]
     fn test_external_rule_options_are_recorded() {
         // Register a fake external plugin and rule
@@ -2,14 +2,15 @@ use std::{
     borrow::Cow,
     cell::{Cell, RefCell},
     ffi::OsStr,
-    path::Path,
+    path::{Path, PathBuf},
     rc::Rc,
     sync::Arc,
 };
 
 use oxc_diagnostics::{OxcDiagnostic, Severity};
-use oxc_semantic::Semantic;
+use oxc_semantic::{Semantic, SymbolId};
 use oxc_span::{SourceType, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     AllowWarnDeny, FrameworkFlags,
@@ -20,10 +21,15 @@ use crate::{
     module_record::ModuleRecord,
     options::LintOptions,
     rules::RuleEnum,
+    utils::{PackageJsonFile, read_package_json},
 };
 
 use super::{LintContext, plugin_name_to_prefix};
 
+/// Files read by rules while linting a file, with hashes of their contents, or `None` for files
+/// which do not exist. Sorted by path.
+pub type ReadFiles = Vec<(PathBuf, Option<u64>)>;
+
 /// Stores shared information about a script block being linted.
 pub struct ContextSubHost<'a> {
     /// Semantic information about the file being linted, which includes scopes, symbols and AST nodes.
@@ -38,6 +44,8 @@ pub struct ContextSubHost<'a> {
     pub(super) framework_options: FrameworkOptions,
     /// The source text offset of the sub host
     pub(super) source_text_offset: u32,
+    /// Root scope bindings used by the template of a Vue or Svelte file.
+    pub(super) template_symbols: FxHashSet<SymbolId>,
 }
 
 impl<'a> ContextSubHost<'a> {
@@ -78,9 +86,17 @@ impl<'a> ContextSubHost<'a> {
             source_text_offset,
             disable_directives,
             framework_options: frameworks_options,
+            template_symbols: FxHashSet::default(),
         }
     }
 
+    /// Set the root scope bindings used by the template of a Vue or Svelte file.
+    #[must_use]
+    pub fn with_template_symbols(mut self, template_symbols: FxHashSet<SymbolId>) -> Self {
+        self.template_symbols = template_symbols;
+        self
+    }
+
     /// Shared reference to the [`Semantic`] analysis
     #[inline]
     pub fn semantic(&self) -> &Semantic<'a> {
@@ -140,6 +156,9 @@ pub struct ContextHost<'a> {
     /// Set via the `--fix`, `--fix-suggestions`, and `--fix-dangerously` CLI
     /// flags.
     pub(super) fix: FixKind,
+    /// Whether to keep diagnostics which are suppressed by disable directives,
+    /// instead of discarding them.
+    pub(super) report_suppressed: bool,
     /// Path to the file being linted.
     pub(super) file_path: Box<Path>,
     /// Extension of the file being linted.
@@ -149,6 +168,9 @@ pub struct ContextHost<'a> {
     pub(super) config: Arc<LintConfig>,
     /// Front-end frameworks that might be in use in the target file.
     pub(super) frameworks: FrameworkFlags,
+    /// `package.json` files read by rules, or `None` for files which do not exist.
+    /// Lint results depend on them, so they are part of lint result cache entries.
+    read_files: RefCell<FxHashMap<PathBuf, Option<PackageJsonFile>>>,
 }
 
 impl std::fmt::Debug for ContextHost<'_> {
@@ -181,10 +203,12 @@ impl<'a> ContextHost<'a> {
             current_sub_host_index: Cell::new(0),
             diagnostics: RefCell::new(Vec::with_capacity(DIAGNOSTICS_INITIAL_CAPACITY)),
             fix: options.fix,
+            report_suppressed: options.report_suppressed,
             file_path,
             file_extension,
             config,
             frameworks: options.framework_hints,
+            read_files: RefCell::default(),
         }
         .sniff_for_frameworks()
     }
@@ -227,6 +251,11 @@ impl<'a> ContextHost<'a> {
         &self.current_sub_host().disable_directives
     }
 
+    /// Whether diagnostics suppressed by disable directives are reported, instead of discarded.
+    pub(crate) fn report_suppressed(&self) -> bool {
+        self.report_suppressed
+    }
+
     /// Path to the file being linted.
     ///
     /// When created from a [`LintService`](`crate::service::LintService`), this
@@ -386,6 +415,28 @@ impl<'a> ContextHost<'a> {
         std::mem::take(&mut *messages)
     }
 
+    /// Read the `package.json` at `path`, and record it as read for the file being linted.
+    pub(super) fn package_json(&self, path: &Path) -> Option<PackageJsonFile> {
+        if let Some(file) = self.read_files.borrow().get(path) {
+            return file.clone();
+        }
+        let file = read_package_json(path);
+        self.read_files.borrow_mut().insert(path.to_path_buf(), file.clone());
+        file
+    }
+
+    /// Take the files read by rules.
+    pub fn take_read_files(&self) -> ReadFiles {
+        let mut files = self
+            .read_files
+            .take()
+            .into_iter()
+            .map(|(path, file)| (path, file.map(|file| file.content_hash)))
+            .collect::<Vec<_>>();
+        files.sort_unstable();
+        files
+    }
+
     /// Take ownership of the disable directives from the first sub host.
     /// This consumes the `ContextHost`.
     ///
@@ -473,6 +524,11 @@ impl<'a> ContextHost<'a> {
         self.current_sub_host().framework_options
     }
 
+    /// Whether the symbol is used by the template of a Vue or Svelte file.
+    pub fn is_used_in_template(&self, symbol_id: SymbolId) -> bool {
+        self.current_sub_host().template_symbols.contains(&symbol_id)
+    }
+
     pub fn other_file_hosts(&self) -> Vec<&ContextSubHost<'a>> {
         self.sub_hosts
             .iter()
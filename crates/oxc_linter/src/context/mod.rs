@@ -1,14 +1,15 @@
 #![expect(rustdoc::private_intra_doc_links)] // useful for intellisense
 
-use std::{ffi::OsStr, ops::Deref, path::Path, rc::Rc};
+use std::{ffi::OsStr, ops::Deref, path::Path, rc::Rc, sync::Arc};
 
 use javascript_globals::GLOBALS;
 
 use oxc_ast::ast::IdentifierReference;
 use oxc_cfg::ControlFlowGraph;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
-use oxc_semantic::Semantic;
+use oxc_semantic::{Semantic, SymbolId};
 use oxc_span::Span;
+use serde_json::Value;
 
 #[cfg(debug_assertions)]
 use crate::rule::RuleFixMeta;
@@ -22,7 +23,7 @@ use crate::{
 };
 
 mod host;
-pub use host::{ContextHost, ContextSubHost};
+pub use host::{ContextHost, ContextSubHost, ReadFiles};
 
 /// Contains all of the state and context specific to this lint rule.
 ///
@@ -185,6 +186,32 @@ impl<'a> LintContext<'a> {
         self.parent.file_extension()
     }
 
+    /// Directory of the configuration file which applies to the file currently being linted.
+    #[inline]
+    pub fn config_dir(&self) -> Option<&Path> {
+        self.parent.config.path.as_deref().and_then(Path::parent)
+    }
+
+    /// Read the `package.json` at `path`. Returns `None` if it does not exist or is not valid JSON.
+    ///
+    /// Cached lint results of the file being linted are invalidated when the `package.json` changes.
+    pub fn package_json(&self, path: &Path) -> Option<Arc<Value>> {
+        self.parent.package_json(path)?.json
+    }
+
+    /// Read the `package.json` closest to the file being linted. Returns `None` if there is none,
+    /// or it is not valid JSON.
+    ///
+    /// Cached lint results of the file being linted are invalidated when the `package.json` changes,
+    /// or another one is added closer to the file.
+    pub fn closest_package_json(&self) -> Option<Arc<Value>> {
+        self.file_path()
+            .ancestors()
+            .skip(1)
+            .find_map(|dir| self.parent.package_json(&dir.join("package.json")))?
+            .json
+    }
+
     /// Plugin settings
     #[inline]
     pub fn settings(&self) -> &OxlintSettings {
@@ -268,7 +295,12 @@ impl<'a> LintContext<'a> {
     /// name, severity, and a link to the rule's documentation URL.
     fn add_diagnostic(&self, mut message: Message) {
         if self.parent.disable_directives().contains(self.current_rule_name, message.span) {
-            return;
+            if !self.parent.report_suppressed {
+                return;
+            }
+            // Suppressed diagnostics are only reported, their fixes are never applied.
+            message.error = message.error.with_suppressed(true);
+            message.fixes = PossibleFixes::None;
         }
         message.error = message
             .error
@@ -545,6 +577,12 @@ impl<'a> LintContext<'a> {
     pub fn other_file_hosts(&self) -> Vec<&ContextSubHost<'a>> {
         self.parent.other_file_hosts()
     }
+
+    /// Whether the symbol is used by the template of a Vue or Svelte file. Template usages are
+    /// not part of [`Semantic`], as there are no AST nodes for them.
+    pub fn is_used_in_template(&self, symbol_id: SymbolId) -> bool {
+        self.parent.is_used_in_template(symbol_id)
+    }
 }
 
 /// Gets the prefixed plugin name, given the short plugin name.
@@ -555,7 +593,7 @@ impl<'a> LintContext<'a> {
 /// assert_eq!(plugin_name_to_prefix("react"), "eslint-plugin-react");
 /// ```
 #[inline]
-fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
+pub fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
     match plugin_name {
         "import" => "eslint-plugin-import",
         "jest" => "eslint-plugin-jest",
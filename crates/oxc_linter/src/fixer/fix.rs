@@ -6,6 +6,7 @@ use std::{
 
 use bitflags::bitflags;
 
+use oxc_diagnostics::{DiagnosticFix, SourceSpan};
 use oxc_span::{GetSpan, SPAN, Span};
 
 bitflags! {
@@ -327,6 +328,16 @@ impl Default for Fix {
     }
 }
 
+impl From<Fix> for DiagnosticFix {
+    fn from(fix: Fix) -> Self {
+        Self {
+            message: fix.message,
+            span: SourceSpan::from((fix.span.start as usize, fix.span.size() as usize)),
+            content: fix.content.into_owned(),
+        }
+    }
+}
+
 impl Fix {
     pub const fn delete(span: Span) -> Self {
         Self { content: Cow::Borrowed(""), message: None, span, kind: FixKind::None }
@@ -285,6 +285,16 @@ impl Message {
 
         self
     }
+
+    /// Convert into an [`OxcDiagnostic`] with the fixes attached, for reporters which output them.
+    pub fn into_diagnostic_with_fixes(self) -> OxcDiagnostic {
+        let fixes = match self.fixes {
+            PossibleFixes::None => return self.error,
+            PossibleFixes::Single(fix) => vec![fix.into()],
+            PossibleFixes::Multiple(fixes) => fixes.into_iter().map(Into::into).collect(),
+        };
+        self.error.with_fixes(fixes)
+    }
 }
 
 impl From<Message> for OxcDiagnostic {
@@ -353,6 +363,7 @@ impl<'a> Fixer<'a> {
         let mut fixed = false;
         let mut output = String::with_capacity(source_text.len());
         let mut last_pos: u32 = 0;
+        let mut last_fix: Option<(Span, Cow<'static, str>)> = None;
 
         // only keep messages that were not fixed
         let mut filtered_messages = Vec::with_capacity(self.messages.len());
@@ -377,7 +388,16 @@ impl<'a> Fixer<'a> {
                 continue;
             }
             if start < last_pos {
-                filtered_messages.push(m);
+                // Several messages can share the same fix, e.g. a fix which sorts a list,
+                // reported for each element out of order. It is applied once, and fixes all of them.
+                let is_applied = last_fix.as_ref().is_some_and(|(last_span, last_content)| {
+                    last_span == span && last_content == content
+                });
+                if is_applied {
+                    m.fixed = true;
+                } else {
+                    filtered_messages.push(m);
+                }
                 continue;
             }
 
@@ -387,6 +407,7 @@ impl<'a> Fixer<'a> {
             output.push_str(&source_text[offset..start as usize]);
             output.push_str(content);
             last_pos = end;
+            last_fix = Some((*span, content.clone()));
         }
 
         output.push_str(&source_text[last_pos as usize..]);
@@ -732,6 +753,17 @@ mod test {
         assert_eq!(result1.fixed_code, result2.fixed_code);
     }
 
+    #[test]
+    fn apply_same_fix_of_multiple_messages_once() {
+        let result = get_fix_result(vec![
+            create_message(replace_id(), PossibleFixes::Single(REPLACE_ID)),
+            create_message(replace_id(), PossibleFixes::Single(REPLACE_ID)),
+        ]);
+        assert_eq!(result.fixed_code, TEST_CODE.cow_replace("answer", "foo"));
+        assert_eq!(result.messages.len(), 0);
+        assert!(result.fixed);
+    }
+
     #[test]
     fn should_not_apply_fix_with_one_no_fix() {
         let result =
@@ -110,6 +110,7 @@ pub use crate::rules::eslint::no_redeclare::NoRedeclare as EslintNoRedeclare;
 pub use crate::rules::eslint::no_regex_spaces::NoRegexSpaces as EslintNoRegexSpaces;
 pub use crate::rules::eslint::no_restricted_globals::NoRestrictedGlobals as EslintNoRestrictedGlobals;
 pub use crate::rules::eslint::no_restricted_imports::NoRestrictedImports as EslintNoRestrictedImports;
+pub use crate::rules::eslint::no_restricted_syntax::NoRestrictedSyntax as EslintNoRestrictedSyntax;
 pub use crate::rules::eslint::no_return_assign::NoReturnAssign as EslintNoReturnAssign;
 pub use crate::rules::eslint::no_script_url::NoScriptUrl as EslintNoScriptUrl;
 pub use crate::rules::eslint::no_self_assign::NoSelfAssign as EslintNoSelfAssign;
@@ -128,6 +129,7 @@ pub use crate::rules::eslint::no_undefined::NoUndefined as EslintNoUndefined;
 pub use crate::rules::eslint::no_unexpected_multiline::NoUnexpectedMultiline as EslintNoUnexpectedMultiline;
 pub use crate::rules::eslint::no_unneeded_ternary::NoUnneededTernary as EslintNoUnneededTernary;
 pub use crate::rules::eslint::no_unreachable::NoUnreachable as EslintNoUnreachable;
+pub use crate::rules::eslint::no_unreachable_loop::NoUnreachableLoop as EslintNoUnreachableLoop;
 pub use crate::rules::eslint::no_unsafe_finally::NoUnsafeFinally as EslintNoUnsafeFinally;
 pub use crate::rules::eslint::no_unsafe_negation::NoUnsafeNegation as EslintNoUnsafeNegation;
 pub use crate::rules::eslint::no_unsafe_optional_chaining::NoUnsafeOptionalChaining as EslintNoUnsafeOptionalChaining;
@@ -135,6 +137,7 @@ pub use crate::rules::eslint::no_unused_expressions::NoUnusedExpressions as Esli
 pub use crate::rules::eslint::no_unused_labels::NoUnusedLabels as EslintNoUnusedLabels;
 pub use crate::rules::eslint::no_unused_private_class_members::NoUnusedPrivateClassMembers as EslintNoUnusedPrivateClassMembers;
 pub use crate::rules::eslint::no_unused_vars::NoUnusedVars as EslintNoUnusedVars;
+pub use crate::rules::eslint::no_useless_assignment::NoUselessAssignment as EslintNoUselessAssignment;
 pub use crate::rules::eslint::no_useless_backreference::NoUselessBackreference as EslintNoUselessBackreference;
 pub use crate::rules::eslint::no_useless_call::NoUselessCall as EslintNoUselessCall;
 pub use crate::rules::eslint::no_useless_catch::NoUselessCatch as EslintNoUselessCatch;
@@ -191,6 +194,7 @@ pub use crate::rules::import::no_default_export::NoDefaultExport as ImportNoDefa
 pub use crate::rules::import::no_duplicates::NoDuplicates as ImportNoDuplicates;
 pub use crate::rules::import::no_dynamic_require::NoDynamicRequire as ImportNoDynamicRequire;
 pub use crate::rules::import::no_empty_named_blocks::NoEmptyNamedBlocks as ImportNoEmptyNamedBlocks;
+pub use crate::rules::import::no_extraneous_dependencies::NoExtraneousDependencies as ImportNoExtraneousDependencies;
 pub use crate::rules::import::no_mutable_exports::NoMutableExports as ImportNoMutableExports;
 pub use crate::rules::import::no_named_as_default::NoNamedAsDefault as ImportNoNamedAsDefault;
 pub use crate::rules::import::no_named_as_default_member::NoNamedAsDefaultMember as ImportNoNamedAsDefaultMember;
@@ -201,7 +205,10 @@ pub use crate::rules::import::no_nodejs_modules::NoNodejsModules as ImportNoNode
 pub use crate::rules::import::no_relative_parent_imports::NoRelativeParentImports as ImportNoRelativeParentImports;
 pub use crate::rules::import::no_self_import::NoSelfImport as ImportNoSelfImport;
 pub use crate::rules::import::no_unassigned_import::NoUnassignedImport as ImportNoUnassignedImport;
+pub use crate::rules::import::no_unresolved::NoUnresolved as ImportNoUnresolved;
+pub use crate::rules::import::no_unused_modules::NoUnusedModules as ImportNoUnusedModules;
 pub use crate::rules::import::no_webpack_loader_syntax::NoWebpackLoaderSyntax as ImportNoWebpackLoaderSyntax;
+pub use crate::rules::import::order::Order as ImportOrder;
 pub use crate::rules::import::prefer_default_export::PreferDefaultExport as ImportPreferDefaultExport;
 pub use crate::rules::import::unambiguous::Unambiguous as ImportUnambiguous;
 pub use crate::rules::jest::consistent_test_it::ConsistentTestIt as JestConsistentTestIt;
@@ -330,6 +337,9 @@ pub use crate::rules::node::global_require::GlobalRequire as NodeGlobalRequire;
 pub use crate::rules::node::no_exports_assign::NoExportsAssign as NodeNoExportsAssign;
 pub use crate::rules::node::no_new_require::NoNewRequire as NodeNoNewRequire;
 pub use crate::rules::node::no_process_env::NoProcessEnv as NodeNoProcessEnv;
+pub use crate::rules::node::no_unsupported_features_es_builtins::NoUnsupportedFeaturesEsBuiltins as NodeNoUnsupportedFeaturesEsBuiltins;
+pub use crate::rules::node::no_unsupported_features_es_syntax::NoUnsupportedFeaturesEsSyntax as NodeNoUnsupportedFeaturesEsSyntax;
+pub use crate::rules::node::no_unsupported_features_node_builtins::NoUnsupportedFeaturesNodeBuiltins as NodeNoUnsupportedFeaturesNodeBuiltins;
 pub use crate::rules::oxc::approx_constant::ApproxConstant as OxcApproxConstant;
 pub use crate::rules::oxc::bad_array_method_on_arguments::BadArrayMethodOnArguments as OxcBadArrayMethodOnArguments;
 pub use crate::rules::oxc::bad_bitwise_operator::BadBitwiseOperator as OxcBadBitwiseOperator;
@@ -439,6 +449,7 @@ pub use crate::rules::typescript::consistent_type_definitions::ConsistentTypeDef
 pub use crate::rules::typescript::consistent_type_imports::ConsistentTypeImports as TypescriptConsistentTypeImports;
 pub use crate::rules::typescript::explicit_function_return_type::ExplicitFunctionReturnType as TypescriptExplicitFunctionReturnType;
 pub use crate::rules::typescript::explicit_module_boundary_types::ExplicitModuleBoundaryTypes as TypescriptExplicitModuleBoundaryTypes;
+pub use crate::rules::typescript::naming_convention::NamingConvention as TypescriptNamingConvention;
 pub use crate::rules::typescript::no_array_delete::NoArrayDelete as TypescriptNoArrayDelete;
 pub use crate::rules::typescript::no_base_to_string::NoBaseToString as TypescriptNoBaseToString;
 pub use crate::rules::typescript::no_confusing_non_null_assertion::NoConfusingNonNullAssertion as TypescriptNoConfusingNonNullAssertion;
@@ -697,6 +708,7 @@ pub enum RuleEnum {
     ImportNoDuplicates(ImportNoDuplicates),
     ImportNoDynamicRequire(ImportNoDynamicRequire),
     ImportNoEmptyNamedBlocks(ImportNoEmptyNamedBlocks),
+    ImportNoExtraneousDependencies(ImportNoExtraneousDependencies),
     ImportNoMutableExports(ImportNoMutableExports),
     ImportNoNamedAsDefault(ImportNoNamedAsDefault),
     ImportNoNamedAsDefaultMember(ImportNoNamedAsDefaultMember),
@@ -707,7 +719,10 @@ pub enum RuleEnum {
     ImportNoRelativeParentImports(ImportNoRelativeParentImports),
     ImportNoSelfImport(ImportNoSelfImport),
     ImportNoUnassignedImport(ImportNoUnassignedImport),
+    ImportNoUnresolved(ImportNoUnresolved),
+    ImportNoUnusedModules(ImportNoUnusedModules),
     ImportNoWebpackLoaderSyntax(ImportNoWebpackLoaderSyntax),
+    ImportOrder(ImportOrder),
     ImportPreferDefaultExport(ImportPreferDefaultExport),
     ImportUnambiguous(ImportUnambiguous),
     EslintAccessorPairs(EslintAccessorPairs),
@@ -815,6 +830,7 @@ pub enum RuleEnum {
     EslintNoRegexSpaces(EslintNoRegexSpaces),
     EslintNoRestrictedGlobals(EslintNoRestrictedGlobals),
     EslintNoRestrictedImports(EslintNoRestrictedImports),
+    EslintNoRestrictedSyntax(EslintNoRestrictedSyntax),
     EslintNoReturnAssign(EslintNoReturnAssign),
     EslintNoScriptUrl(EslintNoScriptUrl),
     EslintNoSelfAssign(EslintNoSelfAssign),
@@ -833,6 +849,7 @@ pub enum RuleEnum {
     EslintNoUnexpectedMultiline(EslintNoUnexpectedMultiline),
     EslintNoUnneededTernary(EslintNoUnneededTernary),
     EslintNoUnreachable(EslintNoUnreachable),
+    EslintNoUnreachableLoop(EslintNoUnreachableLoop),
     EslintNoUnsafeFinally(EslintNoUnsafeFinally),
     EslintNoUnsafeNegation(EslintNoUnsafeNegation),
     EslintNoUnsafeOptionalChaining(EslintNoUnsafeOptionalChaining),
@@ -840,6 +857,7 @@ pub enum RuleEnum {
     EslintNoUnusedLabels(EslintNoUnusedLabels),
     EslintNoUnusedPrivateClassMembers(EslintNoUnusedPrivateClassMembers),
     EslintNoUnusedVars(EslintNoUnusedVars),
+    EslintNoUselessAssignment(EslintNoUselessAssignment),
     EslintNoUselessBackreference(EslintNoUselessBackreference),
     EslintNoUselessCall(EslintNoUselessCall),
     EslintNoUselessCatch(EslintNoUselessCatch),
@@ -889,6 +907,7 @@ pub enum RuleEnum {
     TypescriptConsistentTypeImports(TypescriptConsistentTypeImports),
     TypescriptExplicitFunctionReturnType(TypescriptExplicitFunctionReturnType),
     TypescriptExplicitModuleBoundaryTypes(TypescriptExplicitModuleBoundaryTypes),
+    TypescriptNamingConvention(TypescriptNamingConvention),
     TypescriptNoArrayDelete(TypescriptNoArrayDelete),
     TypescriptNoBaseToString(TypescriptNoBaseToString),
     TypescriptNoConfusingNonNullAssertion(TypescriptNoConfusingNonNullAssertion),
@@ -1329,6 +1348,9 @@ pub enum RuleEnum {
     NodeNoExportsAssign(NodeNoExportsAssign),
     NodeNoNewRequire(NodeNoNewRequire),
     NodeNoProcessEnv(NodeNoProcessEnv),
+    NodeNoUnsupportedFeaturesEsBuiltins(NodeNoUnsupportedFeaturesEsBuiltins),
+    NodeNoUnsupportedFeaturesEsSyntax(NodeNoUnsupportedFeaturesEsSyntax),
+    NodeNoUnsupportedFeaturesNodeBuiltins(NodeNoUnsupportedFeaturesNodeBuiltins),
     VueDefineEmitsDeclaration(VueDefineEmitsDeclaration),
     VueDefinePropsDeclaration(VueDefinePropsDeclaration),
     VueDefinePropsDestructuring(VueDefinePropsDestructuring),
@@ -1369,651 +1391,662 @@ impl RuleEnum {
             Self::ImportNoDuplicates(_) => 16usize,
             Self::ImportNoDynamicRequire(_) => 17usize,
             Self::ImportNoEmptyNamedBlocks(_) => 18usize,
-            Self::ImportNoMutableExports(_) => 19usize,
-            Self::ImportNoNamedAsDefault(_) => 20usize,
-            Self::ImportNoNamedAsDefaultMember(_) => 21usize,
-            Self::ImportNoNamedDefault(_) => 22usize,
-            Self::ImportNoNamedExport(_) => 23usize,
-            Self::ImportNoNamespace(_) => 24usize,
-            Self::ImportNoNodejsModules(_) => 25usize,
-            Self::ImportNoRelativeParentImports(_) => 26usize,
-            Self::ImportNoSelfImport(_) => 27usize,
-            Self::ImportNoUnassignedImport(_) => 28usize,
-            Self::ImportNoWebpackLoaderSyntax(_) => 29usize,
-            Self::ImportPreferDefaultExport(_) => 30usize,
-            Self::ImportUnambiguous(_) => 31usize,
-            Self::EslintAccessorPairs(_) => 32usize,
-            Self::EslintArrayCallbackReturn(_) => 33usize,
-            Self::EslintArrowBodyStyle(_) => 34usize,
-            Self::EslintBlockScopedVar(_) => 35usize,
-            Self::EslintCapitalizedComments(_) => 36usize,
-            Self::EslintClassMethodsUseThis(_) => 37usize,
-            Self::EslintComplexity(_) => 38usize,
-            Self::EslintConstructorSuper(_) => 39usize,
-            Self::EslintCurly(_) => 40usize,
-            Self::EslintDefaultCase(_) => 41usize,
-            Self::EslintDefaultCaseLast(_) => 42usize,
-            Self::EslintDefaultParamLast(_) => 43usize,
-            Self::EslintEqeqeq(_) => 44usize,
-            Self::EslintForDirection(_) => 45usize,
-            Self::EslintFuncNames(_) => 46usize,
-            Self::EslintFuncStyle(_) => 47usize,
-            Self::EslintGetterReturn(_) => 48usize,
-            Self::EslintGroupedAccessorPairs(_) => 49usize,
-            Self::EslintGuardForIn(_) => 50usize,
-            Self::EslintIdLength(_) => 51usize,
-            Self::EslintInitDeclarations(_) => 52usize,
-            Self::EslintMaxClassesPerFile(_) => 53usize,
-            Self::EslintMaxDepth(_) => 54usize,
-            Self::EslintMaxLines(_) => 55usize,
-            Self::EslintMaxLinesPerFunction(_) => 56usize,
-            Self::EslintMaxNestedCallbacks(_) => 57usize,
-            Self::EslintMaxParams(_) => 58usize,
-            Self::EslintMaxStatements(_) => 59usize,
-            Self::EslintNewCap(_) => 60usize,
-            Self::EslintNoAlert(_) => 61usize,
-            Self::EslintNoArrayConstructor(_) => 62usize,
-            Self::EslintNoAsyncPromiseExecutor(_) => 63usize,
-            Self::EslintNoAwaitInLoop(_) => 64usize,
-            Self::EslintNoBitwise(_) => 65usize,
-            Self::EslintNoCaller(_) => 66usize,
-            Self::EslintNoCaseDeclarations(_) => 67usize,
-            Self::EslintNoClassAssign(_) => 68usize,
-            Self::EslintNoCompareNegZero(_) => 69usize,
-            Self::EslintNoCondAssign(_) => 70usize,
-            Self::EslintNoConsole(_) => 71usize,
-            Self::EslintNoConstAssign(_) => 72usize,
-            Self::EslintNoConstantBinaryExpression(_) => 73usize,
-            Self::EslintNoConstantCondition(_) => 74usize,
-            Self::EslintNoConstructorReturn(_) => 75usize,
-            Self::EslintNoContinue(_) => 76usize,
-            Self::EslintNoControlRegex(_) => 77usize,
-            Self::EslintNoDebugger(_) => 78usize,
-            Self::EslintNoDeleteVar(_) => 79usize,
-            Self::EslintNoDivRegex(_) => 80usize,
-            Self::EslintNoDupeClassMembers(_) => 81usize,
-            Self::EslintNoDupeElseIf(_) => 82usize,
-            Self::EslintNoDupeKeys(_) => 83usize,
-            Self::EslintNoDuplicateCase(_) => 84usize,
-            Self::EslintNoDuplicateImports(_) => 85usize,
-            Self::EslintNoElseReturn(_) => 86usize,
-            Self::EslintNoEmpty(_) => 87usize,
-            Self::EslintNoEmptyCharacterClass(_) => 88usize,
-            Self::EslintNoEmptyFunction(_) => 89usize,
-            Self::EslintNoEmptyPattern(_) => 90usize,
-            Self::EslintNoEmptyStaticBlock(_) => 91usize,
-            Self::EslintNoEqNull(_) => 92usize,
-            Self::EslintNoEval(_) => 93usize,
-            Self::EslintNoExAssign(_) => 94usize,
-            Self::EslintNoExtendNative(_) => 95usize,
-            Self::EslintNoExtraBind(_) => 96usize,
-            Self::EslintNoExtraBooleanCast(_) => 97usize,
-            Self::EslintNoExtraLabel(_) => 98usize,
-            Self::EslintNoFallthrough(_) => 99usize,
-            Self::EslintNoFuncAssign(_) => 100usize,
-            Self::EslintNoGlobalAssign(_) => 101usize,
-            Self::EslintNoImplicitCoercion(_) => 102usize,
-            Self::EslintNoImportAssign(_) => 103usize,
-            Self::EslintNoInlineComments(_) => 104usize,
-            Self::EslintNoInnerDeclarations(_) => 105usize,
-            Self::EslintNoInvalidRegexp(_) => 106usize,
-            Self::EslintNoIrregularWhitespace(_) => 107usize,
-            Self::EslintNoIterator(_) => 108usize,
-            Self::EslintNoLabelVar(_) => 109usize,
-            Self::EslintNoLabels(_) => 110usize,
-            Self::EslintNoLoneBlocks(_) => 111usize,
-            Self::EslintNoLonelyIf(_) => 112usize,
-            Self::EslintNoLoopFunc(_) => 113usize,
-            Self::EslintNoLossOfPrecision(_) => 114usize,
-            Self::EslintNoMagicNumbers(_) => 115usize,
-            Self::EslintNoMisleadingCharacterClass(_) => 116usize,
-            Self::EslintNoMultiAssign(_) => 117usize,
-            Self::EslintNoMultiStr(_) => 118usize,
-            Self::EslintNoNegatedCondition(_) => 119usize,
-            Self::EslintNoNestedTernary(_) => 120usize,
-            Self::EslintNoNew(_) => 121usize,
-            Self::EslintNoNewFunc(_) => 122usize,
-            Self::EslintNoNewNativeNonconstructor(_) => 123usize,
-            Self::EslintNoNewWrappers(_) => 124usize,
-            Self::EslintNoNonoctalDecimalEscape(_) => 125usize,
-            Self::EslintNoObjCalls(_) => 126usize,
-            Self::EslintNoObjectConstructor(_) => 127usize,
-            Self::EslintNoParamReassign(_) => 128usize,
-            Self::EslintNoPlusplus(_) => 129usize,
-            Self::EslintNoPromiseExecutorReturn(_) => 130usize,
-            Self::EslintNoProto(_) => 131usize,
-            Self::EslintNoPrototypeBuiltins(_) => 132usize,
-            Self::EslintNoRedeclare(_) => 133usize,
-            Self::EslintNoRegexSpaces(_) => 134usize,
-            Self::EslintNoRestrictedGlobals(_) => 135usize,
-            Self::EslintNoRestrictedImports(_) => 136usize,
-            Self::EslintNoReturnAssign(_) => 137usize,
-            Self::EslintNoScriptUrl(_) => 138usize,
-            Self::EslintNoSelfAssign(_) => 139usize,
-            Self::EslintNoSelfCompare(_) => 140usize,
-            Self::EslintNoSequences(_) => 141usize,
-            Self::EslintNoSetterReturn(_) => 142usize,
-            Self::EslintNoShadowRestrictedNames(_) => 143usize,
-            Self::EslintNoSparseArrays(_) => 144usize,
-            Self::EslintNoTemplateCurlyInString(_) => 145usize,
-            Self::EslintNoTernary(_) => 146usize,
-            Self::EslintNoThisBeforeSuper(_) => 147usize,
-            Self::EslintNoThrowLiteral(_) => 148usize,
-            Self::EslintNoUnassignedVars(_) => 149usize,
-            Self::EslintNoUndef(_) => 150usize,
-            Self::EslintNoUndefined(_) => 151usize,
-            Self::EslintNoUnexpectedMultiline(_) => 152usize,
-            Self::EslintNoUnneededTernary(_) => 153usize,
-            Self::EslintNoUnreachable(_) => 154usize,
-            Self::EslintNoUnsafeFinally(_) => 155usize,
-            Self::EslintNoUnsafeNegation(_) => 156usize,
-            Self::EslintNoUnsafeOptionalChaining(_) => 157usize,
-            Self::EslintNoUnusedExpressions(_) => 158usize,
-            Self::EslintNoUnusedLabels(_) => 159usize,
-            Self::EslintNoUnusedPrivateClassMembers(_) => 160usize,
-            Self::EslintNoUnusedVars(_) => 161usize,
-            Self::EslintNoUselessBackreference(_) => 162usize,
-            Self::EslintNoUselessCall(_) => 163usize,
-            Self::EslintNoUselessCatch(_) => 164usize,
-            Self::EslintNoUselessComputedKey(_) => 165usize,
-            Self::EslintNoUselessConcat(_) => 166usize,
-            Self::EslintNoUselessConstructor(_) => 167usize,
-            Self::EslintNoUselessEscape(_) => 168usize,
-            Self::EslintNoUselessRename(_) => 169usize,
-            Self::EslintNoUselessReturn(_) => 170usize,
-            Self::EslintNoVar(_) => 171usize,
-            Self::EslintNoVoid(_) => 172usize,
-            Self::EslintNoWarningComments(_) => 173usize,
-            Self::EslintNoWith(_) => 174usize,
-            Self::EslintOperatorAssignment(_) => 175usize,
-            Self::EslintPreferConst(_) => 176usize,
-            Self::EslintPreferDestructuring(_) => 177usize,
-            Self::EslintPreferExponentiationOperator(_) => 178usize,
-            Self::EslintPreferNumericLiterals(_) => 179usize,
-            Self::EslintPreferObjectHasOwn(_) => 180usize,
-            Self::EslintPreferObjectSpread(_) => 181usize,
-            Self::EslintPreferPromiseRejectErrors(_) => 182usize,
-            Self::EslintPreferRestParams(_) => 183usize,
-            Self::EslintPreferSpread(_) => 184usize,
-            Self::EslintPreferTemplate(_) => 185usize,
-            Self::EslintPreserveCaughtError(_) => 186usize,
-            Self::EslintRadix(_) => 187usize,
-            Self::EslintRequireAwait(_) => 188usize,
-            Self::EslintRequireYield(_) => 189usize,
-            Self::EslintSortImports(_) => 190usize,
-            Self::EslintSortKeys(_) => 191usize,
-            Self::EslintSortVars(_) => 192usize,
-            Self::EslintSymbolDescription(_) => 193usize,
-            Self::EslintUnicodeBom(_) => 194usize,
-            Self::EslintUseIsnan(_) => 195usize,
-            Self::EslintValidTypeof(_) => 196usize,
-            Self::EslintVarsOnTop(_) => 197usize,
-            Self::EslintYoda(_) => 198usize,
-            Self::TypescriptAdjacentOverloadSignatures(_) => 199usize,
-            Self::TypescriptArrayType(_) => 200usize,
-            Self::TypescriptAwaitThenable(_) => 201usize,
-            Self::TypescriptBanTsComment(_) => 202usize,
-            Self::TypescriptBanTslintComment(_) => 203usize,
-            Self::TypescriptBanTypes(_) => 204usize,
-            Self::TypescriptConsistentGenericConstructors(_) => 205usize,
-            Self::TypescriptConsistentIndexedObjectStyle(_) => 206usize,
-            Self::TypescriptConsistentTypeDefinitions(_) => 207usize,
-            Self::TypescriptConsistentTypeImports(_) => 208usize,
-            Self::TypescriptExplicitFunctionReturnType(_) => 209usize,
-            Self::TypescriptExplicitModuleBoundaryTypes(_) => 210usize,
-            Self::TypescriptNoArrayDelete(_) => 211usize,
-            Self::TypescriptNoBaseToString(_) => 212usize,
-            Self::TypescriptNoConfusingNonNullAssertion(_) => 213usize,
-            Self::TypescriptNoConfusingVoidExpression(_) => 214usize,
-            Self::TypescriptNoDeprecated(_) => 215usize,
-            Self::TypescriptNoDuplicateEnumValues(_) => 216usize,
-            Self::TypescriptNoDuplicateTypeConstituents(_) => 217usize,
-            Self::TypescriptNoDynamicDelete(_) => 218usize,
-            Self::TypescriptNoEmptyInterface(_) => 219usize,
-            Self::TypescriptNoEmptyObjectType(_) => 220usize,
-            Self::TypescriptNoExplicitAny(_) => 221usize,
-            Self::TypescriptNoExtraNonNullAssertion(_) => 222usize,
-            Self::TypescriptNoExtraneousClass(_) => 223usize,
-            Self::TypescriptNoFloatingPromises(_) => 224usize,
-            Self::TypescriptNoForInArray(_) => 225usize,
-            Self::TypescriptNoImpliedEval(_) => 226usize,
-            Self::TypescriptNoImportTypeSideEffects(_) => 227usize,
-            Self::TypescriptNoInferrableTypes(_) => 228usize,
-            Self::TypescriptNoMeaninglessVoidOperator(_) => 229usize,
-            Self::TypescriptNoMisusedNew(_) => 230usize,
-            Self::TypescriptNoMisusedPromises(_) => 231usize,
-            Self::TypescriptNoMisusedSpread(_) => 232usize,
-            Self::TypescriptNoMixedEnums(_) => 233usize,
-            Self::TypescriptNoNamespace(_) => 234usize,
-            Self::TypescriptNoNonNullAssertedNullishCoalescing(_) => 235usize,
-            Self::TypescriptNoNonNullAssertedOptionalChain(_) => 236usize,
-            Self::TypescriptNoNonNullAssertion(_) => 237usize,
-            Self::TypescriptNoRedundantTypeConstituents(_) => 238usize,
-            Self::TypescriptNoRequireImports(_) => 239usize,
-            Self::TypescriptNoRestrictedTypes(_) => 240usize,
-            Self::TypescriptNoThisAlias(_) => 241usize,
-            Self::TypescriptNoUnnecessaryBooleanLiteralCompare(_) => 242usize,
-            Self::TypescriptNoUnnecessaryParameterPropertyAssignment(_) => 243usize,
-            Self::TypescriptNoUnnecessaryTemplateExpression(_) => 244usize,
-            Self::TypescriptNoUnnecessaryTypeArguments(_) => 245usize,
-            Self::TypescriptNoUnnecessaryTypeAssertion(_) => 246usize,
-            Self::TypescriptNoUnnecessaryTypeConstraint(_) => 247usize,
-            Self::TypescriptNoUnsafeArgument(_) => 248usize,
-            Self::TypescriptNoUnsafeAssignment(_) => 249usize,
-            Self::TypescriptNoUnsafeCall(_) => 250usize,
-            Self::TypescriptNoUnsafeDeclarationMerging(_) => 251usize,
-            Self::TypescriptNoUnsafeEnumComparison(_) => 252usize,
-            Self::TypescriptNoUnsafeFunctionType(_) => 253usize,
-            Self::TypescriptNoUnsafeMemberAccess(_) => 254usize,
-            Self::TypescriptNoUnsafeReturn(_) => 255usize,
-            Self::TypescriptNoUnsafeTypeAssertion(_) => 256usize,
-            Self::TypescriptNoUnsafeUnaryMinus(_) => 257usize,
-            Self::TypescriptNoUselessEmptyExport(_) => 258usize,
-            Self::TypescriptNoVarRequires(_) => 259usize,
-            Self::TypescriptNoWrapperObjectTypes(_) => 260usize,
-            Self::TypescriptNonNullableTypeAssertionStyle(_) => 261usize,
-            Self::TypescriptOnlyThrowError(_) => 262usize,
-            Self::TypescriptPreferAsConst(_) => 263usize,
-            Self::TypescriptPreferEnumInitializers(_) => 264usize,
-            Self::TypescriptPreferForOf(_) => 265usize,
-            Self::TypescriptPreferFunctionType(_) => 266usize,
-            Self::TypescriptPreferIncludes(_) => 267usize,
-            Self::TypescriptPreferLiteralEnumMember(_) => 268usize,
-            Self::TypescriptPreferNamespaceKeyword(_) => 269usize,
-            Self::TypescriptPreferNullishCoalescing(_) => 270usize,
-            Self::TypescriptPreferOptionalChain(_) => 271usize,
-            Self::TypescriptPreferPromiseRejectErrors(_) => 272usize,
-            Self::TypescriptPreferReduceTypeParameter(_) => 273usize,
-            Self::TypescriptPreferReturnThisType(_) => 274usize,
-            Self::TypescriptPreferTsExpectError(_) => 275usize,
-            Self::TypescriptPromiseFunctionAsync(_) => 276usize,
-            Self::TypescriptRelatedGetterSetterPairs(_) => 277usize,
-            Self::TypescriptRequireArraySortCompare(_) => 278usize,
-            Self::TypescriptRequireAwait(_) => 279usize,
-            Self::TypescriptRestrictPlusOperands(_) => 280usize,
-            Self::TypescriptRestrictTemplateExpressions(_) => 281usize,
-            Self::TypescriptReturnAwait(_) => 282usize,
-            Self::TypescriptStrictBooleanExpressions(_) => 283usize,
-            Self::TypescriptSwitchExhaustivenessCheck(_) => 284usize,
-            Self::TypescriptTripleSlashReference(_) => 285usize,
-            Self::TypescriptUnboundMethod(_) => 286usize,
-            Self::TypescriptUseUnknownInCatchCallbackVariable(_) => 287usize,
-            Self::JestConsistentTestIt(_) => 288usize,
-            Self::JestExpectExpect(_) => 289usize,
-            Self::JestMaxExpects(_) => 290usize,
-            Self::JestMaxNestedDescribe(_) => 291usize,
-            Self::JestNoAliasMethods(_) => 292usize,
-            Self::JestNoCommentedOutTests(_) => 293usize,
-            Self::JestNoConditionalExpect(_) => 294usize,
-            Self::JestNoConditionalInTest(_) => 295usize,
-            Self::JestNoConfusingSetTimeout(_) => 296usize,
-            Self::JestNoDeprecatedFunctions(_) => 297usize,
-            Self::JestNoDisabledTests(_) => 298usize,
-            Self::JestNoDoneCallback(_) => 299usize,
-            Self::JestNoDuplicateHooks(_) => 300usize,
-            Self::JestNoExport(_) => 301usize,
-            Self::JestNoFocusedTests(_) => 302usize,
-            Self::JestNoHooks(_) => 303usize,
-            Self::JestNoIdenticalTitle(_) => 304usize,
-            Self::JestNoInterpolationInSnapshots(_) => 305usize,
-            Self::JestNoJasmineGlobals(_) => 306usize,
-            Self::JestNoLargeSnapshots(_) => 307usize,
-            Self::JestNoMocksImport(_) => 308usize,
-            Self::JestNoRestrictedJestMethods(_) => 309usize,
-            Self::JestNoRestrictedMatchers(_) => 310usize,
-            Self::JestNoStandaloneExpect(_) => 311usize,
-            Self::JestNoTestPrefixes(_) => 312usize,
-            Self::JestNoTestReturnStatement(_) => 313usize,
-            Self::JestNoUntypedMockFactory(_) => 314usize,
-            Self::JestPaddingAroundTestBlocks(_) => 315usize,
-            Self::JestPreferCalledWith(_) => 316usize,
-            Self::JestPreferComparisonMatcher(_) => 317usize,
-            Self::JestPreferEach(_) => 318usize,
-            Self::JestPreferEqualityMatcher(_) => 319usize,
-            Self::JestPreferExpectResolves(_) => 320usize,
-            Self::JestPreferHooksInOrder(_) => 321usize,
-            Self::JestPreferHooksOnTop(_) => 322usize,
-            Self::JestPreferJestMocked(_) => 323usize,
-            Self::JestPreferLowercaseTitle(_) => 324usize,
-            Self::JestPreferMockPromiseShorthand(_) => 325usize,
-            Self::JestPreferSpyOn(_) => 326usize,
-            Self::JestPreferStrictEqual(_) => 327usize,
-            Self::JestPreferToBe(_) => 328usize,
-            Self::JestPreferToContain(_) => 329usize,
-            Self::JestPreferToHaveBeenCalled(_) => 330usize,
-            Self::JestPreferToHaveBeenCalledTimes(_) => 331usize,
-            Self::JestPreferToHaveLength(_) => 332usize,
-            Self::JestPreferTodo(_) => 333usize,
-            Self::JestRequireHook(_) => 334usize,
-            Self::JestRequireToThrowMessage(_) => 335usize,
-            Self::JestRequireTopLevelDescribe(_) => 336usize,
-            Self::JestValidDescribeCallback(_) => 337usize,
-            Self::JestValidExpect(_) => 338usize,
-            Self::JestValidTitle(_) => 339usize,
-            Self::ReactButtonHasType(_) => 340usize,
-            Self::ReactCheckedRequiresOnchangeOrReadonly(_) => 341usize,
-            Self::ReactDisplayName(_) => 342usize,
-            Self::ReactExhaustiveDeps(_) => 343usize,
-            Self::ReactForbidDomProps(_) => 344usize,
-            Self::ReactForbidElements(_) => 345usize,
-            Self::ReactForwardRefUsesRef(_) => 346usize,
-            Self::ReactIframeMissingSandbox(_) => 347usize,
-            Self::ReactJsxBooleanValue(_) => 348usize,
-            Self::ReactJsxCurlyBracePresence(_) => 349usize,
-            Self::ReactJsxFilenameExtension(_) => 350usize,
-            Self::ReactJsxFragments(_) => 351usize,
-            Self::ReactJsxHandlerNames(_) => 352usize,
-            Self::ReactJsxKey(_) => 353usize,
-            Self::ReactJsxMaxDepth(_) => 354usize,
-            Self::ReactJsxNoCommentTextnodes(_) => 355usize,
-            Self::ReactJsxNoDuplicateProps(_) => 356usize,
-            Self::ReactJsxNoScriptUrl(_) => 357usize,
-            Self::ReactJsxNoTargetBlank(_) => 358usize,
-            Self::ReactJsxNoUndef(_) => 359usize,
-            Self::ReactJsxNoUselessFragment(_) => 360usize,
-            Self::ReactJsxPascalCase(_) => 361usize,
-            Self::ReactJsxPropsNoSpreadMulti(_) => 362usize,
-            Self::ReactJsxPropsNoSpreading(_) => 363usize,
-            Self::ReactNoArrayIndexKey(_) => 364usize,
-            Self::ReactNoChildrenProp(_) => 365usize,
-            Self::ReactNoDanger(_) => 366usize,
-            Self::ReactNoDangerWithChildren(_) => 367usize,
-            Self::ReactNoDidMountSetState(_) => 368usize,
-            Self::ReactNoDirectMutationState(_) => 369usize,
-            Self::ReactNoFindDomNode(_) => 370usize,
-            Self::ReactNoIsMounted(_) => 371usize,
-            Self::ReactNoNamespace(_) => 372usize,
-            Self::ReactNoRedundantShouldComponentUpdate(_) => 373usize,
-            Self::ReactNoRenderReturnValue(_) => 374usize,
-            Self::ReactNoSetState(_) => 375usize,
-            Self::ReactNoStringRefs(_) => 376usize,
-            Self::ReactNoThisInSfc(_) => 377usize,
-            Self::ReactNoUnescapedEntities(_) => 378usize,
-            Self::ReactNoUnknownProperty(_) => 379usize,
-            Self::ReactNoUnsafe(_) => 380usize,
-            Self::ReactNoWillUpdateSetState(_) => 381usize,
-            Self::ReactOnlyExportComponents(_) => 382usize,
-            Self::ReactPreferEs6Class(_) => 383usize,
-            Self::ReactReactInJsxScope(_) => 384usize,
-            Self::ReactRequireRenderReturn(_) => 385usize,
-            Self::ReactRulesOfHooks(_) => 386usize,
-            Self::ReactSelfClosingComp(_) => 387usize,
-            Self::ReactStateInConstructor(_) => 388usize,
-            Self::ReactStylePropObject(_) => 389usize,
-            Self::ReactVoidDomElementsNoChildren(_) => 390usize,
-            Self::ReactPerfJsxNoJsxAsProp(_) => 391usize,
-            Self::ReactPerfJsxNoNewArrayAsProp(_) => 392usize,
-            Self::ReactPerfJsxNoNewFunctionAsProp(_) => 393usize,
-            Self::ReactPerfJsxNoNewObjectAsProp(_) => 394usize,
-            Self::UnicornCatchErrorName(_) => 395usize,
-            Self::UnicornConsistentAssert(_) => 396usize,
-            Self::UnicornConsistentDateClone(_) => 397usize,
-            Self::UnicornConsistentEmptyArraySpread(_) => 398usize,
-            Self::UnicornConsistentExistenceIndexCheck(_) => 399usize,
-            Self::UnicornConsistentFunctionScoping(_) => 400usize,
-            Self::UnicornEmptyBraceSpaces(_) => 401usize,
-            Self::UnicornErrorMessage(_) => 402usize,
-            Self::UnicornEscapeCase(_) => 403usize,
-            Self::UnicornExplicitLengthCheck(_) => 404usize,
-            Self::UnicornFilenameCase(_) => 405usize,
-            Self::UnicornNewForBuiltins(_) => 406usize,
-            Self::UnicornNoAbusiveEslintDisable(_) => 407usize,
-            Self::UnicornNoAccessorRecursion(_) => 408usize,
-            Self::UnicornNoAnonymousDefaultExport(_) => 409usize,
-            Self::UnicornNoArrayCallbackReference(_) => 410usize,
-            Self::UnicornNoArrayForEach(_) => 411usize,
-            Self::UnicornNoArrayMethodThisArgument(_) => 412usize,
-            Self::UnicornNoArrayReduce(_) => 413usize,
-            Self::UnicornNoArrayReverse(_) => 414usize,
-            Self::UnicornNoArraySort(_) => 415usize,
-            Self::UnicornNoAwaitExpressionMember(_) => 416usize,
-            Self::UnicornNoAwaitInPromiseMethods(_) => 417usize,
-            Self::UnicornNoConsoleSpaces(_) => 418usize,
-            Self::UnicornNoDocumentCookie(_) => 419usize,
-            Self::UnicornNoEmptyFile(_) => 420usize,
-            Self::UnicornNoHexEscape(_) => 421usize,
-            Self::UnicornNoImmediateMutation(_) => 422usize,
-            Self::UnicornNoInstanceofArray(_) => 423usize,
-            Self::UnicornNoInstanceofBuiltins(_) => 424usize,
-            Self::UnicornNoInvalidFetchOptions(_) => 425usize,
-            Self::UnicornNoInvalidRemoveEventListener(_) => 426usize,
-            Self::UnicornNoLengthAsSliceEnd(_) => 427usize,
-            Self::UnicornNoLonelyIf(_) => 428usize,
-            Self::UnicornNoMagicArrayFlatDepth(_) => 429usize,
-            Self::UnicornNoNegationInEqualityCheck(_) => 430usize,
-            Self::UnicornNoNestedTernary(_) => 431usize,
-            Self::UnicornNoNewArray(_) => 432usize,
-            Self::UnicornNoNewBuffer(_) => 433usize,
-            Self::UnicornNoNull(_) => 434usize,
-            Self::UnicornNoObjectAsDefaultParameter(_) => 435usize,
-            Self::UnicornNoProcessExit(_) => 436usize,
-            Self::UnicornNoSinglePromiseInPromiseMethods(_) => 437usize,
-            Self::UnicornNoStaticOnlyClass(_) => 438usize,
-            Self::UnicornNoThenable(_) => 439usize,
-            Self::UnicornNoThisAssignment(_) => 440usize,
-            Self::UnicornNoTypeofUndefined(_) => 441usize,
-            Self::UnicornNoUnnecessaryArrayFlatDepth(_) => 442usize,
-            Self::UnicornNoUnnecessaryArraySpliceCount(_) => 443usize,
-            Self::UnicornNoUnnecessaryAwait(_) => 444usize,
-            Self::UnicornNoUnnecessarySliceEnd(_) => 445usize,
-            Self::UnicornNoUnreadableArrayDestructuring(_) => 446usize,
-            Self::UnicornNoUnreadableIife(_) => 447usize,
-            Self::UnicornNoUselessCollectionArgument(_) => 448usize,
-            Self::UnicornNoUselessErrorCaptureStackTrace(_) => 449usize,
-            Self::UnicornNoUselessFallbackInSpread(_) => 450usize,
-            Self::UnicornNoUselessLengthCheck(_) => 451usize,
-            Self::UnicornNoUselessPromiseResolveReject(_) => 452usize,
-            Self::UnicornNoUselessSpread(_) => 453usize,
-            Self::UnicornNoUselessSwitchCase(_) => 454usize,
-            Self::UnicornNoUselessUndefined(_) => 455usize,
-            Self::UnicornNoZeroFractions(_) => 456usize,
-            Self::UnicornNumberLiteralCase(_) => 457usize,
-            Self::UnicornNumericSeparatorsStyle(_) => 458usize,
-            Self::UnicornPreferAddEventListener(_) => 459usize,
-            Self::UnicornPreferArrayFind(_) => 460usize,
-            Self::UnicornPreferArrayFlat(_) => 461usize,
-            Self::UnicornPreferArrayFlatMap(_) => 462usize,
-            Self::UnicornPreferArrayIndexOf(_) => 463usize,
-            Self::UnicornPreferArraySome(_) => 464usize,
-            Self::UnicornPreferAt(_) => 465usize,
-            Self::UnicornPreferBigintLiterals(_) => 466usize,
-            Self::UnicornPreferBlobReadingMethods(_) => 467usize,
-            Self::UnicornPreferClassFields(_) => 468usize,
-            Self::UnicornPreferClasslistToggle(_) => 469usize,
-            Self::UnicornPreferCodePoint(_) => 470usize,
-            Self::UnicornPreferDateNow(_) => 471usize,
-            Self::UnicornPreferDefaultParameters(_) => 472usize,
-            Self::UnicornPreferDomNodeAppend(_) => 473usize,
-            Self::UnicornPreferDomNodeDataset(_) => 474usize,
-            Self::UnicornPreferDomNodeRemove(_) => 475usize,
-            Self::UnicornPreferDomNodeTextContent(_) => 476usize,
-            Self::UnicornPreferEventTarget(_) => 477usize,
-            Self::UnicornPreferGlobalThis(_) => 478usize,
-            Self::UnicornPreferIncludes(_) => 479usize,
-            Self::UnicornPreferKeyboardEventKey(_) => 480usize,
-            Self::UnicornPreferLogicalOperatorOverTernary(_) => 481usize,
-            Self::UnicornPreferMathMinMax(_) => 482usize,
-            Self::UnicornPreferMathTrunc(_) => 483usize,
-            Self::UnicornPreferModernDomApis(_) => 484usize,
-            Self::UnicornPreferModernMathApis(_) => 485usize,
-            Self::UnicornPreferNativeCoercionFunctions(_) => 486usize,
-            Self::UnicornPreferNegativeIndex(_) => 487usize,
-            Self::UnicornPreferNodeProtocol(_) => 488usize,
-            Self::UnicornPreferNumberProperties(_) => 489usize,
-            Self::UnicornPreferObjectFromEntries(_) => 490usize,
-            Self::UnicornPreferOptionalCatchBinding(_) => 491usize,
-            Self::UnicornPreferPrototypeMethods(_) => 492usize,
-            Self::UnicornPreferQuerySelector(_) => 493usize,
-            Self::UnicornPreferReflectApply(_) => 494usize,
-            Self::UnicornPreferRegexpTest(_) => 495usize,
-            Self::UnicornPreferResponseStaticJson(_) => 496usize,
-            Self::UnicornPreferSetHas(_) => 497usize,
-            Self::UnicornPreferSetSize(_) => 498usize,
-            Self::UnicornPreferSpread(_) => 499usize,
-            Self::UnicornPreferStringRaw(_) => 500usize,
-            Self::UnicornPreferStringReplaceAll(_) => 501usize,
-            Self::UnicornPreferStringSlice(_) => 502usize,
-            Self::UnicornPreferStringStartsEndsWith(_) => 503usize,
-            Self::UnicornPreferStringTrimStartEnd(_) => 504usize,
-            Self::UnicornPreferStructuredClone(_) => 505usize,
-            Self::UnicornPreferTopLevelAwait(_) => 506usize,
-            Self::UnicornPreferTypeError(_) => 507usize,
-            Self::UnicornRequireArrayJoinSeparator(_) => 508usize,
-            Self::UnicornRequireModuleAttributes(_) => 509usize,
-            Self::UnicornRequireModuleSpecifiers(_) => 510usize,
-            Self::UnicornRequireNumberToFixedDigitsArgument(_) => 511usize,
-            Self::UnicornRequirePostMessageTargetOrigin(_) => 512usize,
-            Self::UnicornSwitchCaseBraces(_) => 513usize,
-            Self::UnicornTextEncodingIdentifierCase(_) => 514usize,
-            Self::UnicornThrowNewError(_) => 515usize,
-            Self::JsxA11YAltText(_) => 516usize,
-            Self::JsxA11YAnchorAmbiguousText(_) => 517usize,
-            Self::JsxA11YAnchorHasContent(_) => 518usize,
-            Self::JsxA11YAnchorIsValid(_) => 519usize,
-            Self::JsxA11YAriaActivedescendantHasTabindex(_) => 520usize,
-            Self::JsxA11YAriaProps(_) => 521usize,
-            Self::JsxA11YAriaProptypes(_) => 522usize,
-            Self::JsxA11YAriaRole(_) => 523usize,
-            Self::JsxA11YAriaUnsupportedElements(_) => 524usize,
-            Self::JsxA11YAutocompleteValid(_) => 525usize,
-            Self::JsxA11YClickEventsHaveKeyEvents(_) => 526usize,
-            Self::JsxA11YHeadingHasContent(_) => 527usize,
-            Self::JsxA11YHtmlHasLang(_) => 528usize,
-            Self::JsxA11YIframeHasTitle(_) => 529usize,
-            Self::JsxA11YImgRedundantAlt(_) => 530usize,
-            Self::JsxA11YLabelHasAssociatedControl(_) => 531usize,
-            Self::JsxA11YLang(_) => 532usize,
-            Self::JsxA11YMediaHasCaption(_) => 533usize,
-            Self::JsxA11YMouseEventsHaveKeyEvents(_) => 534usize,
-            Self::JsxA11YNoAccessKey(_) => 535usize,
-            Self::JsxA11YNoAriaHiddenOnFocusable(_) => 536usize,
-            Self::JsxA11YNoAutofocus(_) => 537usize,
-            Self::JsxA11YNoDistractingElements(_) => 538usize,
-            Self::JsxA11YNoNoninteractiveTabindex(_) => 539usize,
-            Self::JsxA11YNoRedundantRoles(_) => 540usize,
-            Self::JsxA11YNoStaticElementInteractions(_) => 541usize,
-            Self::JsxA11YPreferTagOverRole(_) => 542usize,
-            Self::JsxA11YRoleHasRequiredAriaProps(_) => 543usize,
-            Self::JsxA11YRoleSupportsAriaProps(_) => 544usize,
-            Self::JsxA11YScope(_) => 545usize,
-            Self::JsxA11YTabindexNoPositive(_) => 546usize,
-            Self::OxcApproxConstant(_) => 547usize,
-            Self::OxcBadArrayMethodOnArguments(_) => 548usize,
-            Self::OxcBadBitwiseOperator(_) => 549usize,
-            Self::OxcBadCharAtComparison(_) => 550usize,
-            Self::OxcBadComparisonSequence(_) => 551usize,
-            Self::OxcBadMinMaxFunc(_) => 552usize,
-            Self::OxcBadObjectLiteralComparison(_) => 553usize,
-            Self::OxcBadReplaceAllArg(_) => 554usize,
-            Self::OxcBranchesSharingCode(_) => 555usize,
-            Self::OxcConstComparisons(_) => 556usize,
-            Self::OxcDoubleComparisons(_) => 557usize,
-            Self::OxcErasingOp(_) => 558usize,
-            Self::OxcMisrefactoredAssignOp(_) => 559usize,
-            Self::OxcMissingThrow(_) => 560usize,
-            Self::OxcNoAccumulatingSpread(_) => 561usize,
-            Self::OxcNoAsyncAwait(_) => 562usize,
-            Self::OxcNoAsyncEndpointHandlers(_) => 563usize,
-            Self::OxcNoBarrelFile(_) => 564usize,
-            Self::OxcNoConstEnum(_) => 565usize,
-            Self::OxcNoMapSpread(_) => 566usize,
-            Self::OxcNoOptionalChaining(_) => 567usize,
-            Self::OxcNoRestSpreadProperties(_) => 568usize,
-            Self::OxcNoThisInExportedFunction(_) => 569usize,
-            Self::OxcNumberArgOutOfRange(_) => 570usize,
-            Self::OxcOnlyUsedInRecursion(_) => 571usize,
-            Self::OxcUninvokedArrayCallback(_) => 572usize,
-            Self::NextjsGoogleFontDisplay(_) => 573usize,
-            Self::NextjsGoogleFontPreconnect(_) => 574usize,
-            Self::NextjsInlineScriptId(_) => 575usize,
-            Self::NextjsNextScriptForGa(_) => 576usize,
-            Self::NextjsNoAssignModuleVariable(_) => 577usize,
-            Self::NextjsNoAsyncClientComponent(_) => 578usize,
-            Self::NextjsNoBeforeInteractiveScriptOutsideDocument(_) => 579usize,
-            Self::NextjsNoCssTags(_) => 580usize,
-            Self::NextjsNoDocumentImportInPage(_) => 581usize,
-            Self::NextjsNoDuplicateHead(_) => 582usize,
-            Self::NextjsNoHeadElement(_) => 583usize,
-            Self::NextjsNoHeadImportInDocument(_) => 584usize,
-            Self::NextjsNoHtmlLinkForPages(_) => 585usize,
-            Self::NextjsNoImgElement(_) => 586usize,
-            Self::NextjsNoPageCustomFont(_) => 587usize,
-            Self::NextjsNoScriptComponentInHead(_) => 588usize,
-            Self::NextjsNoStyledJsxInDocument(_) => 589usize,
-            Self::NextjsNoSyncScripts(_) => 590usize,
-            Self::NextjsNoTitleInDocumentHead(_) => 591usize,
-            Self::NextjsNoTypos(_) => 592usize,
-            Self::NextjsNoUnwantedPolyfillio(_) => 593usize,
-            Self::JsdocCheckAccess(_) => 594usize,
-            Self::JsdocCheckPropertyNames(_) => 595usize,
-            Self::JsdocCheckTagNames(_) => 596usize,
-            Self::JsdocEmptyTags(_) => 597usize,
-            Self::JsdocImplementsOnClasses(_) => 598usize,
-            Self::JsdocNoDefaults(_) => 599usize,
-            Self::JsdocRequireParam(_) => 600usize,
-            Self::JsdocRequireParamDescription(_) => 601usize,
-            Self::JsdocRequireParamName(_) => 602usize,
-            Self::JsdocRequireParamType(_) => 603usize,
-            Self::JsdocRequireProperty(_) => 604usize,
-            Self::JsdocRequirePropertyDescription(_) => 605usize,
-            Self::JsdocRequirePropertyName(_) => 606usize,
-            Self::JsdocRequirePropertyType(_) => 607usize,
-            Self::JsdocRequireReturns(_) => 608usize,
-            Self::JsdocRequireReturnsDescription(_) => 609usize,
-            Self::JsdocRequireReturnsType(_) => 610usize,
-            Self::JsdocRequireYields(_) => 611usize,
-            Self::PromiseAlwaysReturn(_) => 612usize,
-            Self::PromiseAvoidNew(_) => 613usize,
-            Self::PromiseCatchOrReturn(_) => 614usize,
-            Self::PromiseNoCallbackInPromise(_) => 615usize,
-            Self::PromiseNoMultipleResolved(_) => 616usize,
-            Self::PromiseNoNesting(_) => 617usize,
-            Self::PromiseNoNewStatics(_) => 618usize,
-            Self::PromiseNoPromiseInCallback(_) => 619usize,
-            Self::PromiseNoReturnInFinally(_) => 620usize,
-            Self::PromiseNoReturnWrap(_) => 621usize,
-            Self::PromiseParamNames(_) => 622usize,
-            Self::PromisePreferAwaitToCallbacks(_) => 623usize,
-            Self::PromisePreferAwaitToThen(_) => 624usize,
-            Self::PromisePreferCatch(_) => 625usize,
-            Self::PromiseSpecOnly(_) => 626usize,
-            Self::PromiseValidParams(_) => 627usize,
-            Self::VitestConsistentEachFor(_) => 628usize,
-            Self::VitestConsistentTestFilename(_) => 629usize,
-            Self::VitestConsistentVitestVi(_) => 630usize,
-            Self::VitestHoistedApisOnTop(_) => 631usize,
-            Self::VitestNoConditionalTests(_) => 632usize,
-            Self::VitestNoImportNodeTest(_) => 633usize,
-            Self::VitestNoUnneededAsyncExpectFunction(_) => 634usize,
-            Self::VitestPreferCalledOnce(_) => 635usize,
-            Self::VitestPreferCalledTimes(_) => 636usize,
-            Self::VitestPreferDescribeFunctionTitle(_) => 637usize,
-            Self::VitestPreferToBeFalsy(_) => 638usize,
-            Self::VitestPreferToBeObject(_) => 639usize,
-            Self::VitestPreferToBeTruthy(_) => 640usize,
-            Self::VitestRequireLocalTestContextForConcurrentSnapshots(_) => 641usize,
-            Self::VitestWarnTodo(_) => 642usize,
-            Self::NodeGlobalRequire(_) => 643usize,
-            Self::NodeNoExportsAssign(_) => 644usize,
-            Self::NodeNoNewRequire(_) => 645usize,
-            Self::NodeNoProcessEnv(_) => 646usize,
-            Self::VueDefineEmitsDeclaration(_) => 647usize,
-            Self::VueDefinePropsDeclaration(_) => 648usize,
-            Self::VueDefinePropsDestructuring(_) => 649usize,
-            Self::VueMaxProps(_) => 650usize,
-            Self::VueNoArrowFunctionsInWatch(_) => 651usize,
-            Self::VueNoDeprecatedDestroyedLifecycle(_) => 652usize,
-            Self::VueNoExportInScriptSetup(_) => 653usize,
-            Self::VueNoImportCompilerMacros(_) => 654usize,
-            Self::VueNoLifecycleAfterAwait(_) => 655usize,
-            Self::VueNoMultipleSlotArgs(_) => 656usize,
-            Self::VueNoRequiredPropWithDefault(_) => 657usize,
-            Self::VueNoThisInBeforeRouteEnter(_) => 658usize,
-            Self::VuePreferImportFromVue(_) => 659usize,
-            Self::VueRequireDefaultExport(_) => 660usize,
-            Self::VueRequireTypedRef(_) => 661usize,
-            Self::VueValidDefineEmits(_) => 662usize,
-            Self::VueValidDefineProps(_) => 663usize,
+            Self::ImportNoExtraneousDependencies(_) => 19usize,
+            Self::ImportNoMutableExports(_) => 20usize,
+            Self::ImportNoNamedAsDefault(_) => 21usize,
+            Self::ImportNoNamedAsDefaultMember(_) => 22usize,
+            Self::ImportNoNamedDefault(_) => 23usize,
+            Self::ImportNoNamedExport(_) => 24usize,
+            Self::ImportNoNamespace(_) => 25usize,
+            Self::ImportNoNodejsModules(_) => 26usize,
+            Self::ImportNoRelativeParentImports(_) => 27usize,
+            Self::ImportNoSelfImport(_) => 28usize,
+            Self::ImportNoUnassignedImport(_) => 29usize,
+            Self::ImportNoUnresolved(_) => 30usize,
+            Self::ImportNoUnusedModules(_) => 31usize,
+            Self::ImportNoWebpackLoaderSyntax(_) => 32usize,
+            Self::ImportOrder(_) => 33usize,
+            Self::ImportPreferDefaultExport(_) => 34usize,
+            Self::ImportUnambiguous(_) => 35usize,
+            Self::EslintAccessorPairs(_) => 36usize,
+            Self::EslintArrayCallbackReturn(_) => 37usize,
+            Self::EslintArrowBodyStyle(_) => 38usize,
+            Self::EslintBlockScopedVar(_) => 39usize,
+            Self::EslintCapitalizedComments(_) => 40usize,
+            Self::EslintClassMethodsUseThis(_) => 41usize,
+            Self::EslintComplexity(_) => 42usize,
+            Self::EslintConstructorSuper(_) => 43usize,
+            Self::EslintCurly(_) => 44usize,
+            Self::EslintDefaultCase(_) => 45usize,
+            Self::EslintDefaultCaseLast(_) => 46usize,
+            Self::EslintDefaultParamLast(_) => 47usize,
+            Self::EslintEqeqeq(_) => 48usize,
+            Self::EslintForDirection(_) => 49usize,
+            Self::EslintFuncNames(_) => 50usize,
+            Self::EslintFuncStyle(_) => 51usize,
+            Self::EslintGetterReturn(_) => 52usize,
+            Self::EslintGroupedAccessorPairs(_) => 53usize,
+            Self::EslintGuardForIn(_) => 54usize,
+            Self::EslintIdLength(_) => 55usize,
+            Self::EslintInitDeclarations(_) => 56usize,
+            Self::EslintMaxClassesPerFile(_) => 57usize,
+            Self::EslintMaxDepth(_) => 58usize,
+            Self::EslintMaxLines(_) => 59usize,
+            Self::EslintMaxLinesPerFunction(_) => 60usize,
+            Self::EslintMaxNestedCallbacks(_) => 61usize,
+            Self::EslintMaxParams(_) => 62usize,
+            Self::EslintMaxStatements(_) => 63usize,
+            Self::EslintNewCap(_) => 64usize,
+            Self::EslintNoAlert(_) => 65usize,
+            Self::EslintNoArrayConstructor(_) => 66usize,
+            Self::EslintNoAsyncPromiseExecutor(_) => 67usize,
+            Self::EslintNoAwaitInLoop(_) => 68usize,
+            Self::EslintNoBitwise(_) => 69usize,
+            Self::EslintNoCaller(_) => 70usize,
+            Self::EslintNoCaseDeclarations(_) => 71usize,
+            Self::EslintNoClassAssign(_) => 72usize,
+            Self::EslintNoCompareNegZero(_) => 73usize,
+            Self::EslintNoCondAssign(_) => 74usize,
+            Self::EslintNoConsole(_) => 75usize,
+            Self::EslintNoConstAssign(_) => 76usize,
+            Self::EslintNoConstantBinaryExpression(_) => 77usize,
+            Self::EslintNoConstantCondition(_) => 78usize,
+            Self::EslintNoConstructorReturn(_) => 79usize,
+            Self::EslintNoContinue(_) => 80usize,
+            Self::EslintNoControlRegex(_) => 81usize,
+            Self::EslintNoDebugger(_) => 82usize,
+            Self::EslintNoDeleteVar(_) => 83usize,
+            Self::EslintNoDivRegex(_) => 84usize,
+            Self::EslintNoDupeClassMembers(_) => 85usize,
+            Self::EslintNoDupeElseIf(_) => 86usize,
+            Self::EslintNoDupeKeys(_) => 87usize,
+            Self::EslintNoDuplicateCase(_) => 88usize,
+            Self::EslintNoDuplicateImports(_) => 89usize,
+            Self::EslintNoElseReturn(_) => 90usize,
+            Self::EslintNoEmpty(_) => 91usize,
+            Self::EslintNoEmptyCharacterClass(_) => 92usize,
+            Self::EslintNoEmptyFunction(_) => 93usize,
+            Self::EslintNoEmptyPattern(_) => 94usize,
+            Self::EslintNoEmptyStaticBlock(_) => 95usize,
+            Self::EslintNoEqNull(_) => 96usize,
+            Self::EslintNoEval(_) => 97usize,
+            Self::EslintNoExAssign(_) => 98usize,
+            Self::EslintNoExtendNative(_) => 99usize,
+            Self::EslintNoExtraBind(_) => 100usize,
+            Self::EslintNoExtraBooleanCast(_) => 101usize,
+            Self::EslintNoExtraLabel(_) => 102usize,
+            Self::EslintNoFallthrough(_) => 103usize,
+            Self::EslintNoFuncAssign(_) => 104usize,
+            Self::EslintNoGlobalAssign(_) => 105usize,
+            Self::EslintNoImplicitCoercion(_) => 106usize,
+            Self::EslintNoImportAssign(_) => 107usize,
+            Self::EslintNoInlineComments(_) => 108usize,
+            Self::EslintNoInnerDeclarations(_) => 109usize,
+            Self::EslintNoInvalidRegexp(_) => 110usize,
+            Self::EslintNoIrregularWhitespace(_) => 111usize,
+            Self::EslintNoIterator(_) => 112usize,
+            Self::EslintNoLabelVar(_) => 113usize,
+            Self::EslintNoLabels(_) => 114usize,
+            Self::EslintNoLoneBlocks(_) => 115usize,
+            Self::EslintNoLonelyIf(_) => 116usize,
+            Self::EslintNoLoopFunc(_) => 117usize,
+            Self::EslintNoLossOfPrecision(_) => 118usize,
+            Self::EslintNoMagicNumbers(_) => 119usize,
+            Self::EslintNoMisleadingCharacterClass(_) => 120usize,
+            Self::EslintNoMultiAssign(_) => 121usize,
+            Self::EslintNoMultiStr(_) => 122usize,
+            Self::EslintNoNegatedCondition(_) => 123usize,
+            Self::EslintNoNestedTernary(_) => 124usize,
+            Self::EslintNoNew(_) => 125usize,
+            Self::EslintNoNewFunc(_) => 126usize,
+            Self::EslintNoNewNativeNonconstructor(_) => 127usize,
+            Self::EslintNoNewWrappers(_) => 128usize,
+            Self::EslintNoNonoctalDecimalEscape(_) => 129usize,
+            Self::EslintNoObjCalls(_) => 130usize,
+            Self::EslintNoObjectConstructor(_) => 131usize,
+            Self::EslintNoParamReassign(_) => 132usize,
+            Self::EslintNoPlusplus(_) => 133usize,
+            Self::EslintNoPromiseExecutorReturn(_) => 134usize,
+            Self::EslintNoProto(_) => 135usize,
+            Self::EslintNoPrototypeBuiltins(_) => 136usize,
+            Self::EslintNoRedeclare(_) => 137usize,
+            Self::EslintNoRegexSpaces(_) => 138usize,
+            Self::EslintNoRestrictedGlobals(_) => 139usize,
+            Self::EslintNoRestrictedImports(_) => 140usize,
+            Self::EslintNoRestrictedSyntax(_) => 141usize,
+            Self::EslintNoReturnAssign(_) => 142usize,
+            Self::EslintNoScriptUrl(_) => 143usize,
+            Self::EslintNoSelfAssign(_) => 144usize,
+            Self::EslintNoSelfCompare(_) => 145usize,
+            Self::EslintNoSequences(_) => 146usize,
+            Self::EslintNoSetterReturn(_) => 147usize,
+            Self::EslintNoShadowRestrictedNames(_) => 148usize,
+            Self::EslintNoSparseArrays(_) => 149usize,
+            Self::EslintNoTemplateCurlyInString(_) => 150usize,
+            Self::EslintNoTernary(_) => 151usize,
+            Self::EslintNoThisBeforeSuper(_) => 152usize,
+            Self::EslintNoThrowLiteral(_) => 153usize,
+            Self::EslintNoUnassignedVars(_) => 154usize,
+            Self::EslintNoUndef(_) => 155usize,
+            Self::EslintNoUndefined(_) => 156usize,
+            Self::EslintNoUnexpectedMultiline(_) => 157usize,
+            Self::EslintNoUnneededTernary(_) => 158usize,
+            Self::EslintNoUnreachable(_) => 159usize,
+            Self::EslintNoUnreachableLoop(_) => 160usize,
+            Self::EslintNoUnsafeFinally(_) => 161usize,
+            Self::EslintNoUnsafeNegation(_) => 162usize,
+            Self::EslintNoUnsafeOptionalChaining(_) => 163usize,
+            Self::EslintNoUnusedExpressions(_) => 164usize,
+            Self::EslintNoUnusedLabels(_) => 165usize,
+            Self::EslintNoUnusedPrivateClassMembers(_) => 166usize,
+            Self::EslintNoUnusedVars(_) => 167usize,
+            Self::EslintNoUselessAssignment(_) => 168usize,
+            Self::EslintNoUselessBackreference(_) => 169usize,
+            Self::EslintNoUselessCall(_) => 170usize,
+            Self::EslintNoUselessCatch(_) => 171usize,
+            Self::EslintNoUselessComputedKey(_) => 172usize,
+            Self::EslintNoUselessConcat(_) => 173usize,
+            Self::EslintNoUselessConstructor(_) => 174usize,
+            Self::EslintNoUselessEscape(_) => 175usize,
+            Self::EslintNoUselessRename(_) => 176usize,
+            Self::EslintNoUselessReturn(_) => 177usize,
+            Self::EslintNoVar(_) => 178usize,
+            Self::EslintNoVoid(_) => 179usize,
+            Self::EslintNoWarningComments(_) => 180usize,
+            Self::EslintNoWith(_) => 181usize,
+            Self::EslintOperatorAssignment(_) => 182usize,
+            Self::EslintPreferConst(_) => 183usize,
+            Self::EslintPreferDestructuring(_) => 184usize,
+            Self::EslintPreferExponentiationOperator(_) => 185usize,
+            Self::EslintPreferNumericLiterals(_) => 186usize,
+            Self::EslintPreferObjectHasOwn(_) => 187usize,
+            Self::EslintPreferObjectSpread(_) => 188usize,
+            Self::EslintPreferPromiseRejectErrors(_) => 189usize,
+            Self::EslintPreferRestParams(_) => 190usize,
+            Self::EslintPreferSpread(_) => 191usize,
+            Self::EslintPreferTemplate(_) => 192usize,
+            Self::EslintPreserveCaughtError(_) => 193usize,
+            Self::EslintRadix(_) => 194usize,
+            Self::EslintRequireAwait(_) => 195usize,
+            Self::EslintRequireYield(_) => 196usize,
+            Self::EslintSortImports(_) => 197usize,
+            Self::EslintSortKeys(_) => 198usize,
+            Self::EslintSortVars(_) => 199usize,
+            Self::EslintSymbolDescription(_) => 200usize,
+            Self::EslintUnicodeBom(_) => 201usize,
+            Self::EslintUseIsnan(_) => 202usize,
+            Self::EslintValidTypeof(_) => 203usize,
+            Self::EslintVarsOnTop(_) => 204usize,
+            Self::EslintYoda(_) => 205usize,
+            Self::TypescriptAdjacentOverloadSignatures(_) => 206usize,
+            Self::TypescriptArrayType(_) => 207usize,
+            Self::TypescriptAwaitThenable(_) => 208usize,
+            Self::TypescriptBanTsComment(_) => 209usize,
+            Self::TypescriptBanTslintComment(_) => 210usize,
+            Self::TypescriptBanTypes(_) => 211usize,
+            Self::TypescriptConsistentGenericConstructors(_) => 212usize,
+            Self::TypescriptConsistentIndexedObjectStyle(_) => 213usize,
+            Self::TypescriptConsistentTypeDefinitions(_) => 214usize,
+            Self::TypescriptConsistentTypeImports(_) => 215usize,
+            Self::TypescriptExplicitFunctionReturnType(_) => 216usize,
+            Self::TypescriptExplicitModuleBoundaryTypes(_) => 217usize,
+            Self::TypescriptNamingConvention(_) => 218usize,
+            Self::TypescriptNoArrayDelete(_) => 219usize,
+            Self::TypescriptNoBaseToString(_) => 220usize,
+            Self::TypescriptNoConfusingNonNullAssertion(_) => 221usize,
+            Self::TypescriptNoConfusingVoidExpression(_) => 222usize,
+            Self::TypescriptNoDeprecated(_) => 223usize,
+            Self::TypescriptNoDuplicateEnumValues(_) => 224usize,
+            Self::TypescriptNoDuplicateTypeConstituents(_) => 225usize,
+            Self::TypescriptNoDynamicDelete(_) => 226usize,
+            Self::TypescriptNoEmptyInterface(_) => 227usize,
+            Self::TypescriptNoEmptyObjectType(_) => 228usize,
+            Self::TypescriptNoExplicitAny(_) => 229usize,
+            Self::TypescriptNoExtraNonNullAssertion(_) => 230usize,
+            Self::TypescriptNoExtraneousClass(_) => 231usize,
+            Self::TypescriptNoFloatingPromises(_) => 232usize,
+            Self::TypescriptNoForInArray(_) => 233usize,
+            Self::TypescriptNoImpliedEval(_) => 234usize,
+            Self::TypescriptNoImportTypeSideEffects(_) => 235usize,
+            Self::TypescriptNoInferrableTypes(_) => 236usize,
+            Self::TypescriptNoMeaninglessVoidOperator(_) => 237usize,
+            Self::TypescriptNoMisusedNew(_) => 238usize,
+            Self::TypescriptNoMisusedPromises(_) => 239usize,
+            Self::TypescriptNoMisusedSpread(_) => 240usize,
+            Self::TypescriptNoMixedEnums(_) => 241usize,
+            Self::TypescriptNoNamespace(_) => 242usize,
+            Self::TypescriptNoNonNullAssertedNullishCoalescing(_) => 243usize,
+            Self::TypescriptNoNonNullAssertedOptionalChain(_) => 244usize,
+            Self::TypescriptNoNonNullAssertion(_) => 245usize,
+            Self::TypescriptNoRedundantTypeConstituents(_) => 246usize,
+            Self::TypescriptNoRequireImports(_) => 247usize,
+            Self::TypescriptNoRestrictedTypes(_) => 248usize,
+            Self::TypescriptNoThisAlias(_) => 249usize,
+            Self::TypescriptNoUnnecessaryBooleanLiteralCompare(_) => 250usize,
+            Self::TypescriptNoUnnecessaryParameterPropertyAssignment(_) => 251usize,
+            Self::TypescriptNoUnnecessaryTemplateExpression(_) => 252usize,
+            Self::TypescriptNoUnnecessaryTypeArguments(_) => 253usize,
+            Self::TypescriptNoUnnecessaryTypeAssertion(_) => 254usize,
+            Self::TypescriptNoUnnecessaryTypeConstraint(_) => 255usize,
+            Self::TypescriptNoUnsafeArgument(_) => 256usize,
+            Self::TypescriptNoUnsafeAssignment(_) => 257usize,
+            Self::TypescriptNoUnsafeCall(_) => 258usize,
+            Self::TypescriptNoUnsafeDeclarationMerging(_) => 259usize,
+            Self::TypescriptNoUnsafeEnumComparison(_) => 260usize,
+            Self::TypescriptNoUnsafeFunctionType(_) => 261usize,
+            Self::TypescriptNoUnsafeMemberAccess(_) => 262usize,
+            Self::TypescriptNoUnsafeReturn(_) => 263usize,
+            Self::TypescriptNoUnsafeTypeAssertion(_) => 264usize,
+            Self::TypescriptNoUnsafeUnaryMinus(_) => 265usize,
+            Self::TypescriptNoUselessEmptyExport(_) => 266usize,
+            Self::TypescriptNoVarRequires(_) => 267usize,
+            Self::TypescriptNoWrapperObjectTypes(_) => 268usize,
+            Self::TypescriptNonNullableTypeAssertionStyle(_) => 269usize,
+            Self::TypescriptOnlyThrowError(_) => 270usize,
+            Self::TypescriptPreferAsConst(_) => 271usize,
+            Self::TypescriptPreferEnumInitializers(_) => 272usize,
+            Self::TypescriptPreferForOf(_) => 273usize,
+            Self::TypescriptPreferFunctionType(_) => 274usize,
+            Self::TypescriptPreferIncludes(_) => 275usize,
+            Self::TypescriptPreferLiteralEnumMember(_) => 276usize,
+            Self::TypescriptPreferNamespaceKeyword(_) => 277usize,
+            Self::TypescriptPreferNullishCoalescing(_) => 278usize,
+            Self::TypescriptPreferOptionalChain(_) => 279usize,
+            Self::TypescriptPreferPromiseRejectErrors(_) => 280usize,
+            Self::TypescriptPreferReduceTypeParameter(_) => 281usize,
+            Self::TypescriptPreferReturnThisType(_) => 282usize,
+            Self::TypescriptPreferTsExpectError(_) => 283usize,
+            Self::TypescriptPromiseFunctionAsync(_) => 284usize,
+            Self::TypescriptRelatedGetterSetterPairs(_) => 285usize,
+            Self::TypescriptRequireArraySortCompare(_) => 286usize,
+            Self::TypescriptRequireAwait(_) => 287usize,
+            Self::TypescriptRestrictPlusOperands(_) => 288usize,
+            Self::TypescriptRestrictTemplateExpressions(_) => 289usize,
+            Self::TypescriptReturnAwait(_) => 290usize,
+            Self::TypescriptStrictBooleanExpressions(_) => 291usize,
+            Self::TypescriptSwitchExhaustivenessCheck(_) => 292usize,
+            Self::TypescriptTripleSlashReference(_) => 293usize,
+            Self::TypescriptUnboundMethod(_) => 294usize,
+            Self::TypescriptUseUnknownInCatchCallbackVariable(_) => 295usize,
+            Self::JestConsistentTestIt(_) => 296usize,
+            Self::JestExpectExpect(_) => 297usize,
+            Self::JestMaxExpects(_) => 298usize,
+            Self::JestMaxNestedDescribe(_) => 299usize,
+            Self::JestNoAliasMethods(_) => 300usize,
+            Self::JestNoCommentedOutTests(_) => 301usize,
+            Self::JestNoConditionalExpect(_) => 302usize,
+            Self::JestNoConditionalInTest(_) => 303usize,
+            Self::JestNoConfusingSetTimeout(_) => 304usize,
+            Self::JestNoDeprecatedFunctions(_) => 305usize,
+            Self::JestNoDisabledTests(_) => 306usize,
+            Self::JestNoDoneCallback(_) => 307usize,
+            Self::JestNoDuplicateHooks(_) => 308usize,
+            Self::JestNoExport(_) => 309usize,
+            Self::JestNoFocusedTests(_) => 310usize,
+            Self::JestNoHooks(_) => 311usize,
+            Self::JestNoIdenticalTitle(_) => 312usize,
+            Self::JestNoInterpolationInSnapshots(_) => 313usize,
+            Self::JestNoJasmineGlobals(_) => 314usize,
+            Self::JestNoLargeSnapshots(_) => 315usize,
+            Self::JestNoMocksImport(_) => 316usize,
+            Self::JestNoRestrictedJestMethods(_) => 317usize,
+            Self::JestNoRestrictedMatchers(_) => 318usize,
+            Self::JestNoStandaloneExpect(_) => 319usize,
+            Self::JestNoTestPrefixes(_) => 320usize,
+            Self::JestNoTestReturnStatement(_) => 321usize,
+            Self::JestNoUntypedMockFactory(_) => 322usize,
+            Self::JestPaddingAroundTestBlocks(_) => 323usize,
+            Self::JestPreferCalledWith(_) => 324usize,
+            Self::JestPreferComparisonMatcher(_) => 325usize,
+            Self::JestPreferEach(_) => 326usize,
+            Self::JestPreferEqualityMatcher(_) => 327usize,
+            Self::JestPreferExpectResolves(_) => 328usize,
+            Self::JestPreferHooksInOrder(_) => 329usize,
+            Self::JestPreferHooksOnTop(_) => 330usize,
+            Self::JestPreferJestMocked(_) => 331usize,
+            Self::JestPreferLowercaseTitle(_) => 332usize,
+            Self::JestPreferMockPromiseShorthand(_) => 333usize,
+            Self::JestPreferSpyOn(_) => 334usize,
+            Self::JestPreferStrictEqual(_) => 335usize,
+            Self::JestPreferToBe(_) => 336usize,
+            Self::JestPreferToContain(_) => 337usize,
+            Self::JestPreferToHaveBeenCalled(_) => 338usize,
+            Self::JestPreferToHaveBeenCalledTimes(_) => 339usize,
+            Self::JestPreferToHaveLength(_) => 340usize,
+            Self::JestPreferTodo(_) => 341usize,
+            Self::JestRequireHook(_) => 342usize,
+            Self::JestRequireToThrowMessage(_) => 343usize,
+            Self::JestRequireTopLevelDescribe(_) => 344usize,
+            Self::JestValidDescribeCallback(_) => 345usize,
+            Self::JestValidExpect(_) => 346usize,
+            Self::JestValidTitle(_) => 347usize,
+            Self::ReactButtonHasType(_) => 348usize,
+            Self::ReactCheckedRequiresOnchangeOrReadonly(_) => 349usize,
+            Self::ReactDisplayName(_) => 350usize,
+            Self::ReactExhaustiveDeps(_) => 351usize,
+            Self::ReactForbidDomProps(_) => 352usize,
+            Self::ReactForbidElements(_) => 353usize,
+            Self::ReactForwardRefUsesRef(_) => 354usize,
+            Self::ReactIframeMissingSandbox(_) => 355usize,
+            Self::ReactJsxBooleanValue(_) => 356usize,
+            Self::ReactJsxCurlyBracePresence(_) => 357usize,
+            Self::ReactJsxFilenameExtension(_) => 358usize,
+            Self::ReactJsxFragments(_) => 359usize,
+            Self::ReactJsxHandlerNames(_) => 360usize,
+            Self::ReactJsxKey(_) => 361usize,
+            Self::ReactJsxMaxDepth(_) => 362usize,
+            Self::ReactJsxNoCommentTextnodes(_) => 363usize,
+            Self::ReactJsxNoDuplicateProps(_) => 364usize,
+            Self::ReactJsxNoScriptUrl(_) => 365usize,
+            Self::ReactJsxNoTargetBlank(_) => 366usize,
+            Self::ReactJsxNoUndef(_) => 367usize,
+            Self::ReactJsxNoUselessFragment(_) => 368usize,
+            Self::ReactJsxPascalCase(_) => 369usize,
+            Self::ReactJsxPropsNoSpreadMulti(_) => 370usize,
+            Self::ReactJsxPropsNoSpreading(_) => 371usize,
+            Self::ReactNoArrayIndexKey(_) => 372usize,
+            Self::ReactNoChildrenProp(_) => 373usize,
+            Self::ReactNoDanger(_) => 374usize,
+            Self::ReactNoDangerWithChildren(_) => 375usize,
+            Self::ReactNoDidMountSetState(_) => 376usize,
+            Self::ReactNoDirectMutationState(_) => 377usize,
+            Self::ReactNoFindDomNode(_) => 378usize,
+            Self::ReactNoIsMounted(_) => 379usize,
+            Self::ReactNoNamespace(_) => 380usize,
+            Self::ReactNoRedundantShouldComponentUpdate(_) => 381usize,
+            Self::ReactNoRenderReturnValue(_) => 382usize,
+            Self::ReactNoSetState(_) => 383usize,
+            Self::ReactNoStringRefs(_) => 384usize,
+            Self::ReactNoThisInSfc(_) => 385usize,
+            Self::ReactNoUnescapedEntities(_) => 386usize,
+            Self::ReactNoUnknownProperty(_) => 387usize,
+            Self::ReactNoUnsafe(_) => 388usize,
+            Self::ReactNoWillUpdateSetState(_) => 389usize,
+            Self::ReactOnlyExportComponents(_) => 390usize,
+            Self::ReactPreferEs6Class(_) => 391usize,
+            Self::ReactReactInJsxScope(_) => 392usize,
+            Self::ReactRequireRenderReturn(_) => 393usize,
+            Self::ReactRulesOfHooks(_) => 394usize,
+            Self::ReactSelfClosingComp(_) => 395usize,
+            Self::ReactStateInConstructor(_) => 396usize,
+            Self::ReactStylePropObject(_) => 397usize,
+            Self::ReactVoidDomElementsNoChildren(_) => 398usize,
+            Self::ReactPerfJsxNoJsxAsProp(_) => 399usize,
+            Self::ReactPerfJsxNoNewArrayAsProp(_) => 400usize,
+            Self::ReactPerfJsxNoNewFunctionAsProp(_) => 401usize,
+            Self::ReactPerfJsxNoNewObjectAsProp(_) => 402usize,
+            Self::UnicornCatchErrorName(_) => 403usize,
+            Self::UnicornConsistentAssert(_) => 404usize,
+            Self::UnicornConsistentDateClone(_) => 405usize,
+            Self::UnicornConsistentEmptyArraySpread(_) => 406usize,
+            Self::UnicornConsistentExistenceIndexCheck(_) => 407usize,
+            Self::UnicornConsistentFunctionScoping(_) => 408usize,
+            Self::UnicornEmptyBraceSpaces(_) => 409usize,
+            Self::UnicornErrorMessage(_) => 410usize,
+            Self::UnicornEscapeCase(_) => 411usize,
+            Self::UnicornExplicitLengthCheck(_) => 412usize,
+            Self::UnicornFilenameCase(_) => 413usize,
+            Self::UnicornNewForBuiltins(_) => 414usize,
+            Self::UnicornNoAbusiveEslintDisable(_) => 415usize,
+            Self::UnicornNoAccessorRecursion(_) => 416usize,
+            Self::UnicornNoAnonymousDefaultExport(_) => 417usize,
+            Self::UnicornNoArrayCallbackReference(_) => 418usize,
+            Self::UnicornNoArrayForEach(_) => 419usize,
+            Self::UnicornNoArrayMethodThisArgument(_) => 420usize,
+            Self::UnicornNoArrayReduce(_) => 421usize,
+            Self::UnicornNoArrayReverse(_) => 422usize,
+            Self::UnicornNoArraySort(_) => 423usize,
+            Self::UnicornNoAwaitExpressionMember(_) => 424usize,
+            Self::UnicornNoAwaitInPromiseMethods(_) => 425usize,
+            Self::UnicornNoConsoleSpaces(_) => 426usize,
+            Self::UnicornNoDocumentCookie(_) => 427usize,
+            Self::UnicornNoEmptyFile(_) => 428usize,
+            Self::UnicornNoHexEscape(_) => 429usize,
+            Self::UnicornNoImmediateMutation(_) => 430usize,
+            Self::UnicornNoInstanceofArray(_) => 431usize,
+            Self::UnicornNoInstanceofBuiltins(_) => 432usize,
+            Self::UnicornNoInvalidFetchOptions(_) => 433usize,
+            Self::UnicornNoInvalidRemoveEventListener(_) => 434usize,
+            Self::UnicornNoLengthAsSliceEnd(_) => 435usize,
+            Self::UnicornNoLonelyIf(_) => 436usize,
+            Self::UnicornNoMagicArrayFlatDepth(_) => 437usize,
+            Self::UnicornNoNegationInEqualityCheck(_) => 438usize,
+            Self::UnicornNoNestedTernary(_) => 439usize,
+            Self::UnicornNoNewArray(_) => 440usize,
+            Self::UnicornNoNewBuffer(_) => 441usize,
+            Self::UnicornNoNull(_) => 442usize,
+            Self::UnicornNoObjectAsDefaultParameter(_) => 443usize,
+            Self::UnicornNoProcessExit(_) => 444usize,
+            Self::UnicornNoSinglePromiseInPromiseMethods(_) => 445usize,
+            Self::UnicornNoStaticOnlyClass(_) => 446usize,
+            Self::UnicornNoThenable(_) => 447usize,
+            Self::UnicornNoThisAssignment(_) => 448usize,
+            Self::UnicornNoTypeofUndefined(_) => 449usize,
+            Self::UnicornNoUnnecessaryArrayFlatDepth(_) => 450usize,
+            Self::UnicornNoUnnecessaryArraySpliceCount(_) => 451usize,
+            Self::UnicornNoUnnecessaryAwait(_) => 452usize,
+            Self::UnicornNoUnnecessarySliceEnd(_) => 453usize,
+            Self::UnicornNoUnreadableArrayDestructuring(_) => 454usize,
+            Self::UnicornNoUnreadableIife(_) => 455usize,
+            Self::UnicornNoUselessCollectionArgument(_) => 456usize,
+            Self::UnicornNoUselessErrorCaptureStackTrace(_) => 457usize,
+            Self::UnicornNoUselessFallbackInSpread(_) => 458usize,
+            Self::UnicornNoUselessLengthCheck(_) => 459usize,
+            Self::UnicornNoUselessPromiseResolveReject(_) => 460usize,
+            Self::UnicornNoUselessSpread(_) => 461usize,
+            Self::UnicornNoUselessSwitchCase(_) => 462usize,
+            Self::UnicornNoUselessUndefined(_) => 463usize,
+            Self::UnicornNoZeroFractions(_) => 464usize,
+            Self::UnicornNumberLiteralCase(_) => 465usize,
+            Self::UnicornNumericSeparatorsStyle(_) => 466usize,
+            Self::UnicornPreferAddEventListener(_) => 467usize,
+            Self::UnicornPreferArrayFind(_) => 468usize,
+            Self::UnicornPreferArrayFlat(_) => 469usize,
+            Self::UnicornPreferArrayFlatMap(_) => 470usize,
+            Self::UnicornPreferArrayIndexOf(_) => 471usize,
+            Self::UnicornPreferArraySome(_) => 472usize,
+            Self::UnicornPreferAt(_) => 473usize,
+            Self::UnicornPreferBigintLiterals(_) => 474usize,
+            Self::UnicornPreferBlobReadingMethods(_) => 475usize,
+            Self::UnicornPreferClassFields(_) => 476usize,
+            Self::UnicornPreferClasslistToggle(_) => 477usize,
+            Self::UnicornPreferCodePoint(_) => 478usize,
+            Self::UnicornPreferDateNow(_) => 479usize,
+            Self::UnicornPreferDefaultParameters(_) => 480usize,
+            Self::UnicornPreferDomNodeAppend(_) => 481usize,
+            Self::UnicornPreferDomNodeDataset(_) => 482usize,
+            Self::UnicornPreferDomNodeRemove(_) => 483usize,
+            Self::UnicornPreferDomNodeTextContent(_) => 484usize,
+            Self::UnicornPreferEventTarget(_) => 485usize,
+            Self::UnicornPreferGlobalThis(_) => 486usize,
+            Self::UnicornPreferIncludes(_) => 487usize,
+            Self::UnicornPreferKeyboardEventKey(_) => 488usize,
+            Self::UnicornPreferLogicalOperatorOverTernary(_) => 489usize,
+            Self::UnicornPreferMathMinMax(_) => 490usize,
+            Self::UnicornPreferMathTrunc(_) => 491usize,
+            Self::UnicornPreferModernDomApis(_) => 492usize,
+            Self::UnicornPreferModernMathApis(_) => 493usize,
+            Self::UnicornPreferNativeCoercionFunctions(_) => 494usize,
+            Self::UnicornPreferNegativeIndex(_) => 495usize,
+            Self::UnicornPreferNodeProtocol(_) => 496usize,
+            Self::UnicornPreferNumberProperties(_) => 497usize,
+            Self::UnicornPreferObjectFromEntries(_) => 498usize,
+            Self::UnicornPreferOptionalCatchBinding(_) => 499usize,
+            Self::UnicornPreferPrototypeMethods(_) => 500usize,
+            Self::UnicornPreferQuerySelector(_) => 501usize,
+            Self::UnicornPreferReflectApply(_) => 502usize,
+            Self::UnicornPreferRegexpTest(_) => 503usize,
+            Self::UnicornPreferResponseStaticJson(_) => 504usize,
+            Self::UnicornPreferSetHas(_) => 505usize,
+            Self::UnicornPreferSetSize(_) => 506usize,
+            Self::UnicornPreferSpread(_) => 507usize,
+            Self::UnicornPreferStringRaw(_) => 508usize,
+            Self::UnicornPreferStringReplaceAll(_) => 509usize,
+            Self::UnicornPreferStringSlice(_) => 510usize,
+            Self::UnicornPreferStringStartsEndsWith(_) => 511usize,
+            Self::UnicornPreferStringTrimStartEnd(_) => 512usize,
+            Self::UnicornPreferStructuredClone(_) => 513usize,
+            Self::UnicornPreferTopLevelAwait(_) => 514usize,
+            Self::UnicornPreferTypeError(_) => 515usize,
+            Self::UnicornRequireArrayJoinSeparator(_) => 516usize,
+            Self::UnicornRequireModuleAttributes(_) => 517usize,
+            Self::UnicornRequireModuleSpecifiers(_) => 518usize,
+            Self::UnicornRequireNumberToFixedDigitsArgument(_) => 519usize,
+            Self::UnicornRequirePostMessageTargetOrigin(_) => 520usize,
+            Self::UnicornSwitchCaseBraces(_) => 521usize,
+            Self::UnicornTextEncodingIdentifierCase(_) => 522usize,
+            Self::UnicornThrowNewError(_) => 523usize,
+            Self::JsxA11YAltText(_) => 524usize,
+            Self::JsxA11YAnchorAmbiguousText(_) => 525usize,
+            Self::JsxA11YAnchorHasContent(_) => 526usize,
+            Self::JsxA11YAnchorIsValid(_) => 527usize,
+            Self::JsxA11YAriaActivedescendantHasTabindex(_) => 528usize,
+            Self::JsxA11YAriaProps(_) => 529usize,
+            Self::JsxA11YAriaProptypes(_) => 530usize,
+            Self::JsxA11YAriaRole(_) => 531usize,
+            Self::JsxA11YAriaUnsupportedElements(_) => 532usize,
+            Self::JsxA11YAutocompleteValid(_) => 533usize,
+            Self::JsxA11YClickEventsHaveKeyEvents(_) => 534usize,
+            Self::JsxA11YHeadingHasContent(_) => 535usize,
+            Self::JsxA11YHtmlHasLang(_) => 536usize,
+            Self::JsxA11YIframeHasTitle(_) => 537usize,
+            Self::JsxA11YImgRedundantAlt(_) => 538usize,
+            Self::JsxA11YLabelHasAssociatedControl(_) => 539usize,
+            Self::JsxA11YLang(_) => 540usize,
+            Self::JsxA11YMediaHasCaption(_) => 541usize,
+            Self::JsxA11YMouseEventsHaveKeyEvents(_) => 542usize,
+            Self::JsxA11YNoAccessKey(_) => 543usize,
+            Self::JsxA11YNoAriaHiddenOnFocusable(_) => 544usize,
+            Self::JsxA11YNoAutofocus(_) => 545usize,
+            Self::JsxA11YNoDistractingElements(_) => 546usize,
+            Self::JsxA11YNoNoninteractiveTabindex(_) => 547usize,
+            Self::JsxA11YNoRedundantRoles(_) => 548usize,
+            Self::JsxA11YNoStaticElementInteractions(_) => 549usize,
+            Self::JsxA11YPreferTagOverRole(_) => 550usize,
+            Self::JsxA11YRoleHasRequiredAriaProps(_) => 551usize,
+            Self::JsxA11YRoleSupportsAriaProps(_) => 552usize,
+            Self::JsxA11YScope(_) => 553usize,
+            Self::JsxA11YTabindexNoPositive(_) => 554usize,
+            Self::OxcApproxConstant(_) => 555usize,
+            Self::OxcBadArrayMethodOnArguments(_) => 556usize,
+            Self::OxcBadBitwiseOperator(_) => 557usize,
+            Self::OxcBadCharAtComparison(_) => 558usize,
+            Self::OxcBadComparisonSequence(_) => 559usize,
+            Self::OxcBadMinMaxFunc(_) => 560usize,
+            Self::OxcBadObjectLiteralComparison(_) => 561usize,
+            Self::OxcBadReplaceAllArg(_) => 562usize,
+            Self::OxcBranchesSharingCode(_) => 563usize,
+            Self::OxcConstComparisons(_) => 564usize,
+            Self::OxcDoubleComparisons(_) => 565usize,
+            Self::OxcErasingOp(_) => 566usize,
+            Self::OxcMisrefactoredAssignOp(_) => 567usize,
+            Self::OxcMissingThrow(_) => 568usize,
+            Self::OxcNoAccumulatingSpread(_) => 569usize,
+            Self::OxcNoAsyncAwait(_) => 570usize,
+            Self::OxcNoAsyncEndpointHandlers(_) => 571usize,
+            Self::OxcNoBarrelFile(_) => 572usize,
+            Self::OxcNoConstEnum(_) => 573usize,
+            Self::OxcNoMapSpread(_) => 574usize,
+            Self::OxcNoOptionalChaining(_) => 575usize,
+            Self::OxcNoRestSpreadProperties(_) => 576usize,
+            Self::OxcNoThisInExportedFunction(_) => 577usize,
+            Self::OxcNumberArgOutOfRange(_) => 578usize,
+            Self::OxcOnlyUsedInRecursion(_) => 579usize,
+            Self::OxcUninvokedArrayCallback(_) => 580usize,
+            Self::NextjsGoogleFontDisplay(_) => 581usize,
+            Self::NextjsGoogleFontPreconnect(_) => 582usize,
+            Self::NextjsInlineScriptId(_) => 583usize,
+            Self::NextjsNextScriptForGa(_) => 584usize,
+            Self::NextjsNoAssignModuleVariable(_) => 585usize,
+            Self::NextjsNoAsyncClientComponent(_) => 586usize,
+            Self::NextjsNoBeforeInteractiveScriptOutsideDocument(_) => 587usize,
+            Self::NextjsNoCssTags(_) => 588usize,
+            Self::NextjsNoDocumentImportInPage(_) => 589usize,
+            Self::NextjsNoDuplicateHead(_) => 590usize,
+            Self::NextjsNoHeadElement(_) => 591usize,
+            Self::NextjsNoHeadImportInDocument(_) => 592usize,
+            Self::NextjsNoHtmlLinkForPages(_) => 593usize,
+            Self::NextjsNoImgElement(_) => 594usize,
+            Self::NextjsNoPageCustomFont(_) => 595usize,
+            Self::NextjsNoScriptComponentInHead(_) => 596usize,
+            Self::NextjsNoStyledJsxInDocument(_) => 597usize,
+            Self::NextjsNoSyncScripts(_) => 598usize,
+            Self::NextjsNoTitleInDocumentHead(_) => 599usize,
+            Self::NextjsNoTypos(_) => 600usize,
+            Self::NextjsNoUnwantedPolyfillio(_) => 601usize,
+            Self::JsdocCheckAccess(_) => 602usize,
+            Self::JsdocCheckPropertyNames(_) => 603usize,
+            Self::JsdocCheckTagNames(_) => 604usize,
+            Self::JsdocEmptyTags(_) => 605usize,
+            Self::JsdocImplementsOnClasses(_) => 606usize,
+            Self::JsdocNoDefaults(_) => 607usize,
+            Self::JsdocRequireParam(_) => 608usize,
+            Self::JsdocRequireParamDescription(_) => 609usize,
+            Self::JsdocRequireParamName(_) => 610usize,
+            Self::JsdocRequireParamType(_) => 611usize,
+            Self::JsdocRequireProperty(_) => 612usize,
+            Self::JsdocRequirePropertyDescription(_) => 613usize,
+            Self::JsdocRequirePropertyName(_) => 614usize,
+            Self::JsdocRequirePropertyType(_) => 615usize,
+            Self::JsdocRequireReturns(_) => 616usize,
+            Self::JsdocRequireReturnsDescription(_) => 617usize,
+            Self::JsdocRequireReturnsType(_) => 618usize,
+            Self::JsdocRequireYields(_) => 619usize,
+            Self::PromiseAlwaysReturn(_) => 620usize,
+            Self::PromiseAvoidNew(_) => 621usize,
+            Self::PromiseCatchOrReturn(_) => 622usize,
+            Self::PromiseNoCallbackInPromise(_) => 623usize,
+            Self::PromiseNoMultipleResolved(_) => 624usize,
+            Self::PromiseNoNesting(_) => 625usize,
+            Self::PromiseNoNewStatics(_) => 626usize,
+            Self::PromiseNoPromiseInCallback(_) => 627usize,
+            Self::PromiseNoReturnInFinally(_) => 628usize,
+            Self::PromiseNoReturnWrap(_) => 629usize,
+            Self::PromiseParamNames(_) => 630usize,
+            Self::PromisePreferAwaitToCallbacks(_) => 631usize,
+            Self::PromisePreferAwaitToThen(_) => 632usize,
+            Self::PromisePreferCatch(_) => 633usize,
+            Self::PromiseSpecOnly(_) => 634usize,
+            Self::PromiseValidParams(_) => 635usize,
+            Self::VitestConsistentEachFor(_) => 636usize,
+            Self::VitestConsistentTestFilename(_) => 637usize,
+            Self::VitestConsistentVitestVi(_) => 638usize,
+            Self::VitestHoistedApisOnTop(_) => 639usize,
+            Self::VitestNoConditionalTests(_) => 640usize,
+            Self::VitestNoImportNodeTest(_) => 641usize,
+            Self::VitestNoUnneededAsyncExpectFunction(_) => 642usize,
+            Self::VitestPreferCalledOnce(_) => 643usize,
+            Self::VitestPreferCalledTimes(_) => 644usize,
+            Self::VitestPreferDescribeFunctionTitle(_) => 645usize,
+            Self::VitestPreferToBeFalsy(_) => 646usize,
+            Self::VitestPreferToBeObject(_) => 647usize,
+            Self::VitestPreferToBeTruthy(_) => 648usize,
+            Self::VitestRequireLocalTestContextForConcurrentSnapshots(_) => 649usize,
+            Self::VitestWarnTodo(_) => 650usize,
+            Self::NodeGlobalRequire(_) => 651usize,
+            Self::NodeNoExportsAssign(_) => 652usize,
+            Self::NodeNoNewRequire(_) => 653usize,
+            Self::NodeNoProcessEnv(_) => 654usize,
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(_) => 655usize,
+            Self::NodeNoUnsupportedFeaturesEsSyntax(_) => 656usize,
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => 657usize,
+            Self::VueDefineEmitsDeclaration(_) => 658usize,
+            Self::VueDefinePropsDeclaration(_) => 659usize,
+            Self::VueDefinePropsDestructuring(_) => 660usize,
+            Self::VueMaxProps(_) => 661usize,
+            Self::VueNoArrowFunctionsInWatch(_) => 662usize,
+            Self::VueNoDeprecatedDestroyedLifecycle(_) => 663usize,
+            Self::VueNoExportInScriptSetup(_) => 664usize,
+            Self::VueNoImportCompilerMacros(_) => 665usize,
+            Self::VueNoLifecycleAfterAwait(_) => 666usize,
+            Self::VueNoMultipleSlotArgs(_) => 667usize,
+            Self::VueNoRequiredPropWithDefault(_) => 668usize,
+            Self::VueNoThisInBeforeRouteEnter(_) => 669usize,
+            Self::VuePreferImportFromVue(_) => 670usize,
+            Self::VueRequireDefaultExport(_) => 671usize,
+            Self::VueRequireTypedRef(_) => 672usize,
+            Self::VueValidDefineEmits(_) => 673usize,
+            Self::VueValidDefineProps(_) => 674usize,
         }
     }
     pub fn name(&self) -> &'static str {
@@ -2037,6 +2070,7 @@ impl RuleEnum {
             Self::ImportNoDuplicates(_) => ImportNoDuplicates::NAME,
             Self::ImportNoDynamicRequire(_) => ImportNoDynamicRequire::NAME,
             Self::ImportNoEmptyNamedBlocks(_) => ImportNoEmptyNamedBlocks::NAME,
+            Self::ImportNoExtraneousDependencies(_) => ImportNoExtraneousDependencies::NAME,
             Self::ImportNoMutableExports(_) => ImportNoMutableExports::NAME,
             Self::ImportNoNamedAsDefault(_) => ImportNoNamedAsDefault::NAME,
             Self::ImportNoNamedAsDefaultMember(_) => ImportNoNamedAsDefaultMember::NAME,
@@ -2047,7 +2081,10 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(_) => ImportNoRelativeParentImports::NAME,
             Self::ImportNoSelfImport(_) => ImportNoSelfImport::NAME,
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::NAME,
+            Self::ImportNoUnresolved(_) => ImportNoUnresolved::NAME,
+            Self::ImportNoUnusedModules(_) => ImportNoUnusedModules::NAME,
             Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::NAME,
+            Self::ImportOrder(_) => ImportOrder::NAME,
             Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::NAME,
             Self::ImportUnambiguous(_) => ImportUnambiguous::NAME,
             Self::EslintAccessorPairs(_) => EslintAccessorPairs::NAME,
@@ -2155,6 +2192,7 @@ impl RuleEnum {
             Self::EslintNoRegexSpaces(_) => EslintNoRegexSpaces::NAME,
             Self::EslintNoRestrictedGlobals(_) => EslintNoRestrictedGlobals::NAME,
             Self::EslintNoRestrictedImports(_) => EslintNoRestrictedImports::NAME,
+            Self::EslintNoRestrictedSyntax(_) => EslintNoRestrictedSyntax::NAME,
             Self::EslintNoReturnAssign(_) => EslintNoReturnAssign::NAME,
             Self::EslintNoScriptUrl(_) => EslintNoScriptUrl::NAME,
             Self::EslintNoSelfAssign(_) => EslintNoSelfAssign::NAME,
@@ -2173,6 +2211,7 @@ impl RuleEnum {
             Self::EslintNoUnexpectedMultiline(_) => EslintNoUnexpectedMultiline::NAME,
             Self::EslintNoUnneededTernary(_) => EslintNoUnneededTernary::NAME,
             Self::EslintNoUnreachable(_) => EslintNoUnreachable::NAME,
+            Self::EslintNoUnreachableLoop(_) => EslintNoUnreachableLoop::NAME,
             Self::EslintNoUnsafeFinally(_) => EslintNoUnsafeFinally::NAME,
             Self::EslintNoUnsafeNegation(_) => EslintNoUnsafeNegation::NAME,
             Self::EslintNoUnsafeOptionalChaining(_) => EslintNoUnsafeOptionalChaining::NAME,
@@ -2180,6 +2219,7 @@ impl RuleEnum {
             Self::EslintNoUnusedLabels(_) => EslintNoUnusedLabels::NAME,
             Self::EslintNoUnusedPrivateClassMembers(_) => EslintNoUnusedPrivateClassMembers::NAME,
             Self::EslintNoUnusedVars(_) => EslintNoUnusedVars::NAME,
+            Self::EslintNoUselessAssignment(_) => EslintNoUselessAssignment::NAME,
             Self::EslintNoUselessBackreference(_) => EslintNoUselessBackreference::NAME,
             Self::EslintNoUselessCall(_) => EslintNoUselessCall::NAME,
             Self::EslintNoUselessCatch(_) => EslintNoUselessCatch::NAME,
@@ -2241,6 +2281,7 @@ impl RuleEnum {
             Self::TypescriptExplicitModuleBoundaryTypes(_) => {
                 TypescriptExplicitModuleBoundaryTypes::NAME
             }
+            Self::TypescriptNamingConvention(_) => TypescriptNamingConvention::NAME,
             Self::TypescriptNoArrayDelete(_) => TypescriptNoArrayDelete::NAME,
             Self::TypescriptNoBaseToString(_) => TypescriptNoBaseToString::NAME,
             Self::TypescriptNoConfusingNonNullAssertion(_) => {
@@ -2751,6 +2792,13 @@ impl RuleEnum {
             Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::NAME,
             Self::NodeNoNewRequire(_) => NodeNoNewRequire::NAME,
             Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::NAME,
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(_) => {
+                NodeNoUnsupportedFeaturesEsBuiltins::NAME
+            }
+            Self::NodeNoUnsupportedFeaturesEsSyntax(_) => NodeNoUnsupportedFeaturesEsSyntax::NAME,
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::NAME
+            }
             Self::VueDefineEmitsDeclaration(_) => VueDefineEmitsDeclaration::NAME,
             Self::VueDefinePropsDeclaration(_) => VueDefinePropsDeclaration::NAME,
             Self::VueDefinePropsDestructuring(_) => VueDefinePropsDestructuring::NAME,
@@ -2793,6 +2841,7 @@ impl RuleEnum {
             Self::ImportNoDuplicates(_) => ImportNoDuplicates::CATEGORY,
             Self::ImportNoDynamicRequire(_) => ImportNoDynamicRequire::CATEGORY,
             Self::ImportNoEmptyNamedBlocks(_) => ImportNoEmptyNamedBlocks::CATEGORY,
+            Self::ImportNoExtraneousDependencies(_) => ImportNoExtraneousDependencies::CATEGORY,
             Self::ImportNoMutableExports(_) => ImportNoMutableExports::CATEGORY,
             Self::ImportNoNamedAsDefault(_) => ImportNoNamedAsDefault::CATEGORY,
             Self::ImportNoNamedAsDefaultMember(_) => ImportNoNamedAsDefaultMember::CATEGORY,
@@ -2803,7 +2852,10 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(_) => ImportNoRelativeParentImports::CATEGORY,
             Self::ImportNoSelfImport(_) => ImportNoSelfImport::CATEGORY,
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::CATEGORY,
+            Self::ImportNoUnresolved(_) => ImportNoUnresolved::CATEGORY,
+            Self::ImportNoUnusedModules(_) => ImportNoUnusedModules::CATEGORY,
             Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::CATEGORY,
+            Self::ImportOrder(_) => ImportOrder::CATEGORY,
             Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::CATEGORY,
             Self::ImportUnambiguous(_) => ImportUnambiguous::CATEGORY,
             Self::EslintAccessorPairs(_) => EslintAccessorPairs::CATEGORY,
@@ -2911,6 +2963,7 @@ impl RuleEnum {
             Self::EslintNoRegexSpaces(_) => EslintNoRegexSpaces::CATEGORY,
             Self::EslintNoRestrictedGlobals(_) => EslintNoRestrictedGlobals::CATEGORY,
             Self::EslintNoRestrictedImports(_) => EslintNoRestrictedImports::CATEGORY,
+            Self::EslintNoRestrictedSyntax(_) => EslintNoRestrictedSyntax::CATEGORY,
             Self::EslintNoReturnAssign(_) => EslintNoReturnAssign::CATEGORY,
             Self::EslintNoScriptUrl(_) => EslintNoScriptUrl::CATEGORY,
             Self::EslintNoSelfAssign(_) => EslintNoSelfAssign::CATEGORY,
@@ -2929,6 +2982,7 @@ impl RuleEnum {
             Self::EslintNoUnexpectedMultiline(_) => EslintNoUnexpectedMultiline::CATEGORY,
             Self::EslintNoUnneededTernary(_) => EslintNoUnneededTernary::CATEGORY,
             Self::EslintNoUnreachable(_) => EslintNoUnreachable::CATEGORY,
+            Self::EslintNoUnreachableLoop(_) => EslintNoUnreachableLoop::CATEGORY,
             Self::EslintNoUnsafeFinally(_) => EslintNoUnsafeFinally::CATEGORY,
             Self::EslintNoUnsafeNegation(_) => EslintNoUnsafeNegation::CATEGORY,
             Self::EslintNoUnsafeOptionalChaining(_) => EslintNoUnsafeOptionalChaining::CATEGORY,
@@ -2938,6 +2992,7 @@ impl RuleEnum {
                 EslintNoUnusedPrivateClassMembers::CATEGORY
             }
             Self::EslintNoUnusedVars(_) => EslintNoUnusedVars::CATEGORY,
+            Self::EslintNoUselessAssignment(_) => EslintNoUselessAssignment::CATEGORY,
             Self::EslintNoUselessBackreference(_) => EslintNoUselessBackreference::CATEGORY,
             Self::EslintNoUselessCall(_) => EslintNoUselessCall::CATEGORY,
             Self::EslintNoUselessCatch(_) => EslintNoUselessCatch::CATEGORY,
@@ -3001,6 +3056,7 @@ impl RuleEnum {
             Self::TypescriptExplicitModuleBoundaryTypes(_) => {
                 TypescriptExplicitModuleBoundaryTypes::CATEGORY
             }
+            Self::TypescriptNamingConvention(_) => TypescriptNamingConvention::CATEGORY,
             Self::TypescriptNoArrayDelete(_) => TypescriptNoArrayDelete::CATEGORY,
             Self::TypescriptNoBaseToString(_) => TypescriptNoBaseToString::CATEGORY,
             Self::TypescriptNoConfusingNonNullAssertion(_) => {
@@ -3543,6 +3599,15 @@ impl RuleEnum {
             Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::CATEGORY,
             Self::NodeNoNewRequire(_) => NodeNoNewRequire::CATEGORY,
             Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::CATEGORY,
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(_) => {
+                NodeNoUnsupportedFeaturesEsBuiltins::CATEGORY
+            }
+            Self::NodeNoUnsupportedFeaturesEsSyntax(_) => {
+                NodeNoUnsupportedFeaturesEsSyntax::CATEGORY
+            }
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::CATEGORY
+            }
             Self::VueDefineEmitsDeclaration(_) => VueDefineEmitsDeclaration::CATEGORY,
             Self::VueDefinePropsDeclaration(_) => VueDefinePropsDeclaration::CATEGORY,
             Self::VueDefinePropsDestructuring(_) => VueDefinePropsDestructuring::CATEGORY,
@@ -3586,6 +3651,7 @@ impl RuleEnum {
             Self::ImportNoDuplicates(_) => ImportNoDuplicates::FIX,
             Self::ImportNoDynamicRequire(_) => ImportNoDynamicRequire::FIX,
             Self::ImportNoEmptyNamedBlocks(_) => ImportNoEmptyNamedBlocks::FIX,
+            Self::ImportNoExtraneousDependencies(_) => ImportNoExtraneousDependencies::FIX,
             Self::ImportNoMutableExports(_) => ImportNoMutableExports::FIX,
             Self::ImportNoNamedAsDefault(_) => ImportNoNamedAsDefault::FIX,
             Self::ImportNoNamedAsDefaultMember(_) => ImportNoNamedAsDefaultMember::FIX,
@@ -3596,7 +3662,10 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(_) => ImportNoRelativeParentImports::FIX,
             Self::ImportNoSelfImport(_) => ImportNoSelfImport::FIX,
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::FIX,
+            Self::ImportNoUnresolved(_) => ImportNoUnresolved::FIX,
+            Self::ImportNoUnusedModules(_) => ImportNoUnusedModules::FIX,
             Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::FIX,
+            Self::ImportOrder(_) => ImportOrder::FIX,
             Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::FIX,
             Self::ImportUnambiguous(_) => ImportUnambiguous::FIX,
             Self::EslintAccessorPairs(_) => EslintAccessorPairs::FIX,
@@ -3704,6 +3773,7 @@ impl RuleEnum {
             Self::EslintNoRegexSpaces(_) => EslintNoRegexSpaces::FIX,
             Self::EslintNoRestrictedGlobals(_) => EslintNoRestrictedGlobals::FIX,
             Self::EslintNoRestrictedImports(_) => EslintNoRestrictedImports::FIX,
+            Self::EslintNoRestrictedSyntax(_) => EslintNoRestrictedSyntax::FIX,
             Self::EslintNoReturnAssign(_) => EslintNoReturnAssign::FIX,
             Self::EslintNoScriptUrl(_) => EslintNoScriptUrl::FIX,
             Self::EslintNoSelfAssign(_) => EslintNoSelfAssign::FIX,
@@ -3722,6 +3792,7 @@ impl RuleEnum {
             Self::EslintNoUnexpectedMultiline(_) => EslintNoUnexpectedMultiline::FIX,
             Self::EslintNoUnneededTernary(_) => EslintNoUnneededTernary::FIX,
             Self::EslintNoUnreachable(_) => EslintNoUnreachable::FIX,
+            Self::EslintNoUnreachableLoop(_) => EslintNoUnreachableLoop::FIX,
             Self::EslintNoUnsafeFinally(_) => EslintNoUnsafeFinally::FIX,
             Self::EslintNoUnsafeNegation(_) => EslintNoUnsafeNegation::FIX,
             Self::EslintNoUnsafeOptionalChaining(_) => EslintNoUnsafeOptionalChaining::FIX,
@@ -3729,6 +3800,7 @@ impl RuleEnum {
             Self::EslintNoUnusedLabels(_) => EslintNoUnusedLabels::FIX,
             Self::EslintNoUnusedPrivateClassMembers(_) => EslintNoUnusedPrivateClassMembers::FIX,
             Self::EslintNoUnusedVars(_) => EslintNoUnusedVars::FIX,
+            Self::EslintNoUselessAssignment(_) => EslintNoUselessAssignment::FIX,
             Self::EslintNoUselessBackreference(_) => EslintNoUselessBackreference::FIX,
             Self::EslintNoUselessCall(_) => EslintNoUselessCall::FIX,
             Self::EslintNoUselessCatch(_) => EslintNoUselessCatch::FIX,
@@ -3790,6 +3862,7 @@ impl RuleEnum {
             Self::TypescriptExplicitModuleBoundaryTypes(_) => {
                 TypescriptExplicitModuleBoundaryTypes::FIX
             }
+            Self::TypescriptNamingConvention(_) => TypescriptNamingConvention::FIX,
             Self::TypescriptNoArrayDelete(_) => TypescriptNoArrayDelete::FIX,
             Self::TypescriptNoBaseToString(_) => TypescriptNoBaseToString::FIX,
             Self::TypescriptNoConfusingNonNullAssertion(_) => {
@@ -4300,6 +4373,13 @@ impl RuleEnum {
             Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::FIX,
             Self::NodeNoNewRequire(_) => NodeNoNewRequire::FIX,
             Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::FIX,
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(_) => {
+                NodeNoUnsupportedFeaturesEsBuiltins::FIX
+            }
+            Self::NodeNoUnsupportedFeaturesEsSyntax(_) => NodeNoUnsupportedFeaturesEsSyntax::FIX,
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::FIX
+            }
             Self::VueDefineEmitsDeclaration(_) => VueDefineEmitsDeclaration::FIX,
             Self::VueDefinePropsDeclaration(_) => VueDefinePropsDeclaration::FIX,
             Self::VueDefinePropsDestructuring(_) => VueDefinePropsDestructuring::FIX,
@@ -4345,6 +4425,9 @@ impl RuleEnum {
             Self::ImportNoDuplicates(_) => ImportNoDuplicates::documentation(),
             Self::ImportNoDynamicRequire(_) => ImportNoDynamicRequire::documentation(),
             Self::ImportNoEmptyNamedBlocks(_) => ImportNoEmptyNamedBlocks::documentation(),
+            Self::ImportNoExtraneousDependencies(_) => {
+                ImportNoExtraneousDependencies::documentation()
+            }
             Self::ImportNoMutableExports(_) => ImportNoMutableExports::documentation(),
             Self::ImportNoNamedAsDefault(_) => ImportNoNamedAsDefault::documentation(),
             Self::ImportNoNamedAsDefaultMember(_) => ImportNoNamedAsDefaultMember::documentation(),
@@ -4357,7 +4440,10 @@ impl RuleEnum {
             }
             Self::ImportNoSelfImport(_) => ImportNoSelfImport::documentation(),
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::documentation(),
+            Self::ImportNoUnresolved(_) => ImportNoUnresolved::documentation(),
+            Self::ImportNoUnusedModules(_) => ImportNoUnusedModules::documentation(),
             Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::documentation(),
+            Self::ImportOrder(_) => ImportOrder::documentation(),
             Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::documentation(),
             Self::ImportUnambiguous(_) => ImportUnambiguous::documentation(),
             Self::EslintAccessorPairs(_) => EslintAccessorPairs::documentation(),
@@ -4475,6 +4561,7 @@ impl RuleEnum {
             Self::EslintNoRegexSpaces(_) => EslintNoRegexSpaces::documentation(),
             Self::EslintNoRestrictedGlobals(_) => EslintNoRestrictedGlobals::documentation(),
             Self::EslintNoRestrictedImports(_) => EslintNoRestrictedImports::documentation(),
+            Self::EslintNoRestrictedSyntax(_) => EslintNoRestrictedSyntax::documentation(),
             Self::EslintNoReturnAssign(_) => EslintNoReturnAssign::documentation(),
             Self::EslintNoScriptUrl(_) => EslintNoScriptUrl::documentation(),
             Self::EslintNoSelfAssign(_) => EslintNoSelfAssign::documentation(),
@@ -4497,6 +4584,7 @@ impl RuleEnum {
             Self::EslintNoUnexpectedMultiline(_) => EslintNoUnexpectedMultiline::documentation(),
             Self::EslintNoUnneededTernary(_) => EslintNoUnneededTernary::documentation(),
             Self::EslintNoUnreachable(_) => EslintNoUnreachable::documentation(),
+            Self::EslintNoUnreachableLoop(_) => EslintNoUnreachableLoop::documentation(),
             Self::EslintNoUnsafeFinally(_) => EslintNoUnsafeFinally::documentation(),
             Self::EslintNoUnsafeNegation(_) => EslintNoUnsafeNegation::documentation(),
             Self::EslintNoUnsafeOptionalChaining(_) => {
@@ -4508,6 +4596,7 @@ impl RuleEnum {
                 EslintNoUnusedPrivateClassMembers::documentation()
             }
             Self::EslintNoUnusedVars(_) => EslintNoUnusedVars::documentation(),
+            Self::EslintNoUselessAssignment(_) => EslintNoUselessAssignment::documentation(),
             Self::EslintNoUselessBackreference(_) => EslintNoUselessBackreference::documentation(),
             Self::EslintNoUselessCall(_) => EslintNoUselessCall::documentation(),
             Self::EslintNoUselessCatch(_) => EslintNoUselessCatch::documentation(),
@@ -4575,6 +4664,7 @@ impl RuleEnum {
             Self::TypescriptExplicitModuleBoundaryTypes(_) => {
                 TypescriptExplicitModuleBoundaryTypes::documentation()
             }
+            Self::TypescriptNamingConvention(_) => TypescriptNamingConvention::documentation(),
             Self::TypescriptNoArrayDelete(_) => TypescriptNoArrayDelete::documentation(),
             Self::TypescriptNoBaseToString(_) => TypescriptNoBaseToString::documentation(),
             Self::TypescriptNoConfusingNonNullAssertion(_) => {
@@ -5231,6 +5321,15 @@ impl RuleEnum {
             Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::documentation(),
             Self::NodeNoNewRequire(_) => NodeNoNewRequire::documentation(),
             Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::documentation(),
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(_) => {
+                NodeNoUnsupportedFeaturesEsBuiltins::documentation()
+            }
+            Self::NodeNoUnsupportedFeaturesEsSyntax(_) => {
+                NodeNoUnsupportedFeaturesEsSyntax::documentation()
+            }
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::documentation()
+            }
             Self::VueDefineEmitsDeclaration(_) => VueDefineEmitsDeclaration::documentation(),
             Self::VueDefinePropsDeclaration(_) => VueDefinePropsDeclaration::documentation(),
             Self::VueDefinePropsDestructuring(_) => VueDefinePropsDestructuring::documentation(),
@@ -5306,6 +5405,10 @@ impl RuleEnum {
                 .or_else(|| ImportNoDynamicRequire::schema(generator)),
             Self::ImportNoEmptyNamedBlocks(_) => ImportNoEmptyNamedBlocks::config_schema(generator)
                 .or_else(|| ImportNoEmptyNamedBlocks::schema(generator)),
+            Self::ImportNoExtraneousDependencies(_) => {
+                ImportNoExtraneousDependencies::config_schema(generator)
+                    .or_else(|| ImportNoExtraneousDependencies::schema(generator))
+            }
             Self::ImportNoMutableExports(_) => ImportNoMutableExports::config_schema(generator)
                 .or_else(|| ImportNoMutableExports::schema(generator)),
             Self::ImportNoNamedAsDefault(_) => ImportNoNamedAsDefault::config_schema(generator)
@@ -5330,10 +5433,17 @@ impl RuleEnum {
                 .or_else(|| ImportNoSelfImport::schema(generator)),
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::config_schema(generator)
                 .or_else(|| ImportNoUnassignedImport::schema(generator)),
+            Self::ImportNoUnresolved(_) => ImportNoUnresolved::config_schema(generator)
+                .or_else(|| ImportNoUnresolved::schema(generator)),
+            Self::ImportNoUnusedModules(_) => ImportNoUnusedModules::config_schema(generator)
+                .or_else(|| ImportNoUnusedModules::schema(generator)),
             Self::ImportNoWebpackLoaderSyntax(_) => {
                 ImportNoWebpackLoaderSyntax::config_schema(generator)
                     .or_else(|| ImportNoWebpackLoaderSyntax::schema(generator))
             }
+            Self::ImportOrder(_) => {
+                ImportOrder::config_schema(generator).or_else(|| ImportOrder::schema(generator))
+            }
             Self::ImportPreferDefaultExport(_) => {
                 ImportPreferDefaultExport::config_schema(generator)
                     .or_else(|| ImportPreferDefaultExport::schema(generator))
@@ -5598,6 +5708,8 @@ impl RuleEnum {
                 EslintNoRestrictedImports::config_schema(generator)
                     .or_else(|| EslintNoRestrictedImports::schema(generator))
             }
+            Self::EslintNoRestrictedSyntax(_) => EslintNoRestrictedSyntax::config_schema(generator)
+                .or_else(|| EslintNoRestrictedSyntax::schema(generator)),
             Self::EslintNoReturnAssign(_) => EslintNoReturnAssign::config_schema(generator)
                 .or_else(|| EslintNoReturnAssign::schema(generator)),
             Self::EslintNoScriptUrl(_) => EslintNoScriptUrl::config_schema(generator)
@@ -5641,6 +5753,8 @@ impl RuleEnum {
                 .or_else(|| EslintNoUnneededTernary::schema(generator)),
             Self::EslintNoUnreachable(_) => EslintNoUnreachable::config_schema(generator)
                 .or_else(|| EslintNoUnreachable::schema(generator)),
+            Self::EslintNoUnreachableLoop(_) => EslintNoUnreachableLoop::config_schema(generator)
+                .or_else(|| EslintNoUnreachableLoop::schema(generator)),
             Self::EslintNoUnsafeFinally(_) => EslintNoUnsafeFinally::config_schema(generator)
                 .or_else(|| EslintNoUnsafeFinally::schema(generator)),
             Self::EslintNoUnsafeNegation(_) => EslintNoUnsafeNegation::config_schema(generator)
@@ -5661,6 +5775,10 @@ impl RuleEnum {
             }
             Self::EslintNoUnusedVars(_) => EslintNoUnusedVars::config_schema(generator)
                 .or_else(|| EslintNoUnusedVars::schema(generator)),
+            Self::EslintNoUselessAssignment(_) => {
+                EslintNoUselessAssignment::config_schema(generator)
+                    .or_else(|| EslintNoUselessAssignment::schema(generator))
+            }
             Self::EslintNoUselessBackreference(_) => {
                 EslintNoUselessBackreference::config_schema(generator)
                     .or_else(|| EslintNoUselessBackreference::schema(generator))
@@ -5796,6 +5914,10 @@ impl RuleEnum {
                 TypescriptExplicitModuleBoundaryTypes::config_schema(generator)
                     .or_else(|| TypescriptExplicitModuleBoundaryTypes::schema(generator))
             }
+            Self::TypescriptNamingConvention(_) => {
+                TypescriptNamingConvention::config_schema(generator)
+                    .or_else(|| TypescriptNamingConvention::schema(generator))
+            }
             Self::TypescriptNoArrayDelete(_) => TypescriptNoArrayDelete::config_schema(generator)
                 .or_else(|| TypescriptNoArrayDelete::schema(generator)),
             Self::TypescriptNoBaseToString(_) => TypescriptNoBaseToString::config_schema(generator)
@@ -7076,6 +7198,18 @@ impl RuleEnum {
                 .or_else(|| NodeNoNewRequire::schema(generator)),
             Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::config_schema(generator)
                 .or_else(|| NodeNoProcessEnv::schema(generator)),
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(_) => {
+                NodeNoUnsupportedFeaturesEsBuiltins::config_schema(generator)
+                    .or_else(|| NodeNoUnsupportedFeaturesEsBuiltins::schema(generator))
+            }
+            Self::NodeNoUnsupportedFeaturesEsSyntax(_) => {
+                NodeNoUnsupportedFeaturesEsSyntax::config_schema(generator)
+                    .or_else(|| NodeNoUnsupportedFeaturesEsSyntax::schema(generator))
+            }
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::config_schema(generator)
+                    .or_else(|| NodeNoUnsupportedFeaturesNodeBuiltins::schema(generator))
+            }
             Self::VueDefineEmitsDeclaration(_) => {
                 VueDefineEmitsDeclaration::config_schema(generator)
                     .or_else(|| VueDefineEmitsDeclaration::schema(generator))
@@ -7150,6 +7284,7 @@ impl RuleEnum {
             Self::ImportNoDuplicates(_) => "import",
             Self::ImportNoDynamicRequire(_) => "import",
             Self::ImportNoEmptyNamedBlocks(_) => "import",
+            Self::ImportNoExtraneousDependencies(_) => "import",
             Self::ImportNoMutableExports(_) => "import",
             Self::ImportNoNamedAsDefault(_) => "import",
             Self::ImportNoNamedAsDefaultMember(_) => "import",
@@ -7160,7 +7295,10 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(_) => "import",
             Self::ImportNoSelfImport(_) => "import",
             Self::ImportNoUnassignedImport(_) => "import",
+            Self::ImportNoUnresolved(_) => "import",
+            Self::ImportNoUnusedModules(_) => "import",
             Self::ImportNoWebpackLoaderSyntax(_) => "import",
+            Self::ImportOrder(_) => "import",
             Self::ImportPreferDefaultExport(_) => "import",
             Self::ImportUnambiguous(_) => "import",
             Self::EslintAccessorPairs(_) => "eslint",
@@ -7268,6 +7406,7 @@ impl RuleEnum {
             Self::EslintNoRegexSpaces(_) => "eslint",
             Self::EslintNoRestrictedGlobals(_) => "eslint",
             Self::EslintNoRestrictedImports(_) => "eslint",
+            Self::EslintNoRestrictedSyntax(_) => "eslint",
             Self::EslintNoReturnAssign(_) => "eslint",
             Self::EslintNoScriptUrl(_) => "eslint",
             Self::EslintNoSelfAssign(_) => "eslint",
@@ -7286,6 +7425,7 @@ impl RuleEnum {
             Self::EslintNoUnexpectedMultiline(_) => "eslint",
             Self::EslintNoUnneededTernary(_) => "eslint",
             Self::EslintNoUnreachable(_) => "eslint",
+            Self::EslintNoUnreachableLoop(_) => "eslint",
             Self::EslintNoUnsafeFinally(_) => "eslint",
             Self::EslintNoUnsafeNegation(_) => "eslint",
             Self::EslintNoUnsafeOptionalChaining(_) => "eslint",
@@ -7293,6 +7433,7 @@ impl RuleEnum {
             Self::EslintNoUnusedLabels(_) => "eslint",
             Self::EslintNoUnusedPrivateClassMembers(_) => "eslint",
             Self::EslintNoUnusedVars(_) => "eslint",
+            Self::EslintNoUselessAssignment(_) => "eslint",
             Self::EslintNoUselessBackreference(_) => "eslint",
             Self::EslintNoUselessCall(_) => "eslint",
             Self::EslintNoUselessCatch(_) => "eslint",
@@ -7342,6 +7483,7 @@ impl RuleEnum {
             Self::TypescriptConsistentTypeImports(_) => "typescript",
             Self::TypescriptExplicitFunctionReturnType(_) => "typescript",
             Self::TypescriptExplicitModuleBoundaryTypes(_) => "typescript",
+            Self::TypescriptNamingConvention(_) => "typescript",
             Self::TypescriptNoArrayDelete(_) => "typescript",
             Self::TypescriptNoBaseToString(_) => "typescript",
             Self::TypescriptNoConfusingNonNullAssertion(_) => "typescript",
@@ -7778,6 +7920,9 @@ impl RuleEnum {
             Self::NodeNoExportsAssign(_) => "node",
             Self::NodeNoNewRequire(_) => "node",
             Self::NodeNoProcessEnv(_) => "node",
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(_) => "node",
+            Self::NodeNoUnsupportedFeaturesEsSyntax(_) => "node",
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => "node",
             Self::VueDefineEmitsDeclaration(_) => "vue",
             Self::VueDefinePropsDeclaration(_) => "vue",
             Self::VueDefinePropsDestructuring(_) => "vue",
@@ -7855,6 +8000,9 @@ impl RuleEnum {
             Self::ImportNoEmptyNamedBlocks(_) => Ok(Self::ImportNoEmptyNamedBlocks(
                 ImportNoEmptyNamedBlocks::from_configuration(value)?,
             )),
+            Self::ImportNoExtraneousDependencies(_) => Ok(Self::ImportNoExtraneousDependencies(
+                ImportNoExtraneousDependencies::from_configuration(value)?,
+            )),
             Self::ImportNoMutableExports(_) => {
                 Ok(Self::ImportNoMutableExports(ImportNoMutableExports::from_configuration(value)?))
             }
@@ -7885,9 +8033,16 @@ impl RuleEnum {
             Self::ImportNoUnassignedImport(_) => Ok(Self::ImportNoUnassignedImport(
                 ImportNoUnassignedImport::from_configuration(value)?,
             )),
+            Self::ImportNoUnresolved(_) => {
+                Ok(Self::ImportNoUnresolved(ImportNoUnresolved::from_configuration(value)?))
+            }
+            Self::ImportNoUnusedModules(_) => {
+                Ok(Self::ImportNoUnusedModules(ImportNoUnusedModules::from_configuration(value)?))
+            }
             Self::ImportNoWebpackLoaderSyntax(_) => Ok(Self::ImportNoWebpackLoaderSyntax(
                 ImportNoWebpackLoaderSyntax::from_configuration(value)?,
             )),
+            Self::ImportOrder(_) => Ok(Self::ImportOrder(ImportOrder::from_configuration(value)?)),
             Self::ImportPreferDefaultExport(_) => Ok(Self::ImportPreferDefaultExport(
                 ImportPreferDefaultExport::from_configuration(value)?,
             )),
@@ -8209,6 +8364,9 @@ impl RuleEnum {
             Self::EslintNoRestrictedImports(_) => Ok(Self::EslintNoRestrictedImports(
                 EslintNoRestrictedImports::from_configuration(value)?,
             )),
+            Self::EslintNoRestrictedSyntax(_) => Ok(Self::EslintNoRestrictedSyntax(
+                EslintNoRestrictedSyntax::from_configuration(value)?,
+            )),
             Self::EslintNoReturnAssign(_) => {
                 Ok(Self::EslintNoReturnAssign(EslintNoReturnAssign::from_configuration(value)?))
             }
@@ -8263,6 +8421,9 @@ impl RuleEnum {
             Self::EslintNoUnreachable(_) => {
                 Ok(Self::EslintNoUnreachable(EslintNoUnreachable::from_configuration(value)?))
             }
+            Self::EslintNoUnreachableLoop(_) => Ok(Self::EslintNoUnreachableLoop(
+                EslintNoUnreachableLoop::from_configuration(value)?,
+            )),
             Self::EslintNoUnsafeFinally(_) => {
                 Ok(Self::EslintNoUnsafeFinally(EslintNoUnsafeFinally::from_configuration(value)?))
             }
@@ -8286,6 +8447,9 @@ impl RuleEnum {
             Self::EslintNoUnusedVars(_) => {
                 Ok(Self::EslintNoUnusedVars(EslintNoUnusedVars::from_configuration(value)?))
             }
+            Self::EslintNoUselessAssignment(_) => Ok(Self::EslintNoUselessAssignment(
+                EslintNoUselessAssignment::from_configuration(value)?,
+            )),
             Self::EslintNoUselessBackreference(_) => Ok(Self::EslintNoUselessBackreference(
                 EslintNoUselessBackreference::from_configuration(value)?,
             )),
@@ -8441,6 +8605,9 @@ impl RuleEnum {
                     TypescriptExplicitModuleBoundaryTypes::from_configuration(value)?,
                 ))
             }
+            Self::TypescriptNamingConvention(_) => Ok(Self::TypescriptNamingConvention(
+                TypescriptNamingConvention::from_configuration(value)?,
+            )),
             Self::TypescriptNoArrayDelete(_) => Ok(Self::TypescriptNoArrayDelete(
                 TypescriptNoArrayDelete::from_configuration(value)?,
             )),
@@ -9867,6 +10034,21 @@ impl RuleEnum {
             Self::NodeNoProcessEnv(_) => {
                 Ok(Self::NodeNoProcessEnv(NodeNoProcessEnv::from_configuration(value)?))
             }
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(_) => {
+                Ok(Self::NodeNoUnsupportedFeaturesEsBuiltins(
+                    NodeNoUnsupportedFeaturesEsBuiltins::from_configuration(value)?,
+                ))
+            }
+            Self::NodeNoUnsupportedFeaturesEsSyntax(_) => {
+                Ok(Self::NodeNoUnsupportedFeaturesEsSyntax(
+                    NodeNoUnsupportedFeaturesEsSyntax::from_configuration(value)?,
+                ))
+            }
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                Ok(Self::NodeNoUnsupportedFeaturesNodeBuiltins(
+                    NodeNoUnsupportedFeaturesNodeBuiltins::from_configuration(value)?,
+                ))
+            }
             Self::VueDefineEmitsDeclaration(_) => Ok(Self::VueDefineEmitsDeclaration(
                 VueDefineEmitsDeclaration::from_configuration(value)?,
             )),
@@ -9941,6 +10123,7 @@ impl RuleEnum {
             Self::ImportNoDuplicates(rule) => rule.to_configuration(),
             Self::ImportNoDynamicRequire(rule) => rule.to_configuration(),
             Self::ImportNoEmptyNamedBlocks(rule) => rule.to_configuration(),
+            Self::ImportNoExtraneousDependencies(rule) => rule.to_configuration(),
             Self::ImportNoMutableExports(rule) => rule.to_configuration(),
             Self::ImportNoNamedAsDefault(rule) => rule.to_configuration(),
             Self::ImportNoNamedAsDefaultMember(rule) => rule.to_configuration(),
@@ -9951,7 +10134,10 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.to_configuration(),
             Self::ImportNoSelfImport(rule) => rule.to_configuration(),
             Self::ImportNoUnassignedImport(rule) => rule.to_configuration(),
+            Self::ImportNoUnresolved(rule) => rule.to_configuration(),
+            Self::ImportNoUnusedModules(rule) => rule.to_configuration(),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.to_configuration(),
+            Self::ImportOrder(rule) => rule.to_configuration(),
             Self::ImportPreferDefaultExport(rule) => rule.to_configuration(),
             Self::ImportUnambiguous(rule) => rule.to_configuration(),
             Self::EslintAccessorPairs(rule) => rule.to_configuration(),
@@ -10059,6 +10245,7 @@ impl RuleEnum {
             Self::EslintNoRegexSpaces(rule) => rule.to_configuration(),
             Self::EslintNoRestrictedGlobals(rule) => rule.to_configuration(),
             Self::EslintNoRestrictedImports(rule) => rule.to_configuration(),
+            Self::EslintNoRestrictedSyntax(rule) => rule.to_configuration(),
             Self::EslintNoReturnAssign(rule) => rule.to_configuration(),
             Self::EslintNoScriptUrl(rule) => rule.to_configuration(),
             Self::EslintNoSelfAssign(rule) => rule.to_configuration(),
@@ -10077,6 +10264,7 @@ impl RuleEnum {
             Self::EslintNoUnexpectedMultiline(rule) => rule.to_configuration(),
             Self::EslintNoUnneededTernary(rule) => rule.to_configuration(),
             Self::EslintNoUnreachable(rule) => rule.to_configuration(),
+            Self::EslintNoUnreachableLoop(rule) => rule.to_configuration(),
             Self::EslintNoUnsafeFinally(rule) => rule.to_configuration(),
             Self::EslintNoUnsafeNegation(rule) => rule.to_configuration(),
             Self::EslintNoUnsafeOptionalChaining(rule) => rule.to_configuration(),
@@ -10084,6 +10272,7 @@ impl RuleEnum {
             Self::EslintNoUnusedLabels(rule) => rule.to_configuration(),
             Self::EslintNoUnusedPrivateClassMembers(rule) => rule.to_configuration(),
             Self::EslintNoUnusedVars(rule) => rule.to_configuration(),
+            Self::EslintNoUselessAssignment(rule) => rule.to_configuration(),
             Self::EslintNoUselessBackreference(rule) => rule.to_configuration(),
             Self::EslintNoUselessCall(rule) => rule.to_configuration(),
             Self::EslintNoUselessCatch(rule) => rule.to_configuration(),
@@ -10133,6 +10322,7 @@ impl RuleEnum {
             Self::TypescriptConsistentTypeImports(rule) => rule.to_configuration(),
             Self::TypescriptExplicitFunctionReturnType(rule) => rule.to_configuration(),
             Self::TypescriptExplicitModuleBoundaryTypes(rule) => rule.to_configuration(),
+            Self::TypescriptNamingConvention(rule) => rule.to_configuration(),
             Self::TypescriptNoArrayDelete(rule) => rule.to_configuration(),
             Self::TypescriptNoBaseToString(rule) => rule.to_configuration(),
             Self::TypescriptNoConfusingNonNullAssertion(rule) => rule.to_configuration(),
@@ -10573,6 +10763,9 @@ impl RuleEnum {
             Self::NodeNoExportsAssign(rule) => rule.to_configuration(),
             Self::NodeNoNewRequire(rule) => rule.to_configuration(),
             Self::NodeNoProcessEnv(rule) => rule.to_configuration(),
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(rule) => rule.to_configuration(),
+            Self::NodeNoUnsupportedFeaturesEsSyntax(rule) => rule.to_configuration(),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(rule) => rule.to_configuration(),
             Self::VueDefineEmitsDeclaration(rule) => rule.to_configuration(),
             Self::VueDefinePropsDeclaration(rule) => rule.to_configuration(),
             Self::VueDefinePropsDestructuring(rule) => rule.to_configuration(),
@@ -10613,6 +10806,7 @@ impl RuleEnum {
             Self::ImportNoDuplicates(rule) => rule.run(node, ctx),
             Self::ImportNoDynamicRequire(rule) => rule.run(node, ctx),
             Self::ImportNoEmptyNamedBlocks(rule) => rule.run(node, ctx),
+            Self::ImportNoExtraneousDependencies(rule) => rule.run(node, ctx),
             Self::ImportNoMutableExports(rule) => rule.run(node, ctx),
             Self::ImportNoNamedAsDefault(rule) => rule.run(node, ctx),
             Self::ImportNoNamedAsDefaultMember(rule) => rule.run(node, ctx),
@@ -10623,7 +10817,10 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.run(node, ctx),
             Self::ImportNoSelfImport(rule) => rule.run(node, ctx),
             Self::ImportNoUnassignedImport(rule) => rule.run(node, ctx),
+            Self::ImportNoUnresolved(rule) => rule.run(node, ctx),
+            Self::ImportNoUnusedModules(rule) => rule.run(node, ctx),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.run(node, ctx),
+            Self::ImportOrder(rule) => rule.run(node, ctx),
             Self::ImportPreferDefaultExport(rule) => rule.run(node, ctx),
             Self::ImportUnambiguous(rule) => rule.run(node, ctx),
             Self::EslintAccessorPairs(rule) => rule.run(node, ctx),
@@ -10731,6 +10928,7 @@ impl RuleEnum {
             Self::EslintNoRegexSpaces(rule) => rule.run(node, ctx),
             Self::EslintNoRestrictedGlobals(rule) => rule.run(node, ctx),
             Self::EslintNoRestrictedImports(rule) => rule.run(node, ctx),
+            Self::EslintNoRestrictedSyntax(rule) => rule.run(node, ctx),
             Self::EslintNoReturnAssign(rule) => rule.run(node, ctx),
             Self::EslintNoScriptUrl(rule) => rule.run(node, ctx),
             Self::EslintNoSelfAssign(rule) => rule.run(node, ctx),
@@ -10749,6 +10947,7 @@ impl RuleEnum {
             Self::EslintNoUnexpectedMultiline(rule) => rule.run(node, ctx),
             Self::EslintNoUnneededTernary(rule) => rule.run(node, ctx),
             Self::EslintNoUnreachable(rule) => rule.run(node, ctx),
+            Self::EslintNoUnreachableLoop(rule) => rule.run(node, ctx),
             Self::EslintNoUnsafeFinally(rule) => rule.run(node, ctx),
             Self::EslintNoUnsafeNegation(rule) => rule.run(node, ctx),
             Self::EslintNoUnsafeOptionalChaining(rule) => rule.run(node, ctx),
@@ -10756,6 +10955,7 @@ impl RuleEnum {
             Self::EslintNoUnusedLabels(rule) => rule.run(node, ctx),
             Self::EslintNoUnusedPrivateClassMembers(rule) => rule.run(node, ctx),
             Self::EslintNoUnusedVars(rule) => rule.run(node, ctx),
+            Self::EslintNoUselessAssignment(rule) => rule.run(node, ctx),
             Self::EslintNoUselessBackreference(rule) => rule.run(node, ctx),
             Self::EslintNoUselessCall(rule) => rule.run(node, ctx),
             Self::EslintNoUselessCatch(rule) => rule.run(node, ctx),
@@ -10805,6 +11005,7 @@ impl RuleEnum {
             Self::TypescriptConsistentTypeImports(rule) => rule.run(node, ctx),
             Self::TypescriptExplicitFunctionReturnType(rule) => rule.run(node, ctx),
             Self::TypescriptExplicitModuleBoundaryTypes(rule) => rule.run(node, ctx),
+            Self::TypescriptNamingConvention(rule) => rule.run(node, ctx),
             Self::TypescriptNoArrayDelete(rule) => rule.run(node, ctx),
             Self::TypescriptNoBaseToString(rule) => rule.run(node, ctx),
             Self::TypescriptNoConfusingNonNullAssertion(rule) => rule.run(node, ctx),
@@ -11241,6 +11442,9 @@ impl RuleEnum {
             Self::NodeNoExportsAssign(rule) => rule.run(node, ctx),
             Self::NodeNoNewRequire(rule) => rule.run(node, ctx),
             Self::NodeNoProcessEnv(rule) => rule.run(node, ctx),
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(rule) => rule.run(node, ctx),
+            Self::NodeNoUnsupportedFeaturesEsSyntax(rule) => rule.run(node, ctx),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(rule) => rule.run(node, ctx),
             Self::VueDefineEmitsDeclaration(rule) => rule.run(node, ctx),
             Self::VueDefinePropsDeclaration(rule) => rule.run(node, ctx),
             Self::VueDefinePropsDestructuring(rule) => rule.run(node, ctx),
@@ -11281,6 +11485,7 @@ impl RuleEnum {
             Self::ImportNoDuplicates(rule) => rule.run_once(ctx),
             Self::ImportNoDynamicRequire(rule) => rule.run_once(ctx),
             Self::ImportNoEmptyNamedBlocks(rule) => rule.run_once(ctx),
+            Self::ImportNoExtraneousDependencies(rule) => rule.run_once(ctx),
             Self::ImportNoMutableExports(rule) => rule.run_once(ctx),
             Self::ImportNoNamedAsDefault(rule) => rule.run_once(ctx),
             Self::ImportNoNamedAsDefaultMember(rule) => rule.run_once(ctx),
@@ -11291,7 +11496,10 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.run_once(ctx),
             Self::ImportNoSelfImport(rule) => rule.run_once(ctx),
             Self::ImportNoUnassignedImport(rule) => rule.run_once(ctx),
+            Self::ImportNoUnresolved(rule) => rule.run_once(ctx),
+            Self::ImportNoUnusedModules(rule) => rule.run_once(ctx),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.run_once(ctx),
+            Self::ImportOrder(rule) => rule.run_once(ctx),
             Self::ImportPreferDefaultExport(rule) => rule.run_once(ctx),
             Self::ImportUnambiguous(rule) => rule.run_once(ctx),
             Self::EslintAccessorPairs(rule) => rule.run_once(ctx),
@@ -11399,6 +11607,7 @@ impl RuleEnum {
             Self::EslintNoRegexSpaces(rule) => rule.run_once(ctx),
             Self::EslintNoRestrictedGlobals(rule) => rule.run_once(ctx),
             Self::EslintNoRestrictedImports(rule) => rule.run_once(ctx),
+            Self::EslintNoRestrictedSyntax(rule) => rule.run_once(ctx),
             Self::EslintNoReturnAssign(rule) => rule.run_once(ctx),
             Self::EslintNoScriptUrl(rule) => rule.run_once(ctx),
             Self::EslintNoSelfAssign(rule) => rule.run_once(ctx),
@@ -11417,6 +11626,7 @@ impl RuleEnum {
             Self::EslintNoUnexpectedMultiline(rule) => rule.run_once(ctx),
             Self::EslintNoUnneededTernary(rule) => rule.run_once(ctx),
             Self::EslintNoUnreachable(rule) => rule.run_once(ctx),
+            Self::EslintNoUnreachableLoop(rule) => rule.run_once(ctx),
             Self::EslintNoUnsafeFinally(rule) => rule.run_once(ctx),
             Self::EslintNoUnsafeNegation(rule) => rule.run_once(ctx),
             Self::EslintNoUnsafeOptionalChaining(rule) => rule.run_once(ctx),
@@ -11424,6 +11634,7 @@ impl RuleEnum {
             Self::EslintNoUnusedLabels(rule) => rule.run_once(ctx),
             Self::EslintNoUnusedPrivateClassMembers(rule) => rule.run_once(ctx),
             Self::EslintNoUnusedVars(rule) => rule.run_once(ctx),
+            Self::EslintNoUselessAssignment(rule) => rule.run_once(ctx),
             Self::EslintNoUselessBackreference(rule) => rule.run_once(ctx),
             Self::EslintNoUselessCall(rule) => rule.run_once(ctx),
             Self::EslintNoUselessCatch(rule) => rule.run_once(ctx),
@@ -11473,6 +11684,7 @@ impl RuleEnum {
             Self::TypescriptConsistentTypeImports(rule) => rule.run_once(ctx),
             Self::TypescriptExplicitFunctionReturnType(rule) => rule.run_once(ctx),
             Self::TypescriptExplicitModuleBoundaryTypes(rule) => rule.run_once(ctx),
+            Self::TypescriptNamingConvention(rule) => rule.run_once(ctx),
             Self::TypescriptNoArrayDelete(rule) => rule.run_once(ctx),
             Self::TypescriptNoBaseToString(rule) => rule.run_once(ctx),
             Self::TypescriptNoConfusingNonNullAssertion(rule) => rule.run_once(ctx),
@@ -11909,6 +12121,9 @@ impl RuleEnum {
             Self::NodeNoExportsAssign(rule) => rule.run_once(ctx),
             Self::NodeNoNewRequire(rule) => rule.run_once(ctx),
             Self::NodeNoProcessEnv(rule) => rule.run_once(ctx),
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(rule) => rule.run_once(ctx),
+            Self::NodeNoUnsupportedFeaturesEsSyntax(rule) => rule.run_once(ctx),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(rule) => rule.run_once(ctx),
             Self::VueDefineEmitsDeclaration(rule) => rule.run_once(ctx),
             Self::VueDefinePropsDeclaration(rule) => rule.run_once(ctx),
             Self::VueDefinePropsDestructuring(rule) => rule.run_once(ctx),
@@ -11953,6 +12168,7 @@ impl RuleEnum {
             Self::ImportNoDuplicates(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportNoDynamicRequire(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportNoEmptyNamedBlocks(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::ImportNoExtraneousDependencies(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportNoMutableExports(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportNoNamedAsDefault(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportNoNamedAsDefaultMember(rule) => rule.run_on_jest_node(jest_node, ctx),
@@ -11963,7 +12179,10 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportNoSelfImport(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportNoUnassignedImport(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::ImportNoUnresolved(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::ImportNoUnusedModules(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::ImportOrder(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportPreferDefaultExport(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::ImportUnambiguous(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintAccessorPairs(rule) => rule.run_on_jest_node(jest_node, ctx),
@@ -12071,6 +12290,7 @@ impl RuleEnum {
             Self::EslintNoRegexSpaces(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoRestrictedGlobals(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoRestrictedImports(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::EslintNoRestrictedSyntax(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoReturnAssign(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoScriptUrl(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoSelfAssign(rule) => rule.run_on_jest_node(jest_node, ctx),
@@ -12089,6 +12309,7 @@ impl RuleEnum {
             Self::EslintNoUnexpectedMultiline(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoUnneededTernary(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoUnreachable(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::EslintNoUnreachableLoop(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoUnsafeFinally(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoUnsafeNegation(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoUnsafeOptionalChaining(rule) => rule.run_on_jest_node(jest_node, ctx),
@@ -12096,6 +12317,7 @@ impl RuleEnum {
             Self::EslintNoUnusedLabels(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoUnusedPrivateClassMembers(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoUnusedVars(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::EslintNoUselessAssignment(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoUselessBackreference(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoUselessCall(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::EslintNoUselessCatch(rule) => rule.run_on_jest_node(jest_node, ctx),
@@ -12157,6 +12379,7 @@ impl RuleEnum {
             Self::TypescriptExplicitModuleBoundaryTypes(rule) => {
                 rule.run_on_jest_node(jest_node, ctx)
             }
+            Self::TypescriptNamingConvention(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::TypescriptNoArrayDelete(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::TypescriptNoBaseToString(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::TypescriptNoConfusingNonNullAssertion(rule) => {
@@ -12667,6 +12890,13 @@ impl RuleEnum {
             Self::NodeNoExportsAssign(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::NodeNoNewRequire(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::NodeNoProcessEnv(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(rule) => {
+                rule.run_on_jest_node(jest_node, ctx)
+            }
+            Self::NodeNoUnsupportedFeaturesEsSyntax(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(rule) => {
+                rule.run_on_jest_node(jest_node, ctx)
+            }
             Self::VueDefineEmitsDeclaration(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::VueDefinePropsDeclaration(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::VueDefinePropsDestructuring(rule) => rule.run_on_jest_node(jest_node, ctx),
@@ -12707,6 +12937,7 @@ impl RuleEnum {
             Self::ImportNoDuplicates(rule) => rule.should_run(ctx),
             Self::ImportNoDynamicRequire(rule) => rule.should_run(ctx),
             Self::ImportNoEmptyNamedBlocks(rule) => rule.should_run(ctx),
+            Self::ImportNoExtraneousDependencies(rule) => rule.should_run(ctx),
             Self::ImportNoMutableExports(rule) => rule.should_run(ctx),
             Self::ImportNoNamedAsDefault(rule) => rule.should_run(ctx),
             Self::ImportNoNamedAsDefaultMember(rule) => rule.should_run(ctx),
@@ -12717,7 +12948,10 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.should_run(ctx),
             Self::ImportNoSelfImport(rule) => rule.should_run(ctx),
             Self::ImportNoUnassignedImport(rule) => rule.should_run(ctx),
+            Self::ImportNoUnresolved(rule) => rule.should_run(ctx),
+            Self::ImportNoUnusedModules(rule) => rule.should_run(ctx),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.should_run(ctx),
+            Self::ImportOrder(rule) => rule.should_run(ctx),
             Self::ImportPreferDefaultExport(rule) => rule.should_run(ctx),
             Self::ImportUnambiguous(rule) => rule.should_run(ctx),
             Self::EslintAccessorPairs(rule) => rule.should_run(ctx),
@@ -12825,6 +13059,7 @@ impl RuleEnum {
             Self::EslintNoRegexSpaces(rule) => rule.should_run(ctx),
             Self::EslintNoRestrictedGlobals(rule) => rule.should_run(ctx),
             Self::EslintNoRestrictedImports(rule) => rule.should_run(ctx),
+            Self::EslintNoRestrictedSyntax(rule) => rule.should_run(ctx),
             Self::EslintNoReturnAssign(rule) => rule.should_run(ctx),
             Self::EslintNoScriptUrl(rule) => rule.should_run(ctx),
             Self::EslintNoSelfAssign(rule) => rule.should_run(ctx),
@@ -12843,6 +13078,7 @@ impl RuleEnum {
             Self::EslintNoUnexpectedMultiline(rule) => rule.should_run(ctx),
             Self::EslintNoUnneededTernary(rule) => rule.should_run(ctx),
             Self::EslintNoUnreachable(rule) => rule.should_run(ctx),
+            Self::EslintNoUnreachableLoop(rule) => rule.should_run(ctx),
             Self::EslintNoUnsafeFinally(rule) => rule.should_run(ctx),
             Self::EslintNoUnsafeNegation(rule) => rule.should_run(ctx),
             Self::EslintNoUnsafeOptionalChaining(rule) => rule.should_run(ctx),
@@ -12850,6 +13086,7 @@ impl RuleEnum {
             Self::EslintNoUnusedLabels(rule) => rule.should_run(ctx),
             Self::EslintNoUnusedPrivateClassMembers(rule) => rule.should_run(ctx),
             Self::EslintNoUnusedVars(rule) => rule.should_run(ctx),
+            Self::EslintNoUselessAssignment(rule) => rule.should_run(ctx),
             Self::EslintNoUselessBackreference(rule) => rule.should_run(ctx),
             Self::EslintNoUselessCall(rule) => rule.should_run(ctx),
             Self::EslintNoUselessCatch(rule) => rule.should_run(ctx),
@@ -12899,6 +13136,7 @@ impl RuleEnum {
             Self::TypescriptConsistentTypeImports(rule) => rule.should_run(ctx),
             Self::TypescriptExplicitFunctionReturnType(rule) => rule.should_run(ctx),
             Self::TypescriptExplicitModuleBoundaryTypes(rule) => rule.should_run(ctx),
+            Self::TypescriptNamingConvention(rule) => rule.should_run(ctx),
             Self::TypescriptNoArrayDelete(rule) => rule.should_run(ctx),
             Self::TypescriptNoBaseToString(rule) => rule.should_run(ctx),
             Self::TypescriptNoConfusingNonNullAssertion(rule) => rule.should_run(ctx),
@@ -13335,6 +13573,9 @@ impl RuleEnum {
             Self::NodeNoExportsAssign(rule) => rule.should_run(ctx),
             Self::NodeNoNewRequire(rule) => rule.should_run(ctx),
             Self::NodeNoProcessEnv(rule) => rule.should_run(ctx),
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(rule) => rule.should_run(ctx),
+            Self::NodeNoUnsupportedFeaturesEsSyntax(rule) => rule.should_run(ctx),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(rule) => rule.should_run(ctx),
             Self::VueDefineEmitsDeclaration(rule) => rule.should_run(ctx),
             Self::VueDefinePropsDeclaration(rule) => rule.should_run(ctx),
             Self::VueDefinePropsDestructuring(rule) => rule.should_run(ctx),
@@ -13379,6 +13620,9 @@ impl RuleEnum {
             Self::ImportNoDuplicates(_) => ImportNoDuplicates::IS_TSGOLINT_RULE,
             Self::ImportNoDynamicRequire(_) => ImportNoDynamicRequire::IS_TSGOLINT_RULE,
             Self::ImportNoEmptyNamedBlocks(_) => ImportNoEmptyNamedBlocks::IS_TSGOLINT_RULE,
+            Self::ImportNoExtraneousDependencies(_) => {
+                ImportNoExtraneousDependencies::IS_TSGOLINT_RULE
+            }
             Self::ImportNoMutableExports(_) => ImportNoMutableExports::IS_TSGOLINT_RULE,
             Self::ImportNoNamedAsDefault(_) => ImportNoNamedAsDefault::IS_TSGOLINT_RULE,
             Self::ImportNoNamedAsDefaultMember(_) => ImportNoNamedAsDefaultMember::IS_TSGOLINT_RULE,
@@ -13391,7 +13635,10 @@ impl RuleEnum {
             }
             Self::ImportNoSelfImport(_) => ImportNoSelfImport::IS_TSGOLINT_RULE,
             Self::ImportNoUnassignedImport(_) => ImportNoUnassignedImport::IS_TSGOLINT_RULE,
+            Self::ImportNoUnresolved(_) => ImportNoUnresolved::IS_TSGOLINT_RULE,
+            Self::ImportNoUnusedModules(_) => ImportNoUnusedModules::IS_TSGOLINT_RULE,
             Self::ImportNoWebpackLoaderSyntax(_) => ImportNoWebpackLoaderSyntax::IS_TSGOLINT_RULE,
+            Self::ImportOrder(_) => ImportOrder::IS_TSGOLINT_RULE,
             Self::ImportPreferDefaultExport(_) => ImportPreferDefaultExport::IS_TSGOLINT_RULE,
             Self::ImportUnambiguous(_) => ImportUnambiguous::IS_TSGOLINT_RULE,
             Self::EslintAccessorPairs(_) => EslintAccessorPairs::IS_TSGOLINT_RULE,
@@ -13509,6 +13756,7 @@ impl RuleEnum {
             Self::EslintNoRegexSpaces(_) => EslintNoRegexSpaces::IS_TSGOLINT_RULE,
             Self::EslintNoRestrictedGlobals(_) => EslintNoRestrictedGlobals::IS_TSGOLINT_RULE,
             Self::EslintNoRestrictedImports(_) => EslintNoRestrictedImports::IS_TSGOLINT_RULE,
+            Self::EslintNoRestrictedSyntax(_) => EslintNoRestrictedSyntax::IS_TSGOLINT_RULE,
             Self::EslintNoReturnAssign(_) => EslintNoReturnAssign::IS_TSGOLINT_RULE,
             Self::EslintNoScriptUrl(_) => EslintNoScriptUrl::IS_TSGOLINT_RULE,
             Self::EslintNoSelfAssign(_) => EslintNoSelfAssign::IS_TSGOLINT_RULE,
@@ -13531,6 +13779,7 @@ impl RuleEnum {
             Self::EslintNoUnexpectedMultiline(_) => EslintNoUnexpectedMultiline::IS_TSGOLINT_RULE,
             Self::EslintNoUnneededTernary(_) => EslintNoUnneededTernary::IS_TSGOLINT_RULE,
             Self::EslintNoUnreachable(_) => EslintNoUnreachable::IS_TSGOLINT_RULE,
+            Self::EslintNoUnreachableLoop(_) => EslintNoUnreachableLoop::IS_TSGOLINT_RULE,
             Self::EslintNoUnsafeFinally(_) => EslintNoUnsafeFinally::IS_TSGOLINT_RULE,
             Self::EslintNoUnsafeNegation(_) => EslintNoUnsafeNegation::IS_TSGOLINT_RULE,
             Self::EslintNoUnsafeOptionalChaining(_) => {
@@ -13542,6 +13791,7 @@ impl RuleEnum {
                 EslintNoUnusedPrivateClassMembers::IS_TSGOLINT_RULE
             }
             Self::EslintNoUnusedVars(_) => EslintNoUnusedVars::IS_TSGOLINT_RULE,
+            Self::EslintNoUselessAssignment(_) => EslintNoUselessAssignment::IS_TSGOLINT_RULE,
             Self::EslintNoUselessBackreference(_) => EslintNoUselessBackreference::IS_TSGOLINT_RULE,
             Self::EslintNoUselessCall(_) => EslintNoUselessCall::IS_TSGOLINT_RULE,
             Self::EslintNoUselessCatch(_) => EslintNoUselessCatch::IS_TSGOLINT_RULE,
@@ -13609,6 +13859,7 @@ impl RuleEnum {
             Self::TypescriptExplicitModuleBoundaryTypes(_) => {
                 TypescriptExplicitModuleBoundaryTypes::IS_TSGOLINT_RULE
             }
+            Self::TypescriptNamingConvention(_) => TypescriptNamingConvention::IS_TSGOLINT_RULE,
             Self::TypescriptNoArrayDelete(_) => TypescriptNoArrayDelete::IS_TSGOLINT_RULE,
             Self::TypescriptNoBaseToString(_) => TypescriptNoBaseToString::IS_TSGOLINT_RULE,
             Self::TypescriptNoConfusingNonNullAssertion(_) => {
@@ -14265,6 +14516,15 @@ impl RuleEnum {
             Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::IS_TSGOLINT_RULE,
             Self::NodeNoNewRequire(_) => NodeNoNewRequire::IS_TSGOLINT_RULE,
             Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::IS_TSGOLINT_RULE,
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(_) => {
+                NodeNoUnsupportedFeaturesEsBuiltins::IS_TSGOLINT_RULE
+            }
+            Self::NodeNoUnsupportedFeaturesEsSyntax(_) => {
+                NodeNoUnsupportedFeaturesEsSyntax::IS_TSGOLINT_RULE
+            }
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::IS_TSGOLINT_RULE
+            }
             Self::VueDefineEmitsDeclaration(_) => VueDefineEmitsDeclaration::IS_TSGOLINT_RULE,
             Self::VueDefinePropsDeclaration(_) => VueDefinePropsDeclaration::IS_TSGOLINT_RULE,
             Self::VueDefinePropsDestructuring(_) => VueDefinePropsDestructuring::IS_TSGOLINT_RULE,
@@ -14307,6 +14567,7 @@ impl RuleEnum {
             Self::ImportNoDuplicates(rule) => rule.types_info(),
             Self::ImportNoDynamicRequire(rule) => rule.types_info(),
             Self::ImportNoEmptyNamedBlocks(rule) => rule.types_info(),
+            Self::ImportNoExtraneousDependencies(rule) => rule.types_info(),
             Self::ImportNoMutableExports(rule) => rule.types_info(),
             Self::ImportNoNamedAsDefault(rule) => rule.types_info(),
             Self::ImportNoNamedAsDefaultMember(rule) => rule.types_info(),
@@ -14317,7 +14578,10 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.types_info(),
             Self::ImportNoSelfImport(rule) => rule.types_info(),
             Self::ImportNoUnassignedImport(rule) => rule.types_info(),
+            Self::ImportNoUnresolved(rule) => rule.types_info(),
+            Self::ImportNoUnusedModules(rule) => rule.types_info(),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.types_info(),
+            Self::ImportOrder(rule) => rule.types_info(),
             Self::ImportPreferDefaultExport(rule) => rule.types_info(),
             Self::ImportUnambiguous(rule) => rule.types_info(),
             Self::EslintAccessorPairs(rule) => rule.types_info(),
@@ -14425,6 +14689,7 @@ impl RuleEnum {
             Self::EslintNoRegexSpaces(rule) => rule.types_info(),
             Self::EslintNoRestrictedGlobals(rule) => rule.types_info(),
             Self::EslintNoRestrictedImports(rule) => rule.types_info(),
+            Self::EslintNoRestrictedSyntax(rule) => rule.types_info(),
             Self::EslintNoReturnAssign(rule) => rule.types_info(),
             Self::EslintNoScriptUrl(rule) => rule.types_info(),
             Self::EslintNoSelfAssign(rule) => rule.types_info(),
@@ -14443,6 +14708,7 @@ impl RuleEnum {
             Self::EslintNoUnexpectedMultiline(rule) => rule.types_info(),
             Self::EslintNoUnneededTernary(rule) => rule.types_info(),
             Self::EslintNoUnreachable(rule) => rule.types_info(),
+            Self::EslintNoUnreachableLoop(rule) => rule.types_info(),
             Self::EslintNoUnsafeFinally(rule) => rule.types_info(),
             Self::EslintNoUnsafeNegation(rule) => rule.types_info(),
             Self::EslintNoUnsafeOptionalChaining(rule) => rule.types_info(),
@@ -14450,6 +14716,7 @@ impl RuleEnum {
             Self::EslintNoUnusedLabels(rule) => rule.types_info(),
             Self::EslintNoUnusedPrivateClassMembers(rule) => rule.types_info(),
             Self::EslintNoUnusedVars(rule) => rule.types_info(),
+            Self::EslintNoUselessAssignment(rule) => rule.types_info(),
             Self::EslintNoUselessBackreference(rule) => rule.types_info(),
             Self::EslintNoUselessCall(rule) => rule.types_info(),
             Self::EslintNoUselessCatch(rule) => rule.types_info(),
@@ -14499,6 +14766,7 @@ impl RuleEnum {
             Self::TypescriptConsistentTypeImports(rule) => rule.types_info(),
             Self::TypescriptExplicitFunctionReturnType(rule) => rule.types_info(),
             Self::TypescriptExplicitModuleBoundaryTypes(rule) => rule.types_info(),
+            Self::TypescriptNamingConvention(rule) => rule.types_info(),
             Self::TypescriptNoArrayDelete(rule) => rule.types_info(),
             Self::TypescriptNoBaseToString(rule) => rule.types_info(),
             Self::TypescriptNoConfusingNonNullAssertion(rule) => rule.types_info(),
@@ -14935,6 +15203,9 @@ impl RuleEnum {
             Self::NodeNoExportsAssign(rule) => rule.types_info(),
             Self::NodeNoNewRequire(rule) => rule.types_info(),
             Self::NodeNoProcessEnv(rule) => rule.types_info(),
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(rule) => rule.types_info(),
+            Self::NodeNoUnsupportedFeaturesEsSyntax(rule) => rule.types_info(),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(rule) => rule.types_info(),
             Self::VueDefineEmitsDeclaration(rule) => rule.types_info(),
             Self::VueDefinePropsDeclaration(rule) => rule.types_info(),
             Self::VueDefinePropsDestructuring(rule) => rule.types_info(),
@@ -14975,6 +15246,7 @@ impl RuleEnum {
             Self::ImportNoDuplicates(rule) => rule.run_info(),
             Self::ImportNoDynamicRequire(rule) => rule.run_info(),
             Self::ImportNoEmptyNamedBlocks(rule) => rule.run_info(),
+            Self::ImportNoExtraneousDependencies(rule) => rule.run_info(),
             Self::ImportNoMutableExports(rule) => rule.run_info(),
             Self::ImportNoNamedAsDefault(rule) => rule.run_info(),
             Self::ImportNoNamedAsDefaultMember(rule) => rule.run_info(),
@@ -14985,7 +15257,10 @@ impl RuleEnum {
             Self::ImportNoRelativeParentImports(rule) => rule.run_info(),
             Self::ImportNoSelfImport(rule) => rule.run_info(),
             Self::ImportNoUnassignedImport(rule) => rule.run_info(),
+            Self::ImportNoUnresolved(rule) => rule.run_info(),
+            Self::ImportNoUnusedModules(rule) => rule.run_info(),
             Self::ImportNoWebpackLoaderSyntax(rule) => rule.run_info(),
+            Self::ImportOrder(rule) => rule.run_info(),
             Self::ImportPreferDefaultExport(rule) => rule.run_info(),
             Self::ImportUnambiguous(rule) => rule.run_info(),
             Self::EslintAccessorPairs(rule) => rule.run_info(),
@@ -15093,6 +15368,7 @@ impl RuleEnum {
             Self::EslintNoRegexSpaces(rule) => rule.run_info(),
             Self::EslintNoRestrictedGlobals(rule) => rule.run_info(),
             Self::EslintNoRestrictedImports(rule) => rule.run_info(),
+            Self::EslintNoRestrictedSyntax(rule) => rule.run_info(),
             Self::EslintNoReturnAssign(rule) => rule.run_info(),
             Self::EslintNoScriptUrl(rule) => rule.run_info(),
             Self::EslintNoSelfAssign(rule) => rule.run_info(),
@@ -15111,6 +15387,7 @@ impl RuleEnum {
             Self::EslintNoUnexpectedMultiline(rule) => rule.run_info(),
             Self::EslintNoUnneededTernary(rule) => rule.run_info(),
             Self::EslintNoUnreachable(rule) => rule.run_info(),
+            Self::EslintNoUnreachableLoop(rule) => rule.run_info(),
             Self::EslintNoUnsafeFinally(rule) => rule.run_info(),
             Self::EslintNoUnsafeNegation(rule) => rule.run_info(),
             Self::EslintNoUnsafeOptionalChaining(rule) => rule.run_info(),
@@ -15118,6 +15395,7 @@ impl RuleEnum {
             Self::EslintNoUnusedLabels(rule) => rule.run_info(),
             Self::EslintNoUnusedPrivateClassMembers(rule) => rule.run_info(),
             Self::EslintNoUnusedVars(rule) => rule.run_info(),
+            Self::EslintNoUselessAssignment(rule) => rule.run_info(),
             Self::EslintNoUselessBackreference(rule) => rule.run_info(),
             Self::EslintNoUselessCall(rule) => rule.run_info(),
             Self::EslintNoUselessCatch(rule) => rule.run_info(),
@@ -15167,6 +15445,7 @@ impl RuleEnum {
             Self::TypescriptConsistentTypeImports(rule) => rule.run_info(),
             Self::TypescriptExplicitFunctionReturnType(rule) => rule.run_info(),
             Self::TypescriptExplicitModuleBoundaryTypes(rule) => rule.run_info(),
+            Self::TypescriptNamingConvention(rule) => rule.run_info(),
             Self::TypescriptNoArrayDelete(rule) => rule.run_info(),
             Self::TypescriptNoBaseToString(rule) => rule.run_info(),
             Self::TypescriptNoConfusingNonNullAssertion(rule) => rule.run_info(),
@@ -15603,6 +15882,9 @@ impl RuleEnum {
             Self::NodeNoExportsAssign(rule) => rule.run_info(),
             Self::NodeNoNewRequire(rule) => rule.run_info(),
             Self::NodeNoProcessEnv(rule) => rule.run_info(),
+            Self::NodeNoUnsupportedFeaturesEsBuiltins(rule) => rule.run_info(),
+            Self::NodeNoUnsupportedFeaturesEsSyntax(rule) => rule.run_info(),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(rule) => rule.run_info(),
             Self::VueDefineEmitsDeclaration(rule) => rule.run_info(),
             Self::VueDefinePropsDeclaration(rule) => rule.run_info(),
             Self::VueDefinePropsDestructuring(rule) => rule.run_info(),
@@ -15665,6 +15947,7 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::ImportNoDuplicates(ImportNoDuplicates::default()),
         RuleEnum::ImportNoDynamicRequire(ImportNoDynamicRequire::default()),
         RuleEnum::ImportNoEmptyNamedBlocks(ImportNoEmptyNamedBlocks::default()),
+        RuleEnum::ImportNoExtraneousDependencies(ImportNoExtraneousDependencies::default()),
         RuleEnum::ImportNoMutableExports(ImportNoMutableExports::default()),
         RuleEnum::ImportNoNamedAsDefault(ImportNoNamedAsDefault::default()),
         RuleEnum::ImportNoNamedAsDefaultMember(ImportNoNamedAsDefaultMember::default()),
@@ -15675,7 +15958,10 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::ImportNoRelativeParentImports(ImportNoRelativeParentImports::default()),
         RuleEnum::ImportNoSelfImport(ImportNoSelfImport::default()),
         RuleEnum::ImportNoUnassignedImport(ImportNoUnassignedImport::default()),
+        RuleEnum::ImportNoUnresolved(ImportNoUnresolved::default()),
+        RuleEnum::ImportNoUnusedModules(ImportNoUnusedModules::default()),
         RuleEnum::ImportNoWebpackLoaderSyntax(ImportNoWebpackLoaderSyntax::default()),
+        RuleEnum::ImportOrder(ImportOrder::default()),
         RuleEnum::ImportPreferDefaultExport(ImportPreferDefaultExport::default()),
         RuleEnum::ImportUnambiguous(ImportUnambiguous::default()),
         RuleEnum::EslintAccessorPairs(EslintAccessorPairs::default()),
@@ -15783,6 +16069,7 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::EslintNoRegexSpaces(EslintNoRegexSpaces::default()),
         RuleEnum::EslintNoRestrictedGlobals(EslintNoRestrictedGlobals::default()),
         RuleEnum::EslintNoRestrictedImports(EslintNoRestrictedImports::default()),
+        RuleEnum::EslintNoRestrictedSyntax(EslintNoRestrictedSyntax::default()),
         RuleEnum::EslintNoReturnAssign(EslintNoReturnAssign::default()),
         RuleEnum::EslintNoScriptUrl(EslintNoScriptUrl::default()),
         RuleEnum::EslintNoSelfAssign(EslintNoSelfAssign::default()),
@@ -15801,6 +16088,7 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::EslintNoUnexpectedMultiline(EslintNoUnexpectedMultiline::default()),
         RuleEnum::EslintNoUnneededTernary(EslintNoUnneededTernary::default()),
         RuleEnum::EslintNoUnreachable(EslintNoUnreachable::default()),
+        RuleEnum::EslintNoUnreachableLoop(EslintNoUnreachableLoop::default()),
         RuleEnum::EslintNoUnsafeFinally(EslintNoUnsafeFinally::default()),
         RuleEnum::EslintNoUnsafeNegation(EslintNoUnsafeNegation::default()),
         RuleEnum::EslintNoUnsafeOptionalChaining(EslintNoUnsafeOptionalChaining::default()),
@@ -15808,6 +16096,7 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::EslintNoUnusedLabels(EslintNoUnusedLabels::default()),
         RuleEnum::EslintNoUnusedPrivateClassMembers(EslintNoUnusedPrivateClassMembers::default()),
         RuleEnum::EslintNoUnusedVars(EslintNoUnusedVars::default()),
+        RuleEnum::EslintNoUselessAssignment(EslintNoUselessAssignment::default()),
         RuleEnum::EslintNoUselessBackreference(EslintNoUselessBackreference::default()),
         RuleEnum::EslintNoUselessCall(EslintNoUselessCall::default()),
         RuleEnum::EslintNoUselessCatch(EslintNoUselessCatch::default()),
@@ -15869,6 +16158,7 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::TypescriptExplicitModuleBoundaryTypes(
             TypescriptExplicitModuleBoundaryTypes::default(),
         ),
+        RuleEnum::TypescriptNamingConvention(TypescriptNamingConvention::default()),
         RuleEnum::TypescriptNoArrayDelete(TypescriptNoArrayDelete::default()),
         RuleEnum::TypescriptNoBaseToString(TypescriptNoBaseToString::default()),
         RuleEnum::TypescriptNoConfusingNonNullAssertion(
@@ -16379,6 +16669,13 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::NodeNoExportsAssign(NodeNoExportsAssign::default()),
         RuleEnum::NodeNoNewRequire(NodeNoNewRequire::default()),
         RuleEnum::NodeNoProcessEnv(NodeNoProcessEnv::default()),
+        RuleEnum::NodeNoUnsupportedFeaturesEsBuiltins(
+            NodeNoUnsupportedFeaturesEsBuiltins::default(),
+        ),
+        RuleEnum::NodeNoUnsupportedFeaturesEsSyntax(NodeNoUnsupportedFeaturesEsSyntax::default()),
+        RuleEnum::NodeNoUnsupportedFeaturesNodeBuiltins(
+            NodeNoUnsupportedFeaturesNodeBuiltins::default(),
+        ),
         RuleEnum::VueDefineEmitsDeclaration(VueDefineEmitsDeclaration::default()),
         RuleEnum::VueDefinePropsDeclaration(VueDefinePropsDeclaration::default()),
         RuleEnum::VueDefinePropsDestructuring(VueDefinePropsDestructuring::default()),
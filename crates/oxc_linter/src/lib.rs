@@ -11,6 +11,8 @@ use std::{
     path::Path,
     ptr::{self, NonNull},
     rc::Rc,
+    sync::Arc,
+    time::Instant,
 };
 
 use oxc_allocator::{Allocator, AllocatorPool, CloneIn};
@@ -36,6 +38,7 @@ mod module_record;
 mod options;
 mod rule;
 mod service;
+mod timing;
 mod tsgolint;
 mod utils;
 
@@ -65,7 +68,7 @@ pub use crate::{
         Config, ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ESLintRule, LintIgnoreMatcher,
         LintPlugins, Oxlintrc, ResolvedLinterState,
     },
-    context::{ContextSubHost, LintContext},
+    context::{ContextSubHost, LintContext, ReadFiles, plugin_name_to_prefix},
     external_linter::{
         ExternalLinter, ExternalLinterLintFileCb, ExternalLinterLoadPluginCb,
         ExternalLinterSetupRuleConfigsCb, JsFix, LintFileResult, LoadPluginResult,
@@ -74,12 +77,13 @@ pub use crate::{
     fixer::{Fix, FixKind, Message, PossibleFixes},
     frameworks::FrameworkFlags,
     lint_runner::{DirectivesStore, LintRunner, LintRunnerBuilder},
-    loader::LINTABLE_EXTENSIONS,
-    module_record::ModuleRecord,
+    loader::{LINTABLE_EXTENSIONS, MARKDOWN_EXTENSIONS},
+    module_record::{ModuleRecord, ModuleResolution},
     options::LintOptions,
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
     rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleRunFunctionsImplemented, RuleRunner},
     service::{LintService, LintServiceOptions, OsFileSystem, RuntimeFileSystem},
+    timing::{FileTiming, LintTimings, RuleTiming, RuleTimingEntry},
     tsgolint::TsGoLintState,
     utils::{read_to_arena_str, read_to_string},
 };
@@ -90,6 +94,7 @@ use crate::{
     fixer::{CompositeFix, Fixer},
     loader::LINT_PARTIAL_LOADER_EXTENSIONS,
     rules::RuleEnum,
+    timing::timed,
     utils::iter_possible_jest_call_node,
 };
 
@@ -103,7 +108,7 @@ fn size_asserts() {
 }
 
 /// Base URL for the documentation, used to generate rule documentation URLs when a diagnostic is reported.
-const WEBSITE_BASE_RULES_URL: &str = "https://oxc.rs/docs/guide/usage/linter/rules";
+pub const WEBSITE_BASE_RULES_URL: &str = "https://oxc.rs/docs/guide/usage/linter/rules";
 
 #[derive(Debug)]
 #[expect(clippy::struct_field_names)]
@@ -111,6 +116,7 @@ pub struct Linter {
     options: LintOptions,
     config: ConfigStore,
     external_linter: Option<ExternalLinter>,
+    timings: Option<Arc<LintTimings>>,
 }
 
 impl Linter {
@@ -119,7 +125,7 @@ impl Linter {
         config: ConfigStore,
         external_linter: Option<ExternalLinter>,
     ) -> Self {
-        Self { options, config, external_linter }
+        Self { options, config, external_linter, timings: None }
     }
 
     /// Set the kind of auto fixes to apply.
@@ -135,6 +141,29 @@ impl Linter {
         self
     }
 
+    /// Report diagnostics which are suppressed by disable directives, instead of discarding them.
+    /// Suppressed diagnostics are marked with [`OxcDiagnostic::with_suppressed`], and their fixes
+    /// are not applied.
+    #[must_use]
+    pub fn with_report_suppressed(mut self, yes: bool) -> Self {
+        self.options.report_suppressed = yes;
+        self
+    }
+
+    /// Record the time spent in each rule and in each phase of linting a file to `timings`.
+    ///
+    /// With timings enabled, rules always run one after another over all AST nodes, instead of
+    /// being interleaved for large files.
+    #[must_use]
+    pub fn with_timings(mut self, timings: Arc<LintTimings>) -> Self {
+        self.timings = Some(timings);
+        self
+    }
+
+    pub(crate) fn timings(&self) -> Option<&LintTimings> {
+        self.timings.as_deref()
+    }
+
     pub(crate) fn options(&self) -> &LintOptions {
         &self.options
     }
@@ -151,6 +180,14 @@ impl Linter {
         self.external_linter.is_some()
     }
 
+    /// Return `true` if any rule needs the importers of modules, see [`ModuleRecord::importers`].
+    /// Importers are only tracked with cross-module analysis.
+    pub fn uses_importers(&self) -> bool {
+        self.config.is_any_rule_enabled(
+            |rule| matches!(rule, RuleEnum::ImportNoUnusedModules(rule) if rule.uses_importers()),
+        )
+    }
+
     /// # Panics
     /// Panics if running in debug mode and the number of diagnostics does not match when running with/without optimizations
     pub fn run<'a>(
@@ -162,7 +199,8 @@ impl Linter {
         self.run_with_disable_directives(path, context_sub_hosts, allocator, None).0
     }
 
-    /// Same as `run` but also returns the disable directives for the file
+    /// Same as `run` but also returns the disable directives for the file, and the `package.json`
+    /// files read by rules with hashes of their contents (see [`ContextHost::take_read_files`]).
     ///
     /// # Parameters
     /// - `js_allocator_pool`: Optional pool of fixed-size allocators for copying AST before JS transfer.
@@ -177,7 +215,9 @@ impl Linter {
         context_sub_hosts: Vec<ContextSubHost<'a>>,
         allocator: &'a Allocator,
         js_allocator_pool: Option<&AllocatorPool>,
-    ) -> (Vec<Message>, Option<DisableDirectives>) {
+    ) -> (Vec<Message>, Option<DisableDirectives>, ReadFiles) {
+        let lint_start = self.timings.is_some().then(Instant::now);
+
         let ResolvedLinterState { rules, config, external_rules } = self.config.resolve(path);
 
         let mut ctx_host = Rc::new(ContextHost::new(path, context_sub_hosts, self.options, config));
@@ -215,7 +255,10 @@ impl Linter {
             let should_run_on_jest_node =
                 ctx_host.plugins().has_test() && ctx_host.frameworks().is_test();
 
-            let execute_rules = |with_runtime_optimization: bool| {
+            let mut rule_timings =
+                self.timings.is_some().then(|| vec![RuleTiming::default(); rules.len()]);
+
+            let mut execute_rules = |with_runtime_optimization: bool| {
                 // IMPORTANT: We have two branches here for performance reasons:
                 //
                 // 1) Branch where we iterate over each node, then each rule
@@ -234,7 +277,9 @@ impl Linter {
                 // don't thrash the cache too much. Feel free to tweak based on benchmarking.
                 //
                 // See https://github.com/oxc-project/oxc/pull/6600 for more context.
-                if semantic.nodes().len() > 200_000 {
+                //
+                // Time spent in each rule can only be measured in the second branch.
+                if semantic.nodes().len() > 200_000 && rule_timings.is_none() {
                     // TODO: It seems like there is probably a more intelligent way to preallocate space here. This will
                     // likely incur quite a few unnecessary reallocs currently. We theoretically could compute this at
                     // compile-time since we know all of the rules and their AST node type information ahead of time.
@@ -289,36 +334,52 @@ impl Linter {
                         }
                     }
                 } else {
-                    for (rule, ctx) in &rules {
+                    for (index, (rule, ctx)) in rules.iter().enumerate() {
                         let run_info = rule.run_info();
+                        // Only the optimized run is timed, the unoptimized run is a debug assertion.
+                        let mut timing = rule_timings
+                            .as_mut()
+                            .filter(|_| with_runtime_optimization)
+                            .map(|rule_timings| &mut rule_timings[index]);
+
                         if !with_runtime_optimization || run_info.is_run_once_implemented() {
-                            rule.run_once(ctx);
+                            timed(timing.as_mut().map(|timing| &mut timing.run_once), || {
+                                rule.run_once(ctx);
+                            });
                         }
 
                         if !with_runtime_optimization || run_info.is_run_implemented() {
-                            // For smaller files, benchmarking showed it was faster to iterate over all rules and just check the
-                            // node types as we go, rather than pre-bucketing rules by AST node type and doing extra allocations.
-                            if with_runtime_optimization && let Some(ast_types) = rule.types_info()
-                            {
-                                for node in semantic.nodes() {
-                                    if ast_types.has(node.kind().ty()) {
+                            timed(timing.as_mut().map(|timing| &mut timing.run), || {
+                                // For smaller files, benchmarking showed it was faster to iterate over all rules and just check the
+                                // node types as we go, rather than pre-bucketing rules by AST node type and doing extra allocations.
+                                if with_runtime_optimization
+                                    && let Some(ast_types) = rule.types_info()
+                                {
+                                    for node in semantic.nodes() {
+                                        if ast_types.has(node.kind().ty()) {
+                                            rule.run(node, ctx);
+                                        }
+                                    }
+                                } else {
+                                    for node in semantic.nodes() {
                                         rule.run(node, ctx);
                                     }
                                 }
-                            } else {
-                                for node in semantic.nodes() {
-                                    rule.run(node, ctx);
-                                }
-                            }
+                            });
                         }
 
                         if should_run_on_jest_node
                             && (!with_runtime_optimization
                                 || run_info.is_run_on_jest_node_implemented())
                         {
-                            for jest_node in iter_possible_jest_call_node(semantic) {
-                                rule.run_on_jest_node(&jest_node, ctx);
-                            }
+                            timed(
+                                timing.as_mut().map(|timing| &mut timing.run_on_jest_node),
+                                || {
+                                    for jest_node in iter_possible_jest_call_node(semantic) {
+                                        rule.run_on_jest_node(&jest_node, ctx);
+                                    }
+                                },
+                            );
                         }
                     }
                 }
@@ -363,6 +424,10 @@ impl Linter {
                 });
             }
 
+            if let (Some(timings), Some(rule_timings)) = (self.timings(), rule_timings) {
+                timings.add_rules(rules.iter().map(|(rule, _)| *rule).zip(rule_timings));
+            }
+
             // Drop `rules` to release its `Rc` clones of `ctx_host`, ensuring `run_external_rules`
             // can mutably access `ctx_host` via `Rc::get_mut` without panicking due to multiple references.
             drop(rules);
@@ -395,14 +460,20 @@ impl Linter {
             }
         }
 
+        if let (Some(timings), Some(lint_start)) = (self.timings(), lint_start) {
+            timings
+                .add_file(path, FileTiming { lint: lint_start.elapsed(), ..FileTiming::default() });
+        }
+
         let diagnostics = ctx_host.take_diagnostics();
+        let read_files = ctx_host.take_read_files();
         let disable_directives = if is_partial_loader_file {
             None
         } else {
             Rc::try_unwrap(ctx_host).unwrap().into_disable_directives()
         };
 
-        (diagnostics, disable_directives)
+        (diagnostics, disable_directives, read_files)
     }
 
     #[cfg(all(target_pointer_width = "64", target_endian = "little"))]
@@ -630,10 +701,21 @@ impl Linter {
                     let (plugin_name, rule_name) =
                         self.config.resolve_plugin_rule_names(external_rule_id);
 
+                    let error = OxcDiagnostic::error(diagnostic.message)
+                        .with_label(span)
+                        .with_error_code(plugin_name.to_string(), rule_name.to_string())
+                        .with_severity(severity.into());
+
                     if ctx_host
                         .disable_directives()
                         .contains(&format!("{plugin_name}/{rule_name}"), span)
                     {
+                        if ctx_host.report_suppressed() {
+                            ctx_host.push_diagnostic(Message::new(
+                                error.with_suppressed(true),
+                                PossibleFixes::None,
+                            ));
+                        }
                         continue;
                     }
 
@@ -674,13 +756,7 @@ impl Linter {
                         PossibleFixes::None
                     };
 
-                    ctx_host.push_diagnostic(Message::new(
-                        OxcDiagnostic::error(diagnostic.message)
-                            .with_label(span)
-                            .with_error_code(plugin_name.to_string(), rule_name.to_string())
-                            .with_severity(severity.into()),
-                        fix,
-                    ));
+                    ctx_host.push_diagnostic(Message::new(error, fix));
                 }
             }
             Err(err) => {
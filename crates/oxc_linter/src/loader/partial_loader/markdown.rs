@@ -0,0 +1,212 @@
+use cow_utils::CowUtils;
+
+use oxc_span::SourceType;
+
+use crate::loader::JavaScriptSource;
+
+pub struct MarkdownPartialLoader<'a> {
+    source_text: &'a str,
+    is_mdx: bool,
+}
+
+impl<'a> MarkdownPartialLoader<'a> {
+    pub fn new(source_text: &'a str, is_mdx: bool) -> Self {
+        Self { source_text, is_mdx }
+    }
+
+    /// Extract fenced code blocks tagged as JavaScript or TypeScript, e.g. ```` ```ts ````.
+    /// In MDX files, top-level `import` and `export` statements are extracted as well.
+    /// <https://spec.commonmark.org/0.31.2/#fenced-code-blocks>
+    /// <https://mdxjs.com/docs/what-is-mdx/#esm>
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        let mut results = vec![];
+        let mut lines = self.lines();
+        // Start of the MDX ESM paragraph being collected.
+        let mut esm_start = None;
+
+        while let Some((offset, line)) = lines.next() {
+            if let Some(fence) = Fence::parse(line) {
+                if let Some(start) = esm_start.take() {
+                    results.push(self.source(start, offset, SourceType::jsx()));
+                }
+                let content_start = offset + line.len();
+                let content_end = lines
+                    .by_ref()
+                    .find(|(_, line)| fence.is_closed_by(line))
+                    .map_or(self.source_text.len(), |(offset, _)| offset);
+                if let Some(source_type) = fence.source_type() {
+                    results.push(self.source(content_start, content_end, source_type));
+                }
+                continue;
+            }
+
+            if !self.is_mdx {
+                continue;
+            }
+            if esm_start.is_none() && is_esm_statement(line) {
+                esm_start = Some(offset);
+            } else if let Some(start) = esm_start
+                && line.trim().is_empty()
+            {
+                results.push(self.source(start, offset, SourceType::jsx()));
+                esm_start = None;
+            }
+        }
+
+        if let Some(start) = esm_start {
+            results.push(self.source(start, self.source_text.len(), SourceType::jsx()));
+        }
+
+        results
+    }
+
+    /// Lines of the file, including their line terminator, with their offset.
+    fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> {
+        self.source_text.split_inclusive('\n').scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len();
+            Some((start, line))
+        })
+    }
+
+    fn source(&self, start: usize, end: usize, source_type: SourceType) -> JavaScriptSource<'a> {
+        // NOTE: loader checked that source_text.len() is less than u32::MAX
+        #[expect(clippy::cast_possible_truncation)]
+        JavaScriptSource::partial(&self.source_text[start..end], source_type, start as u32)
+    }
+}
+
+/// The opening fence of a fenced code block, e.g. ```` ```ts title="example.ts" ````.
+struct Fence<'a> {
+    marker: u8,
+    len: usize,
+    lang: &'a str,
+}
+
+impl<'a> Fence<'a> {
+    fn parse(line: &'a str) -> Option<Self> {
+        // Fences can be indented, e.g. inside list items.
+        let line = line.trim_start();
+        let marker = *line.as_bytes().first().filter(|c| matches!(c, b'`' | b'~'))?;
+        let len = line.bytes().take_while(|&c| c == marker).count();
+        if len < 3 {
+            return None;
+        }
+        let info = line[len..].trim();
+        // The info string of a backtick fence cannot contain backticks.
+        if marker == b'`' && info.contains('`') {
+            return None;
+        }
+        let lang = info.split(|c: char| c.is_ascii_whitespace() || c == '{').next().unwrap_or("");
+        Some(Self { marker, len, lang })
+    }
+
+    fn is_closed_by(&self, line: &str) -> bool {
+        let line = line.trim();
+        line.len() >= self.len && line.bytes().all(|c| c == self.marker)
+    }
+
+    fn source_type(&self) -> Option<SourceType> {
+        let lang = self.lang.cow_to_ascii_lowercase();
+        let extension = match lang.as_ref() {
+            "javascript" => "js",
+            "typescript" => "ts",
+            lang @ ("js" | "mjs" | "cjs" | "jsx" | "ts" | "mts" | "cts" | "tsx") => lang,
+            _ => return None,
+        };
+        let mut source_type = SourceType::from_extension(extension).ok()?;
+        // Code samples are usually written as modules.
+        if source_type.is_unambiguous() {
+            source_type = source_type.with_module(true);
+        }
+        Some(source_type)
+    }
+}
+
+/// Whether an MDX line starts an ESM block, e.g. `import { Chart } from './chart.js'`.
+fn is_esm_statement(line: &str) -> bool {
+    ["import", "export"].iter().any(|keyword| {
+        line.strip_prefix(keyword)
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_whitespace() || c == '{'))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::MarkdownPartialLoader;
+
+    #[test]
+    fn test_parse_code_blocks() {
+        let source_text = "# Title
+
+```js
+const a = 1;
+```
+
+```sh
+npm install
+```
+
+- item
+
+  ~~~ts title=\"example.ts\"
+  let b: number = 2;
+  ~~~
+
+````tsx
+```
+<App />
+````
+";
+        let sources = MarkdownPartialLoader::new(source_text, false).parse();
+        assert_eq!(sources.len(), 3);
+
+        assert_eq!(sources[0].source_text, "const a = 1;\n");
+        assert_eq!(sources[0].start as usize, source_text.find("const a").unwrap());
+        assert!(sources[0].source_type.is_module());
+        assert!(!sources[0].source_type.is_typescript());
+
+        assert_eq!(sources[1].source_text, "  let b: number = 2;\n");
+        assert!(sources[1].source_type.is_typescript());
+
+        assert_eq!(sources[2].source_text, "```\n<App />\n");
+        assert!(sources[2].source_type.is_jsx());
+    }
+
+    #[test]
+    fn test_unclosed_code_block() {
+        let source_text = "```ts\nlet a = 1;\n";
+        let sources = MarkdownPartialLoader::new(source_text, false).parse();
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, "let a = 1;\n");
+    }
+
+    #[test]
+    fn test_parse_mdx_esm() {
+        let source_text = "import { Chart } from './chart.js'
+export const meta = {
+  title: 'Hello',
+}
+
+# Hello, <Chart />
+
+Importing things in text is fine: import x from 'y'
+
+```js
+console.log(meta);
+```
+";
+        let sources = MarkdownPartialLoader::new(source_text, true).parse();
+        assert_eq!(sources.len(), 2);
+        assert_eq!(
+            sources[0].source_text,
+            "import { Chart } from './chart.js'\nexport const meta = {\n  title: 'Hello',\n}\n"
+        );
+        assert_eq!(sources[0].start, 0);
+        assert_eq!(sources[1].source_text, "console.log(meta);\n");
+
+        // ESM is not extracted from plain markdown.
+        let sources = MarkdownPartialLoader::new(source_text, false).parse();
+        assert_eq!(sources.len(), 1);
+    }
+}
@@ -4,10 +4,14 @@ use oxc_span::VALID_EXTENSIONS;
 use crate::loader::JavaScriptSource;
 
 mod astro;
+mod markdown;
 mod svelte;
+mod template;
 mod vue;
 pub use astro::AstroPartialLoader;
+pub use markdown::MarkdownPartialLoader;
 pub use svelte::SveltePartialLoader;
+pub use template::TemplateReferences;
 pub use vue::VuePartialLoader;
 
 const SCRIPT_START: &str = "<script";
@@ -15,14 +19,22 @@ const SCRIPT_END: &str = "</script>";
 const COMMENT_START: &str = "<!--";
 const COMMENT_END: &str = "-->";
 
-/// File extensions that can contain JS/TS code in certain parts, such as in `<script>` tags, and can
-/// be loaded using the [`PartialLoader`].
-pub const LINT_PARTIAL_LOADER_EXTENSIONS: &[&str] = &["vue", "astro", "svelte"];
+/// File extensions of framework files that contain JS/TS code in `<script>` tags or similar.
+const FRAMEWORK_EXTENSIONS: &[&str] = &["vue", "astro", "svelte"];
+
+/// File extensions of Markdown files, whose fenced JS/TS code blocks are linted.
+/// Markdown files are only linted when explicitly enabled, so they are not in [`LINTABLE_EXTENSIONS`].
+pub const MARKDOWN_EXTENSIONS: &[&str] = &["md", "mdx"];
+
+/// File extensions that can contain JS/TS code in certain parts, such as in `<script>` tags or
+/// fenced code blocks, and can be loaded using the [`PartialLoader`].
+pub const LINT_PARTIAL_LOADER_EXTENSIONS: &[&str] =
+    constcat::concat_slices!([&str]: FRAMEWORK_EXTENSIONS, MARKDOWN_EXTENSIONS);
 
 /// All valid JavaScript/TypeScript extensions, plus additional framework files that
 /// contain JavaScript/TypeScript code in them (e.g., Vue, Astro, Svelte, etc.).
 pub const LINTABLE_EXTENSIONS: &[&str] =
-    constcat::concat_slices!([&str]: VALID_EXTENSIONS, LINT_PARTIAL_LOADER_EXTENSIONS);
+    constcat::concat_slices!([&str]: VALID_EXTENSIONS, FRAMEWORK_EXTENSIONS);
 
 pub struct PartialLoader;
 
@@ -34,6 +46,22 @@ impl PartialLoader {
             "vue" => Some(VuePartialLoader::new(source_text).parse()),
             "astro" => Some(AstroPartialLoader::new(source_text).parse()),
             "svelte" => Some(SveltePartialLoader::new(source_text).parse()),
+            "md" => Some(MarkdownPartialLoader::new(source_text, false).parse()),
+            "mdx" => Some(MarkdownPartialLoader::new(source_text, true).parse()),
+            _ => None,
+        }
+    }
+
+    /// Collect the names used by the template of special files, so that script bindings which
+    /// are only used there are not considered unused.
+    /// Returns `None` if the template of the file is not scanned.
+    pub fn parse_template_references<'a>(
+        ext: &str,
+        source_text: &'a str,
+    ) -> Option<TemplateReferences<'a>> {
+        match ext {
+            "vue" => Some(VuePartialLoader::new(source_text).parse_template_references()),
+            "svelte" => Some(SveltePartialLoader::new(source_text).parse_template_references()),
             _ => None,
         }
     }
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use memchr::memmem::{Finder, FinderRev};
 
 use oxc_span::SourceType;
@@ -7,8 +9,16 @@ use crate::loader::JavaScriptSource;
 use super::{
     COMMENT_END, COMMENT_START, SCRIPT_END, SCRIPT_START, find_script_closing_angle,
     find_script_start,
+    template::{Tag, TemplateReferences, collect_identifiers, find_closing_brace, parse_tag},
 };
 
+/// Directives whose name refers to a function in scope, e.g. `fade` in `transition:fade`.
+const FUNCTION_DIRECTIVES: &[&str] = &["animate", "in", "out", "transition", "use"];
+
+/// Directives whose name refers to a variable when they have no value, e.g. `active` in
+/// `class:active`.
+const SHORTHAND_DIRECTIVES: &[&str] = &["bind", "class", "style"];
+
 pub struct SveltePartialLoader<'a> {
     source_text: &'a str,
 }
@@ -73,12 +83,94 @@ impl<'a> SveltePartialLoader<'a> {
         #[expect(clippy::cast_possible_truncation)]
         Some(JavaScriptSource::partial(source_text, source_type, js_start as u32))
     }
+
+    /// Collects the names used by the markup: identifiers in `{ }` tags and blocks, attribute
+    /// values, directives, and component tag names.
+    pub fn parse_template_references(&self) -> TemplateReferences<'a> {
+        let mut references = TemplateReferences::default();
+        let mut rest = self.source_text;
+
+        while let Some(offset) = rest.find(['<', '{']) {
+            rest = &rest[offset..];
+            if let Some(comment) = rest.strip_prefix(COMMENT_START) {
+                rest = comment.find(COMMENT_END).map_or("", |end| &comment[end..]);
+            } else if let Some(expression) = rest.strip_prefix('{') {
+                let end = find_closing_brace(expression).unwrap_or(expression.len());
+                collect_identifiers(strip_block_keyword(&expression[..end]), &mut references);
+                rest = &expression[end..];
+            } else if let Some(tag) = rest.strip_prefix('<')
+                && tag.starts_with(|c: char| c.is_ascii_alphabetic())
+            {
+                let (tag, after) = parse_tag(tag);
+                rest = after;
+                if matches!(tag.name, "script" | "style") {
+                    let end_tag = format!("</{}>", tag.name);
+                    rest = rest.find(&end_tag).map_or("", |end| &rest[end..]);
+                } else {
+                    Self::collect_tag_references(&tag, &mut references);
+                }
+            } else {
+                rest = &rest[1..];
+            }
+        }
+
+        references
+    }
+
+    fn collect_tag_references(tag: &Tag<'a>, references: &mut TemplateReferences<'a>) {
+        // `<Component>` and `<Foo.Bar>`
+        if tag.name.starts_with(|c: char| c.is_ascii_uppercase()) {
+            references.insert(Cow::Borrowed(tag.name.split('.').next().unwrap_or_default()));
+        }
+
+        for attribute in &tag.attributes {
+            // `{value}` and `{...props}`
+            if attribute.name.starts_with('{') {
+                collect_identifiers(attribute.name, references);
+                continue;
+            }
+            if let Some((directive, name)) = attribute.name.split_once(':') {
+                let name = name.split('|').next().unwrap_or_default();
+                if FUNCTION_DIRECTIVES.contains(&directive)
+                    || (attribute.value.is_none() && SHORTHAND_DIRECTIVES.contains(&directive))
+                {
+                    references.insert(Cow::Borrowed(name));
+                }
+            }
+            // `value={count}` and `title="{count} items"`
+            let mut value = attribute.value.unwrap_or_default();
+            while let Some(start) = value.find('{') {
+                let expression = &value[start + 1..];
+                let end = find_closing_brace(expression).unwrap_or(expression.len());
+                collect_identifiers(&expression[..end], references);
+                value = &expression[end..];
+            }
+        }
+    }
+}
+
+/// Strips the keyword of a block or special tag, e.g. `#if` in `{#if visible}` or `@html` in
+/// `{@html content}`.
+fn strip_block_keyword(expression: &str) -> &str {
+    let expression = expression.trim_start();
+    if expression.starts_with(['#', ':', '/', '@']) {
+        expression[1..].trim_start_matches(|c: char| c.is_ascii_alphanumeric())
+    } else {
+        expression
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::{JavaScriptSource, SveltePartialLoader};
 
+    fn template_references(source_text: &str) -> Vec<String> {
+        let references = SveltePartialLoader::new(source_text).parse_template_references();
+        let mut references: Vec<String> = references.into_iter().map(Into::into).collect();
+        references.sort();
+        references
+    }
+
     fn parse_svelte(source_text: &str) -> JavaScriptSource<'_> {
         let sources = SveltePartialLoader::new(source_text).parse();
         *sources.first().unwrap()
@@ -143,4 +235,26 @@ mod test {
         );
         assert_eq!(sources[1].source_text.trim(), r#"console.log("hi");"#);
     }
+
+    #[test]
+    fn test_template_references() {
+        let source_text = r#"
+        <script>let notInTemplate = { a: 1 };</script>
+        <style>p { color: red; }</style>
+        <button on:click={toggle} class:active use:tooltip={options}>Toggle</button>
+        {#if visible}
+          <p transition:fade title="{count} items">Hello {user.name}! {$store}</p>
+        {:else}
+          <Nested {answer} {...rest} />
+        {/if}
+        "#;
+
+        assert_eq!(
+            template_references(source_text),
+            [
+                "$store", "Nested", "active", "answer", "count", "fade", "options", "rest",
+                "toggle", "tooltip", "user", "visible"
+            ]
+        );
+    }
 }
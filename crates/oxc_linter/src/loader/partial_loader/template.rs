@@ -0,0 +1,165 @@
+//! Helpers for scanning the markup of framework files for names used by the template.
+//!
+//! The scanning is intentionally approximate: it collects every identifier which could refer to a
+//! script binding. Collecting too many names only hides some unused variables, while missing one
+//! would report a variable which is actually used.
+
+use std::borrow::Cow;
+
+use rustc_hash::FxHashSet;
+
+/// Names referenced from the template of a framework file, such as `count` in Vue's `{{ count }}`
+/// or Svelte's `{count}`, and component tag names.
+pub type TemplateReferences<'a> = FxHashSet<Cow<'a, str>>;
+
+/// An opening tag, e.g. `<MyComponent :value="count" @click="increment">`.
+pub struct Tag<'a> {
+    pub name: &'a str,
+    pub attributes: Vec<Attribute<'a>>,
+}
+
+/// An attribute of a [`Tag`]. The value does not include the surrounding quotes.
+pub struct Attribute<'a> {
+    pub name: &'a str,
+    pub value: Option<&'a str>,
+}
+
+/// Parses an opening tag. `text` starts right after the `<`.
+///
+/// Returns the tag and the text after it.
+pub fn parse_tag(text: &str) -> (Tag<'_>, &str) {
+    let name_end =
+        text.find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/').unwrap_or(text.len());
+    let mut tag = Tag { name: &text[..name_end], attributes: vec![] };
+    let mut rest = &text[name_end..];
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+        if rest.is_empty() {
+            return (tag, rest);
+        }
+        if let Some(after) = rest.strip_prefix('>') {
+            return (tag, after);
+        }
+
+        // Svelte attribute shorthand and spread, e.g. `{value}` and `{...props}`.
+        if rest.starts_with('{') {
+            let end = find_closing_brace(&rest[1..]).map_or(rest.len(), |end| end + 2);
+            tag.attributes.push(Attribute { name: &rest[..end], value: None });
+            rest = &rest[end..];
+            continue;
+        }
+
+        let name_end = rest
+            .find(|c: char| c.is_ascii_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(rest.len());
+        let name = &rest[..name_end];
+        rest = rest[name_end..].trim_start();
+
+        let Some(after_equals) = rest.strip_prefix('=') else {
+            tag.attributes.push(Attribute { name, value: None });
+            continue;
+        };
+        rest = after_equals.trim_start();
+
+        let (value, after) = match rest.as_bytes().first() {
+            Some(&quote @ (b'"' | b'\'')) => {
+                let value = &rest[1..];
+                let end = value.find(char::from(quote)).unwrap_or(value.len());
+                (&value[..end], value.get(end + 1..).unwrap_or_default())
+            }
+            Some(b'{') => {
+                let end = find_closing_brace(&rest[1..]).map_or(rest.len(), |end| end + 2);
+                (&rest[..end], &rest[end..])
+            }
+            _ => {
+                let end =
+                    rest.find(|c: char| c.is_ascii_whitespace() || c == '>').unwrap_or(rest.len());
+                (&rest[..end], &rest[end..])
+            }
+        };
+        tag.attributes.push(Attribute { name, value: Some(value) });
+        rest = after;
+    }
+}
+
+/// Returns the offset of the `}` matching an already consumed `{`, skipping nested braces and
+/// string literals.
+pub fn find_closing_brace(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'"' | b'\'' | b'`') => i = skip_string(bytes, i, quote),
+            b'{' => depth += 1,
+            b'}' if depth == 0 => return Some(i),
+            b'}' => depth -= 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Collects all identifiers in a template expression which are not property names, e.g. `items`
+/// and `format` in `items.map((item) => format(item.name))`.
+pub fn collect_identifiers<'a>(expression: &'a str, references: &mut TemplateReferences<'a>) {
+    let bytes = expression.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'"' | b'\'') => i = skip_string(bytes, i, quote) + 1,
+            b'0'..=b'9' => {
+                while i < bytes.len() && (is_identifier_part(bytes[i]) || bytes[i] == b'.') {
+                    i += 1;
+                }
+            }
+            c if is_identifier_start(c) => {
+                let start = i;
+                while i < bytes.len() && is_identifier_part(bytes[i]) {
+                    i += 1;
+                }
+                let before = expression[..start].trim_end();
+                // Skip `b` in `a.b` and `a?.b`, but not `b` in `...b`.
+                if !before.ends_with('.') || before.ends_with("...") {
+                    references.insert(Cow::Borrowed(&expression[start..i]));
+                }
+            }
+            _ => i += 1,
+        }
+    }
+}
+
+/// Converts a kebab-case name to PascalCase, e.g. `my-component` to `MyComponent`.
+pub fn kebab_to_pascal_case(name: &str) -> String {
+    name.split('-')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            })
+        })
+        .collect()
+}
+
+/// Returns the index of the closing quote of the string starting at `start`.
+fn skip_string(bytes: &[u8], start: usize, quote: u8) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() && bytes[i] != quote {
+        if bytes[i] == b'\\' {
+            i += 1;
+        }
+        i += 1;
+    }
+    i
+}
+
+fn is_identifier_start(c: u8) -> bool {
+    c.is_ascii_alphabetic() || c == b'_' || c == b'$' || !c.is_ascii()
+}
+
+fn is_identifier_part(c: u8) -> bool {
+    is_identifier_start(c) || c.is_ascii_digit()
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use memchr::memmem::{Finder, FinderRev};
 
 use oxc_span::SourceType;
@@ -7,8 +9,15 @@ use crate::frameworks::FrameworkOptions;
 use super::{
     COMMENT_END, COMMENT_START, JavaScriptSource, SCRIPT_END, SCRIPT_START,
     find_script_closing_angle, find_script_start,
+    template::{Tag, TemplateReferences, collect_identifiers, kebab_to_pascal_case, parse_tag},
 };
 
+/// Built-in directives, which unlike custom directives do not refer to a `vName` binding.
+const BUILTIN_DIRECTIVES: &[&str] = &[
+    "bind", "cloak", "else", "else-if", "for", "html", "if", "is", "memo", "model", "on", "once",
+    "pre", "show", "slot", "text",
+];
+
 pub struct VuePartialLoader<'a> {
     source_text: &'a str,
 }
@@ -94,6 +103,78 @@ impl<'a> VuePartialLoader<'a> {
         ))
     }
 
+    /// Collects the names used by the `<template>` block: identifiers in `{{ }}` interpolations and
+    /// directive values, component tag names, custom directives and template refs.
+    /// <https://vuejs.org/api/sfc-script-setup.html#using-components>
+    pub fn parse_template_references(&self) -> TemplateReferences<'a> {
+        let mut references = TemplateReferences::default();
+        let Some(start) = self.source_text.find("<template") else {
+            return references;
+        };
+        let end = self.source_text.rfind("</template>").unwrap_or(self.source_text.len());
+        let mut rest = self.source_text.get(start..end).unwrap_or_default();
+
+        while let Some(offset) = rest.find(['<', '{']) {
+            rest = &rest[offset..];
+            if let Some(comment) = rest.strip_prefix(COMMENT_START) {
+                rest = comment.find(COMMENT_END).map_or("", |end| &comment[end..]);
+            } else if let Some(interpolation) = rest.strip_prefix("{{") {
+                let end = interpolation.find("}}").unwrap_or(interpolation.len());
+                collect_identifiers(&interpolation[..end], &mut references);
+                rest = &interpolation[end..];
+            } else if let Some(tag) = rest.strip_prefix('<')
+                && tag.starts_with(|c: char| c.is_ascii_alphabetic())
+            {
+                let (tag, after) = parse_tag(tag);
+                Self::collect_tag_references(&tag, &mut references);
+                rest = after;
+            } else {
+                rest = &rest[1..];
+            }
+        }
+
+        references
+    }
+
+    fn collect_tag_references(tag: &Tag<'a>, references: &mut TemplateReferences<'a>) {
+        // `<MyComponent>`, `<my-component>` and `<Foo.Bar>`
+        let name = tag.name.split('.').next().unwrap_or_default();
+        if name.contains('-') {
+            let pascal_case = kebab_to_pascal_case(name);
+            let mut camel_case = pascal_case.clone();
+            camel_case[..1].make_ascii_lowercase();
+            references.insert(Cow::Owned(pascal_case));
+            references.insert(Cow::Owned(camel_case));
+        } else if name.starts_with(|c: char| c.is_ascii_uppercase()) {
+            references.insert(Cow::Borrowed(name));
+        }
+
+        for attribute in &tag.attributes {
+            let name = attribute.name;
+            if let Some(directive) = name.strip_prefix("v-") {
+                let directive = directive.split([':', '.']).next().unwrap_or_default();
+                if !BUILTIN_DIRECTIVES.contains(&directive) {
+                    references.insert(Cow::Owned(format!("v{}", kebab_to_pascal_case(directive))));
+                }
+            } else if name == "ref" {
+                // `ref="input"` refers to a `const input = ref()` binding in `<script setup>`.
+                if let Some(value) = attribute.value {
+                    references.insert(Cow::Borrowed(value));
+                }
+                continue;
+            } else if !name.starts_with([':', '@', '#']) {
+                continue;
+            }
+            // Dynamic arguments, e.g. `:[key]="value"`
+            if let Some((_, argument)) = name.split_once('[') {
+                collect_identifiers(argument, references);
+            }
+            if let Some(value) = attribute.value {
+                collect_identifiers(value, references);
+            }
+        }
+    }
+
     fn extract_lang_attribute(content: &str) -> &str {
         let content = content.trim();
 
@@ -136,6 +217,13 @@ impl<'a> VuePartialLoader<'a> {
 mod test {
     use super::{JavaScriptSource, VuePartialLoader};
 
+    fn template_references(source_text: &str) -> Vec<String> {
+        let references = VuePartialLoader::new(source_text).parse_template_references();
+        let mut references: Vec<String> = references.into_iter().map(Into::into).collect();
+        references.sort();
+        references
+    }
+
     fn parse_vue(source_text: &str) -> JavaScriptSource<'_> {
         let sources = VuePartialLoader::new(source_text).parse();
         *sources.first().unwrap()
@@ -345,4 +433,25 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_template_references() {
+        let source_text = r#"
+        <script setup>const a = 1</script>
+        <template>
+          <!-- {{ commented }} -->
+          <my-button :label="format(user.name)" @click="count++" v-focus>{{ title }}</my-button>
+          <Icons.Close v-if="visible" ref="input" class="static" />
+          <p :title="'a' + b">{{ items.map((item) => item.id) }}</p>
+        </template>
+        "#;
+
+        assert_eq!(
+            template_references(source_text),
+            [
+                "Icons", "MyButton", "b", "count", "format", "input", "item", "items", "myButton",
+                "title", "user", "vFocus", "visible"
+            ]
+        );
+    }
 }
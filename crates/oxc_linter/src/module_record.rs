@@ -85,6 +85,34 @@ pub struct ModuleRecord {
     /// `export default name`
     ///         ^^^^^^^ span
     pub export_default: Option<Span>,
+
+    /// Resolutions of the ModuleSpecifiers in `requested_modules` and of the specifiers of
+    /// `require("...")` calls. This is a non-standard field.
+    ///
+    /// Only populated when cross-module analysis (the import plugin) is enabled. `require("...")`
+    /// calls are only resolved when `import/no-unresolved` or `import/no-extraneous-dependencies`
+    /// is enabled, and `import("...")` calls when importers are tracked.
+    pub resolutions: FxHashMap<CompactStr, ModuleResolution>,
+
+    /// Modules which depend on this module, with the specifier they request it with.
+    /// This is a non-standard field.
+    ///
+    /// Includes `require("...")` and `import("...")` calls. `None` if importers are not tracked,
+    /// see [ModuleRecord::importers].
+    importers: RwLock<Option<Importers>>,
+}
+
+type Importers = Vec<(CompactStr, Weak<ModuleRecord>)>;
+
+/// Result of resolving a module specifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModuleResolution {
+    /// Resolved to a file
+    Path(PathBuf),
+    /// A Node.js builtin module, e.g. `fs` or `node:fs`
+    Builtin,
+    /// The specifier could not be resolved
+    Unresolved,
 }
 
 impl fmt::Debug for ModuleRecord {
@@ -99,6 +127,15 @@ impl fmt::Debug for ModuleRecord {
             .reduce(|acc, key| format!("{acc}, {key}"))
             .unwrap_or_default();
         let loaded_modules = format!("{{ {loaded_modules} }}");
+        // only the paths of importers, for the same reason
+        let importers = self.importers.read().unwrap().as_ref().map(|importers| {
+            importers
+                .iter()
+                .filter_map(|(_, importer)| {
+                    Some(importer.upgrade()?.resolved_absolute_path.clone())
+                })
+                .collect::<Vec<_>>()
+        });
         f.debug_struct("ModuleRecord")
             .field("has_module_syntax", &self.has_module_syntax)
             .field("resolved_absolute_path", &self.resolved_absolute_path)
@@ -111,6 +148,8 @@ impl fmt::Debug for ModuleRecord {
             .field("exported_bindings", &self.exported_bindings)
             .field("exported_bindings_from_star_export", &self.exported_bindings_from_star_export)
             .field("export_default", &self.export_default)
+            .field("resolutions", &self.resolutions)
+            .field("importers", &importers)
             .finish()
     }
 }
@@ -538,6 +577,42 @@ impl ModuleRecord {
         loaded_modules.get(key).map(|weak| Weak::upgrade(weak).unwrap())
     }
 
+    /// Track the modules which depend on this module.
+    ///
+    /// # Panics
+    ///
+    /// * If the RwLock is poisoned (which only happens if a thread panicked while holding the lock).
+    pub(crate) fn track_importers(&self) {
+        self.importers.write().unwrap().get_or_insert_default();
+    }
+
+    /// # Panics
+    ///
+    /// * If the RwLock is poisoned (which only happens if a thread panicked while holding the lock).
+    pub(crate) fn add_importer(&self, specifier: CompactStr, importer: Weak<ModuleRecord>) {
+        self.importers.write().unwrap().get_or_insert_default().push((specifier, importer));
+    }
+
+    /// The modules which depend on this module, with the specifier they request it with.
+    ///
+    /// Importers are only tracked when a rule needs them and all files are linted together, so
+    /// that the module graph is complete before any module is linted. Returns `None` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// * If the RwLock is poisoned (which only happens if a thread panicked while holding the lock).
+    /// * If `ModuleRecord` is dropped (fails to Weak::upgrade).
+    pub fn importers(&self) -> Option<Vec<(CompactStr, Arc<ModuleRecord>)>> {
+        let importers = self.importers.read().unwrap();
+        Some(
+            importers
+                .as_ref()?
+                .iter()
+                .map(|(specifier, importer)| (specifier.clone(), Weak::upgrade(importer).unwrap()))
+                .collect(),
+        )
+    }
+
     pub(crate) fn exported_bindings_from_star_export(
         &self,
     ) -> &FxHashMap<PathBuf, Vec<CompactStr>> {
@@ -13,4 +13,6 @@ pub struct LintOptions {
     pub fix: FixKind,
     pub framework_hints: FrameworkFlags,
     pub report_unused_directive: Option<AllowWarnDeny>,
+    /// Report diagnostics which are suppressed by disable directives, marked as suppressed.
+    pub report_suppressed: bool,
 }
@@ -24,6 +24,7 @@ pub(crate) mod import {
     pub mod no_duplicates;
     pub mod no_dynamic_require;
     pub mod no_empty_named_blocks;
+    pub mod no_extraneous_dependencies;
     pub mod no_mutable_exports;
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
@@ -34,7 +35,10 @@ pub(crate) mod import {
     pub mod no_relative_parent_imports;
     pub mod no_self_import;
     pub mod no_unassigned_import;
+    pub mod no_unresolved;
+    pub mod no_unused_modules;
     pub mod no_webpack_loader_syntax;
+    pub mod order;
     pub mod prefer_default_export;
     pub mod unambiguous;
 }
@@ -145,6 +149,7 @@ pub(crate) mod eslint {
     pub mod no_regex_spaces;
     pub mod no_restricted_globals;
     pub mod no_restricted_imports;
+    pub mod no_restricted_syntax;
     pub mod no_return_assign;
     pub mod no_script_url;
     pub mod no_self_assign;
@@ -163,6 +168,7 @@ pub(crate) mod eslint {
     pub mod no_unexpected_multiline;
     pub mod no_unneeded_ternary;
     pub mod no_unreachable;
+    pub mod no_unreachable_loop;
     pub mod no_unsafe_finally;
     pub mod no_unsafe_negation;
     pub mod no_unsafe_optional_chaining;
@@ -170,6 +176,7 @@ pub(crate) mod eslint {
     pub mod no_unused_labels;
     pub mod no_unused_private_class_members;
     pub mod no_unused_vars;
+    pub mod no_useless_assignment;
     pub mod no_useless_backreference;
     pub mod no_useless_call;
     pub mod no_useless_catch;
@@ -222,6 +229,7 @@ pub(crate) mod typescript {
     pub mod consistent_type_imports;
     pub mod explicit_function_return_type;
     pub mod explicit_module_boundary_types;
+    pub mod naming_convention;
     pub mod no_array_delete;
     pub mod no_base_to_string;
     pub mod no_confusing_non_null_assertion;
@@ -692,6 +700,9 @@ pub(crate) mod node {
     pub mod no_exports_assign;
     pub mod no_new_require;
     pub mod no_process_env;
+    pub mod no_unsupported_features_es_builtins;
+    pub mod no_unsupported_features_es_syntax;
+    pub mod no_unsupported_features_node_builtins;
 }
 
 pub(crate) mod vue {
@@ -0,0 +1,322 @@
+use cow_utils::CowUtils;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::de::Error;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{EsQuery, QueryContext},
+};
+
+fn no_restricted_syntax_diagnostic(
+    selector: &str,
+    message: Option<&str>,
+    span: Span,
+) -> OxcDiagnostic {
+    let message = match message {
+        // Custom messages may refer to the selector, like ESLint's message placeholders.
+        Some(message) => message.cow_replace("{{selector}}", selector).into_owned(),
+        None => format!("Using '{selector}' is not allowed."),
+    };
+    OxcDiagnostic::warn(message).with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax(Box<NoRestrictedSyntaxConfig>);
+
+#[derive(Debug, Default, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoRestrictedSyntaxConfig {
+    /// Selectors, either as strings or as objects in the format
+    /// `{ "selector": "WithStatement", "message": "Avoid `with`." }`, which define the syntax
+    /// that is restricted from use.
+    restricted_syntax: Vec<RestrictedSyntax>,
+}
+
+impl std::ops::Deref for NoRestrictedSyntax {
+    type Target = NoRestrictedSyntaxConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct RestrictedSyntax {
+    /// An [ESQuery](https://github.com/estools/esquery) selector matching the restricted nodes.
+    selector: String,
+    /// Custom message to report instead of the default one.
+    message: Option<String>,
+    #[schemars(skip)]
+    query: EsQuery,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows syntax matching the configured
+    /// [selectors](https://eslint.org/docs/latest/extend/selectors).
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript has a lot of language features, and not everyone likes all of them. Some
+    /// projects want to restrict the use of certain language constructs, such as `try`/`catch`,
+    /// `class`, or calls to specific functions, without writing a dedicated rule for each of them.
+    ///
+    /// Selectors are matched against the ESTree shape of the AST, so selectors written for
+    /// ESLint work unchanged. Node types, attributes (`[callee.name="eval"]`, regexes and
+    /// `type(...)` values), fields (`.init`), the descendant, child (`>`), sibling (`~`) and
+    /// adjacent (`+`) combinators, and the `:not`, `:matches`, `:is`, `:has`, `:first-child`,
+    /// `:last-child`, `:nth-child`, `:nth-last-child`, `:statement`, `:expression`,
+    /// `:declaration`, `:function` and `:pattern` pseudo-selectors are supported.
+    ///
+    /// ### Examples
+    ///
+    /// If we have options:
+    ///
+    /// ```json
+    /// "no-restricted-syntax": [
+    ///   "error",
+    ///   "FunctionExpression",
+    ///   { "selector": "CallExpression[callee.name='setTimeout'][arguments.length!=2]", "message": "setTimeout must always be invoked with two arguments." }
+    /// ]
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const doSomething = function () {};
+    ///
+    /// setTimeout(() => {});
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// function doSomething() {}
+    ///
+    /// setTimeout(() => {}, 100);
+    /// ```
+    NoRestrictedSyntax,
+    eslint,
+    restriction,
+    config = NoRestrictedSyntaxConfig,
+);
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        let Value::Array(entries) = value else { return Ok(Self::default()) };
+        let restricted_syntax = entries
+            .iter()
+            .filter_map(|entry| match entry {
+                // "no-restricted-syntax": ["error", "WithStatement"]
+                Value::String(selector) => Some((selector.as_str(), None)),
+                // "no-restricted-syntax": ["error", { "selector": "WithStatement", "message": "..." }]
+                Value::Object(obj) => obj.get("selector").and_then(Value::as_str).map(|selector| {
+                    (selector, obj.get("message").and_then(Value::as_str).map(ToString::to_string))
+                }),
+                _ => None,
+            })
+            .map(|(selector, message)| {
+                let query = EsQuery::parse(selector).map_err(|err| {
+                    serde_json::Error::custom(format!("invalid selector `{selector}`: {err}"))
+                })?;
+                Ok(RestrictedSyntax { selector: selector.to_string(), message, query })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self(Box::new(NoRestrictedSyntaxConfig { restricted_syntax })))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        if self.restricted_syntax.is_empty() {
+            return;
+        }
+        let query_ctx = QueryContext::new(ctx.nodes(), ctx.source_text());
+        for node in ctx.nodes() {
+            for restricted in &self.restricted_syntax {
+                if restricted.query.matches(&query_ctx, node.id()) {
+                    ctx.diagnostic(no_restricted_syntax_diagnostic(
+                        &restricted.selector,
+                        restricted.message.as_deref(),
+                        node.kind().span(),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        // https://github.com/eslint/eslint/blob/main/tests/lib/rules/no-restricted-syntax.js
+        ("doSomething();", None),
+        ("var foo = 42;", Some(json!(["ConditionalExpression"]))),
+        ("foo += 42;", Some(json!(["VariableDeclaration", "FunctionExpression"]))),
+        ("foo;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("() => 5", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, bar: 2 })", Some(json!(["Property > Literal.key"]))),
+        ("A: for (;;) break;", Some(json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz) {}", Some(json!(["FunctionDeclaration[params.length>2]"]))),
+        ("var foo = 42;", Some(json!([{ "selector": "ConditionalExpression" }]))),
+        ("({ foo: 1, bar: 2 })", Some(json!([{ "selector": "Property > Literal.key" }]))),
+        (
+            "({ foo: 1, bar: 2 })",
+            Some(json!([{ "selector": "FunctionDeclaration[params.length>2]" }])),
+        ),
+        ("console.log(/a/);", Some(json!(["Literal[regex.flags=/./]"]))),
+        // attribute values
+        ("foo(1)", Some(json!(["Literal[value='1']:not(:first-child)"]))),
+        ("foo(1, 2)", Some(json!(["Literal[value>2]"]))),
+        ("foo('a')", Some(json!(["Literal[value=type(number)]"]))),
+        ("foo('bar')", Some(json!(["CallExpression[callee.name!=/^f/]"]))),
+        ("foo.bar()", Some(json!(["CallExpression[callee.property.name='baz']"]))),
+        // combinators
+        (
+            "a; b;",
+            Some(json!(["ExpressionStatement + ExpressionStatement > Identifier[name='a']"])),
+        ),
+        ("a; b;", Some(json!(["ExpressionStatement:has(Identifier[name='a']) ~ EmptyStatement"]))),
+        ("if (a) { b }", Some(json!(["IfStatement > ExpressionStatement"]))),
+        ("f(function () {})", Some(json!(["ArrowFunctionExpression:has(> BlockStatement)"]))),
+        ("const { a } = b;", Some(json!(["VariableDeclarator > Identifier.id"]))),
+        // parentheses and parameters are not nodes
+        ("(a)", Some(json!(["ParenthesizedExpression"]))),
+        ("function f(a) {}", Some(json!(["AssignmentPattern"]))),
+        // classes
+        ("import.meta", Some(json!([":expression:not(MetaProperty)"]))),
+        ("a = 1", Some(json!([":statement:not(ExpressionStatement, Program)"]))),
+        // TypeScript
+        ("let a: string;", Some(json!(["TSAnyKeyword"]))),
+    ];
+
+    let fail = vec![
+        // https://github.com/eslint/eslint/blob/main/tests/lib/rules/no-restricted-syntax.js
+        ("var foo = 41;", Some(json!(["VariableDeclaration"]))),
+        (";function lol(a) { return 42; }", Some(json!(["EmptyStatement"]))),
+        (
+            "try { voila(); } catch (e) { oops(); }",
+            Some(json!(["TryStatement", "CallExpression", "CatchClause"])),
+        ),
+        ("bar;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("bar;", Some(json!(["Identifier", "Identifier[name=\"bar\"]"]))),
+        ("() => {}", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, 'bar': 2 })", Some(json!(["Property > Literal.key"]))),
+        ("A: for (;;) break A;", Some(json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz, qux) {}", Some(json!(["FunctionDeclaration[params.length>2]"]))),
+        ("var foo = 41;", Some(json!([{ "selector": "VariableDeclaration" }]))),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(json!([{ "selector": "FunctionDeclaration[params.length>2]" }])),
+        ),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(
+                json!([{ "selector": "FunctionDeclaration[params.length>2]", "message": "custom error message." }]),
+            ),
+        ),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(
+                json!([{ "selector": "FunctionDeclaration[params.length>2]", "message": "custom message with {{selector}}" }]),
+            ),
+        ),
+        ("console.log(/a/i);", Some(json!(["Literal[regex.flags=/./]"]))),
+        ("var foo = foo as string;", Some(json!(["TSAsExpression > Identifier"]))),
+        ("a?.b", Some(json!(["ChainExpression"]))),
+        ("a?.b", Some(json!(["MemberExpression[optional=true]"]))),
+        ("a.b?.()", Some(json!(["CallExpression[optional=true]"]))),
+        ("bar?.foo", Some(json!(["ChainExpression MemberExpression"]))),
+        ("a?.b", Some(json!(["MemberExpression:matches([optional=true], [computed])"]))),
+        // attribute values
+        (
+            "setTimeout(() => {})",
+            Some(json!(["CallExpression[callee.name='setTimeout'][arguments.length!=2]"])),
+        ),
+        ("foo(1, 2, 3)", Some(json!(["Literal[value>=2]"]))),
+        ("foo('a')", Some(json!(["Literal[value=type(string)]"]))),
+        ("foo('bar')", Some(json!(["CallExpression[callee.name=/^F/i]"]))),
+        ("foo.bar()", Some(json!(["CallExpression[callee.property.name=bar]"]))),
+        (
+            "eval('x')",
+            Some(json!(["CallExpression[callee.type='Identifier'][callee.name='eval']"])),
+        ),
+        ("a.b.c = 1", Some(json!(["AssignmentExpression[left.object.object.name='a']"]))),
+        ("`a${b}c`", Some(json!(["TemplateElement[value.raw='c'][tail=true]"]))),
+        ("x = 10n", Some(json!(["Literal[bigint]"]))),
+        ("x = null", Some(json!(["Literal[value=null]"]))),
+        ("x = true", Some(json!(["Literal[value=true]"]))),
+        // fields
+        (
+            "const { a } = b;",
+            Some(json!(["VariableDeclarator > ObjectPattern.id Identifier.value"])),
+        ),
+        ("foo.bar.baz()", Some(json!([".callee.object"]))),
+        ("for (const a of b) {}", Some(json!(["ForOfStatement > .left"]))),
+        // combinators
+        ("a; b; c;", Some(json!(["ExpressionStatement + ExpressionStatement"]))),
+        ("a; b; c;", Some(json!(["ExpressionStatement ~ ExpressionStatement"]))),
+        (
+            "a; b; c;",
+            Some(json!(["ExpressionStatement:first-child, ExpressionStatement:last-child"])),
+        ),
+        (
+            "a; b; c;",
+            Some(json!([
+                "ExpressionStatement:nth-child(2), ExpressionStatement:nth-last-child(3)"
+            ])),
+        ),
+        ("foo(a, b)", Some(json!(["CallExpression > Identifier:nth-child(1)"]))),
+        ("if (a) { b }", Some(json!(["IfStatement > BlockStatement > ExpressionStatement"]))),
+        ("if (a) { b }", Some(json!(["IfStatement ExpressionStatement"]))),
+        ("f(() => { return 1 })", Some(json!(["ArrowFunctionExpression:has(> BlockStatement)"]))),
+        ("f(() => { return 1 })", Some(json!(["CallExpression:has(ReturnStatement Literal)"]))),
+        ("f(() => { return 1 })", Some(json!(["CallExpression:not(:has(ThrowStatement))"]))),
+        ("arr.map(x => x * 2)", Some(json!(["ArrowFunctionExpression > BinaryExpression.body"]))),
+        ("a, b", Some(json!(["SequenceExpression > :not(Identifier[name='a'])"]))),
+        // parentheses and parameters
+        ("(a) + 1", Some(json!(["BinaryExpression > Identifier.left"]))),
+        (
+            "function f(a = 1, ...b) {}",
+            Some(json!([
+                "FunctionDeclaration > AssignmentPattern",
+                "FunctionDeclaration > RestElement"
+            ])),
+        ),
+        ("try {} catch ({ message }) {}", Some(json!(["CatchClause > ObjectPattern.param"]))),
+        // classes
+        ("function a() {} const b = () => {}", Some(json!([":function"]))),
+        ("import.meta.url", Some(json!([":expression"]))),
+        ("class A {} let a;", Some(json!([":declaration"]))),
+        ("[a] = b", Some(json!([":pattern.left"]))),
+        // case-insensitive types and wildcards
+        ("debugger", Some(json!(["debuggerstatement"]))),
+        ("x", Some(json!(["Program > *"]))),
+        // JSX and TypeScript
+        ("<Foo bar='baz' />", Some(json!(["JSXOpeningElement > JSXIdentifier[name='Foo']"]))),
+        ("<div>{x}</div>", Some(json!(["JSXExpressionContainer > Identifier"]))),
+        ("let a: any;", Some(json!(["TSAnyKeyword"]))),
+        ("enum E { A }", Some(json!(["TSEnumDeclaration[const=false]"]))),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, NoRestrictedSyntax::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_invalid_selector() {
+    for selector in
+        ["", "Identifier[", "Identifier[name=/(/]", ":unknown", "A >", "A:has()", "[a</x/]"]
+    {
+        let result = NoRestrictedSyntax::from_configuration(serde_json::json!([selector]));
+        assert!(result.is_err(), "expected `{selector}` to be rejected");
+    }
+}
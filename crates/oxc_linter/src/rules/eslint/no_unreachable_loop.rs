@@ -0,0 +1,284 @@
+use oxc_ast::AstKind;
+use oxc_cfg::{
+    BlockNodeId, ControlFlowGraph, EdgeType,
+    graph::{Direction, visit::EdgeRef},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::NodeId;
+use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn no_unreachable_loop_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Invalid loop. Its body allows only one iteration.")
+        .with_help("Use an `if` statement instead, or make sure the loop can continue.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnreachableLoop(Box<NoUnreachableLoopConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnreachableLoopConfig {
+    /// Loop types which are not checked, e.g. `["ForInStatement", "ForOfStatement"]`.
+    ignore: Vec<LoopType>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+enum LoopType {
+    #[serde(rename = "WhileStatement")]
+    While,
+    #[serde(rename = "DoWhileStatement")]
+    DoWhile,
+    #[serde(rename = "ForStatement")]
+    For,
+    #[serde(rename = "ForInStatement")]
+    ForIn,
+    #[serde(rename = "ForOfStatement")]
+    ForOf,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow loops with a body that allows only one iteration.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A loop whose body always exits with `break`, `return` or `throw` before reaching the next
+    /// iteration is not a loop. This is usually a mistake left behind by a refactor, e.g. a
+    /// `return` which was meant to be inside an `if` statement.
+    ///
+    /// Loops reached again through `continue`, including a labeled `continue` from a nested loop,
+    /// are not reported.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// for (const item of items) {
+    ///     if (item.id === id) {
+    ///         found = item;
+    ///     }
+    ///     break;
+    /// }
+    ///
+    /// outer: for (const row of rows) {
+    ///     for (const cell of row) {
+    ///         continue outer;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// for (const item of items) {
+    ///     if (item.id === id) {
+    ///         found = item;
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// outer: for (const row of rows) {
+    ///     for (const cell of row) {
+    ///         if (!cell) continue outer;
+    ///     }
+    /// }
+    /// ```
+    NoUnreachableLoop,
+    eslint,
+    nursery,
+    config = NoUnreachableLoopConfig,
+);
+
+impl Rule for NoUnreachableLoop {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let nodes = ctx.nodes();
+        let cfg = ctx.cfg();
+        let mut reachable = None;
+
+        for node in nodes.iter() {
+            let loop_type = match node.kind() {
+                AstKind::WhileStatement(_) => LoopType::While,
+                AstKind::DoWhileStatement(_) => LoopType::DoWhile,
+                AstKind::ForStatement(_) => LoopType::For,
+                AstKind::ForInStatement(_) => LoopType::ForIn,
+                AstKind::ForOfStatement(_) => LoopType::ForOf,
+                _ => continue,
+            };
+            if self.0.ignore.contains(&loop_type) {
+                continue;
+            }
+
+            let reachable =
+                reachable.get_or_insert_with(|| reachable_blocks(cfg, nodes.cfg_id(NodeId::ROOT)));
+            // Unreachable loops are reported by `no-unreachable`.
+            if !reachable[nodes.cfg_id(node.id()).index()] {
+                continue;
+            }
+            let Some(continue_block) = continue_block(node, ctx) else { continue };
+
+            // The next iteration starts where `continue` jumps to. The end of the body flows
+            // into it through a back edge, except in `do...while` loops, where the condition is
+            // evaluated after the body.
+            let is_do_while = loop_type == LoopType::DoWhile;
+            let iterates = cfg
+                .graph()
+                .edges_directed(continue_block, Direction::Incoming)
+                .filter(|edge| reachable[edge.source().index()])
+                .any(|edge| match edge.weight() {
+                    EdgeType::Backedge | EdgeType::Jump => true,
+                    EdgeType::Normal => is_do_while,
+                    _ => false,
+                });
+            if !iterates {
+                ctx.diagnostic(no_unreachable_loop_diagnostic(node.kind().span()));
+            }
+        }
+    }
+}
+
+/// Blocks which can be reached from `start` without going through `break`, `continue`,
+/// `return` or `throw`. Nested functions are always considered reachable.
+fn reachable_blocks(cfg: &ControlFlowGraph, start: BlockNodeId) -> Vec<bool> {
+    let graph = cfg.graph();
+    let mut reachable = vec![false; cfg.basic_blocks.len()];
+    reachable[start.index()] = true;
+    let mut stack = vec![start];
+    while let Some(block) = stack.pop() {
+        for edge in graph.edges_directed(block, Direction::Outgoing) {
+            if !matches!(edge.weight(), EdgeType::Unreachable) && !reachable[edge.target().index()]
+            {
+                reachable[edge.target().index()] = true;
+                stack.push(edge.target());
+            }
+        }
+    }
+    reachable
+}
+
+/// The block that `continue` in the body of `node` jumps to, which starts the next iteration.
+fn continue_block(node: &AstNode, ctx: &LintContext) -> Option<BlockNodeId> {
+    let nodes = ctx.nodes();
+    let graph = ctx.cfg().graph();
+    // Children are created right after their parent, in the order they are visited.
+    let child_block = |span: Span| {
+        nodes
+            .iter()
+            .skip(node.id().index() + 1)
+            .find(|child| nodes.parent_id(child.id()) == node.id() && child.kind().span() == span)
+            .map(|child| nodes.cfg_id(child.id()))
+    };
+    // The block flowing into `block` through a jump, or a back edge if `backedge` is set.
+    let source_of = |block: BlockNodeId, backedge: bool| {
+        graph
+            .edges_directed(block, Direction::Incoming)
+            .find(|edge| match edge.weight() {
+                EdgeType::Backedge => backedge,
+                EdgeType::Jump => !backedge,
+                _ => false,
+            })
+            .map(|edge| edge.source())
+    };
+
+    match node.kind() {
+        AstKind::WhileStatement(stmt) => child_block(stmt.test.span()),
+        AstKind::DoWhileStatement(stmt) => child_block(stmt.test.span()),
+        AstKind::ForStatement(stmt) => {
+            if let Some(update) = &stmt.update {
+                return child_block(update.span());
+            }
+            // Without an update, the update block is empty and only jumps back to the test.
+            let test = match &stmt.test {
+                Some(test) => child_block(test.span())?,
+                None => source_of(child_block(stmt.body.span())?, false)?,
+            };
+            source_of(test, true)
+        }
+        AstKind::ForInStatement(stmt) => source_of(child_block(stmt.body.span())?, false),
+        AstKind::ForOfStatement(stmt) => source_of(child_block(stmt.body.span())?, false),
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("while (foo) { bar(); }", None),
+        ("do { bar(); } while (foo)", None),
+        ("for (let i = 0; i < 10; i++) { bar(); }", None),
+        ("for (const key in obj) { bar(key); }", None),
+        ("for (const item of items) { bar(item); }", None),
+        ("while (foo) { if (bar) break; }", None),
+        ("while (foo) { if (bar) { continue; } break; }", None),
+        ("do { if (bar) { continue; } return; } while (foo)", None),
+        ("for (;;) { if (foo) continue; break; }", None),
+        ("for (;;) { if (foo) break; }", None),
+        ("for (let i = 0; i < 10; i++) { if (foo) continue; return; }", None),
+        ("for (const item of items) { if (item) return item; }", None),
+        ("for (const item of items) { try { return f(item); } catch {} }", None),
+        ("for (const item of items) { switch (item) { case 1: continue; default: break; } }", None),
+        // A labeled `continue` in a nested loop starts the next iteration of the outer loop.
+        (
+            "outer: for (const row of rows) { for (const cell of row) { if (cell) continue outer; } return; }",
+            None,
+        ),
+        (
+            "outer: while (foo) { for (const item of items) { if (item) continue outer; } break; }",
+            None,
+        ),
+        (
+            "outer: do { inner: while (bar) { if (baz) continue inner; continue outer; } } while (foo)",
+            None,
+        ),
+        ("function f() { for (const item of items) { if (item) return; } }", None),
+        ("while (foo) { (() => { return; })(); }", None),
+        // Unreachable loops are reported by `no-unreachable`.
+        ("function f() { return; while (foo) { break; } }", None),
+        (
+            "for (const item of items) { return item; }",
+            Some(serde_json::json!([{ "ignore": ["ForOfStatement"] }])),
+        ),
+        ("while (foo) { break; }", Some(serde_json::json!([{ "ignore": ["WhileStatement"] }]))),
+    ];
+
+    let fail = vec![
+        ("while (foo) { bar(); break; }", None),
+        ("do { bar(); break; } while (foo)", None),
+        ("do { return; } while (foo)", None),
+        ("for (let i = 0; i < 10; i++) { bar(); break; }", None),
+        ("for (;;) { break; }", None),
+        ("for (; foo; ) { throw err; }", None),
+        ("for (const key in obj) { return key; }", None),
+        ("for (const item of items) { return item; }", None),
+        ("for (const item of items) { if (item) { return item; } else { break; } }", None),
+        ("for (const item of items) { try { return f(item); } finally { cleanup(); } }", None),
+        ("for (const item of items) { try { f(item); } catch { } return; }", None),
+        ("while (foo) { switch (bar) { case 1: return; default: throw err; } }", None),
+        // The inner loop never iterates, as it always continues the outer loop.
+        ("outer: for (const row of rows) { for (const cell of row) { continue outer; } }", None),
+        ("outer: while (foo) { while (bar) { break outer; } }", None),
+        ("function f() { for (const item of items) { return item; } }", None),
+        ("const f = () => { while (foo) { return; } }", None),
+        (
+            "for (const item of items) { return item; }",
+            Some(serde_json::json!([{ "ignore": ["WhileStatement"] }])),
+        ),
+    ];
+
+    Tester::new(NoUnreachableLoop::NAME, NoUnreachableLoop::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -78,11 +78,15 @@ declare_oxc_lint!(
     /// functions, etc.
     ///
     /// #### Ignored Files
-    /// This rule ignores `.d.ts`, `.astro`, `.svelte` and `.vue` files entirely. Variables,
+    /// This rule ignores `.d.ts` and `.astro` files entirely. Variables,
     /// classes, interfaces, and types declared in `.d.ts` files are generally
     /// used by other files, which are not checked by Oxlint. Since Oxlint does
-    /// not support parsing template syntax, this rule cannot tell if a variable
-    /// is used or unused in a Vue / Svelte / Astro file.
+    /// not support parsing Astro templates, this rule cannot tell if a variable
+    /// is used or unused in an Astro file.
+    ///
+    /// In Vue and Svelte files, top-level bindings referenced from the template
+    /// (in interpolations, directives, event handlers and component tags) are
+    /// considered used.
     ///
     /// #### Exported
     ///
@@ -218,14 +222,12 @@ impl Rule for NoUnusedVars {
     }
 
     fn should_run(&self, ctx: &ContextHost) -> bool {
-        // ignore .d.ts and vue/svelte/astro files.
+        // ignore .d.ts and astro files.
         // 1. declarations have side effects (they get merged together)
-        // 2. vue/svelte/astro scripts declare variables that get used in the template, which
-        //    we can't detect
+        // 2. astro scripts declare variables that get used in the template, which we can't
+        //    detect. Usages in vue/svelte templates are collected by the partial loaders.
         !ctx.source_type().is_typescript_definition()
-            && !ctx
-                .file_extension()
-                .is_some_and(|ext| ext == "vue" || ext == "svelte" || ext == "astro")
+            && ctx.file_extension().is_none_or(|ext| ext != "astro")
     }
 }
 
@@ -238,7 +240,8 @@ impl NoUnusedVars {
         }
 
         // Order matters. We want to call cheap/high "yield" functions first.
-        let is_used = symbol.is_exported() || symbol.has_usages(self);
+        let is_used =
+            symbol.is_exported() || ctx.is_used_in_template(symbol.id()) || symbol.has_usages(self);
 
         match (is_used, is_ignored) {
             (true, true) => {
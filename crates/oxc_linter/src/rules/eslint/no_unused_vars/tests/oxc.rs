@@ -1422,12 +1422,6 @@ fn test_report_vars_only_used_as_types() {
 #[test]
 fn test_should_run() {
     let pass = vec![
-        (
-            r#"<script setup lang="ts"> import * as vue from 'vue' </script>"#,
-            None,
-            None,
-            Some(PathBuf::from("src/foo/bar.vue")),
-        ),
         (
             r"---
 import Welcome from '../components/Welcome.astro';
@@ -1456,6 +1450,113 @@ import Layout from '../layouts/Layout.astro';
         .test();
 }
 
+#[test]
+fn test_templates() {
+    let vue = || Some(PathBuf::from("src/foo/bar.vue"));
+    let svelte = || Some(PathBuf::from("src/foo/bar.svelte"));
+
+    let pass = vec![
+        (
+            r#"<script setup lang="ts">
+            import MyButton from './MyButton.vue';
+            import { formatDate } from './utils';
+            const count = ref(0);
+            const items = ref([]);
+            function increment() { count.value++; }
+            </script>
+            <template>
+              <my-button :label="formatDate(date)" @click="increment">{{ count }}</my-button>
+              <li v-for="item in items" :key="item.id">{{ item.name }}</li>
+            </template>"#,
+            None,
+            None,
+            vue(),
+        ),
+        (
+            r#"<script setup>
+            import Icons from './icons';
+            const vFocus = { mounted: (el) => el.focus() };
+            const input = ref(null);
+            </script>
+            <template>
+              <Icons.Close />
+              <input ref="input" v-focus />
+            </template>"#,
+            None,
+            None,
+            vue(),
+        ),
+        (
+            r"<script>
+            import Nested from './Nested.svelte';
+            import { fade } from 'svelte/transition';
+            import { count } from './stores';
+            let visible = true;
+            let name = 'world';
+            let items = [];
+            function toggle() { visible = !visible; }
+            </script>
+            <button on:click={toggle}>Toggle</button>
+            {#if visible}
+              <p transition:fade>Hello {name}! {$count}</p>
+            {/if}
+            {#each items as item}<Nested {item} />{/each}",
+            None,
+            None,
+            svelte(),
+        ),
+        (
+            r"<script>
+            import { count } from './stores';
+            $: doubled = $count * 2;
+            </script>
+            <p>{doubled}</p>",
+            None,
+            None,
+            svelte(),
+        ),
+    ];
+
+    let fail = vec![
+        (r#"<script setup lang="ts"> import * as vue from 'vue' </script>"#, None, None, vue()),
+        (
+            r"<script setup>
+            const count = ref(0);
+            const unused = 1;
+            </script>
+            <template><p>{{ count }}</p><!-- {{ unused }} --></template>",
+            None,
+            None,
+            vue(),
+        ),
+        (
+            r"<script setup>
+            const props = defineProps(['title']);
+            const title = 'x';
+            </script>
+            <template><p>{{ props.title }}</p></template>",
+            None,
+            None,
+            vue(),
+        ),
+        (
+            r"<script>
+            let name = 'world';
+            let unused = 1;
+            </script>
+            <h1>Hello {name}!</h1>",
+            None,
+            None,
+            svelte(),
+        ),
+    ];
+
+    Tester::new(NoUnusedVars::NAME, NoUnusedVars::PLUGIN, pass, fail)
+        .intentionally_allow_no_fix_tests()
+        .with_snapshot_suffix("oxc-templates")
+        .test_and_snapshot();
+}
+
 #[test]
 fn test_jsx_non_ascii() {
     // Test that non-ASCII component names (e.g., Korean characters) are correctly recognized
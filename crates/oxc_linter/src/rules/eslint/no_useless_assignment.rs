@@ -0,0 +1,349 @@
+use oxc_ast::{AstKind, ast::VariableDeclarationKind};
+use oxc_cfg::dataflow::{AccessAnalysis, LiveVariables, solve};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_useless_assignment_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("This assigned value is not used in subsequent statements.")
+        .with_help(format!("Remove the assignment, or read `{name}` before it is overwritten."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessAssignment;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow variable assignments when the value is not used.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A value which is overwritten or goes out of scope before it is ever read is a dead store.
+    /// It is wasted work at best, and often a sign of a bug, e.g. a refactor which moved the read
+    /// of a variable before its assignment, or assigned the wrong variable.
+    ///
+    /// The rule follows the control flow of each function, including loops, labeled `continue`
+    /// statements and `try`/`catch`/`finally` blocks: a value is used if it can be read on some
+    /// path before being overwritten, even if an exception is thrown in between. Variables which
+    /// are referenced from closures, exported, or global in scripts are not checked, as their
+    /// value can be read from elsewhere. Variables which are never read are reported by
+    /// `no-unused-vars` instead.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function fn1() {
+    ///     let v = 'used';
+    ///     doSomething(v);
+    ///     v = 'unused';
+    /// }
+    ///
+    /// function fn2() {
+    ///     let v = 'unused';
+    ///     if (condition) {
+    ///         v = 'used';
+    ///         doSomething(v);
+    ///     }
+    /// }
+    ///
+    /// function fn3() {
+    ///     let v = 'unused';
+    ///     try {
+    ///         v = compute();
+    ///     } catch {
+    ///         v = fallback();
+    ///     }
+    ///     return v;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function fn1() {
+    ///     let v = 'used';
+    ///     doSomething(v);
+    ///     v = 'used-2';
+    ///     doSomething(v);
+    /// }
+    ///
+    /// function fn2() {
+    ///     let v = 'used';
+    ///     try {
+    ///         v = compute();
+    ///     } catch {
+    ///         // `compute` may throw before `v` is assigned
+    ///     }
+    ///     return v;
+    /// }
+    ///
+    /// function fn3() {
+    ///     let v = 'used';
+    ///     const read = () => v;
+    ///     v = 'used-2';
+    ///     return read();
+    /// }
+    /// ```
+    NoUselessAssignment,
+    eslint,
+    nursery
+);
+
+impl Rule for NoUselessAssignment {
+    fn run_once(&self, ctx: &LintContext) {
+        let symbols = ctx
+            .scoping()
+            .symbol_ids()
+            .filter(|&symbol_id| is_checked_symbol(symbol_id, ctx))
+            .collect::<FxHashSet<_>>();
+        if symbols.is_empty() {
+            return;
+        }
+        // Each function is analyzed separately, starting from its first block.
+        let starts = symbols
+            .iter()
+            .map(|&symbol_id| ctx.nodes().cfg_id(declaring_function(symbol_id, ctx)))
+            .collect::<FxHashSet<_>>();
+
+        let accesses = ctx.semantic().symbol_accesses();
+        let analysis = LiveVariables::new(&accesses);
+        let mut useless = vec![];
+        for start in starts {
+            let results = solve(ctx.cfg(), start, &analysis);
+            for block in results.blocks() {
+                analysis.for_each_access(&results, block, |access, live| {
+                    if access.is_write()
+                        && !live.contains(&access.symbol_id)
+                        && symbols.contains(&access.symbol_id)
+                        && let Some(span) = reported_span(access.symbol_id, access.node_id, ctx)
+                    {
+                        useless.push((span, access.symbol_id));
+                    }
+                });
+            }
+        }
+
+        useless.sort_unstable_by_key(|(span, _)| span.start);
+        for (span, symbol_id) in useless {
+            let name = ctx.scoping().symbol_name(symbol_id);
+            ctx.diagnostic(no_useless_assignment_diagnostic(name, span));
+        }
+    }
+}
+
+/// Whether all reads of the symbol are visible to the analysis of the function declaring it.
+fn is_checked_symbol(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let scoping = ctx.scoping();
+    let nodes = ctx.nodes();
+    if !scoping.symbol_flags(symbol_id).is_variable() {
+        return false;
+    }
+    // Globals of scripts can be read by other scripts.
+    if scoping.symbol_scope_id(symbol_id) == scoping.root_scope_id()
+        && !ctx.source_type().is_module()
+    {
+        return false;
+    }
+    let declaration = scoping.symbol_declaration(symbol_id);
+    if let AstKind::VariableDeclarator(declarator) = nodes.kind(declaration) {
+        // Disposing a resource reads it when its scope is exited.
+        if matches!(
+            declarator.kind,
+            VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing
+        ) {
+            return false;
+        }
+        if nodes
+            .ancestor_kinds(declaration)
+            .nth(1)
+            .is_some_and(|kind| matches!(kind, AstKind::ExportNamedDeclaration(_)))
+        {
+            return false;
+        }
+    }
+
+    // Bindings used by a Vue or Svelte template can be read at any time.
+    if ctx.is_used_in_template(symbol_id) {
+        return false;
+    }
+
+    let function = declaring_function(symbol_id, ctx);
+    let mut is_read = false;
+    for reference in scoping.get_resolved_references(symbol_id) {
+        let node_id = reference.node_id();
+        if matches!(
+            nodes.parent_kind(node_id),
+            AstKind::ExportSpecifier(_) | AstKind::ExportDefaultDeclaration(_)
+        ) || is_deferred(node_id, function, ctx)
+        {
+            return false;
+        }
+        is_read |= reference.is_read();
+    }
+    // Variables which are never read are reported by `no-unused-vars`.
+    is_read
+}
+
+/// The function or program declaring the symbol, whose control flow contains its accesses.
+fn declaring_function(symbol_id: SymbolId, ctx: &LintContext) -> NodeId {
+    let scoping = ctx.scoping();
+    scoping
+        .scope_ancestors(scoping.symbol_scope_id(symbol_id))
+        .find(|&scope_id| {
+            let flags = scoping.scope_flags(scope_id);
+            flags.is_function() || flags.is_top()
+        })
+        .map_or(NodeId::ROOT, |scope_id| scoping.get_node_id(scope_id))
+}
+
+/// Whether the node is evaluated later than its position in `function`, i.e. inside a nested
+/// function or a class field initializer, which can run at any time after being created.
+fn is_deferred(node_id: NodeId, function: NodeId, ctx: &LintContext) -> bool {
+    for ancestor_id in ctx.nodes().ancestor_ids(node_id) {
+        if ancestor_id == function {
+            return false;
+        }
+        if matches!(
+            ctx.nodes().kind(ancestor_id),
+            AstKind::Function(_)
+                | AstKind::ArrowFunctionExpression(_)
+                | AstKind::PropertyDefinition(_)
+                | AstKind::AccessorProperty(_)
+                | AstKind::StaticBlock(_)
+        ) {
+            return true;
+        }
+    }
+    false
+}
+
+/// The span to report for a write, if the write is an assignment written by the user and its
+/// value is not otherwise used. Writes of parameters and of `for...in`/`for...of` loops are
+/// implicit, and the value of `foo(x = 1)` is used even if `x` is not read.
+fn reported_span(symbol_id: SymbolId, node_id: NodeId, ctx: &LintContext) -> Option<Span> {
+    let nodes = ctx.nodes();
+    match nodes.kind(node_id) {
+        AstKind::VariableDeclarator(declarator) => {
+            declarator.init.as_ref().map(|_| ctx.scoping().symbol_span(symbol_id))
+        }
+        AstKind::IdentifierReference(ident) => {
+            let assignment = nodes.ancestors(node_id).find(|ancestor| {
+                matches!(
+                    ancestor.kind(),
+                    AstKind::AssignmentExpression(_)
+                        | AstKind::UpdateExpression(_)
+                        | AstKind::ForInStatement(_)
+                        | AstKind::ForOfStatement(_)
+                )
+            })?;
+            if matches!(assignment.kind(), AstKind::ForInStatement(_) | AstKind::ForOfStatement(_))
+                || is_value_used(assignment.id(), ctx)
+            {
+                return None;
+            }
+            Some(ident.span)
+        }
+        _ => None,
+    }
+}
+
+fn is_value_used(node_id: NodeId, ctx: &LintContext) -> bool {
+    let mut span = ctx.nodes().kind(node_id).span();
+    for ancestor in ctx.nodes().ancestors(node_id) {
+        match ancestor.kind() {
+            AstKind::ParenthesizedExpression(_) => {}
+            AstKind::SequenceExpression(expr) => {
+                if expr.expressions.last().is_some_and(|last| last.span() != span) {
+                    return false;
+                }
+            }
+            AstKind::ExpressionStatement(_) => return false,
+            AstKind::ForStatement(stmt) => {
+                return stmt.test.as_ref().is_some_and(|test| test.span() == span);
+            }
+            _ => return true,
+        }
+        span = ancestor.kind().span();
+    }
+    true
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "let v = 'used'; doSomething(v); v = 'used-2'; doSomething(v);",
+        "function foo() { let v = 'used'; doSomething(v); v = 'used-2'; doSomething(v); }",
+        "function foo() { let v = 'used'; if (condition) { v = 'used-2'; } doSomething(v); }",
+        "function foo() { let v = 'used'; if (condition) { doSomething(v); } else { v = 'used-2'; doSomething(v); } }",
+        "function foo() { let v; v = 'used'; doSomething(v); }",
+        "function foo() { let i = 0; while (i < 10) { i++; } }",
+        "function foo() { for (let i = 0; i < 10; i++) { doSomething(i); } }",
+        "function foo() { let sum = 0; for (const item of items) { sum += item; } return sum; }",
+        "function foo() { let v = 0; do { v = next(v); } while (v); }",
+        "function foo() { let v = 'used'; return v; v = 'unreachable'; }",
+        "function foo() { let v = 0; v = condition ? v + 1 : 0; return v; }",
+        "function foo() { let v = 0; v = a || v; return v; }",
+        "function foo() { let v = null; v ??= create(); return v; }",
+        "function foo() { let v; return (v = 1), v; }",
+        // The value of the assignment expression is used.
+        "function foo() { let v; doSomething(v = 1); v = 2; return v; }",
+        "function foo() { let v; while ((v = next())) {} }",
+        "function foo(a) { return a; }",
+        "function foo() { for (const item of items) { doSomething(item); } }",
+        // Variables referenced from closures are not checked.
+        "function foo() { let v = 'used'; const read = () => v; v = 'used-2'; return read(); }",
+        "function foo() { let v = 'used'; function read() { return v; } v = 'used-2'; return read; }",
+        "function foo() { let v = 0; setTimeout(() => { v = 1; }); doSomething(v); v = 2; }",
+        "function foo() { let v = 0; class A { field = v; } v = 1; return A; }",
+        // Unused variables are reported by `no-unused-vars`.
+        "function foo() { let v = 'unused'; v = 'unused-2'; }",
+        "export let v = 1; v = 2;",
+        "let v = 1; v = 2; doSomething(v); v = 3; export { v };",
+        "let v = 1; doSomething(v); v = 2; export default v;",
+        "function foo() { using v = getResource(); doSomething(v); }",
+        // try/catch/finally
+        "function foo() { let v = 'used'; try { v = compute(); } catch { } return v; }",
+        "function foo() { let v = 0; try { v = 1; mayThrow(); v = 2; } catch { return v; } return v; }",
+        "function foo() { let v = 0; try { v = 1; return; } finally { doSomething(v); } }",
+        "function foo() { let v = 0; try { doSomething(); } catch { v = 1; } return v; }",
+        "function foo() { let v = 0; try { v = compute(); } finally { log(v); } return v; }",
+        // Whether `finally` is entered by an exception is not tracked, so `v` may be returned.
+        "function foo() { let v = 'used'; try { v = compute(); } finally { cleanup(); } return v; }",
+        // Loops with labeled `continue`
+        "function foo() { let last = null; outer: for (const row of rows) { for (const cell of row) { if (cell === last) continue outer; last = cell; } } }",
+        "function foo() { let v = 0; outer: while (a) { while (b) { if (v) continue outer; v = next(); } } }",
+        "function foo() { let v = 0; loop: for (;;) { doSomething(v); v = 1; continue loop; } }",
+        "function foo() { let v = 0; switch (a) { case 1: v = 1; case 2: return v; } }",
+    ];
+
+    let fail = vec![
+        "function foo() { let v = 'used'; doSomething(v); v = 'unused'; }",
+        "function foo() { let v = 'unused'; if (condition) { v = 'used'; doSomething(v); } }",
+        "function foo() { let v = 'unused'; v = 'used'; doSomething(v); }",
+        "function foo() { let v = 0; v++; v = 2; return v; }",
+        "function foo() { let v = 0; v = v + 1; doSomething(v); v += 1; }",
+        "function foo(a) { doSomething(a); a = 1; }",
+        "function foo() { let v = 'unused'; try { doSomething(); } finally { v = compute(); } return v; }",
+        "function foo() { let v = 0; try { v = 1; } catch { v = 2; } return v; }",
+        "function foo() { let v = 'unused'; try { v = compute(); } catch { v = fallback(); } return v; }",
+        "function foo() { let v = 0; for (const item of items) { v = item; } v = 1; return v; }",
+        "function foo() { let v = 0; outer: for (const row of rows) { for (const cell of row) { v = cell; continue outer; } } v = 1; return v; }",
+        "function foo() { let v = 'unused'; const read = () => 1; v = read(); return v; }",
+        "const foo = () => { let v = 1; v = 2; return v; };",
+        "import x from 'x'; let v = 1; v = 2; doSomething(v, x);",
+        "function foo() { let v = 1; (v = 2), doSomething(); v = 3; return v; }",
+        "function foo() { let v = 1; for (v = 2; ; ) { break; } v = 3; return v; }",
+        "function foo() { let v = 0; v = condition ? 1 : 2; v = 3; return v; }",
+    ];
+
+    Tester::new(NoUselessAssignment::NAME, NoUselessAssignment::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
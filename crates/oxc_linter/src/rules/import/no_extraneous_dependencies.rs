@@ -0,0 +1,433 @@
+use std::{
+    ffi::OsStr,
+    path::{Component, Path, PathBuf},
+};
+
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::{CompactStr, Span};
+
+use crate::{
+    ModuleResolution,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn missing_dependency_diagnostic(span: Span, package_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{package_name}' should be listed in the project's dependencies"))
+        .with_help(format!("Run `npm i -S {package_name}` to add it."))
+        .with_label(span)
+}
+
+fn wrong_dependency_kind_diagnostic(span: Span, package_name: &str, kind: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "'{package_name}' should be listed in the project's dependencies, not {kind}"
+    ))
+    .with_help(format!("Move '{package_name}' from `{kind}` to `dependencies` in package.json."))
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoExtraneousDependencies(Box<NoExtraneousDependenciesConfig>);
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoExtraneousDependenciesConfig {
+    /// Allow importing packages listed in `devDependencies`.
+    /// Either a boolean, or glob patterns of the files in which it is allowed, e.g. `["**/*.test.js"]`.
+    /// Relative patterns also match paths relative to the directory of the configuration file.
+    dev_dependencies: DependencyOption,
+    /// Allow importing packages listed in `optionalDependencies`.
+    /// Either a boolean, or glob patterns of the files in which it is allowed.
+    optional_dependencies: DependencyOption,
+    /// Allow importing packages listed in `peerDependencies`.
+    /// Either a boolean, or glob patterns of the files in which it is allowed.
+    peer_dependencies: DependencyOption,
+    /// Allow importing packages listed in `bundleDependencies` or `bundledDependencies`.
+    /// Either a boolean, or glob patterns of the files in which it is allowed.
+    bundled_dependencies: DependencyOption,
+    /// Also check type-only imports (`import type`).
+    include_types: bool,
+    /// Directories of the `package.json` files to read dependencies from, relative to the directory
+    /// of the configuration file. Dependencies of all of them are merged.
+    /// By default, the `package.json` closest to the linted file is used.
+    package_dir: PackageDir,
+}
+
+impl Default for NoExtraneousDependenciesConfig {
+    fn default() -> Self {
+        Self {
+            dev_dependencies: DependencyOption::Bool(true),
+            optional_dependencies: DependencyOption::Bool(true),
+            peer_dependencies: DependencyOption::Bool(true),
+            bundled_dependencies: DependencyOption::Bool(true),
+            include_types: false,
+            package_dir: PackageDir::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(untagged)]
+enum DependencyOption {
+    Bool(bool),
+    Globs(Vec<CompactStr>),
+}
+
+impl DependencyOption {
+    fn is_allowed(&self, path: &Path, config_dir: &Path) -> bool {
+        match self {
+            Self::Bool(allowed) => *allowed,
+            Self::Globs(globs) => {
+                let path = path.to_string_lossy();
+                globs.iter().any(|glob| {
+                    fast_glob::glob_match(glob.as_str(), path.as_ref())
+                        || fast_glob::glob_match(
+                            config_dir.join(glob.as_str()).to_string_lossy().as_ref(),
+                            path.as_ref(),
+                        )
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(untagged)]
+enum PackageDir {
+    #[default]
+    Nearest,
+    One(PathBuf),
+    Many(Vec<PathBuf>),
+}
+
+impl std::ops::Deref for NoExtraneousDependencies {
+    type Target = NoExtraneousDependenciesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbids importing packages which are not declared in the `dependencies`,
+    /// `devDependencies`, `optionalDependencies`, `peerDependencies` or `bundleDependencies`
+    /// of the closest `package.json`.
+    ///
+    /// Both `import` declarations and `require()` calls are checked.
+    /// Imports which resolve to files outside of `node_modules`, e.g. through `paths` in
+    /// `tsconfig.json`, and Node.js builtin modules are ignored.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Packages which are not declared are not installed with the project. Code which imports
+    /// them only works by accident, e.g. because another package depends on them, and breaks
+    /// when they are removed. Packages in `devDependencies` are not installed in production.
+    ///
+    /// ### Examples
+    ///
+    /// Given a `package.json` with:
+    /// ```json
+    /// {
+    ///   "dependencies": { "react": "^19.0.0" },
+    ///   "devDependencies": { "vitest": "^3.0.0" }
+    /// }
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import lodash from 'lodash';
+    /// const chalk = require('chalk');
+    ///
+    /// /* import/no-extraneous-dependencies: ["error", { "devDependencies": false }] */
+    /// import { test } from 'vitest';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import React from 'react';
+    /// import { useState } from 'react';
+    /// import fs from 'node:fs';
+    /// import foo from './foo';
+    ///
+    /// /* import/no-extraneous-dependencies: ["error", { "devDependencies": ["**/*.test.ts"] }] */
+    /// // in `src/foo.test.js`
+    /// import { test } from 'vitest';
+    /// ```
+    NoExtraneousDependencies,
+    import,
+    pedantic,
+    config = NoExtraneousDependenciesConfig,
+);
+
+impl Rule for NoExtraneousDependencies {
+    fn from_configuration(value: Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        let mut imports = module_record
+            .requested_modules
+            .iter()
+            .flat_map(|(specifier, requested_modules)| {
+                requested_modules
+                    .iter()
+                    .filter(|requested_module| self.include_types || !requested_module.is_type)
+                    .map(move |requested_module| (specifier.as_str(), requested_module.span))
+            })
+            .collect::<Vec<_>>();
+        for node in ctx.nodes() {
+            if let AstKind::CallExpression(call_expr) = node.kind()
+                && let Some(specifier) = call_expr.common_js_require()
+                && call_expr.callee.is_global_reference_name("require", ctx.scoping())
+            {
+                imports.push((specifier.value.as_str(), specifier.span));
+            }
+        }
+
+        let mut dependencies = None;
+        for (specifier, span) in imports {
+            let Some(package_name) = package_name(specifier) else {
+                continue;
+            };
+            match module_record.resolutions.get(specifier) {
+                Some(ModuleResolution::Builtin) => continue,
+                Some(ModuleResolution::Path(path)) if !is_in_node_modules(path) => continue,
+                _ => {}
+            }
+
+            let dependencies = dependencies.get_or_insert_with(|| self.dependencies(ctx));
+            let Some(dependencies) = dependencies else {
+                return;
+            };
+            self.check(ctx, dependencies, package_name, span);
+        }
+    }
+}
+
+impl NoExtraneousDependencies {
+    /// Dependencies of the configured package directories, or of the `package.json` closest to the
+    /// linted file.
+    fn dependencies(&self, ctx: &LintContext<'_>) -> Option<PackageDependencies> {
+        let package_dirs = match &self.package_dir {
+            PackageDir::Nearest => {
+                return ctx.closest_package_json().map(|json| PackageDependencies::parse(&json));
+            }
+            PackageDir::One(dir) => std::slice::from_ref(dir),
+            PackageDir::Many(dirs) => dirs.as_slice(),
+        };
+        let config_dir = ctx.config_dir().unwrap_or(Path::new(""));
+        let mut dependencies = PackageDependencies::default();
+        for dir in package_dirs {
+            if let Some(json) = ctx.package_json(&config_dir.join(dir).join("package.json")) {
+                dependencies.extend(PackageDependencies::parse(&json));
+            }
+        }
+        Some(dependencies)
+    }
+
+    fn check(
+        &self,
+        ctx: &LintContext<'_>,
+        dependencies: &PackageDependencies,
+        package_name: &str,
+        span: Span,
+    ) {
+        if dependencies.dependencies.contains(package_name) {
+            return;
+        }
+        let config_dir = ctx.config_dir().unwrap_or(Path::new(""));
+        let path = ctx.file_path();
+        let allow_dev = self.dev_dependencies.is_allowed(path, config_dir);
+        let allow_optional = self.optional_dependencies.is_allowed(path, config_dir);
+        let in_dev = dependencies.dev_dependencies.contains(package_name);
+        let in_optional = dependencies.optional_dependencies.contains(package_name);
+        if (allow_dev && in_dev)
+            || (allow_optional && in_optional)
+            || (self.peer_dependencies.is_allowed(path, config_dir)
+                && dependencies.peer_dependencies.contains(package_name))
+            || (self.bundled_dependencies.is_allowed(path, config_dir)
+                && dependencies.bundled_dependencies.contains(package_name))
+        {
+            return;
+        }
+        if in_dev {
+            ctx.diagnostic(wrong_dependency_kind_diagnostic(span, package_name, "devDependencies"));
+        } else if in_optional {
+            ctx.diagnostic(wrong_dependency_kind_diagnostic(
+                span,
+                package_name,
+                "optionalDependencies",
+            ));
+        } else {
+            ctx.diagnostic(missing_dependency_diagnostic(span, package_name));
+        }
+    }
+}
+
+/// Names of the packages declared in a `package.json`.
+#[derive(Debug, Default, Clone)]
+struct PackageDependencies {
+    dependencies: FxHashSet<String>,
+    dev_dependencies: FxHashSet<String>,
+    optional_dependencies: FxHashSet<String>,
+    peer_dependencies: FxHashSet<String>,
+    bundled_dependencies: FxHashSet<String>,
+}
+
+impl PackageDependencies {
+    fn parse(package_json: &Value) -> Self {
+        let names = |key: &str| -> FxHashSet<String> {
+            match package_json.get(key) {
+                Some(Value::Object(dependencies)) => dependencies.keys().cloned().collect(),
+                Some(Value::Array(names)) => {
+                    names.iter().filter_map(Value::as_str).map(String::from).collect()
+                }
+                _ => FxHashSet::default(),
+            }
+        };
+        let mut bundled_dependencies = names("bundleDependencies");
+        bundled_dependencies.extend(names("bundledDependencies"));
+        Self {
+            dependencies: names("dependencies"),
+            dev_dependencies: names("devDependencies"),
+            optional_dependencies: names("optionalDependencies"),
+            peer_dependencies: names("peerDependencies"),
+            bundled_dependencies,
+        }
+    }
+
+    fn extend(&mut self, other: Self) {
+        self.dependencies.extend(other.dependencies);
+        self.dev_dependencies.extend(other.dev_dependencies);
+        self.optional_dependencies.extend(other.optional_dependencies);
+        self.peer_dependencies.extend(other.peer_dependencies);
+        self.bundled_dependencies.extend(other.bundled_dependencies);
+    }
+}
+
+/// Name of the package imported by a bare module specifier, e.g. `lodash` for `lodash/fp`
+/// or `@babel/core` for `@babel/core/lib/index.js`.
+/// Returns `None` for relative and absolute paths, and for specifiers with a protocol (e.g. `node:fs`).
+fn package_name(specifier: &str) -> Option<&str> {
+    if specifier.is_empty()
+        || specifier.starts_with(['.', '/', '#'])
+        || specifier.contains(':')
+        || Path::new(specifier).is_absolute()
+    {
+        return None;
+    }
+    let end = if specifier.starts_with('@') {
+        let scope_end = specifier.find('/')?;
+        specifier[scope_end + 1..].find('/').map(|i| scope_end + 1 + i)
+    } else {
+        specifier.find('/')
+    };
+    Some(end.map_or(specifier, |end| &specifier[..end]))
+}
+
+fn is_in_node_modules(path: &Path) -> bool {
+    path.components().any(|c| matches!(c, Component::Normal(p) if p == OsStr::new("node_modules")))
+}
+
+#[test]
+fn test_package_name() {
+    assert_eq!(package_name("lodash"), Some("lodash"));
+    assert_eq!(package_name("lodash/fp"), Some("lodash"));
+    assert_eq!(package_name("@babel/core"), Some("@babel/core"));
+    assert_eq!(package_name("@babel/core/lib/index.js"), Some("@babel/core"));
+    assert_eq!(package_name("@babel"), None);
+    assert_eq!(package_name("./foo"), None);
+    assert_eq!(package_name("../foo"), None);
+    assert_eq!(package_name("/foo"), None);
+    assert_eq!(package_name("#internal"), None);
+    assert_eq!(package_name("node:fs"), None);
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/import");
+    let package_dir = |dir: &str| fixtures.join(dir).to_string_lossy().into_owned();
+
+    let pass = vec![
+        (r"import 'jquery'", None),
+        (r"import { cond } from 'lodash.cond'", None),
+        (r"import foo from '@org/package'", None),
+        (r"import foo from 'esm-package/esm-module'", None),
+        (r"const $ = require('jquery')", None),
+        (r"import fs from 'fs'", None),
+        (r"import fs from 'node:fs'", None),
+        (r"import foo from './foo'", None),
+        (r"import foo from '../foo'", None),
+        (r"import glob from 'glob'", None),
+        (r"import eslint from 'eslint'", None),
+        (r"import isArray from 'lodash.isarray'", None),
+        (r"import foo from '@generated/foo'", None),
+        (r"import type { Foo } from 'not-a-dependency'", None),
+        (r"const foo = require(name)", None),
+        (r"function f(require) { require('not-a-dependency') }", None),
+        (r"import eslint from 'eslint'", Some(json!([{ "devDependencies": false }]))),
+        (r"import glob from 'glob'", Some(json!([{ "devDependencies": ["**/index.ts"] }]))),
+        (
+            r"import a from 'a'",
+            Some(json!([{ "packageDir": package_dir("with-typescript-dev-dependencies") }])),
+        ),
+        (
+            r"import foo from '@generated/foo'",
+            Some(
+                json!([{ "packageDir": package_dir("bundled-dependencies/as-array-bundle-deps") }]),
+            ),
+        ),
+        (
+            r"import foo from '@generated/foo'",
+            Some(json!([{ "packageDir": package_dir("bundled-dependencies/as-object") }])),
+        ),
+        (
+            r"import a from 'a'; import $ from 'jquery'",
+            Some(
+                json!([{ "packageDir": [package_dir("."), package_dir("with-typescript-dev-dependencies")] }]),
+            ),
+        ),
+    ];
+
+    let fail = vec![
+        (r"import 'not-a-dependency'", None),
+        (r"import foo from '@org/not-a-dependency'", None),
+        (r"import foo from 'not-a-dependency/foo'", None),
+        (r"const foo = require('not-a-dependency')", None),
+        (r"export { foo } from 'not-a-dependency'", None),
+        (r"import glob from 'glob'", Some(json!([{ "devDependencies": false }]))),
+        (r"import glob from 'glob'", Some(json!([{ "devDependencies": ["**/*.test.ts"] }]))),
+        (r"import isArray from 'lodash.isarray'", Some(json!([{ "optionalDependencies": false }]))),
+        (
+            r"import eslint from 'eslint'",
+            Some(json!([{ "devDependencies": false, "peerDependencies": false }])),
+        ),
+        (r"import foo from '@generated/foo'", Some(json!([{ "bundledDependencies": false }]))),
+        (
+            r"import $ from 'jquery'",
+            Some(json!([{ "packageDir": package_dir("with-typescript-dev-dependencies") }])),
+        ),
+        (r"import type { Foo } from 'not-a-dependency'", Some(json!([{ "includeTypes": true }]))),
+    ];
+
+    Tester::new(NoExtraneousDependencies::NAME, NoExtraneousDependencies::PLUGIN, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,184 @@
+use lazy_regex::{Regex, RegexBuilder};
+use schemars::JsonSchema;
+use serde::de::Error as _;
+
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::Span;
+
+use crate::{ModuleRecord, ModuleResolution, context::LintContext, rule::Rule};
+
+fn no_unresolved_diagnostic(span: Span, specifier: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unable to resolve path to module '{specifier}'"))
+        .with_help("Check that the file or package exists, and that the path is spelled correctly.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolved(Box<NoUnresolvedConfig>);
+
+#[derive(Debug, Default, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnresolvedConfig {
+    /// Also report `require("...")` calls of modules which cannot be resolved.
+    commonjs: bool,
+    /// Regular expressions of module specifiers which are not reported, even if they cannot be resolved.
+    ///
+    /// Example configuration:
+    /// ```json
+    /// {
+    ///     "import/no-unresolved": ["error", { "ignore": ["^virtual:", "\\.svg\\?react$"] }]
+    /// }
+    /// ```
+    ignore: Vec<Regex>,
+}
+
+impl std::ops::Deref for NoUnresolved {
+    type Target = NoUnresolvedConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures that imported modules can be resolved to a file or a Node.js builtin module.
+    ///
+    /// Modules are resolved like Node.js does, including `paths` of `tsconfig.json`.
+    /// Type-only imports (`import type`) are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing a module which does not exist fails at runtime or when bundling, e.g. because of
+    /// a typo in the path, a file which was moved, or a package which is not installed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import foo from './not-a-file';
+    /// import bar from 'not-a-package';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import foo from './foo';
+    /// import fs from 'node:fs';
+    /// ```
+    NoUnresolved,
+    import,
+    correctness,
+    config = NoUnresolvedConfig,
+);
+
+impl Rule for NoUnresolved {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        let Some(config) = value.get(0) else {
+            return Ok(Self::default());
+        };
+        let commonjs =
+            config.get("commonjs").and_then(serde_json::Value::as_bool).unwrap_or_default();
+        let ignore = config
+            .get("ignore")
+            .and_then(serde_json::Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(serde_json::Value::as_str)
+            .map(|pattern| {
+                RegexBuilder::new(pattern).build().map_err(|err| {
+                    serde_json::Error::custom(format!(
+                        "Invalid `ignore` pattern `{pattern}`: {err}"
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(Box::new(NoUnresolvedConfig { commonjs, ignore })))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            if !self.is_unresolved(module_record, specifier) {
+                continue;
+            }
+            for requested_module in requested_modules {
+                if !requested_module.is_type {
+                    ctx.diagnostic(no_unresolved_diagnostic(requested_module.span, specifier));
+                }
+            }
+        }
+
+        if self.commonjs {
+            for node in ctx.nodes() {
+                if let AstKind::CallExpression(call_expr) = node.kind()
+                    && let Some(specifier) = call_expr.common_js_require()
+                    && call_expr.callee.is_global_reference_name("require", ctx.scoping())
+                    && self.is_unresolved(module_record, &specifier.value)
+                {
+                    ctx.diagnostic(no_unresolved_diagnostic(specifier.span, &specifier.value));
+                }
+            }
+        }
+    }
+}
+
+impl NoUnresolvedConfig {
+    fn is_unresolved(&self, module_record: &ModuleRecord, specifier: &str) -> bool {
+        matches!(module_record.resolutions.get(specifier), Some(ModuleResolution::Unresolved))
+            && !self.ignore.iter().any(|pattern| pattern.is_match(specifier))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (r"import foo from './bar'", None),
+        (r"import foo from './bar.js'", None),
+        (r"import foo from './data.json'", None),
+        (r"import foo from './bar/'", None),
+        (r"import { bar } from 'esm-package'", None),
+        (r"import { bar } from '@org/package'", None),
+        (r"import fs from 'fs'", None),
+        (r"import fs from 'node:fs'", None),
+        (r"export { foo } from './bar'", None),
+        (r"export * from './bar'", None),
+        (r"import type { Foo } from './does-not-exist'", None),
+        (r"const foo = require('./does-not-exist')", None),
+        (r"const foo = require('./bar')", Some(json!([{ "commonjs": true }]))),
+        (r"const foo = require('fs')", Some(json!([{ "commonjs": true }]))),
+        (r"const foo = require(name)", Some(json!([{ "commonjs": true }]))),
+        (
+            r"function f(require) { require('./does-not-exist') }",
+            Some(json!([{ "commonjs": true }])),
+        ),
+        (r"import foo from './does-not-exist'", Some(json!([{ "ignore": ["does-not-exist$"] }]))),
+        (r"import foo from 'virtual:module'", Some(json!([{ "ignore": ["^virtual:"] }]))),
+    ];
+
+    let fail = vec![
+        (r"import foo from './does-not-exist'", None),
+        (r"import { foo } from './does-not-exist.js'", None),
+        (r"import foo from 'not-installed'", None),
+        (r"import foo from '@org/not-installed'", None),
+        (r"export { foo } from './does-not-exist'", None),
+        (r"export * from './does-not-exist'", None),
+        (r"import './does-not-exist'", None),
+        (r"import foo from './empty-folder'", None),
+        (r"import foo from './does-not-exist'; import bar from './does-not-exist'", None),
+        (r"const foo = require('./does-not-exist')", Some(json!([{ "commonjs": true }]))),
+        (r"import foo from './does-not-exist'", Some(json!([{ "ignore": ["^virtual:"] }]))),
+    ];
+
+    Tester::new(NoUnresolved::NAME, NoUnresolved::PLUGIN, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,503 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use schemars::JsonSchema;
+use serde::{Deserialize, de::Error as _};
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+
+use crate::{
+    ModuleRecord,
+    context::LintContext,
+    module_record::{ExportEntry, ExportExportName, ExportImportName, ImportImportName},
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn unused_export_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Exported declaration '{name}' is not used within other modules"))
+        .with_help("Remove the export, or import it where it is needed.")
+        .with_label(span)
+}
+
+fn missing_exports_diagnostic() -> OxcDiagnostic {
+    OxcDiagnostic::warn("No exports found")
+        .with_help("Export the declarations this module provides, or remove the module.")
+        .with_label(Span::default())
+}
+
+fn unused_file_diagnostic() -> OxcDiagnostic {
+    OxcDiagnostic::warn("File is not reachable from any entry point")
+        .with_help("Remove the file, or import it from a module which is in use.")
+        .with_label(Span::default())
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnusedModules(Box<NoUnusedModulesConfig>);
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUnusedModulesConfig {
+    /// Report modules without any exports.
+    missing_exports: bool,
+    /// Report exports which are not imported by any other module.
+    unused_exports: bool,
+    /// Report files in `src` which are not reachable from any of the `entries`.
+    /// Requires `entries` to be set.
+    unused_files: bool,
+    /// Glob patterns of the files to analyze, relative to the directory of the configuration file.
+    /// Only these files are reported, and only imports from these files are taken into account.
+    /// Defaults to all linted files.
+    src: Vec<CompactStr>,
+    /// Glob patterns of files whose exports are never reported, e.g. the public API of a library.
+    ignore_exports: Vec<CompactStr>,
+    /// Glob patterns of the entry points of the project, e.g. `["src/main.ts", "**/*.test.ts"]`.
+    ///
+    /// When set, only imports from files reachable from an entry point are taken into account,
+    /// and exports of entry points are never reported.
+    entries: Vec<CompactStr>,
+    /// Do not report unused exports of type aliases and interfaces.
+    ignore_unused_type_exports: bool,
+    /// Whether modules are reachable from the entries, see [`NoUnusedModules::is_reachable`].
+    /// Importers are only tracked when all files are linted together, so this is computed once
+    /// per run and shared by all files.
+    #[serde(skip)]
+    reachable: Arc<Mutex<FxHashMap<PathBuf, bool>>>,
+}
+
+impl std::ops::Deref for NoUnusedModules {
+    type Target = NoUnusedModulesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports exports which are not imported by any other module of the project, modules
+    /// without any exports, and files which cannot be reached from the entry points of the
+    /// project.
+    ///
+    /// Which exports are used is found out from the module graph of the linted files, so only
+    /// imports from linted files are taken into account. When `unusedExports` or `unusedFiles` is
+    /// set, the module graph is built before any file is linted, so files are parsed twice. Unused
+    /// exports and files are not reported by the language server, which lints files one by one.
+    ///
+    /// An export counts as used if it is imported by name, re-exported (`export { foo } from`),
+    /// or if its module is imported as a namespace (`import * as ns`, `export * as ns from`),
+    /// with a dynamic `import()` of a string literal, or with `require()`.
+    /// Names used through `export * from` are forwarded to the re-exported module.
+    /// Imports in the scripts of Vue and Svelte files count whether their bindings are used by
+    /// the script or only by the template.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unused exports and files are dead code. They make the code base harder to navigate,
+    /// slow down builds and tests, and are often left behind by refactorings.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "unusedExports": true }`:
+    /// ```js
+    /// // foo.js, when no module imports `bar`
+    /// export const foo = 1;
+    /// export const bar = 2;
+    ///
+    /// // index.js
+    /// import { foo } from './foo';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "unusedExports": true }`:
+    /// ```js
+    /// // foo.js
+    /// export const foo = 1;
+    /// const bar = 2;
+    ///
+    /// // index.js
+    /// import { foo } from './foo';
+    /// ```
+    ///
+    /// Example configuration to report unused files and exports, with `src/main.ts` and all
+    /// tests as entry points:
+    /// ```json
+    /// {
+    ///   "import/no-unused-modules": ["error", {
+    ///     "unusedExports": true,
+    ///     "unusedFiles": true,
+    ///     "src": ["src/**"],
+    ///     "entries": ["src/main.ts", "src/**/*.test.ts"]
+    ///   }]
+    /// }
+    /// ```
+    NoUnusedModules,
+    import,
+    restriction,
+    config = NoUnusedModulesConfig,
+);
+
+impl Rule for NoUnusedModules {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        let rule = serde_json::from_value::<DefaultRuleConfig<Self>>(value)
+            .map(DefaultRuleConfig::into_inner)?;
+        if rule.unused_files && rule.entries.is_empty() {
+            return Err(serde_json::Error::custom("`unusedFiles` requires `entries` to be set"));
+        }
+        Ok(rule)
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        if !self.missing_exports && !self.unused_exports && !self.unused_files {
+            return;
+        }
+        if !self.is_source(ctx, ctx.file_path()) {
+            return;
+        }
+        let module_record = ctx.module_record();
+
+        if self.missing_exports
+            && module_record.local_export_entries.is_empty()
+            && module_record.indirect_export_entries.is_empty()
+            && module_record.star_export_entries.is_empty()
+        {
+            ctx.diagnostic(missing_exports_diagnostic());
+        }
+        if !self.unused_exports && !self.unused_files {
+            return;
+        }
+        // Importers are only known when all files are linted together.
+        if module_record.importers().is_none() {
+            return;
+        }
+
+        if self.unused_files && !self.is_reachable(ctx, module_record) {
+            ctx.diagnostic(unused_file_diagnostic());
+            return;
+        }
+
+        let path = ctx.file_path();
+        if !self.unused_exports
+            || matches_any(&self.ignore_exports, ctx, path)
+            || matches_any(&self.entries, ctx, path)
+        {
+            return;
+        }
+        let usage = self.export_usage(ctx, module_record, &mut FxHashSet::default());
+        if usage.all {
+            return;
+        }
+        let exports =
+            module_record.local_export_entries.iter().chain(&module_record.indirect_export_entries);
+        for entry in exports {
+            let (name, span) = match &entry.export_name {
+                ExportExportName::Name(name) => (name.name.as_str(), name.span),
+                ExportExportName::Default(span) => ("default", *span),
+                ExportExportName::Null => continue,
+            };
+            if usage.is_used(name) {
+                continue;
+            }
+            if self.ignore_unused_type_exports && is_type_export(ctx, entry) {
+                continue;
+            }
+            ctx.diagnostic(unused_export_diagnostic(span, name));
+        }
+    }
+}
+
+impl NoUnusedModules {
+    /// Whether the rule needs the importers of modules, see [`ModuleRecord::importers`].
+    pub(crate) fn uses_importers(&self) -> bool {
+        self.unused_exports || self.unused_files
+    }
+
+    fn is_source(&self, ctx: &LintContext<'_>, path: &Path) -> bool {
+        self.src.is_empty() || matches_any(&self.src, ctx, path)
+    }
+
+    /// Whether the module is an entry point, or is imported by a module reachable from one.
+    ///
+    /// The reachability of every module visited on the way is stored, so that each module is only
+    /// visited once per run.
+    fn is_reachable(&self, ctx: &LintContext<'_>, module_record: &ModuleRecord) -> bool {
+        let path = &module_record.resolved_absolute_path;
+        let mut reachable = self.reachable.lock().unwrap();
+        if let Some(&is_reachable) = reachable.get(path) {
+            return is_reachable;
+        }
+
+        // Walk up the importers whose reachability is not known yet. `dependents` maps each of
+        // them to the visited modules it imports, and `found` holds those which are reachable.
+        let mut dependents = FxHashMap::<PathBuf, Vec<PathBuf>>::default();
+        let mut found = vec![];
+        dependents.insert(path.clone(), vec![]);
+        let mut stack = vec![(path.clone(), module_record.importers().unwrap_or_default())];
+        while let Some((path, importers)) = stack.pop() {
+            if matches_any(&self.entries, ctx, &path) {
+                found.push(path);
+                continue;
+            }
+            for (_, importer) in importers {
+                let importer_path = &importer.resolved_absolute_path;
+                if !self.is_source(ctx, importer_path) {
+                    continue;
+                }
+                match reachable.get(importer_path) {
+                    Some(true) => found.push(path.clone()),
+                    Some(false) => {}
+                    None => {
+                        if let Some(dependents) = dependents.get_mut(importer_path) {
+                            dependents.push(path.clone());
+                        } else {
+                            dependents.insert(importer_path.clone(), vec![path.clone()]);
+                            stack.push((
+                                importer_path.clone(),
+                                importer.importers().unwrap_or_default(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        // All importers of the visited modules are known now, so the visited modules which are
+        // not imported by a reachable module are unreachable.
+        let mut reached = FxHashSet::default();
+        while let Some(path) = found.pop() {
+            if reached.insert(path.clone()) {
+                found.extend(dependents.get(&path).into_iter().flatten().cloned());
+            }
+        }
+        for visited in dependents.into_keys() {
+            let is_reachable = reached.contains(&visited);
+            reachable.insert(visited, is_reachable);
+        }
+        reached.contains(path)
+    }
+
+    /// Exports of the module which are used by other modules.
+    ///
+    /// `visited` holds the modules whose usage is being computed, to stop at cycles of
+    /// `export * from`.
+    fn export_usage(
+        &self,
+        ctx: &LintContext<'_>,
+        module_record: &ModuleRecord,
+        visited: &mut FxHashSet<PathBuf>,
+    ) -> ExportUsage {
+        let mut usage = ExportUsage::default();
+        let path = &module_record.resolved_absolute_path;
+        // Exports of entry points and ignored files are used by whatever uses the project.
+        if matches_any(&self.entries, ctx, path) || matches_any(&self.ignore_exports, ctx, path) {
+            usage.all = true;
+            return usage;
+        }
+        if !visited.insert(path.clone()) {
+            return usage;
+        }
+
+        for (specifier, importer) in module_record.importers().unwrap_or_default() {
+            if !self.is_source(ctx, &importer.resolved_absolute_path)
+                || (!self.entries.is_empty() && !self.is_reachable(ctx, &importer))
+            {
+                continue;
+            }
+            match Dependency::of(&importer, &specifier) {
+                Dependency::Names(names) => usage.names.extend(names),
+                Dependency::All => usage.all = true,
+                Dependency::SideEffect => {}
+                // Forward names used from the importer, but not `default` and names it exports
+                // itself, as they take precedence over `export *`.
+                Dependency::StarExport => {
+                    let forwarded = self.export_usage(ctx, &importer, visited);
+                    usage.all_but_default |= forwarded.all || forwarded.all_but_default;
+                    let own_exports = export_names(&importer);
+                    usage.names.extend(forwarded.names.into_iter().filter(|name| {
+                        name.as_str() != "default" && !own_exports.contains(name.as_str())
+                    }));
+                }
+            }
+            if usage.all {
+                break;
+            }
+        }
+
+        visited.remove(path);
+        usage
+    }
+}
+
+/// Whether an export is a type alias or an interface.
+fn is_type_export(ctx: &LintContext<'_>, entry: &ExportEntry) -> bool {
+    if entry.is_type {
+        return true;
+    }
+    entry.local_name.name().and_then(|name| ctx.scoping().get_root_binding(name)).is_some_and(
+        |symbol_id| {
+            let flags = ctx.scoping().symbol_flags(symbol_id);
+            flags.is_type() && !flags.is_value()
+        },
+    )
+}
+
+/// Names exported by the module itself, not including `export * from`.
+fn export_names(module_record: &ModuleRecord) -> FxHashSet<&str> {
+    module_record
+        .local_export_entries
+        .iter()
+        .chain(&module_record.indirect_export_entries)
+        .filter_map(|entry| match &entry.export_name {
+            ExportExportName::Name(name) => Some(name.name.as_str()),
+            ExportExportName::Default(_) => Some("default"),
+            ExportExportName::Null => None,
+        })
+        .collect()
+}
+
+/// Exports of a module which are used by other modules.
+#[derive(Debug, Default)]
+struct ExportUsage {
+    /// All exports are used, e.g. because of a namespace import.
+    all: bool,
+    /// All exports but `default` are used, through `export * from` of a module whose exports
+    /// are all used.
+    all_but_default: bool,
+    names: FxHashSet<CompactStr>,
+}
+
+impl ExportUsage {
+    fn is_used(&self, name: &str) -> bool {
+        self.all || (self.all_but_default && name != "default") || self.names.contains(name)
+    }
+}
+
+/// How a module uses a module it depends on.
+#[derive(Debug)]
+enum Dependency {
+    /// `import { foo } from 'mod'`, `export { foo } from 'mod'`
+    Names(Vec<CompactStr>),
+    /// `import * as ns from 'mod'`, `import('mod')`, `require('mod')`
+    All,
+    /// `import 'mod'`
+    SideEffect,
+    /// `export * from 'mod'`
+    StarExport,
+}
+
+impl Dependency {
+    /// How `importer` uses the module it requests with `specifier`.
+    fn of(importer: &ModuleRecord, specifier: &str) -> Self {
+        // `import()` and `require()` calls are resolved, but are not requested modules.
+        if !importer.requested_modules.contains_key(specifier) {
+            return Self::All;
+        }
+        let mut dependency = Self::SideEffect;
+        for entry in &importer.import_entries {
+            if entry.module_request.name() != specifier {
+                continue;
+            }
+            dependency.merge(match &entry.import_name {
+                ImportImportName::Name(name) => Self::Names(vec![name.name.clone()]),
+                ImportImportName::Default(_) => Self::Names(vec!["default".into()]),
+                ImportImportName::NamespaceObject => Self::All,
+            });
+        }
+        for entry in &importer.indirect_export_entries {
+            if entry.module_request.as_ref().is_none_or(|request| request.name() != specifier) {
+                continue;
+            }
+            dependency.merge(match &entry.import_name {
+                ExportImportName::Name(name) => Self::Names(vec![name.name.clone()]),
+                ExportImportName::All | ExportImportName::AllButDefault => Self::All,
+                ExportImportName::Null => continue,
+            });
+        }
+        if importer.star_export_entries.iter().any(|entry| {
+            entry.module_request.as_ref().is_some_and(|request| request.name() == specifier)
+        }) {
+            dependency.merge(Self::StarExport);
+        }
+        dependency
+    }
+
+    fn merge(&mut self, other: Self) {
+        *self = match (std::mem::replace(self, Self::SideEffect), other) {
+            // Names imported from a module which is also re-exported with `export *` cannot be
+            // told apart from forwarded names, so all exports are considered used.
+            (Self::All, _)
+            | (_, Self::All)
+            | (Self::StarExport, Self::Names(_))
+            | (Self::Names(_), Self::StarExport) => Self::All,
+            (dependency, Self::SideEffect) | (Self::SideEffect, dependency) => dependency,
+            (Self::Names(mut names), Self::Names(other_names)) => {
+                names.extend(other_names);
+                Self::Names(names)
+            }
+            (Self::StarExport, Self::StarExport) => Self::StarExport,
+        };
+    }
+}
+
+/// Whether `path` matches any of `globs`. Relative globs are resolved against the directory of
+/// the configuration file, like the `files` of overrides.
+fn matches_any(globs: &[CompactStr], ctx: &LintContext<'_>, path: &Path) -> bool {
+    if globs.is_empty() {
+        return false;
+    }
+    let relative_path = ctx.config_dir().and_then(|dir| path.strip_prefix(dir).ok());
+    let relative_path = relative_path.unwrap_or(path).to_string_lossy();
+    let path = path.to_string_lossy();
+    globs.iter().any(|glob| {
+        let path = if Path::new(glob.as_str()).is_absolute() { &path } else { &relative_path };
+        fast_glob::glob_match(glob.as_str(), path.as_ref())
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let missing_exports = json!([{ "missingExports": true }]);
+    let unused_exports =
+        json!([{ "unusedExports": true, "unusedFiles": true, "entries": ["main.js"] }]);
+
+    let test = |path: &'static str,
+                pass: Vec<(&'static str, serde_json::Value)>,
+                fail: Vec<(&'static str, serde_json::Value)>| {
+        let pass = pass.into_iter().map(|(code, config)| (code, Some(config))).collect::<Vec<_>>();
+        let fail = fail.into_iter().map(|(code, config)| (code, Some(config))).collect::<Vec<_>>();
+        Tester::new(NoUnusedModules::NAME, NoUnusedModules::PLUGIN, pass, fail)
+            .change_rule_path(&format!("no-unused-modules/{path}"))
+            .with_import_plugin(true)
+            .test();
+    };
+
+    test("file-b.js", vec![("export const b = 2", missing_exports.clone())], vec![]);
+    test(
+        "file-s.js",
+        vec![("export { default } from './file-o'", missing_exports.clone())],
+        vec![],
+    );
+    test("filte-r.js", vec![("export * from './cjs'", missing_exports.clone())], vec![]);
+    test("empty_file.js", vec![], vec![("", missing_exports.clone())]);
+
+    // The importers of a module are only known when all files are linted together, which is
+    // tested in `apps/oxlint`.
+    test("file-f.js", vec![("export default () => 1", unused_exports)], vec![]);
+
+    Tester::new(
+        NoUnusedModules::NAME,
+        NoUnusedModules::PLUGIN,
+        vec![("import { h3 as h0 } from './file-h'; export { h0 }", Some(missing_exports.clone()))],
+        vec![("import { h3 as h0 } from './file-h'", Some(missing_exports))],
+    )
+    .change_rule_path("no-unused-modules/file-p.js")
+    .with_import_plugin(true)
+    .test_and_snapshot();
+}
@@ -0,0 +1,1221 @@
+use std::{borrow::Cow, cmp::Ordering};
+
+use cow_utils::CowUtils;
+
+use nodejs_built_in_modules::is_nodejs_builtin_module;
+use schemars::JsonSchema;
+use serde::{Deserialize, de::Error as _};
+
+use oxc_ast::ast::{BindingPattern, Expression, Statement, TSModuleReference};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+
+use crate::{
+    ModuleResolution,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn out_of_order_diagnostic(
+    span: Span,
+    import: &Entry,
+    order: &str,
+    other: &Entry,
+) -> OxcDiagnostic {
+    let kind = |entry: &Entry| if entry.is_type { "type import" } else { "import" };
+    OxcDiagnostic::warn(format!(
+        "`{}` {} should occur {order} {} of `{}`",
+        import.specifier,
+        kind(import),
+        kind(other),
+        other.specifier
+    ))
+    .with_label(span)
+}
+
+fn missing_empty_line_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be at least one empty line between import groups")
+        .with_label(span)
+}
+
+fn empty_line_within_group_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line within import group").with_label(span)
+}
+
+fn empty_line_between_groups_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line between import groups").with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct Order(Box<OrderConfig>);
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OrderConfig {
+    /// How groups are ordered. Each element is an import type, or an array of import types which
+    /// are mixed together. Omitted types are grouped together as the last element.
+    ///
+    /// Import types are `builtin`, `external`, `internal`, `unknown`, `parent`, `sibling`,
+    /// `index`, `object` (TypeScript `import x = Foo.bar`) and `type` (`import type`; only
+    /// distinguished from other imports if it is listed).
+    groups: Vec<Group>,
+    /// Groups of imports matching a glob pattern, placed before or after another group, e.g.
+    /// `[{ "pattern": "~/**", "group": "internal" }]`.
+    path_groups: Vec<PathGroup>,
+    /// Import types which are not matched against `pathGroups`.
+    path_groups_excluded_import_types: Vec<ImportType>,
+    /// Whether `pathGroups` with a `position` form groups of their own, for `newlines-between`.
+    distinct_group: bool,
+    /// Whether empty lines are enforced between groups:
+    /// - `ignore`: empty lines are not checked.
+    /// - `always`: groups are separated by empty lines, and there are no empty lines
+    ///   within a group.
+    /// - `always-and-inside-groups`: like `always`, but empty lines within a group are allowed.
+    /// - `never`: no empty lines are allowed.
+    #[serde(rename = "newlines-between")]
+    newlines_between: NewlinesBetween,
+    /// Sort imports within each group by module specifier.
+    alphabetize: Alphabetize,
+    /// Also check imports without any bindings, e.g. `import 'polyfill'`.
+    /// They are never moved by the fixer, as their order may matter.
+    warn_on_unassigned_imports: bool,
+}
+
+impl Default for OrderConfig {
+    fn default() -> Self {
+        Self {
+            groups: [
+                ImportType::Builtin,
+                ImportType::External,
+                ImportType::Parent,
+                ImportType::Sibling,
+                ImportType::Index,
+            ]
+            .into_iter()
+            .map(Group::One)
+            .collect(),
+            path_groups: vec![],
+            path_groups_excluded_import_types: vec![
+                ImportType::Builtin,
+                ImportType::External,
+                ImportType::Object,
+            ],
+            distinct_group: true,
+            newlines_between: NewlinesBetween::default(),
+            alphabetize: Alphabetize::default(),
+            warn_on_unassigned_imports: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, JsonSchema, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ImportType {
+    Builtin,
+    External,
+    Internal,
+    Unknown,
+    Parent,
+    Sibling,
+    Index,
+    Object,
+    Type,
+}
+
+impl ImportType {
+    const ALL: [Self; 9] = [
+        Self::Builtin,
+        Self::External,
+        Self::Internal,
+        Self::Unknown,
+        Self::Parent,
+        Self::Sibling,
+        Self::Index,
+        Self::Object,
+        Self::Type,
+    ];
+}
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(untagged)]
+enum Group {
+    One(ImportType),
+    Many(Vec<ImportType>),
+}
+
+impl Group {
+    fn types(&self) -> &[ImportType] {
+        match self {
+            Self::One(import_type) => std::slice::from_ref(import_type),
+            Self::Many(import_types) => import_types,
+        }
+    }
+}
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PathGroup {
+    /// Glob pattern matched against the module specifier.
+    pattern: CompactStr,
+    /// The group to place matching imports in.
+    group: ImportType,
+    /// Place matching imports before or after the other imports of `group`.
+    /// By default, they are mixed with them.
+    #[serde(default)]
+    position: Option<Position>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, JsonSchema, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Position {
+    Before,
+    After,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, JsonSchema, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum NewlinesBetween {
+    #[default]
+    Ignore,
+    Always,
+    AlwaysAndInsideGroups,
+    Never,
+}
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct Alphabetize {
+    /// Sort module specifiers in ascending (`asc`) or descending (`desc`) order.
+    order: SortOrder,
+    /// Sort imports of the same module by kind, `type` or `value`.
+    order_import_kind: SortOrder,
+    /// Ignore case when sorting.
+    case_insensitive: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, JsonSchema, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum SortOrder {
+    #[default]
+    Ignore,
+    Asc,
+    Desc,
+}
+
+impl std::ops::Deref for Order {
+    type Target = OrderConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a convention in the order of `import` declarations, `import x = require()`
+    /// and top-level `require()` calls.
+    ///
+    /// Imports are ordered by their type, in the order of the `groups` option, e.g. with
+    /// `["builtin", "external", "internal", "parent", "sibling", "index"]`:
+    /// ```js
+    /// // 1. Node.js builtin modules
+    /// import fs from 'fs';
+    /// // 2. packages
+    /// import chalk from 'chalk';
+    /// // 3. modules of the project which are not relative, e.g. through `paths` in tsconfig.json
+    /// import foo from 'src/foo';
+    /// // 4. modules from a parent directory
+    /// import bar from '../bar';
+    /// // 5. modules from the same or a sibling directory
+    /// import baz from './bar/baz';
+    /// // 6. the index of the current directory
+    /// import main from './';
+    /// ```
+    ///
+    /// Imports without any bindings (`import 'polyfill'`) are ignored unless
+    /// `warnOnUnassignedImports` is set, and `require()` calls are ordered after imports.
+    ///
+    /// The fixer sorts runs of consecutive imports. Comments between two imports, and comments
+    /// after an import on the same line, are moved along with that import.
+    /// Imports are not moved across other statements or across imports without bindings.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A consistent order of imports makes it easier to see what a module depends on,
+    /// and avoids merge conflicts.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import _ from 'lodash';
+    /// import path from 'path'; // `path` import should occur before import of `lodash`
+    ///
+    /// import foo from './foo';
+    /// import bar from '../bar'; // `../bar` import should occur before import of `./foo`
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import path from 'path';
+    /// import _ from 'lodash';
+    ///
+    /// import bar from '../bar';
+    /// import foo from './foo';
+    /// ```
+    ///
+    /// Example configuration with alphabetized groups separated by empty lines, and imports
+    /// from `~/` placed after packages:
+    /// ```json
+    /// {
+    ///   "import/order": ["error", {
+    ///     "groups": ["builtin", "external", "internal", ["parent", "sibling"], "index"],
+    ///     "pathGroups": [{ "pattern": "~/**", "group": "external", "position": "after" }],
+    ///     "newlines-between": "always",
+    ///     "alphabetize": { "order": "asc", "caseInsensitive": true }
+    ///   }]
+    /// }
+    /// ```
+    Order,
+    import,
+    style,
+    fix,
+    config = OrderConfig,
+);
+
+impl Rule for Order {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        let rule = serde_json::from_value::<DefaultRuleConfig<Self>>(value)
+            .map(DefaultRuleConfig::into_inner)?;
+        let mut seen = vec![];
+        for import_type in rule.groups.iter().flat_map(Group::types) {
+            if seen.contains(import_type) {
+                return Err(serde_json::Error::custom(format!(
+                    "Incorrect configuration of the rule: `{import_type:?}` is duplicated in `groups`"
+                )));
+            }
+            seen.push(*import_type);
+        }
+        Ok(rule)
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let ranks = self.ranks();
+        let mut entries = self.collect_entries(ctx, &ranks);
+        if entries.len() < 2 {
+            return;
+        }
+
+        if self.newlines_between != NewlinesBetween::Ignore {
+            self.check_newlines_between(ctx, &entries);
+        }
+        if self.alphabetize.order != SortOrder::Ignore {
+            self.alphabetize_ranks(&mut entries);
+        }
+        self.check_order(ctx, &entries);
+    }
+}
+
+/// Ranks of import types and path groups, derived from the configuration.
+struct Ranks<'c> {
+    /// Rank of each import type, in the order of [`ImportType::ALL`].
+    /// Ranks of groups are spaced apart, to leave room for path groups around them.
+    types: [i64; 9],
+    /// Whether `type` is listed in `groups`.
+    has_type_group: bool,
+    /// Pattern, rank of the group and rank of the path group.
+    path_groups: Vec<(&'c str, i64, i64)>,
+}
+
+impl Ranks<'_> {
+    fn of(&self, import_type: ImportType) -> i64 {
+        let index = ImportType::ALL.iter().position(|t| *t == import_type).unwrap_or_default();
+        self.types[index]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EntryKind {
+    Import,
+    Require,
+    Object,
+}
+
+#[derive(Debug)]
+struct Entry<'a> {
+    specifier: &'a str,
+    is_type: bool,
+    /// Index of the statement in the program body.
+    statement: usize,
+    span: Span,
+    /// Rank of the group, without path group positions.
+    group: i64,
+    /// Rank of the group, including path group positions.
+    rank: i64,
+    /// Position within the group once alphabetized.
+    position: usize,
+    /// Whether the fixer may move this import.
+    fixable: bool,
+}
+
+impl Entry<'_> {
+    fn key(&self) -> (i64, usize) {
+        (self.rank, self.position)
+    }
+
+    fn is_before(&self, other: &Self) -> bool {
+        self.key() < other.key()
+    }
+}
+
+impl Order {
+    #[expect(clippy::cast_possible_wrap)]
+    fn ranks(&self) -> Ranks<'_> {
+        // Path groups positioned before a group get negative positions, in the order they are
+        // listed, and path groups positioned after a group get positive positions.
+        let positions = self
+            .path_groups
+            .iter()
+            .enumerate()
+            .map(|(i, path_group)| {
+                let is_same = |other: &&PathGroup| {
+                    other.group == path_group.group && other.position == path_group.position
+                };
+                match path_group.position {
+                    Some(Position::After) => {
+                        self.path_groups[..i].iter().filter(is_same).count() as i64 + 1
+                    }
+                    Some(Position::Before) => {
+                        -(self.path_groups[i + 1..].iter().filter(is_same).count() as i64 + 1)
+                    }
+                    None => 0,
+                }
+            })
+            .collect::<Vec<_>>();
+        // Leave room for all positions between the ranks of two groups.
+        let spacing = 2 * (self.path_groups.len() as i64 + 1);
+
+        let mut types = [self.groups.len() as i64 * spacing; 9];
+        let mut has_type_group = false;
+        for (index, group) in self.groups.iter().enumerate() {
+            for import_type in group.types() {
+                let i = ImportType::ALL.iter().position(|t| t == import_type).unwrap_or_default();
+                types[i] = index as i64 * spacing;
+                has_type_group |= *import_type == ImportType::Type;
+            }
+        }
+        let ranks = Ranks { types, has_type_group, path_groups: vec![] };
+        let path_groups = self
+            .path_groups
+            .iter()
+            .zip(positions)
+            .map(|(path_group, position)| {
+                let group = ranks.of(path_group.group);
+                (path_group.pattern.as_str(), group, group + position)
+            })
+            .collect();
+        Ranks { path_groups, ..ranks }
+    }
+
+    fn collect_entries<'a>(&self, ctx: &LintContext<'a>, ranks: &Ranks<'_>) -> Vec<Entry<'a>> {
+        let mut entries = vec![];
+        for (statement, stmt) in ctx.nodes().program().body.iter().enumerate() {
+            let (specifier, kind, is_type, fixable) = match stmt {
+                Statement::ImportDeclaration(decl) => {
+                    let unassigned =
+                        decl.specifiers.as_ref().is_none_or(|specifiers| specifiers.is_empty());
+                    if unassigned && !self.warn_on_unassigned_imports {
+                        continue;
+                    }
+                    (
+                        decl.source.value.as_str(),
+                        EntryKind::Import,
+                        decl.import_kind.is_type(),
+                        !unassigned,
+                    )
+                }
+                Statement::TSImportEqualsDeclaration(decl) => match &decl.module_reference {
+                    TSModuleReference::ExternalModuleReference(reference) => (
+                        reference.expression.value.as_str(),
+                        EntryKind::Import,
+                        decl.import_kind.is_type(),
+                        true,
+                    ),
+                    reference => (
+                        ctx.source_range(reference.span()),
+                        EntryKind::Object,
+                        decl.import_kind.is_type(),
+                        true,
+                    ),
+                },
+                Statement::VariableDeclaration(decl) => {
+                    let [declarator] = decl.declarations.as_slice() else {
+                        continue;
+                    };
+                    let Some(Expression::CallExpression(call)) = &declarator.init else {
+                        continue;
+                    };
+                    let Some(source) = call.common_js_require() else {
+                        continue;
+                    };
+                    if !matches!(
+                        declarator.id,
+                        BindingPattern::BindingIdentifier(_) | BindingPattern::ObjectPattern(_)
+                    ) {
+                        continue;
+                    }
+                    (source.value.as_str(), EntryKind::Require, false, true)
+                }
+                _ => continue,
+            };
+
+            let import_type = if kind == EntryKind::Object {
+                ImportType::Object
+            } else if is_type && ranks.has_type_group {
+                ImportType::Type
+            } else {
+                import_type(ctx, specifier)
+            };
+            let (mut group, mut rank) = (ranks.of(import_type), ranks.of(import_type));
+            if !self.path_groups_excluded_import_types.contains(&import_type)
+                && let Some((_, path_group, path_rank)) = ranks
+                    .path_groups
+                    .iter()
+                    .find(|(pattern, _, _)| fast_glob::glob_match(*pattern, specifier))
+            {
+                (group, rank) = (*path_group, *path_rank);
+            }
+            // `require()` calls are ordered after all imports.
+            if kind == EntryKind::Require {
+                group += i64::from(u32::MAX);
+                rank += i64::from(u32::MAX);
+            }
+            entries.push(Entry {
+                specifier,
+                is_type,
+                statement,
+                span: stmt.span(),
+                group,
+                rank,
+                position: 0,
+                fixable,
+            });
+        }
+        entries
+    }
+
+    /// Order imports of the same rank by their module specifier.
+    fn alphabetize_ranks(&self, entries: &mut [Entry<'_>]) {
+        let mut sorted = (0..entries.len()).collect::<Vec<_>>();
+        sorted.sort_by(|a, b| {
+            let (a, b) = (&entries[*a], &entries[*b]);
+            a.rank.cmp(&b.rank).then_with(|| self.compare_specifiers(a, b))
+        });
+        let mut position = 0;
+        for (i, index) in sorted.iter().enumerate() {
+            if i > 0 {
+                let previous = &entries[sorted[i - 1]];
+                let entry = &entries[*index];
+                if previous.rank != entry.rank
+                    || self.compare_specifiers(previous, entry) != Ordering::Equal
+                {
+                    position += 1;
+                }
+            }
+            entries[*index].position = position;
+        }
+    }
+
+    fn compare_specifiers<'a>(&self, a: &Entry<'a>, b: &Entry<'a>) -> Ordering {
+        let normalize = |specifier: &'a str| -> Cow<'a, str> {
+            if self.alphabetize.case_insensitive {
+                specifier.cow_to_lowercase()
+            } else {
+                Cow::Borrowed(specifier)
+            }
+        };
+        let (a_specifier, b_specifier) = (normalize(a.specifier), normalize(b.specifier));
+        let mut result = if a_specifier.contains('/') || b_specifier.contains('/') {
+            compare_paths(&a_specifier, &b_specifier)
+        } else {
+            a_specifier.cmp(&b_specifier)
+        };
+        if self.alphabetize.order == SortOrder::Desc {
+            result = result.reverse();
+        }
+        if result == Ordering::Equal && self.alphabetize.order_import_kind != SortOrder::Ignore {
+            // `type` sorts before `value`.
+            result = b.is_type.cmp(&a.is_type);
+            if self.alphabetize.order_import_kind == SortOrder::Desc {
+                result = result.reverse();
+            }
+        }
+        result
+    }
+
+    fn check_newlines_between(&self, ctx: &LintContext<'_>, entries: &[Entry<'_>]) {
+        let source_text = ctx.source_text();
+        for pair in entries.windows(2) {
+            let [previous, current] = pair else { unreachable!() };
+            let between = &source_text[previous.span.end as usize..current.span.start as usize];
+            let empty_lines = empty_lines_between(between);
+            let distinct = self.is_distinct_group(previous, current);
+
+            match self.newlines_between {
+                NewlinesBetween::Always | NewlinesBetween::AlwaysAndInsideGroups
+                    if distinct && empty_lines == 0 =>
+                {
+                    let line_end = end_of_line(ctx, previous.span.end);
+                    if between.contains('\n') {
+                        ctx.diagnostic_with_fix(
+                            missing_empty_line_diagnostic(previous.span),
+                            |fixer| {
+                                fixer
+                                    .insert_text_after_range(Span::empty(line_end), "\n")
+                                    .with_message("Insert an empty line")
+                            },
+                        );
+                    } else {
+                        ctx.diagnostic(missing_empty_line_diagnostic(previous.span));
+                    }
+                }
+                NewlinesBetween::Always if !distinct && empty_lines > 0 => {
+                    report_empty_lines(ctx, previous, current, empty_line_within_group_diagnostic);
+                }
+                NewlinesBetween::Never if empty_lines > 0 => {
+                    report_empty_lines(
+                        ctx,
+                        previous,
+                        current,
+                        empty_line_between_groups_diagnostic,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn is_distinct_group(&self, a: &Entry<'_>, b: &Entry<'_>) -> bool {
+        if self.distinct_group { a.rank != b.rank } else { a.group != b.group }
+    }
+
+    fn check_order(&self, ctx: &LintContext<'_>, entries: &[Entry<'_>]) {
+        let out_of_order = find_out_of_order(entries.iter(), false);
+        if out_of_order.is_empty() {
+            return;
+        }
+        // Report whichever direction needs fewer imports to be moved.
+        let reversed_out_of_order = find_out_of_order(entries.iter().rev(), true);
+        let (out_of_order, order, reversed) = if reversed_out_of_order.len() < out_of_order.len() {
+            (reversed_out_of_order, "after", true)
+        } else {
+            (out_of_order, "before", false)
+        };
+
+        for entry in out_of_order {
+            let is_target = |other: &&Entry<'_>| {
+                if reversed { other.is_before(entry) } else { entry.is_before(other) }
+            };
+            let target = if reversed {
+                entries.iter().rev().find(is_target)
+            } else {
+                entries.iter().find(is_target)
+            };
+            let Some(target) = target else {
+                continue;
+            };
+            let diagnostic = out_of_order_diagnostic(entry.span, entry, order, target);
+            match self.fix_run(ctx, entries, entry) {
+                Some((span, text)) => {
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                        fixer.replace(span, text).with_message("Sort the imports")
+                    });
+                }
+                None => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+
+    /// Sort the run of consecutive fixable imports which contains `entry`.
+    /// Returns `None` if `entry` is not fixable, or if its run is already sorted.
+    fn fix_run(
+        &self,
+        ctx: &LintContext<'_>,
+        entries: &[Entry<'_>],
+        entry: &Entry<'_>,
+    ) -> Option<(Span, String)> {
+        if !entry.fixable {
+            return None;
+        }
+        let index = entries.iter().position(|e| e.span == entry.span)?;
+        let mut start = index;
+        while start > 0
+            && entries[start - 1].fixable
+            && entries[start - 1].statement + 1 == entries[start].statement
+        {
+            start -= 1;
+        }
+        let mut end = index + 1;
+        while end < entries.len()
+            && entries[end].fixable
+            && entries[end - 1].statement + 1 == entries[end].statement
+        {
+            end += 1;
+        }
+        let run = &entries[start..end];
+        let mut sorted = (0..run.len()).collect::<Vec<_>>();
+        sorted.sort_by(|a, b| run[*a].key().partial_cmp(&run[*b].key()).unwrap_or(Ordering::Equal));
+        if sorted.iter().enumerate().all(|(i, index)| i == *index) {
+            return None;
+        }
+
+        // Each import takes the comments before it (except for the first import of the run)
+        // and the comments after it on the same line.
+        let source_text = ctx.source_text();
+        let mut chunks = Vec::with_capacity(run.len());
+        let mut chunk_start = run[0].span.start;
+        for e in run {
+            let chunk_end = end_of_statement_line(ctx, e.span.end);
+            chunks.push(Span::new(chunk_start, chunk_end));
+            let rest = &source_text[chunk_end as usize..];
+            let whitespace = rest.len() - rest.trim_start().len();
+            chunk_start = chunk_end + u32::try_from(whitespace).ok()?;
+        }
+        let separators = chunks
+            .windows(2)
+            .map(|pair| &source_text[pair[0].end as usize..pair[1].start as usize])
+            .collect::<Vec<_>>();
+
+        let mut text = String::new();
+        for (i, index) in sorted.iter().enumerate() {
+            if i > 0 {
+                let previous = sorted[i - 1];
+                let separator = self.separator(separators[i - 1], &run[previous], &run[*index]);
+                if !separator.contains('\n') && ends_with_line_comment(ctx, chunks[previous].end) {
+                    text.push('\n');
+                } else {
+                    text.push_str(&separator);
+                }
+            }
+            text.push_str(ctx.source_range(chunks[*index]));
+        }
+        Some((Span::new(chunks[0].start, chunks[chunks.len() - 1].end), text))
+    }
+
+    /// The whitespace to put between two imports when sorting, given the whitespace which was
+    /// at the same position before.
+    fn separator(&self, original: &str, previous: &Entry<'_>, current: &Entry<'_>) -> String {
+        let Some(last_newline) = original.rfind('\n') else {
+            return original.to_string();
+        };
+        let indent = &original[last_newline + 1..];
+        let distinct = self.is_distinct_group(previous, current);
+        match self.newlines_between {
+            NewlinesBetween::Always | NewlinesBetween::AlwaysAndInsideGroups if distinct => {
+                format!("\n\n{indent}")
+            }
+            NewlinesBetween::Always | NewlinesBetween::Never => format!("\n{indent}"),
+            NewlinesBetween::AlwaysAndInsideGroups | NewlinesBetween::Ignore => {
+                original.to_string()
+            }
+        }
+    }
+}
+
+/// Entries which should occur before an entry preceding them in `entries`.
+/// If `reversed` is set, `entries` are iterated in reverse and the order is reversed as well.
+fn find_out_of_order<'e, 'a: 'e>(
+    mut entries: impl Iterator<Item = &'e Entry<'a>>,
+    reversed: bool,
+) -> Vec<&'e Entry<'a>> {
+    let Some(mut max_seen) = entries.next() else {
+        return vec![];
+    };
+    let is_before =
+        |a: &Entry<'_>, b: &Entry<'_>| if reversed { b.is_before(a) } else { a.is_before(b) };
+    let mut out_of_order = vec![];
+    for entry in entries {
+        if is_before(entry, max_seen) {
+            out_of_order.push(entry);
+        }
+        if is_before(max_seen, entry) {
+            max_seen = entry;
+        }
+    }
+    out_of_order
+}
+
+/// Type of the import of `specifier`.
+///
+/// Bare specifiers are `external`, unless they resolve to a file outside of `node_modules`,
+/// in which case they are `internal`. Other specifiers which resolve to such a file, e.g.
+/// `@/foo` or `~/foo`, are `internal` too.
+fn import_type(ctx: &LintContext<'_>, specifier: &str) -> ImportType {
+    let resolution = ctx.module_record().resolutions.get(specifier);
+    if is_nodejs_builtin_module(specifier) || matches!(resolution, Some(ModuleResolution::Builtin))
+    {
+        return ImportType::Builtin;
+    }
+    if specifier == ".." || specifier.starts_with("../") {
+        return ImportType::Parent;
+    }
+    if is_index(specifier) {
+        return ImportType::Index;
+    }
+    if specifier.starts_with("./") {
+        return ImportType::Sibling;
+    }
+    let is_internal_path = matches!(resolution, Some(ModuleResolution::Path(path))
+        if !path.components().any(|c| c.as_os_str() == "node_modules"));
+    if is_internal_path {
+        return ImportType::Internal;
+    }
+    let is_module = specifier.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_');
+    let is_scoped = specifier
+        .strip_prefix('@')
+        .and_then(|rest| rest.split_once('/'))
+        .is_some_and(|(scope, name)| !scope.is_empty() && !name.is_empty());
+    if is_module || is_scoped { ImportType::External } else { ImportType::Unknown }
+}
+
+fn is_index(specifier: &str) -> bool {
+    matches!(specifier, "." | "./" | "./index")
+        || specifier
+            .strip_prefix("./index.")
+            .is_some_and(|ext| !ext.is_empty() && !ext.contains('/'))
+}
+
+/// Compare two module specifiers segment by segment, so that `a/b` sorts before `a-b`.
+fn compare_paths(a: &str, b: &str) -> Ordering {
+    let a = a.split('/').collect::<Vec<_>>();
+    let b = b.split('/').collect::<Vec<_>>();
+    for (i, (a_segment, b_segment)) in a.iter().zip(&b).enumerate() {
+        // Relative imports from the same directory compare by the rest of the path.
+        if i == 0 && matches!(*a_segment, "." | "..") && matches!(*b_segment, "." | "..") {
+            if a_segment != b_segment {
+                break;
+            }
+            continue;
+        }
+        match a_segment.cmp(b_segment) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+/// Number of empty lines in `text`, excluding its first and last line.
+fn empty_lines_between(text: &str) -> usize {
+    let lines = text.split('\n').collect::<Vec<_>>();
+    if lines.len() < 3 {
+        return 0;
+    }
+    lines[1..lines.len() - 1].iter().filter(|line| line.trim().is_empty()).count()
+}
+
+/// The end of a statement ending at `end`, including comments after it on the same line.
+fn end_of_statement_line(ctx: &LintContext<'_>, mut end: u32) -> u32 {
+    let source_text = ctx.source_text();
+    for comment in ctx.comments_range(end..) {
+        let between = &source_text[end as usize..comment.span.start as usize];
+        if !between.trim().is_empty() || between.contains('\n') {
+            break;
+        }
+        end = comment.span.end;
+    }
+    end
+}
+
+/// The position of the line break after a statement ending at `end`, skipping comments after it
+/// on the same line.
+fn end_of_line(ctx: &LintContext<'_>, end: u32) -> u32 {
+    let end = end_of_statement_line(ctx, end);
+    let rest = &ctx.source_text()[end as usize..];
+    let offset = rest.find('\n').map_or(rest.len(), |i| rest[..i].trim_end_matches('\r').len());
+    end + u32::try_from(offset).unwrap_or(0)
+}
+
+fn ends_with_line_comment(ctx: &LintContext<'_>, end: u32) -> bool {
+    ctx.comments().iter().any(|comment| comment.span.end == end && comment.is_line())
+}
+
+/// Report empty lines between two imports, with a fix to remove them if there are no comments
+/// between the imports.
+fn report_empty_lines(
+    ctx: &LintContext<'_>,
+    previous: &Entry<'_>,
+    current: &Entry<'_>,
+    diagnostic: fn(Span) -> OxcDiagnostic,
+) {
+    let line_end = end_of_line(ctx, previous.span.end);
+    let between = &ctx.source_text()[line_end as usize..current.span.start as usize];
+    if !between.trim().is_empty() {
+        ctx.diagnostic(diagnostic(previous.span));
+        return;
+    }
+    let line_start =
+        line_end + between.rfind('\n').map_or(0, |i| u32::try_from(i + 1).unwrap_or(0));
+    ctx.diagnostic_with_fix(diagnostic(previous.span), |fixer| {
+        fixer.replace(Span::new(line_end, line_start), "\n").with_message("Remove the empty lines")
+    });
+}
+
+#[test]
+fn test_compare_paths() {
+    assert_eq!(compare_paths("a/b", "a-b"), Ordering::Less);
+    assert_eq!(compare_paths("./a", "./b"), Ordering::Less);
+    assert_eq!(compare_paths("./a/b", "./a"), Ordering::Greater);
+    assert_eq!(compare_paths("../a", "./b"), Ordering::Equal);
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            r"import fs from 'fs';
+            import async from 'async';
+            import sibling from './foo';
+            import index from './';",
+            None,
+        ),
+        (
+            r"import fs from 'fs';
+            import async from 'async';
+            import parent from '../foo';
+            import sibling from './foo';
+            import index from '.';",
+            None,
+        ),
+        (
+            r"var fs = require('fs');
+            var async = require('async');
+            var relParent = require('../foo');
+            var sibling = require('./foo');
+            var index = require('./');",
+            None,
+        ),
+        // Unassigned imports are ignored
+        (
+            r"import './foo';
+            import 'fs';
+            import async from 'async';",
+            None,
+        ),
+        // Imports and requires are ordered separately
+        (
+            r"import sibling from './foo';
+            var fs = require('fs');",
+            None,
+        ),
+        // Requires in functions and other expressions are ignored
+        (
+            r"var sibling = require('./foo');
+            function f() { var fs = require('fs'); }
+            var path = require('path').join;",
+            None,
+        ),
+        (r"import { a } from 'fs'; import { b } from 'fs';", None),
+        // Groups mixed together
+        (
+            r"import sibling from './foo';
+            import fs from 'fs';
+            import index from '.';",
+            Some(json!([{ "groups": [["builtin", "sibling"], "index"] }])),
+        ),
+        // Omitted types go last
+        (
+            r"import index from '.';
+            import fs from 'fs';",
+            Some(json!([{ "groups": ["index"] }])),
+        ),
+        (
+            r"import fs from 'fs';
+            import async from 'async';
+
+            import sibling from './foo';",
+            Some(
+                json!([{ "groups": [["builtin", "external"], "sibling"], "newlines-between": "always" }]),
+            ),
+        ),
+        (
+            r"import fs from 'fs';
+
+            import async from 'async';
+            import lodash from 'lodash';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"import fs from 'fs';
+
+            import async from 'async';
+
+            import lodash from 'lodash';",
+            Some(json!([{ "newlines-between": "always-and-inside-groups" }])),
+        ),
+        (
+            r"import fs from 'fs';
+            import async from 'async';
+            import sibling from './foo';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            r"import fs from 'fs';
+
+            import async from 'async';",
+            Some(json!([{ "newlines-between": "ignore" }])),
+        ),
+        // Alphabetize
+        (
+            r"import a from 'a';
+            import B from 'B';
+            import c from 'c';",
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": true } }])),
+        ),
+        (
+            r"import B from 'B';
+            import a from 'a';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"import c from 'c';
+            import b from 'b';
+            import a from 'a';",
+            Some(json!([{ "alphabetize": { "order": "desc" } }])),
+        ),
+        (
+            r"import a from 'a/b';
+            import b from 'a-b';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"import type { A } from 'a';
+            import a from 'a';",
+            Some(json!([{ "alphabetize": { "order": "asc", "orderImportKind": "asc" } }])),
+        ),
+        // Path groups
+        (
+            r"import fs from 'fs';
+            import async from 'async';
+            import foo from '~/foo';
+            import sibling from './foo';",
+            Some(json!([{
+                "pathGroups": [{ "pattern": "~/**", "group": "external", "position": "after" }],
+            }])),
+        ),
+        (
+            r"import react from 'react';
+            import fs from 'fs';",
+            Some(json!([{
+                "pathGroups": [{ "pattern": "react", "group": "builtin", "position": "before" }],
+                "pathGroupsExcludedImportTypes": [],
+            }])),
+        ),
+        (
+            r"import async from 'async';
+            import foo from '~/foo';",
+            Some(json!([{
+                "pathGroups": [{ "pattern": "~/**", "group": "external", "position": "after" }],
+                "distinctGroup": false,
+                "newlines-between": "always",
+            }])),
+        ),
+        // Type imports as their own group
+        (
+            r"import fs from 'fs';
+            import sibling from './foo';
+            import type { Foo } from 'async';",
+            Some(json!([{ "groups": ["builtin", "external", "sibling", "type"] }])),
+        ),
+        // Object imports
+        (
+            r"import fs = require('fs');
+            import log = console.log;",
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (
+            r"import sibling from './foo';
+            import fs from 'fs';",
+            None,
+        ),
+        (
+            r"import parent from '../foo';
+            import async from 'async';",
+            None,
+        ),
+        (
+            r"import index from './';
+            import sibling from './foo';",
+            None,
+        ),
+        (
+            r"var sibling = require('./foo');
+            var fs = require('fs');",
+            None,
+        ),
+        // Not fixable across other statements
+        (
+            r"import sibling from './foo';
+            foo();
+            import fs from 'fs';",
+            None,
+        ),
+        (
+            r"import sibling from './foo';
+            import 'polyfill';
+            import fs from 'fs';",
+            None,
+        ),
+        (
+            r"import './foo';
+            import 'fs';",
+            Some(json!([{ "warnOnUnassignedImports": true }])),
+        ),
+        // Reported after the imports it should follow, when fewer imports are out of order
+        (
+            r"import index from './';
+            import fs from 'fs';
+            import path from 'path';
+            import async from 'async';",
+            None,
+        ),
+        (
+            r"import fs from 'fs';
+            import async from 'async';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"import fs from 'fs';
+
+            import async from 'async';
+
+            import lodash from 'lodash';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"import fs from 'fs';
+
+            import async from 'async';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            r"import b from 'b';
+            import a from 'a';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"import a from 'a';
+            import B from 'B';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"import a from 'a';
+            import type { A } from 'a';",
+            Some(json!([{ "alphabetize": { "order": "asc", "orderImportKind": "asc" } }])),
+        ),
+        (
+            r"import foo from '~/foo';
+            import async from 'async';",
+            Some(json!([{
+                "pathGroups": [{ "pattern": "~/**", "group": "external", "position": "after" }],
+            }])),
+        ),
+        (
+            r"import type { Foo } from 'async';
+            import fs from 'fs';",
+            Some(json!([{ "groups": ["builtin", "external", "type"] }])),
+        ),
+        (
+            r"import log = console.log;
+            import fs = require('fs');",
+            None,
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "import sibling from './foo';\nimport fs from 'fs';",
+            "import fs from 'fs';\nimport sibling from './foo';",
+            None,
+        ),
+        // Comments move with their imports
+        (
+            "// header\nimport sibling from './foo'; // sibling\n// about fs\nimport fs from 'fs'; /* fs */\nfoo();",
+            "// header\n// about fs\nimport fs from 'fs'; /* fs */\nimport sibling from './foo'; // sibling\nfoo();",
+            None,
+        ),
+        (
+            "import sibling from './foo'; // sibling\nimport parent from '../foo';\nimport fs from 'fs';",
+            "import fs from 'fs';\nimport parent from '../foo';\nimport sibling from './foo'; // sibling",
+            None,
+        ),
+        (
+            "import sibling from './foo'; import fs from 'fs';",
+            "import fs from 'fs'; import sibling from './foo';",
+            None,
+        ),
+        (
+            "import b from 'b'; // b\nimport a from 'a';",
+            "import a from 'a';\nimport b from 'b'; // b",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            "var sibling = require('./foo');\nvar fs = require('fs');",
+            "var fs = require('fs');\nvar sibling = require('./foo');",
+            None,
+        ),
+        // Only the run containing both imports is sorted
+        (
+            "import sibling from './foo';\nimport fs from 'fs';\nfoo();\nimport b from './b';\nimport a from '../a';",
+            "import fs from 'fs';\nimport sibling from './foo';\nfoo();\nimport a from '../a';\nimport b from './b';",
+            None,
+        ),
+        // Not fixed across other statements or unassigned imports
+        (
+            "import sibling from './foo';\nfoo();\nimport fs from 'fs';",
+            "import sibling from './foo';\nfoo();\nimport fs from 'fs';",
+            None,
+        ),
+        (
+            "import sibling from './foo';\nimport 'polyfill';\nimport fs from 'fs';",
+            "import sibling from './foo';\nimport 'polyfill';\nimport fs from 'fs';",
+            None,
+        ),
+        // Newlines between groups
+        (
+            "import sibling from './foo';\nimport async from 'async';\nimport fs from 'fs';",
+            "import fs from 'fs';\n\nimport async from 'async';\n\nimport sibling from './foo';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "  import fs from 'fs';\n  import async from 'async'; // async\n  import lodash from 'lodash';",
+            "  import fs from 'fs';\n\n  import async from 'async'; // async\n  import lodash from 'lodash';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\nimport async from 'async';\n\n\nimport lodash from 'lodash';",
+            "import fs from 'fs';\nimport async from 'async';\nimport lodash from 'lodash';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport async from 'async';\n\nimport lodash from 'lodash';",
+            "import fs from 'fs';\n\nimport async from 'async';\nimport lodash from 'lodash';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import sibling from './foo';\n\nimport fs from 'fs';",
+            "import fs from 'fs';\nimport sibling from './foo';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+    ];
+
+    Tester::new(Order::NAME, Order::PLUGIN, pass, fail).expect_fix(fix).test_and_snapshot();
+}
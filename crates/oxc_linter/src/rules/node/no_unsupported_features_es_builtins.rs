@@ -0,0 +1,187 @@
+use oxc_ast::AstKind;
+use oxc_compat::Version;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::Span;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{UnsupportedFeaturesConfig, unsupported_feature_diagnostic},
+};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnsupportedFeaturesEsBuiltins(Box<UnsupportedFeaturesConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows ECMAScript built-in globals and static methods which are not available in the
+    /// configured Node.js versions.
+    ///
+    /// The version range is read from the `version` option, or from the `engines.node` field of
+    /// the closest `package.json`, and defaults to `>=16.0.0`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unlike new syntax, missing built-ins are not detected when the module loads. The code
+    /// only throws once it reaches the call, which may be in a rarely taken branch.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "version": ">=14.0.0" }`:
+    /// ```js
+    /// const results = await Promise.any(requests);
+    /// if (Object.hasOwn(options, "cwd")) {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "version": ">=14.0.0" }`:
+    /// ```js
+    /// const results = await Promise.allSettled(requests);
+    /// if (Object.prototype.hasOwnProperty.call(options, "cwd")) {}
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// This rule accepts a configuration object with the following properties:
+    ///
+    /// #### version
+    ///
+    /// `{ type: string }`
+    ///
+    /// The semver range of Node.js versions to check against, e.g. `">=14.17.0"`.
+    ///
+    /// #### ignores
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Built-ins to never report, e.g. `["globalThis", "Promise.any"]`.
+    NoUnsupportedFeaturesEsBuiltins,
+    node,
+    correctness,
+    config = UnsupportedFeaturesConfig,
+);
+
+impl Rule for NoUnsupportedFeaturesEsBuiltins {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let range = self.0.node_version_range(ctx);
+        let check = |name: &str, supported: Version, span: Span| {
+            if !range.supports(supported) && !self.0.ignores.contains(name) {
+                ctx.diagnostic(unsupported_feature_diagnostic(
+                    &format!("`{name}`"),
+                    supported,
+                    &range,
+                    span,
+                ));
+            }
+        };
+
+        for node in ctx.nodes() {
+            match node.kind() {
+                AstKind::IdentifierReference(ident) => {
+                    if let Some(&(name, supported)) =
+                        GLOBALS.iter().find(|(name, _)| ident.name == *name)
+                        && ident.is_global_reference(ctx.scoping())
+                    {
+                        check(name, supported, ident.span);
+                    }
+                }
+                AstKind::StaticMemberExpression(member) => {
+                    let Some(object) = member.object.get_identifier_reference() else {
+                        continue;
+                    };
+                    if let Some(&(object_name, property_name, supported)) =
+                        STATIC_MEMBERS.iter().find(|(object_name, property_name, _)| {
+                            object.name == *object_name && member.property.name == *property_name
+                        })
+                        && object.is_global_reference(ctx.scoping())
+                    {
+                        check(&format!("{object_name}.{property_name}"), supported, member.span);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Global objects and the first Node.js version providing them.
+const GLOBALS: &[(&str, Version)] = &[
+    ("Proxy", Version(6, 0, 0)),
+    ("Reflect", Version(6, 0, 0)),
+    ("Atomics", Version(8, 10, 0)),
+    ("SharedArrayBuffer", Version(8, 10, 0)),
+    ("BigInt", Version(10, 4, 0)),
+    ("BigInt64Array", Version(10, 4, 0)),
+    ("BigUint64Array", Version(10, 4, 0)),
+    ("globalThis", Version(12, 0, 0)),
+    ("WeakRef", Version(14, 6, 0)),
+    ("FinalizationRegistry", Version(14, 6, 0)),
+    ("AggregateError", Version(15, 0, 0)),
+    ("Iterator", Version(22, 0, 0)),
+    ("DisposableStack", Version(24, 0, 0)),
+    ("AsyncDisposableStack", Version(24, 0, 0)),
+    ("SuppressedError", Version(24, 0, 0)),
+];
+
+/// Static members of global objects and the first Node.js version providing them.
+const STATIC_MEMBERS: &[(&str, &str, Version)] = &[
+    ("Object", "values", Version(7, 0, 0)),
+    ("Object", "entries", Version(7, 0, 0)),
+    ("Object", "getOwnPropertyDescriptors", Version(7, 0, 0)),
+    ("Symbol", "asyncIterator", Version(10, 0, 0)),
+    ("Object", "fromEntries", Version(12, 0, 0)),
+    ("Promise", "allSettled", Version(12, 9, 0)),
+    ("Promise", "any", Version(15, 0, 0)),
+    ("Object", "hasOwn", Version(16, 9, 0)),
+    ("Object", "groupBy", Version(21, 0, 0)),
+    ("Map", "groupBy", Version(21, 0, 0)),
+    ("Array", "fromAsync", Version(22, 0, 0)),
+    ("Promise", "withResolvers", Version(22, 0, 0)),
+    ("Promise", "try", Version(23, 0, 0)),
+    ("RegExp", "escape", Version(24, 0, 0)),
+];
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("Promise.allSettled(requests);", Some(json!([{ "version": ">=12.9.0" }]))),
+        ("Object.hasOwn(a, 'b');", Some(json!([{ "version": ">=16.9.0" }]))),
+        ("const g = globalThis;", None),
+        ("const Promise = lib; Promise.any(requests);", Some(json!([{ "version": ">=12.0.0" }]))),
+        ("function f(globalThis) { return globalThis; }", Some(json!([{ "version": ">=10.0.0" }]))),
+        ("foo.Object.hasOwn(a, 'b');", Some(json!([{ "version": ">=14.0.0" }]))),
+        ("Object.assign({}, a);", Some(json!([{ "version": ">=4.0.0" }]))),
+        (
+            "Promise.any(requests);",
+            Some(json!([{ "version": ">=14.0.0", "ignores": ["Promise.any"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("Promise.any(requests);", Some(json!([{ "version": ">=14.0.0" }]))),
+        ("Object.hasOwn(a, 'b');", Some(json!([{ "version": "^14.17.0 || >=16.0.0" }]))),
+        ("const g = globalThis;", Some(json!([{ "version": ">=10.0.0" }]))),
+        ("const ref = new WeakRef(target);", Some(json!([{ "version": ">=14.0.0" }]))),
+        ("const groups = Object.groupBy(items, (item) => item.type);", None),
+        ("const items = await Array.fromAsync(stream);", Some(json!([{ "version": ">=20.0.0" }]))),
+        ("const n = BigInt(1);", Some(json!([{ "version": ">=10.0.0" }]))),
+        ("Object.fromEntries(Object.entries(a));", Some(json!([{ "version": ">=6.0.0" }]))),
+    ];
+
+    Tester::new(
+        NoUnsupportedFeaturesEsBuiltins::NAME,
+        NoUnsupportedFeaturesEsBuiltins::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
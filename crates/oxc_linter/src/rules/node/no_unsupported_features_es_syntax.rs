@@ -0,0 +1,450 @@
+use oxc_ast::{
+    AstKind,
+    ast::{ModuleExportName, RegExpFlags, VariableDeclarationKind},
+};
+use oxc_compat::{ESFeature, Engine, features};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator};
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{UnsupportedFeaturesConfig, unsupported_feature_diagnostic},
+};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnsupportedFeaturesEsSyntax(Box<UnsupportedFeaturesConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows ECMAScript syntax which is not supported by the configured Node.js versions.
+    ///
+    /// The version range is read from the `version` option, or from the `engines.node` field of
+    /// the closest `package.json`, and defaults to `>=16.0.0`. Whether a feature is supported is
+    /// decided by the same compatibility data the transformer uses for its `target` option.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Code using syntax the runtime does not understand fails to parse, so the whole module
+    /// fails to load on the oldest Node.js versions the package claims to support.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "version": ">=12.0.0" }`:
+    /// ```js
+    /// const name = user?.name ?? "anonymous";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "version": ">=12.0.0" }`:
+    /// ```js
+    /// const name = user && user.name != null ? user.name : "anonymous";
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// This rule accepts a configuration object with the following properties:
+    ///
+    /// #### version
+    ///
+    /// `{ type: string }`
+    ///
+    /// The semver range of Node.js versions to check against, e.g. `">=14.17.0"`.
+    ///
+    /// #### ignores
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Syntax to never report, e.g. `["optionalChaining", "nullishCoalescingOperators"]`.
+    NoUnsupportedFeaturesEsSyntax,
+    node,
+    correctness,
+    config = UnsupportedFeaturesConfig,
+);
+
+impl Rule for NoUnsupportedFeaturesEsSyntax {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let range = self.0.node_version_range(ctx);
+        let check = |syntax: Syntax, span: Span| {
+            let Some(&supported) = features()[&syntax.feature].get(&Engine::Node) else {
+                return;
+            };
+            if range.supports(supported) || self.0.ignores.contains(syntax.key) {
+                return;
+            }
+            ctx.diagnostic(unsupported_feature_diagnostic(syntax.name, supported, &range, span));
+        };
+
+        for node in ctx.nodes() {
+            match node.kind() {
+                AstKind::ArrowFunctionExpression(arrow) => {
+                    check(ARROW_FUNCTIONS, arrow.span);
+                    if arrow.r#async {
+                        check(ASYNC_FUNCTIONS, arrow.span);
+                    }
+                }
+                AstKind::Function(func) => match (func.r#async, func.generator) {
+                    (true, true) => check(ASYNC_ITERATION, func.span),
+                    (true, false) => check(ASYNC_FUNCTIONS, func.span),
+                    (false, true) => check(GENERATORS, func.span),
+                    (false, false) => {}
+                },
+                AstKind::Class(class) => check(CLASSES, class.span),
+                AstKind::PropertyDefinition(prop) if !prop.declare => {
+                    check(CLASS_FIELDS, prop.span);
+                }
+                AstKind::MethodDefinition(method) if method.key.is_private_identifier() => {
+                    check(PRIVATE_METHODS, method.span);
+                }
+                AstKind::StaticBlock(block) => check(CLASS_STATIC_BLOCK, block.span),
+                AstKind::PrivateInExpression(expr) => check(PRIVATE_IN, expr.span),
+                AstKind::TemplateLiteral(lit) => check(TEMPLATE_LITERALS, lit.span),
+                AstKind::ForOfStatement(stmt) => {
+                    if stmt.r#await {
+                        check(ASYNC_ITERATION, stmt.span);
+                        if is_top_level(node.id(), ctx) {
+                            check(TOP_LEVEL_AWAIT, stmt.span);
+                        }
+                    } else {
+                        check(FOR_OF, stmt.span);
+                    }
+                }
+                AstKind::AwaitExpression(expr) if is_top_level(node.id(), ctx) => {
+                    check(TOP_LEVEL_AWAIT, expr.span);
+                }
+                AstKind::VariableDeclaration(decl) => match decl.kind {
+                    VariableDeclarationKind::Let | VariableDeclarationKind::Const => {
+                        check(BLOCK_SCOPED_VARIABLES, decl.span);
+                    }
+                    VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing => {
+                        check(EXPLICIT_RESOURCE_MANAGEMENT, decl.span);
+                    }
+                    VariableDeclarationKind::Var => {}
+                },
+                AstKind::FormalParameter(param) if param.initializer.is_some() => {
+                    check(DEFAULT_PARAMETERS, param.span);
+                }
+                AstKind::FormalParameterRest(rest) => check(REST_PARAMETERS, rest.span),
+                AstKind::ObjectPattern(pattern) => {
+                    check(DESTRUCTURING, pattern.span);
+                    if let Some(rest) = &pattern.rest {
+                        check(REST_SPREAD_PROPERTIES, rest.span);
+                    }
+                }
+                AstKind::ObjectAssignmentTarget(target) => {
+                    check(DESTRUCTURING, target.span);
+                    if let Some(rest) = &target.rest {
+                        check(REST_SPREAD_PROPERTIES, rest.span);
+                    }
+                }
+                AstKind::ArrayPattern(pattern) => check(DESTRUCTURING, pattern.span),
+                AstKind::ArrayAssignmentTarget(target) => check(DESTRUCTURING, target.span),
+                AstKind::SpreadElement(spread) => {
+                    if matches!(ctx.nodes().parent_kind(node.id()), AstKind::ObjectExpression(_)) {
+                        check(REST_SPREAD_PROPERTIES, spread.span);
+                    } else {
+                        check(SPREAD_ELEMENTS, spread.span);
+                    }
+                }
+                AstKind::ObjectProperty(prop) => {
+                    if prop.computed {
+                        check(COMPUTED_PROPERTIES, prop.key.span());
+                    }
+                    if prop.shorthand || prop.method {
+                        check(PROPERTY_SHORTHANDS, prop.span);
+                    }
+                }
+                AstKind::MetaProperty(meta)
+                    if meta.meta.name == "new" && meta.property.name == "target" =>
+                {
+                    check(NEW_TARGET, meta.span);
+                }
+                AstKind::NumericLiteral(lit) => {
+                    let raw = lit.span.source_text(ctx.source_text());
+                    if raw.starts_with("0b") || raw.starts_with("0B") {
+                        check(BINARY_NUMERIC_LITERALS, lit.span);
+                    } else if raw.starts_with("0o") || raw.starts_with("0O") {
+                        check(OCTAL_NUMERIC_LITERALS, lit.span);
+                    }
+                    if raw.contains('_') {
+                        check(NUMERIC_SEPARATORS, lit.span);
+                    }
+                }
+                AstKind::BigIntLiteral(lit) => {
+                    check(BIGINT, lit.span);
+                    if lit.span.source_text(ctx.source_text()).contains('_') {
+                        check(NUMERIC_SEPARATORS, lit.span);
+                    }
+                }
+                AstKind::RegExpLiteral(lit) => {
+                    let flags = lit.regex.flags;
+                    for (flag, syntax) in [
+                        (RegExpFlags::Y, REGEXP_Y_FLAG),
+                        (RegExpFlags::U, REGEXP_U_FLAG),
+                        (RegExpFlags::S, REGEXP_S_FLAG),
+                        (RegExpFlags::D, REGEXP_D_FLAG),
+                        (RegExpFlags::V, REGEXP_V_FLAG),
+                    ] {
+                        if flags.contains(flag) {
+                            check(syntax, lit.span);
+                        }
+                    }
+                    let pattern = lit.regex.pattern.text.as_str();
+                    if pattern.contains("(?<=") || pattern.contains("(?<!") {
+                        check(REGEXP_LOOKBEHIND, lit.span);
+                    }
+                    if pattern
+                        .match_indices("(?<")
+                        .any(|(i, _)| !matches!(pattern.as_bytes().get(i + 3), Some(b'=' | b'!')))
+                    {
+                        check(REGEXP_NAMED_CAPTURE_GROUPS, lit.span);
+                    }
+                    if flags.intersects(RegExpFlags::U | RegExpFlags::V)
+                        && (pattern.contains("\\p{") || pattern.contains("\\P{"))
+                    {
+                        check(REGEXP_UNICODE_PROPERTY_ESCAPES, lit.span);
+                    }
+                }
+                AstKind::BinaryExpression(expr) if expr.operator == BinaryOperator::Exponential => {
+                    check(EXPONENTIAL_OPERATORS, expr.span);
+                }
+                AstKind::AssignmentExpression(expr) => {
+                    if expr.operator.is_logical() {
+                        check(LOGICAL_ASSIGNMENT_OPERATORS, expr.span);
+                    } else if expr.operator.to_binary_operator()
+                        == Some(BinaryOperator::Exponential)
+                    {
+                        check(EXPONENTIAL_OPERATORS, expr.span);
+                    }
+                }
+                AstKind::LogicalExpression(expr) if expr.operator == LogicalOperator::Coalesce => {
+                    check(NULLISH_COALESCING_OPERATORS, expr.span);
+                }
+                AstKind::ChainExpression(expr) => check(OPTIONAL_CHAINING, expr.span),
+                AstKind::CatchClause(clause) if clause.param.is_none() => {
+                    check(OPTIONAL_CATCH_BINDING, Span::sized(clause.span.start, 5));
+                }
+                AstKind::ExportAllDeclaration(decl) if decl.exported.is_some() => {
+                    check(EXPORT_NS_FROM, decl.span);
+                }
+                AstKind::ImportSpecifier(specifier) => {
+                    if let ModuleExportName::StringLiteral(name) = &specifier.imported {
+                        check(ARBITRARY_MODULE_NAMESPACE_NAMES, name.span);
+                    }
+                }
+                AstKind::ExportSpecifier(specifier) => {
+                    for name in [&specifier.local, &specifier.exported] {
+                        if let ModuleExportName::StringLiteral(name) = name {
+                            check(ARBITRARY_MODULE_NAMESPACE_NAMES, name.span);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Returns `true` if the node at `node_id` is not inside any function.
+fn is_top_level(node_id: oxc_semantic::NodeId, ctx: &LintContext) -> bool {
+    !ctx.nodes()
+        .ancestor_kinds(node_id)
+        .any(|kind| matches!(kind, AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)))
+}
+
+/// A piece of syntax, the compat data entry which decides whether it is supported, the name it
+/// can be ignored by, and how it is described in diagnostics.
+#[derive(Clone, Copy)]
+struct Syntax {
+    feature: ESFeature,
+    key: &'static str,
+    name: &'static str,
+}
+
+const fn syntax(feature: ESFeature, key: &'static str, name: &'static str) -> Syntax {
+    Syntax { feature, key, name }
+}
+
+// Ignore keys follow the names used by `eslint-plugin-n`.
+const DEFAULT_PARAMETERS: Syntax =
+    syntax(ESFeature::ES2015Parameters, "defaultParameters", "Default parameter");
+const REST_PARAMETERS: Syntax =
+    syntax(ESFeature::ES2015Parameters, "restParameters", "Rest parameter");
+const TEMPLATE_LITERALS: Syntax =
+    syntax(ESFeature::ES2015TemplateLiterals, "templateLiterals", "Template literal");
+const BINARY_NUMERIC_LITERALS: Syntax =
+    syntax(ESFeature::ES2015Literals, "binaryNumericLiterals", "Binary numeric literal");
+const OCTAL_NUMERIC_LITERALS: Syntax =
+    syntax(ESFeature::ES2015Literals, "octalNumericLiterals", "Octal numeric literal");
+const ARROW_FUNCTIONS: Syntax =
+    syntax(ESFeature::ES2015ArrowFunctions, "arrowFunctions", "Arrow function");
+const CLASSES: Syntax = syntax(ESFeature::ES2015Classes, "classes", "Class");
+const PROPERTY_SHORTHANDS: Syntax =
+    syntax(ESFeature::ES2015ShorthandProperties, "propertyShorthands", "Property shorthand");
+const COMPUTED_PROPERTIES: Syntax =
+    syntax(ESFeature::ES2015ComputedProperties, "computedProperties", "Computed property");
+const FOR_OF: Syntax = syntax(ESFeature::ES2015ForOf, "forOf", "`for...of` loop");
+const REGEXP_Y_FLAG: Syntax = syntax(ESFeature::ES2015StickyRegex, "regexpY", "RegExp `y` flag");
+const REGEXP_U_FLAG: Syntax = syntax(ESFeature::ES2015UnicodeRegex, "regexpU", "RegExp `u` flag");
+const SPREAD_ELEMENTS: Syntax = syntax(ESFeature::ES2015Spread, "spreadElements", "Spread element");
+const DESTRUCTURING: Syntax =
+    syntax(ESFeature::ES2015Destructuring, "destructuring", "Destructuring");
+const BLOCK_SCOPED_VARIABLES: Syntax =
+    syntax(ESFeature::ES2015BlockScoping, "blockScopedVariables", "Block-scoped variable");
+const NEW_TARGET: Syntax = syntax(ESFeature::ES2015NewTarget, "newTarget", "`new.target`");
+const GENERATORS: Syntax = syntax(ESFeature::ES2015Regenerator, "generators", "Generator function");
+const EXPONENTIAL_OPERATORS: Syntax = syntax(
+    ESFeature::ES2016ExponentiationOperator,
+    "exponentialOperators",
+    "Exponentiation operator",
+);
+const ASYNC_FUNCTIONS: Syntax =
+    syntax(ESFeature::ES2017AsyncToGenerator, "asyncFunctions", "Async function");
+const ASYNC_ITERATION: Syntax =
+    syntax(ESFeature::ES2018AsyncGeneratorFunctions, "asyncIteration", "Async iteration");
+const REST_SPREAD_PROPERTIES: Syntax = syntax(
+    ESFeature::ES2018ObjectRestSpread,
+    "restSpreadProperties",
+    "Object rest/spread property",
+);
+const REGEXP_S_FLAG: Syntax = syntax(ESFeature::ES2018DotallRegex, "regexpS", "RegExp `s` flag");
+const REGEXP_UNICODE_PROPERTY_ESCAPES: Syntax = syntax(
+    ESFeature::ES2018UnicodePropertyRegex,
+    "regexpUnicodePropertyEscapes",
+    "RegExp Unicode property escape",
+);
+const REGEXP_NAMED_CAPTURE_GROUPS: Syntax = syntax(
+    ESFeature::ES2018NamedCapturingGroupsRegex,
+    "regexpNamedCaptureGroups",
+    "RegExp named capture group",
+);
+const REGEXP_LOOKBEHIND: Syntax =
+    syntax(ESFeature::ES2018LookbehindRegex, "regexpLookbehind", "RegExp lookbehind assertion");
+const OPTIONAL_CATCH_BINDING: Syntax =
+    syntax(ESFeature::ES2019OptionalCatchBinding, "optionalCatchBinding", "Optional catch binding");
+const NULLISH_COALESCING_OPERATORS: Syntax = syntax(
+    ESFeature::ES2020NullishCoalescingOperator,
+    "nullishCoalescingOperators",
+    "Nullish coalescing operator",
+);
+const OPTIONAL_CHAINING: Syntax =
+    syntax(ESFeature::ES2020OptionalChaining, "optionalChaining", "Optional chaining");
+const BIGINT: Syntax = syntax(ESFeature::ES2020BigInt, "bigint", "BigInt literal");
+const NUMERIC_SEPARATORS: Syntax =
+    syntax(ESFeature::ES2021NumericSeparator, "numericSeparators", "Numeric separator");
+const LOGICAL_ASSIGNMENT_OPERATORS: Syntax = syntax(
+    ESFeature::ES2021LogicalAssignmentOperators,
+    "logicalAssignmentOperators",
+    "Logical assignment operator",
+);
+const CLASS_STATIC_BLOCK: Syntax =
+    syntax(ESFeature::ES2022ClassStaticBlock, "classStaticBlock", "Class static block");
+const PRIVATE_IN: Syntax =
+    syntax(ESFeature::ES2022PrivatePropertyInObject, "privateIn", "`#x in obj` check");
+const CLASS_FIELDS: Syntax = syntax(ESFeature::ES2022ClassProperties, "classFields", "Class field");
+const PRIVATE_METHODS: Syntax =
+    syntax(ESFeature::ES2022PrivateMethods, "classFields", "Private method");
+const REGEXP_D_FLAG: Syntax =
+    syntax(ESFeature::ES2022MatchIndicesRegex, "regexpD", "RegExp `d` flag");
+const REGEXP_V_FLAG: Syntax =
+    syntax(ESFeature::ES2024UnicodeSetsRegex, "regexpV", "RegExp `v` flag");
+const EXPLICIT_RESOURCE_MANAGEMENT: Syntax = syntax(
+    ESFeature::ES2026ExplicitResourceManagement,
+    "explicitResourceManagement",
+    "`using` declaration",
+);
+const EXPORT_NS_FROM: Syntax =
+    syntax(ESFeature::ES2020ExportNamespaceFrom, "exportNsFrom", "`export * as ns` declaration");
+const ARBITRARY_MODULE_NAMESPACE_NAMES: Syntax = syntax(
+    ESFeature::ES2020ArbitraryModuleNamespaceNames,
+    "arbitraryModuleNamespaceNames",
+    "String module export name",
+);
+const TOP_LEVEL_AWAIT: Syntax =
+    syntax(ESFeature::ES2022TopLevelAwait, "topLevelAwait", "Top-level await");
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("const { a, ...rest } = obj;", Some(json!([{ "version": ">=8.3.0" }]))),
+        ("const x = a?.b ?? c;", Some(json!([{ "version": ">=16.1.0" }]))),
+        ("const x = a?.b ?? c;", Some(json!([{ "version": "^16.13.0 || >=18" }]))),
+        ("a ||= b;", Some(json!([{ "version": ">=15.0.0" }]))),
+        (
+            "class A { static {} #x = 1; has(o) { return #x in o; } }",
+            Some(json!([{ "version": ">=16.11" }])),
+        ),
+        ("const n = 1_000_000n;", None),
+        ("try {} catch {}", Some(json!([{ "version": ">=10.0.0" }]))),
+        ("const re = /(?<year>\\d{4})/u;", Some(json!([{ "version": ">=10.0.0" }]))),
+        (
+            "async function f() { for await (const x of xs) {} }",
+            Some(json!([{ "version": ">=10" }])),
+        ),
+        ("var x = a ? a.b : c;", Some(json!([{ "version": ">=0.10.0" }]))),
+        ("var f = function () {};", Some(json!([{ "version": ">=0.10.0" }]))),
+        (
+            "const x = a?.b;",
+            Some(json!([{ "version": ">=12.0.0", "ignores": ["optionalChaining"] }])),
+        ),
+        ("export * as ns from 'mod';", Some(json!([{ "version": ">=13.2.0" }]))),
+        ("function f(a = 1, ...rest) {}", Some(json!([{ "version": ">=6.0.0" }]))),
+    ];
+
+    let fail = vec![
+        ("const x = a?.b;", Some(json!([{ "version": ">=14.0.0" }]))),
+        ("const x = a ?? b;", Some(json!([{ "version": ">=12.0.0" }]))),
+        ("a ||= b; c &&= d; e ??= f;", Some(json!([{ "version": ">=14.0.0" }]))),
+        ("const n = 1_000_000;", Some(json!([{ "version": ">=12.0.0" }]))),
+        ("const n = 10n;", Some(json!([{ "version": ">=10.0.0" }]))),
+        ("class A { static { init(); } }", Some(json!([{ "version": ">=16.0.0" }]))),
+        (
+            "class A { #x = 1; has(o) { return #x in o; } }",
+            Some(json!([{ "version": ">=12.0.0" }])),
+        ),
+        ("class A { #m() {} }", Some(json!([{ "version": ">=14.0.0" }]))),
+        ("try {} catch {}", Some(json!([{ "version": ">=8.0.0" }]))),
+        ("const { a, ...rest } = obj;", Some(json!([{ "version": ">=8.0.0" }]))),
+        ("const copy = { ...obj };", Some(json!([{ "version": ">=8.0.0" }]))),
+        ("const x = 2 ** 10; y **= 2;", Some(json!([{ "version": ">=6.0.0" }]))),
+        ("async function f() {}", Some(json!([{ "version": ">=7.0.0" }]))),
+        ("async function* f() {}", Some(json!([{ "version": ">=8.0.0" }]))),
+        ("const re = /(?<=\\$)\\d+/s;", Some(json!([{ "version": ">=8.0.0" }]))),
+        ("const re = /\\p{Letter}/u;", Some(json!([{ "version": ">=8.0.0" }]))),
+        ("const re = /a/d;", Some(json!([{ "version": ">=14.0.0" }]))),
+        ("const re = /[\\p{L}--a]/v;", Some(json!([{ "version": ">=18.0.0" }]))),
+        ("await init();", Some(json!([{ "version": ">=14.0.0" }]))),
+        ("for await (const x of xs) {}", Some(json!([{ "version": ">=12.0.0" }]))),
+        ("export * as ns from 'mod';", Some(json!([{ "version": ">=12.0.0" }]))),
+        ("export { a as 'string name' };", Some(json!([{ "version": ">=14.0.0" }]))),
+        ("using res = getResource();", Some(json!([{ "version": ">=22.0.0" }]))),
+        ("let a = `${b}`;", Some(json!([{ "version": ">=4.0.0" }]))),
+        ("const f = () => new.target;", Some(json!([{ "version": ">=4.0.0" }]))),
+        ("function f(a = 1, ...rest) {}", Some(json!([{ "version": ">=4.0.0" }]))),
+        ("const o = { a, b() {}, [c]: 1 };", Some(json!([{ "version": ">=0.12.0" }]))),
+        ("for (const [a, b] of entries) {}", Some(json!([{ "version": ">=5.0.0" }]))),
+        ("const n = 0b1010 + 0o17;", Some(json!([{ "version": ">=0.12.0" }]))),
+        ("function* gen() {} f(...args);", Some(json!([{ "version": ">=5.0.0" }]))),
+        (
+            "const x = a?.b ?? c;",
+            Some(json!([{ "version": ">=12.0.0", "ignores": ["optionalChaining"] }])),
+        ),
+    ];
+
+    Tester::new(
+        NoUnsupportedFeaturesEsSyntax::NAME,
+        NoUnsupportedFeaturesEsSyntax::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
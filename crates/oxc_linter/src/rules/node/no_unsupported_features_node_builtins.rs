@@ -0,0 +1,369 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, BindingPattern, Expression, ImportDeclarationSpecifier},
+};
+use oxc_compat::Version;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{IsGlobalReference, SymbolId};
+use oxc_span::{GetSpan, Span};
+use serde::Deserialize;
+
+use crate::{
+    ast_util::is_global_require_call,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{NodeVersionRange, UnsupportedFeaturesConfig, unsupported_feature_diagnostic},
+};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnsupportedFeaturesNodeBuiltins(Box<UnsupportedFeaturesConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows Node.js built-in modules, module members and globals which are not available in
+    /// the configured Node.js versions.
+    ///
+    /// The version range is read from the `version` option, or from the `engines.node` field of
+    /// the closest `package.json`, and defaults to `>=16.0.0`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing a module that does not exist fails at startup, and calling a missing function
+    /// throws a `TypeError` at runtime, on the oldest Node.js versions the package claims to
+    /// support.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "version": ">=16.0.0" }`:
+    /// ```js
+    /// import { parseArgs } from "node:util";
+    /// import test from "node:test";
+    /// const response = await fetch(url);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "version": ">=18.3.0" }`:
+    /// ```js
+    /// import { parseArgs } from "node:util";
+    /// import test from "node:test";
+    /// const response = await fetch(url);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// This rule accepts a configuration object with the following properties:
+    ///
+    /// #### version
+    ///
+    /// `{ type: string }`
+    ///
+    /// The semver range of Node.js versions to check against, e.g. `">=14.17.0"`.
+    ///
+    /// #### ignores
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Modules, module members and globals to never report, e.g.
+    /// `["worker_threads", "fs.rm", "fetch"]`.
+    NoUnsupportedFeaturesNodeBuiltins,
+    node,
+    correctness,
+    config = UnsupportedFeaturesConfig,
+);
+
+impl Rule for NoUnsupportedFeaturesNodeBuiltins {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let range = self.0.node_version_range(ctx);
+
+        for node in ctx.nodes() {
+            match node.kind() {
+                AstKind::ImportDeclaration(decl) => {
+                    let module =
+                        self.check_module(&decl.source.value, decl.source.span, &range, ctx);
+                    for specifier in decl.specifiers.iter().flatten() {
+                        match specifier {
+                            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                                self.check_member(
+                                    module,
+                                    &specifier.imported.name(),
+                                    specifier.imported.span(),
+                                    &range,
+                                    ctx,
+                                );
+                            }
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                                self.check_member_accesses(
+                                    module,
+                                    specifier.local.symbol_id(),
+                                    &range,
+                                    ctx,
+                                );
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                                self.check_member_accesses(
+                                    module,
+                                    specifier.local.symbol_id(),
+                                    &range,
+                                    ctx,
+                                );
+                            }
+                        }
+                    }
+                }
+                AstKind::ImportExpression(expr) => {
+                    if let Expression::StringLiteral(source) = &expr.source {
+                        self.check_module(&source.value, source.span, &range, ctx);
+                    }
+                }
+                AstKind::CallExpression(call) if is_global_require_call(call, ctx.semantic()) => {
+                    let Some(Argument::StringLiteral(source)) = call.arguments.first() else {
+                        continue;
+                    };
+                    let module = self.check_module(&source.value, source.span, &range, ctx);
+                    match ctx.nodes().parent_kind(node.id()) {
+                        // `const fs = require("fs")`, `const { rm } = require("fs")`
+                        AstKind::VariableDeclarator(decl) => match &decl.id {
+                            BindingPattern::BindingIdentifier(ident) => {
+                                self.check_member_accesses(module, ident.symbol_id(), &range, ctx);
+                            }
+                            BindingPattern::ObjectPattern(pattern) => {
+                                for property in &pattern.properties {
+                                    if let Some(name) = property.key.static_name() {
+                                        self.check_member(
+                                            module,
+                                            &name,
+                                            property.key.span(),
+                                            &range,
+                                            ctx,
+                                        );
+                                    }
+                                }
+                            }
+                            _ => {}
+                        },
+                        // `require("fs").rm`
+                        AstKind::StaticMemberExpression(member) => {
+                            self.check_member(
+                                module,
+                                &member.property.name,
+                                member.span,
+                                &range,
+                                ctx,
+                            );
+                        }
+                        _ => {}
+                    }
+                }
+                AstKind::IdentifierReference(ident) => {
+                    if let Some(&(name, supported)) =
+                        GLOBALS.iter().find(|(name, _)| ident.name == *name)
+                        && ident.is_global_reference(ctx.scoping())
+                    {
+                        self.check(name, supported, ident.span, &range, ctx);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl NoUnsupportedFeaturesNodeBuiltins {
+    fn check(
+        &self,
+        name: &str,
+        supported: Version,
+        span: Span,
+        range: &NodeVersionRange,
+        ctx: &LintContext,
+    ) {
+        if !range.supports(supported) && !self.0.ignores.contains(name) {
+            ctx.diagnostic(unsupported_feature_diagnostic(
+                &format!("`{name}`"),
+                supported,
+                range,
+                span,
+            ));
+        }
+    }
+
+    /// Checks an imported or required module, returning its name without the `node:` scheme.
+    fn check_module<'s>(
+        &self,
+        specifier: &'s str,
+        span: Span,
+        range: &NodeVersionRange,
+        ctx: &LintContext,
+    ) -> &'s str {
+        let module = specifier.strip_prefix("node:").unwrap_or(specifier);
+        if let Some(&(name, supported)) = MODULES.iter().find(|(name, _)| *name == module) {
+            self.check(name, supported, span, range, ctx);
+        }
+        module
+    }
+
+    fn check_member(
+        &self,
+        module: &str,
+        member: &str,
+        span: Span,
+        range: &NodeVersionRange,
+        ctx: &LintContext,
+    ) {
+        if let Some(&(_, _, supported)) =
+            MODULE_MEMBERS.iter().find(|(name, property, _)| *name == module && *property == member)
+        {
+            self.check(&format!("{module}.{member}"), supported, span, range, ctx);
+        }
+    }
+
+    /// Checks `module.member` accesses on a binding holding a whole module, e.g. `fs.rm()` after
+    /// `import fs from "fs"` or `const fs = require("fs")`.
+    fn check_member_accesses(
+        &self,
+        module: &str,
+        symbol_id: SymbolId,
+        range: &NodeVersionRange,
+        ctx: &LintContext,
+    ) {
+        for reference in ctx.scoping().get_resolved_references(symbol_id) {
+            if let AstKind::StaticMemberExpression(member) =
+                ctx.nodes().parent_kind(reference.node_id())
+            {
+                self.check_member(module, &member.property.name, member.span, range, ctx);
+            }
+        }
+    }
+}
+
+/// Built-in modules and the first Node.js version providing them.
+const MODULES: &[(&str, Version)] = &[
+    ("inspector", Version(8, 0, 0)),
+    ("async_hooks", Version(8, 1, 0)),
+    ("http2", Version(8, 4, 0)),
+    ("perf_hooks", Version(8, 5, 0)),
+    ("trace_events", Version(10, 0, 0)),
+    ("worker_threads", Version(11, 7, 0)),
+    ("fs/promises", Version(14, 0, 0)),
+    ("assert/strict", Version(15, 0, 0)),
+    ("dns/promises", Version(15, 0, 0)),
+    ("stream/promises", Version(15, 0, 0)),
+    ("timers/promises", Version(15, 0, 0)),
+    ("diagnostics_channel", Version(15, 1, 0)),
+    ("path/posix", Version(15, 3, 0)),
+    ("path/win32", Version(15, 3, 0)),
+    ("util/types", Version(15, 3, 0)),
+    ("stream/web", Version(16, 5, 0)),
+    ("stream/consumers", Version(16, 7, 0)),
+    ("readline/promises", Version(17, 0, 0)),
+    ("test", Version(18, 0, 0)),
+    ("sqlite", Version(22, 5, 0)),
+];
+
+/// Members of built-in modules and the first Node.js version providing them.
+const MODULE_MEMBERS: &[(&str, &str, Version)] = &[
+    ("assert", "rejects", Version(10, 0, 0)),
+    ("url", "fileURLToPath", Version(10, 12, 0)),
+    ("url", "pathToFileURL", Version(10, 12, 0)),
+    ("events", "once", Version(11, 13, 0)),
+    ("module", "createRequire", Version(12, 2, 0)),
+    ("assert", "match", Version(13, 6, 0)),
+    ("events", "on", Version(13, 6, 0)),
+    ("fs", "rm", Version(14, 14, 0)),
+    ("fs", "rmSync", Version(14, 14, 0)),
+    ("crypto", "randomUUID", Version(14, 17, 0)),
+    ("crypto", "webcrypto", Version(15, 0, 0)),
+    ("events", "setMaxListeners", Version(15, 4, 0)),
+    ("buffer", "Blob", Version(15, 7, 0)),
+    ("buffer", "atob", Version(15, 13, 0)),
+    ("buffer", "btoa", Version(15, 13, 0)),
+    ("fs", "cp", Version(16, 7, 0)),
+    ("fs", "cpSync", Version(16, 7, 0)),
+    ("util", "stripVTControlCharacters", Version(16, 11, 0)),
+    ("crypto", "getRandomValues", Version(17, 4, 0)),
+    ("util", "parseArgs", Version(18, 3, 0)),
+    ("buffer", "File", Version(19, 2, 0)),
+    ("os", "availableParallelism", Version(19, 4, 0)),
+    ("fs", "openAsBlob", Version(19, 8, 0)),
+    ("module", "register", Version(20, 6, 0)),
+    ("util", "styleText", Version(20, 12, 0)),
+];
+
+/// Globals provided by Node.js and the first version providing them.
+const GLOBALS: &[(&str, Version)] = &[
+    ("URL", Version(10, 0, 0)),
+    ("URLSearchParams", Version(10, 0, 0)),
+    ("queueMicrotask", Version(11, 0, 0)),
+    ("TextDecoder", Version(11, 0, 0)),
+    ("TextEncoder", Version(11, 0, 0)),
+    ("AbortController", Version(15, 0, 0)),
+    ("AbortSignal", Version(15, 0, 0)),
+    ("Event", Version(15, 0, 0)),
+    ("EventTarget", Version(15, 0, 0)),
+    ("performance", Version(16, 0, 0)),
+    ("structuredClone", Version(17, 0, 0)),
+    ("Blob", Version(18, 0, 0)),
+    ("BroadcastChannel", Version(18, 0, 0)),
+    ("fetch", Version(18, 0, 0)),
+    ("FormData", Version(18, 0, 0)),
+    ("Headers", Version(18, 0, 0)),
+    ("ReadableStream", Version(18, 0, 0)),
+    ("Request", Version(18, 0, 0)),
+    ("Response", Version(18, 0, 0)),
+    ("crypto", Version(19, 0, 0)),
+    ("navigator", Version(21, 0, 0)),
+    ("WebSocket", Version(22, 4, 0)),
+];
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import fs from 'fs'; fs.rm(path);", Some(json!([{ "version": ">=14.14.0" }]))),
+        ("import { rm } from 'node:fs';", Some(json!([{ "version": ">=16.0.0" }]))),
+        ("const { Worker } = require('worker_threads');", None),
+        ("import test from 'node:test';", Some(json!([{ "version": ">=18.0.0" }]))),
+        ("const response = await fetch(url);", Some(json!([{ "version": ">=18.0.0" }]))),
+        ("import fetch from 'node-fetch'; fetch(url);", Some(json!([{ "version": ">=14.0.0" }]))),
+        ("const { rm } = require('fs-extra');", Some(json!([{ "version": ">=12.0.0" }]))),
+        ("const require = load; require('node:test');", Some(json!([{ "version": ">=16.0.0" }]))),
+        (
+            "import { cp } from 'fs';",
+            Some(json!([{ "version": ">=16.0.0", "ignores": ["fs.cp"] }])),
+        ),
+        ("fetch(url);", Some(json!([{ "version": ">=16.0.0", "ignores": ["fetch"] }]))),
+    ];
+
+    let fail = vec![
+        ("import { parseArgs } from 'node:util';", None),
+        ("import test from 'node:test';", None),
+        ("import { cp } from 'fs';", None),
+        ("import * as fs from 'fs'; fs.cp(from, to);", None),
+        ("const fs = require('fs'); fs.rm(path);", Some(json!([{ "version": ">=14.0.0" }]))),
+        (
+            "const { randomUUID } = require('node:crypto');",
+            Some(json!([{ "version": ">=14.0.0" }])),
+        ),
+        ("require('util').styleText('red', text);", Some(json!([{ "version": ">=20.0.0" }]))),
+        ("import { readFile } from 'fs/promises';", Some(json!([{ "version": ">=12.0.0" }]))),
+        ("const { Worker } = require('worker_threads');", Some(json!([{ "version": ">=10.0.0" }]))),
+        ("const sqlite = await import('node:sqlite');", Some(json!([{ "version": ">=22.0.0" }]))),
+        ("const response = await fetch(url);", None),
+        ("const copy = structuredClone(value);", Some(json!([{ "version": "^16.13.0 || >=18" }]))),
+        ("const controller = new AbortController();", Some(json!([{ "version": ">=14.17.0" }]))),
+    ];
+
+    Tester::new(
+        NoUnsupportedFeaturesNodeBuiltins::NAME,
+        NoUnsupportedFeaturesNodeBuiltins::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
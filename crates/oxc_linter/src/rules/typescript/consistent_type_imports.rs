@@ -347,6 +347,10 @@ fn format_word_list<'a>(words: &[Cow<'a, str>]) -> Cow<'a, str> {
 // Returns `true` if the symbol is only used as a type reference, and `false` otherwise.
 // Specifically, return `false` if the symbol does not have any references.
 fn is_only_has_type_references(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    // Vue and Svelte templates use bindings as values.
+    if ctx.is_used_in_template(symbol_id) {
+        return false;
+    }
     let mut peekable_iter = ctx.semantic().symbol_references(symbol_id).peekable();
 
     if peekable_iter.peek().is_none() {
@@ -3420,3 +3424,67 @@ export class Foo extends Bar {}
         .expect_fix(fix)
         .test_and_snapshot();
 }
+
+#[test]
+fn test_templates() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let vue = || Some(PathBuf::from("src/foo/bar.vue"));
+    let svelte = || Some(PathBuf::from("src/foo/bar.svelte"));
+
+    let pass = vec![
+        (
+            r#"<script setup lang="ts">
+            import { Status, type Props } from './status';
+            const props: Props = {};
+            const status: Status = props.status;
+            </script>
+            <template>{{ status === Status.Active }}</template>"#,
+            None,
+            None,
+            vue(),
+        ),
+        (
+            r#"<script lang="ts">
+            import { Status } from './status';
+            let status: Status;
+            </script>
+            <p>{status === Status.Active}</p>"#,
+            None,
+            None,
+            svelte(),
+        ),
+    ];
+
+    let fail = vec![(
+        r#"<script setup lang="ts">
+        import { Status } from './status';
+        const status: Status = 'active';
+        </script>
+        <template>{{ status }}</template>"#,
+        None,
+        None,
+        vue(),
+    )];
+
+    let fix = vec![(
+        r#"<script setup lang="ts">
+        import { Status } from './status';
+        const status: Status = 'active';
+        </script>
+        <template>{{ status }}</template>"#,
+        r#"<script setup lang="ts">
+        import type { Status } from './status';
+        const status: Status = 'active';
+        </script>
+        <template>{{ status }}</template>"#,
+        None,
+        vue(),
+    )];
+
+    Tester::new(ConsistentTypeImports::NAME, ConsistentTypeImports::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test();
+}
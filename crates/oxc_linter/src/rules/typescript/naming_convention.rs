@@ -0,0 +1,1669 @@
+use std::{borrow::Cow, cmp::Ordering};
+
+use bitflags::bitflags;
+use lazy_regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, de::Error};
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        BindingIdentifier, Expression, Function, PropertyKey, PropertyKind, TSAccessibility,
+        TSEnumMemberName, TSLiteral, TSType, TSTypeAnnotation, TSTypeName, TSTypeOperatorOperator,
+        UnaryOperator, VariableDeclarationKind,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::identifier::is_identifier_name;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn naming_convention_diagnostic(message: String, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(message).with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NamingConvention(Box<NamingConventionConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NamingConventionConfig {
+    /// Normalized options, sorted so that the most specific ones come first.
+    options: Vec<NormalizedOption>,
+}
+
+impl std::ops::Deref for NamingConvention {
+    type Target = NamingConventionConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for NamingConvention {
+    fn default() -> Self {
+        let options = normalize_options(default_options()).unwrap_or_default();
+        Self(Box::new(NamingConventionConfig { options }))
+    }
+}
+
+/// The options of this rule: a list of selectors and the naming conventions they enforce.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(transparent)]
+pub struct NamingConventionOptions(Vec<NamingConventionOption>);
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct NamingConventionOption {
+    /// The kind(s) of identifiers this option applies to.
+    selector: OneOrMany<SelectorName>,
+    /// The formats the name must match one of, after trimming underscores and affixes.
+    /// `null` skips the format check.
+    format: Option<Vec<PredefinedFormat>>,
+    /// An additional regex the name must (or must not) match.
+    custom: Option<MatchRegex>,
+    /// How leading underscores are treated.
+    leading_underscore: Option<UnderscoreOption>,
+    /// How trailing underscores are treated.
+    trailing_underscore: Option<UnderscoreOption>,
+    /// The name must start with one of these prefixes, which is trimmed before the format check.
+    prefix: Option<Vec<String>>,
+    /// The name must end with one of these suffixes, which is trimmed before the format check.
+    suffix: Option<Vec<String>>,
+    /// Only names (not) matching this regex are checked by this option.
+    filter: Option<Filter>,
+    /// Only identifiers with all of these modifiers are checked by this option.
+    modifiers: Option<Vec<ModifierName>>,
+    /// Only identifiers of one of these types are checked by this option.
+    types: Option<Vec<TypeName>>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+struct MatchRegex {
+    regex: String,
+    #[serde(rename = "match")]
+    is_match: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum Filter {
+    Regex(String),
+    Match(MatchRegex),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum SelectorName {
+    Default,
+    VariableLike,
+    MemberLike,
+    TypeLike,
+    Method,
+    Property,
+    Accessor,
+    Variable,
+    Function,
+    Parameter,
+    ParameterProperty,
+    ClassicAccessor,
+    EnumMember,
+    ClassMethod,
+    ObjectLiteralMethod,
+    TypeMethod,
+    ClassProperty,
+    ObjectLiteralProperty,
+    TypeProperty,
+    AutoAccessor,
+    Class,
+    Interface,
+    TypeAlias,
+    Enum,
+    TypeParameter,
+    Import,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[expect(clippy::enum_variant_names)]
+enum PredefinedFormat {
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    #[serde(rename = "strictCamelCase")]
+    StrictCamelCase,
+    PascalCase,
+    StrictPascalCase,
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+    #[serde(rename = "UPPER_CASE")]
+    UpperCase,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum UnderscoreOption {
+    Forbid,
+    Require,
+    RequireDouble,
+    Allow,
+    AllowDouble,
+    AllowSingleOrDouble,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum ModifierName {
+    Const,
+    Readonly,
+    Static,
+    Public,
+    Protected,
+    Private,
+    #[serde(rename = "#private")]
+    HashPrivate,
+    Abstract,
+    Destructured,
+    Global,
+    Exported,
+    Unused,
+    RequiresQuotes,
+    Override,
+    Async,
+    Default,
+    Namespace,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum TypeName {
+    Boolean,
+    String,
+    Number,
+    Function,
+    Array,
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Selectors: u32 {
+        const Variable = 1 << 0;
+        const Function = 1 << 1;
+        const Parameter = 1 << 2;
+        const ParameterProperty = 1 << 3;
+        const ClassicAccessor = 1 << 4;
+        const EnumMember = 1 << 5;
+        const ClassMethod = 1 << 6;
+        const ObjectLiteralMethod = 1 << 7;
+        const TypeMethod = 1 << 8;
+        const ClassProperty = 1 << 9;
+        const ObjectLiteralProperty = 1 << 10;
+        const TypeProperty = 1 << 11;
+        const AutoAccessor = 1 << 12;
+        const Class = 1 << 13;
+        const Interface = 1 << 14;
+        const TypeAlias = 1 << 15;
+        const Enum = 1 << 16;
+        const TypeParameter = 1 << 17;
+        const Import = 1 << 18;
+
+        const VariableLike = Self::Variable.bits() | Self::Function.bits() | Self::Parameter.bits();
+        const MemberLike = Self::ParameterProperty.bits()
+            | Self::ClassicAccessor.bits()
+            | Self::EnumMember.bits()
+            | Self::Method.bits()
+            | Self::Property.bits()
+            | Self::AutoAccessor.bits();
+        const TypeLike = Self::Class.bits()
+            | Self::Interface.bits()
+            | Self::TypeAlias.bits()
+            | Self::Enum.bits()
+            | Self::TypeParameter.bits();
+        const Method = Self::ClassMethod.bits() | Self::ObjectLiteralMethod.bits() | Self::TypeMethod.bits();
+        const Property = Self::ClassProperty.bits()
+            | Self::ObjectLiteralProperty.bits()
+            | Self::TypeProperty.bits();
+        const Accessor = Self::ClassicAccessor.bits() | Self::AutoAccessor.bits();
+        const Default = (1 << 19) - 1;
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    struct Modifiers: u32 {
+        const Const = 1 << 0;
+        const Readonly = 1 << 1;
+        const Static = 1 << 2;
+        const Public = 1 << 3;
+        const Protected = 1 << 4;
+        const Private = 1 << 5;
+        const HashPrivate = 1 << 6;
+        const Abstract = 1 << 7;
+        const Destructured = 1 << 8;
+        const Global = 1 << 9;
+        const Exported = 1 << 10;
+        const Unused = 1 << 11;
+        const RequiresQuotes = 1 << 12;
+        const Override = 1 << 13;
+        const Async = 1 << 14;
+        const Default = 1 << 15;
+        const Namespace = 1 << 16;
+    }
+}
+
+bitflags! {
+    /// Continues the bits of [`Modifiers`], so that both can be combined into an option's weight.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    struct Types: u32 {
+        const Boolean = 1 << 17;
+        const String = 1 << 18;
+        const Number = 1 << 19;
+        const Function = 1 << 20;
+        const Array = 1 << 21;
+    }
+}
+
+impl From<SelectorName> for Selectors {
+    fn from(name: SelectorName) -> Self {
+        match name {
+            SelectorName::Default => Self::Default,
+            SelectorName::VariableLike => Self::VariableLike,
+            SelectorName::MemberLike => Self::MemberLike,
+            SelectorName::TypeLike => Self::TypeLike,
+            SelectorName::Method => Self::Method,
+            SelectorName::Property => Self::Property,
+            SelectorName::Accessor => Self::Accessor,
+            SelectorName::Variable => Self::Variable,
+            SelectorName::Function => Self::Function,
+            SelectorName::Parameter => Self::Parameter,
+            SelectorName::ParameterProperty => Self::ParameterProperty,
+            SelectorName::ClassicAccessor => Self::ClassicAccessor,
+            SelectorName::EnumMember => Self::EnumMember,
+            SelectorName::ClassMethod => Self::ClassMethod,
+            SelectorName::ObjectLiteralMethod => Self::ObjectLiteralMethod,
+            SelectorName::TypeMethod => Self::TypeMethod,
+            SelectorName::ClassProperty => Self::ClassProperty,
+            SelectorName::ObjectLiteralProperty => Self::ObjectLiteralProperty,
+            SelectorName::TypeProperty => Self::TypeProperty,
+            SelectorName::AutoAccessor => Self::AutoAccessor,
+            SelectorName::Class => Self::Class,
+            SelectorName::Interface => Self::Interface,
+            SelectorName::TypeAlias => Self::TypeAlias,
+            SelectorName::Enum => Self::Enum,
+            SelectorName::TypeParameter => Self::TypeParameter,
+            SelectorName::Import => Self::Import,
+        }
+    }
+}
+
+impl Selectors {
+    /// Name of an individual selector as used in diagnostics, e.g. `Class Property`.
+    fn display_name(self) -> &'static str {
+        match self {
+            Self::Variable => "Variable",
+            Self::Function => "Function",
+            Self::Parameter => "Parameter",
+            Self::ParameterProperty => "Parameter Property",
+            Self::ClassicAccessor => "Classic Accessor",
+            Self::EnumMember => "Enum Member",
+            Self::ClassMethod => "Class Method",
+            Self::ObjectLiteralMethod => "Object Literal Method",
+            Self::TypeMethod => "Type Method",
+            Self::ClassProperty => "Class Property",
+            Self::ObjectLiteralProperty => "Object Literal Property",
+            Self::TypeProperty => "Type Property",
+            Self::AutoAccessor => "Auto Accessor",
+            Self::Class => "Class",
+            Self::Interface => "Interface",
+            Self::TypeAlias => "Type Alias",
+            Self::Enum => "Enum",
+            Self::TypeParameter => "Type Parameter",
+            _ => "Import",
+        }
+    }
+
+    /// Individual selectors are more specific than `method` and `property`, which in turn are
+    /// more specific than the other groups. `default` comes last.
+    fn specificity(self) -> u8 {
+        match self {
+            Self::Default => 0,
+            Self::Method | Self::Property => 2,
+            _ if self.bits().is_power_of_two() => 3,
+            _ => 1,
+        }
+    }
+}
+
+impl From<ModifierName> for Modifiers {
+    fn from(name: ModifierName) -> Self {
+        match name {
+            ModifierName::Const => Self::Const,
+            ModifierName::Readonly => Self::Readonly,
+            ModifierName::Static => Self::Static,
+            ModifierName::Public => Self::Public,
+            ModifierName::Protected => Self::Protected,
+            ModifierName::Private => Self::Private,
+            ModifierName::HashPrivate => Self::HashPrivate,
+            ModifierName::Abstract => Self::Abstract,
+            ModifierName::Destructured => Self::Destructured,
+            ModifierName::Global => Self::Global,
+            ModifierName::Exported => Self::Exported,
+            ModifierName::Unused => Self::Unused,
+            ModifierName::RequiresQuotes => Self::RequiresQuotes,
+            ModifierName::Override => Self::Override,
+            ModifierName::Async => Self::Async,
+            ModifierName::Default => Self::Default,
+            ModifierName::Namespace => Self::Namespace,
+        }
+    }
+}
+
+impl From<TypeName> for Types {
+    fn from(name: TypeName) -> Self {
+        match name {
+            TypeName::Boolean => Self::Boolean,
+            TypeName::String => Self::String,
+            TypeName::Number => Self::Number,
+            TypeName::Function => Self::Function,
+            TypeName::Array => Self::Array,
+        }
+    }
+}
+
+impl PredefinedFormat {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::CamelCase => "camelCase",
+            Self::StrictCamelCase => "strictCamelCase",
+            Self::PascalCase => "PascalCase",
+            Self::StrictPascalCase => "StrictPascalCase",
+            Self::SnakeCase => "snake_case",
+            Self::UpperCase => "UPPER_CASE",
+        }
+    }
+
+    fn check(self, name: &str) -> bool {
+        let Some(first) = name.chars().next() else { return true };
+        match self {
+            Self::CamelCase => !first.is_uppercase() && !name.contains('_'),
+            Self::StrictCamelCase => !first.is_uppercase() && has_strict_camel_humps(name, false),
+            Self::PascalCase => !first.is_lowercase() && !name.contains('_'),
+            Self::StrictPascalCase => !first.is_lowercase() && has_strict_camel_humps(name, true),
+            Self::SnakeCase => !name.chars().any(char::is_uppercase) && validate_underscores(name),
+            Self::UpperCase => !name.chars().any(char::is_lowercase) && validate_underscores(name),
+        }
+    }
+}
+
+/// Every hump of a strict camel case name is a single uppercase letter followed by lowercase
+/// letters, so `myID` is not strict but `myId` is.
+fn has_strict_camel_humps(name: &str, mut is_upper: bool) -> bool {
+    if name.starts_with('_') {
+        return false;
+    }
+    for c in name.chars().skip(1) {
+        if c == '_' {
+            return false;
+        }
+        if is_upper == c.is_uppercase() {
+            if is_upper {
+                return false;
+            }
+        } else {
+            is_upper = !is_upper;
+        }
+    }
+    true
+}
+
+/// Words are separated by single underscores, without leading or trailing ones.
+fn validate_underscores(name: &str) -> bool {
+    !name.starts_with('_') && !name.ends_with('_') && !name.contains("__")
+}
+
+#[derive(Debug, Clone)]
+struct NormalizedOption {
+    selector: Selectors,
+    formats: Vec<PredefinedFormat>,
+    custom: Option<(Regex, bool)>,
+    leading_underscore: Option<UnderscoreOption>,
+    trailing_underscore: Option<UnderscoreOption>,
+    prefix: Vec<String>,
+    suffix: Vec<String>,
+    filter: Option<(Regex, bool)>,
+    modifiers: Modifiers,
+    types: Option<Types>,
+}
+
+impl NormalizedOption {
+    fn weight(&self) -> u32 {
+        self.modifiers.bits() | self.types.unwrap_or_default().bits()
+    }
+}
+
+fn build_regex(regex: &str) -> Result<Regex, serde_json::Error> {
+    Regex::new(regex)
+        .map_err(|err| serde_json::Error::custom(format!("invalid regex `{regex}`: {err}")))
+}
+
+impl TryFrom<NamingConventionOption> for Vec<NormalizedOption> {
+    type Error = serde_json::Error;
+
+    fn try_from(option: NamingConventionOption) -> Result<Self, Self::Error> {
+        let custom = option
+            .custom
+            .map(|custom| {
+                Ok::<_, serde_json::Error>((build_regex(&custom.regex)?, custom.is_match))
+            })
+            .transpose()?;
+        let filter = option
+            .filter
+            .map(|filter| match filter {
+                Filter::Regex(regex) => Ok((build_regex(&regex)?, true)),
+                Filter::Match(filter) => Ok((build_regex(&filter.regex)?, filter.is_match)),
+            })
+            .transpose()?;
+        let modifiers = option
+            .modifiers
+            .unwrap_or_default()
+            .into_iter()
+            .fold(Modifiers::empty(), |acc, name| acc | Modifiers::from(name));
+        let types = option.types.map(|types| {
+            types.into_iter().fold(Types::empty(), |acc, name| acc | Types::from(name))
+        });
+        let selectors = match option.selector {
+            OneOrMany::One(selector) => vec![selector],
+            OneOrMany::Many(selectors) => selectors,
+        };
+        Ok(selectors
+            .into_iter()
+            .map(|selector| NormalizedOption {
+                selector: Selectors::from(selector),
+                formats: option.format.clone().unwrap_or_default(),
+                custom: custom.clone(),
+                leading_underscore: option.leading_underscore,
+                trailing_underscore: option.trailing_underscore,
+                prefix: option.prefix.clone().unwrap_or_default(),
+                suffix: option.suffix.clone().unwrap_or_default(),
+                filter: filter.clone(),
+                modifiers,
+                types,
+            })
+            .collect())
+    }
+}
+
+/// Expands the options into one option per selector, sorted by precedence.
+fn normalize_options(
+    options: Vec<NamingConventionOption>,
+) -> Result<Vec<NormalizedOption>, serde_json::Error> {
+    let mut normalized = vec![];
+    for option in options {
+        normalized.extend(Vec::<NormalizedOption>::try_from(option)?);
+    }
+    Ok(sort_options(normalized))
+}
+
+fn default_options() -> Vec<NamingConventionOption> {
+    serde_json::from_value(serde_json::json!([
+        {
+            "selector": "default",
+            "format": ["camelCase"],
+            "leadingUnderscore": "allow",
+            "trailingUnderscore": "allow",
+        },
+        { "selector": "import", "format": ["camelCase", "PascalCase"] },
+        {
+            "selector": "variable",
+            "format": ["camelCase", "UPPER_CASE"],
+            "leadingUnderscore": "allow",
+            "trailingUnderscore": "allow",
+        },
+        { "selector": "typeLike", "format": ["PascalCase"] },
+    ]))
+    .unwrap_or_default()
+}
+
+/// Orders options the same way as typescript-eslint: by selector specificity, then by the
+/// number of modifiers and types for options with the same selector.
+fn sort_options(mut options: Vec<NormalizedOption>) -> Vec<NormalizedOption> {
+    options.sort_by(|a, b| {
+        if a.selector == b.selector {
+            return b.weight().cmp(&a.weight());
+        }
+        match b.selector.specificity().cmp(&a.selector.specificity()) {
+            Ordering::Equal => b.selector.bits().cmp(&a.selector.bits()),
+            ordering => ordering,
+        }
+    });
+    options
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces naming conventions for everything across a codebase.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Naming conventions help a team read and navigate code: they tell at a glance whether
+    /// a name refers to a class, a constant or a private member. Conventions that are not
+    /// enforced drift quickly, especially in larger codebases.
+    ///
+    /// ### Options
+    ///
+    /// The options are a list of objects, each selecting identifiers with `selector` (and
+    /// optionally `modifiers`, `types` and `filter`) and describing the format they must
+    /// follow with `format`, `custom`, `leadingUnderscore`, `trailingUnderscore`, `prefix`
+    /// and `suffix`. Each name is checked against the most specific matching option only:
+    /// individual selectors take precedence over the `method` and `property` groups, which
+    /// take precedence over the other groups and finally `default`. Options with the same
+    /// selector are ordered by the number of `modifiers` and `types` they require.
+    ///
+    /// See the [typescript-eslint documentation](https://typescript-eslint.io/rules/naming-convention/)
+    /// for a full description of the options.
+    ///
+    /// The `types` filter is resolved from type annotations and initializers without the
+    /// type checker, e.g. `const enabled = true` is a `boolean` and
+    /// `let items: Array<string>` an `array`. Identifiers whose type cannot be determined
+    /// this way are never matched by options that specify `types`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the default options:
+    /// ```ts
+    /// const my_variable = 1;
+    /// function do_something() {}
+    /// class myClass {}
+    /// interface myInterface {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default options:
+    /// ```ts
+    /// const myVariable = 1;
+    /// const MAX_SIZE = 10;
+    /// function doSomething() {}
+    /// class MyClass {}
+    /// interface MyInterface {}
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with
+    /// `[{ "selector": "variable", "types": ["boolean"], "format": ["PascalCase"], "prefix": ["is", "should", "has"] }]`:
+    /// ```ts
+    /// const enabled = true;
+    /// let visible: boolean = false;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the same options:
+    /// ```ts
+    /// const isEnabled = true;
+    /// let shouldShow: boolean = false;
+    /// ```
+    NamingConvention,
+    typescript,
+    style,
+    config = NamingConventionOptions,
+);
+
+impl Rule for NamingConvention {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        if value.as_array().is_none_or(Vec::is_empty) {
+            return Ok(Self::default());
+        }
+        let NamingConventionOptions(options) = serde_json::from_value(value)?;
+        Ok(Self(Box::new(NamingConventionConfig { options: normalize_options(options)? })))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::BindingIdentifier(ident) => self.check_binding(ident, node, ctx),
+            AstKind::PropertyDefinition(prop) if !prop.computed => {
+                let is_method = prop.value.as_ref().is_some_and(Expression::is_function);
+                let mut modifiers = member_modifiers(
+                    prop.key.is_private_identifier(),
+                    prop.accessibility,
+                    prop.r#static,
+                    prop.readonly,
+                    prop.r#override,
+                    prop.r#type.is_abstract(),
+                );
+                let selector = if is_method {
+                    if prop.value.as_ref().is_some_and(is_async_function) {
+                        modifiers |= Modifiers::Async;
+                    }
+                    Selectors::ClassMethod
+                } else {
+                    Selectors::ClassProperty
+                };
+                let type_of = || {
+                    prop.type_annotation.as_deref().map_or_else(
+                        || prop.value.as_ref().and_then(type_of_expression),
+                        type_of_annotation,
+                    )
+                };
+                self.check_key(&prop.key, selector, modifiers, type_of, ctx);
+            }
+            AstKind::MethodDefinition(method) if !method.computed => {
+                let mut modifiers = member_modifiers(
+                    method.key.is_private_identifier(),
+                    method.accessibility,
+                    method.r#static,
+                    false,
+                    method.r#override,
+                    method.r#type.is_abstract(),
+                );
+                let selector = if method.kind.is_accessor() {
+                    Selectors::ClassicAccessor
+                } else if method.kind.is_method() {
+                    if method.value.r#async {
+                        modifiers |= Modifiers::Async;
+                    }
+                    Selectors::ClassMethod
+                } else {
+                    return;
+                };
+                let type_of = || accessor_type_of_function(method.kind.is_get(), &method.value);
+                self.check_key(&method.key, selector, modifiers, type_of, ctx);
+            }
+            AstKind::AccessorProperty(prop) if !prop.computed => {
+                let modifiers = member_modifiers(
+                    prop.key.is_private_identifier(),
+                    prop.accessibility,
+                    prop.r#static,
+                    false,
+                    prop.r#override,
+                    prop.r#type.is_abstract(),
+                );
+                let type_of = || {
+                    prop.type_annotation.as_deref().map_or_else(
+                        || prop.value.as_ref().and_then(type_of_expression),
+                        type_of_annotation,
+                    )
+                };
+                self.check_key(&prop.key, Selectors::AutoAccessor, modifiers, type_of, ctx);
+            }
+            AstKind::ObjectProperty(prop) if !prop.computed => {
+                let mut modifiers = Modifiers::Public;
+                let selector = if prop.kind.is_accessor() {
+                    Selectors::ClassicAccessor
+                } else if prop.value.is_function() {
+                    if is_async_function(&prop.value) {
+                        modifiers |= Modifiers::Async;
+                    }
+                    Selectors::ObjectLiteralMethod
+                } else {
+                    Selectors::ObjectLiteralProperty
+                };
+                let type_of = || match &prop.value {
+                    Expression::FunctionExpression(func) if prop.kind.is_accessor() => {
+                        accessor_type_of_function(prop.kind == PropertyKind::Get, func)
+                    }
+                    value => type_of_expression(value),
+                };
+                self.check_key(&prop.key, selector, modifiers, type_of, ctx);
+            }
+            AstKind::TSPropertySignature(sig) if !sig.computed => {
+                let mut modifiers = Modifiers::Public;
+                if sig.readonly {
+                    modifiers |= Modifiers::Readonly;
+                }
+                let type_of = || sig.type_annotation.as_deref().and_then(type_of_annotation);
+                let selector = if type_of() == Some(Types::Function) {
+                    Selectors::TypeMethod
+                } else {
+                    Selectors::TypeProperty
+                };
+                self.check_key(&sig.key, selector, modifiers, type_of, ctx);
+            }
+            AstKind::TSMethodSignature(sig) if !sig.computed => {
+                self.check_key(&sig.key, Selectors::TypeMethod, Modifiers::Public, || None, ctx);
+            }
+            AstKind::TSEnumMember(member) => {
+                let (name, span) = match &member.id {
+                    TSEnumMemberName::Identifier(ident) => (ident.name.as_str(), ident.span),
+                    TSEnumMemberName::String(lit) | TSEnumMemberName::ComputedString(lit) => {
+                        (lit.value.as_str(), lit.span)
+                    }
+                    TSEnumMemberName::ComputedTemplateString(_) => return,
+                };
+                let modifiers = if is_identifier_name(name) {
+                    Modifiers::empty()
+                } else {
+                    Modifiers::RequiresQuotes
+                };
+                self.check(name, span, Selectors::EnumMember, modifiers, || None, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NamingConvention {
+    /// Checks identifiers declared through a binding: variables, functions, parameters,
+    /// classes, type declarations and imports.
+    fn check_binding<'a>(
+        &self,
+        ident: &BindingIdentifier<'a>,
+        node: &AstNode<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let nodes = ctx.nodes();
+        let symbol_id = ident.symbol_id.get();
+        let parent = nodes.parent_node(node.id());
+        let name = ident.name.as_str();
+        let (selector, mut modifiers) = match parent.kind() {
+            AstKind::Class(class) => {
+                let mut modifiers = Modifiers::empty();
+                if class.r#abstract {
+                    modifiers |= Modifiers::Abstract;
+                }
+                (Selectors::Class, modifiers | exported_modifier(parent.id(), symbol_id, ctx))
+            }
+            AstKind::Function(func) => {
+                let mut modifiers = exported_modifier(parent.id(), symbol_id, ctx);
+                if symbol_id.is_some_and(|id| is_global_symbol(id, ctx)) {
+                    modifiers |= Modifiers::Global;
+                }
+                if func.r#async {
+                    modifiers |= Modifiers::Async;
+                }
+                (Selectors::Function, modifiers)
+            }
+            AstKind::TSInterfaceDeclaration(_) => {
+                (Selectors::Interface, exported_modifier(parent.id(), symbol_id, ctx))
+            }
+            AstKind::TSTypeAliasDeclaration(_) => {
+                (Selectors::TypeAlias, exported_modifier(parent.id(), symbol_id, ctx))
+            }
+            AstKind::TSEnumDeclaration(_) => {
+                (Selectors::Enum, exported_modifier(parent.id(), symbol_id, ctx))
+            }
+            AstKind::TSTypeParameter(_) => (Selectors::TypeParameter, Modifiers::empty()),
+            AstKind::ImportDefaultSpecifier(_) => (Selectors::Import, Modifiers::Default),
+            AstKind::ImportNamespaceSpecifier(_) => (Selectors::Import, Modifiers::Namespace),
+            AstKind::ImportSpecifier(spec) if spec.imported.name() == "default" => {
+                (Selectors::Import, Modifiers::Default)
+            }
+            AstKind::ImportSpecifier(_) => return,
+            _ => return self.check_pattern_binding(ident, node, ctx),
+        };
+        if selector != Selectors::Import && symbol_id.is_some_and(|id| is_unused(id, ctx)) {
+            modifiers |= Modifiers::Unused;
+        }
+        self.check(name, ident.span, selector, modifiers, || None, ctx);
+    }
+
+    /// Checks identifiers bound by a variable declarator or a parameter, possibly inside a
+    /// destructuring pattern.
+    fn check_pattern_binding<'a>(
+        &self,
+        ident: &BindingIdentifier<'a>,
+        node: &AstNode<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let nodes = ctx.nodes();
+        let parent = nodes.parent_node(node.id());
+        let Some(declaration) = nodes.ancestors(node.id()).find(|ancestor| {
+            !matches!(
+                ancestor.kind(),
+                AstKind::ObjectPattern(_)
+                    | AstKind::ArrayPattern(_)
+                    | AstKind::BindingProperty(_)
+                    | AstKind::AssignmentPattern(_)
+                    | AstKind::BindingRestElement(_)
+            )
+        }) else {
+            return;
+        };
+
+        let mut modifiers = Modifiers::empty();
+        if is_destructured(node.id(), ctx) {
+            modifiers |= Modifiers::Destructured;
+        }
+        let symbol_id = ident.symbol_id.get();
+        if symbol_id.is_some_and(|id| is_unused(id, ctx)) {
+            modifiers |= Modifiers::Unused;
+        }
+        let is_direct = parent.id() == declaration.id();
+
+        match declaration.kind() {
+            AstKind::VariableDeclarator(decl) => {
+                if decl.kind == VariableDeclarationKind::Const {
+                    modifiers |= Modifiers::Const;
+                }
+                if declaration.scope_id() == ctx.scoping().root_scope_id() {
+                    modifiers |= Modifiers::Global;
+                }
+                let declaration_parent = nodes.parent_id(declaration.id());
+                modifiers |= exported_modifier(declaration_parent, symbol_id, ctx);
+                if is_direct && decl.init.as_ref().is_some_and(is_async_function) {
+                    modifiers |= Modifiers::Async;
+                }
+                let type_of = || {
+                    if !is_direct {
+                        return None;
+                    }
+                    decl.type_annotation.as_deref().map_or_else(
+                        || decl.init.as_ref().and_then(type_of_expression),
+                        type_of_annotation,
+                    )
+                };
+                self.check(&ident.name, ident.span, Selectors::Variable, modifiers, type_of, ctx);
+            }
+            AstKind::FormalParameter(param) => {
+                if !is_function_parameter(declaration.id(), ctx) {
+                    return;
+                }
+                let is_property =
+                    param.accessibility.is_some() || param.readonly || param.r#override;
+                let selector = if is_property {
+                    modifiers = member_modifiers(
+                        false,
+                        param.accessibility,
+                        false,
+                        param.readonly,
+                        param.r#override,
+                        false,
+                    );
+                    Selectors::ParameterProperty
+                } else {
+                    Selectors::Parameter
+                };
+                let type_of = || {
+                    if !is_direct {
+                        return None;
+                    }
+                    param.type_annotation.as_deref().map_or_else(
+                        || param.initializer.as_deref().and_then(type_of_expression),
+                        type_of_annotation,
+                    )
+                };
+                self.check(&ident.name, ident.span, selector, modifiers, type_of, ctx);
+            }
+            AstKind::FormalParameterRest(_) => {
+                if !is_function_parameter(declaration.id(), ctx) {
+                    return;
+                }
+                let is_rest = nodes.parent_id(parent.id()) == declaration.id();
+                let type_of = || is_rest.then_some(Types::Array);
+                self.check(&ident.name, ident.span, Selectors::Parameter, modifiers, type_of, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    /// Checks the key of a class member, object literal property or type member.
+    fn check_key(
+        &self,
+        key: &PropertyKey,
+        selector: Selectors,
+        mut modifiers: Modifiers,
+        type_of: impl Fn() -> Option<Types>,
+        ctx: &LintContext,
+    ) {
+        let name = if let PropertyKey::PrivateIdentifier(ident) = key {
+            Cow::Borrowed(ident.name.as_str())
+        } else {
+            let Some(name) = key.static_name() else { return };
+            name
+        };
+        if !is_identifier_name(&name) {
+            modifiers |= Modifiers::RequiresQuotes;
+        }
+        self.check(&name, key.span(), selector, modifiers, type_of, ctx);
+    }
+
+    /// Validates `name` against the first option matching its selector, modifiers and type.
+    fn check(
+        &self,
+        name: &str,
+        span: Span,
+        selector: Selectors,
+        modifiers: Modifiers,
+        type_of: impl Fn() -> Option<Types>,
+        ctx: &LintContext,
+    ) {
+        let Some(option) = self.options.iter().find(|option| {
+            option.selector.contains(selector)
+                && option
+                    .filter
+                    .as_ref()
+                    .is_none_or(|(regex, is_match)| regex.is_match(name) == *is_match)
+                && modifiers.contains(option.modifiers)
+                && option.types.is_none_or(|types| type_of().is_some_and(|ty| types.contains(ty)))
+        }) else {
+            return;
+        };
+        if let Err(message) = validate(option, name, modifiers) {
+            ctx.diagnostic(naming_convention_diagnostic(
+                format!("{} name `{name}` {message}", selector.display_name()),
+                span,
+            ));
+        }
+    }
+}
+
+/// Runs the checks of an option in the same order as typescript-eslint: underscores, affixes,
+/// the custom regex, and finally the formats on what remains of the name.
+fn validate(option: &NormalizedOption, original: &str, modifiers: Modifiers) -> Result<(), String> {
+    let name = validate_underscore(option.leading_underscore, original, true)?;
+    let name = validate_underscore(option.trailing_underscore, name, false)?;
+    let name = validate_affix(&option.prefix, name, true)?;
+    let name = validate_affix(&option.suffix, name, false)?;
+
+    if let Some((regex, is_match)) = &option.custom
+        && regex.is_match(name) != *is_match
+    {
+        let verb = if *is_match { "match" } else { "not match" };
+        return Err(format!("must {verb} the RegExp: /{}/u", regex.as_str()));
+    }
+
+    if option.formats.is_empty() {
+        return Ok(());
+    }
+    if !modifiers.contains(Modifiers::RequiresQuotes)
+        && option.formats.iter().any(|format| format.check(name))
+    {
+        return Ok(());
+    }
+    let formats =
+        option.formats.iter().map(|format| format.as_str()).collect::<Vec<_>>().join(", ");
+    if name == original {
+        Err(format!("must match one of the following formats: {formats}"))
+    } else {
+        Err(format!("trimmed as `{name}` must match one of the following formats: {formats}"))
+    }
+}
+
+fn validate_underscore(
+    option: Option<UnderscoreOption>,
+    name: &str,
+    leading: bool,
+) -> Result<&str, String> {
+    let Some(option) = option else { return Ok(name) };
+    let position = if leading { "leading" } else { "trailing" };
+    let trim = |count: usize| -> Option<&str> {
+        let underscores = &"__"[..count];
+        if leading { name.strip_prefix(underscores) } else { name.strip_suffix(underscores) }
+    };
+    match option {
+        UnderscoreOption::Allow => Ok(trim(1).unwrap_or(name)),
+        UnderscoreOption::AllowDouble => Ok(trim(2).unwrap_or(name)),
+        UnderscoreOption::AllowSingleOrDouble => Ok(trim(2).or_else(|| trim(1)).unwrap_or(name)),
+        UnderscoreOption::Forbid => match trim(1) {
+            Some(_) => Err(format!("must not have a {position} underscore.")),
+            None => Ok(name),
+        },
+        UnderscoreOption::Require => {
+            trim(1).ok_or_else(|| format!("must have one {position} underscore(s)."))
+        }
+        UnderscoreOption::RequireDouble => {
+            trim(2).ok_or_else(|| format!("must have two {position} underscore(s)."))
+        }
+    }
+}
+
+fn validate_affix<'n>(affixes: &[String], name: &'n str, prefix: bool) -> Result<&'n str, String> {
+    if affixes.is_empty() {
+        return Ok(name);
+    }
+    affixes
+        .iter()
+        .find_map(|affix| {
+            if prefix {
+                name.strip_prefix(affix.as_str())
+            } else {
+                name.strip_suffix(affix.as_str())
+            }
+        })
+        .ok_or_else(|| {
+            let position = if prefix { "prefix" } else { "suffix" };
+            format!("must have one of the following {position}es: {}", affixes.join(", "))
+        })
+}
+
+fn member_modifiers(
+    is_hash_private: bool,
+    accessibility: Option<TSAccessibility>,
+    is_static: bool,
+    is_readonly: bool,
+    is_override: bool,
+    is_abstract: bool,
+) -> Modifiers {
+    let mut modifiers = if is_hash_private {
+        Modifiers::HashPrivate
+    } else {
+        match accessibility {
+            Some(TSAccessibility::Private) => Modifiers::Private,
+            Some(TSAccessibility::Protected) => Modifiers::Protected,
+            Some(TSAccessibility::Public) | None => Modifiers::Public,
+        }
+    };
+    modifiers.set(Modifiers::Static, is_static);
+    modifiers.set(Modifiers::Readonly, is_readonly);
+    modifiers.set(Modifiers::Override, is_override);
+    modifiers.set(Modifiers::Abstract, is_abstract);
+    modifiers
+}
+
+fn exported_modifier(
+    declaration_id: NodeId,
+    symbol_id: Option<SymbolId>,
+    ctx: &LintContext,
+) -> Modifiers {
+    if is_exported(declaration_id, symbol_id, ctx) {
+        Modifiers::Exported
+    } else {
+        Modifiers::empty()
+    }
+}
+
+/// A declaration is exported when it is part of an export declaration, or when its symbol is
+/// exported separately, e.g. `export { foo }` or `export default foo`.
+fn is_exported(declaration_id: NodeId, symbol_id: Option<SymbolId>, ctx: &LintContext) -> bool {
+    if matches!(
+        ctx.nodes().parent_kind(declaration_id),
+        AstKind::ExportNamedDeclaration(_) | AstKind::ExportDefaultDeclaration(_)
+    ) {
+        return true;
+    }
+    let Some(symbol_id) = symbol_id else { return false };
+    ctx.scoping().get_resolved_references(symbol_id).any(|reference| {
+        matches!(
+            ctx.nodes().parent_kind(reference.node_id()),
+            AstKind::ExportSpecifier(_) | AstKind::ExportDefaultDeclaration(_)
+        )
+    })
+}
+
+fn is_unused(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    !ctx.scoping()
+        .get_resolved_references(symbol_id)
+        .any(|reference| reference.is_read() || reference.is_type())
+}
+
+fn is_global_symbol(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    ctx.scoping().symbol_scope_id(symbol_id) == ctx.scoping().root_scope_id()
+}
+
+/// `a` in `const { a } = b` or `const { a = 1 } = b`, but not in `const { x: a } = b`.
+fn is_destructured(node_id: NodeId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let mut parent = nodes.parent_node(node_id);
+    if matches!(parent.kind(), AstKind::AssignmentPattern(_)) {
+        parent = nodes.parent_node(parent.id());
+    }
+    matches!(parent.kind(), AstKind::BindingProperty(prop) if prop.shorthand)
+}
+
+/// Parameters of functions, as opposed to those of function types and signatures.
+fn is_function_parameter(param_id: NodeId, ctx: &LintContext) -> bool {
+    let params_id = ctx.nodes().parent_id(param_id);
+    matches!(
+        ctx.nodes().parent_kind(params_id),
+        AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+    )
+}
+
+fn is_async_function(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::FunctionExpression(func) => func.r#async,
+        Expression::ArrowFunctionExpression(arrow) => arrow.r#async,
+        _ => false,
+    }
+}
+
+/// The type of an accessor, from the return type of a getter or the parameter of a setter.
+fn accessor_type_of_function(is_getter: bool, func: &Function) -> Option<Types> {
+    let annotation = if is_getter {
+        func.return_type.as_deref()
+    } else {
+        func.params.items.first().and_then(|param| param.type_annotation.as_deref())
+    };
+    annotation.and_then(type_of_annotation)
+}
+
+fn type_of_annotation(annotation: &TSTypeAnnotation) -> Option<Types> {
+    type_of_ts_type(&annotation.type_annotation)
+}
+
+/// Resolves the type of a type annotation without the type checker. Literal types are widened
+/// to their primitive, and unions only resolve when all their members resolve to the same type.
+fn type_of_ts_type(ty: &TSType) -> Option<Types> {
+    match ty {
+        TSType::TSBooleanKeyword(_) => Some(Types::Boolean),
+        TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => Some(Types::String),
+        TSType::TSNumberKeyword(_) => Some(Types::Number),
+        TSType::TSLiteralType(lit) => match &lit.literal {
+            TSLiteral::BooleanLiteral(_) => Some(Types::Boolean),
+            TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => Some(Types::String),
+            TSLiteral::NumericLiteral(_) => Some(Types::Number),
+            TSLiteral::UnaryExpression(expr) => type_of_expression(&expr.argument),
+            TSLiteral::BigIntLiteral(_) => None,
+        },
+        TSType::TSFunctionType(_) => Some(Types::Function),
+        TSType::TSArrayType(_) | TSType::TSTupleType(_) => Some(Types::Array),
+        TSType::TSTypeOperatorType(op) if op.operator == TSTypeOperatorOperator::Readonly => {
+            type_of_ts_type(&op.type_annotation)
+        }
+        TSType::TSTypeReference(reference) => match &reference.type_name {
+            TSTypeName::IdentifierReference(ident)
+                if matches!(ident.name.as_str(), "Array" | "ReadonlyArray") =>
+            {
+                Some(Types::Array)
+            }
+            _ => None,
+        },
+        TSType::TSParenthesizedType(ty) => type_of_ts_type(&ty.type_annotation),
+        TSType::TSUnionType(union) => {
+            let mut types = union.types.iter().map(type_of_ts_type);
+            let first = types.next()??;
+            types.all(|ty| ty == Some(first)).then_some(first)
+        }
+        _ => None,
+    }
+}
+
+/// Resolves the type of an initializer without the type checker.
+fn type_of_expression(expr: &Expression) -> Option<Types> {
+    match expr.get_inner_expression() {
+        Expression::BooleanLiteral(_) => Some(Types::Boolean),
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => Some(Types::String),
+        Expression::NumericLiteral(_) => Some(Types::Number),
+        Expression::UnaryExpression(unary) => match unary.operator {
+            UnaryOperator::LogicalNot => Some(Types::Boolean),
+            UnaryOperator::UnaryNegation | UnaryOperator::UnaryPlus => {
+                matches!(unary.argument.get_inner_expression(), Expression::NumericLiteral(_))
+                    .then_some(Types::Number)
+            }
+            UnaryOperator::Typeof => Some(Types::String),
+            _ => None,
+        },
+        Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => {
+            Some(Types::Function)
+        }
+        Expression::ArrayExpression(_) => Some(Types::Array),
+        Expression::TSAsExpression(expr) => type_of_ts_type(&expr.type_annotation),
+        Expression::TSTypeAssertion(expr) => type_of_ts_type(&expr.type_annotation),
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        // default options
+        ("const myVariable = 1;", None),
+        ("const MY_CONSTANT = 1;", None),
+        ("let _private = 1;", None),
+        ("function doSomething(firstArg, _ignored) {}", None),
+        ("class MyClass { myProperty = 1; myMethod() {} get myValue() { return 1 } }", None),
+        ("interface MyInterface { myProperty: string; myMethod(): void }", None),
+        ("type MyType<TValue> = TValue[];", None),
+        ("enum MyEnum { firstMember }", None),
+        ("import React from 'react'; import * as path from 'path';", None),
+        ("import { use_state } from 'react';", None),
+        (
+            "const obj = { 'Content-Type': 1 };",
+            Some(
+                json!([{ "selector": "objectLiteralProperty", "format": ["camelCase"], "filter": { "regex": "-", "match": false } }]),
+            ),
+        ),
+        (
+            "const { snake_case } = obj;",
+            Some(
+                json!([{ "selector": "variable", "modifiers": ["destructured"], "format": null }, { "selector": "variable", "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "const { a: renamed } = obj;",
+            Some(json!([{ "selector": "variable", "format": ["camelCase"] }])),
+        ),
+        ("foo(function (this: Foo) {});", None),
+        ("const f = (x: (snake_case: number) => void) => x;", None),
+        (
+            "declare function f(first_param: number): void;",
+            Some(json!([{ "selector": "function", "format": ["camelCase"] }])),
+        ),
+        ("class A { [computed_key] = 1 }", None),
+        (
+            "class A { constructor() {} }",
+            Some(json!([{ "selector": "classMethod", "format": ["UPPER_CASE"] }])),
+        ),
+        // modifiers
+        (
+            "const MAX = 1; let count = 0;",
+            Some(
+                json!([{ "selector": "variable", "modifiers": ["const"], "format": ["UPPER_CASE"] }, { "selector": "variable", "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "function f() { const local = 1; } const GLOBAL = 1;",
+            Some(
+                json!([{ "selector": "variable", "modifiers": ["global"], "format": ["UPPER_CASE"] }, { "selector": "variable", "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "export const MyComponent = 1; const local = 1; local;",
+            Some(
+                json!([{ "selector": "variable", "modifiers": ["exported"], "format": ["PascalCase"] }, { "selector": "variable", "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "const MyComponent = 1; export { MyComponent };",
+            Some(
+                json!([{ "selector": "variable", "modifiers": ["exported"], "format": ["PascalCase"] }, { "selector": "variable", "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "const _unused = 1; const used = 1; used;",
+            Some(
+                json!([{ "selector": "variable", "modifiers": ["unused"], "format": ["camelCase"], "leadingUnderscore": "require" }, { "selector": "variable", "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "const fetchAsync = async () => {}; const fetch = () => {};",
+            Some(
+                json!([{ "selector": "variable", "modifiers": ["async"], "format": ["camelCase"], "suffix": ["Async"] }]),
+            ),
+        ),
+        (
+            "class A { private _secret = 1; public visible = 1; #hidden = 1 }",
+            Some(
+                json!([{ "selector": "classProperty", "modifiers": ["private"], "format": ["camelCase"], "leadingUnderscore": "require" }, { "selector": "classProperty", "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "class A { static readonly MAX = 1; value = 1 }",
+            Some(
+                json!([{ "selector": "classProperty", "modifiers": ["static", "readonly"], "format": ["UPPER_CASE"] }, { "selector": "classProperty", "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "abstract class AbstractBase {} class Impl {}",
+            Some(
+                json!([{ "selector": "class", "modifiers": ["abstract"], "format": ["PascalCase"], "prefix": ["Abstract"] }, { "selector": "class", "format": ["PascalCase"] }]),
+            ),
+        ),
+        (
+            "const headers = { 'Content-Type': 'json' };",
+            Some(
+                json!([{ "selector": "objectLiteralProperty", "modifiers": ["requiresQuotes"], "format": null }, { "selector": "objectLiteralProperty", "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "import Default from 'a'; import * as ns from 'b'; import { default as Other } from 'c';",
+            Some(
+                json!([{ "selector": "import", "modifiers": ["namespace"], "format": ["camelCase"] }, { "selector": "import", "modifiers": ["default"], "format": ["PascalCase"] }]),
+            ),
+        ),
+        (
+            "class A { constructor(private readonly _dep: Dep, plain: number) { plain } }",
+            Some(
+                json!([{ "selector": "parameterProperty", "modifiers": ["private"], "format": ["camelCase"], "leadingUnderscore": "require" }]),
+            ),
+        ),
+        // types
+        (
+            "const isEnabled = true; let hasItems: boolean; const count = 1;",
+            Some(
+                json!([{ "selector": "variable", "types": ["boolean"], "format": ["PascalCase"], "prefix": ["is", "should", "has", "can"] }]),
+            ),
+        ),
+        (
+            "const items: string[] = []; const names = [] as Array<string>; const itemsList: ReadonlyArray<number> = [];",
+            Some(
+                json!([{ "selector": "variable", "types": ["array"], "format": ["camelCase"], "suffix": ["s", "List"] }]),
+            ),
+        ),
+        (
+            "const onClick = () => {}; let handler: (e: Event) => void;",
+            Some(
+                json!([{ "selector": "variable", "types": ["function"], "format": ["camelCase"] }, { "selector": "variable", "format": ["UPPER_CASE"] }]),
+            ),
+        ),
+        (
+            "const value = getValue();",
+            Some(
+                json!([{ "selector": "variable", "types": ["boolean"], "format": ["UPPER_CASE"] }]),
+            ),
+        ),
+        (
+            "function f(isOk: boolean, count = 0, ...args) {}",
+            Some(
+                json!([{ "selector": "parameter", "types": ["boolean"], "format": ["PascalCase"], "prefix": ["is"] }]),
+            ),
+        ),
+        (
+            "let maybe: string | null;",
+            Some(
+                json!([{ "selector": "variable", "types": ["string"], "format": ["UPPER_CASE"] }]),
+            ),
+        ),
+        // underscores, affixes and custom
+        (
+            "const __meta__ = 1;",
+            Some(
+                json!([{ "selector": "variable", "format": ["camelCase"], "leadingUnderscore": "allowDouble", "trailingUnderscore": "allowDouble" }]),
+            ),
+        ),
+        (
+            "const _a = 1; const __b = 1; const c = 1;",
+            Some(
+                json!([{ "selector": "variable", "format": ["camelCase"], "leadingUnderscore": "allowSingleOrDouble" }]),
+            ),
+        ),
+        (
+            "interface IFoo {}",
+            Some(
+                json!([{ "selector": "interface", "format": ["PascalCase"], "custom": { "regex": "^I[A-Z]", "match": true } }]),
+            ),
+        ),
+        (
+            "interface Foo {}",
+            Some(
+                json!([{ "selector": "interface", "format": ["PascalCase"], "custom": { "regex": "^I[A-Z]", "match": false } }]),
+            ),
+        ),
+        (
+            "type TFoo = 1;",
+            Some(json!([{ "selector": "typeAlias", "format": ["PascalCase"], "prefix": ["T"] }])),
+        ),
+        (
+            "function f<TKey>() {}",
+            Some(
+                json!([{ "selector": "typeParameter", "format": ["PascalCase"], "prefix": ["T"] }]),
+            ),
+        ),
+        // formats
+        (
+            "const myId = 1;",
+            Some(json!([{ "selector": "variable", "format": ["strictCamelCase"] }])),
+        ),
+        ("class MyId {}", Some(json!([{ "selector": "class", "format": ["StrictPascalCase"] }]))),
+        (
+            "const my_var_2 = 1;",
+            Some(json!([{ "selector": "variable", "format": ["snake_case"] }])),
+        ),
+        (
+            "let x = 1;",
+            Some(json!([{ "selector": "variable", "format": ["snake_case", "UPPER_CASE"] }])),
+        ),
+        ("const $ = 1; const é = 1;", None),
+        // selector specificity
+        (
+            "class a { MY_METHOD() {} myProperty = 1 }",
+            Some(
+                json!([{ "selector": "default", "format": ["camelCase"] }, { "selector": "method", "format": ["UPPER_CASE"] }]),
+            ),
+        ),
+        (
+            "class A { get MyValue() { return 1 } }",
+            Some(
+                json!([{ "selector": "memberLike", "format": ["camelCase"] }, { "selector": "classicAccessor", "format": ["PascalCase"] }]),
+            ),
+        ),
+        (
+            "const obj = { MyMethod() {}, myProp: 1 };",
+            Some(
+                json!([{ "selector": ["objectLiteralMethod", "typeMethod"], "format": ["PascalCase"] }, { "selector": "default", "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "enum Direction { UP, DOWN }",
+            Some(json!([{ "selector": "enumMember", "format": ["UPPER_CASE"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        // default options
+        ("const my_variable = 1;", None),
+        ("function do_something() {}", None),
+        ("function doSomething(First_Arg) {}", None),
+        ("class myClass {}", None),
+        ("interface myInterface { My_Property: string }", None),
+        ("type my_type = string;", None),
+        ("enum myEnum { First_Member }", None),
+        ("class A { My_Method() {} }", None),
+        ("import my_default from 'a';", None),
+        ("function f<t_value>() {}", None),
+        ("const obj = { My_Prop: 1, 'Content-Type': 2 };", None),
+        ("let _my_private = 1;", None),
+        // modifiers
+        (
+            "const max = 1;",
+            Some(
+                json!([{ "selector": "variable", "modifiers": ["const"], "format": ["UPPER_CASE"] }]),
+            ),
+        ),
+        (
+            "export function helper() {}",
+            Some(
+                json!([{ "selector": "function", "modifiers": ["exported"], "format": ["PascalCase"] }]),
+            ),
+        ),
+        (
+            "function helper() {} export default helper;",
+            Some(
+                json!([{ "selector": "function", "modifiers": ["exported"], "format": ["PascalCase"] }]),
+            ),
+        ),
+        (
+            "const { snake_case } = obj;",
+            Some(
+                json!([{ "selector": "variable", "modifiers": ["destructured"], "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "function f({ first_arg = 1 }) {}",
+            Some(
+                json!([{ "selector": "parameter", "modifiers": ["destructured"], "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "const unusedValue = 1;",
+            Some(
+                json!([{ "selector": "variable", "modifiers": ["unused"], "format": ["camelCase"], "leadingUnderscore": "require" }]),
+            ),
+        ),
+        (
+            "async function fetchData() {}",
+            Some(
+                json!([{ "selector": "function", "modifiers": ["async"], "format": ["camelCase"], "suffix": ["Async"] }]),
+            ),
+        ),
+        (
+            "class A { private secret = 1 }",
+            Some(
+                json!([{ "selector": "classProperty", "modifiers": ["private"], "format": ["camelCase"], "leadingUnderscore": "require" }]),
+            ),
+        ),
+        (
+            "class A { #secret = 1 }",
+            Some(
+                json!([{ "selector": "classProperty", "modifiers": ["#private"], "format": ["UPPER_CASE"] }]),
+            ),
+        ),
+        (
+            "class A { static readonly max = 1 }",
+            Some(
+                json!([{ "selector": "classProperty", "modifiers": ["static", "readonly"], "format": ["UPPER_CASE"] }]),
+            ),
+        ),
+        (
+            "class A { override my_method() {} }",
+            Some(
+                json!([{ "selector": "classMethod", "modifiers": ["override"], "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "abstract class Base {}",
+            Some(
+                json!([{ "selector": "class", "modifiers": ["abstract"], "format": ["PascalCase"], "prefix": ["Abstract"] }]),
+            ),
+        ),
+        (
+            "const headers = { 'Content-Type': 'json' };",
+            Some(json!([{ "selector": "objectLiteralProperty", "format": ["camelCase"] }])),
+        ),
+        (
+            "import * as Path from 'path';",
+            Some(
+                json!([{ "selector": "import", "modifiers": ["namespace"], "format": ["camelCase"] }]),
+            ),
+        ),
+        (
+            "class A { constructor(private readonly dep: Dep) {} }",
+            Some(
+                json!([{ "selector": "parameterProperty", "modifiers": ["private"], "format": ["camelCase"], "leadingUnderscore": "require" }]),
+            ),
+        ),
+        (
+            "class A { accessor my_value = 1 }",
+            Some(json!([{ "selector": "autoAccessor", "format": ["camelCase"] }])),
+        ),
+        ("interface A { readonly my_prop: string; my_fn: () => void; my_method(): void }", None),
+        // types
+        (
+            "const enabled = true; let visible: boolean; const negated = !x;",
+            Some(
+                json!([{ "selector": "variable", "types": ["boolean"], "format": ["PascalCase"], "prefix": ["is", "should", "has", "can"] }]),
+            ),
+        ),
+        (
+            "const item: string[] = []; const names = new Set();",
+            Some(
+                json!([{ "selector": "variable", "types": ["array"], "format": ["camelCase"], "suffix": ["s", "List"] }]),
+            ),
+        ),
+        (
+            "const on_click = () => {};",
+            Some(
+                json!([{ "selector": "variable", "types": ["function"], "format": ["camelCase"] }, { "selector": "variable", "format": ["snake_case"] }]),
+            ),
+        ),
+        (
+            "class A { enabled: boolean = true }",
+            Some(
+                json!([{ "selector": "classProperty", "types": ["boolean"], "format": ["PascalCase"], "prefix": ["is"] }]),
+            ),
+        ),
+        (
+            "function f(...item) {}",
+            Some(
+                json!([{ "selector": "parameter", "types": ["array"], "format": ["camelCase"], "suffix": ["s"] }]),
+            ),
+        ),
+        (
+            "function f(enabled: boolean) {}",
+            Some(
+                json!([{ "selector": "parameter", "types": ["boolean"], "format": ["PascalCase"], "prefix": ["is"] }]),
+            ),
+        ),
+        (
+            "let state: 'on' | 'off';",
+            Some(
+                json!([{ "selector": "variable", "types": ["string"], "format": ["UPPER_CASE"] }]),
+            ),
+        ),
+        // underscores, affixes and custom
+        (
+            "const _value = 1; const value_ = 1;",
+            Some(
+                json!([{ "selector": "variable", "format": ["camelCase"], "leadingUnderscore": "forbid", "trailingUnderscore": "forbid" }]),
+            ),
+        ),
+        (
+            "const value = 1;",
+            Some(
+                json!([{ "selector": "variable", "format": ["camelCase"], "leadingUnderscore": "requireDouble" }]),
+            ),
+        ),
+        (
+            "const value = 1;",
+            Some(
+                json!([{ "selector": "variable", "format": ["camelCase"], "trailingUnderscore": "require" }]),
+            ),
+        ),
+        (
+            "interface Foo {}",
+            Some(
+                json!([{ "selector": "interface", "format": ["PascalCase"], "custom": { "regex": "^I[A-Z]", "match": true } }]),
+            ),
+        ),
+        (
+            "interface IFoo {}",
+            Some(
+                json!([{ "selector": "interface", "format": ["PascalCase"], "custom": { "regex": "^I[A-Z]", "match": false } }]),
+            ),
+        ),
+        (
+            "type Foo = 1;",
+            Some(
+                json!([{ "selector": "typeAlias", "format": ["PascalCase"], "prefix": ["T", "Type"] }]),
+            ),
+        ),
+        (
+            "type TFoo_bar = 1;",
+            Some(json!([{ "selector": "typeAlias", "format": ["PascalCase"], "prefix": ["T"] }])),
+        ),
+        (
+            "const fooService = 1;",
+            Some(
+                json!([{ "selector": "variable", "format": ["camelCase"], "suffix": ["Factory"] }]),
+            ),
+        ),
+        (
+            "const badName = 1;",
+            Some(
+                json!([{ "selector": "variable", "format": ["camelCase"], "filter": "^bad" , "custom": { "regex": "^bad", "match": false } }]),
+            ),
+        ),
+        // formats
+        (
+            "const myID = 1;",
+            Some(json!([{ "selector": "variable", "format": ["strictCamelCase"] }])),
+        ),
+        ("class MyID {}", Some(json!([{ "selector": "class", "format": ["StrictPascalCase"] }]))),
+        (
+            "const my__var = 1; const My_var = 1;",
+            Some(json!([{ "selector": "variable", "format": ["snake_case"] }])),
+        ),
+        (
+            "const MY__VAR = 1; const MY_VAR_ = 1;",
+            Some(json!([{ "selector": "variable", "format": ["UPPER_CASE"] }])),
+        ),
+        // selector specificity
+        (
+            "class a { my_method() {} My_Prop = 1 }",
+            Some(
+                json!([{ "selector": "default", "format": ["camelCase"] }, { "selector": "method", "format": ["snake_case"] }]),
+            ),
+        ),
+        (
+            "class A { MY_PROP = () => {} }",
+            Some(json!([{ "selector": "classMethod", "format": ["camelCase"] }])),
+        ),
+        (
+            "const obj = { get MyValue() { return 1 } };",
+            Some(json!([{ "selector": "accessor", "format": ["camelCase"] }])),
+        ),
+        (
+            "enum Direction { up }",
+            Some(json!([{ "selector": "enumMember", "format": ["UPPER_CASE"] }])),
+        ),
+    ];
+
+    Tester::new(NamingConvention::NAME, NamingConvention::PLUGIN, pass, fail).test_and_snapshot();
+}
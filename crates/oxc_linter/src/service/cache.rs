@@ -0,0 +1,440 @@
+//! On-disk cache of lint results, so files which have not changed since the last run are not linted again.
+//!
+//! Each linted file has an entry keyed by its path, storing the diagnostics (and their fixes) reported for it.
+//! An entry is only reused if all of these are unchanged:
+//!
+//! * The source text of the file.
+//! * The configuration resolved for the file (rules, rule options, plugins, settings, env and globals).
+//! * If cross-module rules are enabled (e.g. `import/no-cycle`), the source text of all modules the file
+//!   imports, directly or indirectly.
+//! * The contents of `package.json` files read by rules (e.g. `import/no-extraneous-dependencies`),
+//!   and the absence of those which were looked up but did not exist.
+//!
+//! The whole cache is discarded if the linter version or linter options change, including the kind of
+//! fixes to apply, as fixes are only created for diagnostics if fixing is enabled.
+//!
+//! Diagnostics are cached with their fixes, before fixes are applied.
+//! Files with parse errors are not cached.
+
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    fmt::{self, Write as _},
+    fs,
+    hash::{DefaultHasher, Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use oxc_diagnostics::{LabeledSpan, OxcCode, OxcDiagnostic, Severity};
+use oxc_span::Span;
+
+use crate::{
+    ConfigStore, Fix, FixKind, LintOptions, Message, PossibleFixes, ReadFiles,
+    utils::file_content_hash,
+};
+
+pub struct LintCache {
+    /// Path of the cache file
+    path: PathBuf,
+    /// Hash of linter version and options. The cache file is discarded if it does not match.
+    key: u64,
+    /// Entries loaded from the cache file.
+    entries: FxHashMap<PathBuf, CacheEntry>,
+    /// Entries of files linted or reused in this run.
+    new_entries: Mutex<FxHashMap<PathBuf, CacheEntry>>,
+    /// Hashes of the source text of all modules read in this run, including dependencies which are not linted.
+    content_hashes: Mutex<FxHashMap<PathBuf, u64>>,
+    /// Hashes of configs without overrides, keyed by address of the `Config` in `ConfigStore`.
+    config_hashes: Mutex<FxHashMap<usize, u64>>,
+    /// Current hashes of the contents of files read by rules in previous runs, or `None` if they do
+    /// not exist.
+    read_file_hashes: Mutex<FxHashMap<PathBuf, Option<u64>>>,
+}
+
+/// Contents of the cache file.
+#[derive(Deserialize)]
+struct CacheFile {
+    key: u64,
+    entries: FxHashMap<PathBuf, CacheEntry>,
+}
+
+/// Contents of the cache file, for writing. Entries are sorted to keep the file stable.
+#[derive(Serialize)]
+struct CacheFileRef<'a> {
+    key: u64,
+    entries: BTreeMap<&'a Path, &'a CacheEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Hash of the source text.
+    content_hash: u64,
+    /// Hash of the configuration resolved for the file.
+    config_hash: u64,
+    /// Modules imported by the file, directly or indirectly, and hashes of their source text.
+    /// Sorted by path. Only tracked if cross-module rules are enabled.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    dependencies: Vec<(PathBuf, u64)>,
+    /// Files read by rules, such as `package.json`, and hashes of their contents, or `None` if they
+    /// did not exist. Sorted by path.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    read_files: ReadFiles,
+    messages: Vec<CachedMessage>,
+}
+
+impl LintCache {
+    /// Load the cache from `path`.
+    ///
+    /// Starts with an empty cache if the file does not exist, cannot be parsed,
+    /// or was written by a different linter version or with different options.
+    pub fn load(path: PathBuf, version: &str, options: LintOptions) -> Self {
+        let key = {
+            let mut hasher = HashWriter::default();
+            version.hash(&mut hasher.0);
+            let _ = write!(hasher, "{options:?}");
+            hasher.0.finish()
+        };
+        let entries = fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<CacheFile>(&bytes).ok())
+            .filter(|file| file.key == key)
+            .map(|file| file.entries)
+            .unwrap_or_default();
+        Self {
+            path,
+            key,
+            entries,
+            new_entries: Mutex::default(),
+            content_hashes: Mutex::default(),
+            config_hashes: Mutex::default(),
+            read_file_hashes: Mutex::default(),
+        }
+    }
+
+    /// Record the hash of the source text of a module, and return it.
+    pub fn insert_content_hash(&self, path: &Path, source_text: &str) -> u64 {
+        let hash = file_content_hash(source_text);
+        self.content_hashes.lock().unwrap().insert(path.to_path_buf(), hash);
+        hash
+    }
+
+    /// Get the hash of the source text of a module read in this run.
+    pub fn content_hash(&self, path: &Path) -> Option<u64> {
+        self.content_hashes.lock().unwrap().get(path).copied()
+    }
+
+    /// Get the hash of the configuration resolved for `path`.
+    pub fn config_hash(&self, config_store: &ConfigStore, path: &Path) -> u64 {
+        let config = config_store.get_related_config(path);
+        if !config.overrides.is_empty() {
+            return hash_debug(&config.apply_overrides(path));
+        }
+        // Configs in `ConfigStore` live for the whole run, so their addresses are not reused.
+        let key = std::ptr::from_ref(config).addr();
+        *self.config_hashes.lock().unwrap().entry(key).or_insert_with(|| hash_debug(&config.base))
+    }
+
+    /// Get the cached messages for `path`, if the cache entry is still valid.
+    ///
+    /// The entry is kept for the next run.
+    pub fn get(
+        &self,
+        path: &Path,
+        content_hash: u64,
+        config_hash: u64,
+        dependencies: &[(PathBuf, u64)],
+    ) -> Option<Vec<Message>> {
+        let entry = self.entries.get(path)?;
+        if entry.content_hash != content_hash
+            || entry.config_hash != config_hash
+            || entry.dependencies != dependencies
+            || entry.read_files.iter().any(|(path, hash)| self.read_file_hash(path) != *hash)
+        {
+            return None;
+        }
+        let messages = entry.messages.iter().cloned().map(Message::from).collect();
+        self.new_entries.lock().unwrap().insert(path.to_path_buf(), entry.clone());
+        Some(messages)
+    }
+
+    /// Get the current hash of the contents of a file read by rules in a previous run.
+    fn read_file_hash(&self, path: &Path) -> Option<u64> {
+        *self.read_file_hashes.lock().unwrap().entry(path.to_path_buf()).or_insert_with(|| {
+            fs::read_to_string(path).ok().map(|content| file_content_hash(&content))
+        })
+    }
+
+    /// Store the messages reported for `path`, before fixes are applied.
+    ///
+    /// `read_files` are the files read by rules while linting `path`, see [`Linter::run_with_disable_directives`].
+    ///
+    /// [`Linter::run_with_disable_directives`]: crate::Linter::run_with_disable_directives
+    pub fn insert(
+        &self,
+        path: &Path,
+        content_hash: u64,
+        config_hash: u64,
+        dependencies: Vec<(PathBuf, u64)>,
+        read_files: ReadFiles,
+        messages: &[Message],
+    ) {
+        let entry = CacheEntry {
+            content_hash,
+            config_hash,
+            dependencies,
+            read_files,
+            messages: messages.iter().map(CachedMessage::from).collect(),
+        };
+        self.new_entries.lock().unwrap().insert(path.to_path_buf(), entry);
+    }
+
+    /// Write the cache file.
+    ///
+    /// Entries of files which were not linted in this run are kept, unless the file no longer exists.
+    ///
+    /// # Errors
+    /// Returns an error if the cache file cannot be serialized or written.
+    pub fn save(&self) -> Result<(), OxcDiagnostic> {
+        let new_entries = self.new_entries.lock().unwrap();
+        let entries = self
+            .entries
+            .iter()
+            .filter(|(path, _)| !new_entries.contains_key(*path) && path.exists())
+            .chain(new_entries.iter())
+            .map(|(path, entry)| (path.as_path(), entry))
+            .collect();
+        let file = CacheFileRef { key: self.key, entries };
+        let write = || -> Result<(), Box<dyn std::error::Error>> {
+            if let Some(dir) = self.path.parent()
+                && !dir.as_os_str().is_empty()
+            {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&self.path, serde_json::to_vec(&file)?)?;
+            Ok(())
+        };
+        write().map_err(|err| {
+            OxcDiagnostic::warn(format!(
+                "Failed to write cache file {}: {err}",
+                self.path.display()
+            ))
+        })
+    }
+}
+
+/// Hash the `Debug` representation of `value`, without allocating a string for it.
+///
+/// `Debug` output of resolved configs includes rule options, so any change to the config changes the hash.
+fn hash_debug(value: &impl fmt::Debug) -> u64 {
+    let mut hasher = HashWriter::default();
+    let _ = write!(hasher, "{value:?}");
+    hasher.0.finish()
+}
+
+#[derive(Default)]
+struct HashWriter(DefaultHasher);
+
+impl fmt::Write for HashWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write(s.as_bytes());
+        Ok(())
+    }
+}
+
+/// A [`Message`] as stored in the cache file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedMessage {
+    message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    labels: Option<Vec<CachedLabel>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    help: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    severity: CachedSeverity,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    suppressed: bool,
+    fixes: CachedFixes,
+    span: (u32, u32),
+    section_offset: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedLabel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    offset: usize,
+    len: usize,
+    primary: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+enum CachedSeverity {
+    Advice,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum CachedFixes {
+    None,
+    Single(CachedFix),
+    Multiple(Vec<CachedFix>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedFix {
+    content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    kind: u8,
+    span: (u32, u32),
+}
+
+impl From<&Message> for CachedMessage {
+    fn from(message: &Message) -> Self {
+        let error = &message.error;
+        Self {
+            message: error.message.to_string(),
+            labels: error.labels.as_ref().map(|labels| {
+                labels
+                    .iter()
+                    .map(|label| CachedLabel {
+                        label: label.label().map(ToString::to_string),
+                        offset: label.offset(),
+                        len: label.len(),
+                        primary: label.primary(),
+                    })
+                    .collect()
+            }),
+            help: error.help.as_ref().map(ToString::to_string),
+            note: error.note.as_ref().map(ToString::to_string),
+            severity: match error.severity {
+                Severity::Advice => CachedSeverity::Advice,
+                Severity::Warning => CachedSeverity::Warning,
+                Severity::Error => CachedSeverity::Error,
+            },
+            scope: error.code.scope.as_ref().map(ToString::to_string),
+            number: error.code.number.as_ref().map(ToString::to_string),
+            url: error.url.as_ref().map(ToString::to_string),
+            suppressed: error.suppressed,
+            fixes: match &message.fixes {
+                PossibleFixes::None => CachedFixes::None,
+                PossibleFixes::Single(fix) => CachedFixes::Single(fix.into()),
+                PossibleFixes::Multiple(fixes) => {
+                    CachedFixes::Multiple(fixes.iter().map(CachedFix::from).collect())
+                }
+            },
+            span: (message.span.start, message.span.end),
+            section_offset: message.section_offset,
+        }
+    }
+}
+
+impl From<CachedMessage> for Message {
+    fn from(cached: CachedMessage) -> Self {
+        let mut error = OxcDiagnostic::error(cached.message);
+        error.labels = cached.labels.map(|labels| {
+            labels
+                .into_iter()
+                .map(|label| {
+                    let span = (label.offset, label.len);
+                    if label.primary {
+                        LabeledSpan::new_primary_with_span(label.label, span)
+                    } else {
+                        LabeledSpan::new_with_span(label.label, span)
+                    }
+                })
+                .collect()
+        });
+        error.help = cached.help.map(Cow::Owned);
+        error.note = cached.note.map(Cow::Owned);
+        error.severity = match cached.severity {
+            CachedSeverity::Advice => Severity::Advice,
+            CachedSeverity::Warning => Severity::Warning,
+            CachedSeverity::Error => Severity::Error,
+        };
+        error.code =
+            OxcCode { scope: cached.scope.map(Cow::Owned), number: cached.number.map(Cow::Owned) };
+        error.url = cached.url.map(Cow::Owned);
+        error.suppressed = cached.suppressed;
+
+        let fixes = match cached.fixes {
+            CachedFixes::None => PossibleFixes::None,
+            CachedFixes::Single(fix) => PossibleFixes::Single(fix.into()),
+            CachedFixes::Multiple(fixes) => {
+                PossibleFixes::Multiple(fixes.into_iter().map(Fix::from).collect())
+            }
+        };
+
+        let mut message = Message::new(error, fixes);
+        message.span = Span::new(cached.span.0, cached.span.1);
+        message.section_offset = cached.section_offset;
+        message
+    }
+}
+
+impl From<&Fix> for CachedFix {
+    fn from(fix: &Fix) -> Self {
+        Self {
+            content: fix.content.to_string(),
+            message: fix.message.as_ref().map(ToString::to_string),
+            kind: fix.kind.bits(),
+            span: (fix.span.start, fix.span.end),
+        }
+    }
+}
+
+impl From<CachedFix> for Fix {
+    fn from(cached: CachedFix) -> Self {
+        Self {
+            content: Cow::Owned(cached.content),
+            message: cached.message.map(Cow::Owned),
+            kind: FixKind::from_bits_truncate(cached.kind),
+            span: Span::new(cached.span.0, cached.span.1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+    use oxc_span::Span;
+
+    use super::CachedMessage;
+    use crate::{Fix, FixKind, Message, PossibleFixes};
+
+    #[test]
+    fn message_round_trip() {
+        let error = OxcDiagnostic::warn("Unexpected `debugger` statement")
+            .with_help("Remove the debugger statement")
+            .with_note("note")
+            .with_error_code("eslint", "no-debugger")
+            .with_url("https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html")
+            .with_labels([
+                LabeledSpan::new_primary_with_span(Some("here".to_string()), (0, 9)),
+                LabeledSpan::new_with_span(None, (10, 3)),
+            ]);
+        let fixes = PossibleFixes::Multiple(vec![
+            Fix::delete(Span::new(0, 9)).with_message("Delete"),
+            Fix::new("foo", Span::new(10, 13)).with_kind(FixKind::DangerousSuggestion),
+        ]);
+        let mut message = Message::new(error, fixes);
+        message.section_offset = 4;
+
+        let cached: CachedMessage =
+            serde_json::from_str(&serde_json::to_string(&CachedMessage::from(&message)).unwrap())
+                .unwrap();
+        assert_eq!(Message::from(cached), message);
+    }
+}
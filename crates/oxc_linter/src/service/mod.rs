@@ -10,6 +10,7 @@ use oxc_diagnostics::DiagnosticSender;
 
 use crate::Linter;
 
+mod cache;
 mod runtime;
 use runtime::Runtime;
 pub use runtime::{OsFileSystem, RuntimeFileSystem};
@@ -21,6 +22,15 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Path of the lint result cache file, and version of the linter, which is part of the cache key
+    cache: Option<(PathBuf, String)>,
+
+    /// Do not write fixes to files
+    fix_dry_run: bool,
+
+    /// Attach fixes to the reported diagnostics
+    report_fixes: bool,
 }
 
 impl LintServiceOptions {
@@ -29,7 +39,14 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), tsconfig: None, cross_module: false }
+        Self {
+            cwd: cwd.into(),
+            tsconfig: None,
+            cross_module: false,
+            cache: None,
+            fix_dry_run: false,
+            report_fixes: false,
+        }
     }
 
     #[inline]
@@ -54,6 +71,43 @@ impl LintServiceOptions {
         self
     }
 
+    /// Cache lint results in the file at `location`, and reuse results of files which have not changed.
+    ///
+    /// The cache is discarded if `version` changes.
+    /// The cache is not used if unused disable directives are reported, JS plugins are enabled,
+    /// or `import/no-unused-modules` reports unused exports or files.
+    #[inline]
+    #[must_use]
+    pub fn with_cache<T>(mut self, location: T, version: &str) -> Self
+    where
+        T: Into<PathBuf>,
+    {
+        let location = location.into();
+        let location = if location.is_relative() { self.cwd.join(location) } else { location };
+        self.cache = Some((location, version.to_string()));
+        self
+    }
+
+    /// Compute fixes of the enabled fix kinds without writing them to files,
+    /// so that they can be reported with [`Self::with_report_fixes`].
+    #[inline]
+    #[must_use]
+    pub fn with_fix_dry_run(mut self, yes: bool) -> Self {
+        self.fix_dry_run = yes;
+        self
+    }
+
+    /// Attach fixes which were not applied to the reported diagnostics, with
+    /// [`OxcDiagnostic::with_fixes`]. Fixes are not copied into diagnostics otherwise.
+    ///
+    /// [`OxcDiagnostic::with_fixes`]: oxc_diagnostics::OxcDiagnostic::with_fixes
+    #[inline]
+    #[must_use]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.report_fixes = yes;
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
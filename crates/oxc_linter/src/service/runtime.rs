@@ -6,6 +6,7 @@ use std::{
     mem::take,
     path::{Path, PathBuf},
     sync::{Arc, Mutex, mpsc},
+    time::Instant,
 };
 
 use indexmap::IndexSet;
@@ -20,30 +21,42 @@ use self_cell::self_cell;
 use smallvec::SmallVec;
 
 use oxc_allocator::{Allocator, AllocatorGuard, AllocatorPool};
+use oxc_ast::{AstKind, ast::Expression};
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
 use oxc_parser::{ParseOptions, Parser};
-use oxc_resolver::Resolver;
-use oxc_semantic::{Semantic, SemanticBuilder};
+use oxc_resolver::{ResolveError, Resolver};
+use oxc_semantic::{Semantic, SemanticBuilder, SymbolId};
 use oxc_span::{CompactStr, SourceType, VALID_EXTENSIONS};
 
 use crate::{
     Fixer, Linter, Message, PossibleFixes,
     context::ContextSubHost,
     disable_directives::DisableDirectives,
-    loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
-    module_record::ModuleRecord,
+    loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader, TemplateReferences},
+    module_record::{ModuleRecord, ModuleResolution},
+    rules::RuleEnum,
+    timing::{FileTiming, timed},
     utils::read_to_arena_str,
 };
 
-use super::LintServiceOptions;
+use super::{LintServiceOptions, cache::LintCache};
 
 type ModulesByPath =
     papaya::HashMap<Arc<OsStr>, SmallVec<[Arc<ModuleRecord>; 1]>, BuildHasherDefault<FxHasher>>;
 
+/// Paths of modules to keep the content of, when no content should be kept.
+static NO_PATHS: IndexSet<Arc<OsStr>, FxBuildHasher> = IndexSet::with_hasher(FxBuildHasher);
+
 pub struct Runtime {
     cwd: Box<Path>,
     pub(super) linter: Linter,
     resolver: Option<Resolver>,
+    /// Resolve the specifiers of `require()` calls, which are not part of the module graph.
+    /// Only rules checking the resolution of all dependencies need them.
+    resolve_requires: bool,
+    /// Track the importers of modules, see [`ModuleRecord::importers`].
+    /// The specifiers of `import()` calls are resolved as well.
+    track_importers: bool,
 
     /// Pool of allocators for parsing and linting.
     allocator_pool: AllocatorPool,
@@ -64,6 +77,12 @@ pub struct Runtime {
     modules_by_path: ModulesByPath,
     /// Collected disable directives from linted files
     disable_directives_map: Arc<Mutex<FxHashMap<PathBuf, DisableDirectives>>>,
+    /// Cache of lint results from previous runs, if enabled.
+    cache: Option<LintCache>,
+    /// Do not write fixes to files.
+    fix_dry_run: bool,
+    /// Attach fixes which were not applied to the reported diagnostics.
+    report_fixes: bool,
 }
 
 /// Output of `Runtime::process_path`
@@ -115,6 +134,8 @@ self_cell! {
 struct ModuleContentDependent<'a> {
     source_text: &'a str,
     section_contents: SectionContents<'a>,
+    /// Messages from the lint result cache. If `Some`, the module was not parsed and `section_contents` is empty.
+    cached_messages: Option<Vec<Message>>,
 }
 
 // Safety: dependent borrows from owner. They're safe to be sent together.
@@ -127,6 +148,8 @@ struct SectionContent<'a> {
     /// None if section parsing failed. The corresponding item with the same index in
     /// `ProcessedModule.section_module_records` would be `Err(Vec<OxcDiagnostic>)`.
     semantic: Option<Semantic<'a>>,
+    /// Root scope bindings used by the template of a Vue or Svelte file.
+    template_symbols: FxHashSet<SymbolId>,
 }
 
 /// A module with its source text and semantic, ready to be linted.
@@ -244,7 +267,28 @@ impl Runtime {
         #[cfg(not(all(target_pointer_width = "64", target_endian = "little")))]
         let allocator_pool = AllocatorPool::new(thread_count);
 
-        let resolver = options.cross_module.then(|| Self::get_resolver(options.tsconfig));
+        let builtin_modules = options.cross_module
+            && linter.config.is_any_rule_enabled(|rule| {
+                matches!(
+                    rule,
+                    RuleEnum::ImportNoUnresolved(_) | RuleEnum::ImportNoExtraneousDependencies(_)
+                )
+            });
+        let track_importers = options.cross_module && linter.uses_importers();
+        let resolver =
+            options.cross_module.then(|| Self::get_resolver(options.tsconfig, builtin_modules));
+
+        // Disable directives and results of JS plugins are not cached, so the cache can't be used
+        // if unused directives are reported or JS plugins are enabled. Results depending on the
+        // importers of a module can't be cached either, as only dependencies are tracked.
+        let cache = options
+            .cache
+            .filter(|_| {
+                linter.options().report_unused_directive.is_none()
+                    && !linter.has_external_linter()
+                    && !track_importers
+            })
+            .map(|(path, version)| LintCache::load(path, &version, *linter.options()));
 
         Self {
             allocator_pool,
@@ -253,11 +297,16 @@ impl Runtime {
             cwd: options.cwd,
             linter,
             resolver,
+            resolve_requires: builtin_modules || track_importers,
+            track_importers,
             modules_by_path: papaya::HashMap::builder()
                 .hasher(BuildHasherDefault::default())
                 .resize_mode(papaya::ResizeMode::Blocking)
                 .build(),
             disable_directives_map: Arc::new(Mutex::new(FxHashMap::default())),
+            cache,
+            fix_dry_run: options.fix_dry_run,
+            report_fixes: options.report_fixes,
         }
     }
 
@@ -279,7 +328,8 @@ impl Runtime {
         self.disable_directives_map = map;
     }
 
-    fn get_resolver(tsconfig_path: Option<PathBuf>) -> Resolver {
+    /// `builtin_modules` tells Node.js builtin modules apart from unresolved modules.
+    fn get_resolver(tsconfig_path: Option<PathBuf>, builtin_modules: bool) -> Resolver {
         use oxc_resolver::{
             ResolveOptions, TsconfigDiscovery, TsconfigOptions, TsconfigReferences,
         };
@@ -304,6 +354,7 @@ impl Runtime {
             condition_names: vec!["module".into(), "import".into()],
             extension_alias,
             tsconfig,
+            builtin_modules,
             ..ResolveOptions::default()
         })
     }
@@ -343,12 +394,18 @@ impl Runtime {
     ///
     /// `on_module_to_lint` is called for each entry modules in `paths` when it's ready for linting,
     /// which means all its dependencies are resolved if import plugin is enabled.
+    ///
+    /// If `track_importers` is set, the importers of modules are tracked as well. The module graph
+    /// is built in a first pass which only keeps module records, so that the importers of all
+    /// modules are complete before any of them is linted. Paths are then processed again for linting.
+    #[expect(clippy::too_many_arguments)]
     fn resolve_modules<'a>(
         &'a self,
         file_system: &'a (dyn RuntimeFileSystem + Sync + Send),
         paths: &'a IndexSet<Arc<OsStr>, FxBuildHasher>,
         scope: &Scope<'a>,
         check_syntax_errors: bool,
+        track_importers: bool,
         tx_error: Option<&'a DiagnosticSender>,
         on_module_to_lint: impl Fn(&'a Self, ModuleToLint) + Send + Sync + Clone + 'a,
     ) {
@@ -405,6 +462,9 @@ impl Runtime {
         // This size is empirical based on AFFiNE@97cc814a.
         let group_size = rayon::current_num_threads() * 4;
 
+        // When importers are tracked, no content is kept while building the module graph.
+        let content_paths = if track_importers { &NO_PATHS } else { paths };
+
         // Stores modules that belongs to `self.paths` in current group.
         // They are passed to `on_module_to_lint` at the end of each group.
         let mut modules_to_lint: Vec<ModuleToLint> = Vec::with_capacity(group_size);
@@ -452,7 +512,7 @@ impl Runtime {
                         tx_process_output
                             .send(me.process_path(
                                 file_system,
-                                paths,
+                                content_paths,
                                 &path,
                                 check_syntax_errors,
                                 tx_error,
@@ -492,7 +552,7 @@ impl Runtime {
                                     tx_process_output
                                         .send(me.process_path(
                                             file_system,
-                                            paths,
+                                            content_paths,
                                             &dep_path,
                                             check_syntax_errors,
                                             tx_error,
@@ -576,6 +636,55 @@ impl Runtime {
                 });
             }
         }
+
+        if track_importers {
+            // Modules required with `require()` or `import()` are only in the module graph once
+            // all groups are processed.
+            let module_paths =
+                self.modules_by_path.pin().keys().map(Arc::clone).collect::<Vec<_>>();
+            module_paths.par_iter().for_each(|path| self.add_importers(path));
+
+            // Errors reading files were reported while building the module graph.
+            paths.par_iter().for_each(|path| {
+                let output = self.process_path(file_system, paths, path, check_syntax_errors, None);
+                let Some(mut entry) =
+                    ModuleToLint::from_processed_module(output.path, output.processed_module)
+                else {
+                    return;
+                };
+                // Lint with the records of the module graph, which have importers.
+                if let Some(records) = self.modules_by_path.pin().get(path) {
+                    let mut records = records.iter();
+                    for record in entry.section_module_records.iter_mut().flatten() {
+                        if let Some(graph_record) = records.next() {
+                            *record = Arc::clone(graph_record);
+                        }
+                    }
+                }
+                on_module_to_lint(self, entry);
+            });
+        }
+    }
+
+    /// Record the module at `path` as an importer of the modules it resolves to, including
+    /// modules which are not part of the module graph, e.g. through `require()`.
+    fn add_importers(&self, path: &OsStr) {
+        let modules_by_path = self.modules_by_path.pin();
+        let Some(records) = modules_by_path.get(path) else {
+            return;
+        };
+        for record in records {
+            record.track_importers();
+            for (specifier, resolution) in &record.resolutions {
+                if let ModuleResolution::Path(dep_path) = resolution
+                    && let Some(dep_record) = modules_by_path
+                        .get(dep_path.as_os_str())
+                        .and_then(|dep_records| dep_records.last())
+                {
+                    dep_record.add_importer(specifier.clone(), Arc::downgrade(record));
+                }
+            }
+        }
     }
 
     pub(super) fn run(
@@ -593,34 +702,81 @@ impl Runtime {
                 &paths_set,
                 scope,
                 true,
+                self.track_importers,
                 Some(tx_error),
                 move |me, mut module_to_lint| {
                     module_to_lint.content.with_dependent_mut(|allocator_guard, dep| {
-                        // If there are fixes, we will accumulate all of them and write to the file at the end.
-                        // This means we do not write multiple times to the same file if there are multiple sources
-                        // in the same file (for example, multiple scripts in an `.astro` file).
-                        let mut new_source_text = Cow::from(dep.source_text);
-
                         let path = Path::new(&module_to_lint.path);
 
+                        if let Some(messages) = dep.cached_messages.take() {
+                            me.report_messages(
+                                file_system,
+                                path,
+                                dep.source_text,
+                                messages,
+                                tx_error,
+                            );
+                            return;
+                        }
+
                         assert_eq!(
                             module_to_lint.section_module_records.len(),
                             dep.section_contents.len()
                         );
 
+                        // Files with parse errors are not cached.
+                        let cache_key = me
+                            .cache
+                            .as_ref()
+                            .filter(|_| {
+                                module_to_lint.section_module_records.iter().all(Result::is_ok)
+                            })
+                            .and_then(|cache| {
+                                let content_hash = cache.content_hash(path)?;
+                                let config_hash = cache.config_hash(&me.linter.config, path);
+                                let dependencies = if me.resolver.is_some() {
+                                    Self::collect_dependencies(
+                                        cache,
+                                        path,
+                                        &module_to_lint.section_module_records,
+                                    )
+                                } else {
+                                    vec![]
+                                };
+                                Some((content_hash, config_hash, dependencies))
+                            });
+
+                        // With cross-module rules, cache entries can only be checked once dependencies are resolved.
+                        if me.resolver.is_some()
+                            && let Some(cache) = &me.cache
+                            && let Some((content_hash, config_hash, dependencies)) = &cache_key
+                            && let Some(messages) =
+                                cache.get(path, *content_hash, *config_hash, dependencies)
+                        {
+                            me.report_messages(
+                                file_system,
+                                path,
+                                dep.source_text,
+                                messages,
+                                tx_error,
+                            );
+                            return;
+                        }
+
                         let context_sub_hosts: Vec<ContextSubHost<'_>> = module_to_lint
                             .section_module_records
                             .into_iter()
                             .zip(dep.section_contents.drain(..))
                             .filter_map(|(record_result, section)| match record_result {
-                                Ok(module_record) => {
-                                    Some(ContextSubHost::new_with_framework_options(
+                                Ok(module_record) => Some(
+                                    ContextSubHost::new_with_framework_options(
                                         section.semantic.unwrap(),
                                         Arc::clone(&module_record),
                                         section.source.start,
                                         section.source.framework_options,
-                                    ))
-                                }
+                                    )
+                                    .with_template_symbols(section.template_symbols),
+                                ),
                                 Err(messages) => {
                                     if !messages.is_empty() {
                                         let diagnostics = DiagnosticService::wrap_diagnostics(
@@ -640,7 +796,7 @@ impl Runtime {
                             return;
                         }
 
-                        let (mut messages, disable_directives) =
+                        let (messages, disable_directives, read_files) =
                             me.linter.run_with_disable_directives(
                                 path,
                                 context_sub_hosts,
@@ -656,46 +812,113 @@ impl Runtime {
                                 .insert(path.to_path_buf(), disable_directives);
                         }
 
-                        if me.linter.options().fix.is_some() {
-                            let fix_result = Fixer::new(
-                                dep.source_text,
-                                messages,
-                                SourceType::from_path(path).ok().map(|st| {
-                                    if st.is_javascript() { st.with_jsx(true) } else { st }
-                                }),
-                            )
-                            .fix();
-                            if fix_result.fixed {
-                                // write to file, replacing only the changed part
-                                let start = 0;
-                                let end = start + dep.source_text.len();
-                                new_source_text
-                                    .to_mut()
-                                    .replace_range(start..end, &fix_result.fixed_code);
-                            }
-                            messages = fix_result.messages;
-                        }
-
-                        if !messages.is_empty() {
-                            let errors = messages.into_iter().map(Into::into).collect();
-                            let diagnostics = DiagnosticService::wrap_diagnostics(
-                                &me.cwd,
+                        if let Some(cache) = &me.cache
+                            && let Some((content_hash, config_hash, dependencies)) = cache_key
+                        {
+                            cache.insert(
                                 path,
-                                dep.source_text,
-                                errors,
+                                content_hash,
+                                config_hash,
+                                dependencies,
+                                read_files,
+                                &messages,
                             );
-                            tx_error.send(diagnostics).unwrap();
                         }
 
-                        // If the new source text is owned, that means it was modified,
-                        // so we write the new source text to the file.
-                        if let Cow::Owned(new_source_text) = &new_source_text {
-                            file_system.write_file(path, new_source_text).unwrap();
-                        }
+                        me.report_messages(file_system, path, dep.source_text, messages, tx_error);
                     });
                 },
             );
         });
+
+        if let Some(cache) = &self.cache
+            && let Err(error) = cache.save()
+        {
+            tx_error.send(vec![Error::new(error)]).unwrap();
+        }
+    }
+
+    /// Apply fixes of `messages` if fixing is enabled, and report the remaining messages.
+    fn report_messages(
+        &self,
+        file_system: &(dyn RuntimeFileSystem + Sync + Send),
+        path: &Path,
+        source_text: &str,
+        mut messages: Vec<Message>,
+        tx_error: &DiagnosticSender,
+    ) {
+        // If there are fixes, we will accumulate all of them and write to the file at the end.
+        // This means we do not write multiple times to the same file if there are multiple sources
+        // in the same file (for example, multiple scripts in an `.astro` file).
+        let mut new_source_text = Cow::from(source_text);
+
+        if self.linter.options().fix.is_some() && !self.fix_dry_run {
+            let fix_result = Fixer::new(
+                source_text,
+                messages,
+                SourceType::from_path(path)
+                    .ok()
+                    .map(|st| if st.is_javascript() { st.with_jsx(true) } else { st }),
+            )
+            .fix();
+            if fix_result.fixed {
+                // write to file, replacing only the changed part
+                let start = 0;
+                let end = start + source_text.len();
+                new_source_text.to_mut().replace_range(start..end, &fix_result.fixed_code);
+            }
+            messages = fix_result.messages;
+        }
+
+        if !messages.is_empty() {
+            let errors = if self.report_fixes {
+                messages.into_iter().map(Message::into_diagnostic_with_fixes).collect()
+            } else {
+                messages.into_iter().map(Into::into).collect()
+            };
+            let diagnostics =
+                DiagnosticService::wrap_diagnostics(&self.cwd, path, source_text, errors);
+            tx_error.send(diagnostics).unwrap();
+        }
+
+        // If the new source text is owned, that means it was modified,
+        // so we write the new source text to the file.
+        if let Cow::Owned(new_source_text) = &new_source_text {
+            file_system.write_file(path, new_source_text).unwrap();
+        }
+    }
+
+    /// Collect all modules imported by the module at `path`, directly or indirectly,
+    /// with hashes of their source text. Sorted by path.
+    fn collect_dependencies(
+        cache: &LintCache,
+        path: &Path,
+        section_module_records: &[Result<Arc<ModuleRecord>, Vec<OxcDiagnostic>>],
+    ) -> Vec<(PathBuf, u64)> {
+        let mut visited = FxHashSet::<PathBuf>::default();
+        let mut stack = section_module_records
+            .iter()
+            .filter_map(|record| record.as_ref().ok().map(Arc::clone))
+            .collect::<Vec<_>>();
+        while let Some(record) = stack.pop() {
+            for dependency in record.loaded_modules().values() {
+                let Some(dependency) = dependency.upgrade() else { continue };
+                if dependency.resolved_absolute_path != path
+                    && visited.insert(dependency.resolved_absolute_path.clone())
+                {
+                    stack.push(dependency);
+                }
+            }
+        }
+        let mut dependencies = visited
+            .into_iter()
+            .map(|path| {
+                let hash = cache.content_hash(&path).unwrap_or_default();
+                (path, hash)
+            })
+            .collect::<Vec<_>>();
+        dependencies.sort_unstable();
+        dependencies
     }
 
     // language_server: the language server needs line and character position
@@ -718,10 +941,11 @@ impl Runtime {
                 &paths_set,
                 scope,
                 true,
+                false,
                 None,
                 |me, mut module_to_lint| {
                     module_to_lint.content.with_dependent_mut(
-                    |allocator_guard, ModuleContentDependent { source_text: _, section_contents }| {
+                    |allocator_guard, ModuleContentDependent { source_text: _, section_contents, .. }| {
                         assert_eq!(
                             module_to_lint.section_module_records.len(),
                             section_contents.len()
@@ -738,7 +962,7 @@ impl Runtime {
                                         Arc::clone(&module_record),
                                         section.source.start,
                                         section.source.framework_options,
-                                    ))
+                                    ).with_template_symbols(section.template_symbols))
                                 }
                                 Err(diagnostics) => {
                                     if !diagnostics.is_empty() {
@@ -759,7 +983,7 @@ impl Runtime {
 
                         let path = Path::new(&module_to_lint.path);
 
-                        let (section_messages, disable_directives) = me
+                        let (section_messages, disable_directives, _) = me
                             .linter
                             .run_with_disable_directives(path, context_sub_hosts, allocator_guard, me.js_allocator_pool());
 
@@ -797,9 +1021,9 @@ impl Runtime {
 
         let messages = Mutex::new(Vec::<Message>::new());
         rayon::scope(|scope| {
-            self.resolve_modules(file_system, &paths_set, scope, check_syntax_errors, Some(tx_error), |me, mut module| {
+            self.resolve_modules(file_system, &paths_set, scope, check_syntax_errors, false, Some(tx_error), |me, mut module| {
                 module.content.with_dependent_mut(
-                    |allocator_guard, ModuleContentDependent { source_text: _, section_contents }| {
+                    |allocator_guard, ModuleContentDependent { source_text: _, section_contents, .. }| {
                         assert_eq!(module.section_module_records.len(), section_contents.len());
 
                         let context_sub_hosts: Vec<ContextSubHost<'_>> = module
@@ -812,7 +1036,7 @@ impl Runtime {
                                     Arc::clone(&module_record),
                                     section.source.start,
                                     section.source.framework_options
-                                )),
+                                ).with_template_symbols(section.template_symbols)),
                                 Err(errors) => {
                                     if !errors.is_empty() {
                                         messages
@@ -903,6 +1127,27 @@ impl Runtime {
                     }
                 };
 
+                if let Some(cache) = &self.cache {
+                    let path = Path::new(path);
+                    let content_hash = cache.insert_content_hash(path, source_text);
+                    // Without cross-module rules, lint results only depend on the file itself,
+                    // so cached results can be used without parsing the file.
+                    if self.resolver.is_none()
+                        && let Some(messages) = cache.get(
+                            path,
+                            content_hash,
+                            cache.config_hash(&self.linter.config, path),
+                            &[],
+                        )
+                    {
+                        return Ok(ModuleContentDependent {
+                            source_text,
+                            section_contents: SmallVec::new(),
+                            cached_messages: Some(messages),
+                        });
+                    }
+                }
+
                 let mut section_contents = SmallVec::new();
                 records = self.process_source(
                     Path::new(path),
@@ -914,7 +1159,7 @@ impl Runtime {
                     Some(&mut section_contents),
                 );
 
-                Ok(ModuleContentDependent { source_text, section_contents })
+                Ok(ModuleContentDependent { source_text, section_contents, cached_messages: None })
             });
             let module_content = module_content.ok()?;
 
@@ -934,6 +1179,10 @@ impl Runtime {
                 }
             };
 
+            if let Some(cache) = &self.cache {
+                cache.insert_content_hash(Path::new(path), source_text);
+            }
+
             let records = self.process_source(
                 Path::new(path),
                 ext,
@@ -961,6 +1210,10 @@ impl Runtime {
     ) -> SmallVec<[Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1]> {
         let section_sources = PartialLoader::parse(ext, source_text)
             .unwrap_or_else(|| vec![JavaScriptSource::partial(source_text, source_type, 0)]);
+        let template_references = out_sections
+            .is_some()
+            .then(|| PartialLoader::parse_template_references(ext, source_text))
+            .flatten();
 
         let mut section_module_records = SmallVec::<
             [Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1],
@@ -976,9 +1229,16 @@ impl Runtime {
                 Ok((record, semantic)) => {
                     section_module_records.push(Ok(record));
                     if let Some(sections) = &mut out_sections {
+                        let template_symbols = template_references
+                            .as_ref()
+                            .map(|references| {
+                                template_symbols(&semantic, references, ext == "svelte")
+                            })
+                            .unwrap_or_default();
                         sections.push(SectionContent {
                             source: section_source,
                             semantic: Some(semantic),
+                            template_symbols,
                         });
                     }
                 }
@@ -999,7 +1259,11 @@ impl Runtime {
 
                     section_module_records.push(Err(err));
                     if let Some(sections) = &mut out_sections {
-                        sections.push(SectionContent { source: section_source, semantic: None });
+                        sections.push(SectionContent {
+                            source: section_source,
+                            semantic: None,
+                            template_symbols: FxHashSet::default(),
+                        });
                     }
                 }
             }
@@ -1015,22 +1279,50 @@ impl Runtime {
         source_type: SourceType,
         check_syntax_errors: bool,
     ) -> Result<(ResolvedModuleRecord, Semantic<'a>), Vec<OxcDiagnostic>> {
-        let ret = Parser::new(allocator, source_text, source_type)
-            .with_options(ParseOptions {
-                parse_regular_expression: true,
-                allow_return_outside_function: true,
-                ..ParseOptions::default()
-            })
-            .parse();
+        let mut timing = self.linter.timings().map(|_| FileTiming::default());
+        let result = self.process_source_section_timed(
+            path,
+            allocator,
+            source_text,
+            source_type,
+            check_syntax_errors,
+            timing.as_mut(),
+        );
+        if let (Some(timings), Some(timing)) = (self.linter.timings(), timing) {
+            timings.add_file(path, timing);
+        }
+        result
+    }
+
+    fn process_source_section_timed<'a>(
+        &self,
+        path: &Path,
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+        check_syntax_errors: bool,
+        mut timing: Option<&mut FileTiming>,
+    ) -> Result<(ResolvedModuleRecord, Semantic<'a>), Vec<OxcDiagnostic>> {
+        let ret = timed(timing.as_mut().map(|timing| &mut timing.parse), || {
+            Parser::new(allocator, source_text, source_type)
+                .with_options(ParseOptions {
+                    parse_regular_expression: true,
+                    allow_return_outside_function: true,
+                    ..ParseOptions::default()
+                })
+                .parse()
+        });
 
         if !ret.errors.is_empty() {
             return Err(if ret.is_flow_language { vec![] } else { ret.errors });
         }
 
-        let semantic_ret = SemanticBuilder::new()
-            .with_cfg(true)
-            .with_check_syntax_error(check_syntax_errors)
-            .build(allocator.alloc(ret.program));
+        let semantic_ret = timed(timing.as_mut().map(|timing| &mut timing.semantic), || {
+            SemanticBuilder::new()
+                .with_cfg(true)
+                .with_check_syntax_error(check_syntax_errors)
+                .build(allocator.alloc(ret.program))
+        });
 
         if !semantic_ret.errors.is_empty() {
             return Err(semantic_ret.errors);
@@ -1039,7 +1331,9 @@ impl Runtime {
         let mut semantic = semantic_ret.semantic;
         semantic.set_irregular_whitespaces(ret.irregular_whitespaces);
 
-        let module_record = Arc::new(ModuleRecord::new(path, &ret.module_record, &semantic));
+        let module_graph_start = timing.is_some().then(Instant::now);
+
+        let mut module_record = ModuleRecord::new(path, &ret.module_record, &semantic);
 
         let mut resolved_module_requests: Vec<ResolvedModuleRequest> = vec![];
 
@@ -1047,18 +1341,86 @@ impl Runtime {
         if let Some(resolver) = &self.resolver {
             // Retrieve all dependent modules from this module.
             let dir = path.parent().unwrap();
-            resolved_module_requests = module_record
+            let resolve = |specifier: &str| match resolver.resolve(dir, specifier) {
+                Ok(resolution) => ModuleResolution::Path(resolution.into_path_buf()),
+                Err(ResolveError::Builtin { .. }) => ModuleResolution::Builtin,
+                Err(ResolveError::Ignored(path)) => ModuleResolution::Path(path),
+                Err(_) => ModuleResolution::Unresolved,
+            };
+            module_record.resolutions = module_record
                 .requested_modules
                 .keys()
-                .filter_map(|specifier| {
-                    let resolution = resolver.resolve(dir, specifier).ok()?;
-                    Some(ResolvedModuleRequest {
+                .map(|specifier| (specifier.clone(), resolve(specifier)))
+                .collect();
+            resolved_module_requests = module_record
+                .resolutions
+                .iter()
+                .filter_map(|(specifier, resolution)| match resolution {
+                    ModuleResolution::Path(path) => Some(ResolvedModuleRequest {
                         specifier: specifier.clone(),
-                        resolved_requested_path: Arc::<OsStr>::from(resolution.path().as_os_str()),
-                    })
+                        resolved_requested_path: Arc::<OsStr>::from(path.as_os_str()),
+                    }),
+                    ModuleResolution::Builtin | ModuleResolution::Unresolved => None,
                 })
                 .collect();
+            // CommonJS modules and dynamic imports are resolved for rules, but are not part of the
+            // module graph.
+            if self.resolve_requires {
+                for node in semantic.nodes() {
+                    let specifier = match node.kind() {
+                        AstKind::CallExpression(call_expr) => {
+                            call_expr.common_js_require().map(|specifier| specifier.value)
+                        }
+                        AstKind::ImportExpression(import_expr) if self.track_importers => {
+                            match &import_expr.source {
+                                Expression::StringLiteral(specifier) => Some(specifier.value),
+                                Expression::TemplateLiteral(specifier) => specifier.single_quasi(),
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    };
+                    if let Some(specifier) = specifier
+                        && !module_record.resolutions.contains_key(specifier.as_str())
+                    {
+                        let specifier = CompactStr::from(specifier.as_str());
+                        let resolution = resolve(&specifier);
+                        module_record.resolutions.insert(specifier, resolution);
+                    }
+                }
+            }
+        }
+
+        let module_record = Arc::new(module_record);
+
+        if let (Some(timing), Some(start)) = (timing, module_graph_start) {
+            timing.module_graph += start.elapsed();
         }
+
         Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic))
     }
 }
+
+/// The script bindings used by the template of a Vue or Svelte file, so that rules such as
+/// `no-unused-vars` do not report them. Only bindings in the root scope of a script are visible
+/// to the template.
+fn template_symbols(
+    semantic: &Semantic,
+    references: &TemplateReferences,
+    is_svelte: bool,
+) -> FxHashSet<SymbolId> {
+    let scoping = semantic.scoping();
+    // Svelte subscribes to stores referenced with a `$` prefix, both in the markup and in the
+    // script, e.g. `$count` reads the `count` store.
+    let stores = scoping
+        .root_unresolved_references()
+        .keys()
+        .filter(|_| is_svelte)
+        .filter_map(|name| name.strip_prefix('$'));
+    references
+        .iter()
+        .map(|name| if is_svelte { name.strip_prefix('$').unwrap_or(name) } else { name })
+        .chain(stores)
+        .filter_map(|name| scoping.get_root_binding(name))
+        .collect()
+}
@@ -0,0 +1,166 @@
+//! Time spent in each rule, and in each phase of linting a file.
+//!
+//! Timings are only collected if enabled with [`Linter::with_timings`](crate::Linter::with_timings).
+//! They are cumulative over all threads, so the total may exceed the wall-clock time of the run.
+
+use std::{
+    ops::AddAssign,
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use rustc_hash::FxHashMap;
+
+use crate::rules::RuleEnum;
+
+/// Cumulative time spent in the functions of a rule.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RuleTiming {
+    /// Time spent in `Rule::run`, for all AST nodes.
+    pub run: Duration,
+    /// Time spent in `Rule::run_once`.
+    pub run_once: Duration,
+    /// Time spent in `Rule::run_on_jest_node`, for all Jest/Vitest call nodes.
+    pub run_on_jest_node: Duration,
+}
+
+impl RuleTiming {
+    pub fn total(&self) -> Duration {
+        self.run + self.run_once + self.run_on_jest_node
+    }
+}
+
+impl AddAssign for RuleTiming {
+    fn add_assign(&mut self, other: Self) {
+        self.run += other.run;
+        self.run_once += other.run_once;
+        self.run_on_jest_node += other.run_on_jest_node;
+    }
+}
+
+/// Time spent in each phase of processing a file. Files with multiple sources (e.g. `.vue` files
+/// with several `<script>` blocks) have the time of all sections added up.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FileTiming {
+    pub parse: Duration,
+    pub semantic: Duration,
+    /// Time spent building the module record and resolving imported modules.
+    pub module_graph: Duration,
+    /// Time spent running rules, including JS plugins.
+    pub lint: Duration,
+}
+
+impl FileTiming {
+    pub fn total(&self) -> Duration {
+        self.parse + self.semantic + self.module_graph + self.lint
+    }
+}
+
+impl AddAssign for FileTiming {
+    fn add_assign(&mut self, other: Self) {
+        self.parse += other.parse;
+        self.semantic += other.semantic;
+        self.module_graph += other.module_graph;
+        self.lint += other.lint;
+    }
+}
+
+/// Timing of a rule, identified by its plugin and name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleTimingEntry {
+    pub plugin_name: &'static str,
+    pub rule_name: &'static str,
+    pub timing: RuleTiming,
+}
+
+/// Timings collected while linting. Shared between threads.
+#[derive(Debug, Default)]
+pub struct LintTimings {
+    /// Keyed by `RuleEnum::id`.
+    rules: Mutex<FxHashMap<usize, RuleTimingEntry>>,
+    files: Mutex<FxHashMap<PathBuf, FileTiming>>,
+}
+
+impl LintTimings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_rules<'r>(&self, timings: impl IntoIterator<Item = (&'r RuleEnum, RuleTiming)>) {
+        let mut rules = self.rules.lock().unwrap_or_else(PoisonError::into_inner);
+        for (rule, timing) in timings {
+            rules
+                .entry(rule.id())
+                .or_insert_with(|| RuleTimingEntry {
+                    plugin_name: rule.plugin_name(),
+                    rule_name: rule.name(),
+                    timing: RuleTiming::default(),
+                })
+                .timing += timing;
+        }
+    }
+
+    pub fn add_file(&self, path: &Path, timing: FileTiming) {
+        let mut files = self.files.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(file) = files.get_mut(path) {
+            *file += timing;
+        } else {
+            files.insert(path.to_path_buf(), timing);
+        }
+    }
+
+    /// Timings of all rules which ran, slowest first.
+    pub fn rules(&self) -> Vec<RuleTimingEntry> {
+        let mut rules = self
+            .rules
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .copied()
+            .collect::<Vec<_>>();
+        rules.sort_unstable_by(|a, b| {
+            b.timing
+                .total()
+                .cmp(&a.timing.total())
+                .then_with(|| (a.plugin_name, a.rule_name).cmp(&(b.plugin_name, b.rule_name)))
+        });
+        rules
+    }
+
+    /// Timings of all processed files, slowest first.
+    pub fn files(&self) -> Vec<(PathBuf, FileTiming)> {
+        let mut files = self
+            .files
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(path, timing)| (path.clone(), *timing))
+            .collect::<Vec<_>>();
+        files.sort_unstable_by(|a, b| b.1.total().cmp(&a.1.total()).then_with(|| a.0.cmp(&b.0)));
+        files
+    }
+
+    /// Time spent in each phase, over all files.
+    pub fn phases(&self) -> FileTiming {
+        let mut total = FileTiming::default();
+        for timing in self.files.lock().unwrap_or_else(PoisonError::into_inner).values() {
+            total += *timing;
+        }
+        total
+    }
+}
+
+/// Run `f`, adding the time it took to `duration` if it is `Some`.
+#[inline]
+pub fn timed<T>(duration: Option<&mut Duration>, f: impl FnOnce() -> T) -> T {
+    match duration {
+        Some(duration) => {
+            let start = Instant::now();
+            let result = f();
+            *duration += start.elapsed();
+            result
+        }
+        None => f(),
+    }
+}
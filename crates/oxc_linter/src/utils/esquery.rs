@@ -0,0 +1,688 @@
+//! [ESQuery](https://github.com/estools/esquery) selectors over oxc's AST.
+//!
+//! Selectors are matched against an ESTree view of the AST (see [`estree`]), so a selector written
+//! for ESLint's `no-restricted-syntax` behaves the same here.
+//!
+//! Supported syntax:
+//! * node types (`Identifier`) and the wildcard (`*`)
+//! * attributes: `[attr]`, `[attr.path="value"]`, `[attr!=value]`, `[attr=/regex/i]`,
+//!   `[attr=type(string)]`, `[attr>1]`
+//! * fields: `.callee.object`
+//! * combinators: descendant (`A B`), child (`A > B`), sibling (`A ~ B`), adjacent (`A + B`)
+//! * pseudo-selectors: `:not()`, `:matches()`, `:is()`, `:has()`, `:first-child`, `:last-child`,
+//!   `:nth-child(n)`, `:nth-last-child(n)`, and the classes `:statement`, `:expression`,
+//!   `:declaration`, `:function` and `:pattern`
+
+mod estree;
+
+use cow_utils::CowUtils;
+use lazy_regex::{Regex, RegexBuilder};
+use oxc_allocator::{Address, GetAddress};
+use oxc_ast::AstKind;
+use oxc_semantic::AstNodes;
+use oxc_syntax::node::NodeId;
+use rustc_hash::FxHashMap;
+
+use self::estree::Value;
+
+/// A parsed ESQuery selector.
+#[derive(Debug, Clone)]
+pub struct EsQuery(Selector);
+
+#[derive(Debug, Clone)]
+enum Selector {
+    Wildcard,
+    Type(String),
+    Attribute {
+        path: Vec<String>,
+        test: Option<(Operator, AttributeValue)>,
+    },
+    Field(Vec<String>),
+    Compound(Vec<Selector>),
+    Matches(Vec<Selector>),
+    Not(Vec<Selector>),
+    Has(Vec<Selector>),
+    NthChild(usize),
+    NthLastChild(usize),
+    Class(Class),
+    Descendant(Box<Selector>, Box<Selector>),
+    Child(Box<Selector>, Box<Selector>),
+    Sibling(Box<Selector>, Box<Selector>),
+    Adjacent(Box<Selector>, Box<Selector>),
+    /// The node a `:has()` selector is evaluated for.
+    Scope,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+#[derive(Debug, Clone)]
+enum AttributeValue {
+    String(String),
+    Number(f64),
+    Regex(Regex),
+    Type(String),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Class {
+    Statement,
+    Expression,
+    Declaration,
+    Function,
+    Pattern,
+}
+
+impl EsQuery {
+    /// Parse a selector such as `CallExpression[callee.name="eval"]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the problem if `selector` is not a valid selector.
+    pub fn parse(selector: &str) -> Result<Self, String> {
+        let mut parser = Parser { source: selector, pos: 0 };
+        let selectors = parser.parse_selectors(false)?;
+        if let Some(c) = parser.peek() {
+            return Err(parser.unexpected(c));
+        }
+        Ok(Self(into_matches(selectors)))
+    }
+
+    /// Whether the node matches this selector.
+    pub fn matches(&self, ctx: &QueryContext, node_id: NodeId) -> bool {
+        ctx.estree_type(node_id).is_some() && ctx.matches(&self.0, node_id, None)
+    }
+}
+
+/// State shared by all selectors matched against one file.
+pub struct QueryContext<'a, 'n> {
+    nodes: &'n AstNodes<'a>,
+    source_text: &'a str,
+    addresses: FxHashMap<Address, NodeId>,
+}
+
+impl<'a, 'n> QueryContext<'a, 'n> {
+    pub fn new(nodes: &'n AstNodes<'a>, source_text: &'a str) -> Self {
+        let mut addresses = FxHashMap::default();
+        addresses.reserve(nodes.len());
+        for node in nodes.iter() {
+            // Keep the outermost node if a node shares its address with its first child.
+            addresses.entry(node.kind().address()).or_insert(node.id());
+        }
+        Self { nodes, source_text, addresses }
+    }
+
+    fn estree_type(&self, id: NodeId) -> Option<&'static str> {
+        estree::node_type(self.nodes, id)
+    }
+
+    /// The nearest ancestor which exists in ESTree.
+    fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes.ancestor_ids(id).find(|&ancestor| self.estree_type(ancestor).is_some())
+    }
+
+    fn fields(&self, id: NodeId) -> Vec<(&'static str, Value<'a>)> {
+        estree::fields(self.nodes.kind(id), self.source_text)
+    }
+
+    fn resolve(&self, address: Address) -> Option<NodeId> {
+        let mut id = *self.addresses.get(&address)?;
+        while estree::is_wrapper(self.nodes.kind(id)) {
+            id = NodeId::new(id.index() + 1);
+        }
+        Some(id)
+    }
+
+    fn attribute(&self, id: NodeId, path: &[String]) -> Option<Value<'a>> {
+        let (first, rest) = path.split_first()?;
+        let mut value = self.field(id, first)?;
+        for key in rest {
+            value = match value {
+                Value::Node(address) => self.field(self.resolve(address)?, key)?,
+                Value::Object(fields) => fields.into_iter().find(|(k, _)| k == key)?.1,
+                Value::Nodes(nodes) if key == "length" =>
+                {
+                    #[expect(clippy::cast_precision_loss)]
+                    Value::Number(nodes.len() as f64)
+                }
+                _ => return None,
+            };
+        }
+        Some(value)
+    }
+
+    fn field(&self, id: NodeId, key: &str) -> Option<Value<'a>> {
+        if key == "type" {
+            return self.estree_type(id).map(Value::String);
+        }
+        self.fields(id).into_iter().find(|(k, _)| *k == key).map(|(_, value)| value)
+    }
+
+    /// The property of the ESTree parent which holds this node, and the node's index if that
+    /// property is a list.
+    fn key_in_parent(&self, id: NodeId) -> Option<(NodeId, &'static str, Option<usize>)> {
+        let parent = self.parent(id)?;
+        self.fields(parent).into_iter().find_map(|(key, value)| match value {
+            Value::Node(address) => {
+                (self.resolve(address) == Some(id)).then_some((parent, key, None))
+            }
+            Value::Nodes(nodes) => nodes
+                .iter()
+                .position(|node| node.and_then(|address| self.resolve(address)) == Some(id))
+                .map(|index| (parent, key, Some(index))),
+            _ => None,
+        })
+    }
+
+    /// Nodes in the same list property of the parent, and this node's index among them.
+    fn siblings(&self, id: NodeId) -> Option<(Vec<Option<NodeId>>, usize)> {
+        let (parent, key, index) = self.key_in_parent(id)?;
+        let index = index?;
+        let (_, Value::Nodes(nodes)) = self.fields(parent).into_iter().find(|(k, _)| *k == key)?
+        else {
+            return None;
+        };
+        let siblings = nodes.into_iter().map(|node| node.and_then(|a| self.resolve(a))).collect();
+        Some((siblings, index))
+    }
+
+    fn matches(&self, selector: &Selector, id: NodeId, scope: Option<NodeId>) -> bool {
+        match selector {
+            Selector::Wildcard => true,
+            Selector::Type(name) => {
+                self.estree_type(id).is_some_and(|ty| ty.eq_ignore_ascii_case(name))
+            }
+            Selector::Attribute { path, test } => {
+                let value = self.attribute(id, path);
+                match test {
+                    None => !matches!(value, None | Some(Value::Null)),
+                    Some((operator, expected)) => {
+                        test_attribute(value.as_ref(), *operator, expected)
+                    }
+                }
+            }
+            Selector::Field(path) => {
+                let mut current = id;
+                for name in path.iter().rev() {
+                    match self.key_in_parent(current) {
+                        Some((parent, key, _)) if key == name => current = parent,
+                        _ => return false,
+                    }
+                }
+                true
+            }
+            Selector::Compound(selectors) => {
+                selectors.iter().all(|selector| self.matches(selector, id, scope))
+            }
+            Selector::Matches(selectors) => {
+                selectors.iter().any(|selector| self.matches(selector, id, scope))
+            }
+            Selector::Not(selectors) => {
+                !selectors.iter().any(|selector| self.matches(selector, id, scope))
+            }
+            Selector::Has(selectors) => self.descendants(id).any(|descendant| {
+                self.estree_type(descendant).is_some()
+                    && selectors.iter().any(|selector| self.matches(selector, descendant, Some(id)))
+            }),
+            Selector::NthChild(n) => self.siblings(id).is_some_and(|(_, index)| index + 1 == *n),
+            Selector::NthLastChild(n) => {
+                self.siblings(id).is_some_and(|(siblings, index)| siblings.len() - index == *n)
+            }
+            Selector::Class(class) => {
+                self.estree_type(id).is_some_and(|ty| self.is_class(ty, id, *class))
+            }
+            Selector::Descendant(ancestor, selector) => {
+                self.matches(selector, id, scope) && {
+                    let mut current = self.parent(id);
+                    loop {
+                        match current {
+                            Some(parent) if self.matches(ancestor, parent, scope) => break true,
+                            Some(parent) => current = self.parent(parent),
+                            None => break false,
+                        }
+                    }
+                }
+            }
+            Selector::Child(parent, selector) => {
+                self.matches(selector, id, scope)
+                    && self.parent(id).is_some_and(|p| self.matches(parent, p, scope))
+            }
+            Selector::Sibling(sibling, selector) => {
+                self.matches(selector, id, scope)
+                    && self.siblings(id).is_some_and(|(siblings, index)| {
+                        siblings[..index]
+                            .iter()
+                            .flatten()
+                            .any(|&sibling_id| self.matches(sibling, sibling_id, scope))
+                    })
+            }
+            Selector::Adjacent(sibling, selector) => {
+                self.matches(selector, id, scope)
+                    && self.siblings(id).is_some_and(|(siblings, index)| {
+                        index > 0
+                            && siblings[index - 1]
+                                .is_some_and(|sibling_id| self.matches(sibling, sibling_id, scope))
+                    })
+            }
+            Selector::Scope => scope == Some(id),
+        }
+    }
+
+    fn is_class(&self, ty: &str, id: NodeId, class: Class) -> bool {
+        match class {
+            Class::Statement => ty.ends_with("Statement") || ty.ends_with("Declaration"),
+            Class::Declaration => ty.ends_with("Declaration"),
+            Class::Function => matches!(
+                ty,
+                "FunctionDeclaration" | "FunctionExpression" | "ArrowFunctionExpression"
+            ),
+            Class::Expression => self.is_expression(ty, id),
+            Class::Pattern => ty.ends_with("Pattern") || self.is_expression(ty, id),
+        }
+    }
+
+    fn is_expression(&self, ty: &str, id: NodeId) -> bool {
+        ty.ends_with("Expression")
+            || ty.ends_with("Literal")
+            || ty == "MetaProperty"
+            || (ty == "Identifier"
+                && !self.parent(id).is_some_and(|parent| {
+                    matches!(self.nodes.kind(parent), AstKind::MetaProperty(_))
+                }))
+    }
+
+    /// All nodes below `id`. Node ids are assigned in pre-order, so a subtree is a contiguous
+    /// range of ids.
+    fn descendants(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        (id.index() + 1..self.nodes.len()).map(NodeId::new).take_while(move |&descendant| {
+            self.nodes.ancestor_ids(descendant).find(|ancestor| ancestor.index() <= id.index())
+                == Some(id)
+        })
+    }
+}
+
+fn test_attribute(value: Option<&Value>, operator: Operator, expected: &AttributeValue) -> bool {
+    let equality = matches!(operator, Operator::Eq | Operator::NotEq);
+    let ordering = match (expected, value) {
+        (AttributeValue::Type(ty), _) => {
+            let actual = match value {
+                None => "undefined",
+                Some(Value::String(_)) => "string",
+                Some(Value::Number(_)) => "number",
+                Some(Value::Bool(_)) => "boolean",
+                Some(Value::Node(_) | Value::Nodes(_) | Value::Object(_) | Value::Null) => "object",
+            };
+            return (actual == ty) == (operator == Operator::Eq);
+        }
+        (AttributeValue::Regex(regex), _) => {
+            let matched = matches!(value, Some(Value::String(s)) if regex.is_match(s));
+            return matched == (operator == Operator::Eq);
+        }
+        (AttributeValue::String(expected), _) if equality => {
+            let equal = stringify(value).is_some_and(|actual| actual == *expected);
+            return equal == (operator == Operator::Eq);
+        }
+        (AttributeValue::Number(expected), _) if equality => {
+            let equal = stringify(value).is_some_and(|actual| actual == expected.to_string());
+            return equal == (operator == Operator::Eq);
+        }
+        (AttributeValue::String(expected), Some(Value::String(actual))) => {
+            Some((*actual).cmp(expected.as_str()))
+        }
+        (AttributeValue::Number(expected), Some(Value::Number(actual))) => {
+            actual.partial_cmp(expected)
+        }
+        _ => None,
+    };
+    ordering.is_some_and(|ordering| match operator {
+        Operator::Lt => ordering.is_lt(),
+        Operator::LtEq => ordering.is_le(),
+        Operator::Gt => ordering.is_gt(),
+        Operator::GtEq => ordering.is_ge(),
+        Operator::Eq | Operator::NotEq => false,
+    })
+}
+
+/// Converts a property to a string the way JavaScript's template literals would.
+fn stringify(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(s) => Some((*s).to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Null => Some("null".to_string()),
+        Value::Node(_) | Value::Nodes(_) | Value::Object(_) => None,
+    }
+}
+
+fn into_matches(mut selectors: Vec<Selector>) -> Selector {
+    if selectors.len() == 1 { selectors.pop().unwrap() } else { Selector::Matches(selectors) }
+}
+
+struct Parser<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let eaten = self.peek() == Some(c);
+        if eaten {
+            self.bump();
+        }
+        eaten
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.eat(c) {
+            return Ok(());
+        }
+        Err(match self.peek() {
+            Some(found) => format!("expected `{c}` but found `{found}` at position {}", self.pos),
+            None => format!("expected `{c}` but reached the end of the selector"),
+        })
+    }
+
+    fn unexpected(&self, c: char) -> String {
+        format!("unexpected `{c}` at position {}", self.pos)
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+        self.pos > start
+    }
+
+    /// Comma-separated selectors. In a `relative` list (the argument of `:has()`) each selector
+    /// may start with a combinator.
+    fn parse_selectors(&mut self, relative: bool) -> Result<Vec<Selector>, String> {
+        let mut selectors = vec![self.parse_selector(relative)?];
+        while self.eat(',') {
+            selectors.push(self.parse_selector(relative)?);
+        }
+        Ok(selectors)
+    }
+
+    fn parse_selector(&mut self, relative: bool) -> Result<Selector, String> {
+        self.skip_whitespace();
+        let mut left = if relative { Selector::Scope } else { self.parse_sequence()? };
+        let mut implicit_descendant = relative;
+        loop {
+            let whitespace = self.skip_whitespace() || implicit_descendant;
+            implicit_descendant = false;
+            let combinator = match self.peek() {
+                Some(c @ ('>' | '~' | '+')) => {
+                    self.bump();
+                    self.skip_whitespace();
+                    c
+                }
+                Some(',' | ')') | None => break,
+                Some(_) if whitespace => ' ',
+                Some(c) => return Err(self.unexpected(c)),
+            };
+            let right = Box::new(self.parse_sequence()?);
+            let left_box = Box::new(left);
+            left = match combinator {
+                '>' => Selector::Child(left_box, right),
+                '~' => Selector::Sibling(left_box, right),
+                '+' => Selector::Adjacent(left_box, right),
+                _ => Selector::Descendant(left_box, right),
+            };
+        }
+        if matches!(left, Selector::Scope) {
+            return Err(match self.peek() {
+                Some(c) => self.unexpected(c),
+                None => "expected a selector but reached the end of the selector".to_string(),
+            });
+        }
+        Ok(left)
+    }
+
+    fn parse_sequence(&mut self) -> Result<Selector, String> {
+        let mut selectors = vec![];
+        while let Some(c) = self.peek() {
+            let selector = match c {
+                '*' => {
+                    self.bump();
+                    Selector::Wildcard
+                }
+                '[' => self.parse_attribute()?,
+                '.' => {
+                    self.bump();
+                    Selector::Field(self.parse_path()?)
+                }
+                ':' => self.parse_pseudo()?,
+                c if is_name_char(c) => Selector::Type(self.parse_name()?.to_string()),
+                _ => break,
+            };
+            selectors.push(selector);
+        }
+        match selectors.len() {
+            0 => Err(match self.peek() {
+                Some(c) => self.unexpected(c),
+                None => "expected a selector but reached the end of the selector".to_string(),
+            }),
+            1 => Ok(selectors.pop().unwrap()),
+            _ => Ok(Selector::Compound(selectors)),
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<&str, String> {
+        let start = self.pos;
+        while self.peek().is_some_and(is_name_char) {
+            self.bump();
+        }
+        if self.pos == start {
+            return Err(match self.peek() {
+                Some(c) => format!("expected a name but found `{c}` at position {start}"),
+                None => "expected a name but reached the end of the selector".to_string(),
+            });
+        }
+        Ok(&self.source[start..self.pos])
+    }
+
+    fn parse_path(&mut self) -> Result<Vec<String>, String> {
+        let mut path = vec![self.parse_name()?.to_string()];
+        while self.eat('.') {
+            path.push(self.parse_name()?.to_string());
+        }
+        Ok(path)
+    }
+
+    fn parse_attribute(&mut self) -> Result<Selector, String> {
+        self.expect('[')?;
+        self.skip_whitespace();
+        let path = self.parse_path()?;
+        self.skip_whitespace();
+        let operator = match self.peek() {
+            Some('=') => Some(Operator::Eq),
+            Some('!') => {
+                self.bump();
+                if self.peek() != Some('=') {
+                    return Err(self.unexpected(self.peek().unwrap_or('!')));
+                }
+                Some(Operator::NotEq)
+            }
+            Some('<') => {
+                self.bump();
+                Some(if self.peek() == Some('=') { Operator::LtEq } else { Operator::Lt })
+            }
+            Some('>') => {
+                self.bump();
+                Some(if self.peek() == Some('=') { Operator::GtEq } else { Operator::Gt })
+            }
+            _ => None,
+        };
+        let test = match operator {
+            Some(operator) => {
+                if matches!(
+                    operator,
+                    Operator::Eq | Operator::NotEq | Operator::LtEq | Operator::GtEq
+                ) {
+                    self.expect('=')?;
+                }
+                self.skip_whitespace();
+                let value = self.parse_attribute_value()?;
+                if matches!(value, AttributeValue::Regex(_) | AttributeValue::Type(_))
+                    && !matches!(operator, Operator::Eq | Operator::NotEq)
+                {
+                    return Err(format!(
+                        "regex and type values only support `=` and `!=` (at position {})",
+                        self.pos
+                    ));
+                }
+                self.skip_whitespace();
+                Some((operator, value))
+            }
+            None => None,
+        };
+        self.expect(']')?;
+        Ok(Selector::Attribute { path, test })
+    }
+
+    fn parse_attribute_value(&mut self) -> Result<AttributeValue, String> {
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.bump();
+                let mut value = String::new();
+                loop {
+                    match self.peek() {
+                        Some('\\') => {
+                            self.bump();
+                            match self.peek() {
+                                Some(c) => value.push(c),
+                                None => break,
+                            }
+                        }
+                        Some(c) if c == quote => break,
+                        Some(c) => value.push(c),
+                        None => break,
+                    }
+                    self.bump();
+                }
+                self.expect(quote)?;
+                Ok(AttributeValue::String(value))
+            }
+            Some('/') => {
+                self.bump();
+                let start = self.pos;
+                let mut in_class = false;
+                loop {
+                    match self.peek() {
+                        Some('\\') => self.bump(),
+                        Some('[') => in_class = true,
+                        Some(']') => in_class = false,
+                        Some('/') if !in_class => break,
+                        Some(_) => {}
+                        None => return Err("unterminated regular expression".to_string()),
+                    }
+                    self.bump();
+                }
+                let pattern = &self.source[start..self.pos];
+                self.bump();
+                let mut builder = RegexBuilder::new(pattern);
+                while let Some(flag @ ('i' | 'm' | 's' | 'u')) = self.peek() {
+                    match flag {
+                        'i' => builder.case_insensitive(true),
+                        'm' => builder.multi_line(true),
+                        's' => builder.dot_matches_new_line(true),
+                        _ => &mut builder,
+                    };
+                    self.bump();
+                }
+                builder
+                    .build()
+                    .map(AttributeValue::Regex)
+                    .map_err(|err| format!("invalid regular expression /{pattern}/: {err}"))
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => {
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+                    self.bump();
+                }
+                let number = &self.source[start..self.pos];
+                number
+                    .parse()
+                    .map(AttributeValue::Number)
+                    .map_err(|_| format!("invalid number `{number}` at position {start}"))
+            }
+            _ => {
+                let start = self.pos;
+                let name = self.parse_name()?;
+                if name == "type" && self.eat('(') {
+                    self.skip_whitespace();
+                    let ty = self.parse_name()?.to_string();
+                    self.skip_whitespace();
+                    self.expect(')')?;
+                    return Ok(AttributeValue::Type(ty));
+                }
+                self.pos = start;
+                Ok(AttributeValue::String(self.parse_path()?.join(".")))
+            }
+        }
+    }
+
+    fn parse_pseudo(&mut self) -> Result<Selector, String> {
+        self.expect(':')?;
+        let start = self.pos;
+        let name = self.parse_name()?.cow_to_ascii_lowercase().into_owned();
+        let selector = match name.as_str() {
+            "not" | "matches" | "is" | "has" => {
+                self.expect('(')?;
+                let selectors = self.parse_selectors(name == "has")?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                match name.as_str() {
+                    "not" => Selector::Not(selectors),
+                    "has" => Selector::Has(selectors),
+                    _ => Selector::Matches(selectors),
+                }
+            }
+            "nth-child" | "nth-last-child" => {
+                self.expect('(')?;
+                self.skip_whitespace();
+                let digits_start = self.pos;
+                while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.bump();
+                }
+                let n = self.source[digits_start..self.pos]
+                    .parse()
+                    .map_err(|_| format!("expected a number at position {digits_start}"))?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                if name == "nth-child" { Selector::NthChild(n) } else { Selector::NthLastChild(n) }
+            }
+            "first-child" => Selector::NthChild(1),
+            "last-child" => Selector::NthLastChild(1),
+            "statement" => Selector::Class(Class::Statement),
+            "expression" => Selector::Class(Class::Expression),
+            "declaration" => Selector::Class(Class::Declaration),
+            "function" => Selector::Class(Class::Function),
+            "pattern" => Selector::Class(Class::Pattern),
+            _ => return Err(format!("unknown pseudo-selector `:{name}` at position {start}")),
+        };
+        Ok(selector)
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '$' | '-')
+}
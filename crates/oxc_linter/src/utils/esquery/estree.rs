@@ -0,0 +1,1120 @@
+//! ESTree view of oxc's AST, as seen by ESQuery selectors.
+//!
+//! Selectors are written against ESTree node types and property names (`MemberExpression`,
+//! `callee.name`, ...), so this module translates [`AstKind`]s to ESTree type names and exposes
+//! their properties in ESTree shape.
+
+use oxc_allocator::{Address, GetAddress, UnstableAddress};
+use oxc_ast::{
+    AstKind,
+    ast::{
+        AccessorPropertyType, ArrayExpressionElement, ArrowFunctionExpression, ClassType,
+        FormalParameters, FunctionType, ImportAttributeKey, ImportOrExportKind, ImportPhase,
+        JSXExpression, MethodDefinitionKind, MethodDefinitionType, ModuleExportName,
+        PropertyDefinitionType, PropertyKind, TSAccessibility, TSMethodSignatureKind,
+        TSModuleDeclarationName, TSTypePredicateName, WithClause,
+    },
+};
+use oxc_semantic::AstNodes;
+use oxc_span::GetSpan;
+use oxc_syntax::node::NodeId;
+
+/// Value of an ESTree property.
+#[derive(Debug, Clone)]
+pub enum Value<'a> {
+    Node(Address),
+    /// List of nodes. `None` marks a hole, e.g. an elision in `[a, , b]`.
+    Nodes(Vec<Option<Address>>),
+    String(&'a str),
+    Number(f64),
+    Bool(bool),
+    Null,
+    Object(Vec<(&'static str, Value<'a>)>),
+}
+
+impl<'a> Value<'a> {
+    fn node<T: GetAddress>(node: &T) -> Self {
+        Self::Node(node.address())
+    }
+
+    fn inline<T: UnstableAddress>(node: &T) -> Self {
+        // The linter's AST is immutable, so addresses of inline nodes are stable.
+        Self::Node(node.unstable_address())
+    }
+
+    fn opt_node<T: GetAddress>(node: Option<&T>) -> Self {
+        node.map_or(Self::Null, Self::node)
+    }
+
+    fn opt_inline<T: UnstableAddress>(node: Option<&T>) -> Self {
+        node.map_or(Self::Null, Self::inline)
+    }
+
+    fn nodes<'n, T: GetAddress + 'n>(nodes: impl IntoIterator<Item = &'n T>) -> Self {
+        Self::Nodes(nodes.into_iter().map(|node| Some(node.address())).collect())
+    }
+
+    fn inline_nodes<'n, T: UnstableAddress + 'n>(nodes: impl IntoIterator<Item = &'n T>) -> Self {
+        Self::Nodes(nodes.into_iter().map(|node| Some(node.unstable_address())).collect())
+    }
+
+    fn opt_str(s: Option<&'a str>) -> Self {
+        s.map_or(Self::Null, Self::String)
+    }
+}
+
+/// ESTree type of the node, or `None` if the node does not exist in ESTree.
+///
+/// Nodes without an ESTree type (e.g. `ParenthesizedExpression`, `FormalParameters`) are
+/// transparent: selectors never match them, and their children are treated as children of their
+/// nearest ESTree ancestor.
+pub fn node_type(nodes: &AstNodes, id: NodeId) -> Option<&'static str> {
+    let kind = nodes.kind(id);
+    let ty = match kind {
+        AstKind::Program(_) => "Program",
+        AstKind::IdentifierReference(_) => {
+            if id != NodeId::ROOT
+                && matches!(
+                    nodes.parent_kind(id),
+                    AstKind::JSXOpeningElement(_)
+                        | AstKind::JSXClosingElement(_)
+                        | AstKind::JSXMemberExpression(_)
+                )
+            {
+                "JSXIdentifier"
+            } else {
+                "Identifier"
+            }
+        }
+        AstKind::IdentifierName(_)
+        | AstKind::BindingIdentifier(_)
+        | AstKind::LabelIdentifier(_)
+        | AstKind::TSIndexSignatureName(_)
+        | AstKind::TSThisParameter(_) => "Identifier",
+        AstKind::BooleanLiteral(_)
+        | AstKind::NullLiteral(_)
+        | AstKind::NumericLiteral(_)
+        | AstKind::StringLiteral(_)
+        | AstKind::BigIntLiteral(_)
+        | AstKind::RegExpLiteral(_) => "Literal",
+        AstKind::ComputedMemberExpression(_)
+        | AstKind::StaticMemberExpression(_)
+        | AstKind::PrivateFieldExpression(_) => "MemberExpression",
+        AstKind::ObjectProperty(_)
+        | AstKind::BindingProperty(_)
+        | AstKind::AssignmentTargetPropertyIdentifier(_)
+        | AstKind::AssignmentTargetPropertyProperty(_) => "Property",
+        AstKind::ArrayAssignmentTarget(_) | AstKind::ArrayPattern(_) => "ArrayPattern",
+        AstKind::ObjectAssignmentTarget(_) | AstKind::ObjectPattern(_) => "ObjectPattern",
+        AstKind::AssignmentTargetRest(_) | AstKind::BindingRestElement(_) => "RestElement",
+        AstKind::AssignmentTargetWithDefault(_) | AstKind::AssignmentPattern(_) => {
+            "AssignmentPattern"
+        }
+        AstKind::FormalParameter(param) => {
+            param.initializer.as_ref()?;
+            "AssignmentPattern"
+        }
+        AstKind::Function(func) => match func.r#type {
+            FunctionType::FunctionDeclaration => "FunctionDeclaration",
+            FunctionType::FunctionExpression => "FunctionExpression",
+            FunctionType::TSDeclareFunction => "TSDeclareFunction",
+            FunctionType::TSEmptyBodyFunctionExpression => "TSEmptyBodyFunctionExpression",
+        },
+        AstKind::Class(class) => match class.r#type {
+            ClassType::ClassDeclaration => "ClassDeclaration",
+            ClassType::ClassExpression => "ClassExpression",
+        },
+        AstKind::MethodDefinition(method) => match method.r#type {
+            MethodDefinitionType::MethodDefinition => "MethodDefinition",
+            MethodDefinitionType::TSAbstractMethodDefinition => "TSAbstractMethodDefinition",
+        },
+        AstKind::PropertyDefinition(prop) => match prop.r#type {
+            PropertyDefinitionType::PropertyDefinition => "PropertyDefinition",
+            PropertyDefinitionType::TSAbstractPropertyDefinition => "TSAbstractPropertyDefinition",
+        },
+        AstKind::AccessorProperty(prop) => match prop.r#type {
+            AccessorPropertyType::AccessorProperty => "AccessorProperty",
+            AccessorPropertyType::TSAbstractAccessorProperty => "TSAbstractAccessorProperty",
+        },
+        AstKind::FunctionBody(_) => {
+            if is_expression_arrow(nodes.parent_kind(id)) {
+                return None;
+            }
+            "BlockStatement"
+        }
+        AstKind::ExpressionStatement(_) => {
+            let parent = nodes.parent_id(id);
+            if matches!(nodes.kind(parent), AstKind::FunctionBody(_))
+                && is_expression_arrow(nodes.parent_kind(parent))
+            {
+                return None;
+            }
+            "ExpressionStatement"
+        }
+        AstKind::Directive(_) => "ExpressionStatement",
+        AstKind::JSDocNullableType(_) => "TSJSDocNullableType",
+        AstKind::JSDocNonNullableType(_) => "TSJSDocNonNullableType",
+        AstKind::JSDocUnknownType(_) => "TSJSDocUnknownType",
+        AstKind::ParenthesizedExpression(_)
+        | AstKind::TSParenthesizedType(_)
+        | AstKind::FormalParameters(_)
+        | AstKind::FormalParameterRest(_)
+        | AstKind::CatchParameter(_)
+        | AstKind::WithClause(_)
+        | AstKind::Elision(_)
+        | AstKind::Hashbang(_) => return None,
+        AstKind::ThisExpression(_) => "ThisExpression",
+        AstKind::ArrayExpression(_) => "ArrayExpression",
+        AstKind::ObjectExpression(_) => "ObjectExpression",
+        AstKind::TemplateLiteral(_) => "TemplateLiteral",
+        AstKind::TaggedTemplateExpression(_) => "TaggedTemplateExpression",
+        AstKind::TemplateElement(_) => "TemplateElement",
+        AstKind::CallExpression(_) => "CallExpression",
+        AstKind::NewExpression(_) => "NewExpression",
+        AstKind::MetaProperty(_) => "MetaProperty",
+        AstKind::SpreadElement(_) => "SpreadElement",
+        AstKind::UpdateExpression(_) => "UpdateExpression",
+        AstKind::UnaryExpression(_) => "UnaryExpression",
+        AstKind::BinaryExpression(_) | AstKind::PrivateInExpression(_) => "BinaryExpression",
+        AstKind::LogicalExpression(_) => "LogicalExpression",
+        AstKind::ConditionalExpression(_) => "ConditionalExpression",
+        AstKind::AssignmentExpression(_) => "AssignmentExpression",
+        AstKind::SequenceExpression(_) => "SequenceExpression",
+        AstKind::Super(_) => "Super",
+        AstKind::AwaitExpression(_) => "AwaitExpression",
+        AstKind::ChainExpression(_) => "ChainExpression",
+        AstKind::BlockStatement(_) => "BlockStatement",
+        AstKind::VariableDeclaration(_) => "VariableDeclaration",
+        AstKind::VariableDeclarator(_) => "VariableDeclarator",
+        AstKind::EmptyStatement(_) => "EmptyStatement",
+        AstKind::IfStatement(_) => "IfStatement",
+        AstKind::DoWhileStatement(_) => "DoWhileStatement",
+        AstKind::WhileStatement(_) => "WhileStatement",
+        AstKind::ForStatement(_) => "ForStatement",
+        AstKind::ForInStatement(_) => "ForInStatement",
+        AstKind::ForOfStatement(_) => "ForOfStatement",
+        AstKind::ContinueStatement(_) => "ContinueStatement",
+        AstKind::BreakStatement(_) => "BreakStatement",
+        AstKind::ReturnStatement(_) => "ReturnStatement",
+        AstKind::WithStatement(_) => "WithStatement",
+        AstKind::SwitchStatement(_) => "SwitchStatement",
+        AstKind::SwitchCase(_) => "SwitchCase",
+        AstKind::LabeledStatement(_) => "LabeledStatement",
+        AstKind::ThrowStatement(_) => "ThrowStatement",
+        AstKind::TryStatement(_) => "TryStatement",
+        AstKind::CatchClause(_) => "CatchClause",
+        AstKind::DebuggerStatement(_) => "DebuggerStatement",
+        AstKind::ArrowFunctionExpression(_) => "ArrowFunctionExpression",
+        AstKind::YieldExpression(_) => "YieldExpression",
+        AstKind::ClassBody(_) => "ClassBody",
+        AstKind::PrivateIdentifier(_) => "PrivateIdentifier",
+        AstKind::StaticBlock(_) => "StaticBlock",
+        AstKind::ImportExpression(_) => "ImportExpression",
+        AstKind::ImportDeclaration(_) => "ImportDeclaration",
+        AstKind::ImportSpecifier(_) => "ImportSpecifier",
+        AstKind::ImportDefaultSpecifier(_) => "ImportDefaultSpecifier",
+        AstKind::ImportNamespaceSpecifier(_) => "ImportNamespaceSpecifier",
+        AstKind::ImportAttribute(_) => "ImportAttribute",
+        AstKind::ExportNamedDeclaration(_) => "ExportNamedDeclaration",
+        AstKind::ExportDefaultDeclaration(_) => "ExportDefaultDeclaration",
+        AstKind::ExportAllDeclaration(_) => "ExportAllDeclaration",
+        AstKind::ExportSpecifier(_) => "ExportSpecifier",
+        AstKind::V8IntrinsicExpression(_) => "V8IntrinsicExpression",
+        AstKind::JSXElement(_) => "JSXElement",
+        AstKind::JSXOpeningElement(_) => "JSXOpeningElement",
+        AstKind::JSXClosingElement(_) => "JSXClosingElement",
+        AstKind::JSXFragment(_) => "JSXFragment",
+        AstKind::JSXOpeningFragment(_) => "JSXOpeningFragment",
+        AstKind::JSXClosingFragment(_) => "JSXClosingFragment",
+        AstKind::JSXNamespacedName(_) => "JSXNamespacedName",
+        AstKind::JSXMemberExpression(_) => "JSXMemberExpression",
+        AstKind::JSXExpressionContainer(_) => "JSXExpressionContainer",
+        AstKind::JSXEmptyExpression(_) => "JSXEmptyExpression",
+        AstKind::JSXAttribute(_) => "JSXAttribute",
+        AstKind::JSXSpreadAttribute(_) => "JSXSpreadAttribute",
+        AstKind::JSXIdentifier(_) => "JSXIdentifier",
+        AstKind::JSXSpreadChild(_) => "JSXSpreadChild",
+        AstKind::JSXText(_) => "JSXText",
+        AstKind::TSEnumDeclaration(_) => "TSEnumDeclaration",
+        AstKind::TSEnumBody(_) => "TSEnumBody",
+        AstKind::TSEnumMember(_) => "TSEnumMember",
+        AstKind::TSTypeAnnotation(_) => "TSTypeAnnotation",
+        AstKind::TSLiteralType(_) => "TSLiteralType",
+        AstKind::TSConditionalType(_) => "TSConditionalType",
+        AstKind::TSUnionType(_) => "TSUnionType",
+        AstKind::TSIntersectionType(_) => "TSIntersectionType",
+        AstKind::TSTypeOperator(_) => "TSTypeOperator",
+        AstKind::TSArrayType(_) => "TSArrayType",
+        AstKind::TSIndexedAccessType(_) => "TSIndexedAccessType",
+        AstKind::TSTupleType(_) => "TSTupleType",
+        AstKind::TSNamedTupleMember(_) => "TSNamedTupleMember",
+        AstKind::TSOptionalType(_) => "TSOptionalType",
+        AstKind::TSRestType(_) => "TSRestType",
+        AstKind::TSAnyKeyword(_) => "TSAnyKeyword",
+        AstKind::TSStringKeyword(_) => "TSStringKeyword",
+        AstKind::TSBooleanKeyword(_) => "TSBooleanKeyword",
+        AstKind::TSNumberKeyword(_) => "TSNumberKeyword",
+        AstKind::TSNeverKeyword(_) => "TSNeverKeyword",
+        AstKind::TSIntrinsicKeyword(_) => "TSIntrinsicKeyword",
+        AstKind::TSUnknownKeyword(_) => "TSUnknownKeyword",
+        AstKind::TSNullKeyword(_) => "TSNullKeyword",
+        AstKind::TSUndefinedKeyword(_) => "TSUndefinedKeyword",
+        AstKind::TSVoidKeyword(_) => "TSVoidKeyword",
+        AstKind::TSSymbolKeyword(_) => "TSSymbolKeyword",
+        AstKind::TSThisType(_) => "TSThisType",
+        AstKind::TSObjectKeyword(_) => "TSObjectKeyword",
+        AstKind::TSBigIntKeyword(_) => "TSBigIntKeyword",
+        AstKind::TSTypeReference(_) => "TSTypeReference",
+        AstKind::TSQualifiedName(_) | AstKind::TSImportTypeQualifiedName(_) => "TSQualifiedName",
+        AstKind::TSTypeParameterInstantiation(_) => "TSTypeParameterInstantiation",
+        AstKind::TSTypeParameter(_) => "TSTypeParameter",
+        AstKind::TSTypeParameterDeclaration(_) => "TSTypeParameterDeclaration",
+        AstKind::TSTypeAliasDeclaration(_) => "TSTypeAliasDeclaration",
+        AstKind::TSClassImplements(_) => "TSClassImplements",
+        AstKind::TSInterfaceDeclaration(_) => "TSInterfaceDeclaration",
+        AstKind::TSInterfaceBody(_) => "TSInterfaceBody",
+        AstKind::TSPropertySignature(_) => "TSPropertySignature",
+        AstKind::TSIndexSignature(_) => "TSIndexSignature",
+        AstKind::TSCallSignatureDeclaration(_) => "TSCallSignatureDeclaration",
+        AstKind::TSMethodSignature(_) => "TSMethodSignature",
+        AstKind::TSConstructSignatureDeclaration(_) => "TSConstructSignatureDeclaration",
+        AstKind::TSInterfaceHeritage(_) => "TSInterfaceHeritage",
+        AstKind::TSTypePredicate(_) => "TSTypePredicate",
+        AstKind::TSModuleDeclaration(_) | AstKind::TSGlobalDeclaration(_) => "TSModuleDeclaration",
+        AstKind::TSModuleBlock(_) => "TSModuleBlock",
+        AstKind::TSTypeLiteral(_) => "TSTypeLiteral",
+        AstKind::TSInferType(_) => "TSInferType",
+        AstKind::TSTypeQuery(_) => "TSTypeQuery",
+        AstKind::TSImportType(_) => "TSImportType",
+        AstKind::TSFunctionType(_) => "TSFunctionType",
+        AstKind::TSConstructorType(_) => "TSConstructorType",
+        AstKind::TSMappedType(_) => "TSMappedType",
+        AstKind::TSTemplateLiteralType(_) => "TSTemplateLiteralType",
+        AstKind::TSAsExpression(_) => "TSAsExpression",
+        AstKind::TSSatisfiesExpression(_) => "TSSatisfiesExpression",
+        AstKind::TSTypeAssertion(_) => "TSTypeAssertion",
+        AstKind::TSImportEqualsDeclaration(_) => "TSImportEqualsDeclaration",
+        AstKind::TSExternalModuleReference(_) => "TSExternalModuleReference",
+        AstKind::TSNonNullExpression(_) => "TSNonNullExpression",
+        AstKind::Decorator(_) => "Decorator",
+        AstKind::TSExportAssignment(_) => "TSExportAssignment",
+        AstKind::TSNamespaceExportDeclaration(_) => "TSNamespaceExportDeclaration",
+        AstKind::TSInstantiationExpression(_) => "TSInstantiationExpression",
+    };
+    Some(ty)
+}
+
+/// Whether the node is transparent and stands in for its only child, e.g. `(a)` for `a`.
+pub fn is_wrapper(kind: AstKind) -> bool {
+    matches!(kind, AstKind::ParenthesizedExpression(_) | AstKind::TSParenthesizedType(_))
+}
+
+fn is_expression_arrow(kind: AstKind) -> bool {
+    matches!(kind, AstKind::ArrowFunctionExpression(arrow) if arrow.expression)
+}
+
+/// ESTree properties of the node.
+#[expect(clippy::too_many_lines)]
+pub fn fields<'a>(kind: AstKind<'a>, source_text: &'a str) -> Vec<(&'static str, Value<'a>)> {
+    match kind {
+        AstKind::Program(program) => vec![
+            (
+                "body",
+                Value::Nodes(
+                    program
+                        .directives
+                        .iter()
+                        .map(|directive| Some(directive.unstable_address()))
+                        .chain(program.body.iter().map(|stmt| Some(stmt.address())))
+                        .collect(),
+                ),
+            ),
+            (
+                "sourceType",
+                Value::String(if program.source_type.is_module() { "module" } else { "script" }),
+            ),
+        ],
+        AstKind::IdentifierName(ident) => vec![("name", Value::String(ident.name.as_str()))],
+        AstKind::IdentifierReference(ident) => vec![("name", Value::String(ident.name.as_str()))],
+        AstKind::BindingIdentifier(ident) => vec![("name", Value::String(ident.name.as_str()))],
+        AstKind::LabelIdentifier(ident) => vec![("name", Value::String(ident.name.as_str()))],
+        AstKind::PrivateIdentifier(ident) => vec![("name", Value::String(ident.name.as_str()))],
+        AstKind::JSXIdentifier(ident) => vec![("name", Value::String(ident.name.as_str()))],
+        AstKind::TSIndexSignatureName(name) => vec![
+            ("name", Value::String(name.name.as_str())),
+            ("typeAnnotation", Value::node(&name.type_annotation)),
+        ],
+        AstKind::TSThisParameter(param) => vec![
+            ("name", Value::String("this")),
+            ("typeAnnotation", Value::opt_node(param.type_annotation.as_ref())),
+        ],
+        AstKind::BooleanLiteral(lit) => literal(Value::Bool(lit.value), lit.span(), source_text),
+        AstKind::NullLiteral(lit) => literal(Value::Null, lit.span(), source_text),
+        AstKind::NumericLiteral(lit) => literal(Value::Number(lit.value), lit.span(), source_text),
+        AstKind::StringLiteral(lit) => {
+            literal(Value::String(lit.value.as_str()), lit.span(), source_text)
+        }
+        AstKind::BigIntLiteral(lit) => {
+            let mut fields = literal(Value::String(lit.value.as_str()), lit.span(), source_text);
+            fields.push(("bigint", Value::String(lit.value.as_str())));
+            fields
+        }
+        AstKind::RegExpLiteral(lit) => {
+            let raw = lit.span().source_text(source_text);
+            let flags = raw.rfind('/').map_or("", |index| &raw[index + 1..]);
+            let mut fields = literal(Value::Null, lit.span(), source_text);
+            fields.push((
+                "regex",
+                Value::Object(vec![
+                    ("pattern", Value::String(lit.regex.pattern.text.as_str())),
+                    ("flags", Value::String(flags)),
+                ]),
+            ));
+            fields
+        }
+        AstKind::ArrayExpression(array) => vec![(
+            "elements",
+            Value::Nodes(
+                array
+                    .elements
+                    .iter()
+                    .map(|element| match element {
+                        ArrayExpressionElement::Elision(_) => None,
+                        ArrayExpressionElement::SpreadElement(spread) => Some(spread.address()),
+                        _ => element.as_expression().map(GetAddress::address),
+                    })
+                    .collect(),
+            ),
+        )],
+        AstKind::ObjectExpression(object) => {
+            vec![("properties", Value::nodes(&object.properties))]
+        }
+        AstKind::ObjectProperty(prop) => vec![
+            ("key", Value::node(&prop.key)),
+            ("value", Value::node(&prop.value)),
+            ("kind", Value::String(property_kind(prop.kind))),
+            ("method", Value::Bool(prop.method)),
+            ("shorthand", Value::Bool(prop.shorthand)),
+            ("computed", Value::Bool(prop.computed)),
+        ],
+        AstKind::TemplateLiteral(lit) => vec![
+            ("quasis", Value::inline_nodes(&lit.quasis)),
+            ("expressions", Value::nodes(&lit.expressions)),
+        ],
+        AstKind::TaggedTemplateExpression(expr) => vec![
+            ("tag", Value::node(&expr.tag)),
+            ("quasi", Value::inline(&expr.quasi)),
+            ("typeArguments", Value::opt_node(expr.type_arguments.as_ref())),
+        ],
+        AstKind::TemplateElement(element) => vec![
+            (
+                "value",
+                Value::Object(vec![
+                    ("raw", Value::String(element.value.raw.as_str())),
+                    ("cooked", Value::opt_str(element.value.cooked.map(|cooked| cooked.as_str()))),
+                ]),
+            ),
+            ("tail", Value::Bool(element.tail)),
+        ],
+        AstKind::ComputedMemberExpression(expr) => vec![
+            ("object", Value::node(&expr.object)),
+            ("property", Value::node(&expr.expression)),
+            ("computed", Value::Bool(true)),
+            ("optional", Value::Bool(expr.optional)),
+        ],
+        AstKind::StaticMemberExpression(expr) => vec![
+            ("object", Value::node(&expr.object)),
+            ("property", Value::inline(&expr.property)),
+            ("computed", Value::Bool(false)),
+            ("optional", Value::Bool(expr.optional)),
+        ],
+        AstKind::PrivateFieldExpression(expr) => vec![
+            ("object", Value::node(&expr.object)),
+            ("property", Value::inline(&expr.field)),
+            ("computed", Value::Bool(false)),
+            ("optional", Value::Bool(expr.optional)),
+        ],
+        AstKind::CallExpression(call) => vec![
+            ("callee", Value::node(&call.callee)),
+            ("arguments", Value::nodes(&call.arguments)),
+            ("optional", Value::Bool(call.optional)),
+            ("typeArguments", Value::opt_node(call.type_arguments.as_ref())),
+        ],
+        AstKind::NewExpression(new) => vec![
+            ("callee", Value::node(&new.callee)),
+            ("arguments", Value::nodes(&new.arguments)),
+            ("typeArguments", Value::opt_node(new.type_arguments.as_ref())),
+        ],
+        AstKind::MetaProperty(meta) => {
+            vec![("meta", Value::inline(&meta.meta)), ("property", Value::inline(&meta.property))]
+        }
+        AstKind::SpreadElement(spread) => vec![("argument", Value::node(&spread.argument))],
+        AstKind::UpdateExpression(expr) => vec![
+            ("operator", Value::String(expr.operator.as_str())),
+            ("prefix", Value::Bool(expr.prefix)),
+            ("argument", Value::node(&expr.argument)),
+        ],
+        AstKind::UnaryExpression(expr) => vec![
+            ("operator", Value::String(expr.operator.as_str())),
+            ("prefix", Value::Bool(true)),
+            ("argument", Value::node(&expr.argument)),
+        ],
+        AstKind::BinaryExpression(expr) => vec![
+            ("left", Value::node(&expr.left)),
+            ("operator", Value::String(expr.operator.as_str())),
+            ("right", Value::node(&expr.right)),
+        ],
+        AstKind::PrivateInExpression(expr) => vec![
+            ("left", Value::inline(&expr.left)),
+            ("operator", Value::String("in")),
+            ("right", Value::node(&expr.right)),
+        ],
+        AstKind::LogicalExpression(expr) => vec![
+            ("left", Value::node(&expr.left)),
+            ("operator", Value::String(expr.operator.as_str())),
+            ("right", Value::node(&expr.right)),
+        ],
+        AstKind::ConditionalExpression(expr) => vec![
+            ("test", Value::node(&expr.test)),
+            ("consequent", Value::node(&expr.consequent)),
+            ("alternate", Value::node(&expr.alternate)),
+        ],
+        AstKind::AssignmentExpression(expr) => vec![
+            ("operator", Value::String(expr.operator.as_str())),
+            ("left", Value::node(&expr.left)),
+            ("right", Value::node(&expr.right)),
+        ],
+        AstKind::ArrayAssignmentTarget(target) => vec![(
+            "elements",
+            Value::Nodes(
+                target
+                    .elements
+                    .iter()
+                    .map(|element| element.as_ref().map(GetAddress::address))
+                    .chain(target.rest.as_ref().map(|rest| Some(rest.address())))
+                    .collect(),
+            ),
+        )],
+        AstKind::ObjectAssignmentTarget(target) => vec![(
+            "properties",
+            Value::Nodes(
+                target
+                    .properties
+                    .iter()
+                    .map(|prop| Some(prop.address()))
+                    .chain(target.rest.as_ref().map(|rest| Some(rest.address())))
+                    .collect(),
+            ),
+        )],
+        AstKind::AssignmentTargetRest(rest) => vec![("argument", Value::node(&rest.target))],
+        AstKind::AssignmentTargetWithDefault(target) => {
+            vec![("left", Value::node(&target.binding)), ("right", Value::node(&target.init))]
+        }
+        AstKind::AssignmentTargetPropertyIdentifier(prop) => vec![
+            ("key", Value::inline(&prop.binding)),
+            ("value", Value::inline(&prop.binding)),
+            ("kind", Value::String("init")),
+            ("method", Value::Bool(false)),
+            ("shorthand", Value::Bool(true)),
+            ("computed", Value::Bool(false)),
+        ],
+        AstKind::AssignmentTargetPropertyProperty(prop) => vec![
+            ("key", Value::node(&prop.name)),
+            ("value", Value::node(&prop.binding)),
+            ("kind", Value::String("init")),
+            ("method", Value::Bool(false)),
+            ("shorthand", Value::Bool(false)),
+            ("computed", Value::Bool(prop.computed)),
+        ],
+        AstKind::SequenceExpression(expr) => {
+            vec![("expressions", Value::nodes(&expr.expressions))]
+        }
+        AstKind::AwaitExpression(expr) => vec![("argument", Value::node(&expr.argument))],
+        AstKind::ChainExpression(expr) => vec![("expression", Value::node(&expr.expression))],
+        AstKind::Directive(directive) => vec![
+            ("expression", Value::inline(&directive.expression)),
+            ("directive", Value::String(directive.directive.as_str())),
+        ],
+        AstKind::BlockStatement(block) => vec![("body", Value::nodes(&block.body))],
+        AstKind::FunctionBody(body) => vec![(
+            "body",
+            Value::Nodes(
+                body.directives
+                    .iter()
+                    .map(|directive| Some(directive.unstable_address()))
+                    .chain(body.statements.iter().map(|stmt| Some(stmt.address())))
+                    .collect(),
+            ),
+        )],
+        AstKind::VariableDeclaration(decl) => vec![
+            ("kind", Value::String(decl.kind.as_str())),
+            ("declarations", Value::inline_nodes(&decl.declarations)),
+            ("declare", Value::Bool(decl.declare)),
+        ],
+        AstKind::VariableDeclarator(decl) => {
+            vec![("id", Value::node(&decl.id)), ("init", Value::opt_node(decl.init.as_ref()))]
+        }
+        AstKind::ExpressionStatement(stmt) => vec![("expression", Value::node(&stmt.expression))],
+        AstKind::IfStatement(stmt) => vec![
+            ("test", Value::node(&stmt.test)),
+            ("consequent", Value::node(&stmt.consequent)),
+            ("alternate", Value::opt_node(stmt.alternate.as_ref())),
+        ],
+        AstKind::DoWhileStatement(stmt) => {
+            vec![("body", Value::node(&stmt.body)), ("test", Value::node(&stmt.test))]
+        }
+        AstKind::WhileStatement(stmt) => {
+            vec![("test", Value::node(&stmt.test)), ("body", Value::node(&stmt.body))]
+        }
+        AstKind::ForStatement(stmt) => vec![
+            ("init", Value::opt_node(stmt.init.as_ref())),
+            ("test", Value::opt_node(stmt.test.as_ref())),
+            ("update", Value::opt_node(stmt.update.as_ref())),
+            ("body", Value::node(&stmt.body)),
+        ],
+        AstKind::ForInStatement(stmt) => vec![
+            ("left", Value::node(&stmt.left)),
+            ("right", Value::node(&stmt.right)),
+            ("body", Value::node(&stmt.body)),
+        ],
+        AstKind::ForOfStatement(stmt) => vec![
+            ("left", Value::node(&stmt.left)),
+            ("right", Value::node(&stmt.right)),
+            ("body", Value::node(&stmt.body)),
+            ("await", Value::Bool(stmt.r#await)),
+        ],
+        AstKind::ContinueStatement(stmt) => {
+            vec![("label", Value::opt_inline(stmt.label.as_ref()))]
+        }
+        AstKind::BreakStatement(stmt) => vec![("label", Value::opt_inline(stmt.label.as_ref()))],
+        AstKind::ReturnStatement(stmt) => {
+            vec![("argument", Value::opt_node(stmt.argument.as_ref()))]
+        }
+        AstKind::WithStatement(stmt) => {
+            vec![("object", Value::node(&stmt.object)), ("body", Value::node(&stmt.body))]
+        }
+        AstKind::SwitchStatement(stmt) => vec![
+            ("discriminant", Value::node(&stmt.discriminant)),
+            ("cases", Value::inline_nodes(&stmt.cases)),
+        ],
+        AstKind::SwitchCase(case) => vec![
+            ("test", Value::opt_node(case.test.as_ref())),
+            ("consequent", Value::nodes(&case.consequent)),
+        ],
+        AstKind::LabeledStatement(stmt) => {
+            vec![("label", Value::inline(&stmt.label)), ("body", Value::node(&stmt.body))]
+        }
+        AstKind::ThrowStatement(stmt) => vec![("argument", Value::node(&stmt.argument))],
+        AstKind::TryStatement(stmt) => vec![
+            ("block", Value::node(&stmt.block)),
+            ("handler", Value::opt_node(stmt.handler.as_ref())),
+            ("finalizer", Value::opt_node(stmt.finalizer.as_ref())),
+        ],
+        AstKind::CatchClause(clause) => vec![
+            ("param", Value::opt_node(clause.param.as_ref().map(|param| &param.pattern))),
+            ("body", Value::node(&clause.body)),
+        ],
+        AstKind::AssignmentPattern(pattern) => {
+            vec![("left", Value::node(&pattern.left)), ("right", Value::node(&pattern.right))]
+        }
+        AstKind::FormalParameter(param) => vec![
+            ("left", Value::node(&param.pattern)),
+            ("right", Value::opt_node(param.initializer.as_ref())),
+        ],
+        AstKind::ObjectPattern(pattern) => vec![(
+            "properties",
+            Value::Nodes(
+                pattern
+                    .properties
+                    .iter()
+                    .map(|prop| Some(prop.unstable_address()))
+                    .chain(pattern.rest.as_ref().map(|rest| Some(rest.address())))
+                    .collect(),
+            ),
+        )],
+        AstKind::ArrayPattern(pattern) => vec![(
+            "elements",
+            Value::Nodes(
+                pattern
+                    .elements
+                    .iter()
+                    .map(|element| element.as_ref().map(GetAddress::address))
+                    .chain(pattern.rest.as_ref().map(|rest| Some(rest.address())))
+                    .collect(),
+            ),
+        )],
+        AstKind::BindingProperty(prop) => vec![
+            ("key", Value::node(&prop.key)),
+            ("value", Value::node(&prop.value)),
+            ("kind", Value::String("init")),
+            ("method", Value::Bool(false)),
+            ("shorthand", Value::Bool(prop.shorthand)),
+            ("computed", Value::Bool(prop.computed)),
+        ],
+        AstKind::BindingRestElement(rest) => vec![("argument", Value::node(&rest.argument))],
+        AstKind::Function(func) => vec![
+            ("id", Value::opt_inline(func.id.as_ref())),
+            ("params", params(&func.params)),
+            ("body", Value::opt_node(func.body.as_ref())),
+            ("generator", Value::Bool(func.generator)),
+            ("async", Value::Bool(func.r#async)),
+            ("expression", Value::Bool(false)),
+            ("declare", Value::Bool(func.declare)),
+            ("typeParameters", Value::opt_node(func.type_parameters.as_ref())),
+            ("returnType", Value::opt_node(func.return_type.as_ref())),
+        ],
+        AstKind::ArrowFunctionExpression(arrow) => vec![
+            ("id", Value::Null),
+            ("params", params(&arrow.params)),
+            ("body", arrow_body(arrow)),
+            ("generator", Value::Bool(false)),
+            ("async", Value::Bool(arrow.r#async)),
+            ("expression", Value::Bool(arrow.expression)),
+            ("typeParameters", Value::opt_node(arrow.type_parameters.as_ref())),
+            ("returnType", Value::opt_node(arrow.return_type.as_ref())),
+        ],
+        AstKind::YieldExpression(expr) => vec![
+            ("delegate", Value::Bool(expr.delegate)),
+            ("argument", Value::opt_node(expr.argument.as_ref())),
+        ],
+        AstKind::Class(class) => vec![
+            ("id", Value::opt_inline(class.id.as_ref())),
+            ("superClass", Value::opt_node(class.super_class.as_ref())),
+            ("body", Value::node(&class.body)),
+            ("decorators", Value::inline_nodes(&class.decorators)),
+            ("implements", Value::inline_nodes(&class.implements)),
+            ("abstract", Value::Bool(class.r#abstract)),
+            ("declare", Value::Bool(class.declare)),
+            ("typeParameters", Value::opt_node(class.type_parameters.as_ref())),
+        ],
+        AstKind::ClassBody(body) => vec![("body", Value::nodes(&body.body))],
+        AstKind::MethodDefinition(method) => vec![
+            ("key", Value::node(&method.key)),
+            ("value", Value::node(&method.value)),
+            ("kind", Value::String(method_kind(method.kind))),
+            ("computed", Value::Bool(method.computed)),
+            ("static", Value::Bool(method.r#static)),
+            ("optional", Value::Bool(method.optional)),
+            ("override", Value::Bool(method.r#override)),
+            ("decorators", Value::inline_nodes(&method.decorators)),
+            ("accessibility", Value::opt_str(method.accessibility.map(TSAccessibility::as_str))),
+        ],
+        AstKind::PropertyDefinition(prop) => vec![
+            ("key", Value::node(&prop.key)),
+            ("value", Value::opt_node(prop.value.as_ref())),
+            ("computed", Value::Bool(prop.computed)),
+            ("static", Value::Bool(prop.r#static)),
+            ("declare", Value::Bool(prop.declare)),
+            ("optional", Value::Bool(prop.optional)),
+            ("readonly", Value::Bool(prop.readonly)),
+            ("override", Value::Bool(prop.r#override)),
+            ("decorators", Value::inline_nodes(&prop.decorators)),
+            ("typeAnnotation", Value::opt_node(prop.type_annotation.as_ref())),
+            ("accessibility", Value::opt_str(prop.accessibility.map(TSAccessibility::as_str))),
+        ],
+        AstKind::AccessorProperty(prop) => vec![
+            ("key", Value::node(&prop.key)),
+            ("value", Value::opt_node(prop.value.as_ref())),
+            ("computed", Value::Bool(prop.computed)),
+            ("static", Value::Bool(prop.r#static)),
+            ("override", Value::Bool(prop.r#override)),
+            ("decorators", Value::inline_nodes(&prop.decorators)),
+            ("typeAnnotation", Value::opt_node(prop.type_annotation.as_ref())),
+            ("accessibility", Value::opt_str(prop.accessibility.map(TSAccessibility::as_str))),
+        ],
+        AstKind::StaticBlock(block) => vec![("body", Value::nodes(&block.body))],
+        AstKind::ImportExpression(expr) => vec![
+            ("source", Value::node(&expr.source)),
+            ("options", Value::opt_node(expr.options.as_ref())),
+            ("phase", Value::opt_str(expr.phase.map(ImportPhase::as_str))),
+        ],
+        AstKind::ImportDeclaration(decl) => vec![
+            ("specifiers", decl.specifiers.as_ref().map_or(Value::Nodes(vec![]), Value::nodes)),
+            ("source", Value::inline(&decl.source)),
+            ("phase", Value::opt_str(decl.phase.map(ImportPhase::as_str))),
+            ("importKind", Value::String(import_or_export_kind(decl.import_kind))),
+            ("attributes", attributes(decl.with_clause.as_deref())),
+        ],
+        AstKind::ImportSpecifier(specifier) => vec![
+            ("imported", module_export_name(&specifier.imported)),
+            ("local", Value::inline(&specifier.local)),
+            ("importKind", Value::String(import_or_export_kind(specifier.import_kind))),
+        ],
+        AstKind::ImportDefaultSpecifier(specifier) => {
+            vec![("local", Value::inline(&specifier.local))]
+        }
+        AstKind::ImportNamespaceSpecifier(specifier) => {
+            vec![("local", Value::inline(&specifier.local))]
+        }
+        AstKind::ImportAttribute(attribute) => vec![
+            (
+                "key",
+                match &attribute.key {
+                    ImportAttributeKey::Identifier(ident) => Value::inline(ident),
+                    ImportAttributeKey::StringLiteral(lit) => Value::inline(lit),
+                },
+            ),
+            ("value", Value::inline(&attribute.value)),
+        ],
+        AstKind::ExportNamedDeclaration(decl) => vec![
+            ("declaration", Value::opt_node(decl.declaration.as_ref())),
+            ("specifiers", Value::inline_nodes(&decl.specifiers)),
+            ("source", Value::opt_inline(decl.source.as_ref())),
+            ("exportKind", Value::String(import_or_export_kind(decl.export_kind))),
+            ("attributes", attributes(decl.with_clause.as_deref())),
+        ],
+        AstKind::ExportDefaultDeclaration(decl) => {
+            vec![("declaration", Value::node(&decl.declaration))]
+        }
+        AstKind::ExportAllDeclaration(decl) => vec![
+            ("exported", decl.exported.as_ref().map_or(Value::Null, module_export_name)),
+            ("source", Value::inline(&decl.source)),
+            ("exportKind", Value::String(import_or_export_kind(decl.export_kind))),
+            ("attributes", attributes(decl.with_clause.as_deref())),
+        ],
+        AstKind::ExportSpecifier(specifier) => vec![
+            ("local", module_export_name(&specifier.local)),
+            ("exported", module_export_name(&specifier.exported)),
+            ("exportKind", Value::String(import_or_export_kind(specifier.export_kind))),
+        ],
+        AstKind::V8IntrinsicExpression(expr) => {
+            vec![("name", Value::inline(&expr.name)), ("arguments", Value::nodes(&expr.arguments))]
+        }
+        AstKind::JSXElement(element) => vec![
+            ("openingElement", Value::node(&element.opening_element)),
+            ("children", Value::nodes(&element.children)),
+            ("closingElement", Value::opt_node(element.closing_element.as_ref())),
+        ],
+        AstKind::JSXOpeningElement(element) => vec![
+            ("name", Value::node(&element.name)),
+            ("attributes", Value::nodes(&element.attributes)),
+            ("typeArguments", Value::opt_node(element.type_arguments.as_ref())),
+        ],
+        AstKind::JSXClosingElement(element) => vec![("name", Value::node(&element.name))],
+        AstKind::JSXFragment(fragment) => vec![
+            ("openingFragment", Value::inline(&fragment.opening_fragment)),
+            ("children", Value::nodes(&fragment.children)),
+            ("closingFragment", Value::inline(&fragment.closing_fragment)),
+        ],
+        AstKind::JSXNamespacedName(name) => {
+            vec![("namespace", Value::inline(&name.namespace)), ("name", Value::inline(&name.name))]
+        }
+        AstKind::JSXMemberExpression(expr) => {
+            vec![("object", Value::node(&expr.object)), ("property", Value::inline(&expr.property))]
+        }
+        AstKind::JSXExpressionContainer(container) => vec![(
+            "expression",
+            match &container.expression {
+                JSXExpression::EmptyExpression(empty) => Value::inline(empty),
+                expr => Value::opt_node(expr.as_expression()),
+            },
+        )],
+        AstKind::JSXAttribute(attribute) => vec![
+            ("name", Value::node(&attribute.name)),
+            ("value", Value::opt_node(attribute.value.as_ref())),
+        ],
+        AstKind::JSXSpreadAttribute(attribute) => {
+            vec![("argument", Value::node(&attribute.argument))]
+        }
+        AstKind::JSXSpreadChild(child) => vec![("expression", Value::node(&child.expression))],
+        AstKind::JSXText(text) => vec![
+            ("value", Value::String(text.value.as_str())),
+            ("raw", Value::String(text.span.source_text(source_text))),
+        ],
+        AstKind::TSEnumDeclaration(decl) => vec![
+            ("id", Value::inline(&decl.id)),
+            ("body", Value::inline(&decl.body)),
+            ("const", Value::Bool(decl.r#const)),
+            ("declare", Value::Bool(decl.declare)),
+        ],
+        AstKind::TSEnumBody(body) => vec![("members", Value::inline_nodes(&body.members))],
+        AstKind::TSEnumMember(member) => vec![
+            ("id", Value::node(&member.id)),
+            ("initializer", Value::opt_node(member.initializer.as_ref())),
+        ],
+        AstKind::TSTypeAnnotation(annotation) => {
+            vec![("typeAnnotation", Value::node(&annotation.type_annotation))]
+        }
+        AstKind::TSLiteralType(ty) => vec![("literal", Value::node(&ty.literal))],
+        AstKind::TSConditionalType(ty) => vec![
+            ("checkType", Value::node(&ty.check_type)),
+            ("extendsType", Value::node(&ty.extends_type)),
+            ("trueType", Value::node(&ty.true_type)),
+            ("falseType", Value::node(&ty.false_type)),
+        ],
+        AstKind::TSUnionType(ty) => vec![("types", Value::nodes(&ty.types))],
+        AstKind::TSIntersectionType(ty) => vec![("types", Value::nodes(&ty.types))],
+        AstKind::TSTypeOperator(ty) => vec![
+            ("operator", Value::String(ty.operator.to_str())),
+            ("typeAnnotation", Value::node(&ty.type_annotation)),
+        ],
+        AstKind::TSArrayType(ty) => vec![("elementType", Value::node(&ty.element_type))],
+        AstKind::TSIndexedAccessType(ty) => vec![
+            ("objectType", Value::node(&ty.object_type)),
+            ("indexType", Value::node(&ty.index_type)),
+        ],
+        AstKind::TSTupleType(ty) => vec![("elementTypes", Value::nodes(&ty.element_types))],
+        AstKind::TSNamedTupleMember(member) => vec![
+            ("label", Value::inline(&member.label)),
+            ("elementType", Value::node(&member.element_type)),
+            ("optional", Value::Bool(member.optional)),
+        ],
+        AstKind::TSOptionalType(ty) => vec![("typeAnnotation", Value::node(&ty.type_annotation))],
+        AstKind::TSRestType(ty) => vec![("typeAnnotation", Value::node(&ty.type_annotation))],
+        AstKind::TSTypeReference(ty) => vec![
+            ("typeName", Value::node(&ty.type_name)),
+            ("typeArguments", Value::opt_node(ty.type_arguments.as_ref())),
+        ],
+        AstKind::TSQualifiedName(name) => {
+            vec![("left", Value::node(&name.left)), ("right", Value::inline(&name.right))]
+        }
+        AstKind::TSImportTypeQualifiedName(name) => {
+            vec![("left", Value::node(&name.left)), ("right", Value::inline(&name.right))]
+        }
+        AstKind::TSTypeParameterInstantiation(params) => {
+            vec![("params", Value::nodes(&params.params))]
+        }
+        AstKind::TSTypeParameterDeclaration(params) => {
+            vec![("params", Value::inline_nodes(&params.params))]
+        }
+        AstKind::TSTypeParameter(param) => vec![
+            ("name", Value::inline(&param.name)),
+            ("constraint", Value::opt_node(param.constraint.as_ref())),
+            ("default", Value::opt_node(param.default.as_ref())),
+            ("in", Value::Bool(param.r#in)),
+            ("out", Value::Bool(param.out)),
+            ("const", Value::Bool(param.r#const)),
+        ],
+        AstKind::TSTypeAliasDeclaration(decl) => vec![
+            ("id", Value::inline(&decl.id)),
+            ("typeParameters", Value::opt_node(decl.type_parameters.as_ref())),
+            ("typeAnnotation", Value::node(&decl.type_annotation)),
+            ("declare", Value::Bool(decl.declare)),
+        ],
+        AstKind::TSInterfaceDeclaration(decl) => vec![
+            ("id", Value::inline(&decl.id)),
+            ("typeParameters", Value::opt_node(decl.type_parameters.as_ref())),
+            ("extends", Value::inline_nodes(&decl.extends)),
+            ("body", Value::node(&decl.body)),
+            ("declare", Value::Bool(decl.declare)),
+        ],
+        AstKind::TSInterfaceBody(body) => vec![("body", Value::nodes(&body.body))],
+        AstKind::TSInterfaceHeritage(heritage) => vec![
+            ("expression", Value::node(&heritage.expression)),
+            ("typeArguments", Value::opt_node(heritage.type_arguments.as_ref())),
+        ],
+        AstKind::TSClassImplements(implements) => vec![
+            ("expression", Value::node(&implements.expression)),
+            ("typeArguments", Value::opt_node(implements.type_arguments.as_ref())),
+        ],
+        AstKind::TSPropertySignature(sig) => vec![
+            ("key", Value::node(&sig.key)),
+            ("computed", Value::Bool(sig.computed)),
+            ("optional", Value::Bool(sig.optional)),
+            ("readonly", Value::Bool(sig.readonly)),
+            ("typeAnnotation", Value::opt_node(sig.type_annotation.as_ref())),
+        ],
+        AstKind::TSMethodSignature(sig) => vec![
+            ("key", Value::node(&sig.key)),
+            ("computed", Value::Bool(sig.computed)),
+            ("optional", Value::Bool(sig.optional)),
+            ("kind", Value::String(method_signature_kind(sig.kind))),
+            ("params", params(&sig.params)),
+            ("returnType", Value::opt_node(sig.return_type.as_ref())),
+            ("typeParameters", Value::opt_node(sig.type_parameters.as_ref())),
+        ],
+        AstKind::TSIndexSignature(sig) => vec![
+            ("parameters", Value::inline_nodes(&sig.parameters)),
+            ("typeAnnotation", Value::node(&sig.type_annotation)),
+            ("readonly", Value::Bool(sig.readonly)),
+            ("static", Value::Bool(sig.r#static)),
+        ],
+        AstKind::TSCallSignatureDeclaration(sig) => vec![
+            ("params", params(&sig.params)),
+            ("returnType", Value::opt_node(sig.return_type.as_ref())),
+            ("typeParameters", Value::opt_node(sig.type_parameters.as_ref())),
+        ],
+        AstKind::TSConstructSignatureDeclaration(sig) => vec![
+            ("params", params(&sig.params)),
+            ("returnType", Value::opt_node(sig.return_type.as_ref())),
+            ("typeParameters", Value::opt_node(sig.type_parameters.as_ref())),
+        ],
+        AstKind::TSFunctionType(ty) => vec![
+            ("params", params(&ty.params)),
+            ("returnType", Value::node(&ty.return_type)),
+            ("typeParameters", Value::opt_node(ty.type_parameters.as_ref())),
+        ],
+        AstKind::TSConstructorType(ty) => vec![
+            ("abstract", Value::Bool(ty.r#abstract)),
+            ("params", params(&ty.params)),
+            ("returnType", Value::node(&ty.return_type)),
+            ("typeParameters", Value::opt_node(ty.type_parameters.as_ref())),
+        ],
+        AstKind::TSTypePredicate(predicate) => vec![
+            (
+                "parameterName",
+                match &predicate.parameter_name {
+                    TSTypePredicateName::Identifier(ident) => Value::node(ident),
+                    TSTypePredicateName::This(this) => Value::inline(this),
+                },
+            ),
+            ("asserts", Value::Bool(predicate.asserts)),
+            ("typeAnnotation", Value::opt_node(predicate.type_annotation.as_ref())),
+        ],
+        AstKind::TSModuleDeclaration(decl) => vec![
+            (
+                "id",
+                match &decl.id {
+                    TSModuleDeclarationName::Identifier(ident) => Value::inline(ident),
+                    TSModuleDeclarationName::StringLiteral(lit) => Value::inline(lit),
+                },
+            ),
+            ("body", Value::opt_node(decl.body.as_ref())),
+            ("kind", Value::String(decl.kind.as_str())),
+            ("declare", Value::Bool(decl.declare)),
+        ],
+        AstKind::TSGlobalDeclaration(decl) => vec![
+            ("body", Value::inline(&decl.body)),
+            ("kind", Value::String("global")),
+            ("declare", Value::Bool(decl.declare)),
+        ],
+        AstKind::TSModuleBlock(block) => vec![(
+            "body",
+            Value::Nodes(
+                block
+                    .directives
+                    .iter()
+                    .map(|directive| Some(directive.unstable_address()))
+                    .chain(block.body.iter().map(|stmt| Some(stmt.address())))
+                    .collect(),
+            ),
+        )],
+        AstKind::TSTypeLiteral(ty) => vec![("members", Value::nodes(&ty.members))],
+        AstKind::TSInferType(ty) => vec![("typeParameter", Value::node(&ty.type_parameter))],
+        AstKind::TSTypeQuery(ty) => vec![
+            ("exprName", Value::node(&ty.expr_name)),
+            ("typeArguments", Value::opt_node(ty.type_arguments.as_ref())),
+        ],
+        AstKind::TSImportType(ty) => vec![
+            ("source", Value::inline(&ty.source)),
+            ("options", Value::opt_node(ty.options.as_ref())),
+            ("qualifier", Value::opt_node(ty.qualifier.as_ref())),
+            ("typeArguments", Value::opt_node(ty.type_arguments.as_ref())),
+        ],
+        AstKind::TSMappedType(ty) => vec![
+            ("key", Value::inline(&ty.key)),
+            ("constraint", Value::node(&ty.constraint)),
+            ("nameType", Value::opt_node(ty.name_type.as_ref())),
+            ("typeAnnotation", Value::opt_node(ty.type_annotation.as_ref())),
+        ],
+        AstKind::TSTemplateLiteralType(ty) => {
+            vec![("quasis", Value::inline_nodes(&ty.quasis)), ("types", Value::nodes(&ty.types))]
+        }
+        AstKind::TSAsExpression(expr) => vec![
+            ("expression", Value::node(&expr.expression)),
+            ("typeAnnotation", Value::node(&expr.type_annotation)),
+        ],
+        AstKind::TSSatisfiesExpression(expr) => vec![
+            ("expression", Value::node(&expr.expression)),
+            ("typeAnnotation", Value::node(&expr.type_annotation)),
+        ],
+        AstKind::TSTypeAssertion(expr) => vec![
+            ("typeAnnotation", Value::node(&expr.type_annotation)),
+            ("expression", Value::node(&expr.expression)),
+        ],
+        AstKind::TSNonNullExpression(expr) => vec![("expression", Value::node(&expr.expression))],
+        AstKind::TSInstantiationExpression(expr) => vec![
+            ("expression", Value::node(&expr.expression)),
+            ("typeArguments", Value::node(&expr.type_arguments)),
+        ],
+        AstKind::TSImportEqualsDeclaration(decl) => vec![
+            ("id", Value::inline(&decl.id)),
+            ("moduleReference", Value::node(&decl.module_reference)),
+            ("importKind", Value::String(import_or_export_kind(decl.import_kind))),
+        ],
+        AstKind::TSExternalModuleReference(reference) => {
+            vec![("expression", Value::inline(&reference.expression))]
+        }
+        AstKind::TSExportAssignment(assignment) => {
+            vec![("expression", Value::node(&assignment.expression))]
+        }
+        AstKind::TSNamespaceExportDeclaration(decl) => vec![("id", Value::inline(&decl.id))],
+        AstKind::Decorator(decorator) => vec![("expression", Value::node(&decorator.expression))],
+        _ => vec![],
+    }
+}
+
+fn literal<'a>(
+    value: Value<'a>,
+    span: oxc_span::Span,
+    source_text: &'a str,
+) -> Vec<(&'static str, Value<'a>)> {
+    vec![("value", value), ("raw", Value::String(span.source_text(source_text)))]
+}
+
+/// `params` of a function-like node. Parameters with an initializer are `AssignmentPattern`s.
+fn params<'a>(params: &FormalParameters<'a>) -> Value<'a> {
+    Value::Nodes(
+        params
+            .items
+            .iter()
+            .map(|param| {
+                Some(if param.initializer.is_some() {
+                    param.unstable_address()
+                } else {
+                    param.pattern.address()
+                })
+            })
+            .chain(params.rest.as_ref().map(|rest| Some(rest.rest.unstable_address())))
+            .collect(),
+    )
+}
+
+fn arrow_body<'a>(arrow: &ArrowFunctionExpression<'a>) -> Value<'a> {
+    match arrow.get_expression() {
+        Some(expr) if arrow.expression => Value::node(expr),
+        _ => Value::node(&arrow.body),
+    }
+}
+
+fn attributes<'a>(with_clause: Option<&WithClause<'a>>) -> Value<'a> {
+    with_clause
+        .map_or(Value::Nodes(vec![]), |with_clause| Value::inline_nodes(&with_clause.with_entries))
+}
+
+fn module_export_name<'a>(name: &ModuleExportName<'a>) -> Value<'a> {
+    match name {
+        ModuleExportName::IdentifierName(ident) => Value::inline(ident),
+        ModuleExportName::IdentifierReference(ident) => Value::inline(ident),
+        ModuleExportName::StringLiteral(lit) => Value::inline(lit),
+    }
+}
+
+fn import_or_export_kind(kind: ImportOrExportKind) -> &'static str {
+    if kind.is_type() { "type" } else { "value" }
+}
+
+fn property_kind(kind: PropertyKind) -> &'static str {
+    match kind {
+        PropertyKind::Init => "init",
+        PropertyKind::Get => "get",
+        PropertyKind::Set => "set",
+    }
+}
+
+fn method_kind(kind: MethodDefinitionKind) -> &'static str {
+    match kind {
+        MethodDefinitionKind::Constructor => "constructor",
+        MethodDefinitionKind::Method => "method",
+        MethodDefinitionKind::Get => "get",
+        MethodDefinitionKind::Set => "set",
+    }
+}
+
+fn method_signature_kind(kind: TSMethodSignatureKind) -> &'static str {
+    match kind {
+        TSMethodSignatureKind::Method => "method",
+        TSMethodSignatureKind::Get => "get",
+        TSMethodSignatureKind::Set => "set",
+    }
+}
@@ -12,10 +12,13 @@ use oxc_allocator::Allocator;
 mod comment;
 mod config;
 mod edit_distance;
+mod esquery;
 mod express;
 mod jest;
 mod jsdoc;
 mod nextjs;
+mod node;
+mod package_json;
 mod promise;
 mod react;
 mod react_perf;
@@ -27,8 +30,9 @@ mod vitest;
 mod vue;
 
 pub use self::{
-    comment::*, config::*, edit_distance::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*,
-    react::*, react_perf::*, regex::*, typescript::*, unicorn::*, url::*, vitest::*, vue::*,
+    comment::*, config::*, edit_distance::*, esquery::*, express::*, jest::*, jsdoc::*, nextjs::*,
+    node::*, package_json::*, promise::*, react::*, react_perf::*, regex::*, typescript::*,
+    unicorn::*, url::*, vitest::*, vue::*,
 };
 
 /// List of Jest rules that have Vitest equivalents.
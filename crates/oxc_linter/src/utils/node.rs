@@ -0,0 +1,155 @@
+use oxc_compat::Version;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::LintContext;
+
+/// Version range assumed when neither the rule options nor a `package.json` specify one.
+const DEFAULT_NODE_VERSION_RANGE: &str = ">=16.0.0";
+
+/// Options shared by the `node/no-unsupported-features-*` rules.
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UnsupportedFeaturesConfig {
+    /// The range of Node.js versions the code must run on, e.g. `">=18.0.0"`. Defaults to the
+    /// `engines.node` field of the closest `package.json`, or `">=16.0.0"` if there is none.
+    pub version: Option<String>,
+    /// Names of features to never report.
+    pub ignores: FxHashSet<String>,
+}
+
+impl UnsupportedFeaturesConfig {
+    /// The configured Node.js version range, falling back to the `package.json` closest to the
+    /// linted file.
+    pub fn node_version_range(&self, ctx: &LintContext<'_>) -> NodeVersionRange {
+        self.version
+            .as_deref()
+            .and_then(NodeVersionRange::parse)
+            .or_else(|| {
+                let package_json = ctx.closest_package_json()?;
+                let range = package_json.get("engines")?.get("node")?.as_str()?;
+                NodeVersionRange::parse(range)
+            })
+            .unwrap_or_else(|| {
+                NodeVersionRange::parse(DEFAULT_NODE_VERSION_RANGE)
+                    .expect("default Node.js version range should be valid")
+            })
+    }
+}
+
+pub fn unsupported_feature_diagnostic(
+    name: &str,
+    supported: Version,
+    range: &NodeVersionRange,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("{name} is not supported until Node.js {supported}."))
+        .with_help(format!("The configured Node.js version range is '{}'.", range.range))
+        .with_label(span)
+}
+
+/// A semver range of Node.js versions, such as the `engines.node` field of a `package.json`.
+///
+/// Features are supported from a given version onwards, so only the lowest version matching the
+/// range matters: a feature is available in the whole range if it is available in that version.
+#[derive(Debug, Clone)]
+pub struct NodeVersionRange {
+    range: String,
+    min_version: Version,
+}
+
+impl NodeVersionRange {
+    /// Parses a range like `>=14`, `^16.13.0 || >=18`, `14.x` or `12 - 16`.
+    /// Returns `None` if the range is not a valid semver range.
+    pub fn parse(range: &str) -> Option<Self> {
+        let min_version = range
+            .split("||")
+            .map(|set| {
+                let set = set.trim();
+                // Hyphen ranges: `12.0.0 - 16` starts at its first version.
+                if let Some((from, _)) = set.split_once(" - ") {
+                    return parse_partial_version(from.trim()).map(|(version, _)| version);
+                }
+                set.split_whitespace().try_fold(Version::default(), |min, comparator| {
+                    Some(min.max(comparator_lower_bound(comparator)?))
+                })
+            })
+            .try_fold(None, |min: Option<Version>, version| {
+                let version = version?;
+                Some(Some(min.map_or(version, |min| min.min(version))))
+            })??;
+        Some(Self { range: range.to_string(), min_version })
+    }
+
+    /// Returns `true` if every version in the range is at least `version`.
+    pub fn supports(&self, version: Version) -> bool {
+        self.min_version >= version
+    }
+}
+
+/// The lowest version allowed by a single comparator such as `>=14.17` or `^16`.
+fn comparator_lower_bound(comparator: &str) -> Option<Version> {
+    let (operator, version) = comparator
+        .find(|c: char| c.is_ascii_alphanumeric() || c == '*')
+        .map(|i| comparator.split_at(i))?;
+    let (version, specified) = parse_partial_version(version)?;
+    Some(match operator.trim() {
+        "<" | "<=" => Version::default(),
+        // `>16` is `>=17.0.0`, `>16.1` is `>=16.2.0` and `>16.1.2` is `>=16.1.3`.
+        ">" => match specified {
+            0 => return None,
+            1 => Version(version.0 + 1, 0, 0),
+            2 => Version(version.0, version.1 + 1, 0),
+            _ => Version(version.0, version.1, version.2 + 1),
+        },
+        "" | "=" | ">=" | "^" | "~" => version,
+        _ => return None,
+    })
+}
+
+/// Parses `16`, `16.1`, `16.x` or `16.1.2`, returning the version with unspecified parts set to
+/// zero and the number of specified parts.
+fn parse_partial_version(version: &str) -> Option<(Version, usize)> {
+    let version = version.trim_start_matches(['v', '=']);
+    // Ignore pre-release and build metadata, e.g. `18.0.0-rc.1`.
+    let version = version.split(['-', '+']).next()?;
+    let mut parts = [0u16; 3];
+    let mut specified = 0;
+    for (i, part) in version.split('.').enumerate() {
+        if i >= 3 {
+            return None;
+        }
+        if matches!(part, "x" | "X" | "*") {
+            break;
+        }
+        parts[i] = part.parse().ok()?;
+        specified += 1;
+    }
+    Some((Version(parts[0], parts[1], parts[2]), specified))
+}
+
+#[test]
+fn test_node_version_range() {
+    let min_version = |range: &str| NodeVersionRange::parse(range).map(|r| r.min_version);
+
+    assert_eq!(min_version(">=16.0.0"), Some(Version(16, 0, 0)));
+    assert_eq!(min_version(">=14.17"), Some(Version(14, 17, 0)));
+    assert_eq!(min_version("^18"), Some(Version(18, 0, 0)));
+    assert_eq!(min_version("~16.13.1"), Some(Version(16, 13, 1)));
+    assert_eq!(min_version("14.x"), Some(Version(14, 0, 0)));
+    assert_eq!(min_version("v20.1.0"), Some(Version(20, 1, 0)));
+    assert_eq!(min_version(">16"), Some(Version(17, 0, 0)));
+    assert_eq!(min_version(">16.1"), Some(Version(16, 2, 0)));
+    assert_eq!(min_version(">=12 <20"), Some(Version(12, 0, 0)));
+    assert_eq!(min_version("<20"), Some(Version(0, 0, 0)));
+    assert_eq!(min_version("^16.13.0 || >=18"), Some(Version(16, 13, 0)));
+    assert_eq!(min_version(">=20 || ^14.18"), Some(Version(14, 18, 0)));
+    assert_eq!(min_version("12 - 16"), Some(Version(12, 0, 0)));
+    assert_eq!(min_version("*"), Some(Version(0, 0, 0)));
+    assert_eq!(min_version(">=18.0.0-rc.1"), Some(Version(18, 0, 0)));
+    assert_eq!(min_version("latest"), None);
+    assert_eq!(min_version(">=a"), None);
+}
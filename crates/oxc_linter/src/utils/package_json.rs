@@ -0,0 +1,51 @@
+use std::{
+    fs,
+    hash::{DefaultHasher, Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+/// A `package.json` read by a rule, see [`LintContext::package_json`].
+///
+/// [`LintContext::package_json`]: crate::LintContext::package_json
+#[derive(Debug, Clone)]
+pub struct PackageJsonFile {
+    /// Hash of the contents of the file.
+    pub content_hash: u64,
+    /// Parsed contents of the file, or `None` if it is not valid JSON.
+    pub json: Option<Arc<Value>>,
+}
+
+/// Hash of the contents of a file read by rules.
+///
+/// Lint result cache entries store these hashes, and are invalidated when they change.
+pub fn file_content_hash(content: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Read the `package.json` at `path`. Returns `None` if it cannot be read.
+///
+/// Parsed files are shared between all linted files, until the contents of the file change.
+pub fn read_package_json(path: &Path) -> Option<PackageJsonFile> {
+    type Cache = RwLock<FxHashMap<PathBuf, PackageJsonFile>>;
+    static CACHE: OnceLock<Cache> = OnceLock::new();
+    let cache = CACHE.get_or_init(Cache::default);
+
+    let content = fs::read_to_string(path).ok()?;
+    let content_hash = file_content_hash(&content);
+    if let Some(file) = cache.read().unwrap().get(path)
+        && file.content_hash == content_hash
+    {
+        return Some(file.clone());
+    }
+
+    let json = serde_json::from_str::<Value>(&content).ok().map(Arc::new);
+    let file = PackageJsonFile { content_hash, json };
+    cache.write().unwrap().insert(path.to_path_buf(), file.clone());
+    Some(file)
+}
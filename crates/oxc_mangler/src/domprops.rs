@@ -0,0 +1,873 @@
+//! Names of built-in JavaScript and DOM properties, which are not mangled by default.
+//!
+//! Mangling these would break code which relies on the environment, e.g. `document.body` or
+//! `promise.then`. Based on terser's [domprops](https://github.com/terser/terser/blob/v5.36.0/tools/domprops.js),
+//! plus the properties of JavaScript built-in objects.
+
+pub static DOMPROPS: &[&str] = &[
+    "Array",
+    "BYTES_PER_ELEMENT",
+    "BigInt",
+    "Boolean",
+    "Collator",
+    "Date",
+    "DateTimeFormat",
+    "DisplayNames",
+    "E",
+    "EPSILON",
+    "Error",
+    "Function",
+    "Infinity",
+    "Intl",
+    "JSON",
+    "LN10",
+    "LN2",
+    "LOG10E",
+    "LOG2E",
+    "ListFormat",
+    "Locale",
+    "MAX_SAFE_INTEGER",
+    "MAX_VALUE",
+    "MIN_SAFE_INTEGER",
+    "MIN_VALUE",
+    "Map",
+    "Math",
+    "NEGATIVE_INFINITY",
+    "NaN",
+    "Number",
+    "NumberFormat",
+    "Object",
+    "PI",
+    "POSITIVE_INFINITY",
+    "PluralRules",
+    "Promise",
+    "Proxy",
+    "Reflect",
+    "RegExp",
+    "RelativeTimeFormat",
+    "SQRT1_2",
+    "SQRT2",
+    "Segmenter",
+    "Set",
+    "String",
+    "Symbol",
+    "URL",
+    "UTC",
+    "WeakMap",
+    "WeakRef",
+    "WeakSet",
+    "__defineGetter__",
+    "__defineSetter__",
+    "__lookupGetter__",
+    "__lookupSetter__",
+    "__proto__",
+    "abort",
+    "aborted",
+    "abs",
+    "acos",
+    "acosh",
+    "activeElement",
+    "add",
+    "addEventListener",
+    "addedNodes",
+    "adoptNode",
+    "after",
+    "alert",
+    "all",
+    "allSettled",
+    "alt",
+    "altKey",
+    "anchor",
+    "and",
+    "animate",
+    "animation",
+    "any",
+    "append",
+    "appendChild",
+    "apply",
+    "arc",
+    "arcTo",
+    "arguments",
+    "arrayBuffer",
+    "asIntN",
+    "asUintN",
+    "asin",
+    "asinh",
+    "assign",
+    "asyncDispose",
+    "asyncIterator",
+    "at",
+    "atan",
+    "atan2",
+    "atanh",
+    "attachShadow",
+    "attributeName",
+    "attributes",
+    "autoplay",
+    "back",
+    "background",
+    "backgroundColor",
+    "baseName",
+    "baseURI",
+    "before",
+    "beginPath",
+    "big",
+    "bind",
+    "blink",
+    "blob",
+    "blur",
+    "body",
+    "bodyUsed",
+    "bold",
+    "border",
+    "borderBoxSize",
+    "bottom",
+    "boundingClientRect",
+    "bubbles",
+    "buffer",
+    "button",
+    "buttons",
+    "byteLength",
+    "byteOffset",
+    "cache",
+    "calendar",
+    "calendars",
+    "call",
+    "callee",
+    "caller",
+    "cancelAnimationFrame",
+    "cancelIdleCallback",
+    "cancelable",
+    "capture",
+    "captureStackTrace",
+    "caseFirst",
+    "catch",
+    "cause",
+    "cbrt",
+    "ceil",
+    "changedTouches",
+    "charAt",
+    "charCode",
+    "charCodeAt",
+    "checkValidity",
+    "checked",
+    "childNodes",
+    "children",
+    "classList",
+    "className",
+    "clear",
+    "clearInterval",
+    "clearRect",
+    "clearTimeout",
+    "click",
+    "clientHeight",
+    "clientLeft",
+    "clientTop",
+    "clientWidth",
+    "clientX",
+    "clientY",
+    "clip",
+    "clipboard",
+    "clone",
+    "cloneNode",
+    "close",
+    "closePath",
+    "closest",
+    "clz32",
+    "code",
+    "codePointAt",
+    "collation",
+    "collations",
+    "color",
+    "compare",
+    "compareExchange",
+    "compile",
+    "complete",
+    "concat",
+    "configurable",
+    "confirm",
+    "console",
+    "construct",
+    "constructor",
+    "contains",
+    "contentBoxSize",
+    "contentRect",
+    "controls",
+    "cookie",
+    "cookieEnabled",
+    "copyWithin",
+    "cos",
+    "cosh",
+    "create",
+    "createComment",
+    "createDocumentFragment",
+    "createElement",
+    "createElementNS",
+    "createEvent",
+    "createImageData",
+    "createRange",
+    "createTextNode",
+    "createTreeWalker",
+    "credentials",
+    "crossOrigin",
+    "crypto",
+    "cssFloat",
+    "cssText",
+    "ctrlKey",
+    "currentSrc",
+    "currentTarget",
+    "currentTime",
+    "cursor",
+    "data",
+    "dataset",
+    "decodeURI",
+    "decodeURIComponent",
+    "decoding",
+    "defaultPrevented",
+    "defaultView",
+    "defineProperties",
+    "defineProperty",
+    "delete",
+    "deleteProperty",
+    "deltaMode",
+    "deltaX",
+    "deltaY",
+    "deltaZ",
+    "deref",
+    "description",
+    "detail",
+    "devicePixelRatio",
+    "disabled",
+    "disconnect",
+    "dispatchEvent",
+    "display",
+    "dispose",
+    "document",
+    "documentElement",
+    "domain",
+    "done",
+    "dotAll",
+    "download",
+    "drawImage",
+    "duration",
+    "elementFromPoint",
+    "elementsFromPoint",
+    "encodeURI",
+    "encodeURIComponent",
+    "ended",
+    "endsWith",
+    "entries",
+    "enumerable",
+    "escape",
+    "eval",
+    "every",
+    "exchange",
+    "exec",
+    "exp",
+    "expm1",
+    "fetch",
+    "files",
+    "fill",
+    "fillRect",
+    "fillStyle",
+    "fillText",
+    "filter",
+    "finally",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "firstChild",
+    "firstElementChild",
+    "fixed",
+    "flags",
+    "flat",
+    "flatMap",
+    "floor",
+    "focus",
+    "font",
+    "fontcolor",
+    "fontsize",
+    "for",
+    "forEach",
+    "form",
+    "formData",
+    "format",
+    "formatRange",
+    "formatRangeToParts",
+    "formatToParts",
+    "forms",
+    "forward",
+    "frameElement",
+    "frames",
+    "freeze",
+    "from",
+    "fromCharCode",
+    "fromCodePoint",
+    "fromEntries",
+    "fround",
+    "fullscreenElement",
+    "geolocation",
+    "get",
+    "getAllResponseHeaders",
+    "getAnimations",
+    "getAttribute",
+    "getAttributeNS",
+    "getAttributeNames",
+    "getBigInt64",
+    "getBigUint64",
+    "getBoundingClientRect",
+    "getCanonicalLocales",
+    "getClientRects",
+    "getComputedStyle",
+    "getContext",
+    "getDate",
+    "getDay",
+    "getElementById",
+    "getElementsByClassName",
+    "getElementsByName",
+    "getElementsByTagName",
+    "getFloat32",
+    "getFloat64",
+    "getFullYear",
+    "getHours",
+    "getImageData",
+    "getInt16",
+    "getInt32",
+    "getInt8",
+    "getItem",
+    "getMilliseconds",
+    "getMinutes",
+    "getMonth",
+    "getOwnPropertyDescriptor",
+    "getOwnPropertyDescriptors",
+    "getOwnPropertyNames",
+    "getOwnPropertySymbols",
+    "getPropertyValue",
+    "getPrototypeOf",
+    "getResponseHeader",
+    "getSeconds",
+    "getSelection",
+    "getTime",
+    "getTimezoneOffset",
+    "getUTCDate",
+    "getUTCDay",
+    "getUTCFullYear",
+    "getUTCHours",
+    "getUTCMilliseconds",
+    "getUTCMinutes",
+    "getUTCMonth",
+    "getUTCSeconds",
+    "getUint16",
+    "getUint32",
+    "getUint8",
+    "getYear",
+    "global",
+    "globalAlpha",
+    "globalThis",
+    "go",
+    "groups",
+    "grow",
+    "growable",
+    "handleEvent",
+    "has",
+    "hasAttribute",
+    "hasAttributeNS",
+    "hasChildNodes",
+    "hasFocus",
+    "hasIndices",
+    "hasInstance",
+    "hasOwn",
+    "hasOwnProperty",
+    "hash",
+    "head",
+    "headers",
+    "height",
+    "hidden",
+    "history",
+    "host",
+    "hostname",
+    "hourCycle",
+    "hourCycles",
+    "href",
+    "hypot",
+    "id",
+    "identifier",
+    "ignoreCase",
+    "images",
+    "importNode",
+    "imul",
+    "includes",
+    "index",
+    "indexOf",
+    "indices",
+    "innerHTML",
+    "innerHeight",
+    "innerText",
+    "innerWidth",
+    "input",
+    "insertAdjacentElement",
+    "insertAdjacentHTML",
+    "insertAdjacentText",
+    "insertBefore",
+    "integrity",
+    "intersectionRatio",
+    "is",
+    "isArray",
+    "isConcatSpreadable",
+    "isExtensible",
+    "isFinite",
+    "isFrozen",
+    "isInteger",
+    "isIntersecting",
+    "isLockFree",
+    "isNaN",
+    "isPrototypeOf",
+    "isSafeInteger",
+    "isSealed",
+    "isTrusted",
+    "isView",
+    "isWellFormed",
+    "italics",
+    "item",
+    "iterator",
+    "join",
+    "json",
+    "keepalive",
+    "key",
+    "keyCode",
+    "keyFor",
+    "keys",
+    "language",
+    "languages",
+    "lastChild",
+    "lastElementChild",
+    "lastEventId",
+    "lastIndex",
+    "lastIndexOf",
+    "lastMatch",
+    "lastModified",
+    "lastParen",
+    "left",
+    "leftContext",
+    "length",
+    "lineTo",
+    "lineWidth",
+    "link",
+    "links",
+    "load",
+    "loading",
+    "localName",
+    "localStorage",
+    "localeCompare",
+    "location",
+    "log",
+    "log10",
+    "log1p",
+    "log2",
+    "loop",
+    "map",
+    "margin",
+    "match",
+    "matchAll",
+    "matchMedia",
+    "matches",
+    "max",
+    "maxByteLength",
+    "maximize",
+    "measureText",
+    "message",
+    "metaKey",
+    "method",
+    "min",
+    "minimize",
+    "mode",
+    "moveTo",
+    "movementX",
+    "movementY",
+    "multiline",
+    "multiple",
+    "muted",
+    "name",
+    "namespaceURI",
+    "naturalHeight",
+    "naturalWidth",
+    "navigator",
+    "next",
+    "nextElementSibling",
+    "nextSibling",
+    "nodeName",
+    "nodeType",
+    "nodeValue",
+    "normalize",
+    "notify",
+    "now",
+    "numberingSystem",
+    "numberingSystems",
+    "numeric",
+    "observe",
+    "of",
+    "offsetHeight",
+    "offsetLeft",
+    "offsetParent",
+    "offsetTop",
+    "offsetWidth",
+    "offsetX",
+    "offsetY",
+    "ok",
+    "oldValue",
+    "onLine",
+    "onabort",
+    "onbeforeunload",
+    "onblur",
+    "once",
+    "onchange",
+    "onclick",
+    "onclose",
+    "ondblclick",
+    "onerror",
+    "onfocus",
+    "onhashchange",
+    "oninput",
+    "onkeydown",
+    "onkeypress",
+    "onkeyup",
+    "onload",
+    "onmessage",
+    "onmousedown",
+    "onmouseenter",
+    "onmouseleave",
+    "onmousemove",
+    "onmouseout",
+    "onmouseover",
+    "onmouseup",
+    "onopen",
+    "onpopstate",
+    "onprogress",
+    "onreadystatechange",
+    "onresize",
+    "onscroll",
+    "onsubmit",
+    "onunload",
+    "opacity",
+    "open",
+    "opener",
+    "options",
+    "or",
+    "origin",
+    "outerHTML",
+    "outerHeight",
+    "outerText",
+    "outerWidth",
+    "overflow",
+    "ownKeys",
+    "ownerDocument",
+    "padEnd",
+    "padStart",
+    "padding",
+    "pageX",
+    "pageXOffset",
+    "pageY",
+    "pageYOffset",
+    "parent",
+    "parentElement",
+    "parentNode",
+    "parse",
+    "parseFloat",
+    "parseInt",
+    "passive",
+    "pathname",
+    "pause",
+    "paused",
+    "performance",
+    "placeholder",
+    "platform",
+    "play",
+    "playbackRate",
+    "pointerId",
+    "pointerType",
+    "pop",
+    "port",
+    "ports",
+    "position",
+    "postMessage",
+    "poster",
+    "pow",
+    "prefix",
+    "prepareStackTrace",
+    "prepend",
+    "pressure",
+    "preventDefault",
+    "preventExtensions",
+    "previousElementSibling",
+    "previousSibling",
+    "print",
+    "prompt",
+    "propertyIsEnumerable",
+    "protocol",
+    "prototype",
+    "push",
+    "pushState",
+    "putImageData",
+    "querySelector",
+    "querySelectorAll",
+    "queueMicrotask",
+    "race",
+    "random",
+    "raw",
+    "readAsArrayBuffer",
+    "readAsDataURL",
+    "readAsText",
+    "readOnly",
+    "readyState",
+    "reason",
+    "rect",
+    "redirect",
+    "redirected",
+    "reduce",
+    "reduceRight",
+    "referrer",
+    "referrerPolicy",
+    "region",
+    "register",
+    "reject",
+    "rel",
+    "reload",
+    "remove",
+    "removeAttribute",
+    "removeAttributeNS",
+    "removeChild",
+    "removeEventListener",
+    "removeItem",
+    "removeProperty",
+    "removedNodes",
+    "repeat",
+    "replace",
+    "replaceAll",
+    "replaceChild",
+    "replaceChildren",
+    "replaceState",
+    "replaceWith",
+    "reportValidity",
+    "requestAnimationFrame",
+    "requestIdleCallback",
+    "required",
+    "reset",
+    "resizable",
+    "resize",
+    "resolve",
+    "resolvedOptions",
+    "response",
+    "responseText",
+    "responseType",
+    "responseURL",
+    "responseXML",
+    "restore",
+    "result",
+    "return",
+    "reverse",
+    "revocable",
+    "right",
+    "rightContext",
+    "rootBounds",
+    "rotate",
+    "round",
+    "save",
+    "scale",
+    "screen",
+    "screenX",
+    "screenY",
+    "script",
+    "scripts",
+    "scroll",
+    "scrollBy",
+    "scrollHeight",
+    "scrollIntoView",
+    "scrollLeft",
+    "scrollTo",
+    "scrollTop",
+    "scrollWidth",
+    "scrollX",
+    "scrollY",
+    "seal",
+    "search",
+    "segment",
+    "select",
+    "selectRange",
+    "selected",
+    "selectedIndex",
+    "self",
+    "send",
+    "sendBeacon",
+    "serviceWorker",
+    "sessionStorage",
+    "set",
+    "setAttribute",
+    "setAttributeNS",
+    "setBigInt64",
+    "setBigUint64",
+    "setCustomValidity",
+    "setDate",
+    "setFloat32",
+    "setFloat64",
+    "setFullYear",
+    "setHours",
+    "setInt16",
+    "setInt32",
+    "setInt8",
+    "setInterval",
+    "setItem",
+    "setMilliseconds",
+    "setMinutes",
+    "setMonth",
+    "setProperty",
+    "setPrototypeOf",
+    "setRequestHeader",
+    "setSeconds",
+    "setTime",
+    "setTimeout",
+    "setTransform",
+    "setUTCDate",
+    "setUTCFullYear",
+    "setUTCHours",
+    "setUTCMilliseconds",
+    "setUTCMinutes",
+    "setUTCMonth",
+    "setUTCSeconds",
+    "setUint16",
+    "setUint32",
+    "setUint8",
+    "setYear",
+    "shadowRoot",
+    "shift",
+    "shiftKey",
+    "sign",
+    "signal",
+    "sin",
+    "sinh",
+    "size",
+    "sizes",
+    "slice",
+    "slot",
+    "small",
+    "some",
+    "sort",
+    "source",
+    "species",
+    "splice",
+    "split",
+    "sqrt",
+    "src",
+    "srcset",
+    "stack",
+    "stackTraceLimit",
+    "startsWith",
+    "state",
+    "status",
+    "statusText",
+    "sticky",
+    "stopImmediatePropagation",
+    "stopPropagation",
+    "store",
+    "stream",
+    "strike",
+    "stringify",
+    "stroke",
+    "strokeRect",
+    "strokeStyle",
+    "strokeText",
+    "style",
+    "styleSheets",
+    "sub",
+    "subarray",
+    "submit",
+    "substr",
+    "substring",
+    "sup",
+    "supportedLocalesOf",
+    "supportedValuesOf",
+    "tagName",
+    "takeRecords",
+    "tan",
+    "tanh",
+    "target",
+    "targetTouches",
+    "test",
+    "text",
+    "textContent",
+    "textInfo",
+    "then",
+    "throw",
+    "timeStamp",
+    "timeZones",
+    "timeout",
+    "title",
+    "toBlob",
+    "toDataURL",
+    "toDateString",
+    "toExponential",
+    "toFixed",
+    "toGMTString",
+    "toISOString",
+    "toJSON",
+    "toLocaleDateString",
+    "toLocaleLowerCase",
+    "toLocaleString",
+    "toLocaleTimeString",
+    "toLocaleUpperCase",
+    "toLowerCase",
+    "toPrecision",
+    "toPrimitive",
+    "toReversed",
+    "toSorted",
+    "toSpliced",
+    "toString",
+    "toStringTag",
+    "toTimeString",
+    "toUTCString",
+    "toUpperCase",
+    "toWellFormed",
+    "toggle",
+    "toggleAttribute",
+    "top",
+    "touches",
+    "transform",
+    "transition",
+    "translate",
+    "trim",
+    "trimEnd",
+    "trimLeft",
+    "trimRight",
+    "trimStart",
+    "trunc",
+    "type",
+    "undefined",
+    "unescape",
+    "unicode",
+    "unicodeSets",
+    "unobserve",
+    "unregister",
+    "unscopables",
+    "unshift",
+    "upload",
+    "userAgent",
+    "validationMessage",
+    "validity",
+    "value",
+    "valueOf",
+    "values",
+    "visibility",
+    "visibilityState",
+    "volume",
+    "wait",
+    "waitAsync",
+    "weekInfo",
+    "which",
+    "width",
+    "window",
+    "with",
+    "withCredentials",
+    "writable",
+    "xor",
+    "zIndex",
+];
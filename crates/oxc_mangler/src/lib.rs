@@ -4,6 +4,7 @@ use itertools::Itertools;
 use keep_names::collect_name_symbols;
 use oxc_index::IndexVec;
 use oxc_syntax::class::ClassId;
+use properties::mangle_properties;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use base54::base54;
@@ -14,11 +15,16 @@ use oxc_semantic::{AstNodes, Reference, Scoping, Semantic, SemanticBuilder, Symb
 use oxc_span::{Atom, CompactStr};
 
 pub(crate) mod base54;
+mod domprops;
 mod keep_names;
+mod name_cache;
+mod properties;
 
 pub use keep_names::MangleOptionsKeepNames;
+pub use name_cache::NameCache;
+pub use properties::MangleOptionsProperties;
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone)]
 pub struct MangleOptions {
     /// Pass true to mangle names declared in the top level scope.
     ///
@@ -33,6 +39,16 @@ pub struct MangleOptions {
     ///
     /// Uses base54 if false.
     pub debug: bool,
+
+    /// Mangle property names of objects and classes.
+    ///
+    /// Properties are not mangled if `None`.
+    pub properties: Option<MangleOptionsProperties>,
+
+    /// Mangled names from a previous build, which are reused where possible.
+    ///
+    /// See [`NameCache`].
+    pub name_cache: Option<NameCache>,
 }
 
 type Slot = u32;
@@ -62,6 +78,21 @@ pub struct ManglerReturn {
     /// A vector where each element corresponds to a class in declaration order.
     /// Each element is a mapping from original private member names to their mangled names.
     pub class_private_mappings: IndexVec<ClassId, FxHashMap<String, CompactStr>>,
+    /// A mapping from original property names to their mangled names.
+    /// Empty if [`MangleOptions::properties`] is `None`.
+    pub property_mappings: FxHashMap<String, CompactStr>,
+    /// The name cache to pass to the next build, see [`MangleOptions::name_cache`].
+    pub name_cache: NameCache,
+}
+
+/// Mappings returned by [`Mangler::build_with_semantic`].
+pub struct ManglerMappings {
+    /// See [`ManglerReturn::class_private_mappings`].
+    pub class_private_mappings: IndexVec<ClassId, FxHashMap<String, CompactStr>>,
+    /// See [`ManglerReturn::property_mappings`].
+    pub property_mappings: FxHashMap<String, CompactStr>,
+    /// See [`ManglerReturn::name_cache`].
+    pub name_cache: NameCache,
 }
 
 /// # Name Mangler / Symbol Minification
@@ -82,7 +113,7 @@ pub struct ManglerReturn {
 /// assert!(parsed.errors.is_empty());
 ///
 /// let mangled_symbols = Mangler::new()
-///     .with_options(MangleOptions { top_level: true, debug: true })
+///     .with_options(MangleOptions { top_level: true, debug: true, ..MangleOptions::default() })
 ///     .build(&parsed.program);
 ///
 /// let js = Codegen::new().with_symbol_table(mangled_symbols).build(&parsed.program);
@@ -267,10 +298,19 @@ impl<'t> Mangler<'t> {
     #[must_use]
     pub fn build(self, program: &Program<'_>) -> ManglerReturn {
         let mut semantic = SemanticBuilder::new().build(program).semantic;
-        let class_private_mappings = self.build_with_semantic(&mut semantic, program);
-        ManglerReturn { scoping: semantic.into_scoping(), class_private_mappings }
+        let ManglerMappings { class_private_mappings, property_mappings, name_cache } =
+            self.build_with_semantic(&mut semantic, program);
+        ManglerReturn {
+            scoping: semantic.into_scoping(),
+            class_private_mappings,
+            property_mappings,
+            name_cache,
+        }
     }
 
+    /// Returns mappings of class private member names and property names to their mangled names,
+    /// and the updated name cache.
+    ///
     /// # Panics
     ///
     /// Panics if the child_ids does not exist in scope_tree.
@@ -278,14 +318,14 @@ impl<'t> Mangler<'t> {
         self,
         semantic: &mut Semantic<'_>,
         program: &Program<'_>,
-    ) -> IndexVec<ClassId, FxHashMap<String, CompactStr>> {
+    ) -> ManglerMappings {
         let class_private_mappings = Self::collect_private_members_from_semantic(semantic);
-        if self.options.debug {
-            self.build_with_semantic_impl(semantic, program, debug_name);
+        let (property_mappings, name_cache) = if self.options.debug {
+            self.build_with_semantic_impl(semantic, program, debug_name)
         } else {
-            self.build_with_semantic_impl(semantic, program, base54);
-        }
-        class_private_mappings
+            self.build_with_semantic_impl(semantic, program, base54)
+        };
+        ManglerMappings { class_private_mappings, property_mappings, name_cache }
     }
 
     fn build_with_semantic_impl<const CAPACITY: usize, G: Fn(u32) -> InlineString<CAPACITY, u8>>(
@@ -293,8 +333,10 @@ impl<'t> Mangler<'t> {
         semantic: &mut Semantic<'_>,
         program: &Program<'_>,
         generate_name: G,
-    ) {
+    ) -> (FxHashMap<String, CompactStr>, NameCache) {
         let (scoping, ast_nodes) = semantic.scoping_mut_and_nodes();
+        let previous_name_cache = self.options.name_cache.as_ref();
+        let mut name_cache = previous_name_cache.cloned().unwrap_or_default();
 
         let (exported_names, exported_symbols) = if self.options.top_level {
             Mangler::collect_exported_symbols(program)
@@ -441,7 +483,7 @@ impl<'t> Mangler<'t> {
 
         let total_number_of_slots = slot_liveness.len();
 
-        let frequencies = self.tally_slot_frequencies(
+        let mut frequencies = self.tally_slot_frequencies(
             scoping,
             &exported_symbols,
             &keep_name_symbols,
@@ -449,12 +491,50 @@ impl<'t> Mangler<'t> {
             &slots,
         );
 
+        let root_scope_id = scoping.root_scope_id();
         let root_unresolved_references = scoping.root_unresolved_references();
-        let root_bindings = scoping.get_bindings(scoping.root_scope_id());
+        let root_bindings = scoping.get_bindings(root_scope_id);
+
+        // Do not mangle keywords, unresolved references, and names from eval scopes.
+        // Variables in direct-eval-containing scopes keep their original names
+        // (those scopes are skipped during slot assignment), and we also reserve
+        // those names here to prevent mangled names from shadowing them.
+        let is_name_available = |n: &str| {
+            !oxc_syntax::keyword::is_reserved_keyword(n)
+                && !is_special_name(n)
+                && !root_unresolved_references.contains_key(n)
+                && !(root_bindings.contains_key(n)
+                    && (!self.options.top_level || exported_names.contains(n)))
+                // TODO: only skip the names that are kept in the current scope
+                && !keep_name_names.contains(n)
+                && !eval_reserved_names.contains(n)
+        };
 
-        // Generate reserved names only for slots that have symbols (frequencies.len())
+        // Slots containing a top level symbol which is in the name cache keep the cached name.
+        // Other symbols in the same slot are not alive in the root scope, so they can share the name.
+        let mut cached_slot_names: FxHashMap<Slot, &str> = FxHashMap::default();
+        if let Some(previous_name_cache) = previous_name_cache {
+            let mut cached_names_used = FxHashSet::default();
+            for freq in &frequencies {
+                let cached_name = freq.symbol_ids.iter().find_map(|&symbol_id| {
+                    if scoping.symbol_scope_id(symbol_id) != root_scope_id {
+                        return None;
+                    }
+                    previous_name_cache.vars.get(scoping.symbol_name(symbol_id))
+                });
+                if let Some(cached_name) = cached_name
+                    && is_name_available(cached_name)
+                    && cached_names_used.insert(cached_name.as_str())
+                {
+                    cached_slot_names.insert(freq.slot, cached_name.as_str());
+                }
+            }
+        }
+        let cached_names = cached_slot_names.values().copied().collect::<FxHashSet<_>>();
+
+        // Generate reserved names only for slots that have symbols and no cached name
         // instead of all slots. This avoids generating unused names.
-        let names_needed = frequencies.len();
+        let names_needed = frequencies.len() - cached_slot_names.len();
         let mut reserved_names = Vec::with_capacity_in(names_needed, temp_allocator);
 
         let mut count = 0;
@@ -462,26 +542,23 @@ impl<'t> Mangler<'t> {
             let name = loop {
                 let name = generate_name(count);
                 count += 1;
-                // Do not mangle keywords, unresolved references, and names from eval scopes.
-                // Variables in direct-eval-containing scopes keep their original names
-                // (those scopes are skipped during slot assignment), and we also reserve
-                // those names here to prevent mangled names from shadowing them.
                 let n = name.as_str();
-                if !oxc_syntax::keyword::is_reserved_keyword(n)
-                    && !is_special_name(n)
-                    && !root_unresolved_references.contains_key(n)
-                    && !(root_bindings.contains_key(n)
-                        && (!self.options.top_level || exported_names.contains(n)))
-                    // TODO: only skip the names that are kept in the current scope
-                    && !keep_name_names.contains(n)
-                    && !eval_reserved_names.contains(n)
-                {
+                if is_name_available(n) && !cached_names.contains(n) {
                     break name;
                 }
             };
             reserved_names.push(name);
         }
 
+        // Rename slots with cached names, leaving the other slots to be assigned generated names.
+        frequencies.retain(|freq| {
+            let Some(&new_name) = cached_slot_names.get(&freq.slot) else { return true };
+            for &symbol_id in &freq.symbol_ids {
+                Self::rename_symbol(scoping, symbol_id, new_name, &mut name_cache);
+            }
+            false
+        });
+
         // Group similar symbols for smaller gzipped file
         // <https://github.com/google/closure-compiler/blob/c383a3a1d2fce33b6c778ef76b5a626e07abca41/src/com/google/javascript/jscomp/RenameVars.java#L475-L483>
         // Original Comment:
@@ -527,10 +604,41 @@ impl<'t> Mangler<'t> {
             // rename the variables
             for (symbol_to_rename, new_name) in symbols_to_rename_with_new_names {
                 for &symbol_id in &symbol_to_rename.symbol_ids {
-                    scoping.set_symbol_name(symbol_id, new_name);
+                    Self::rename_symbol(scoping, symbol_id, new_name, &mut name_cache);
                 }
             }
         }
+
+        // Mangle properties after symbols, so that mangled property names can skip symbol names.
+        let Some(properties) = &self.options.properties else {
+            return (FxHashMap::default(), name_cache);
+        };
+        let symbol_names = scoping.symbol_names().collect::<FxHashSet<_>>();
+        let cached_properties = previous_name_cache.map(|cache| &cache.props);
+        let property_mappings = mangle_properties(
+            properties,
+            ast_nodes,
+            &symbol_names,
+            cached_properties,
+            generate_name,
+        );
+        name_cache.props.extend(property_mappings.iter().map(|(k, v)| (k.clone(), v.clone())));
+        (property_mappings, name_cache)
+    }
+
+    /// Rename a symbol, recording the new name in `name_cache` if it is a top level symbol.
+    fn rename_symbol(
+        scoping: &mut Scoping,
+        symbol_id: SymbolId,
+        new_name: &str,
+        name_cache: &mut NameCache,
+    ) {
+        if scoping.symbol_scope_id(symbol_id) == scoping.root_scope_id() {
+            name_cache
+                .vars
+                .insert(scoping.symbol_name(symbol_id).to_string(), CompactStr::new(new_name));
+        }
+        scoping.set_symbol_name(symbol_id, new_name);
     }
 
     fn tally_slot_frequencies<'a>(
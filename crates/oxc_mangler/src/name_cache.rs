@@ -0,0 +1,28 @@
+use rustc_hash::FxHashMap;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use oxc_span::CompactStr;
+
+/// Mangled names assigned by previous runs of the mangler.
+///
+/// Pass the cache returned in [`crate::ManglerReturn::name_cache`] to the next build via
+/// [`crate::MangleOptions::name_cache`], so names which were mangled before keep their mangled names.
+/// This keeps output stable between builds, e.g. a small change to the input does not rename
+/// identifiers across the whole file. Similar to terser's `nameCache` option.
+///
+/// Names of previous runs which do not appear in the program are kept in the returned cache.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct NameCache {
+    /// A mapping from original names of top level symbols to their mangled names.
+    ///
+    /// Only used if [`crate::MangleOptions::top_level`] is `true`,
+    /// as top level symbols are not mangled otherwise.
+    pub vars: FxHashMap<String, CompactStr>,
+
+    /// A mapping from original property names to their mangled names.
+    ///
+    /// Only used if [`crate::MangleOptions::properties`] is `Some`.
+    pub props: FxHashMap<String, CompactStr>,
+}
@@ -0,0 +1,217 @@
+use lazy_regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_ast::{AstKind, ast::*};
+use oxc_data_structures::inline_string::InlineString;
+use oxc_semantic::AstNodes;
+use oxc_span::CompactStr;
+
+use crate::domprops::DOMPROPS;
+
+/// Options for mangling property names of objects and classes.
+///
+/// Properties are renamed consistently across the whole program, so all code which reads or writes
+/// a mangled property must be minified together. Names used in strings which are not rewritten, like
+/// `"foo" in obj` or `Object.defineProperty(obj, "foo", descriptor)`, are never mangled.
+#[derive(Debug, Clone, Default)]
+pub struct MangleOptionsProperties {
+    /// Only mangle property names matching this regex.
+    ///
+    /// All property names are mangled if `None`.
+    pub regex: Option<Regex>,
+
+    /// Property names which are never mangled.
+    pub reserved: Vec<String>,
+
+    /// Do not mangle quoted property names, e.g. `obj["foo"]` or `{ "foo": 1 }`.
+    ///
+    /// A name which appears quoted anywhere in the program is not mangled in unquoted positions either.
+    ///
+    /// Default `false`
+    pub keep_quoted: bool,
+
+    /// Allow mangling names of built-in JavaScript and DOM properties, e.g. `then` or `addEventListener`.
+    ///
+    /// Default `false`
+    pub builtins: bool,
+}
+
+/// Names which have special meaning in syntax, and cannot be mangled even if `builtins` is `true`.
+const SPECIAL_PROPERTY_NAMES: [&str; 2] = ["__proto__", "constructor"];
+
+/// Functions which take a property name as a string argument, with the index of that argument,
+/// e.g. `Object.defineProperty(obj, "foo", descriptor)`.
+const PROPERTY_NAME_FUNCTIONS: [(&str, &str, usize); 9] = [
+    ("Object", "defineProperty", 1),
+    ("Object", "getOwnPropertyDescriptor", 1),
+    ("Object", "hasOwn", 1),
+    ("Reflect", "defineProperty", 1),
+    ("Reflect", "deleteProperty", 1),
+    ("Reflect", "get", 1),
+    ("Reflect", "getOwnPropertyDescriptor", 1),
+    ("Reflect", "has", 1),
+    ("Reflect", "set", 1),
+];
+
+/// A property name which appears in the program.
+struct PropertyName<'a> {
+    name: &'a str,
+    frequency: usize,
+}
+
+/// Collects all property names in the program.
+#[derive(Default)]
+struct PropertyCollector<'a> {
+    /// Property names in order of first appearance.
+    names: Vec<PropertyName<'a>>,
+    indexes: FxHashMap<&'a str, usize>,
+    quoted: FxHashSet<&'a str>,
+    /// Names in strings which are used as property names at runtime, but are not rewritten,
+    /// e.g. `"foo" in obj`. These are never mangled.
+    reserved: FxHashSet<&'a str>,
+}
+
+impl<'a> PropertyCollector<'a> {
+    fn collect(ast_nodes: &AstNodes<'a>) -> Self {
+        let mut collector = Self::default();
+        for node in ast_nodes.iter() {
+            match node.kind() {
+                AstKind::StaticMemberExpression(expr) => {
+                    collector.add(expr.property.name.as_str(), false);
+                }
+                AstKind::ComputedMemberExpression(expr) => {
+                    if let Expression::StringLiteral(s) = &expr.expression {
+                        collector.add(s.value.as_str(), true);
+                    }
+                }
+                AstKind::ObjectProperty(prop) => collector.add_key(&prop.key),
+                AstKind::MethodDefinition(method) => collector.add_key(&method.key),
+                AstKind::PropertyDefinition(prop) => collector.add_key(&prop.key),
+                AstKind::AccessorProperty(prop) => collector.add_key(&prop.key),
+                AstKind::BindingProperty(prop) => collector.add_key(&prop.key),
+                AstKind::AssignmentTargetPropertyIdentifier(prop) => {
+                    collector.add(prop.binding.name.as_str(), false);
+                }
+                AstKind::AssignmentTargetPropertyProperty(prop) => collector.add_key(&prop.name),
+                AstKind::BinaryExpression(expr) if expr.operator == BinaryOperator::In => {
+                    if let Expression::StringLiteral(s) = &expr.left {
+                        collector.reserved.insert(s.value.as_str());
+                    }
+                }
+                AstKind::CallExpression(call) => {
+                    let index = PROPERTY_NAME_FUNCTIONS.iter().find_map(|(object, name, index)| {
+                        call.callee.is_specific_member_access(object, name).then_some(*index)
+                    });
+                    if let Some(Argument::StringLiteral(s)) =
+                        index.and_then(|i| call.arguments.get(i))
+                    {
+                        collector.reserved.insert(s.value.as_str());
+                    }
+                }
+                _ => {}
+            }
+        }
+        collector
+    }
+
+    fn add_key(&mut self, key: &PropertyKey<'a>) {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => self.add(ident.name.as_str(), false),
+            PropertyKey::StringLiteral(s) => self.add(s.value.as_str(), true),
+            _ => {}
+        }
+    }
+
+    fn add(&mut self, name: &'a str, quoted: bool) {
+        if quoted {
+            self.quoted.insert(name);
+        }
+        let index = *self.indexes.entry(name).or_insert_with(|| {
+            self.names.push(PropertyName { name, frequency: 0 });
+            self.names.len() - 1
+        });
+        self.names[index].frequency += 1;
+    }
+}
+
+/// Generate mangled names for property names in the program.
+///
+/// Names are taken from the same sequence as the names of mangled symbols, skipping names which are
+/// already used by symbols, and names of properties which are not mangled or reserved, so that
+/// mangled properties do not collide with either. Properties in `cache` keep their cached names if
+/// those names are still available. Returns a mapping from original property names to mangled names.
+pub fn mangle_properties<const CAPACITY: usize, G: Fn(u32) -> InlineString<CAPACITY, u8>>(
+    options: &MangleOptionsProperties,
+    ast_nodes: &AstNodes<'_>,
+    symbol_names: &FxHashSet<&str>,
+    cache: Option<&FxHashMap<String, CompactStr>>,
+    generate_name: G,
+) -> FxHashMap<String, CompactStr> {
+    let collector = PropertyCollector::collect(ast_nodes);
+
+    let reserved = options.reserved.iter().map(String::as_str).collect::<FxHashSet<_>>();
+    let builtins = DOMPROPS.iter().copied().collect::<FxHashSet<_>>();
+    let should_mangle = |name: &str| {
+        if SPECIAL_PROPERTY_NAMES.contains(&name)
+            || reserved.contains(name)
+            || collector.reserved.contains(name)
+        {
+            return false;
+        }
+        if !options.builtins && builtins.contains(name) {
+            return false;
+        }
+        if options.keep_quoted && collector.quoted.contains(name) {
+            return false;
+        }
+        options.regex.as_ref().is_none_or(|regex| regex.is_match(name))
+    };
+
+    let (mut mangled, unmangled): (Vec<_>, Vec<_>) =
+        collector.names.iter().partition(|property| should_mangle(property.name));
+    let unmangled = unmangled.iter().map(|property| property.name).collect::<FxHashSet<_>>();
+
+    // The most frequent properties get the shortest names.
+    // Stable sort keeps properties with the same frequency in order of first appearance.
+    mangled.sort_by_key(|property| std::cmp::Reverse(property.frequency));
+
+    // Never use a built-in name, even if `builtins` is `true`,
+    // e.g. `arr.foo` -> `arr.at` would read `Array.prototype.at`.
+    let is_name_available = |n: &str| {
+        !oxc_syntax::keyword::is_reserved_keyword(n)
+            && !symbol_names.contains(n)
+            && !unmangled.contains(n)
+            && !reserved.contains(n)
+            && !builtins.contains(n)
+    };
+
+    let mut mappings = FxHashMap::default();
+    let mut cached_names = FxHashSet::default();
+    if let Some(cache) = cache {
+        for property in &mangled {
+            if let Some(cached_name) = cache.get(property.name)
+                && is_name_available(cached_name)
+                && cached_names.insert(cached_name.as_str())
+            {
+                mappings.insert(property.name.to_string(), cached_name.clone());
+            }
+        }
+    }
+
+    let mut count = 0;
+    for property in mangled {
+        if mappings.contains_key(property.name) {
+            continue;
+        }
+        let name = loop {
+            let name = generate_name(count);
+            count += 1;
+            let n = name.as_str();
+            if is_name_available(n) && !cached_names.contains(n) {
+                break name;
+            }
+        };
+        mappings.insert(property.name.to_string(), CompactStr::new(name.as_str()));
+    }
+    mappings
+}
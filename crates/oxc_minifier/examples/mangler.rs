@@ -15,13 +15,14 @@
 //!
 //! - `--keep-names`: Preserve function and class names
 //! - `--debug`: Enable debug output
+//! - `--properties`: Mangle property names
 //! - `--twice`: Test idempotency by running twice
 
 use std::path::Path;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
-use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler};
+use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, MangleOptionsProperties, Mangler};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 use pico_args::Arguments;
@@ -35,6 +36,7 @@ fn main() -> std::io::Result<()> {
 
     let keep_names = args.contains("--keep-names");
     let debug = args.contains("--debug");
+    let properties = args.contains("--properties");
     let twice = args.contains("--twice");
     let name = args.free_from_str().unwrap_or_else(|_| "test.js".to_string());
 
@@ -46,8 +48,10 @@ fn main() -> std::io::Result<()> {
         top_level: !source_type.is_script(),
         keep_names: MangleOptionsKeepNames { function: keep_names, class: keep_names },
         debug,
+        properties: properties.then(MangleOptionsProperties::default),
+        name_cache: None,
     };
-    let printed = mangler(&source_text, source_type, options);
+    let printed = mangler(&source_text, source_type, options.clone());
     println!("{printed}");
 
     if twice {
@@ -67,6 +71,7 @@ fn mangler(source_text: &str, source_type: SourceType, options: MangleOptions) -
     Codegen::new()
         .with_scoping(Some(mangler_return.scoping))
         .with_private_member_mappings(Some(mangler_return.class_private_mappings))
+        .with_property_mappings(Some(mangler_return.property_mappings))
         .build(&ret.program)
         .code
 }
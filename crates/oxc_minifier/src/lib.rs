@@ -61,7 +61,7 @@ use oxc_span::CompactStr;
 use oxc_syntax::class::ClassId;
 use rustc_hash::FxHashMap;
 
-pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames};
+pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, NameCache};
 
 pub use crate::{compressor::Compressor, options::*};
 
@@ -84,6 +84,12 @@ pub struct MinifierReturn {
     /// Each element is a mapping from original private member names to their mangled names.
     pub class_private_mappings: Option<IndexVec<ClassId, FxHashMap<String, CompactStr>>>,
 
+    /// A mapping from original property names to their mangled names.
+    pub property_mappings: Option<FxHashMap<String, CompactStr>>,
+
+    /// The name cache to pass to the next build, see [`MangleOptions::name_cache`].
+    pub name_cache: Option<NameCache>,
+
     /// Total number of iterations ran. Useful for debugging performance issues.
     pub iterations: u8,
 }
@@ -132,17 +138,28 @@ impl<'a> Minifier {
                 (stats, iterations)
             })
             .unwrap_or_default();
-        let (scoping, class_private_mappings) = self
+        let (scoping, mappings) = self
             .options
             .mangle
             .map(|options| {
                 let mut semantic = SemanticBuilder::new().with_stats(stats).build(program).semantic;
-                let class_private_mappings = Mangler::default()
+                let mappings = Mangler::default()
                     .with_options(options)
                     .build_with_semantic(&mut semantic, program);
-                (semantic.into_scoping(), class_private_mappings)
+                (semantic.into_scoping(), mappings)
             })
-            .map_or((None, None), |(scoping, mappings)| (Some(scoping), Some(mappings)));
-        MinifierReturn { scoping, class_private_mappings, iterations }
+            .unzip();
+        let (class_private_mappings, property_mappings, name_cache) = mappings
+            .map(|m| {
+                (Some(m.class_private_mappings), Some(m.property_mappings), Some(m.name_cache))
+            })
+            .unwrap_or_default();
+        MinifierReturn {
+            scoping,
+            class_private_mappings,
+            property_mappings,
+            name_cache,
+            iterations,
+        }
     }
 }
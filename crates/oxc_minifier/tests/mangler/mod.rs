@@ -1,23 +1,32 @@
 use std::fmt::Write;
 
+use lazy_regex::Regex;
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
-use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler};
+use oxc_mangler::{
+    MangleOptions, MangleOptionsKeepNames, MangleOptionsProperties, Mangler, NameCache,
+};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
 fn mangle(source_text: &str, options: MangleOptions) -> String {
+    mangle_with_name_cache(source_text, options).0
+}
+
+fn mangle_with_name_cache(source_text: &str, options: MangleOptions) -> (String, NameCache) {
     let allocator = Allocator::default();
     let source_type = SourceType::mjs();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     assert!(ret.errors.is_empty(), "Parser errors: {:?}", ret.errors);
     let program = ret.program;
     let mangler_return = Mangler::new().with_options(options).build(&program);
-    Codegen::new()
+    let code = Codegen::new()
         .with_scoping(Some(mangler_return.scoping))
         .with_private_member_mappings(Some(mangler_return.class_private_mappings))
+        .with_property_mappings(Some(mangler_return.property_mappings))
         .build(&program)
-        .code
+        .code;
+    (code, mangler_return.name_cache)
 }
 
 fn test(source_text: &str, expected: &str, options: MangleOptions) {
@@ -41,12 +50,12 @@ fn direct_eval() {
 
     // Symbols in scopes with direct eval should NOT be mangled
     let source_text = "function foo() { let NO_MANGLE; eval('') }";
-    let mangled = mangle(source_text, options);
+    let mangled = mangle(source_text, options.clone());
     assert_eq!(mangled, "function foo() {\n\tlet NO_MANGLE;\n\teval(\"\");\n}\n");
 
     // Nested direct eval: parent scope also should not mangle
     let source_text = "function foo() { let NO_MANGLE; function bar() { eval('') } }";
-    let mangled = mangle(source_text, options);
+    let mangled = mangle(source_text, options.clone());
     assert_eq!(
         mangled,
         "function foo() {\n\tlet NO_MANGLE;\n\tfunction bar() {\n\t\teval(\"\");\n\t}\n}\n"
@@ -55,14 +64,14 @@ fn direct_eval() {
     // Sibling scope without direct eval should be mangled
     let source_text =
         "function foo() { let NO_MANGLE; eval('') } function bar() { let SHOULD_MANGLE; }";
-    let mangled = mangle(source_text, options);
+    let mangled = mangle(source_text, options.clone());
     // SHOULD_MANGLE gets mangled (to some short name), NO_MANGLE stays as is
     assert!(mangled.contains("NO_MANGLE"));
     assert!(!mangled.contains("SHOULD_MANGLE"));
 
     // Child function scope without direct eval CAN be mangled (eval in parent cannot access child function locals)
     let source_text = "function foo() { eval(''); function bar() { let CAN_MANGLE; } }";
-    let mangled = mangle(source_text, options);
+    let mangled = mangle(source_text, options.clone());
     assert!(!mangled.contains("CAN_MANGLE"));
 
     // Indirect eval should still allow mangling
@@ -207,3 +216,121 @@ fn private_member_mangling() {
         insta::assert_snapshot!("private_member_mangling", snapshot);
     });
 }
+
+#[test]
+fn property_mangling() {
+    let cases = [
+        "const obj = { foo: 1, bar: 2 }; obj.foo + obj.bar",
+        "class Foo { foo = 1; bar() { return this.foo; } static baz() {} get qux() {} }",
+        // Shorthand properties are expanded when the key is mangled
+        "function _(foo) { return { foo } }",
+        "function _(obj) { const { foo, bar: baz = 1 } = obj; return foo + baz }",
+        "function _(foo, obj) { ({ foo } = obj); return foo }",
+        // Quoted properties are mangled consistently
+        "const obj = { 'foo': 1 }; obj.foo + obj['foo']",
+        // Built-in and special properties are not mangled
+        "promise.then(x => x.length); document.body.appendChild(el)",
+        "const obj = { __proto__: null, constructor: 1 }; class Foo { constructor() {} }",
+        // Mangled names do not collide with unmangled property names or symbol names
+        "function _(a) { return a.foo + a.bar + a.b + a.c }",
+        // Properties named by strings in `in` and property reflection functions are not mangled
+        "const obj = { foo: 1, bar: 2 }; 'foo' in obj && obj.bar",
+        "const obj = {}; Object.defineProperty(obj, 'foo', { value: 1 }); obj.foo + obj.bar",
+        "function _(obj) { return Reflect.has(obj, 'foo') ? obj.foo : obj.bar }",
+    ];
+
+    let mut snapshot = String::new();
+    cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptions {
+            properties: Some(MangleOptionsProperties::default()),
+            ..MangleOptions::default()
+        };
+        write!(w, "{case}\n{}\n", mangle(case, options)).unwrap();
+        w
+    });
+
+    let regex_cases = [
+        "const obj = { _foo: 1, bar: 2 }; obj._foo + obj.bar",
+        "class Foo { _foo() {} _bar = 1 } new Foo()._foo()",
+    ];
+    regex_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptions {
+            properties: Some(MangleOptionsProperties {
+                regex: Some(Regex::new("^_[a-z]+$").unwrap()),
+                ..MangleOptionsProperties::default()
+            }),
+            ..MangleOptions::default()
+        };
+        write!(w, "{case}\n{}\n", mangle(case, options)).unwrap();
+        w
+    });
+
+    let reserved_cases = [
+        "const obj = { foo: 1, bar: 2, 'baz': 3 }; obj.foo + obj.bar + obj.baz",
+        "const obj = { then: 1 }; obj.then",
+    ];
+    reserved_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptions {
+            properties: Some(MangleOptionsProperties {
+                reserved: vec!["foo".to_string()],
+                keep_quoted: true,
+                builtins: true,
+                ..MangleOptionsProperties::default()
+            }),
+            ..MangleOptions::default()
+        };
+        write!(w, "{case}\n{}\n", mangle(case, options)).unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("property_mangling", snapshot);
+    });
+}
+
+#[test]
+fn name_cache() {
+    // Each case is built twice, the second (edited) source is seeded with the name cache of the first.
+    let cases = [
+        // Existing top level symbols keep their names, even if new symbols are more frequent
+        (
+            "function foo() {} function bar() {} foo(bar)",
+            "function baz() {} function foo() {} function bar() {} foo(bar); baz(baz(baz))",
+        ),
+        // Nested symbols can share the cached names
+        (
+            "function foo(x) { return x } foo(1)",
+            "function bar(x, y) { return x + y } function foo(x) { return bar(x, x) } foo(1)",
+        ),
+        // Cached names which are no longer available are not used
+        ("function foo() {} foo()", "function foo() {} foo(e)"),
+        // Existing properties keep their names
+        ("obj.foo; obj.bar", "obj.baz; obj.baz; obj.baz; obj.foo; obj.bar"),
+    ];
+
+    let options = MangleOptions {
+        top_level: true,
+        properties: Some(MangleOptionsProperties::default()),
+        ..MangleOptions::default()
+    };
+    let mut snapshot = String::new();
+    cases.into_iter().fold(&mut snapshot, |w, (first, second)| {
+        let (first_mangled, name_cache) = mangle_with_name_cache(first, options.clone());
+        let options = MangleOptions { name_cache: Some(name_cache), ..options.clone() };
+        let (second_mangled, name_cache) = mangle_with_name_cache(second, options);
+        let mut vars = name_cache.vars.iter().collect::<Vec<_>>();
+        vars.sort_unstable();
+        let mut props = name_cache.props.iter().collect::<Vec<_>>();
+        props.sort_unstable();
+        write!(
+            w,
+            "{first}\n{first_mangled}\n{second}\n{second_mangled}\nvars: {vars:?}\nprops: {props:?}\n\n"
+        )
+        .unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("name_cache", snapshot);
+    });
+}
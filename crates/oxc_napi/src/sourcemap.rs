@@ -0,0 +1,28 @@
+use oxc_sourcemap::{JSONSourceMap, napi::SourceMap};
+
+/// Convert a source map passed in from JS (e.g. an `inputSourceMap` option) into an
+/// [`oxc_sourcemap::SourceMap`].
+///
+/// # Errors
+///
+/// * The source map is not a valid version 3 source map.
+pub fn parse_input_source_map(map: SourceMap) -> Result<oxc_sourcemap::SourceMap, String> {
+    let json = JSONSourceMap {
+        version: u32::from(map.version),
+        file: map.file,
+        mappings: map.mappings,
+        source_root: map.source_root,
+        sources: map.sources,
+        sources_content: map
+            .sources_content
+            .map(|contents| contents.into_iter().map(Some).collect()),
+        names: map.names,
+        debug_id: None,
+        x_google_ignore_list: map.x_google_ignorelist,
+    };
+    if json.version != 3 {
+        return Err(format!("Invalid inputSourceMap: unsupported version {}.", json.version));
+    }
+    oxc_sourcemap::SourceMap::from_json(json)
+        .map_err(|err| format!("Invalid inputSourceMap: {err}."))
+}
@@ -995,6 +995,8 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
 
         /* cfg */
         control_flow!(self, |cfg| {
+            // The target is written after the right-hand side, which may contain control flow.
+            self.nodes.set_write_cfg_id(self.current_node_id, cfg.current_node_ix);
             if let Some((target_end_ix, expr_start_ix)) = cfg_ixs {
                 let expr_end_ix = cfg.current_node_ix;
                 let after_assignment_ix = cfg.new_basic_block_normal();
@@ -2203,6 +2205,11 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         }
         if let Some(init) = &decl.init {
             self.visit_expression(init);
+            /* cfg */
+            control_flow!(self, |cfg| {
+                self.nodes.set_write_cfg_id(self.current_node_id, cfg.current_node_ix);
+            });
+            /* cfg */
         }
         self.leave_node(kind);
     }
@@ -0,0 +1,111 @@
+use oxc_ast::AstKind;
+use oxc_cfg::{
+    BlockNodeId,
+    dataflow::{Access, AccessKind, BlockAccesses},
+};
+use oxc_span::GetSpan;
+
+use crate::{NodeId, Semantic};
+
+impl Semantic<'_> {
+    /// Collects the reads and writes of all symbols, grouped by basic block in evaluation order,
+    /// for the analyses in [`oxc_cfg::dataflow`].
+    ///
+    /// Besides resolved references, declarations which assign a value are writes: variable
+    /// declarators with an initializer or in the head of a `for...in`/`for...of` loop,
+    /// parameters, imports, and function and class declarations. The write of an assignment is
+    /// placed after its right-hand side, so `x = x + 1` reads `x` before writing it, and
+    /// `x = a ? x : 0` writes `x` after the branches join.
+    ///
+    /// The result is only meaningful if [`Semantic`] is built with cfg creation enabled.
+    pub fn symbol_accesses(&self) -> BlockAccesses {
+        let mut accesses = vec![];
+        for symbol_id in self.scoping.symbol_ids() {
+            let declaration = self.scoping.symbol_declaration(symbol_id);
+            if let Some(position) = self.declaration_write_position(declaration) {
+                // The node of a function is in the first block of its body, but the function is
+                // declared in the enclosing block.
+                let block = match self.nodes.kind(declaration) {
+                    AstKind::Function(_) => self.nodes.cfg_id(self.nodes.parent_id(declaration)),
+                    _ => self.nodes.write_cfg_id(declaration),
+                };
+                let access = Access { symbol_id, node_id: declaration, kind: AccessKind::Write };
+                accesses.push((block, position, access));
+            }
+
+            for reference in self.scoping.get_resolved_references(symbol_id) {
+                let node_id = reference.node_id();
+                if reference.is_read() {
+                    let position = self.nodes.get_node(node_id).span().start;
+                    accesses.push((
+                        self.nodes.cfg_id(node_id),
+                        position,
+                        Access { symbol_id, node_id, kind: AccessKind::Read },
+                    ));
+                }
+                if reference.is_write() {
+                    let (block, position) = self.reference_write_position(node_id);
+                    accesses.push((
+                        block,
+                        position,
+                        Access { symbol_id, node_id, kind: AccessKind::Write },
+                    ));
+                }
+            }
+        }
+
+        // Reads of a position happen before writes, e.g. in `x++`.
+        accesses.sort_by_key(|(block, position, access)| (*block, *position, access.is_write()));
+        let mut block_accesses = BlockAccesses::default();
+        for (block, _, access) in accesses {
+            block_accesses.push(block, access);
+        }
+        block_accesses
+    }
+
+    fn declaration_write_position(&self, node_id: NodeId) -> Option<u32> {
+        match self.nodes.kind(node_id) {
+            AstKind::VariableDeclarator(declarator) => {
+                if declarator.init.is_some() {
+                    return Some(declarator.span.end);
+                }
+                let declaration_id = self.nodes.parent_id(node_id);
+                matches!(
+                    self.nodes.parent_kind(declaration_id),
+                    AstKind::ForInStatement(_) | AstKind::ForOfStatement(_)
+                )
+                .then_some(declarator.span.start)
+            }
+            kind @ (AstKind::FormalParameter(_)
+            | AstKind::CatchParameter(_)
+            | AstKind::Function(_)
+            | AstKind::Class(_)
+            | AstKind::ImportSpecifier(_)
+            | AstKind::ImportDefaultSpecifier(_)
+            | AstKind::ImportNamespaceSpecifier(_)) => Some(kind.span().start),
+            _ => None,
+        }
+    }
+
+    /// The block and position of a write reference, which is the end of the assignment or update
+    /// containing it.
+    fn reference_write_position(&self, node_id: NodeId) -> (BlockNodeId, u32) {
+        let block = self.nodes.cfg_id(node_id);
+        let span = self.nodes.get_node(node_id).span();
+        for ancestor_id in self.nodes.ancestor_ids(node_id) {
+            match self.nodes.kind(ancestor_id) {
+                AstKind::AssignmentExpression(expr) => {
+                    return (self.nodes.write_cfg_id(ancestor_id), expr.span.end);
+                }
+                AstKind::UpdateExpression(expr) => return (block, expr.span.end),
+                // `for (x of items)` writes `x` before each iteration.
+                AstKind::ForInStatement(_) | AstKind::ForOfStatement(_) => {
+                    return (block, span.start);
+                }
+                kind if kind.is_statement() || kind.is_function_like() => break,
+                _ => {}
+            }
+        }
+        (block, span.end)
+    }
+}
@@ -31,6 +31,8 @@ mod binder;
 mod builder;
 mod checker;
 mod class;
+#[cfg(feature = "cfg")]
+mod dataflow;
 mod diagnostics;
 mod is_global_reference;
 #[cfg(feature = "linter")]
@@ -12,6 +12,8 @@ use oxc_ast::AstType;
 
 #[cfg(feature = "cfg")]
 use oxc_cfg::BlockNodeId;
+#[cfg(feature = "cfg")]
+use rustc_hash::FxHashMap;
 
 use super::AstNode;
 
@@ -29,6 +31,10 @@ pub struct AstNodes<'a> {
     /// `node` -> `cfg_id` (control flow graph node)
     #[cfg(feature = "cfg")]
     cfg_ids: IndexVec<NodeId, BlockNodeId>,
+    /// `node` -> `cfg_id` of its write, for assignments and variable declarators whose write
+    /// is in another control flow graph node than the one they start in
+    #[cfg(feature = "cfg")]
+    write_cfg_ids: FxHashMap<NodeId, BlockNodeId>,
     /// Stores a set of bits of a fixed size, where each bit represents a single [`AstKind`]. If the bit is set (1),
     /// then the AST contains at least one node of that kind. If the bit is not set (0), then the AST does not contain
     /// any nodes of that kind.
@@ -137,6 +143,25 @@ impl<'a> AstNodes<'a> {
         self.cfg_ids[node_id]
     }
 
+    /// ID of the control flow graph node in which an [`AstKind::AssignmentExpression`] or an
+    /// [`AstKind::VariableDeclarator`] with an initializer writes its target.
+    ///
+    /// The write happens after the right-hand side is evaluated, so this differs from
+    /// [`AstNodes::cfg_id`] if the right-hand side contains control flow, e.g. `x = a ? b : c`.
+    /// For logical assignments like `x ||= y`, it is the node in which `y` ends.
+    #[inline]
+    #[cfg(feature = "cfg")]
+    pub fn write_cfg_id(&self, node_id: NodeId) -> BlockNodeId {
+        self.write_cfg_ids.get(&node_id).copied().unwrap_or_else(|| self.cfg_ids[node_id])
+    }
+
+    #[cfg(feature = "cfg")]
+    pub(crate) fn set_write_cfg_id(&mut self, node_id: NodeId, cfg_id: BlockNodeId) {
+        if self.cfg_ids[node_id] != cfg_id {
+            self.write_cfg_ids.insert(node_id, cfg_id);
+        }
+    }
+
     /// Get the [`Program`] that's also the root of the AST.
     #[inline]
     pub fn program(&self) -> &'a Program<'a> {
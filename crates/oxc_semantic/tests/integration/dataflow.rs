@@ -0,0 +1,159 @@
+#![cfg(feature = "cfg")]
+
+use oxc_ast::AstKind;
+use oxc_cfg::{
+    BlockNodeId,
+    dataflow::{
+        AccessAnalysis, DefiniteAssignment, DominatorTree, LiveVariables, ReachingDefinitions,
+        solve,
+    },
+};
+use oxc_semantic::{NodeId, Semantic};
+use oxc_span::GetSpan;
+
+use crate::util::SemanticTester;
+
+fn start(semantic: &Semantic) -> BlockNodeId {
+    semantic.nodes().cfg_id(NodeId::ROOT)
+}
+
+fn text(semantic: &Semantic, source_text: &'static str, node_id: NodeId) -> &'static str {
+    semantic.nodes().get_node(node_id).span().source_text(source_text)
+}
+
+/// The block of the statement with the given source text.
+fn block_of(semantic: &Semantic, statement: &str) -> BlockNodeId {
+    let node = semantic
+        .nodes()
+        .iter()
+        .find(|node| {
+            matches!(node.kind(), AstKind::ExpressionStatement(_))
+                && node.span().source_text(semantic.source_text()) == statement
+        })
+        .unwrap();
+    semantic.nodes().cfg_id(node.id())
+}
+
+/// Writes whose value is never read.
+fn dead_stores(source_text: &'static str) -> Vec<&'static str> {
+    let tester = SemanticTester::js(source_text).with_cfg(true);
+    let semantic = tester.build();
+    let accesses = semantic.symbol_accesses();
+    let analysis = LiveVariables::new(&accesses);
+    let results = solve(semantic.cfg().unwrap(), start(&semantic), &analysis);
+
+    let mut dead = vec![];
+    for block in results.blocks() {
+        analysis.for_each_access(&results, block, |access, live| {
+            if access.is_write() && !live.contains(&access.symbol_id) {
+                dead.push(access.node_id);
+            }
+        });
+    }
+    dead.sort_unstable_by_key(|&node_id| semantic.nodes().get_node(node_id).span().start);
+    dead.into_iter().map(|node_id| text(&semantic, source_text, node_id)).collect()
+}
+
+#[test]
+fn test_live_variables() {
+    assert_eq!(dead_stores("let x = 1; x = 2; log(x);"), vec!["x = 1"]);
+    assert!(dead_stores("let x = 0; if (a) x = 1; log(x);").is_empty());
+    assert!(dead_stores("let i = 0; while (i < 10) { i = i + 1; }").is_empty());
+    assert_eq!(dead_stores("let i = 0; i++;"), vec!["i"]);
+    assert_eq!(dead_stores("let x = 0; x = x + 1; x = 5; log(x);"), vec!["x"]);
+    assert_eq!(dead_stores("for (const item of items) {}"), vec!["item"]);
+    // The write happens after the branches of the right-hand side join.
+    assert!(dead_stores("let x = 0; x = a ? x + 1 : 0; log(x);").is_empty());
+    assert!(dead_stores("let x = 0; x ||= a ? x : 1; log(x);").is_empty());
+    // Only the region of the start block is analyzed, nested functions are separate regions.
+    assert_eq!(dead_stores("function f(a) { a = 1; }"), vec!["function f(a) { a = 1; }"]);
+}
+
+#[test]
+fn test_live_variables_exceptions() {
+    // `f()` can throw after `x = 1`, and `catch` reads it.
+    assert!(!dead_stores("let x = 0; try { x = 1; f(); x = 2; } catch { log(x); }").contains(&"x"));
+    assert!(dead_stores("let x = 0; try { x = 1; f(); } finally { log(x); }").is_empty());
+    assert_eq!(dead_stores("let x = 0; try { f(); } catch { x = 1; } log(x);"), Vec::<&str>::new());
+}
+
+#[test]
+fn test_reaching_definitions() {
+    let source_text = "let x = 1; if (a) x = 2; log(x); x = 3; log(x);";
+    let tester = SemanticTester::js(source_text).with_cfg(true);
+    let semantic = tester.build();
+    let accesses = semantic.symbol_accesses();
+    let analysis = ReachingDefinitions::new(&accesses);
+    let results = solve(semantic.cfg().unwrap(), start(&semantic), &analysis);
+
+    let mut reaching = vec![];
+    for block in results.blocks() {
+        analysis.for_each_access(&results, block, |access, definitions| {
+            if access.is_read() {
+                let mut definitions = definitions
+                    .iter()
+                    .filter(|definition| definition.symbol_id == access.symbol_id)
+                    .map(|definition| text(&semantic, source_text, definition.node_id))
+                    .collect::<Vec<_>>();
+                definitions.sort_unstable();
+                reaching
+                    .push((semantic.nodes().get_node(access.node_id).span().start, definitions));
+            }
+        });
+    }
+    reaching.sort_unstable();
+    let reaching = reaching.into_iter().map(|(_, definitions)| definitions).collect::<Vec<_>>();
+    assert_eq!(reaching, vec![vec!["x", "x = 1"], vec!["x"]]);
+}
+
+#[test]
+fn test_definite_assignment() {
+    let tester = SemanticTester::js(
+        "let x, y; if (a) { x = 1; y = 1; } else { x = 2; } log(x, y); while (b) { y = 2; } log(y);",
+    )
+    .with_cfg(true);
+    let semantic = tester.build();
+    let accesses = semantic.symbol_accesses();
+    let analysis = DefiniteAssignment::new(&accesses);
+    let results = solve(semantic.cfg().unwrap(), start(&semantic), &analysis);
+
+    let mut unassigned = vec![];
+    for block in results.blocks() {
+        analysis.for_each_access(&results, block, |access, assigned| {
+            if access.is_read() && !assigned.contains(&access.symbol_id) {
+                unassigned.push(semantic.nodes().get_node(access.node_id).span().start as usize);
+            }
+        });
+    }
+    unassigned.sort_unstable();
+    let source_text = semantic.source_text();
+    assert_eq!(unassigned, vec![source_text.find("y);").unwrap(), source_text.rfind('y').unwrap()]);
+}
+
+#[test]
+fn test_dominators() {
+    let tester =
+        SemanticTester::js("a(); if (b) { c(); } else { d(); } e(); while (f) { g(); } h();")
+            .with_cfg(true);
+    let semantic = tester.build();
+    let cfg = semantic.cfg().unwrap();
+    let [first, then, otherwise, after_if, loop_body, after_loop] =
+        ["a();", "c();", "d();", "e();", "g();", "h();"]
+            .map(|statement| block_of(&semantic, statement));
+
+    let dominators = DominatorTree::dominators(cfg, start(&semantic));
+    assert!(dominators.dominates(first, after_if));
+    assert!(dominators.strictly_dominates(after_if, after_loop));
+    assert!(dominators.dominates(after_if, loop_body));
+    assert!(!dominators.dominates(then, after_if));
+    assert!(!dominators.dominates(otherwise, after_if));
+    assert!(!dominators.dominates(loop_body, after_loop));
+    assert_eq!(dominators.immediate_dominator(start(&semantic)), None);
+
+    let post_dominators = DominatorTree::post_dominators(cfg, start(&semantic));
+    assert!(post_dominators.dominates(after_if, first));
+    assert!(post_dominators.dominates(after_if, then));
+    assert!(post_dominators.dominates(after_loop, loop_body));
+    assert!(!post_dominators.dominates(then, first));
+    assert!(!post_dominators.dominates(loop_body, after_if));
+}
@@ -0,0 +1,4 @@
+function _identity(t) {
+  return t;
+}
+export { _identity as default };
\ No newline at end of file
@@ -0,0 +1,4 @@
+function _readOnlyError(r) {
+  throw new TypeError('"' + r + '" is read-only');
+}
+export { _readOnlyError as default };
\ No newline at end of file
@@ -0,0 +1,274 @@
+//! Inline helpers.
+//!
+//! Helper functions are bundled in the transformer as JS source (`helpers/*.js`, copied from
+//! `npm/runtime/src/helpers/esm`). When a helper is first requested, its source is parsed into the
+//! program's arena and grafted into the program:
+//!
+//! 1. `import` / `export` declarations are removed. Imported helpers are loaded first
+//!    (recursively), and references to them are bound to the inlined dependencies.
+//! 2. Top level bindings (the helper itself and any internal functions or variables) are given
+//!    UIDs in the root scope of the program, so they can't clash with user code or each other.
+//! 3. All scopes, symbols and references from the helper are re-created in the program's `Scoping`,
+//!    and spans are reset, so the result looks as if it was created by the transformer.
+//!
+//! The resulting statements are added to top of program via `TopLevelStatementsStore`.
+//! Each helper is only inlined once per file, no matter how many transforms use it.
+//!
+//! NOTE: A helper can only be inlined if its source is included in [`helper_source`].
+//! When adding a new [`Helper`](super::Helper), copy its source, and the source of any helpers
+//! it imports, from `npm/runtime/src/helpers/esm` into `helpers` directory.
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::VisitMut;
+use oxc_parser::Parser;
+use oxc_semantic::{ScopeFlags, Scoping, SemanticBuilder, SymbolFlags};
+use oxc_span::{SPAN, SourceType, Span};
+use oxc_syntax::{node::NodeId, scope::ScopeId, symbol::SymbolId};
+use oxc_traverse::BoundIdentifier;
+
+use crate::context::{TransformCtx, TraverseCtx};
+
+use super::HelperLoaderStore;
+
+impl<'a> HelperLoaderStore<'a> {
+    /// Get binding for an inlined helper, inlining it first if it's not been used in this file yet.
+    pub(super) fn load_inline_helper(
+        &self,
+        name: &'a str,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if let Some(binding) = self.inlined_helpers.borrow().get(name) {
+            return binding.clone();
+        }
+
+        let Some(source_text) = helper_source(name) else {
+            unreachable!("Source of helper `{name}` is not bundled");
+        };
+        let ret = Parser::new(ctx.ast.allocator, source_text, SourceType::mjs()).parse();
+        debug_assert!(ret.errors.is_empty(), "Failed to parse helper `{name}`");
+        let mut program = ret.program;
+
+        // Register the binding before loading dependencies, so a dependency cycle can't recurse forever
+        let binding = ctx.generate_uid_in_root_scope(name, SymbolFlags::Function);
+        self.inlined_helpers.borrow_mut().insert(name, binding.clone());
+
+        // Remove `import` and `export` declarations, and load imported helpers
+        let mut exported_name = None;
+        let mut dependencies = FxHashMap::default();
+        program.body.retain(|stmt| match stmt {
+            Statement::ImportDeclaration(decl) => {
+                let dependency =
+                    decl.source.value.as_str().trim_start_matches("./").trim_end_matches(".js");
+                if let Some(specifier) = decl.specifiers.as_ref().and_then(|s| s.first()) {
+                    dependencies.insert(specifier.local().name.as_str(), dependency);
+                }
+                false
+            }
+            Statement::ExportNamedDeclaration(decl) => {
+                exported_name =
+                    decl.specifiers.first().map(|specifier| specifier.local.name().as_str());
+                false
+            }
+            _ => true,
+        });
+        let dependencies = dependencies
+            .into_iter()
+            .map(|(local_name, dependency)| {
+                (local_name, self.load_inline_helper(dependency, transform_ctx, ctx))
+            })
+            .collect::<FxHashMap<_, _>>();
+
+        // Build semantic for the helper as if it was part of the program, to get correct strict mode
+        program.source_type = transform_ctx.source_type;
+        let helper_scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let mut scope_ids = FxHashMap::default();
+        for scope_id in helper_scoping.scope_descendants_from_root() {
+            let new_scope_id = match helper_scoping.scope_parent_id(scope_id) {
+                None => root_scope_id,
+                Some(parent_id) => {
+                    let parent_id = scope_ids[&parent_id];
+                    let flags = helper_scoping.scope_flags(scope_id);
+                    let flags = ctx.scoping().get_new_scope_flags(flags, parent_id);
+                    ctx.create_child_scope(parent_id, flags)
+                }
+            };
+            scope_ids.insert(scope_id, new_scope_id);
+        }
+
+        let helper_root_scope_id = helper_scoping.root_scope_id();
+        let mut symbols = FxHashMap::default();
+        for symbol_id in helper_scoping.symbol_ids() {
+            let name = helper_scoping.symbol_name(symbol_id);
+            let flags = helper_scoping.symbol_flags(symbol_id);
+            let scope_id = helper_scoping.symbol_scope_id(symbol_id);
+            let new_binding = if scope_id != helper_root_scope_id {
+                ctx.generate_binding(ctx.ast.atom(name), scope_ids[&scope_id], flags)
+            } else if exported_name == Some(name) {
+                binding.clone()
+            } else {
+                ctx.generate_uid_in_root_scope(name, flags)
+            };
+            // First entry is the original declaration
+            for redeclaration in helper_scoping.symbol_redeclarations(symbol_id).iter().skip(1) {
+                ctx.scoping_mut().add_symbol_redeclaration(
+                    new_binding.symbol_id,
+                    redeclaration.flags,
+                    NodeId::DUMMY,
+                    SPAN,
+                );
+            }
+            symbols.insert(symbol_id, new_binding);
+        }
+
+        let mut grafter = HelperGrafter {
+            helper_scoping: &helper_scoping,
+            scope_ids,
+            symbols,
+            dependencies,
+            ctx,
+        };
+        grafter.visit_statements(&mut program.body);
+
+        transform_ctx.top_level_statements.insert_statements(program.body);
+
+        binding
+    }
+}
+
+/// Visitor which moves a parsed helper's scopes, symbols and references into the program's `Scoping`.
+struct HelperGrafter<'h, 'a, 'c> {
+    helper_scoping: &'h Scoping,
+    scope_ids: FxHashMap<ScopeId, ScopeId>,
+    symbols: FxHashMap<SymbolId, BoundIdentifier<'a>>,
+    /// Imported helpers, keyed by local name. References to them are unresolved in `helper_scoping`,
+    /// as `import` declarations are removed before building semantic.
+    dependencies: FxHashMap<&'a str, BoundIdentifier<'a>>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for HelperGrafter<'_, 'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        scope_id.set(Some(self.scope_ids[&scope_id.get().unwrap()]));
+    }
+
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = SPAN;
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        let binding = &self.symbols[&ident.symbol_id()];
+        ident.span = SPAN;
+        ident.name = binding.name.into();
+        ident.symbol_id.set(Some(binding.symbol_id));
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference = self.helper_scoping.get_reference(ident.reference_id());
+        let flags = reference.flags();
+        let reference_id = if let Some(symbol_id) = reference.symbol_id() {
+            let binding = &self.symbols[&symbol_id];
+            ident.name = binding.name.into();
+            self.ctx.create_bound_reference(binding.symbol_id, flags)
+        } else if let Some(binding) = self.dependencies.get(ident.name.as_str()) {
+            ident.name = binding.name.into();
+            self.ctx.create_bound_reference(binding.symbol_id, flags)
+        } else {
+            // Global reference e.g. `Object`
+            let root_scope_id = self.ctx.scoping().root_scope_id();
+            let symbol_id = self.ctx.scoping().find_binding(root_scope_id, &ident.name);
+            self.ctx.create_reference(&ident.name, symbol_id, flags)
+        };
+        ident.span = SPAN;
+        ident.reference_id.set(Some(reference_id));
+    }
+}
+
+macro_rules! helper_sources {
+    ($($name:literal),* $(,)?) => {
+        /// Get source text of a helper bundled for inlining.
+        fn helper_source(name: &str) -> Option<&'static str> {
+            match name {
+                $($name => Some(include_str!(concat!("helpers/", $name, ".js"))),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+helper_sources!(
+    "applyDecs2311",
+    "arrayLikeToArray",
+    "arrayWithHoles",
+    "arrayWithoutHoles",
+    "assertClassBrand",
+    "assertThisInitialized",
+    "asyncGeneratorDelegate",
+    "asyncIterator",
+    "asyncToGenerator",
+    "awaitAsyncGenerator",
+    "callSuper",
+    "checkInRHS",
+    "checkPrivateRedeclaration",
+    "classCallCheck",
+    "classPrivateFieldGet2",
+    "classPrivateFieldInitSpec",
+    "classPrivateFieldLooseBase",
+    "classPrivateFieldLooseKey",
+    "classPrivateFieldSet2",
+    "classPrivateMethodInitSpec",
+    "construct",
+    "createClass",
+    "createForOfIteratorHelper",
+    "createForOfIteratorHelperLoose",
+    "decorate",
+    "decorateMetadata",
+    "decorateParam",
+    "defineProperty",
+    "extends",
+    "get",
+    "getPrototypeOf",
+    "identity",
+    "inherits",
+    "interopRequireDefault",
+    "interopRequireWildcard",
+    "isNativeReflectConstruct",
+    "iterableToArray",
+    "iterableToArrayLimit",
+    "maybeArrayLike",
+    "nonIterableRest",
+    "nonIterableSpread",
+    "objectDestructuringEmpty",
+    "objectSpread2",
+    "objectWithoutProperties",
+    "objectWithoutPropertiesLoose",
+    "OverloadYield",
+    "possibleConstructorReturn",
+    "readOnlyError",
+    "regeneratorRuntime",
+    "set",
+    "setFunctionName",
+    "setPrototypeOf",
+    "slicedToArray",
+    "superPropBase",
+    "superPropGet",
+    "superPropSet",
+    "taggedTemplateLiteral",
+    "tdz",
+    "toArray",
+    "toConsumableArray",
+    "toPrimitive",
+    "toPropertyKey",
+    "toSetter",
+    "typeof",
+    "unsupportedIterableToArray",
+    "usingCtx",
+    "wrapAsyncGenerator",
+    "writeOnlyError",
+);
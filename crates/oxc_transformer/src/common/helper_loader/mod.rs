@@ -1,7 +1,7 @@
 //! Utility to load helper functions.
 //!
 //! This module provides functionality to load helper functions in different modes.
-//! It supports runtime, external, and inline (not yet implemented) modes for loading helper functions.
+//! It supports runtime, external, and inline modes for loading helper functions.
 //!
 //! ## Usage
 //!
@@ -47,15 +47,15 @@
 //!
 //! ### Inline ([`HelperLoaderMode::Inline`])
 //!
-//! > Note: This mode is not currently implemented.
-//!
 //! Inline helper functions are inserted directly into the top of program.
+//! Source of helpers is bundled in the transformer, so output has no dependency on a runtime package.
+//! Each helper is inserted only once per file, along with any other helpers it depends on.
 //!
 //! Generated code example:
 //!
 //! ```js
-//! function helperName(...arguments) { ... } // Inlined helper function
-//! helperName(...arguments);
+//! function _helperName(...arguments) { ... } // Inlined helper function
+//! _helperName(...arguments);
 //! ```
 //!
 //! Based on [@babel/helper](https://github.com/babel/babel/tree/v7.26.2/packages/babel-helpers).
@@ -63,7 +63,8 @@
 //! ## Implementation
 //!
 //! Unlike other "common" utilities, this one has no transformer. It adds imports to the program
-//! via `ModuleImports` transform.
+//! via `ModuleImports` transform, and inlined helpers via `TopLevelStatements` transform.
+//! See [`inline`] module for how inline helpers are loaded.
 
 use std::{borrow::Cow, cell::RefCell};
 
@@ -81,17 +82,19 @@ use oxc_traverse::BoundIdentifier;
 
 use crate::context::{TransformCtx, TraverseCtx};
 
+mod inline;
+
 /// Defines the mode for loading helper functions.
 #[derive(Default, Clone, Copy, Debug, Deserialize)]
 pub enum HelperLoaderMode {
     /// Inline mode: Helper functions are directly inserted into the program.
     ///
-    /// Note: This mode is not currently implemented.
+    /// Useful for producing self-contained output e.g. when building a library.
     ///
     /// Example output:
     /// ```js
-    /// function helperName(...arguments) { ... } // Inlined helper function
-    /// helperName(...arguments);
+    /// function _helperName(...arguments) { ... } // Inlined helper function
+    /// _helperName(...arguments);
     /// ```
     Inline,
     /// External mode: Helper functions are accessed from a global `babelHelpers` object.
@@ -167,8 +170,27 @@ pub enum Helper {
     Decorate,
     DecorateParam,
     DecorateMetadata,
+    ApplyDecs2311,
+    Identity,
     UsingCtx,
     TaggedTemplateLiteral,
+    ClassCallCheck,
+    CreateClass,
+    Inherits,
+    CallSuper,
+    AssertThisInitialized,
+    PossibleConstructorReturn,
+    ToConsumableArray,
+    SlicedToArray,
+    ToArray,
+    MaybeArrayLike,
+    Construct,
+    CreateForOfIterator,
+    CreateForOfIteratorLoose,
+    Tdz,
+    RegeneratorRuntime,
+    InteropRequireDefault,
+    InteropRequireWildcard,
 }
 
 impl Helper {
@@ -201,8 +223,27 @@ impl Helper {
             Self::Decorate => "decorate",
             Self::DecorateParam => "decorateParam",
             Self::DecorateMetadata => "decorateMetadata",
+            Self::ApplyDecs2311 => "applyDecs2311",
+            Self::Identity => "identity",
             Self::UsingCtx => "usingCtx",
             Self::TaggedTemplateLiteral => "taggedTemplateLiteral",
+            Self::ClassCallCheck => "classCallCheck",
+            Self::CreateClass => "createClass",
+            Self::Inherits => "inherits",
+            Self::CallSuper => "callSuper",
+            Self::AssertThisInitialized => "assertThisInitialized",
+            Self::PossibleConstructorReturn => "possibleConstructorReturn",
+            Self::ToConsumableArray => "toConsumableArray",
+            Self::SlicedToArray => "slicedToArray",
+            Self::ToArray => "toArray",
+            Self::MaybeArrayLike => "maybeArrayLike",
+            Self::Construct => "construct",
+            Self::CreateForOfIterator => "createForOfIteratorHelper",
+            Self::CreateForOfIteratorLoose => "createForOfIteratorHelperLoose",
+            Self::Tdz => "tdz",
+            Self::RegeneratorRuntime => "regeneratorRuntime",
+            Self::InteropRequireDefault => "interopRequireDefault",
+            Self::InteropRequireWildcard => "interopRequireWildcard",
         }
     }
 
@@ -217,6 +258,8 @@ pub struct HelperLoaderStore<'a> {
     mode: HelperLoaderMode,
     /// Loaded helpers, determined what helpers are loaded and what imports should be added.
     loaded_helpers: RefCell<FxHashMap<Helper, BoundIdentifier<'a>>>,
+    /// Inlined helpers, keyed by name. Includes helpers which are only used by other helpers.
+    inlined_helpers: RefCell<FxHashMap<&'a str, BoundIdentifier<'a>>>,
     pub(crate) used_helpers: RefCell<FxHashMap<Helper, String>>,
 }
 
@@ -226,6 +269,7 @@ impl HelperLoaderStore<'_> {
             module_name: options.module_name.clone(),
             mode: options.mode,
             loaded_helpers: RefCell::new(FxHashMap::default()),
+            inlined_helpers: RefCell::new(FxHashMap::default()),
             used_helpers: RefCell::new(FxHashMap::default()),
         }
     }
@@ -272,8 +316,31 @@ impl<'a> TransformCtx<'a> {
             HelperLoaderMode::External => {
                 HelperLoaderStore::transform_for_external_helper(helper, ctx)
             }
+            HelperLoaderMode::Inline => helper_loader
+                .load_inline_helper(helper.name(), self, ctx)
+                .create_read_expression(ctx),
+        }
+    }
+
+    /// Load a helper function without referencing it.
+    ///
+    /// Imports and inlined helpers are inserted into the program in `Common`'s `exit_program`,
+    /// so a transform which only uses a helper after that must load it beforehand with this method.
+    pub fn helper_preload(&self, helper: Helper, ctx: &mut TraverseCtx<'a>) {
+        let helper_loader = &self.helper_loader;
+        let source = helper_loader.get_runtime_source(helper, ctx);
+        helper_loader.used_helpers.borrow_mut().entry(helper).or_insert_with(|| source.to_string());
+
+        match helper_loader.mode {
+            HelperLoaderMode::Runtime => {
+                let mut loaded_helpers = helper_loader.loaded_helpers.borrow_mut();
+                loaded_helpers.entry(helper).or_insert_with(|| {
+                    HelperLoaderStore::get_runtime_helper(helper, source, self, ctx)
+                });
+            }
+            HelperLoaderMode::External => {}
             HelperLoaderMode::Inline => {
-                unreachable!("Inline helpers are not supported yet");
+                helper_loader.load_inline_helper(helper.name(), self, ctx);
             }
         }
     }
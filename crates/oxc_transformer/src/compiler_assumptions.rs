@@ -9,7 +9,6 @@ use serde::Deserialize;
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct CompilerAssumptions {
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub array_like_is_iterable: bool,
 
     #[serde(default)]
@@ -32,7 +31,6 @@ pub struct CompilerAssumptions {
     pub ignore_to_primitive_hint: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub iterable_is_array: bool,
 
     #[serde(default)]
@@ -126,7 +124,6 @@ pub struct CompilerAssumptions {
     pub set_spread_properties: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub skip_for_of_iterator_closing: bool,
 
     #[serde(default)]
@@ -12,6 +12,13 @@ pub struct DecoratorOptions {
     #[serde(skip)]
     pub legacy: bool,
 
+    /// Enables transforming standard decorators, following the 2023-11 version of the
+    /// [TC39 decorators proposal](https://github.com/tc39/proposal-decorators).
+    ///
+    /// Decorators are applied at runtime by the `applyDecs2311` helper. Has no effect when `legacy` is true.
+    #[serde(skip)]
+    pub standard: bool,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option is the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
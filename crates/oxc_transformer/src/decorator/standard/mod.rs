@@ -0,0 +1,1860 @@
+//! Standard decorators (2023-11)
+//!
+//! This plugin transforms decorators following the 2023-11 version of the
+//! [TC39 decorators proposal](https://github.com/tc39/proposal-decorators), and lowers
+//! `accessor` class elements, which are part of the same proposal.
+//!
+//! Decorators are applied at runtime by the `applyDecs2311` helper, which is called in a static block
+//! inserted at the start of the class body. The helper returns the functions which initialize decorated
+//! fields and accessors, run the initializers added with `context.addInitializer`, and the decorated class
+//! itself when the class has decorators.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! @dec
+//! class Class {
+//!   @dec
+//!   method() {}
+//!
+//!   @dec
+//!   accessor prop = 0;
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! let _initProto, _init_prop, _init_extra_prop, _Class, _initClass;
+//! let Class = (class Class {
+//!   static {
+//!     ({ e: [_init_prop, _init_extra_prop, _initProto], c: [_Class, _initClass] } =
+//!       _applyDecs2311(this, [dec], [[dec, 2, "method"], [dec, 1, "prop"]]));
+//!   }
+//!   method() {}
+//!   #A = (_initProto(this), _init_prop(this, 0));
+//!   get prop() { return this.#A; }
+//!   set prop(v) { this.#A = v; }
+//!   constructor() { _init_extra_prop(this); }
+//! }, _initClass(), _Class);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-decorators](https://github.com/babel/babel/blob/v7.26.0/packages/babel-helper-create-class-features-plugin/src/decorators.ts)
+//! with `version: "2023-11"`.
+//!
+//! Classes are transformed before their contents are visited, so that the elements generated here
+//! (static blocks, private fields, getters and setters) are lowered by the plugins which run later,
+//! e.g. `class-properties` and `class-static-block`.
+//!
+//! Known limitations:
+//! * Decorated private methods which contain `super` are not supported.
+//! * Decorators of class elements which reference private names are evaluated outside of the class.
+//!
+//! ## References:
+//! * TC39 proposal: <https://github.com/tc39/proposal-decorators>
+//! * Babel helper: <https://github.com/babel/babel/blob/v7.26.0/packages/babel-helpers/src/helpers/applyDecs2311.ts>
+
+use std::mem;
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{Box as ArenaBox, CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_syntax::{identifier::is_identifier_part, operator::AssignmentOperator};
+use oxc_traverse::{BoundIdentifier, MaybeBoundIdentifier, Traverse};
+
+use crate::{
+    Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::{
+        ast_builder::{
+            create_assignment, create_class_constructor, create_property_access,
+            wrap_statements_in_arrow_function_iife,
+        },
+        reparent_child_scopes,
+    },
+};
+
+// Kinds and flags of class elements, passed to `applyDecs2311`.
+const FIELD: u32 = 0;
+const ACCESSOR: u32 = 1;
+const METHOD: u32 = 2;
+const GETTER: u32 = 3;
+const SETTER: u32 = 4;
+const STATIC: u32 = 8;
+const DECORATORS_HAVE_THIS: u32 = 16;
+
+/// Decorators of a class or a class element, after memoization.
+/// Each decorator is paired with the `this` value it should be called with, if any.
+type Decorators<'a> = Vec<(Option<Expression<'a>>, Expression<'a>)>;
+
+pub struct StandardDecorator<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> StandardDecorator<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for StandardDecorator<'a, '_> {
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !stmts
+            .iter()
+            .any(|stmt| Self::class_of_statement(stmt).is_some_and(Self::should_transform))
+        {
+            return;
+        }
+
+        let old_stmts = stmts.take_in(ctx.ast);
+        let mut new_stmts = ctx.ast.vec_with_capacity(old_stmts.len());
+        for stmt in old_stmts {
+            if Self::class_of_statement(&stmt).is_some_and(Self::should_transform) {
+                self.transform_class_statement(stmt, &mut new_stmts, ctx);
+            } else {
+                new_stmts.push(stmt);
+            }
+        }
+        *stmts = new_stmts;
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ClassExpression(class) = expr else { return };
+        if !Self::should_transform(class) {
+            return;
+        }
+
+        let Expression::ClassExpression(mut class) = expr.take_in(ctx.ast) else { unreachable!() };
+        let (memos, decorated) = self.transform_class(&mut class, ctx);
+        let class_expr = match decorated {
+            Some(decorated) => self.create_decorated_class_expression(class, decorated, ctx),
+            None => Expression::ClassExpression(class),
+        };
+
+        *expr = if memos.is_empty() {
+            class_expr
+        } else {
+            // `(_dec = dec(), class {})`
+            let mut expressions = ctx.ast.vec_from_iter(memos);
+            expressions.push(class_expr);
+            ctx.ast.expression_sequence(SPAN, expressions)
+        };
+    }
+}
+
+/// State of the class currently being transformed.
+struct ClassState<'a> {
+    /// Scope which temp vars are created in.
+    outer_scope_id: ScopeId,
+    class_scope_id: ScopeId,
+    /// Scope of the static block which calls `applyDecs2311`.
+    static_block_scope_id: ScopeId,
+    /// `true` if the class has any decorators.
+    /// Decorators are evaluated before the class, so computed keys must be too, to keep evaluation order.
+    has_decorators: bool,
+    /// Assignments to temp vars which must be evaluated before the class.
+    memos: Vec<Expression<'a>>,
+    private_names: PrivateNames<'a>,
+    /// Decoration info of each decorated class element, passed to `applyDecs2311`.
+    member_decs: Vec<Expression<'a>>,
+    /// Bindings receiving the functions returned by `applyDecs2311`,
+    /// grouped in the order the helper returns them:
+    /// static non-fields, prototype non-fields, static fields, prototype fields.
+    results: [Vec<BoundIdentifier<'a>>; 4],
+    /// Initializers to run before the next instance field is defined, or in the constructor.
+    instance_pending: Vec<BoundIdentifier<'a>>,
+    /// Initializers to run before the next static field or static block is evaluated.
+    static_pending: Vec<BoundIdentifier<'a>>,
+    /// Name of a decorated instance private element, used to brand check instances.
+    instance_brand: Option<Atom<'a>>,
+}
+
+/// Result of transforming a class which has class decorators.
+struct DecoratedClass<'a> {
+    /// `_Class`: the decorated class, which references to the class within its body are replaced with.
+    class_binding: BoundIdentifier<'a>,
+    /// `_initClass`: runs the initializers added by class decorators.
+    init_class_binding: BoundIdentifier<'a>,
+    /// Static initializers which were not run by any static field or static block.
+    static_pending: Vec<BoundIdentifier<'a>>,
+    private_names: PrivateNames<'a>,
+}
+
+impl<'a> StandardDecorator<'a, '_> {
+    fn class_of_statement<'b>(stmt: &'b Statement<'a>) -> Option<&'b Class<'a>> {
+        match stmt {
+            Statement::ClassDeclaration(class) => Some(class),
+            Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                Some(Declaration::ClassDeclaration(class)) => Some(class),
+                _ => None,
+            },
+            Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(class),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Check if `class` has decorators, decorated elements, or `accessor` elements.
+    fn should_transform(class: &Class<'a>) -> bool {
+        !class.declare
+            && (!class.decorators.is_empty()
+                || class.body.body.iter().any(|element| match element {
+                    ClassElement::MethodDefinition(method) => {
+                        method.r#type == MethodDefinitionType::MethodDefinition
+                            && !method.decorators.is_empty()
+                    }
+                    ClassElement::PropertyDefinition(prop) => {
+                        prop.r#type == PropertyDefinitionType::PropertyDefinition
+                            && !prop.declare
+                            && !prop.decorators.is_empty()
+                    }
+                    ClassElement::AccessorProperty(accessor) => {
+                        accessor.r#type == AccessorPropertyType::AccessorProperty
+                    }
+                    ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => false,
+                }))
+    }
+
+    /// Transform a statement containing a class declaration, and push the resulting statements
+    /// to `new_stmts`.
+    ///
+    /// * `class C {}` -> `let C = (class C {}, _initClass(), _C);`
+    /// * `export class C {}` -> `export let C = (class C {}, _initClass(), _C);`
+    /// * `export default class C {}` -> `let C = (class C {}, _initClass(), _C); export { C as default };`
+    /// * `export default class {}` -> `export default (class {}, _initClass(), _Class);`
+    ///
+    /// Classes without class decorators stay as declarations.
+    fn transform_class_statement(
+        &self,
+        stmt: Statement<'a>,
+        new_stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match stmt {
+            Statement::ClassDeclaration(mut class) => {
+                let (memos, decorated) = self.transform_class(&mut class, ctx);
+                Self::push_memos(memos, new_stmts, ctx);
+                let stmt = match decorated {
+                    Some(decorated) => {
+                        let (declaration, _) =
+                            self.create_class_variable_declaration(class, decorated, ctx);
+                        Statement::VariableDeclaration(declaration)
+                    }
+                    None => Statement::ClassDeclaration(class),
+                };
+                new_stmts.push(stmt);
+            }
+            Statement::ExportNamedDeclaration(mut export) => {
+                let Some(Declaration::ClassDeclaration(class)) = &mut export.declaration else {
+                    unreachable!()
+                };
+                let (memos, decorated) = self.transform_class(class, ctx);
+                Self::push_memos(memos, new_stmts, ctx);
+                if let Some(decorated) = decorated {
+                    let Some(Declaration::ClassDeclaration(class)) = export.declaration.take()
+                    else {
+                        unreachable!()
+                    };
+                    let (declaration, _) =
+                        self.create_class_variable_declaration(class, decorated, ctx);
+                    export.declaration = Some(Declaration::VariableDeclaration(declaration));
+                }
+                new_stmts.push(Statement::ExportNamedDeclaration(export));
+            }
+            Statement::ExportDefaultDeclaration(mut export) => {
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) = &mut export.declaration
+                else {
+                    unreachable!()
+                };
+                let (memos, decorated) = self.transform_class(class, ctx);
+                Self::push_memos(memos, new_stmts, ctx);
+                let Some(decorated) = decorated else {
+                    new_stmts.push(Statement::ExportDefaultDeclaration(export));
+                    return;
+                };
+
+                let ExportDefaultDeclarationKind::ClassDeclaration(mut class) =
+                    export.declaration.take_in(ctx.ast)
+                else {
+                    unreachable!()
+                };
+                if class.id.is_some() {
+                    let (declaration, binding) =
+                        self.create_class_variable_declaration(class, decorated, ctx);
+                    new_stmts.push(Statement::VariableDeclaration(declaration));
+                    new_stmts.push(Self::create_export_default_specifier(&binding, ctx));
+                } else {
+                    class.r#type = ClassType::ClassExpression;
+                    let expr = self.create_decorated_class_expression(class, decorated, ctx);
+                    export.declaration = ExportDefaultDeclarationKind::from(expr);
+                    new_stmts.push(Statement::ExportDefaultDeclaration(export));
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn push_memos(
+        memos: Vec<Expression<'a>>,
+        new_stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        new_stmts.extend(memos.into_iter().map(|memo| ctx.ast.statement_expression(SPAN, memo)));
+    }
+
+    /// Transform the class, returning the assignments which must be evaluated before the class,
+    /// and the [`DecoratedClass`] if the class has class decorators.
+    fn transform_class(
+        &self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Vec<Expression<'a>>, Option<DecoratedClass<'a>>) {
+        let class_scope_id = class.scope_id();
+        let has_element_decorators = class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(method) => {
+                method.r#type == MethodDefinitionType::MethodDefinition
+                    && !method.decorators.is_empty()
+            }
+            ClassElement::PropertyDefinition(prop) => {
+                prop.r#type == PropertyDefinitionType::PropertyDefinition
+                    && !prop.decorators.is_empty()
+            }
+            ClassElement::AccessorProperty(accessor) => {
+                accessor.r#type == AccessorPropertyType::AccessorProperty
+                    && !accessor.decorators.is_empty()
+            }
+            ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => false,
+        });
+        let has_class_decorators = !class.decorators.is_empty();
+        let has_decorators = has_class_decorators || has_element_decorators;
+
+        let mut state = ClassState {
+            outer_scope_id: ctx.current_block_scope_id(),
+            class_scope_id,
+            static_block_scope_id: if has_decorators {
+                ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock)
+            } else {
+                class_scope_id
+            },
+            has_decorators,
+            memos: vec![],
+            private_names: PrivateNames::new(&class.body),
+            member_decs: vec![],
+            results: Default::default(),
+            instance_pending: vec![],
+            static_pending: vec![],
+            instance_brand: None,
+        };
+
+        let class_symbol_id = class.id.as_ref().map(BindingIdentifier::symbol_id);
+        let class_decorators = class.decorators.take_in(ctx.ast);
+        let class_decorators =
+            self.memoize_decorators(class_decorators, class_symbol_id, false, &mut state, ctx);
+
+        // `_initProto` and `_initStatic` run initializers added by decorators of methods, getters and setters
+        let (has_proto_methods, has_static_methods) = class.body.body.iter().fold(
+            (false, false),
+            |(proto, r#static), element| match element {
+                ClassElement::MethodDefinition(method)
+                    if method.r#type == MethodDefinitionType::MethodDefinition
+                        && !method.decorators.is_empty() =>
+                {
+                    (proto || !method.r#static, r#static || method.r#static)
+                }
+                _ => (proto, r#static),
+            },
+        );
+        let init_proto_binding = has_proto_methods.then(|| {
+            let binding = self.create_temp_binding("initProto", &state, ctx);
+            state.instance_pending.push(binding.clone());
+            binding
+        });
+        let init_static_binding =
+            has_static_methods.then(|| self.create_temp_binding("initStatic", &state, ctx));
+
+        let elements = class.body.body.take_in(ctx.ast);
+        let mut new_elements = ctx.ast.vec_with_capacity(elements.len() + 2);
+        let mut constructor_index = None;
+        for element in elements {
+            match element {
+                ClassElement::MethodDefinition(mut method) => {
+                    if method.kind.is_constructor() {
+                        constructor_index = Some(new_elements.len());
+                    } else if method.r#type == MethodDefinitionType::MethodDefinition
+                        && !method.decorators.is_empty()
+                    {
+                        self.transform_method(&mut method, class_symbol_id, &mut state, ctx);
+                    } else {
+                        self.memoize_key(&mut method.key, &mut state, ctx);
+                    }
+                    new_elements.push(ClassElement::MethodDefinition(method));
+                }
+                ClassElement::PropertyDefinition(mut prop) => {
+                    if prop.r#type == PropertyDefinitionType::PropertyDefinition && !prop.declare {
+                        self.transform_property(&mut prop, class_symbol_id, &mut state, ctx);
+                    } else {
+                        self.memoize_key(&mut prop.key, &mut state, ctx);
+                    }
+                    new_elements.push(ClassElement::PropertyDefinition(prop));
+                }
+                ClassElement::AccessorProperty(accessor) => {
+                    if accessor.r#type == AccessorPropertyType::AccessorProperty {
+                        self.transform_accessor(
+                            accessor.unbox(),
+                            class_symbol_id,
+                            &mut new_elements,
+                            &mut state,
+                            ctx,
+                        );
+                    } else {
+                        new_elements.push(ClassElement::AccessorProperty(accessor));
+                    }
+                }
+                ClassElement::StaticBlock(mut block) => {
+                    let pending = mem::take(&mut state.static_pending);
+                    if !pending.is_empty() {
+                        let stmts = pending.iter().map(|binding| {
+                            ctx.ast.statement_expression(
+                                SPAN,
+                                Self::create_initializer_call(binding, true, ctx),
+                            )
+                        });
+                        block.body.splice(0..0, stmts.collect::<Vec<_>>());
+                    }
+                    new_elements.push(ClassElement::StaticBlock(block));
+                }
+                ClassElement::TSIndexSignature(_) => new_elements.push(element),
+            }
+        }
+
+        // Initializers which were not run by any field are run in the constructor
+        let instance_pending = mem::take(&mut state.instance_pending);
+        if !instance_pending.is_empty() {
+            Self::insert_instance_initializers(
+                &instance_pending,
+                class.super_class.is_some(),
+                constructor_index,
+                &mut new_elements,
+                class_scope_id,
+                ctx,
+            );
+        }
+        let static_pending = mem::take(&mut state.static_pending);
+        let static_pending = if !static_pending.is_empty() && !has_class_decorators {
+            // `static { _init_extra_x(); }`
+            let stmts = ctx.ast.vec_from_iter(static_pending.iter().map(|binding| {
+                ctx.ast
+                    .statement_expression(SPAN, Self::create_initializer_call(binding, true, ctx))
+            }));
+            let scope_id = ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock);
+            new_elements
+                .push(ctx.ast.class_element_static_block_with_scope_id(SPAN, stmts, scope_id));
+            vec![]
+        } else {
+            static_pending
+        };
+        class.body.body = new_elements;
+
+        if !has_decorators {
+            return (state.memos, None);
+        }
+
+        let class_bindings = has_class_decorators.then(|| {
+            let name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+            let class_binding = self.create_temp_binding(name, &state, ctx);
+            let init_class_binding = self.create_temp_binding("initClass", &state, ctx);
+            (class_binding, init_class_binding)
+        });
+
+        let parent = class
+            .super_class
+            .as_mut()
+            .map(|super_class| self.create_super_class_reference(super_class, &state, ctx));
+
+        let static_block = self.create_apply_decorators_static_block(
+            class_decorators,
+            class_bindings.as_ref(),
+            init_proto_binding.as_ref(),
+            init_static_binding.as_ref(),
+            parent,
+            &mut state,
+            ctx,
+        );
+        class.body.body.insert(0, static_block);
+
+        let decorated = class_bindings.map(|(class_binding, init_class_binding)| {
+            if let Some(symbol_id) = class_symbol_id {
+                ClassReferenceReplacer::new(symbol_id, &class_binding, ctx)
+                    .visit_class_body(&mut class.body);
+            }
+            DecoratedClass {
+                class_binding,
+                init_class_binding,
+                static_pending,
+                private_names: state.private_names,
+            }
+        });
+
+        (state.memos, decorated)
+    }
+
+    /// Transform a decorated method, getter or setter.
+    ///
+    /// Public methods are left as is, `applyDecs2311` replaces them with the decorated methods.
+    /// Private methods are moved into the decoration info, and replaced with a getter:
+    ///
+    /// * `@dec #m() {}` -> `get #m() { return _call_m; }`
+    /// * `@dec get #g() {}` -> `get #g() { return _call_g(this); }`
+    /// * `@dec set #s(v) {}` -> `set #s(v) { _call_s(this, v); }`
+    fn transform_method(
+        &self,
+        method: &mut MethodDefinition<'a>,
+        class_symbol_id: Option<SymbolId>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let decorators = method.decorators.take_in(ctx.ast);
+        let decorators = self.memoize_decorators(decorators, class_symbol_id, true, state, ctx);
+        self.memoize_key(&mut method.key, state, ctx);
+
+        let kind = match method.kind {
+            MethodDefinitionKind::Method => METHOD,
+            MethodDefinitionKind::Get => GETTER,
+            MethodDefinitionKind::Set => SETTER,
+            MethodDefinitionKind::Constructor => unreachable!(),
+        };
+        let is_static = method.r#static;
+        let flags = if is_static { kind | STATIC } else { kind };
+
+        let PropertyKey::PrivateIdentifier(ident) = &method.key else {
+            let name = Self::create_key_name(&method.key, ctx);
+            state.member_decs.push(Self::create_decoration_info(decorators, flags, name, [], ctx));
+            return;
+        };
+        let name = ident.name;
+
+        if SuperDetector::has_super(&method.value) {
+            self.ctx.error(
+                OxcDiagnostic::error(
+                    "Decorated private methods which contain `super` are not supported yet.",
+                )
+                .with_label(method.span),
+            );
+        }
+
+        // Move the method into the decoration info
+        let function_scope_id = method.value.scope_id();
+        ctx.scoping_mut()
+            .change_scope_parent_id(function_scope_id, Some(state.static_block_scope_id));
+        ctx.scoping_mut()
+            .scope_flags_mut(function_scope_id)
+            .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+
+        let call_binding = self.create_temp_binding(&format!("call_{name}"), state, ctx);
+        let (accessor_kind, accessor_function) = match kind {
+            // `get #m() { return _call_m; }`
+            METHOD => {
+                let body = call_binding.create_read_expression(ctx);
+                (MethodDefinitionKind::Get, Self::create_getter_function(body, state, ctx))
+            }
+            // `get #g() { return _call_g(this); }`
+            GETTER => {
+                let body = Self::create_call(&call_binding, [ctx.ast.expression_this(SPAN)], ctx);
+                (MethodDefinitionKind::Get, Self::create_getter_function(body, state, ctx))
+            }
+            // `set #s(v) { _call_s(this, v); }`
+            _ => {
+                let function = Self::create_setter_function(state, ctx, |value, ctx| {
+                    Self::create_call(&call_binding, [ctx.ast.expression_this(SPAN), value], ctx)
+                });
+                (MethodDefinitionKind::Set, function)
+            }
+        };
+        let mut function = mem::replace(&mut method.value, accessor_function);
+        method.kind = accessor_kind;
+        function.r#type = FunctionType::FunctionExpression;
+
+        let name_expr = ctx.ast.expression_string_literal(SPAN, name, None);
+        let function = Expression::FunctionExpression(function);
+        state.member_decs.push(Self::create_decoration_info(
+            decorators,
+            flags,
+            name_expr,
+            [function],
+            ctx,
+        ));
+        state.results[Self::result_group(is_static, false)].push(call_binding);
+        if !is_static && state.instance_brand.is_none() {
+            state.instance_brand = Some(name.into());
+        }
+    }
+
+    /// Transform a field, running pending initializers before its initializer.
+    ///
+    /// `@dec x = 1;` -> `x = _init_x(this, 1);`
+    fn transform_property(
+        &self,
+        prop: &mut PropertyDefinition<'a>,
+        class_symbol_id: Option<SymbolId>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_static = prop.r#static;
+        if prop.decorators.is_empty() {
+            self.memoize_key(&mut prop.key, state, ctx);
+            prop.value =
+                Self::prefix_pending_initializers(prop.value.take(), is_static, state, ctx);
+            return;
+        }
+
+        let decorators = prop.decorators.take_in(ctx.ast);
+        let decorators = self.memoize_decorators(decorators, class_symbol_id, true, state, ctx);
+        self.memoize_key(&mut prop.key, state, ctx);
+
+        let name = Self::key_uid_name(&prop.key);
+        let init_binding = self.create_temp_binding(&format!("init_{name}"), state, ctx);
+        let init_extra_binding =
+            self.create_temp_binding(&format!("init_extra_{name}"), state, ctx);
+
+        let flags = if is_static { FIELD | STATIC } else { FIELD };
+        let decoration_info = if let PropertyKey::PrivateIdentifier(ident) = &prop.key {
+            let name = ident.name;
+            if !is_static && state.instance_brand.is_none() {
+                state.instance_brand = Some(name.into());
+            }
+            let name_expr = ctx.ast.expression_string_literal(SPAN, name, None);
+            let getter = Self::create_private_getter_arrow(name.into(), state, ctx);
+            let setter = Self::create_private_setter_arrow(name.into(), state, ctx);
+            Self::create_decoration_info(decorators, flags, name_expr, [getter, setter], ctx)
+        } else {
+            let name_expr = Self::create_key_name(&prop.key, ctx);
+            Self::create_decoration_info(decorators, flags, name_expr, [], ctx)
+        };
+        state.member_decs.push(decoration_info);
+
+        let value = Self::create_init_call(&init_binding, is_static, prop.value.take(), ctx);
+        prop.value = Self::prefix_pending_initializers(Some(value), is_static, state, ctx);
+
+        let group = &mut state.results[Self::result_group(is_static, true)];
+        group.push(init_binding);
+        group.push(init_extra_binding.clone());
+        Self::pending_initializers(state, is_static).push(init_extra_binding);
+    }
+
+    /// Transform an `accessor` element into a private field, and a getter and setter which
+    /// access the private field.
+    ///
+    /// `accessor x = 1;` -> `#A = 1; get x() { return this.#A; } set x(v) { this.#A = v; }`
+    fn transform_accessor(
+        &self,
+        mut accessor: AccessorProperty<'a>,
+        class_symbol_id: Option<SymbolId>,
+        new_elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_static = accessor.r#static;
+        let is_decorated = !accessor.decorators.is_empty();
+        let decorators = accessor.decorators.take_in(ctx.ast);
+        let decorators = self.memoize_decorators(decorators, class_symbol_id, true, state, ctx);
+        self.memoize_key(&mut accessor.key, state, ctx);
+
+        let backing_name = state.private_names.generate(ctx);
+        let name = Self::key_uid_name(&accessor.key);
+
+        // Keys of the getter and the setter.
+        // A computed key which is not memoized is stored in a temp var by the getter:
+        // `get [_computedKey = _toPropertyKey(x())]() {} set [_computedKey](v) {}`
+        let key_needs_temp_var = !state.has_decorators
+            && accessor
+                .key
+                .as_expression()
+                .is_some_and(|key| self.ctx.key_needs_temp_var(key, ctx));
+        let (getter_key, setter_key) = if key_needs_temp_var {
+            let binding = self.create_temp_binding("computedKey", state, ctx);
+            let key = accessor.key.to_expression_mut().take_in(ctx.ast);
+            let key = self.ctx.helper_call_expr(
+                Helper::ToPropertyKey,
+                SPAN,
+                ctx.ast.vec1(Argument::from(key)),
+                ctx,
+            );
+            let assignment = create_assignment(&binding, key, ctx);
+            (PropertyKey::from(assignment), PropertyKey::from(binding.create_read_expression(ctx)))
+        } else {
+            (Self::clone_key(&accessor.key, ctx), accessor.key)
+        };
+
+        let mut value = accessor.value.take();
+        let mut getter_body = None;
+        let mut setter_call_binding = None;
+        if is_decorated {
+            let init_binding = self.create_temp_binding(&format!("init_{name}"), state, ctx);
+            let init_extra_binding =
+                self.create_temp_binding(&format!("init_extra_{name}"), state, ctx);
+            let flags = if is_static { ACCESSOR | STATIC } else { ACCESSOR };
+
+            let group = &mut state.results[Self::result_group(is_static, false)];
+            group.push(init_binding.clone());
+            let decoration_info = if let PropertyKey::PrivateIdentifier(ident) = &getter_key {
+                let name = ident.name;
+                if !is_static && state.instance_brand.is_none() {
+                    state.instance_brand = Some(name.into());
+                }
+                let get_binding = self.create_temp_binding(&format!("get_{name}"), state, ctx);
+                let set_binding = self.create_temp_binding(&format!("set_{name}"), state, ctx);
+                let group = &mut state.results[Self::result_group(is_static, false)];
+                group.push(get_binding.clone());
+                group.push(set_binding.clone());
+
+                // `get #x() { return _get_x(this); }`
+                let args = (!is_static).then(|| ctx.ast.expression_this(SPAN));
+                getter_body = Some(Self::create_call(&get_binding, args, ctx));
+                setter_call_binding = Some(set_binding);
+
+                let name_expr = ctx.ast.expression_string_literal(SPAN, name, None);
+                let getter = Self::create_private_getter_arrow(backing_name, state, ctx);
+                let setter = Self::create_private_setter_arrow(backing_name, state, ctx);
+                Self::create_decoration_info(decorators, flags, name_expr, [getter, setter], ctx)
+            } else {
+                let name_expr = Self::create_key_name(&getter_key, ctx);
+                Self::create_decoration_info(decorators, flags, name_expr, [], ctx)
+            };
+            state.member_decs.push(decoration_info);
+            state.results[Self::result_group(is_static, false)].push(init_extra_binding.clone());
+
+            value = Some(Self::create_init_call(&init_binding, is_static, value, ctx));
+            value = Self::prefix_pending_initializers(value, is_static, state, ctx);
+            Self::pending_initializers(state, is_static).push(init_extra_binding);
+        } else {
+            value = Self::prefix_pending_initializers(value, is_static, state, ctx);
+        }
+
+        // `#A = value;`
+        new_elements.push(ctx.ast.class_element_property_definition(
+            accessor.span,
+            PropertyDefinitionType::PropertyDefinition,
+            ctx.ast.vec(),
+            ctx.ast.property_key_private_identifier(SPAN, backing_name),
+            NONE,
+            value,
+            false,
+            is_static,
+            false,
+            false,
+            false,
+            false,
+            false,
+            None,
+        ));
+
+        // `get x() { return this.#A; }`
+        let getter_body = getter_body.unwrap_or_else(|| {
+            Self::create_private_field_access(ctx.ast.expression_this(SPAN), backing_name, ctx)
+        });
+        let getter = Self::create_getter_function(getter_body, state, ctx);
+        new_elements.push(ctx.ast.class_element_method_definition(
+            SPAN,
+            MethodDefinitionType::MethodDefinition,
+            ctx.ast.vec(),
+            getter_key,
+            getter,
+            MethodDefinitionKind::Get,
+            accessor.computed,
+            is_static,
+            false,
+            false,
+            None,
+        ));
+
+        // `set x(v) { this.#A = v; }`
+        let setter = Self::create_setter_function(state, ctx, |value, ctx| {
+            if let Some(set_binding) = &setter_call_binding {
+                // `_set_x(this, v)`
+                let args = if is_static {
+                    ctx.ast.vec1(Argument::from(value))
+                } else {
+                    ctx.ast.vec_from_array([
+                        Argument::from(ctx.ast.expression_this(SPAN)),
+                        Argument::from(value),
+                    ])
+                };
+                ctx.ast.expression_call(
+                    SPAN,
+                    set_binding.create_read_expression(ctx),
+                    NONE,
+                    args,
+                    false,
+                )
+            } else {
+                let target = ctx.ast.member_expression_private_field_expression(
+                    SPAN,
+                    ctx.ast.expression_this(SPAN),
+                    ctx.ast.private_identifier(SPAN, backing_name),
+                    false,
+                );
+                ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    value,
+                )
+            }
+        });
+        new_elements.push(ctx.ast.class_element_method_definition(
+            SPAN,
+            MethodDefinitionType::MethodDefinition,
+            ctx.ast.vec(),
+            setter_key,
+            setter,
+            MethodDefinitionKind::Set,
+            accessor.computed,
+            is_static,
+            false,
+            false,
+            None,
+        ));
+    }
+
+    /// Run instance initializers which were not run by any field in the constructor.
+    ///
+    /// * Base class: `constructor() { _initProto(this); ... }`
+    /// * Derived class: `constructor() { _initProto(super()); ... }`
+    fn insert_instance_initializers(
+        initializers: &[BoundIdentifier<'a>],
+        has_super_class: bool,
+        constructor_index: Option<usize>,
+        elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(constructor_index) = constructor_index else {
+            let scope_id = ctx
+                .create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::Constructor);
+            let stmts = initializers.iter().map(|binding| {
+                ctx.ast
+                    .statement_expression(SPAN, Self::create_initializer_call(binding, false, ctx))
+            });
+            let stmts = stmts.collect::<Vec<_>>();
+            elements.push(create_class_constructor(stmts, has_super_class, scope_id, ctx));
+            return;
+        };
+
+        let ClassElement::MethodDefinition(constructor) = &mut elements[constructor_index] else {
+            unreachable!()
+        };
+        let Some(body) = constructor.value.body.as_mut() else { return };
+        if has_super_class {
+            SuperCallReplacer { initializers, ctx }.visit_function_body(body);
+        } else {
+            let stmts = initializers.iter().map(|binding| {
+                ctx.ast
+                    .statement_expression(SPAN, Self::create_initializer_call(binding, false, ctx))
+            });
+            body.statements.splice(0..0, stmts.collect::<Vec<_>>());
+        }
+    }
+
+    /// Create the static block which applies the decorators.
+    ///
+    /// ```js
+    /// static {
+    ///   ({ e: [_init_x, _init_extra_x], c: [_Class, _initClass] } = _applyDecs2311(this, [dec], [[dec, 0, "x"]]));
+    ///   _initStatic(this);
+    /// }
+    /// ```
+    #[expect(clippy::too_many_arguments)]
+    fn create_apply_decorators_static_block(
+        &self,
+        class_decorators: Decorators<'a>,
+        class_bindings: Option<&(BoundIdentifier<'a>, BoundIdentifier<'a>)>,
+        init_proto_binding: Option<&BoundIdentifier<'a>>,
+        init_static_binding: Option<&BoundIdentifier<'a>>,
+        parent: Option<Expression<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        // `_applyDecs2311(this, [classDecs], [memberDecs], classDecsHaveThis, instanceBrand, parent)`
+        let (class_decorators, class_decorators_have_this) =
+            Self::create_decorators_array(class_decorators, ctx);
+        let member_decs = ctx.ast.vec_from_iter(
+            mem::take(&mut state.member_decs).into_iter().map(ArrayExpressionElement::from),
+        );
+        let instance_brand = state
+            .instance_brand
+            .take()
+            .map(|name| Self::create_instance_brand_arrow(name, state, ctx));
+
+        let mut arguments = ctx.ast.vec_from_array([
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            Argument::from(class_decorators),
+            Argument::from(ctx.ast.expression_array(SPAN, member_decs)),
+        ]);
+        if class_decorators_have_this || instance_brand.is_some() || parent.is_some() {
+            let have_this = if class_decorators_have_this { 1.0 } else { 0.0 };
+            arguments.push(Argument::from(ctx.ast.expression_numeric_literal(
+                SPAN,
+                have_this,
+                None,
+                NumberBase::Decimal,
+            )));
+        }
+        if instance_brand.is_some() || parent.is_some() {
+            arguments.push(Argument::from(instance_brand.unwrap_or_else(|| ctx.ast.void_0(SPAN))));
+        }
+        if let Some(parent) = parent {
+            arguments.push(Argument::from(parent));
+        }
+        let call = self.ctx.helper_call_expr(Helper::ApplyDecs2311, SPAN, arguments, ctx);
+
+        let results = mem::take(&mut state.results)
+            .into_iter()
+            .flatten()
+            .chain(init_proto_binding.cloned())
+            .chain(init_static_binding.cloned())
+            .collect::<Vec<_>>();
+        let apply = match (results.is_empty(), class_bindings) {
+            // `_applyDecs2311(...)`
+            (true, None) => call,
+            // `[_init_x, _init_extra_x] = _applyDecs2311(...).e`
+            (false, None) => {
+                let target = Self::create_array_assignment_target(&results, ctx);
+                let call = create_property_access(SPAN, call, "e", ctx);
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, call)
+            }
+            // `[_Class, _initClass] = _applyDecs2311(...).c`
+            (true, Some((class_binding, init_class_binding))) => {
+                let bindings = [class_binding.clone(), init_class_binding.clone()];
+                let target = Self::create_array_assignment_target(&bindings, ctx);
+                let call = create_property_access(SPAN, call, "c", ctx);
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, call)
+            }
+            // `({ e: [_init_x, _init_extra_x], c: [_Class, _initClass] } = _applyDecs2311(...))`
+            (false, Some((class_binding, init_class_binding))) => {
+                let bindings = [class_binding.clone(), init_class_binding.clone()];
+                let properties = ctx.ast.vec_from_array([
+                    ctx.ast.assignment_target_property_assignment_target_property_property(
+                        SPAN,
+                        ctx.ast.property_key_static_identifier(SPAN, "e"),
+                        AssignmentTargetMaybeDefault::from(Self::create_array_assignment_target(
+                            &results, ctx,
+                        )),
+                        false,
+                    ),
+                    ctx.ast.assignment_target_property_assignment_target_property_property(
+                        SPAN,
+                        ctx.ast.property_key_static_identifier(SPAN, "c"),
+                        AssignmentTargetMaybeDefault::from(Self::create_array_assignment_target(
+                            &bindings, ctx,
+                        )),
+                        false,
+                    ),
+                ]);
+                let target = AssignmentTarget::from(
+                    ctx.ast
+                        .assignment_target_pattern_object_assignment_target(SPAN, properties, NONE),
+                );
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, call)
+            }
+        };
+
+        let mut stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, apply));
+        if let Some(init_static_binding) = init_static_binding {
+            // `_initStatic(this)`
+            let call = Self::create_call(init_static_binding, [ctx.ast.expression_this(SPAN)], ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+        ctx.ast.class_element_static_block_with_scope_id(SPAN, stmts, state.static_block_scope_id)
+    }
+
+    /// Create a reference to the super class, for `applyDecs2311` to inherit its metadata.
+    ///
+    /// A super class which is not an identifier is stored in a temp var:
+    /// `class C extends (_super = x()) {}`
+    fn create_super_class_reference(
+        &self,
+        super_class: &mut Expression<'a>,
+        state: &ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let Expression::Identifier(ident) = super_class {
+            return MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                .create_read_expression(ctx);
+        }
+        let binding = self.create_temp_binding("super", state, ctx);
+        let value = super_class.take_in(ctx.ast);
+        *super_class = create_assignment(&binding, value, ctx);
+        binding.create_read_expression(ctx)
+    }
+
+    /// Create the class expression of a class with class decorators.
+    ///
+    /// `(class C {}, _initClass(), _C)`
+    ///
+    /// If the class has static fields or static blocks, they need to be evaluated after the class
+    /// decorators have been applied, and with `this` being the decorated class. So they are moved into
+    /// a wrapper class, whose constructor returns the decorated class:
+    ///
+    /// ```js
+    /// (new class extends _identity {
+    ///   static [class C {}];
+    ///   x = 1;
+    ///   constructor() {
+    ///     super(_C), _initClass();
+    ///   }
+    /// }(), _C)
+    /// ```
+    fn create_decorated_class_expression(
+        &self,
+        mut class: ArenaBox<'a, Class<'a>>,
+        decorated: DecoratedClass<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let DecoratedClass { class_binding, init_class_binding, static_pending, mut private_names } =
+            decorated;
+
+        // The first element is the static block which applies the decorators
+        let has_static_elements = class.body.body.iter().skip(1).any(|element| match element {
+            ClassElement::PropertyDefinition(prop) => prop.r#static,
+            ClassElement::StaticBlock(_) => true,
+            _ => false,
+        });
+        if !has_static_elements {
+            let init_class = Self::create_call(&init_class_binding, [], ctx);
+            return ctx.ast.expression_sequence(
+                SPAN,
+                ctx.ast.vec_from_array([
+                    Expression::ClassExpression(class),
+                    init_class,
+                    class_binding.create_read_expression(ctx),
+                ]),
+            );
+        }
+
+        let class_scope_id = class.scope_id();
+        let parent_scope_id = ctx.scoping().scope_parent_id(class_scope_id).unwrap();
+        let wrapper_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::StrictMode);
+        ctx.scoping_mut().change_scope_parent_id(class_scope_id, Some(wrapper_scope_id));
+
+        // Move static fields and static blocks into the wrapper class as instance fields
+        let mut wrapper_elements = ctx.ast.vec();
+        let elements = class.body.body.take_in(ctx.ast);
+        let mut elements = elements.into_iter();
+        let mut class_elements = ctx.ast.vec_from_iter(elements.next());
+        for element in elements {
+            match element {
+                ClassElement::PropertyDefinition(mut prop) if prop.r#static => {
+                    prop.r#static = false;
+                    if let Some(value) = &prop.value {
+                        reparent_child_scopes(value, wrapper_scope_id, ctx);
+                    }
+                    wrapper_elements.push(ClassElement::PropertyDefinition(prop));
+                }
+                ClassElement::StaticBlock(block) => {
+                    // `static { foo(); }` -> `#B = (() => { foo(); })();`
+                    let StaticBlock { span, body, scope_id } = block.unbox();
+                    let scope_id = scope_id.get().unwrap();
+                    *ctx.scoping_mut().scope_flags_mut(scope_id) =
+                        ScopeFlags::Function | ScopeFlags::Arrow | ScopeFlags::StrictMode;
+                    ctx.scoping_mut().change_scope_parent_id(scope_id, Some(wrapper_scope_id));
+                    let value = wrap_statements_in_arrow_function_iife(body, scope_id, span, ctx);
+                    let name = private_names.generate(ctx);
+                    wrapper_elements.push(ctx.ast.class_element_property_definition(
+                        span,
+                        PropertyDefinitionType::PropertyDefinition,
+                        ctx.ast.vec(),
+                        ctx.ast.property_key_private_identifier(SPAN, name),
+                        NONE,
+                        Some(value),
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                        None,
+                    ));
+                }
+                element => class_elements.push(element),
+            }
+        }
+        class.body.body = class_elements;
+
+        // `static [class C {}];`
+        wrapper_elements.insert(
+            0,
+            ctx.ast.class_element_property_definition(
+                SPAN,
+                PropertyDefinitionType::PropertyDefinition,
+                ctx.ast.vec(),
+                PropertyKey::from(Expression::ClassExpression(class)),
+                NONE,
+                None,
+                true,
+                true,
+                false,
+                false,
+                false,
+                false,
+                false,
+                None,
+            ),
+        );
+
+        // `constructor() { super(_C), _init_extra_x(), _initClass(); }`
+        let super_call = ctx.ast.expression_call(
+            SPAN,
+            ctx.ast.expression_super(SPAN),
+            NONE,
+            ctx.ast.vec1(Argument::from(class_binding.create_read_expression(ctx))),
+            false,
+        );
+        let mut expressions = ctx.ast.vec1(super_call);
+        expressions.extend(
+            static_pending
+                .iter()
+                .map(|binding| Self::create_initializer_call(binding, true, ctx))
+                .collect::<Vec<_>>(),
+        );
+        expressions.push(Self::create_call(&init_class_binding, [], ctx));
+        let stmt =
+            ctx.ast.statement_expression(SPAN, ctx.ast.expression_sequence(SPAN, expressions));
+        let constructor_scope_id = ctx
+            .create_child_scope(wrapper_scope_id, ScopeFlags::Function | ScopeFlags::Constructor);
+        wrapper_elements.push(create_class_constructor([stmt], false, constructor_scope_id, ctx));
+
+        let super_class = self.ctx.helper_load(Helper::Identity, ctx);
+        let wrapper = ctx.ast.expression_class_with_scope_id(
+            SPAN,
+            ClassType::ClassExpression,
+            ctx.ast.vec(),
+            None,
+            NONE,
+            Some(super_class),
+            NONE,
+            ctx.ast.vec(),
+            ctx.ast.class_body(SPAN, wrapper_elements),
+            false,
+            false,
+            wrapper_scope_id,
+        );
+        let wrapper = ctx.ast.expression_new(SPAN, wrapper, NONE, ctx.ast.vec());
+        ctx.ast.expression_sequence(
+            SPAN,
+            ctx.ast.vec_from_array([wrapper, class_binding.create_read_expression(ctx)]),
+        )
+    }
+
+    /// `class C {}` -> `let C = (class C {}, _initClass(), _C);`
+    ///
+    /// Returns the declaration and the binding of the declared variable.
+    fn create_class_variable_declaration(
+        &self,
+        mut class: ArenaBox<'a, Class<'a>>,
+        decorated: DecoratedClass<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (ArenaBox<'a, VariableDeclaration<'a>>, BoundIdentifier<'a>) {
+        let span = class.span;
+        let class_scope_id = class.scope_id();
+        let ident = class.id.as_mut().expect("class declaration always has a binding");
+
+        // The class keeps its name, and a new binding for the class expression is created,
+        // the original binding (and its span) becomes the variable.
+        let name = ident.name.into();
+        let ident_span = mem::replace(&mut ident.span, SPAN);
+        let class_expression_binding =
+            ctx.generate_binding(name, class_scope_id, SymbolFlags::Class);
+        let symbol_id = ident.symbol_id.replace(Some(class_expression_binding.symbol_id));
+        let symbol_id = symbol_id.expect("class always has a symbol id");
+        *ctx.scoping_mut().symbol_flags_mut(symbol_id) = SymbolFlags::BlockScopedVariable;
+        let binding = BoundIdentifier::new(name, symbol_id);
+        let pattern = BindingPattern::BindingIdentifier(
+            ctx.ast.alloc_binding_identifier_with_symbol_id(ident_span, name, symbol_id),
+        );
+
+        class.r#type = ClassType::ClassExpression;
+        let init = self.create_decorated_class_expression(class, decorated, ctx);
+        let kind = VariableDeclarationKind::Let;
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, NONE, Some(init), false);
+        let declaration =
+            ctx.ast.alloc_variable_declaration(span, kind, ctx.ast.vec1(declarator), false);
+        (declaration, binding)
+    }
+
+    /// `export { C as default }`
+    fn create_export_default_specifier(
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let kind = ImportOrExportKind::Value;
+        let local = ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+        let exported = ctx.ast.module_export_name_identifier_name(SPAN, "default");
+        let specifiers = ctx.ast.vec1(ctx.ast.export_specifier(SPAN, local, exported, kind));
+        Statement::from(
+            ctx.ast.module_declaration_export_named_declaration(
+                SPAN, None, specifiers, None, kind, NONE,
+            ),
+        )
+    }
+
+    /// Memoize decorators which can't be evaluated in the static block, so that all decorators
+    /// are evaluated before the class, in order.
+    ///
+    /// * `@dec` -> `dec`
+    /// * `@obj.dec` -> `obj, obj.dec` (called with `this` being `obj`)
+    /// * `@a.b.dec` -> `_a$b = a.b` before the class, and `_a$b, _a$b.dec`
+    /// * `@dec()` -> `_dec = dec()` before the class, and `_dec`
+    fn memoize_decorators(
+        &self,
+        decorators: ArenaVec<'a, Decorator<'a>>,
+        class_symbol_id: Option<SymbolId>,
+        is_element: bool,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Decorators<'a> {
+        decorators
+            .into_iter()
+            .map(|decorator| {
+                let mut expr = decorator.expression;
+                if is_element {
+                    reparent_child_scopes(&expr, state.outer_scope_id, ctx);
+                }
+                match &mut expr {
+                    Expression::Identifier(ident)
+                        if Self::is_constant_reference(ident, class_symbol_id, ctx) =>
+                    {
+                        (None, expr)
+                    }
+                    Expression::StaticMemberExpression(member) if !member.optional => {
+                        let this = self.memoize_decorator_object(
+                            &mut member.object,
+                            class_symbol_id,
+                            state,
+                            ctx,
+                        );
+                        (Some(this), expr)
+                    }
+                    _ => {
+                        let binding = self.create_temp_binding("dec", state, ctx);
+                        state.memos.push(create_assignment(&binding, expr, ctx));
+                        (None, binding.create_read_expression(ctx))
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Get the `this` value of a member expression decorator, memoizing the object if required.
+    fn memoize_decorator_object(
+        &self,
+        object: &mut Expression<'a>,
+        class_symbol_id: Option<SymbolId>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let Expression::Identifier(ident) = object
+            && Self::is_constant_reference(ident, class_symbol_id, ctx)
+        {
+            return MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                .create_read_expression(ctx);
+        }
+        let binding = ctx.generate_uid_based_on_node(
+            &*object,
+            state.outer_scope_id,
+            SymbolFlags::BlockScopedVariable,
+        );
+        self.ctx.var_declarations.insert_let(&binding, None, ctx);
+        let value = object.take_in(ctx.ast);
+        state.memos.push(create_assignment(&binding, value, ctx));
+        *object = binding.create_read_expression(ctx);
+        binding.create_read_expression(ctx)
+    }
+
+    /// Check if a reference can be evaluated later than where it is written, without changing
+    /// its value.
+    fn is_constant_reference(
+        ident: &IdentifierReference<'a>,
+        class_symbol_id: Option<SymbolId>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        match ctx.scoping().get_reference(ident.reference_id()).symbol_id() {
+            Some(symbol_id) => {
+                Some(symbol_id) != class_symbol_id && !ctx.scoping().symbol_is_mutated(symbol_id)
+            }
+            None => true,
+        }
+    }
+
+    /// Memoize a computed key of a class which has decorators, to keep the evaluation order
+    /// of decorators and computed keys.
+    ///
+    /// `[x()]` -> `_computedKey = _toPropertyKey(x())` before the class, and `[_computedKey]`
+    fn memoize_key(
+        &self,
+        key: &mut PropertyKey<'a>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !state.has_decorators {
+            return;
+        }
+        let Some(key_expr) = key.as_expression_mut() else { return };
+        if !self.ctx.key_needs_temp_var(key_expr, ctx) {
+            return;
+        }
+        reparent_child_scopes(key_expr, state.outer_scope_id, ctx);
+        let binding = self.create_temp_binding("computedKey", state, ctx);
+        let value = key_expr.take_in(ctx.ast);
+        let value = self.ctx.helper_call_expr(
+            Helper::ToPropertyKey,
+            SPAN,
+            ctx.ast.vec1(Argument::from(value)),
+            ctx,
+        );
+        state.memos.push(create_assignment(&binding, value, ctx));
+        *key_expr = binding.create_read_expression(ctx);
+    }
+
+    /// Create a temp var binding in the scope outside the class, and insert `let _name;`.
+    fn create_temp_binding(
+        &self,
+        name: &str,
+        state: &ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let binding =
+            ctx.generate_uid(name, state.outer_scope_id, SymbolFlags::BlockScopedVariable);
+        self.ctx.var_declarations.insert_let(&binding, None, ctx);
+        binding
+    }
+
+    /// Get the name used in temp vars for a class element.
+    fn key_uid_name(key: &PropertyKey<'a>) -> String {
+        let name = match key {
+            PropertyKey::StaticIdentifier(ident) => ident.name.as_str(),
+            PropertyKey::PrivateIdentifier(ident) => ident.name.as_str(),
+            PropertyKey::StringLiteral(lit) => lit.value.as_str(),
+            _ => "computedKey",
+        };
+        let name = name.chars().filter(|&c| is_identifier_part(c)).collect::<String>();
+        if name.is_empty() { "computedKey".to_string() } else { name }
+    }
+
+    /// Create the name of a public class element, passed to `applyDecs2311`.
+    fn create_key_name(key: &PropertyKey<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(SPAN, ident.name, None)
+            }
+            PropertyKey::PrivateIdentifier(_) => unreachable!(),
+            key => {
+                let key = key.to_expression();
+                if let Expression::Identifier(ident) = key {
+                    MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                        .create_read_expression(ctx)
+                } else {
+                    key.clone_in(ctx.ast.allocator)
+                }
+            }
+        }
+    }
+
+    /// Clone a key which does not need a temp var.
+    fn clone_key(key: &PropertyKey<'a>, ctx: &mut TraverseCtx<'a>) -> PropertyKey<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(_) | PropertyKey::PrivateIdentifier(_) => {
+                key.clone_in(ctx.ast.allocator)
+            }
+            key => PropertyKey::from(Self::create_key_name(key, ctx)),
+        }
+    }
+
+    /// Index of the group of `applyDecs2311` results which a class element's functions belong to.
+    fn result_group(is_static: bool, is_field: bool) -> usize {
+        match (is_static, is_field) {
+            (true, false) => 0,
+            (false, false) => 1,
+            (true, true) => 2,
+            (false, true) => 3,
+        }
+    }
+
+    fn pending_initializers<'s>(
+        state: &'s mut ClassState<'a>,
+        is_static: bool,
+    ) -> &'s mut Vec<BoundIdentifier<'a>> {
+        if is_static { &mut state.static_pending } else { &mut state.instance_pending }
+    }
+
+    /// Run pending initializers before a field's initializer.
+    ///
+    /// `x = 1` -> `x = (_init_extra_y(this), 1)`
+    fn prefix_pending_initializers(
+        value: Option<Expression<'a>>,
+        is_static: bool,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let pending = mem::take(Self::pending_initializers(state, is_static));
+        if pending.is_empty() {
+            return value;
+        }
+        let mut expressions = ctx.ast.vec_from_iter(
+            pending
+                .iter()
+                .map(|binding| Self::create_initializer_call(binding, is_static, ctx))
+                .collect::<Vec<_>>(),
+        );
+        expressions.push(value.unwrap_or_else(|| ctx.ast.void_0(SPAN)));
+        Some(ctx.ast.expression_sequence(SPAN, expressions))
+    }
+
+    /// * Instance: `_init_extra_x(this)`
+    /// * Static: `_init_extra_x()`
+    fn create_initializer_call(
+        binding: &BoundIdentifier<'a>,
+        is_static: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let this = (!is_static).then(|| ctx.ast.expression_this(SPAN));
+        Self::create_call(binding, this, ctx)
+    }
+
+    /// * Instance: `_init_x(this, value)`
+    /// * Static: `_init_x(value)`
+    fn create_init_call(
+        binding: &BoundIdentifier<'a>,
+        is_static: bool,
+        value: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let this = (!is_static).then(|| ctx.ast.expression_this(SPAN));
+        Self::create_call(binding, this.into_iter().chain(value), ctx)
+    }
+
+    fn create_call(
+        binding: &BoundIdentifier<'a>,
+        arguments: impl IntoIterator<Item = Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments = ctx.ast.vec_from_iter(arguments.into_iter().map(Argument::from));
+        ctx.ast.expression_call(SPAN, binding.create_read_expression(ctx), NONE, arguments, false)
+    }
+
+    /// Create decoration info of a class element: `[decs, flags, name, ...privateFunctions]`.
+    fn create_decoration_info(
+        decorators: Decorators<'a>,
+        mut flags: u32,
+        name: Expression<'a>,
+        private_functions: impl IntoIterator<Item = Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let has_this = decorators.iter().any(|(this, _)| this.is_some());
+        let decorators = if decorators.len() == 1 && !has_this {
+            decorators.into_iter().next().unwrap().1
+        } else {
+            Self::create_decorators_array(decorators, ctx).0
+        };
+        if has_this {
+            flags |= DECORATORS_HAVE_THIS;
+        }
+
+        let mut elements = ctx.ast.vec_from_array([
+            ArrayExpressionElement::from(decorators),
+            ArrayExpressionElement::from(ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(flags),
+                None,
+                NumberBase::Decimal,
+            )),
+            ArrayExpressionElement::from(name),
+        ]);
+        elements.extend(private_functions.into_iter().map(ArrayExpressionElement::from));
+        ctx.ast.expression_array(SPAN, elements)
+    }
+
+    /// Create an array of decorators. If any decorator has a `this` value, the array contains
+    /// `this` and decorator pairs: `[obj, obj.dec, void 0, dec]`.
+    ///
+    /// Returns the array, and whether the decorators have `this` values.
+    fn create_decorators_array(
+        decorators: Decorators<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> (Expression<'a>, bool) {
+        let has_this = decorators.iter().any(|(this, _)| this.is_some());
+        let mut elements = ctx.ast.vec();
+        for (this, decorator) in decorators {
+            if has_this {
+                let this = this.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+                elements.push(ArrayExpressionElement::from(this));
+            }
+            elements.push(ArrayExpressionElement::from(decorator));
+        }
+        (ctx.ast.expression_array(SPAN, elements), has_this)
+    }
+
+    /// `[_a, _b]` assignment target.
+    fn create_array_assignment_target(
+        bindings: &[BoundIdentifier<'a>],
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a> {
+        let elements = ctx.ast.vec_from_iter(
+            bindings
+                .iter()
+                .map(|binding| {
+                    Some(AssignmentTargetMaybeDefault::from(binding.create_write_target(ctx)))
+                })
+                .collect::<Vec<_>>(),
+        );
+        AssignmentTarget::from(
+            ctx.ast.assignment_target_pattern_array_assignment_target(SPAN, elements, NONE),
+        )
+    }
+
+    /// `object.#name`
+    fn create_private_field_access(
+        object: Expression<'a>,
+        name: Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        Expression::from(ctx.ast.member_expression_private_field_expression(
+            SPAN,
+            object,
+            ctx.ast.private_identifier(SPAN, name),
+            false,
+        ))
+    }
+
+    /// `o => o.#name`
+    fn create_private_getter_arrow(
+        name: Atom<'a>,
+        state: &ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        Self::create_arrow(
+            state,
+            ctx,
+            |params, ctx| {
+                let object = params[0].create_read_expression(ctx);
+                Self::create_private_field_access(object, name, ctx)
+            },
+            &["o"],
+        )
+    }
+
+    /// `(o, v) => o.#name = v`
+    fn create_private_setter_arrow(
+        name: Atom<'a>,
+        state: &ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        Self::create_arrow(
+            state,
+            ctx,
+            |params, ctx| {
+                let target = ctx.ast.member_expression_private_field_expression(
+                    SPAN,
+                    params[0].create_read_expression(ctx),
+                    ctx.ast.private_identifier(SPAN, name),
+                    false,
+                );
+                ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    params[1].create_read_expression(ctx),
+                )
+            },
+            &["o", "v"],
+        )
+    }
+
+    /// `_ => #name in _`
+    fn create_instance_brand_arrow(
+        name: Atom<'a>,
+        state: &ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        Self::create_arrow(
+            state,
+            ctx,
+            |params, ctx| {
+                let right = params[0].create_read_expression(ctx);
+                ctx.ast.expression_private_in(SPAN, ctx.ast.private_identifier(SPAN, name), right)
+            },
+            &["_"],
+        )
+    }
+
+    /// Create an arrow function with an expression body, in the scope of the static block which
+    /// applies the decorators.
+    fn create_arrow(
+        state: &ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+        body: impl FnOnce(&[BoundIdentifier<'a>], &mut TraverseCtx<'a>) -> Expression<'a>,
+        param_names: &[&'static str],
+    ) -> Expression<'a> {
+        let scope_id = ctx.create_child_scope(
+            state.static_block_scope_id,
+            ScopeFlags::Function | ScopeFlags::Arrow,
+        );
+        let params = param_names
+            .iter()
+            .map(|&name| {
+                ctx.generate_binding(
+                    Atom::from(name),
+                    scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                )
+            })
+            .collect::<Vec<_>>();
+        let items = ctx
+            .ast
+            .vec_from_iter(params.iter().map(|param| Self::create_formal_parameter(param, ctx)));
+        let params_node = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            items,
+            NONE,
+        );
+        let expr = body(&params, ctx);
+        let body = ctx.ast.alloc_function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, expr)),
+        );
+        ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            true,
+            false,
+            NONE,
+            params_node,
+            NONE,
+            body,
+            scope_id,
+            false,
+            false,
+        )
+    }
+
+    fn create_formal_parameter(
+        binding: &BoundIdentifier<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> FormalParameter<'a> {
+        ctx.ast.formal_parameter(
+            SPAN,
+            ctx.ast.vec(),
+            binding.create_binding_pattern(ctx),
+            NONE,
+            NONE,
+            false,
+            None,
+            false,
+            false,
+        )
+    }
+
+    /// `function() { return value; }`, used as a getter in the class.
+    fn create_getter_function(
+        value: Expression<'a>,
+        state: &ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        let scope_id = ctx.create_child_scope(
+            state.class_scope_id,
+            ScopeFlags::Function | ScopeFlags::GetAccessor,
+        );
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let stmts = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value)));
+        Self::create_method_function(params, stmts, scope_id, ctx)
+    }
+
+    /// `function(v) { body(v); }`, used as a setter in the class.
+    fn create_setter_function(
+        state: &ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+        body: impl FnOnce(Expression<'a>, &mut TraverseCtx<'a>) -> Expression<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        let scope_id = ctx.create_child_scope(
+            state.class_scope_id,
+            ScopeFlags::Function | ScopeFlags::SetAccessor,
+        );
+        let binding =
+            ctx.generate_binding(Atom::from("v"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let param = Self::create_formal_parameter(&binding, ctx);
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec1(param),
+            NONE,
+        );
+        let value = binding.create_read_expression(ctx);
+        let stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, body(value, ctx)));
+        Self::create_method_function(params, stmts, scope_id, ctx)
+    }
+
+    fn create_method_function(
+        params: ArenaBox<'a, FormalParameters<'a>>,
+        stmts: ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts)),
+            scope_id,
+        )
+    }
+}
+
+/// Private names declared in a class, used to generate names for the private fields which
+/// store the values of `accessor`s.
+struct PrivateNames<'a> {
+    names: FxHashSet<&'a str>,
+    next: u32,
+}
+
+impl<'a> PrivateNames<'a> {
+    fn new(body: &ClassBody<'a>) -> Self {
+        let names = body
+            .body
+            .iter()
+            .filter_map(|element| element.property_key()?.private_name())
+            .map(|name| name.as_str())
+            .collect();
+        Self { names, next: 0 }
+    }
+
+    /// Generate a private name which is not used in the class: `A`, `B`, ..., `Z`, `AA`, `AB`, ...
+    fn generate(&mut self, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+        loop {
+            let mut n = self.next;
+            self.next += 1;
+            let mut name = String::new();
+            loop {
+                name.insert(0, char::from(b'A' + u8::try_from(n % 26).unwrap()));
+                if n < 26 {
+                    break;
+                }
+                n = n / 26 - 1;
+            }
+            let name = ctx.ast.atom(&name);
+            if self.names.insert(name.as_str()) {
+                return name;
+            }
+        }
+    }
+}
+
+/// Detects `super` in a function, excluding nested functions and classes.
+#[derive(Default)]
+struct SuperDetector {
+    has_super: bool,
+}
+
+impl SuperDetector {
+    fn has_super(function: &Function<'_>) -> bool {
+        let mut detector = Self::default();
+        if let Some(body) = &function.body {
+            detector.visit_function_body(body);
+        }
+        detector.visit_formal_parameters(&function.params);
+        detector.has_super
+    }
+}
+
+impl<'a> Visit<'a> for SuperDetector {
+    fn visit_super(&mut self, _it: &Super) {
+        self.has_super = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _it: &Class<'a>) {}
+}
+
+/// Replaces references to the class within its body with the decorated class.
+///
+/// `class C { static m() { C; } }` -> `class C { static m() { _C; } }`
+struct ClassReferenceReplacer<'a, 'b, 'ctx> {
+    symbol_id: SymbolId,
+    binding: &'b BoundIdentifier<'a>,
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b, 'ctx> ClassReferenceReplacer<'a, 'b, 'ctx> {
+    fn new(
+        symbol_id: SymbolId,
+        binding: &'b BoundIdentifier<'a>,
+        ctx: &'ctx mut TraverseCtx<'a>,
+    ) -> Self {
+        Self { symbol_id, binding, ctx }
+    }
+}
+
+impl<'a> VisitMut<'a> for ClassReferenceReplacer<'a, '_, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let reference = self.ctx.scoping().get_reference(reference_id);
+        if reference.symbol_id() != Some(self.symbol_id) {
+            return;
+        }
+        let flags = reference.flags();
+        self.ctx.scoping_mut().delete_resolved_reference(self.symbol_id, reference_id);
+        *ident = self.binding.create_spanned_reference(ident.span, flags, self.ctx);
+    }
+}
+
+/// Runs initializers after `super()` in a constructor of a derived class.
+///
+/// `super()` -> `_initProto(super())`
+struct SuperCallReplacer<'a, 'b, 'ctx> {
+    initializers: &'b [BoundIdentifier<'a>],
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for SuperCallReplacer<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_mut::walk_expression(self, expr);
+        if !matches!(expr, Expression::CallExpression(call) if call.callee.is_super()) {
+            return;
+        }
+        for binding in self.initializers {
+            let call = expr.take_in(self.ctx.ast);
+            *expr = StandardDecorator::create_call(binding, [call], self.ctx);
+        }
+    }
+
+    fn visit_function(&mut self, _it: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _it: &mut Class<'a>) {}
+}
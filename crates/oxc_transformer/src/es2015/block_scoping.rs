@@ -0,0 +1,1299 @@
+//! ES2015: Block Scoping
+//!
+//! This plugin transforms `let` and `const` declarations into `var` declarations.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let a = 1;
+//! {
+//!   let a = 2;
+//! }
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(() => i);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = 1;
+//! {
+//!   var _a = 2;
+//! }
+//! var _loop = function (i) {
+//!   fns.push(() => i);
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   _loop(i);
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-block-scoping](https://babel.dev/docs/babel-plugin-transform-block-scoping).
+//!
+//! The transform runs on the whole program at once in `exit_program`, after all other transforms,
+//! because other transforms create `let` declarations, and because a loop can only be analysed once
+//! its body has been fully transformed (e.g. by for-of transform). It is done in 2 passes:
+//!
+//! 1. [`LoopClosureWrapper`] wraps bodies of loops in a `_loop` function, if the loop declares
+//!    bindings which are captured by closures. This gives each iteration its own copy of the bindings.
+//!    `break`, `continue` and `return` in the body are replaced by returning a completion value from
+//!    `_loop`, which is checked after calling it.
+//! 2. [`BlockScopedConverter`] converts `let` and `const` declarations to `var`, and moves their
+//!    bindings to the enclosing function scope. Bindings are renamed if they would clash with
+//!    another binding, or shadow a reference to a global.
+//!
+//! Supports the `tdz` and `throwIfClosureRequired` options.
+//!
+//! Differences from Babel:
+//! * With `tdz` option, only references which are definitely in the temporal dead zone are
+//!   replaced with a `_tdz` call. Babel also inserts runtime checks for references in closures.
+//! * Loops using `super` or `new.target` are not wrapped in a closure, and an error is reported.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-block-scoping>
+//! * let: <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/let>
+
+use std::{cell::Cell, mem};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use oxc_allocator::{Address, GetAddress, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::utils::{
+    SymbolRenamer, binding_pattern_to_assignment_target, create_number, move_all_bindings,
+    reparent_child_scopes,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BlockScopingOptions {
+    /// Throw a `ReferenceError` when a binding is accessed before its declaration.
+    pub tdz: bool,
+    /// Report an error instead of wrapping a loop body in a closure.
+    pub throw_if_closure_required: bool,
+}
+
+pub struct BlockScoping<'a, 'ctx> {
+    options: BlockScopingOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    pub fn new(options: BlockScopingOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for BlockScoping<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        LoopClosureWrapper::new(self.options.throw_if_closure_required, self.ctx, ctx)
+            .visit_program(program);
+
+        let tdz_declarations = if self.options.tdz {
+            let mut collector = TdzDeclarationCollector::default();
+            collector.visit_program(program);
+            collector.declarations
+        } else {
+            FxHashMap::default()
+        };
+        let mut converter = BlockScopedConverter::new(tdz_declarations, self.ctx, ctx);
+        converter.visit_program(program);
+
+        let renamed_symbols = converter.renamed_symbols;
+        if !renamed_symbols.is_empty() {
+            SymbolRenamer::new(renamed_symbols, ctx).visit_program(program);
+        }
+    }
+}
+
+/// Returns `true` if `decl` is a `let` or `const` declaration.
+fn is_block_scoped_declaration(decl: &VariableDeclaration<'_>) -> bool {
+    matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const)
+}
+
+/// Get symbols declared in `left` side of a `for...in` / `for...of` statement, if it's a `let` or `const` declaration.
+fn block_scoped_symbols_in_left(left: &ForStatementLeft<'_>) -> Vec<SymbolId> {
+    match left {
+        ForStatementLeft::VariableDeclaration(decl) => block_scoped_symbols(decl),
+        _ => vec![],
+    }
+}
+
+/// Get symbols declared in `decl`, if it's a `let` or `const` declaration.
+fn block_scoped_symbols(decl: &VariableDeclaration<'_>) -> Vec<SymbolId> {
+    let mut symbol_ids = vec![];
+    if is_block_scoped_declaration(decl) {
+        decl.bound_names(&mut |ident| symbol_ids.push(ident.symbol_id()));
+    }
+    symbol_ids
+}
+
+#[derive(Clone, Copy)]
+struct FunctionInfo {
+    scope_id: ScopeId,
+    is_async: bool,
+    is_generator: bool,
+}
+
+/// Wraps loop bodies in a closure, if they declare bindings which are captured by closures.
+///
+/// ```js
+/// for (let i = 0; i < 3; i++) {
+///   if (i === 1) continue;
+///   fns.push(() => i);
+/// }
+/// ```
+/// ->
+/// ```js
+/// var _loop = function (i) {
+///     if (i === 1) return 0;
+///     fns.push(() => i);
+///   }, _ret;
+/// for (let i = 0; i < 3; i++) {
+///   _ret = _loop(i);
+///   if (_ret === 0) continue;
+/// }
+/// ```
+struct LoopClosureWrapper<'a, 'ctx, 'v> {
+    throw_if_closure_required: bool,
+    transform_ctx: &'ctx TransformCtx<'a>,
+    ctx: &'v mut TraverseCtx<'a>,
+    scope_stack: Vec<ScopeId>,
+    functions: Vec<FunctionInfo>,
+    /// Address of the statement about to be visited, if it is in a statement list
+    statement_in_list: Option<Address>,
+    /// Declaration of `_loop` to be inserted before the statement being visited
+    pending_declaration: Option<Statement<'a>>,
+    /// Declarations of `_loop`s which have been created.
+    /// These are not hoisted when an outer loop is wrapped in a closure.
+    loop_declarations: FxHashSet<Address>,
+}
+
+impl<'a, 'ctx, 'v> LoopClosureWrapper<'a, 'ctx, 'v> {
+    fn new(
+        throw_if_closure_required: bool,
+        transform_ctx: &'ctx TransformCtx<'a>,
+        ctx: &'v mut TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            throw_if_closure_required,
+            transform_ctx,
+            ctx,
+            scope_stack: vec![],
+            functions: vec![],
+            statement_in_list: None,
+            pending_declaration: None,
+            loop_declarations: FxHashSet::default(),
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for LoopClosureWrapper<'a, '_, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_stack.push(scope_id.get().unwrap());
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_program(&mut self, program: &mut Program<'a>) {
+        let scope_id = program.scope_id();
+        self.functions.push(FunctionInfo { scope_id, is_async: false, is_generator: false });
+        walk_mut::walk_program(self, program);
+        self.functions.pop();
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.functions.push(FunctionInfo {
+            scope_id: func.scope_id(),
+            is_async: func.r#async,
+            is_generator: func.generator,
+        });
+        walk_mut::walk_function(self, func, flags);
+        self.functions.pop();
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.functions.push(FunctionInfo {
+            scope_id: arrow.scope_id(),
+            is_async: arrow.r#async,
+            is_generator: false,
+        });
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.functions.pop();
+    }
+
+    fn visit_static_block(&mut self, block: &mut StaticBlock<'a>) {
+        let scope_id = block.scope_id();
+        self.functions.push(FunctionInfo { scope_id, is_async: false, is_generator: false });
+        walk_mut::walk_static_block(self, block);
+        self.functions.pop();
+    }
+
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        let mut index = 0;
+        while index < stmts.len() {
+            self.statement_in_list = Some(stmts[index].address());
+            self.visit_statement(&mut stmts[index]);
+            if let Some(declaration) = self.pending_declaration.take() {
+                stmts.insert(index, declaration);
+                index += 1;
+            }
+            index += 1;
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        let is_in_list = self.statement_in_list.take() == Some(stmt.address());
+
+        let mut labels = vec![];
+        let mut loop_stmt = &*stmt;
+        while let Statement::LabeledStatement(labeled) = loop_stmt {
+            labels.push(Atom::from(labeled.label.name));
+            loop_stmt = &labeled.body;
+        }
+        if !loop_stmt.is_iteration_statement() {
+            walk_mut::walk_statement(self, stmt);
+            return;
+        }
+
+        let mut loop_stmt = &mut *stmt;
+        while let Statement::LabeledStatement(labeled) = loop_stmt {
+            loop_stmt = &mut labeled.body;
+        }
+        walk_mut::walk_statement(self, loop_stmt);
+        let Some(declaration) = self.wrap_loop(loop_stmt, &labels) else { return };
+
+        if is_in_list {
+            self.pending_declaration = Some(declaration);
+        } else {
+            // `if (x) for (let i of y) {}` -> `if (x) { var _loop = ...; for (let i of y) {} }`
+            let parent_scope_id = *self.scope_stack.last().unwrap();
+            let block_scope_id = self.ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+            let stmts = self.ctx.ast.vec_from_array([declaration, stmt.take_in(self.ctx.ast)]);
+            reparent_child_scopes(&stmts, block_scope_id, self.ctx);
+            *stmt = self.ctx.ast.statement_block_with_scope_id(SPAN, stmts, block_scope_id);
+        }
+    }
+}
+
+impl<'a> LoopClosureWrapper<'a, '_, '_> {
+    /// Wrap body of `loop_stmt` in a closure, if required.
+    ///
+    /// Returns declaration of the closure, which should be inserted before the loop.
+    fn wrap_loop(
+        &mut self,
+        loop_stmt: &mut Statement<'a>,
+        labels: &[Atom<'a>],
+    ) -> Option<Statement<'a>> {
+        let loop_span = loop_stmt.span();
+        let (head_scope_id, head_symbols, body) = match loop_stmt {
+            Statement::ForStatement(for_stmt) => {
+                let symbols = match &for_stmt.init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => block_scoped_symbols(decl),
+                    _ => vec![],
+                };
+                (Some(for_stmt.scope_id()), symbols, &mut for_stmt.body)
+            }
+            Statement::ForInStatement(for_in) => {
+                let symbols = block_scoped_symbols_in_left(&for_in.left);
+                (Some(for_in.scope_id()), symbols, &mut for_in.body)
+            }
+            Statement::ForOfStatement(for_of) => {
+                let symbols = block_scoped_symbols_in_left(&for_of.left);
+                (Some(for_of.scope_id()), symbols, &mut for_of.body)
+            }
+            Statement::WhileStatement(while_stmt) => (None, vec![], &mut while_stmt.body),
+            Statement::DoWhileStatement(do_while) => (None, vec![], &mut do_while.body),
+            _ => unreachable!(),
+        };
+
+        let mut analyzer = LoopBodyAnalyzer::new(&head_symbols, self.ctx);
+        analyzer.visit_statement(body);
+        if !analyzer.is_closure_required() {
+            return None;
+        }
+
+        let function = *self.functions.last().unwrap();
+        if self.throw_if_closure_required {
+            self.transform_ctx.error(
+                OxcDiagnostic::error(
+                    "Compiling let/const in this block would add a closure (throwIfClosureRequired).",
+                )
+                .with_label(loop_span),
+            );
+            return None;
+        }
+        if analyzer.uses_super_or_new_target
+            || (analyzer.uses_await && !function.is_async)
+            || (analyzer.uses_yield && !function.is_generator)
+        {
+            self.transform_ctx.error(
+                OxcDiagnostic::error(
+                    "Cannot wrap body of this loop in a closure, which is required to transform let/const declared in it.",
+                )
+                .with_label(loop_span),
+            );
+            return None;
+        }
+
+        let LoopBodyAnalyzer { head_references, uses_this, uses_arguments, .. } = analyzer;
+        let ctx = &mut *self.ctx;
+        let enclosing_scope_id = *self.scope_stack.last().unwrap();
+        let hoist_scope_id = function.scope_id;
+        let function_scope_id = ctx.create_child_scope(enclosing_scope_id, ScopeFlags::Function);
+
+        // Loop head bindings used in the body are passed to the closure.
+        // If the body updates a binding, it is passed back with `i = _i`.
+        let mut params = ctx.ast.vec();
+        let mut arguments = ctx.ast.vec();
+        let mut bindings = FxHashMap::default();
+        let mut updates = vec![];
+        for &symbol_id in &head_symbols {
+            let Some(&is_updated) = head_references.get(&symbol_id) else { continue };
+            let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+            let outer = BoundIdentifier::new(name, symbol_id);
+            let flags = SymbolFlags::FunctionScopedVariable;
+            let inner = if is_updated {
+                ctx.generate_uid(&name, function_scope_id, flags)
+            } else {
+                ctx.generate_binding(name, function_scope_id, flags)
+            };
+            params.push(ctx.ast.plain_formal_parameter(SPAN, inner.create_binding_pattern(ctx)));
+            arguments.push(Argument::from(outer.create_read_expression(ctx)));
+            if is_updated {
+                updates.push((outer, inner.clone()));
+            }
+            bindings.insert(symbol_id, inner);
+        }
+
+        let this_alias = uses_this
+            .then(|| ctx.generate_uid("this", hoist_scope_id, SymbolFlags::FunctionScopedVariable));
+        let arguments_alias = uses_arguments.then(|| {
+            ctx.generate_uid("arguments", hoist_scope_id, SymbolFlags::FunctionScopedVariable)
+        });
+
+        // Move body into the closure
+        let (mut stmts, body_scope_id) = match body.take_in(ctx.ast) {
+            Statement::BlockStatement(block) => {
+                let block = block.unbox();
+                let block_scope_id = block.scope_id();
+                move_all_bindings(block_scope_id, function_scope_id, ctx);
+                reparent_child_scopes(&block.body, function_scope_id, ctx);
+                (block.body, block_scope_id)
+            }
+            stmt => {
+                let stmts = ctx.ast.vec1(stmt);
+                reparent_child_scopes(&stmts, function_scope_id, ctx);
+                let parent_scope_id = head_scope_id.unwrap_or(enclosing_scope_id);
+                (stmts, ctx.create_child_scope(parent_scope_id, ScopeFlags::empty()))
+            }
+        };
+
+        let mut rewriter = LoopBodyRewriter {
+            ctx,
+            loop_labels: labels,
+            bindings,
+            updates: &updates,
+            this_alias: this_alias.as_ref(),
+            arguments_alias: arguments_alias.as_ref(),
+            hoist_scope_id,
+            function_scope_id,
+            loop_declarations: &self.loop_declarations,
+            function_depth: 0,
+            this_depth: 0,
+            labels: vec![],
+            loop_depth: 0,
+            switch_depth: 0,
+            completions: vec![],
+            has_return: false,
+            hoisted_vars: vec![],
+        };
+        rewriter.visit_statements(&mut stmts);
+        let LoopBodyRewriter { completions, has_return, hoisted_vars, .. } = rewriter;
+
+        if !updates.is_empty() {
+            let update = create_updates(&updates, None, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, update));
+        }
+
+        // `var _this = this, _arguments = arguments, _loop = function (i) { ... }, _ret;`
+        let loop_binding =
+            ctx.generate_uid("loop", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+        let function_body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+        let closure = ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            function.is_generator,
+            function.is_async,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(function_body),
+            function_scope_id,
+            false,
+            false,
+        );
+        let ret_binding = (!completions.is_empty() || has_return)
+            .then(|| ctx.generate_uid("ret", hoist_scope_id, SymbolFlags::FunctionScopedVariable));
+
+        let mut declarators = ctx.ast.vec();
+        if let Some(this_alias) = &this_alias {
+            let this = ctx.ast.expression_this(SPAN);
+            declarators.push(create_var_declarator(this_alias, Some(this), ctx));
+        }
+        if let Some(arguments_alias) = &arguments_alias {
+            let arguments =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("arguments"), ReferenceFlags::Read);
+            declarators.push(create_var_declarator(arguments_alias, Some(arguments), ctx));
+        }
+        declarators.push(create_var_declarator(&loop_binding, Some(closure), ctx));
+        if let Some(ret_binding) = &ret_binding {
+            declarators.push(create_var_declarator(ret_binding, None, ctx));
+        }
+        let declaration = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarators,
+            false,
+        ));
+        self.loop_declarations.insert(declaration.address());
+
+        // `_loop(i)`, `yield* _loop(i)` or `await _loop(i)`
+        let call = ctx.ast.expression_call(
+            SPAN,
+            loop_binding.create_read_expression(ctx),
+            NONE,
+            arguments,
+            false,
+        );
+        let call = if function.is_generator {
+            ctx.ast.expression_yield(SPAN, true, Some(call))
+        } else if function.is_async {
+            ctx.ast.expression_await(SPAN, call)
+        } else {
+            call
+        };
+
+        let mut new_body = ctx.ast.vec();
+        if !hoisted_vars.is_empty() {
+            // `var a, b;`
+            let declarators = ctx.ast.vec_from_iter(hoisted_vars.into_iter().map(|ident| {
+                let id = BindingPattern::BindingIdentifier(ctx.alloc(ident));
+                ctx.ast.variable_declarator(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    id,
+                    NONE,
+                    None,
+                    false,
+                )
+            }));
+            new_body.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarators,
+                false,
+            )));
+        }
+        if let Some(ret_binding) = ret_binding {
+            // `_ret = _loop(i);`
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                ret_binding.create_write_target(ctx),
+                call,
+            );
+            new_body.push(ctx.ast.statement_expression(SPAN, assignment));
+            // `if (_ret === 0) break;`
+            for (value, completion) in completions.into_iter().enumerate() {
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    ret_binding.create_read_expression(ctx),
+                    BinaryOperator::StrictEquality,
+                    create_number(value, ctx),
+                );
+                let label = completion.label.map(|label| ctx.ast.label_identifier(SPAN, label));
+                let consequent = if completion.is_break {
+                    ctx.ast.statement_break(SPAN, label)
+                } else {
+                    ctx.ast.statement_continue(SPAN, label)
+                };
+                new_body.push(ctx.ast.statement_if(SPAN, test, consequent, None));
+            }
+            // `if (_ret) return _ret.v;`
+            if has_return {
+                let value = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    ret_binding.create_read_expression(ctx),
+                    ctx.ast.identifier_name(SPAN, "v"),
+                    false,
+                ));
+                new_body.push(ctx.ast.statement_if(
+                    SPAN,
+                    ret_binding.create_read_expression(ctx),
+                    ctx.ast.statement_return(SPAN, Some(value)),
+                    None,
+                ));
+            }
+        } else {
+            new_body.push(ctx.ast.statement_expression(SPAN, call));
+        }
+        *body = ctx.ast.statement_block_with_scope_id(SPAN, new_body, body_scope_id);
+
+        Some(declaration)
+    }
+}
+
+/// `var binding = init`
+fn create_var_declarator<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Option<Expression<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> VariableDeclarator<'a> {
+    ctx.ast.variable_declarator(
+        SPAN,
+        VariableDeclarationKind::Var,
+        binding.create_binding_pattern(ctx),
+        NONE,
+        init,
+        false,
+    )
+}
+
+/// `i = _i, j = _j` or `i = _i, j = _j, value`
+fn create_updates<'a>(
+    updates: &[(BoundIdentifier<'a>, BoundIdentifier<'a>)],
+    value: Option<Expression<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let mut expressions = ctx.ast.vec_from_iter(updates.iter().map(|(outer, inner)| {
+        ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            outer.create_write_target(ctx),
+            inner.create_read_expression(ctx),
+        )
+    }));
+    expressions.extend(value);
+    if expressions.len() == 1 {
+        expressions.pop().unwrap()
+    } else {
+        ctx.ast.expression_sequence(SPAN, expressions)
+    }
+}
+
+/// Collects information about a loop body, to decide if it needs to be wrapped in a closure.
+struct LoopBodyAnalyzer<'a, 'v> {
+    ctx: &'v TraverseCtx<'a>,
+    head_symbols: &'v [SymbolId],
+    /// Depth of functions and classes
+    function_depth: u32,
+    /// Depth of functions and classes which have their own `this`
+    this_depth: u32,
+    /// `let` and `const` bindings declared in the body
+    body_symbols: Vec<SymbolId>,
+    /// Bindings referenced in closures
+    closure_symbols: FxHashSet<SymbolId>,
+    /// Loop head bindings referenced in the body, and whether they are updated
+    head_references: FxHashMap<SymbolId, bool>,
+    uses_this: bool,
+    uses_arguments: bool,
+    uses_super_or_new_target: bool,
+    uses_await: bool,
+    uses_yield: bool,
+}
+
+impl<'a, 'v> LoopBodyAnalyzer<'a, 'v> {
+    fn new(head_symbols: &'v [SymbolId], ctx: &'v TraverseCtx<'a>) -> Self {
+        Self {
+            ctx,
+            head_symbols,
+            function_depth: 0,
+            this_depth: 0,
+            body_symbols: vec![],
+            closure_symbols: FxHashSet::default(),
+            head_references: FxHashMap::default(),
+            uses_this: false,
+            uses_arguments: false,
+            uses_super_or_new_target: false,
+            uses_await: false,
+            uses_yield: false,
+        }
+    }
+
+    /// Returns `true` if any binding declared in the loop is captured by a closure.
+    fn is_closure_required(&self) -> bool {
+        self.head_symbols
+            .iter()
+            .chain(&self.body_symbols)
+            .any(|symbol_id| self.closure_symbols.contains(symbol_id))
+    }
+}
+
+impl<'a> Visit<'a> for LoopBodyAnalyzer<'a, '_> {
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk::walk_function(self, func, flags);
+        self.this_depth -= 1;
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk::walk_class(self, class);
+        self.this_depth -= 1;
+        self.function_depth -= 1;
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if self.function_depth == 0 {
+            self.body_symbols.extend(block_scoped_symbols(decl));
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.ctx.scoping().get_reference(ident.reference_id());
+        if let Some(symbol_id) = reference.symbol_id() {
+            if self.function_depth > 0 {
+                self.closure_symbols.insert(symbol_id);
+            }
+            if self.head_symbols.contains(&symbol_id) {
+                *self.head_references.entry(symbol_id).or_default() |= reference.is_write();
+            }
+        } else if self.this_depth == 0 && ident.name == "arguments" {
+            self.uses_arguments = true;
+        }
+    }
+
+    fn visit_this_expression(&mut self, _this: &ThisExpression) {
+        if self.this_depth == 0 {
+            self.uses_this = true;
+        }
+    }
+
+    fn visit_super(&mut self, _super: &Super) {
+        if self.this_depth == 0 {
+            self.uses_super_or_new_target = true;
+        }
+    }
+
+    fn visit_meta_property(&mut self, meta: &MetaProperty<'a>) {
+        if self.this_depth == 0 && meta.meta.name == "new" {
+            self.uses_super_or_new_target = true;
+        }
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        if self.function_depth == 0 {
+            self.uses_await = true;
+        }
+        walk::walk_await_expression(self, expr);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        if self.function_depth == 0 && stmt.r#await {
+            self.uses_await = true;
+        }
+        walk::walk_for_of_statement(self, stmt);
+    }
+
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        if self.function_depth == 0 {
+            self.uses_yield = true;
+        }
+        walk::walk_yield_expression(self, expr);
+    }
+}
+
+/// `break` or `continue` which exits the loop body.
+#[derive(PartialEq, Eq)]
+struct Completion<'a> {
+    is_break: bool,
+    /// `None` if it targets the loop itself
+    label: Option<Atom<'a>>,
+}
+
+/// Rewrites a loop body which is moved into a closure.
+///
+/// * Loop head bindings are replaced with parameters of the closure.
+/// * `this` and `arguments` are replaced with `_this` and `_arguments`.
+/// * `break`, `continue` and `return` are replaced with returning a completion value.
+/// * `var` declarations are hoisted out of the closure.
+struct LoopBodyRewriter<'a, 'v> {
+    ctx: &'v mut TraverseCtx<'a>,
+    loop_labels: &'v [Atom<'a>],
+    bindings: FxHashMap<SymbolId, BoundIdentifier<'a>>,
+    updates: &'v [(BoundIdentifier<'a>, BoundIdentifier<'a>)],
+    this_alias: Option<&'v BoundIdentifier<'a>>,
+    arguments_alias: Option<&'v BoundIdentifier<'a>>,
+    hoist_scope_id: ScopeId,
+    function_scope_id: ScopeId,
+    loop_declarations: &'v FxHashSet<Address>,
+    function_depth: u32,
+    this_depth: u32,
+    /// Labels declared in the body
+    labels: Vec<Atom<'a>>,
+    loop_depth: u32,
+    switch_depth: u32,
+    completions: Vec<Completion<'a>>,
+    has_return: bool,
+    /// Bindings of `var` declarations, to be declared outside the closure
+    hoisted_vars: Vec<BindingIdentifier<'a>>,
+}
+
+impl<'a> VisitMut<'a> for LoopBodyRewriter<'a, '_> {
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.this_depth -= 1;
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk_mut::walk_class(self, class);
+        self.this_depth -= 1;
+        self.function_depth -= 1;
+    }
+
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        stmts.retain_mut(|stmt| {
+            let is_var_declaration = matches!(stmt, Statement::VariableDeclaration(_));
+            self.visit_statement(stmt);
+            // Remove hoisted `var` declarations without initializers
+            !(is_var_declaration && matches!(stmt, Statement::EmptyStatement(_)))
+        });
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        match stmt {
+            Statement::BreakStatement(_) | Statement::ContinueStatement(_)
+                if self.function_depth == 0 =>
+            {
+                if let Some(new_stmt) = self.transform_jump(stmt) {
+                    *stmt = new_stmt;
+                }
+            }
+            Statement::ReturnStatement(ret) if self.function_depth == 0 => {
+                walk_mut::walk_return_statement(self, ret);
+                self.transform_return(ret);
+            }
+            Statement::VariableDeclaration(decl)
+                if self.function_depth == 0 && decl.kind.is_var() =>
+            {
+                walk_mut::walk_variable_declaration(self, decl);
+                if self.loop_declarations.contains(&decl.address()) {
+                    self.move_loop_declaration(decl);
+                } else {
+                    *stmt = match self.hoist_var_declaration(decl) {
+                        Some(expr) => self.ctx.ast.statement_expression(decl.span, expr),
+                        None => self.ctx.ast.statement_empty(decl.span),
+                    };
+                }
+            }
+            Statement::LabeledStatement(labeled) => {
+                self.labels.push(Atom::from(labeled.label.name));
+                walk_mut::walk_labeled_statement(self, labeled);
+                self.labels.pop();
+            }
+            Statement::SwitchStatement(switch) => {
+                self.switch_depth += 1;
+                walk_mut::walk_switch_statement(self, switch);
+                self.switch_depth -= 1;
+            }
+            stmt if stmt.is_iteration_statement() => {
+                self.loop_depth += 1;
+                walk_mut::walk_statement(self, stmt);
+                self.loop_depth -= 1;
+            }
+            _ => walk_mut::walk_statement(self, stmt),
+        }
+    }
+
+    fn visit_for_statement_init(&mut self, init: &mut ForStatementInit<'a>) {
+        walk_mut::walk_for_statement_init(self, init);
+        if self.function_depth == 0
+            && let ForStatementInit::VariableDeclaration(decl) = init
+            && decl.kind.is_var()
+        {
+            // `for (var i = 0; ;)` -> `for (i = 0; ;)`
+            let span = decl.span;
+            *init = match self.hoist_var_declaration(decl) {
+                Some(expr) => ForStatementInit::from(expr),
+                // `for (var i; ;)` -> `for (void 0; ;)`
+                None => ForStatementInit::from(self.ctx.ast.void_0(span)),
+            };
+        }
+    }
+
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        walk_mut::walk_for_statement_left(self, left);
+        if self.function_depth == 0
+            && let ForStatementLeft::VariableDeclaration(decl) = left
+            && decl.kind.is_var()
+        {
+            // `for (var k in obj)` -> `for (k in obj)`
+            let declarator = decl.declarations.pop().unwrap();
+            declarator.id.bound_names(&mut |ident| self.hoist_binding(ident));
+            *left = ForStatementLeft::from(binding_pattern_to_assignment_target(
+                declarator.id,
+                self.ctx,
+            ));
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::ThisExpression(this) = expr
+            && self.this_depth == 0
+            && let Some(this_alias) = self.this_alias
+        {
+            *expr = this_alias.create_spanned_read_expression(this.span, self.ctx);
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let scoping = self.ctx.scoping_mut();
+        match scoping.get_reference(reference_id).symbol_id() {
+            Some(symbol_id) => {
+                if let Some(inner) = self.bindings.get(&symbol_id) {
+                    ident.name = inner.name.into();
+                    scoping.get_reference_mut(reference_id).set_symbol_id(inner.symbol_id);
+                    scoping.delete_resolved_reference(symbol_id, reference_id);
+                    scoping.add_resolved_reference(inner.symbol_id, reference_id);
+                }
+            }
+            None => {
+                if self.this_depth == 0
+                    && ident.name == "arguments"
+                    && let Some(arguments_alias) = self.arguments_alias
+                {
+                    ident.name = arguments_alias.name.into();
+                    scoping.delete_root_unresolved_reference("arguments", reference_id);
+                    scoping
+                        .get_reference_mut(reference_id)
+                        .set_symbol_id(arguments_alias.symbol_id);
+                    scoping.add_resolved_reference(arguments_alias.symbol_id, reference_id);
+                }
+            }
+        }
+    }
+}
+
+impl<'a> LoopBodyRewriter<'a, '_> {
+    /// `break;` -> `return 0;`
+    /// `continue outer;` -> `return 1;`
+    ///
+    /// Returns `None` if the statement doesn't exit the loop body.
+    fn transform_jump(&mut self, stmt: &Statement<'a>) -> Option<Statement<'a>> {
+        let (is_break, label, span) = match stmt {
+            Statement::BreakStatement(stmt) => (true, stmt.label.as_ref(), stmt.span),
+            Statement::ContinueStatement(stmt) => (false, stmt.label.as_ref(), stmt.span),
+            _ => unreachable!(),
+        };
+        let label = if let Some(label) = label {
+            let label = Atom::from(label.name);
+            if self.labels.contains(&label) {
+                return None;
+            }
+            (!self.loop_labels.contains(&label)).then_some(label)
+        } else {
+            if self.loop_depth > 0 || (is_break && self.switch_depth > 0) {
+                return None;
+            }
+            None
+        };
+
+        let completion = Completion { is_break, label };
+        let value = self.completions.iter().position(|c| *c == completion).unwrap_or_else(|| {
+            self.completions.push(completion);
+            self.completions.len() - 1
+        });
+        let mut value = create_number(value, self.ctx);
+        if !self.updates.is_empty() {
+            value = create_updates(self.updates, Some(value), self.ctx);
+        }
+        Some(self.ctx.ast.statement_return(span, Some(value)))
+    }
+
+    /// `return x;` -> `return { v: x };`
+    fn transform_return(&mut self, ret: &mut ReturnStatement<'a>) {
+        self.has_return = true;
+        let value = ret.argument.take().unwrap_or_else(|| self.ctx.ast.void_0(SPAN));
+        let property = self.ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            self.ctx.ast.property_key_static_identifier(SPAN, "v"),
+            value,
+            false,
+            false,
+            false,
+        );
+        ret.argument = Some(self.ctx.ast.expression_object(SPAN, self.ctx.ast.vec1(property)));
+    }
+
+    /// Convert a `var` declaration to assignments, and record the bindings to be declared outside the closure.
+    ///
+    /// `var a = 1, b;` -> `a = 1`
+    fn hoist_var_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut expressions = self.ctx.ast.vec();
+        for declarator in decl.declarations.take_in(self.ctx.ast) {
+            declarator.id.bound_names(&mut |ident| self.hoist_binding(ident));
+            if let Some(init) = declarator.init {
+                let target = binding_pattern_to_assignment_target(declarator.id, self.ctx);
+                expressions.push(self.ctx.ast.expression_assignment(
+                    declarator.span,
+                    AssignmentOperator::Assign,
+                    target,
+                    init,
+                ));
+            }
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, expressions)),
+        }
+    }
+
+    /// Record a binding to be declared outside the closure.
+    fn hoist_binding(&mut self, ident: &BindingIdentifier<'a>) {
+        let symbol_id = ident.symbol_id();
+        if !self.hoisted_vars.iter().any(|hoisted| hoisted.symbol_id() == symbol_id) {
+            self.hoisted_vars.push(
+                self.ctx.ast.binding_identifier_with_symbol_id(ident.span, ident.name, symbol_id),
+            );
+        }
+    }
+
+    /// Move bindings of a `_loop` declaration of an inner loop into the closure.
+    fn move_loop_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        let scoping = self.ctx.scoping_mut();
+        decl.bound_names(&mut |ident| {
+            scoping.move_binding(self.hoist_scope_id, self.function_scope_id, &ident.name);
+            scoping.set_symbol_scope_id(ident.symbol_id(), self.function_scope_id);
+        });
+    }
+}
+
+/// A `let` or `const` declaration, for TDZ checks.
+struct TdzDeclaration<'a> {
+    name: Atom<'a>,
+    /// End of the declarator. References before it are in the TDZ.
+    end: u32,
+    /// Scope of the function containing the declaration
+    hoist_scope_id: ScopeId,
+}
+
+/// Collects `let` and `const` declarations for TDZ checks.
+#[derive(Default)]
+struct TdzDeclarationCollector<'a> {
+    hoist_scopes: Vec<ScopeId>,
+    declarations: FxHashMap<SymbolId, TdzDeclaration<'a>>,
+}
+
+impl<'a> Visit<'a> for TdzDeclarationCollector<'a> {
+    fn visit_program(&mut self, program: &Program<'a>) {
+        self.hoist_scopes.push(program.scope_id());
+        walk::walk_program(self, program);
+        self.hoist_scopes.pop();
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.hoist_scopes.push(func.scope_id());
+        walk::walk_function(self, func, flags);
+        self.hoist_scopes.pop();
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.hoist_scopes.push(arrow.scope_id());
+        walk::walk_arrow_function_expression(self, arrow);
+        self.hoist_scopes.pop();
+    }
+
+    fn visit_static_block(&mut self, block: &StaticBlock<'a>) {
+        self.hoist_scopes.push(block.scope_id());
+        walk::walk_static_block(self, block);
+        self.hoist_scopes.pop();
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if is_block_scoped_declaration(decl) {
+            let hoist_scope_id = *self.hoist_scopes.last().unwrap();
+            for declarator in &decl.declarations {
+                let end = declarator.span.end;
+                declarator.id.bound_names(&mut |ident| {
+                    let declaration =
+                        TdzDeclaration { name: Atom::from(ident.name), end, hoist_scope_id };
+                    self.declarations.insert(ident.symbol_id(), declaration);
+                });
+            }
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+}
+
+/// Converts `let` and `const` declarations to `var`.
+struct BlockScopedConverter<'a, 'ctx, 'v> {
+    transform_ctx: &'ctx TransformCtx<'a>,
+    ctx: &'v mut TraverseCtx<'a>,
+    tdz_declarations: FxHashMap<SymbolId, TdzDeclaration<'a>>,
+    hoist_scopes: Vec<ScopeId>,
+    loop_depth: u32,
+    /// `true` if the next declaration visited is in head of a loop. e.g. `for (let i = 0; ;)`
+    is_loop_head: bool,
+    renamed_symbols: FxHashMap<SymbolId, Atom<'a>>,
+}
+
+impl<'a, 'ctx, 'v> BlockScopedConverter<'a, 'ctx, 'v> {
+    fn new(
+        tdz_declarations: FxHashMap<SymbolId, TdzDeclaration<'a>>,
+        transform_ctx: &'ctx TransformCtx<'a>,
+        ctx: &'v mut TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            transform_ctx,
+            ctx,
+            tdz_declarations,
+            hoist_scopes: vec![],
+            loop_depth: 0,
+            is_loop_head: false,
+            renamed_symbols: FxHashMap::default(),
+        }
+    }
+
+    fn enter_hoist_scope(&mut self, scope_id: ScopeId) -> u32 {
+        self.hoist_scopes.push(scope_id);
+        mem::replace(&mut self.loop_depth, 0)
+    }
+
+    fn leave_hoist_scope(&mut self, loop_depth: u32) {
+        self.hoist_scopes.pop();
+        self.loop_depth = loop_depth;
+    }
+}
+
+impl<'a> VisitMut<'a> for BlockScopedConverter<'a, '_, '_> {
+    fn visit_program(&mut self, program: &mut Program<'a>) {
+        let loop_depth = self.enter_hoist_scope(program.scope_id());
+        walk_mut::walk_program(self, program);
+        self.leave_hoist_scope(loop_depth);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        let loop_depth = self.enter_hoist_scope(func.scope_id());
+        walk_mut::walk_function(self, func, flags);
+        self.leave_hoist_scope(loop_depth);
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        let loop_depth = self.enter_hoist_scope(arrow.scope_id());
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.leave_hoist_scope(loop_depth);
+    }
+
+    fn visit_static_block(&mut self, block: &mut StaticBlock<'a>) {
+        let loop_depth = self.enter_hoist_scope(block.scope_id());
+        walk_mut::walk_static_block(self, block);
+        self.leave_hoist_scope(loop_depth);
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if stmt.is_iteration_statement() {
+            self.loop_depth += 1;
+            walk_mut::walk_statement(self, stmt);
+            self.loop_depth -= 1;
+        } else {
+            walk_mut::walk_statement(self, stmt);
+        }
+    }
+
+    fn visit_for_statement_init(&mut self, init: &mut ForStatementInit<'a>) {
+        self.is_loop_head = matches!(init, ForStatementInit::VariableDeclaration(_));
+        walk_mut::walk_for_statement_init(self, init);
+    }
+
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        self.is_loop_head = matches!(left, ForStatementLeft::VariableDeclaration(_));
+        walk_mut::walk_for_statement_left(self, left);
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        let is_loop_head = mem::take(&mut self.is_loop_head);
+        if is_block_scoped_declaration(decl) {
+            self.convert_declaration(decl, is_loop_head);
+        }
+        walk_mut::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if !self.tdz_declarations.is_empty() {
+            match expr {
+                // `a` -> `_tdz("a")`
+                Expression::Identifier(ident) => {
+                    if let Some(name) = self.get_tdz_name(ident) {
+                        let span = ident.span;
+                        self.ctx.delete_reference_for_identifier(ident);
+                        *expr = self.create_tdz_error(name, span);
+                        return;
+                    }
+                }
+                // `a = 1` -> `(_tdz("a"), a = 1)`
+                Expression::AssignmentExpression(assign) => {
+                    if let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left
+                        && let Some(name) = self.get_tdz_name(ident)
+                    {
+                        walk_mut::walk_expression(self, expr);
+                        self.wrap_with_tdz_error(expr, name);
+                        return;
+                    }
+                }
+                // `a++` -> `(_tdz("a"), a++)`
+                Expression::UpdateExpression(update) => {
+                    if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) =
+                        &update.argument
+                        && let Some(name) = self.get_tdz_name(ident)
+                    {
+                        walk_mut::walk_expression(self, expr);
+                        self.wrap_with_tdz_error(expr, name);
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+}
+
+impl<'a> BlockScopedConverter<'a, '_, '_> {
+    /// `let a = 1;` -> `var a = 1;`
+    ///
+    /// Bindings are moved to the enclosing function scope, and renamed if necessary.
+    fn convert_declaration(&mut self, decl: &mut VariableDeclaration<'a>, is_loop_head: bool) {
+        // `let a;` in a loop body has to be reset in each iteration.
+        // `for (;;) { let a; }` -> `for (;;) { var a = void 0; }`
+        let is_in_loop_body = self.loop_depth > 0 && !is_loop_head;
+        decl.kind = VariableDeclarationKind::Var;
+        let mut symbol_ids = vec![];
+        for declarator in &mut decl.declarations {
+            if is_in_loop_body && declarator.init.is_none() {
+                declarator.init = Some(self.ctx.ast.void_0(SPAN));
+            }
+            declarator.kind = VariableDeclarationKind::Var;
+            declarator.id.bound_names(&mut |ident| symbol_ids.push(ident.symbol_id()));
+        }
+
+        let hoist_scope_id = *self.hoist_scopes.last().unwrap();
+        for symbol_id in symbol_ids {
+            let scope_id = self.ctx.scoping().symbol_scope_id(symbol_id);
+            let flags = self.ctx.scoping_mut().symbol_flags_mut(symbol_id);
+            flags.remove(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable);
+            flags.insert(SymbolFlags::FunctionScopedVariable);
+            if scope_id == hoist_scope_id {
+                continue;
+            }
+
+            let mut name = self.ctx.ast.atom(self.ctx.scoping().symbol_name(symbol_id));
+            if self.is_name_taken(&name, scope_id) {
+                name = self.ctx.generate_uid_name(&name);
+                self.ctx.scoping_mut().rename_symbol(symbol_id, scope_id, &name);
+                self.renamed_symbols.insert(symbol_id, name);
+            }
+            let scoping = self.ctx.scoping_mut();
+            scoping.move_binding(scope_id, hoist_scope_id, &name);
+            scoping.set_symbol_scope_id(symbol_id, hoist_scope_id);
+        }
+    }
+
+    /// Returns `true` if moving a binding named `name` out of scope `scope_id` may clash with another
+    /// binding, or shadow a reference to a global.
+    fn is_name_taken(&self, name: &str, scope_id: ScopeId) -> bool {
+        let scoping = self.ctx.scoping();
+        scoping
+            .scope_ancestors(scope_id)
+            .skip(1)
+            .any(|scope_id| scoping.get_binding(scope_id, name).is_some())
+            || scoping.root_unresolved_references().contains_key(name)
+    }
+
+    /// Get name of the binding `ident` refers to, if `ident` is definitely in its temporal dead zone.
+    fn get_tdz_name(&self, ident: &IdentifierReference<'a>) -> Option<Atom<'a>> {
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        let declaration = self.tdz_declarations.get(&symbol_id)?;
+        // References created by other transforms don't have a span
+        (!ident.span.is_unspanned()
+            && ident.span.start < declaration.end
+            && *self.hoist_scopes.last().unwrap() == declaration.hoist_scope_id)
+            .then_some(declaration.name)
+    }
+
+    /// `_tdz("a")`
+    fn create_tdz_error(&mut self, name: Atom<'a>, span: Span) -> Expression<'a> {
+        let arguments = self
+            .ctx
+            .ast
+            .vec1(Argument::from(self.ctx.ast.expression_string_literal(SPAN, name, None)));
+        self.transform_ctx.helper_call_expr(Helper::Tdz, span, arguments, self.ctx)
+    }
+
+    /// `expr` -> `(_tdz("a"), expr)`
+    fn wrap_with_tdz_error(&mut self, expr: &mut Expression<'a>, name: Atom<'a>) {
+        let span = expr.span();
+        let error = self.create_tdz_error(name, SPAN);
+        let expressions = self.ctx.ast.vec_from_array([error, expr.take_in(self.ctx.ast)]);
+        *expr = self.ctx.ast.expression_sequence(span, expressions);
+    }
+}
@@ -0,0 +1,1316 @@
+//! ES2015: Classes
+//!
+//! This plugin transforms classes to constructor functions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class A extends B {
+//!   constructor(x) {
+//!     super(x);
+//!     this.y = 1;
+//!   }
+//!   foo() {
+//!     return super.foo();
+//!   }
+//!   get bar() {}
+//!   static baz() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! let A = /*#__PURE__*/function (_B) {
+//!   function A(x) {
+//!     var _this;
+//!     babelHelpers.classCallCheck(this, A);
+//!     _this = babelHelpers.callSuper(this, A, [x]);
+//!     _this.y = 1;
+//!     return _this;
+//!   }
+//!   babelHelpers.inherits(A, _B);
+//!   return babelHelpers.createClass(A, [{
+//!     key: "foo",
+//!     value: function foo() {
+//!       return babelHelpers.superPropGet(A, "foo", this, 3)([]);
+//!     }
+//!   }, {
+//!     key: "bar",
+//!     get: function () {}
+//!   }], [{
+//!     key: "baz",
+//!     value: function baz() {}
+//!   }]);
+//! }(B);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-classes](https://babel.dev/docs/babel-plugin-transform-classes).
+//!
+//! Classes are transformed on exit, after all other plugins have finished with them.
+//! In particular, class properties, static blocks and private methods must already have been
+//! transformed by the ES2022 plugins. Classes which still contain any of these elements, or which
+//! have decorators, are left as is.
+//!
+//! `class-properties` plugin may wrap a class expression in a sequence expression
+//! (`(_Class = class {}, _Class.x = 1, _Class)`), so on exit of an expression we also look for
+//! class expressions in these positions.
+//!
+//! Arguments of `super(...)` and `super.method(...)` calls are passed to helpers as arrays.
+//! If the spread transform is enabled, spread elements in these arrays are transformed too,
+//! as the spread transform has already visited the calls.
+//!
+//! Babel's `loose` mode and assumptions (`constantSuper`, `noClassCalls`, `setClassMethods`,
+//! `superIsCallableConstructor`) are not supported.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-classes>
+//! * Class definitions specification: <https://tc39.es/ecma262/#sec-class-definitions>
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{Box as ArenaBox, GetAddress, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::{identifier::is_identifier_name, keyword::is_reserved_keyword};
+use oxc_traverse::{BoundIdentifier, Traverse, ast_operations::get_var_name_from_node};
+
+use crate::{
+    Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::{create_assignment, create_property_access},
+};
+
+use super::spread::Spread;
+
+pub struct Classes<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Whether the spread transform is enabled
+    spread: bool,
+}
+
+impl<'a, 'ctx> Classes<'a, 'ctx> {
+    pub fn new(spread: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, spread }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Classes<'a, '_> {
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            // `class A {}` -> `let A = ...;`
+            Statement::ClassDeclaration(class) => {
+                if !Self::can_transform(class) {
+                    return;
+                }
+                let old_address = stmt.address();
+                let Statement::ClassDeclaration(class) = stmt.take_in(ctx.ast) else {
+                    unreachable!()
+                };
+                let declaration = self.transform_class_declaration(class, ctx);
+                *stmt = Statement::VariableDeclaration(declaration);
+                // Other plugins may have attached insertions to the class declaration
+                self.ctx.statement_injector.move_insertions(&old_address, stmt);
+            }
+            // `export class A {}` -> `export let A = ...;`
+            Statement::ExportNamedDeclaration(decl) => {
+                let Some(Declaration::ClassDeclaration(class)) = &decl.declaration else {
+                    return;
+                };
+                if !Self::can_transform(class) {
+                    return;
+                }
+                let Some(Declaration::ClassDeclaration(class)) = decl.declaration.take() else {
+                    unreachable!()
+                };
+                let declaration = self.transform_class_declaration(class, ctx);
+                decl.declaration = Some(Declaration::VariableDeclaration(declaration));
+            }
+            Statement::ExportDefaultDeclaration(decl) => {
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) = &decl.declaration
+                else {
+                    return;
+                };
+                if !Self::can_transform(class) {
+                    return;
+                }
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) =
+                    decl.declaration.take_in(ctx.ast)
+                else {
+                    unreachable!()
+                };
+
+                if class.id.is_some() {
+                    // `export default class A {}` -> `let A = ...; export { A as default };`
+                    let old_address = stmt.address();
+                    let declaration = self.transform_class_declaration(class, ctx);
+                    let binding = {
+                        let BindingPattern::BindingIdentifier(ident) =
+                            &declaration.declarations[0].id
+                        else {
+                            unreachable!()
+                        };
+                        BoundIdentifier::from_binding_ident(ident)
+                    };
+                    *stmt = Statement::VariableDeclaration(declaration);
+                    self.ctx.statement_injector.move_insertions(&old_address, stmt);
+
+                    let local =
+                        ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+                    let exported = ctx.ast.module_export_name_identifier_name(SPAN, "default");
+                    let specifier =
+                        ctx.ast.export_specifier(SPAN, local, exported, ImportOrExportKind::Value);
+                    let export = ctx.ast.module_declaration_export_named_declaration(
+                        SPAN,
+                        None,
+                        ctx.ast.vec1(specifier),
+                        None,
+                        ImportOrExportKind::Value,
+                        NONE,
+                    );
+                    self.ctx.statement_injector.insert_after(stmt, Statement::from(export));
+                } else {
+                    // `export default class {}` -> `export default ...;`
+                    let expr = self.transform_class(class, "default", ctx);
+                    decl.declaration = ExportDefaultDeclarationKind::from(expr);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(expr) = Self::find_class_expression(expr) else {
+            return;
+        };
+        let Expression::ClassExpression(class) = expr else { unreachable!() };
+        if !Self::can_transform(class) {
+            return;
+        }
+        let Expression::ClassExpression(class) = expr.take_in(ctx.ast) else { unreachable!() };
+        *expr = self.transform_class(class, "class", ctx);
+    }
+}
+
+impl<'a> Classes<'a, '_> {
+    /// Find class expression which is yet to be transformed.
+    ///
+    /// Usually this is `expr` itself, but `class-properties` plugin may have already wrapped
+    /// the class in an assignment or a sequence expression:
+    ///
+    /// * `_Class = class {}`
+    /// * `(_Class = class {}, _Class.x = 1, _Class)`
+    fn find_class_expression<'e>(expr: &'e mut Expression<'a>) -> Option<&'e mut Expression<'a>> {
+        if matches!(expr, Expression::ClassExpression(_)) {
+            return Some(expr);
+        }
+        match expr {
+            Expression::AssignmentExpression(_) => Self::find_class_expression_in_assignment(expr),
+            Expression::SequenceExpression(seq) => seq.expressions.iter_mut().find_map(|expr| {
+                if matches!(expr, Expression::ClassExpression(_)) {
+                    Some(expr)
+                } else {
+                    Self::find_class_expression_in_assignment(expr)
+                }
+            }),
+            _ => None,
+        }
+    }
+
+    /// `_Class = class {}` -> `class {}`
+    fn find_class_expression_in_assignment<'e>(
+        expr: &'e mut Expression<'a>,
+    ) -> Option<&'e mut Expression<'a>> {
+        match expr {
+            Expression::AssignmentExpression(assign)
+                if matches!(assign.right, Expression::ClassExpression(_)) =>
+            {
+                Some(&mut assign.right)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if class only contains elements which can be transformed by this plugin.
+    fn can_transform(class: &Class<'a>) -> bool {
+        !class.declare
+            && class.decorators.is_empty()
+            && class.body.body.iter().all(|element| match element {
+                ClassElement::MethodDefinition(method) => {
+                    method.decorators.is_empty()
+                        && method.r#type == MethodDefinitionType::MethodDefinition
+                        && method.value.body.is_some()
+                        && !method.key.is_private_identifier()
+                }
+                _ => false,
+            })
+    }
+
+    /// `class A {}` -> `let A = ...;`
+    fn transform_class_declaration(
+        &self,
+        mut class: ArenaBox<'a, Class<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, VariableDeclaration<'a>> {
+        let id = class.id.as_ref().unwrap();
+        let binding = BoundIdentifier::from_binding_ident(id);
+        let id_span = id.span;
+        let span = class.span;
+        class.span = SPAN;
+
+        // Class binding becomes a `let` binding
+        *ctx.scoping_mut().symbol_flags_mut(binding.symbol_id) = SymbolFlags::BlockScopedVariable;
+
+        let init = self.transform_class(class, "class", ctx);
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Let,
+            ctx.ast.binding_pattern_binding_identifier_with_symbol_id(
+                id_span,
+                binding.name,
+                binding.symbol_id,
+            ),
+            NONE,
+            Some(init),
+            false,
+        );
+        ctx.ast.alloc_variable_declaration(
+            span,
+            VariableDeclarationKind::Let,
+            ctx.ast.vec1(declarator),
+            false,
+        )
+    }
+
+    /// Transform class to an expression.
+    ///
+    /// * Class without `extends` clause and methods:
+    ///   `babelHelpers.createClass(function A() { ... })`
+    /// * Otherwise:
+    ///   `function (_B) { function A() { ... } ...; return babelHelpers.createClass(A, ...); }(B)`
+    ///
+    /// `name_hint` is used to name the constructor function if class is anonymous.
+    fn transform_class(
+        &self,
+        class: ArenaBox<'a, Class<'a>>,
+        name_hint: &str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let class_scope_id = class.scope_id();
+        let parent_scope_id = ctx.scoping().scope_parent_id(class_scope_id).unwrap();
+        let is_strict = ctx.scoping().scope_flags(parent_scope_id).is_strict_mode();
+        let is_declaration = class.is_declaration();
+
+        let Class { span, id, super_class, body, .. } = class.unbox();
+
+        let mut constructor = None;
+        let mut methods = vec![];
+        for element in body.unbox().body {
+            let ClassElement::MethodDefinition(method) = element else { unreachable!() };
+            if method.kind.is_constructor() {
+                constructor = Some(method.unbox().value);
+            } else {
+                methods.push(method);
+            }
+        }
+
+        let is_derived = super_class.is_some();
+        let use_iife = is_derived || !methods.is_empty();
+        if use_iife {
+            // IIFE reuses class's scope
+            *ctx.scoping_mut().scope_flags_mut(class_scope_id) =
+                ScopeFlags::Function.with_strict_mode(is_strict);
+        }
+
+        // Constructor function is a child of the IIFE's scope (which reuses class's scope),
+        // or a child of class's parent scope if there is no IIFE.
+        let has_constructor = constructor.is_some();
+        let mut constructor = if let Some(constructor) = constructor {
+            let scope_id = constructor.scope_id();
+            ctx.scoping_mut().scope_flags_mut(scope_id).remove(ScopeFlags::Constructor);
+            if !use_iife {
+                ctx.scoping_mut().change_scope_parent_id(scope_id, Some(parent_scope_id));
+            }
+            constructor
+        } else {
+            let parent_id = if use_iife { class_scope_id } else { parent_scope_id };
+            let scope_id =
+                ctx.create_child_scope(parent_id, ScopeFlags::Function.with_strict_mode(is_strict));
+            Self::create_empty_function(scope_id, ctx)
+        };
+        let constructor_scope_id = constructor.scope_id();
+
+        // Create binding for constructor function.
+        // Function name is a binding in the IIFE's scope, or in the function's own scope
+        // if constructor is a function expression.
+        let name_scope_id = if use_iife { class_scope_id } else { constructor_scope_id };
+        let mut retarget = None;
+        let mut class_id_span = SPAN;
+        let class_binding = match &id {
+            Some(id) if is_declaration => {
+                let binding =
+                    ctx.generate_binding(id.name.into(), name_scope_id, SymbolFlags::Function);
+                retarget = Some((id.symbol_id(), binding.symbol_id));
+                binding
+            }
+            Some(id) => {
+                class_id_span = id.span;
+                let symbol_id = id.symbol_id();
+                let scoping = ctx.scoping_mut();
+                if !use_iife {
+                    scoping.move_binding(class_scope_id, name_scope_id, &id.name);
+                    scoping.set_symbol_scope_id(symbol_id, name_scope_id);
+                }
+                *scoping.symbol_flags_mut(symbol_id) = SymbolFlags::Function;
+                BoundIdentifier::from_binding_ident(id)
+            }
+            None => ctx.generate_uid(name_hint, name_scope_id, SymbolFlags::Function),
+        };
+
+        // Transform constructor and methods
+        let mut visitor = ClassMethodVisitor::new(
+            &class_binding,
+            retarget,
+            is_strict,
+            self.spread,
+            self.ctx,
+            ctx,
+        );
+        self.transform_constructor(&mut constructor, has_constructor, is_derived, &mut visitor);
+
+        let mut instance_descriptors = vec![];
+        let mut static_descriptors = vec![];
+        for method in methods {
+            let descriptors =
+                if method.r#static { &mut static_descriptors } else { &mut instance_descriptors };
+            Self::transform_method(method, descriptors, &mut visitor);
+        }
+        drop(visitor);
+
+        constructor.id = Some(ctx.ast.binding_identifier_with_symbol_id(
+            class_id_span,
+            class_binding.name,
+            class_binding.symbol_id,
+        ));
+
+        if !use_iife {
+            // `babelHelpers.createClass(function A() { ... })`
+            let constructor = Expression::FunctionExpression(constructor);
+            let arguments = ctx.ast.vec1(Argument::from(constructor));
+            return self.create_class_call(span, arguments, ctx);
+        }
+
+        // `function A() { ... }`
+        constructor.r#type = FunctionType::FunctionDeclaration;
+        let mut statements = ctx.ast.vec_with_capacity(3);
+        statements.push(Statement::FunctionDeclaration(constructor));
+
+        let mut params = ctx.ast.vec();
+        let mut iife_arguments = ctx.ast.vec();
+        if let Some(super_class) = super_class {
+            // `extends` clause is evaluated outside of the IIFE.
+            // Any scopes in it become children of class's parent scope.
+            ctx.remove_scope_for_expression(class_scope_id, &super_class);
+
+            let super_binding = ctx.generate_uid_based_on_node(
+                &super_class,
+                class_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            params.push(
+                ctx.ast.plain_formal_parameter(SPAN, super_binding.create_binding_pattern(ctx)),
+            );
+            iife_arguments.push(Argument::from(super_class));
+
+            // `babelHelpers.inherits(A, _B);`
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(class_binding.create_read_expression(ctx)),
+                Argument::from(super_binding.create_read_expression(ctx)),
+            ]);
+            let inherits = self.ctx.helper_call_expr(Helper::Inherits, SPAN, arguments, ctx);
+            statements.push(ctx.ast.statement_expression(SPAN, inherits));
+        }
+
+        // `return babelHelpers.createClass(A, [...], [...]);`
+        let mut arguments = ctx.ast.vec_with_capacity(3);
+        arguments.push(Argument::from(class_binding.create_read_expression(ctx)));
+        if !instance_descriptors.is_empty() || !static_descriptors.is_empty() {
+            let instance_descriptors = if instance_descriptors.is_empty() {
+                ctx.ast.expression_null_literal(SPAN)
+            } else {
+                Self::create_descriptors_array(instance_descriptors, ctx)
+            };
+            arguments.push(Argument::from(instance_descriptors));
+            if !static_descriptors.is_empty() {
+                let static_descriptors = Self::create_descriptors_array(static_descriptors, ctx);
+                arguments.push(Argument::from(static_descriptors));
+            }
+        }
+        let create_class = self.ctx.helper_call_expr(Helper::CreateClass, SPAN, arguments, ctx);
+        statements.push(ctx.ast.statement_return(SPAN, Some(create_class)));
+
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
+        let iife = ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            class_scope_id,
+            false,
+            false,
+        );
+        ctx.ast.expression_call_with_pure(span, iife, NONE, iife_arguments, false, true)
+    }
+
+    /// Transform constructor body.
+    ///
+    /// Base class:
+    /// ```js
+    /// function A() {
+    ///   babelHelpers.classCallCheck(this, A);
+    ///   // ...
+    /// }
+    /// ```
+    ///
+    /// Derived class:
+    /// ```js
+    /// function A() {
+    ///   var _this;
+    ///   babelHelpers.classCallCheck(this, A);
+    ///   _this = babelHelpers.callSuper(this, A, [...]);
+    ///   // ...
+    ///   return _this;
+    /// }
+    /// ```
+    ///
+    /// Derived class without constructor:
+    /// ```js
+    /// function A() {
+    ///   babelHelpers.classCallCheck(this, A);
+    ///   return babelHelpers.callSuper(this, A, arguments);
+    /// }
+    /// ```
+    fn transform_constructor(
+        &self,
+        constructor: &mut Function<'a>,
+        has_constructor: bool,
+        is_derived: bool,
+        visitor: &mut ClassMethodVisitor<'a, '_, '_>,
+    ) {
+        let scope_id = constructor.scope_id();
+        let mut this_binding = None;
+
+        if has_constructor {
+            if is_derived {
+                let binding =
+                    visitor.ctx.generate_uid("this", scope_id, SymbolFlags::FunctionScopedVariable);
+                this_binding = Some(binding);
+            }
+
+            // `super()` as a top-level statement guarantees `_this` is initialized
+            let body = constructor.body.as_ref().unwrap();
+            let is_super_called = body.statements.iter().any(|stmt| {
+                matches!(stmt, Statement::ExpressionStatement(stmt) if stmt.expression.is_super_call_expression())
+            });
+
+            visitor.visit_constructor(constructor, this_binding.clone());
+
+            if let Some(this_binding) = &this_binding {
+                let ctx = &mut *visitor.ctx;
+                let body = constructor.body.as_mut().unwrap();
+                if !matches!(body.statements.last(), Some(Statement::ReturnStatement(_))) {
+                    // `return _this;` or `return babelHelpers.assertThisInitialized(_this);`
+                    let argument = if is_super_called {
+                        this_binding.create_read_expression(ctx)
+                    } else {
+                        create_assert_this_initialized(this_binding, self.ctx, ctx)
+                    };
+                    body.statements.push(ctx.ast.statement_return(SPAN, Some(argument)));
+                }
+            }
+        } else if is_derived {
+            // `return babelHelpers.callSuper(this, A, arguments);`
+            let ctx = &mut *visitor.ctx;
+            let arguments =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("arguments"), ReferenceFlags::Read);
+            let call_super = create_call_super(
+                visitor.class_binding,
+                Some(Argument::from(arguments)),
+                self.ctx,
+                ctx,
+            );
+            let body = constructor.body.as_mut().unwrap();
+            body.statements.push(ctx.ast.statement_return(SPAN, Some(call_super)));
+        }
+
+        let ctx = &mut *visitor.ctx;
+        let body = constructor.body.as_mut().unwrap();
+
+        // `babelHelpers.classCallCheck(this, A);`
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            Argument::from(visitor.class_binding.create_read_expression(ctx)),
+        ]);
+        let class_call_check =
+            self.ctx.helper_call_expr(Helper::ClassCallCheck, SPAN, arguments, ctx);
+        body.statements.insert(0, ctx.ast.statement_expression(SPAN, class_call_check));
+
+        // `var _this;`
+        if let Some(this_binding) = this_binding {
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                this_binding.create_binding_pattern(ctx),
+                NONE,
+                None,
+                false,
+            );
+            let declaration = ctx.ast.declaration_variable(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            );
+            body.statements.insert(0, Statement::from(declaration));
+        }
+    }
+
+    /// Transform method and add it to `descriptors`.
+    ///
+    /// * `foo() {}` -> `{ key: "foo", value: function foo() {} }`
+    /// * `get foo() {}` -> `{ key: "foo", get: function () {} }`
+    /// * `[foo]() {}` -> `{ key: foo, value: function () {} }`
+    ///
+    /// Getter and setter of the same key are merged into a single descriptor.
+    fn transform_method(
+        method: ArenaBox<'a, MethodDefinition<'a>>,
+        descriptors: &mut Vec<Descriptor<'a>>,
+        visitor: &mut ClassMethodVisitor<'a, '_, '_>,
+    ) {
+        let MethodDefinition { key, mut value, kind, computed, r#static, .. } = method.unbox();
+
+        let scope_id = value.scope_id();
+        visitor
+            .ctx
+            .scoping_mut()
+            .scope_flags_mut(scope_id)
+            .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+
+        let referenced_names = visitor.visit_method(&mut value, r#static);
+        let ctx = &mut *visitor.ctx;
+
+        let key_name = if computed {
+            None
+        } else {
+            match &key {
+                PropertyKey::StaticIdentifier(ident) => Some(ident.name.as_str()),
+                PropertyKey::StringLiteral(lit) => Some(lit.value.as_str()),
+                _ => None,
+            }
+        };
+
+        // Name method function after key, unless it would shadow a reference within the method.
+        // `foo() {}` -> `function foo() {}`
+        if kind == MethodDefinitionKind::Method
+            && let Some(name) = key_name
+            && is_identifier_name(name)
+            && !is_reserved_keyword(name)
+            && !matches!(name, "arguments" | "eval")
+            && !referenced_names.contains(name)
+        {
+            let binding = ctx.generate_binding(Atom::from(name), scope_id, SymbolFlags::Function);
+            value.id = Some(binding.create_binding_identifier(ctx));
+        }
+
+        let value = Expression::FunctionExpression(value);
+
+        if let Some(name) = key_name
+            && matches!(kind, MethodDefinitionKind::Get | MethodDefinitionKind::Set)
+            && let Some(descriptor) =
+                descriptors.iter_mut().rev().find(|d| d.key_name == Some(name))
+        {
+            let slot = if kind == MethodDefinitionKind::Get {
+                &mut descriptor.get
+            } else {
+                &mut descriptor.set
+            };
+            if descriptor.value.is_none() && slot.is_none() {
+                *slot = Some(value);
+                return;
+            }
+        }
+
+        let key = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(ident.span, ident.name, None)
+            }
+            PropertyKey::PrivateIdentifier(_) => unreachable!(),
+            key => key.into_expression(),
+        };
+
+        let mut descriptor = Descriptor { key_name, key, value: None, get: None, set: None };
+        match kind {
+            MethodDefinitionKind::Get => descriptor.get = Some(value),
+            MethodDefinitionKind::Set => descriptor.set = Some(value),
+            _ => descriptor.value = Some(value),
+        }
+        descriptors.push(descriptor);
+    }
+
+    /// `[{ key: "foo", value: function foo() {} }, ...]`
+    fn create_descriptors_array(
+        descriptors: Vec<Descriptor<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let elements = ctx.ast.vec_from_iter(descriptors.into_iter().map(|descriptor| {
+            let Descriptor { key, value, get, set, .. } = descriptor;
+            let mut properties = ctx.ast.vec_with_capacity(3);
+            for (name, value) in [("key", Some(key)), ("value", value), ("get", get), ("set", set)]
+            {
+                if let Some(value) = value {
+                    let key = ctx.ast.property_key_static_identifier(SPAN, name);
+                    properties.push(ctx.ast.object_property_kind_object_property(
+                        SPAN,
+                        PropertyKind::Init,
+                        key,
+                        value,
+                        false,
+                        false,
+                        false,
+                    ));
+                }
+            }
+            ArrayExpressionElement::from(ctx.ast.expression_object(SPAN, properties))
+        }));
+        ctx.ast.expression_array(SPAN, elements)
+    }
+
+    /// `/*#__PURE__*/babelHelpers.createClass(...)`
+    fn create_class_call(
+        &self,
+        span: Span,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = self.ctx.helper_load(Helper::CreateClass, ctx);
+        ctx.ast.expression_call_with_pure(span, callee, NONE, arguments, false, true)
+    }
+
+    /// `function () {}`
+    fn create_empty_function(
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec());
+        ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+        )
+    }
+}
+
+/// Property descriptor of a class method, passed to `createClass` helper.
+struct Descriptor<'a> {
+    /// Static name of key, used for merging getters and setters
+    key_name: Option<&'a str>,
+    key: Expression<'a>,
+    value: Option<Expression<'a>>,
+    get: Option<Expression<'a>>,
+    set: Option<Expression<'a>>,
+}
+
+/// `babelHelpers.callSuper(this, A, args)`
+fn create_call_super<'a>(
+    class_binding: &BoundIdentifier<'a>,
+    arguments: Option<Argument<'a>>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let mut args = ctx.ast.vec_with_capacity(3);
+    args.push(Argument::from(ctx.ast.expression_this(SPAN)));
+    args.push(Argument::from(class_binding.create_read_expression(ctx)));
+    args.extend(arguments);
+    transform_ctx.helper_call_expr(Helper::CallSuper, SPAN, args, ctx)
+}
+
+/// `babelHelpers.assertThisInitialized(_this)`
+fn create_assert_this_initialized<'a>(
+    this_binding: &BoundIdentifier<'a>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let arguments = ctx.ast.vec1(Argument::from(this_binding.create_read_expression(ctx)));
+    transform_ctx.helper_call_expr(Helper::AssertThisInitialized, SPAN, arguments, ctx)
+}
+
+/// Visitor for transforming class constructor and methods.
+///
+/// * Transform `super()`, `super.prop`, `this` and `new.target`.
+/// * Transform `return` in derived class constructor.
+/// * Update references to class name in class declarations, which now refer to the constructor
+///   function.
+/// * Remove `StrictMode` flag from scopes, if class is in sloppy mode code.
+struct ClassMethodVisitor<'a, 'ctx, 'v> {
+    transform_ctx: &'ctx TransformCtx<'a>,
+    ctx: &'v mut TraverseCtx<'a>,
+    class_binding: &'v BoundIdentifier<'a>,
+    /// References to symbol `.0` should be updated to refer to symbol `.1`
+    retarget: Option<(SymbolId, SymbolId)>,
+    /// `_this` binding in derived class constructor
+    this_binding: Option<BoundIdentifier<'a>>,
+    is_constructor: bool,
+    is_static: bool,
+    /// Depth of nested functions (including arrow functions)
+    function_depth: u32,
+    /// Depth of nested non-arrow functions, which have their own `this`
+    this_depth: u32,
+    /// Depth of nested object methods and classes, which have their own `super`
+    super_depth: u32,
+    is_strict: bool,
+    /// Whether the spread transform is enabled
+    spread: bool,
+    /// Names of all identifier references in current method
+    referenced_names: FxHashSet<&'a str>,
+}
+
+impl<'a, 'ctx, 'v> ClassMethodVisitor<'a, 'ctx, 'v> {
+    fn new(
+        class_binding: &'v BoundIdentifier<'a>,
+        retarget: Option<(SymbolId, SymbolId)>,
+        is_strict: bool,
+        spread: bool,
+        transform_ctx: &'ctx TransformCtx<'a>,
+        ctx: &'v mut TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            transform_ctx,
+            ctx,
+            class_binding,
+            retarget,
+            this_binding: None,
+            is_constructor: false,
+            is_static: false,
+            function_depth: 0,
+            this_depth: 0,
+            super_depth: 0,
+            is_strict,
+            spread,
+            referenced_names: FxHashSet::default(),
+        }
+    }
+
+    fn visit_constructor(
+        &mut self,
+        func: &mut Function<'a>,
+        this_binding: Option<BoundIdentifier<'a>>,
+    ) {
+        self.this_binding = this_binding;
+        self.is_constructor = true;
+        self.is_static = false;
+        self.visit_method_function(func);
+        self.this_binding = None;
+        self.is_constructor = false;
+    }
+
+    /// Visit method, and return names of all identifier references in it.
+    fn visit_method(&mut self, func: &mut Function<'a>, is_static: bool) -> FxHashSet<&'a str> {
+        self.is_static = is_static;
+        self.visit_method_function(func);
+        self.referenced_names.drain().collect()
+    }
+
+    fn visit_method_function(&mut self, func: &mut Function<'a>) {
+        let is_strict = self.is_strict;
+        self.is_strict |= func.has_use_strict_directive();
+        walk_mut::walk_function(self, func, ScopeFlags::Function);
+        self.is_strict = is_strict;
+    }
+
+    /// Get `_this` binding if `this` should be replaced with it.
+    fn this_replacement(&self) -> Option<BoundIdentifier<'a>> {
+        if self.this_depth == 0 { self.this_binding.clone() } else { None }
+    }
+
+    fn is_super_member_owner(&self) -> bool {
+        self.super_depth == 0
+    }
+
+    /// `this`, or `_this` in derived class constructor
+    fn create_this(&mut self) -> Expression<'a> {
+        match self.this_replacement() {
+            Some(binding) => binding.create_read_expression(self.ctx),
+            None => self.ctx.ast.expression_this(SPAN),
+        }
+    }
+
+    /// `super()` -> `_this = babelHelpers.callSuper(this, A, [...])`
+    fn transform_super_call(&mut self, expr: &mut Expression<'a>) {
+        let Expression::CallExpression(call) = expr.take_in(self.ctx.ast) else { unreachable!() };
+        let CallExpression { span, arguments, .. } = call.unbox();
+        let arguments = if arguments.is_empty() {
+            None
+        } else {
+            Some(Argument::from(self.create_arguments_array(arguments)))
+        };
+        let call_super =
+            create_call_super(self.class_binding, arguments, self.transform_ctx, self.ctx);
+        let this_binding = self.this_binding.as_ref().unwrap();
+        let target = this_binding.create_write_target(self.ctx);
+        *expr = self.ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            target,
+            call_super,
+        );
+    }
+
+    /// `new.target` -> `this.constructor` in constructor, `void 0` in methods.
+    fn transform_new_target(&self, expr: &mut Expression<'a>) {
+        let span = expr.span();
+        *expr = if self.is_constructor {
+            let this = self.ctx.ast.expression_this(SPAN);
+            create_property_access(span, this, "constructor", self.ctx)
+        } else {
+            self.ctx.ast.void_0(span)
+        };
+    }
+
+    /// Property of `super.prop` or `super[prop]`, as an expression.
+    fn take_super_member_property(&self, member: &mut MemberExpression<'a>) -> Expression<'a> {
+        match member {
+            MemberExpression::StaticMemberExpression(member) => {
+                let property = &member.property;
+                self.ctx.ast.expression_string_literal(property.span, property.name, None)
+            }
+            MemberExpression::ComputedMemberExpression(member) => {
+                member.expression.take_in(self.ctx.ast)
+            }
+            MemberExpression::PrivateFieldExpression(_) => unreachable!(),
+        }
+    }
+
+    /// `super.prop` -> `babelHelpers.superPropGet(A, "prop", this, 1)`
+    fn transform_super_member(&mut self, expr: &mut Expression<'a>) {
+        let span = expr.span();
+        let member = expr.as_member_expression_mut().unwrap();
+        let property = self.take_super_member_property(member);
+        *expr = self.create_super_prop_get(span, property, false);
+    }
+
+    /// `super.method(1)` -> `babelHelpers.superPropGet(A, "method", this, 3)([1])`
+    fn transform_super_member_call(&mut self, call: &mut CallExpression<'a>) {
+        let member = call.callee.as_member_expression_mut().unwrap();
+        let property = self.take_super_member_property(member);
+        call.callee = self.create_super_prop_get(SPAN, property, true);
+
+        let arguments = call.arguments.take_in(self.ctx.ast);
+        let array = self.create_arguments_array(arguments);
+        call.arguments.push(Argument::from(array));
+    }
+
+    /// `a, ...b` -> `[a, ...b]`, or `[a].concat(babelHelpers.toConsumableArray(b))` if the spread
+    /// transform is enabled.
+    fn create_arguments_array(&mut self, arguments: ArenaVec<'a, Argument<'a>>) -> Expression<'a> {
+        let elements =
+            self.ctx.ast.vec_from_iter(arguments.into_iter().map(ArrayExpressionElement::from));
+        let mut array = self.ctx.ast.array_expression(SPAN, elements);
+        if self.spread && array.elements.iter().any(ArrayExpressionElement::is_spread) {
+            return Spread::new(self.transform_ctx)
+                .transform_array_expression(&mut array, self.ctx);
+        }
+        Expression::ArrayExpression(self.ctx.ast.alloc(array))
+    }
+
+    /// * `super.prop = value` -> `babelHelpers.superPropSet(A, "prop", value, this, 1, 1)`
+    /// * `super.prop += value`
+    ///   -> `babelHelpers.superPropSet(A, "prop", babelHelpers.superPropGet(A, "prop", this, 1) + value, this, 1, 1)`
+    /// * `super.prop &&= value`
+    ///   -> `babelHelpers.superPropGet(A, "prop", this, 1) && babelHelpers.superPropSet(A, "prop", value, this, 1, 1)`
+    fn transform_super_assignment(&mut self, expr: &mut Expression<'a>) {
+        let Expression::AssignmentExpression(assign) = expr.take_in(self.ctx.ast) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, operator, left, right: value } = assign.unbox();
+        let mut member = left.into_member_expression();
+        let property = self.take_super_member_property(&mut member);
+
+        *expr = if operator == AssignmentOperator::Assign {
+            self.create_super_prop_set(span, property, value)
+        } else {
+            let (property1, property2) =
+                self.transform_ctx.duplicate_expression(property, false, self.ctx);
+            if let Some(operator) = operator.to_binary_operator() {
+                let get_call = self.create_super_prop_get(SPAN, property2, false);
+                let value = self.ctx.ast.expression_binary(SPAN, get_call, operator, value);
+                self.create_super_prop_set(span, property1, value)
+            } else if let Some(operator) = operator.to_logical_operator() {
+                let get_call = self.create_super_prop_get(SPAN, property1, false);
+                let set_call = self.create_super_prop_set(span, property2, value);
+                self.ctx.ast.expression_logical(span, get_call, operator, set_call)
+            } else {
+                unreachable!()
+            }
+        };
+    }
+
+    /// * `++super.prop`
+    ///   -> `babelHelpers.superPropSet(A, "prop", (_super$prop = babelHelpers.superPropGet(A, "prop", this, 1), ++_super$prop), this, 1, 1)`
+    /// * `super.prop++`
+    ///   -> `(babelHelpers.superPropSet(A, "prop", (_super$prop = babelHelpers.superPropGet(A, "prop", this, 1), _super$prop2 = _super$prop++, _super$prop), this, 1, 1), _super$prop2)`
+    fn transform_super_update(&mut self, expr: &mut Expression<'a>) {
+        let Expression::UpdateExpression(mut update_expr) = expr.take_in(self.ctx.ast) else {
+            unreachable!()
+        };
+        let member = update_expr.argument.as_member_expression_mut().unwrap();
+        let temp_var_name_base = get_var_name_from_node(&*member);
+        let property = self.take_super_member_property(member);
+
+        let (property1, property2) =
+            self.transform_ctx.duplicate_expression(property, false, self.ctx);
+        let get_call = self.create_super_prop_get(SPAN, property2, false);
+
+        let var_declarations = &self.transform_ctx.var_declarations;
+        let temp_binding = var_declarations.create_uid_var(&temp_var_name_base, self.ctx);
+        let assignment = create_assignment(&temp_binding, get_call, self.ctx);
+
+        let span = update_expr.span;
+        let prefix = update_expr.prefix;
+        update_expr.span = SPAN;
+        update_expr.argument = temp_binding.create_read_write_simple_target(self.ctx);
+        let update_expr = Expression::UpdateExpression(update_expr);
+
+        *expr = if prefix {
+            let value = self
+                .ctx
+                .ast
+                .expression_sequence(SPAN, self.ctx.ast.vec_from_array([assignment, update_expr]));
+            self.create_super_prop_set(span, property1, value)
+        } else {
+            let temp_binding2 = var_declarations.create_uid_var(&temp_var_name_base, self.ctx);
+            let assignment2 = create_assignment(&temp_binding2, update_expr, self.ctx);
+            let value = self.ctx.ast.expression_sequence(
+                SPAN,
+                self.ctx.ast.vec_from_array([
+                    assignment,
+                    assignment2,
+                    temp_binding.create_read_expression(self.ctx),
+                ]),
+            );
+            let set_call = self.create_super_prop_set(span, property1, value);
+            self.ctx.ast.expression_sequence(
+                span,
+                self.ctx
+                    .ast
+                    .vec_from_array([set_call, temp_binding2.create_read_expression(self.ctx)]),
+            )
+        };
+    }
+
+    /// * Instance: `babelHelpers.superPropGet(A, prop, this, 1)`
+    /// * Static: `babelHelpers.superPropGet(A, prop, this)`
+    /// * Instance callee: `babelHelpers.superPropGet(A, prop, this, 3)`
+    /// * Static callee: `babelHelpers.superPropGet(A, prop, this, 2)`
+    fn create_super_prop_get(
+        &mut self,
+        span: Span,
+        property: Expression<'a>,
+        is_callee: bool,
+    ) -> Expression<'a> {
+        let class = self.class_binding.create_read_expression(self.ctx);
+        let receiver = self.create_this();
+        let mut arguments = self.ctx.ast.vec_with_capacity(4);
+        arguments.push(Argument::from(class));
+        arguments.push(Argument::from(property));
+        arguments.push(Argument::from(receiver));
+        let flags = u8::from(!self.is_static) | (u8::from(is_callee) << 1);
+        if flags != 0 {
+            let flags = self.ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(flags),
+                None,
+                NumberBase::Decimal,
+            );
+            arguments.push(Argument::from(flags));
+        }
+        self.transform_ctx.helper_call_expr(Helper::SuperPropGet, span, arguments, self.ctx)
+    }
+
+    /// * Instance: `babelHelpers.superPropSet(A, prop, value, this, 1, 1)`
+    /// * Static: `babelHelpers.superPropSet(A, prop, value, this, 1)`
+    fn create_super_prop_set(
+        &mut self,
+        span: Span,
+        property: Expression<'a>,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        let class = self.class_binding.create_read_expression(self.ctx);
+        let receiver = self.create_this();
+        let one = || {
+            Argument::from(self.ctx.ast.expression_numeric_literal(
+                SPAN,
+                1.0,
+                None,
+                NumberBase::Decimal,
+            ))
+        };
+        let mut arguments = self.ctx.ast.vec_from_array([
+            Argument::from(class),
+            Argument::from(property),
+            Argument::from(value),
+            Argument::from(receiver),
+            one(),
+        ]);
+        if !self.is_static {
+            arguments.push(one());
+        }
+        self.transform_ctx.helper_call_expr(Helper::SuperPropSet, span, arguments, self.ctx)
+    }
+}
+
+/// `super.prop` or `super[prop]`
+fn is_super_member_expression(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::StaticMemberExpression(member) => member.object.is_super(),
+        Expression::ComputedMemberExpression(member) => member.object.is_super(),
+        _ => false,
+    }
+}
+
+impl<'a> VisitMut<'a> for ClassMethodVisitor<'a, '_, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let scope_id = scope_id.get().unwrap();
+        self.ctx
+            .scoping_mut()
+            .scope_flags_mut(scope_id)
+            .set(ScopeFlags::StrictMode, self.is_strict);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        let is_strict = self.is_strict;
+        self.is_strict |= func.has_use_strict_directive();
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.this_depth -= 1;
+        self.function_depth -= 1;
+        self.is_strict = is_strict;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        let is_strict = self.is_strict;
+        self.is_strict |= arrow.has_use_strict_directive();
+        self.function_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+        self.is_strict = is_strict;
+    }
+
+    /// Classes which were not transformed have their own `this` and `super`, and are always strict.
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        let is_strict = self.is_strict;
+        self.is_strict = true;
+        self.function_depth += 1;
+        self.this_depth += 1;
+        self.super_depth += 1;
+        walk_mut::walk_class(self, class);
+        self.super_depth -= 1;
+        self.this_depth -= 1;
+        self.function_depth -= 1;
+        self.is_strict = is_strict;
+    }
+
+    /// Object methods have their own `super`.
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        if prop.method || prop.kind != PropertyKind::Init {
+            self.visit_property_key(&mut prop.key);
+            self.super_depth += 1;
+            self.visit_expression(&mut prop.value);
+            self.super_depth -= 1;
+        } else {
+            walk_mut::walk_object_property(self, prop);
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        self.referenced_names.insert(ident.name.as_str());
+
+        if let Some((from_symbol_id, to_symbol_id)) = self.retarget {
+            let reference_id = ident.reference_id();
+            let scoping = self.ctx.scoping_mut();
+            let reference = scoping.get_reference_mut(reference_id);
+            if reference.symbol_id() == Some(from_symbol_id) {
+                reference.set_symbol_id(to_symbol_id);
+                scoping.delete_resolved_reference(from_symbol_id, reference_id);
+                scoping.add_resolved_reference(to_symbol_id, reference_id);
+            }
+        }
+    }
+
+    /// `this.prop` -> `_this.prop` in derived class constructor
+    fn visit_static_member_expression(&mut self, member: &mut StaticMemberExpression<'a>) {
+        if let Expression::ThisExpression(this) = &member.object
+            && let Some(binding) = self.this_replacement()
+        {
+            member.object = binding.create_spanned_read_expression(this.span, self.ctx);
+        }
+        walk_mut::walk_static_member_expression(self, member);
+    }
+
+    /// `this[prop]` -> `_this[prop]` in derived class constructor
+    fn visit_computed_member_expression(&mut self, member: &mut ComputedMemberExpression<'a>) {
+        if let Expression::ThisExpression(this) = &member.object
+            && let Some(binding) = self.this_replacement()
+        {
+            member.object = binding.create_spanned_read_expression(this.span, self.ctx);
+        }
+        walk_mut::walk_computed_member_expression(self, member);
+    }
+
+    /// `return x;` -> `return babelHelpers.possibleConstructorReturn(_this, x);`
+    /// `return;` -> `return babelHelpers.assertThisInitialized(_this);`
+    /// in derived class constructor
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        walk_mut::walk_return_statement(self, stmt);
+
+        if self.function_depth > 0 {
+            return;
+        }
+        let Some(this_binding) = &self.this_binding else { return };
+
+        let argument = match stmt.argument.take() {
+            Some(argument) => {
+                let arguments = self.ctx.ast.vec_from_array([
+                    Argument::from(this_binding.create_read_expression(self.ctx)),
+                    Argument::from(argument),
+                ]);
+                self.transform_ctx.helper_call_expr(
+                    Helper::PossibleConstructorReturn,
+                    SPAN,
+                    arguments,
+                    self.ctx,
+                )
+            }
+            None => create_assert_this_initialized(this_binding, self.transform_ctx, self.ctx),
+        };
+        stmt.argument = Some(argument);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            // `this` -> `babelHelpers.assertThisInitialized(_this)` in derived class constructor
+            Expression::ThisExpression(_) => {
+                if let Some(this_binding) = self.this_replacement() {
+                    *expr =
+                        create_assert_this_initialized(&this_binding, self.transform_ctx, self.ctx);
+                }
+                return;
+            }
+            Expression::MetaProperty(meta) => {
+                if meta.meta.name == "new" && meta.property.name == "target" && self.this_depth == 0
+                {
+                    self.transform_new_target(expr);
+                }
+                return;
+            }
+            Expression::CallExpression(call) => {
+                if matches!(call.callee, Expression::Super(_)) {
+                    if self.this_replacement().is_some() {
+                        self.visit_arguments(&mut call.arguments);
+                        self.transform_super_call(expr);
+                        return;
+                    }
+                } else if is_super_member_expression(&call.callee) && self.is_super_member_owner() {
+                    if let Expression::ComputedMemberExpression(member) = &mut call.callee {
+                        self.visit_expression(&mut member.expression);
+                    }
+                    self.visit_arguments(&mut call.arguments);
+                    self.transform_super_member_call(call);
+                    return;
+                }
+            }
+            Expression::StaticMemberExpression(member)
+                if member.object.is_super() && self.is_super_member_owner() =>
+            {
+                self.transform_super_member(expr);
+                return;
+            }
+            Expression::ComputedMemberExpression(member)
+                if member.object.is_super() && self.is_super_member_owner() =>
+            {
+                self.visit_expression(&mut member.expression);
+                self.transform_super_member(expr);
+                return;
+            }
+            Expression::AssignmentExpression(assign)
+                if self.is_super_member_owner()
+                    && assign
+                        .left
+                        .as_member_expression()
+                        .is_some_and(|member| member.object().is_super()) =>
+            {
+                if let AssignmentTarget::ComputedMemberExpression(member) = &mut assign.left {
+                    self.visit_expression(&mut member.expression);
+                }
+                self.visit_expression(&mut assign.right);
+                self.transform_super_assignment(expr);
+                return;
+            }
+            Expression::UpdateExpression(update)
+                if self.is_super_member_owner()
+                    && update
+                        .argument
+                        .as_member_expression()
+                        .is_some_and(|member| member.object().is_super()) =>
+            {
+                if let SimpleAssignmentTarget::ComputedMemberExpression(member) =
+                    &mut update.argument
+                {
+                    self.visit_expression(&mut member.expression);
+                }
+                self.transform_super_update(expr);
+                return;
+            }
+            _ => {}
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+}
@@ -0,0 +1,1082 @@
+//! ES2015: Destructuring
+//!
+//! This plugin transforms destructuring patterns into plain property and index accesses.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let { x, y: [a, b] } = obj;
+//! ({ x, ...rest } = obj);
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _obj;
+//! let x = obj.x,
+//!   _obj$y = _slicedToArray(obj.y, 2),
+//!   a = _obj$y[0],
+//!   b = _obj$y[1];
+//! _obj = obj, x = _obj.x, rest = _objectWithoutProperties(_obj, ["x"]);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-destructuring](https://babel.dev/docs/babel-plugin-transform-destructuring).
+//!
+//! Destructuring in function parameters is handled here only when the parameters transform is
+//! disabled. Otherwise, the parameters transform moves the patterns into the function body
+//! itself, and uses [`create_parameter_declaration`] to destructure them.
+//!
+//! Supports the `iterableIsArray` and `arrayLikeIsIterable` assumptions.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-destructuring>
+//! * Destructuring assignment: <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment>
+
+use oxc_allocator::{GetAddress, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ecmascript::{BoundNames, ToJsString, WithoutGlobalReferenceInformation};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, ast_operations::get_var_name_from_node};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::{create_assignment, create_member_callee},
+};
+
+use super::utils::{
+    ToArrayKind, create_is_undefined, create_number, create_to_array, is_static_expression,
+};
+
+pub struct Destructuring<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Destructuring<'a, '_> {
+    // `let { a } = obj;`
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `export let { a } = obj;` is handled in `exit_statement`,
+        // because the exported names have to be preserved.
+        if matches!(ctx.parent(), Ancestor::ExportNamedDeclarationDeclaration(_)) {
+            return;
+        }
+        self.transform_variable_declaration(decl, ctx);
+    }
+
+    // `export let { a } = obj;`
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Statement::ExportNamedDeclaration(decl) = stmt
+            && let Some(Declaration::VariableDeclaration(_)) = &decl.declaration
+        {
+            self.transform_export_variable_declaration(stmt, ctx);
+        }
+    }
+
+    // `({ a } = obj);`
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::AssignmentExpression(assignment) = expr
+            && assignment.operator.is_assign()
+            && assignment.left.is_assignment_target_pattern()
+        {
+            self.transform_assignment_expression(expr, ctx);
+        }
+    }
+
+    // `for (const { a } of arr);`
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        self.transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    // `for (const [a, b] in obj);`
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        self.transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    // `try {} catch ({ message }) {}`
+    fn exit_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.transform_catch_clause(clause, ctx);
+    }
+
+    // `function f({ a }) {}`
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = func.scope_id();
+        if let Some(body) = &mut func.body {
+            self.transform_params(&mut func.params, &mut body.statements, scope_id, ctx);
+        }
+    }
+
+    // `({ a }) => {}`
+    fn exit_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if has_pattern_params(&arrow.params) {
+            convert_arrow_expression_body_to_block(arrow, ctx);
+            let scope_id = arrow.scope_id();
+            self.transform_params(&mut arrow.params, &mut arrow.body.statements, scope_id, ctx);
+        }
+    }
+}
+
+impl<'a> Destructuring<'a, '_> {
+    /// Transform `let { a, b } = obj;` to `let a = obj.a, b = obj.b;`.
+    fn transform_variable_declaration(
+        &self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !decl.declarations.iter().any(|d| d.init.is_some() && !d.id.is_binding_identifier()) {
+            return;
+        }
+
+        let scope_id = if decl.kind.is_var() {
+            ctx.current_hoist_scope_id()
+        } else {
+            ctx.current_block_scope_id()
+        };
+        let mut destructurer = BindingPatternDestructurer::new(decl.kind, scope_id, self.ctx, ctx);
+        for declarator in decl.declarations.take_in(ctx.ast) {
+            match declarator {
+                VariableDeclarator { id, init: Some(init), .. } if !id.is_binding_identifier() => {
+                    destructurer.push(id, init, ctx);
+                }
+                declarator => destructurer.declarators.push(declarator),
+            }
+        }
+        decl.declarations = destructurer.declarators;
+    }
+
+    /// Transform `export let { a, b } = obj;` to `let a = obj.a, b = obj.b; export { a, b };`.
+    fn transform_export_variable_declaration(
+        &self,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Statement::ExportNamedDeclaration(export) = stmt else { unreachable!() };
+        let Some(Declaration::VariableDeclaration(decl)) = &mut export.declaration else {
+            unreachable!()
+        };
+        if !decl.declarations.iter().any(|d| d.init.is_some() && !d.id.is_binding_identifier()) {
+            return;
+        }
+
+        let mut specifiers = ctx.ast.vec();
+        decl.bound_names(&mut |ident| {
+            let local = ctx.create_bound_ident_reference(
+                SPAN,
+                ident.name.into(),
+                ident.symbol_id(),
+                ReferenceFlags::Read,
+            );
+            specifiers.push(ctx.ast.export_specifier(
+                SPAN,
+                ModuleExportName::IdentifierReference(local),
+                ModuleExportName::IdentifierName(ctx.ast.identifier_name(SPAN, ident.name)),
+                ImportOrExportKind::Value,
+            ));
+        });
+
+        self.transform_variable_declaration(decl, ctx);
+
+        let old_address = stmt.address();
+        let Statement::ExportNamedDeclaration(export) = stmt.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let Some(Declaration::VariableDeclaration(decl)) = export.unbox().declaration else {
+            unreachable!()
+        };
+        *stmt = Statement::VariableDeclaration(decl);
+        self.ctx.statement_injector.move_insertions(&old_address, stmt);
+
+        let export = ctx.ast.module_declaration_export_named_declaration(
+            SPAN,
+            None,
+            specifiers,
+            None,
+            ImportOrExportKind::Value,
+            NONE,
+        );
+        self.ctx.statement_injector.insert_after(stmt, Statement::from(export));
+    }
+
+    /// Transform `({ a, b } = obj)` to `(_obj = obj, a = _obj.a, b = _obj.b, _obj)`.
+    ///
+    /// If the result of the assignment is unused, the trailing `_obj` is omitted.
+    fn transform_assignment_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_value_unused = is_value_unused(ctx);
+
+        let Expression::AssignmentExpression(assignment) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, left, right, .. } = assignment.unbox();
+
+        let mut destructurer = AssignmentTargetDestructurer::new(self.ctx, ctx);
+        if is_value_unused {
+            destructurer.push(left, right, ctx);
+        } else {
+            let mut value = destructurer.memoize(right, ctx);
+            let first = value.read(ctx);
+            destructurer.push(left, first, ctx);
+            let result = value.read(ctx);
+            destructurer.expressions.push(result);
+        }
+        *expr = destructurer.into_expression(span, ctx);
+    }
+
+    /// Transform destructuring in left side of `for-in` / `for-of`.
+    ///
+    /// * `for (const [a, b] of arr) {}` -> `for (const _ref of arr) { const a = _ref[0], b = _ref[1]; }`
+    /// * `for ([a, b] of arr) {}` -> `for (var _ref of arr) { a = _ref[0], b = _ref[1]; }`
+    fn transform_for_statement_left(
+        &self,
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        for_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let stmt = match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let kind = decl.kind;
+                let declarator = &mut decl.declarations[0];
+                if declarator.id.is_binding_identifier() {
+                    return;
+                }
+
+                let block_scope_id = ensure_block_statement(body, for_scope_id, ctx);
+                let (temp_scope_id, scope_id) = if kind.is_var() {
+                    let scope_id = ctx.current_hoist_scope_id();
+                    (scope_id, scope_id)
+                } else {
+                    (for_scope_id, block_scope_id)
+                };
+
+                let temp = ctx.generate_uid("ref", temp_scope_id, kind_to_symbol_flags(kind));
+                let pattern = declarator.id.take_in(ctx.ast);
+                declarator.id = temp.create_binding_pattern(ctx);
+
+                // `let` / `const` bindings are moved to the body block
+                if !kind.is_var() {
+                    move_bindings(&pattern, for_scope_id, block_scope_id, ctx);
+                }
+
+                let mut destructurer =
+                    BindingPatternDestructurer::new(kind, scope_id, self.ctx, ctx);
+                destructurer.push(pattern, temp.create_read_expression(ctx), ctx);
+                Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    kind,
+                    destructurer.declarators,
+                    false,
+                ))
+            }
+            left @ match_assignment_target!(ForStatementLeft) => {
+                if !left.to_assignment_target().is_assignment_target_pattern() {
+                    return;
+                }
+                ensure_block_statement(body, for_scope_id, ctx);
+
+                let temp = ctx.generate_uid_in_current_hoist_scope("ref");
+                let kind = VariableDeclarationKind::Var;
+                let declarator = ctx.ast.variable_declarator(
+                    SPAN,
+                    kind,
+                    temp.create_binding_pattern(ctx),
+                    NONE,
+                    None,
+                    false,
+                );
+                let new_left = ForStatementLeft::VariableDeclaration(
+                    ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false),
+                );
+                let target = std::mem::replace(left, new_left).into_assignment_target();
+
+                let mut destructurer = AssignmentTargetDestructurer::new(self.ctx, ctx);
+                destructurer.push(target, temp.create_read_expression(ctx), ctx);
+                let expression = destructurer.into_expression(SPAN, ctx);
+                ctx.ast.statement_expression(SPAN, expression)
+            }
+        };
+
+        let Statement::BlockStatement(block) = body else { unreachable!() };
+        block.body.insert(0, stmt);
+    }
+
+    /// Transform `catch ({ message }) {}` to `catch (_ref) { let message = _ref.message; }`.
+    fn transform_catch_clause(&self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(param) = &mut clause.param else { return };
+        if param.pattern.is_binding_identifier() {
+            return;
+        }
+
+        let scope_id = clause.body.scope_id();
+        let temp = ctx.generate_uid(
+            "ref",
+            scope_id,
+            SymbolFlags::FunctionScopedVariable | SymbolFlags::CatchVariable,
+        );
+        let pattern = param.pattern.take_in(ctx.ast);
+        param.pattern = temp.create_binding_pattern(ctx);
+
+        let init = temp.create_read_expression(ctx);
+        let decl = create_parameter_declaration(pattern, init, scope_id, true, self.ctx, ctx);
+        clause.body.body.insert(0, decl);
+    }
+
+    /// Move destructuring patterns in params into the function body.
+    ///
+    /// `function f({ a }, [b] = []) {}` -> `function f(_ref, _ref2 = []) { let a = _ref.a; let b = _ref2[0]; }`
+    fn transform_params(
+        &self,
+        params: &mut FormalParameters<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let patterns = params
+            .items
+            .iter_mut()
+            .map(|param| &mut param.pattern)
+            .chain(params.rest.as_mut().map(|rest| &mut rest.rest.argument))
+            .filter(|pattern| !pattern.is_binding_identifier());
+
+        let mut declarations = vec![];
+        for pattern in patterns {
+            let temp = ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+            let pattern = std::mem::replace(pattern, temp.create_binding_pattern(ctx));
+            let init = temp.create_read_expression(ctx);
+            declarations
+                .push(create_parameter_declaration(pattern, init, scope_id, true, self.ctx, ctx));
+        }
+
+        if !declarations.is_empty() {
+            body.splice(0..0, declarations);
+        }
+    }
+}
+
+/// Create `let <pattern> = <init>;` for a binding which was previously declared as
+/// a function parameter or a catch clause parameter, and is moved into the body.
+///
+/// Bindings are changed to `let` bindings, unless any of them is redeclared in the body,
+/// in which case `var` is used instead.
+///
+/// If `destructure` is `true`, destructuring in `pattern` is transformed.
+pub(super) fn create_parameter_declaration<'a>(
+    pattern: BindingPattern<'a>,
+    init: Expression<'a>,
+    scope_id: ScopeId,
+    destructure: bool,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let mut is_redeclared = false;
+    pattern.bound_names(&mut |ident| {
+        is_redeclared |= !ctx.scoping().symbol_redeclarations(ident.symbol_id()).is_empty();
+    });
+    let kind =
+        if is_redeclared { VariableDeclarationKind::Var } else { VariableDeclarationKind::Let };
+    let flags = kind_to_symbol_flags(kind);
+    pattern.bound_names(&mut |ident| {
+        *ctx.scoping_mut().symbol_flags_mut(ident.symbol_id()) = flags;
+    });
+
+    let declarators = if destructure && !pattern.is_binding_identifier() {
+        let mut destructurer = BindingPatternDestructurer::new(kind, scope_id, transform_ctx, ctx);
+        destructurer.push(pattern, init, ctx);
+        destructurer.declarators
+    } else {
+        ctx.ast.vec1(ctx.ast.variable_declarator(SPAN, kind, pattern, NONE, Some(init), false))
+    };
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        declarators,
+        false,
+    ))
+}
+
+/// Returns `true` if any of the params is a destructuring pattern.
+pub(super) fn has_pattern_params(params: &FormalParameters<'_>) -> bool {
+    params.items.iter().any(|param| !param.pattern.is_binding_identifier())
+        || params.rest.as_ref().is_some_and(|rest| !rest.rest.argument.is_binding_identifier())
+}
+
+/// `() => x` -> `() => { return x; }`
+pub(super) fn convert_arrow_expression_body_to_block<'a>(
+    arrow: &mut ArrowFunctionExpression<'a>,
+    ctx: &TraverseCtx<'a>,
+) {
+    if !arrow.expression {
+        return;
+    }
+    arrow.expression = false;
+
+    let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.pop() else {
+        unreachable!(
+            "`arrow.expression` is true, which means it has only one ExpressionStatement."
+        );
+    };
+    let stmt = stmt.unbox();
+    arrow.body.statements.push(ctx.ast.statement_return(stmt.span, Some(stmt.expression)));
+}
+
+/// Returns `true` if the value of current expression is not used.
+///
+/// `({ a } = obj);` -> `true`
+/// `x = ({ a } = obj);` -> `false`
+/// `() => ({ a } = obj)` -> `false`
+fn is_value_unused(ctx: &TraverseCtx<'_>) -> bool {
+    let mut ancestors = ctx
+        .ancestors()
+        .skip_while(|ancestor| matches!(ancestor, Ancestor::ParenthesizedExpressionExpression(_)));
+    if !matches!(ancestors.next(), Some(Ancestor::ExpressionStatementExpression(_))) {
+        return false;
+    }
+    ancestors.next();
+    !matches!(ancestors.next(), Some(Ancestor::ArrowFunctionExpressionBody(body)) if *body.expression())
+}
+
+/// Wrap `stmt` in a block statement if it is not a block statement already.
+/// `stmt` must be in scope `parent_scope_id`.
+///
+/// Returns `ScopeId` of the block.
+fn ensure_block_statement<'a>(
+    stmt: &mut Statement<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> ScopeId {
+    if let Statement::BlockStatement(block) = stmt {
+        return block.scope_id();
+    }
+    let scope_id =
+        ctx.insert_scope_below_statement_from_scope_id(stmt, parent_scope_id, ScopeFlags::empty());
+    let (span, stmts) = if let Statement::EmptyStatement(empty) = stmt {
+        (empty.span, ctx.ast.vec())
+    } else {
+        (stmt.span(), ctx.ast.vec1(stmt.take_in(ctx.ast)))
+    };
+    *stmt = ctx.ast.statement_block_with_scope_id(span, stmts, scope_id);
+    scope_id
+}
+
+/// Move bindings declared in `pattern` from scope `from` to scope `to`.
+fn move_bindings(
+    pattern: &BindingPattern<'_>,
+    from: ScopeId,
+    to: ScopeId,
+    ctx: &mut TraverseCtx<'_>,
+) {
+    pattern.bound_names(&mut |ident| {
+        let scoping = ctx.scoping_mut();
+        scoping.move_binding(from, to, &ident.name);
+        scoping.set_symbol_scope_id(ident.symbol_id(), to);
+    });
+}
+
+fn kind_to_symbol_flags(kind: VariableDeclarationKind) -> SymbolFlags {
+    match kind {
+        VariableDeclarationKind::Var => SymbolFlags::FunctionScopedVariable,
+        VariableDeclarationKind::Let
+        | VariableDeclarationKind::Using
+        | VariableDeclarationKind::AwaitUsing => SymbolFlags::BlockScopedVariable,
+        VariableDeclarationKind::Const => {
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+        }
+    }
+}
+
+/// A value which may be read multiple times.
+struct ValueRef<'a> {
+    /// Original expression. It is used for the first read, to avoid creating a new reference.
+    original: Option<Expression<'a>>,
+    kind: ValueRefKind<'a>,
+}
+
+enum ValueRefKind<'a> {
+    /// `this`
+    This,
+    /// A binding which is never reassigned.
+    Binding(BoundIdentifier<'a>),
+    /// Original expression, which must only be read once.
+    Once,
+}
+
+impl<'a> ValueRef<'a> {
+    fn once(value: Expression<'a>) -> Self {
+        Self { original: Some(value), kind: ValueRefKind::Once }
+    }
+
+    fn binding(binding: BoundIdentifier<'a>) -> Self {
+        Self { original: None, kind: ValueRefKind::Binding(binding) }
+    }
+
+    /// Create a [`ValueRef`] from an expression which [`Destructure::is_static`] returned `true` for.
+    fn from_static(value: Expression<'a>, ctx: &TraverseCtx<'a>) -> Self {
+        let kind = match &value {
+            Expression::ThisExpression(_) => ValueRefKind::This,
+            Expression::Identifier(ident) => {
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                ValueRefKind::Binding(BoundIdentifier::new(ident.name.into(), symbol_id.unwrap()))
+            }
+            _ => unreachable!(),
+        };
+        Self { original: Some(value), kind }
+    }
+
+    fn read(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        if let Some(value) = self.original.take() {
+            return value;
+        }
+        match &self.kind {
+            ValueRefKind::This => ctx.ast.expression_this(SPAN),
+            ValueRefKind::Binding(binding) => binding.create_read_expression(ctx),
+            ValueRefKind::Once => unreachable!("value can only be read once"),
+        }
+    }
+
+    /// Discard the value. Removes the reference of original expression if it was never read.
+    fn discard(self, ctx: &mut TraverseCtx<'a>) {
+        if let Some(Expression::Identifier(ident)) = self.original {
+            ctx.delete_reference(ident.reference_id(), &ident.name);
+        }
+    }
+}
+
+/// Keys to exclude from object rest: `{ a, b, ...rest }` -> `["a", "b"]`.
+struct ExcludedKeys<'a> {
+    keys: ArenaVec<'a, ArrayExpressionElement<'a>>,
+    /// `true` if all keys are string literals, and `toPropertyKey` is not required.
+    all_literal: bool,
+}
+
+impl<'a> ExcludedKeys<'a> {
+    fn new(ctx: &TraverseCtx<'a>) -> Self {
+        Self { keys: ctx.ast.vec(), all_literal: true }
+    }
+
+    fn push_name(&mut self, name: Atom<'a>, ctx: &TraverseCtx<'a>) {
+        let key = ctx.ast.expression_string_literal(SPAN, name, None);
+        self.keys.push(ArrayExpressionElement::from(key));
+    }
+}
+
+/// Shared logic for destructuring binding patterns and assignment targets.
+trait Destructure<'a> {
+    fn transform_ctx(&self) -> &TransformCtx<'a>;
+
+    /// Store `value` in a new temporary variable named based on `name`, and return the binding.
+    fn create_temp(
+        &mut self,
+        name: &str,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a>;
+
+    /// Returns `true` if `expr` can be read multiple times without being stored in a temp var.
+    fn is_static(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        is_static_expression(expr, ctx)
+    }
+
+    /// Make `value` readable multiple times, storing it in a temp var if required.
+    fn memoize(&mut self, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> ValueRef<'a> {
+        if self.is_static(&value, ctx) {
+            return ValueRef::from_static(value, ctx);
+        }
+        let name = get_var_name_from_node(&value);
+        ValueRef::binding(self.create_temp(&name, value, ctx))
+    }
+
+    /// `value` with default `default` -> `_value === void 0 ? default : _value`
+    fn create_default(
+        &mut self,
+        value: Expression<'a>,
+        default: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let name = get_var_name_from_node(&value);
+        let temp = self.create_temp(&name, value, ctx);
+        let test = create_is_undefined(temp.create_read_expression(ctx), ctx);
+        let consequent = temp.create_read_expression(ctx);
+        ctx.ast.expression_conditional(SPAN, test, default, consequent)
+    }
+
+    /// Get reference to object being destructured, which is read `reads` times.
+    fn create_object_ref(
+        &mut self,
+        value: Expression<'a>,
+        reads: usize,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ValueRef<'a> {
+        if reads > 1 { self.memoize(value, ctx) } else { ValueRef::once(value) }
+    }
+
+    /// Create `object.key` or `object[key]`.
+    ///
+    /// If the object pattern has a rest element, `key` is also recorded in `excluded`.
+    fn create_property_access(
+        &mut self,
+        object: Expression<'a>,
+        key: PropertyKey<'a>,
+        excluded: Option<&mut ExcludedKeys<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let key = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                if let Some(excluded) = excluded {
+                    excluded.push_name(ident.name.into(), ctx);
+                }
+                let property = ctx.ast.identifier_name(ident.span, ident.name);
+                return Expression::from(
+                    ctx.ast.member_expression_static(SPAN, object, property, false),
+                );
+            }
+            PropertyKey::PrivateIdentifier(_) => unreachable!(),
+            key => key.into_expression(),
+        };
+
+        let key = match excluded {
+            Some(excluded) => self.create_excluded_key(key, excluded, ctx),
+            None => key,
+        };
+        Expression::from(ctx.ast.member_expression_computed(SPAN, object, key, false))
+    }
+
+    /// Record computed `key` in `excluded`, and return the expression to use as the key.
+    ///
+    /// Keys which are not literals are evaluated only once: `{ [a()]: b, ...c }` ->
+    /// `_a = a(), b = obj[_a], c = _objectWithoutProperties(obj, [_a].map(_toPropertyKey))`
+    fn create_excluded_key(
+        &mut self,
+        key: Expression<'a>,
+        excluded: &mut ExcludedKeys<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if key.is_literal()
+            && let Some(name) = key.to_js_string(&WithoutGlobalReferenceInformation {})
+        {
+            let name = ctx.ast.atom_from_cow(&name);
+            excluded.push_name(name, ctx);
+            return key;
+        }
+
+        excluded.all_literal = false;
+        let mut key = self.memoize(key, ctx);
+        let first = key.read(ctx);
+        excluded.keys.push(ArrayExpressionElement::from(key.read(ctx)));
+        first
+    }
+
+    /// Create value of object rest element.
+    ///
+    /// * `{ a, ...rest }` -> `_objectWithoutProperties(obj, ["a"])`
+    /// * `{ ...rest }` -> `_extends({}, (_objectDestructuringEmpty(obj), obj))`
+    fn create_object_rest(
+        &mut self,
+        object: &mut ValueRef<'a>,
+        excluded: ExcludedKeys<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let transform_ctx = self.transform_ctx();
+        if excluded.keys.is_empty() {
+            let object_expr = object.read(ctx);
+            let check = transform_ctx.helper_call_expr(
+                Helper::ObjectDestructuringEmpty,
+                SPAN,
+                ctx.ast.vec1(Argument::from(object_expr)),
+                ctx,
+            );
+            let object_expr = object.read(ctx);
+            let sequence =
+                ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_array([check, object_expr]));
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ctx.ast.expression_object(SPAN, ctx.ast.vec())),
+                Argument::from(sequence),
+            ]);
+            return transform_ctx.helper_call_expr(Helper::Extends, SPAN, arguments, ctx);
+        }
+
+        let mut keys = ctx.ast.expression_array(SPAN, excluded.keys);
+        if !excluded.all_literal {
+            // `[a, b].map(_toPropertyKey)`
+            let callee = create_member_callee(keys, "map", ctx);
+            let to_property_key = transform_ctx.helper_load(Helper::ToPropertyKey, ctx);
+            let arguments = ctx.ast.vec1(Argument::from(to_property_key));
+            keys = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        }
+        let object_expr = object.read(ctx);
+        let arguments = ctx.ast.vec_from_array([Argument::from(object_expr), Argument::from(keys)]);
+        transform_ctx.helper_call_expr(Helper::ObjectWithoutProperties, SPAN, arguments, ctx)
+    }
+
+    /// Convert `value` to an array which can be indexed.
+    ///
+    /// With `iterableIsArray` assumption, `value` is used as is.
+    fn create_array_ref(
+        &mut self,
+        value: Expression<'a>,
+        kind: ToArrayKind,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ValueRef<'a> {
+        let iterable_is_array = self.transform_ctx().assumptions.iterable_is_array;
+        if iterable_is_array && self.is_static(&value, ctx) {
+            return ValueRef::from_static(value, ctx);
+        }
+        let name = get_var_name_from_node(&value);
+        let value = if iterable_is_array {
+            value
+        } else {
+            create_to_array(value, kind, self.transform_ctx(), ctx)
+        };
+        ValueRef::binding(self.create_temp(&name, value, ctx))
+    }
+}
+
+/// `array` -> `array[index]`
+fn create_array_element_access<'a>(
+    array: Expression<'a>,
+    index: usize,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let index = create_number(index, ctx);
+    Expression::from(ctx.ast.member_expression_computed(SPAN, array, index, false))
+}
+
+/// `array` -> `array.slice(index)`
+fn create_array_slice<'a>(
+    array: Expression<'a>,
+    index: usize,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let callee = create_member_callee(array, "slice", ctx);
+    let arguments = ctx.ast.vec1(Argument::from(create_number(index, ctx)));
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// Destructures binding patterns into a list of [`VariableDeclarator`]s.
+///
+/// `let { a, b: [c] } = obj` -> `let a = obj.a, _obj$b = _slicedToArray(obj.b, 1), c = _obj$b[0]`
+struct BindingPatternDestructurer<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    kind: VariableDeclarationKind,
+    /// Scope which temp vars are created in
+    scope_id: ScopeId,
+    declarators: ArenaVec<'a, VariableDeclarator<'a>>,
+}
+
+impl<'a, 'ctx> BindingPatternDestructurer<'a, 'ctx> {
+    fn new(
+        kind: VariableDeclarationKind,
+        scope_id: ScopeId,
+        transform_ctx: &'ctx TransformCtx<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Self {
+        Self { ctx: transform_ctx, kind, scope_id, declarators: ctx.ast.vec() }
+    }
+
+    fn push(
+        &mut self,
+        pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match pattern {
+            BindingPattern::BindingIdentifier(_) => self.push_declarator(pattern, init, ctx),
+            BindingPattern::AssignmentPattern(assignment) => {
+                let AssignmentPattern { left, right, .. } = assignment.unbox();
+                let value = self.create_default(init, right, ctx);
+                self.push(left, value, ctx);
+            }
+            BindingPattern::ObjectPattern(object) => {
+                self.push_object_pattern(object.unbox(), init, ctx);
+            }
+            BindingPattern::ArrayPattern(array) => {
+                self.push_array_pattern(array.unbox(), init, ctx);
+            }
+        }
+    }
+
+    fn push_declarator(
+        &mut self,
+        id: BindingPattern<'a>,
+        init: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let declarator = ctx.ast.variable_declarator(SPAN, self.kind, id, NONE, Some(init), false);
+        self.declarators.push(declarator);
+    }
+
+    fn push_object_pattern(
+        &mut self,
+        pattern: ObjectPattern<'a>,
+        init: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let ObjectPattern { properties, rest, .. } = pattern;
+
+        // `let {} = obj` -> `let _obj = _objectDestructuringEmpty(obj)`
+        if properties.is_empty() && rest.is_none() {
+            let name = get_var_name_from_node(&init);
+            let value = self.ctx.helper_call_expr(
+                Helper::ObjectDestructuringEmpty,
+                SPAN,
+                ctx.ast.vec1(Argument::from(init)),
+                ctx,
+            );
+            self.create_temp(&name, value, ctx);
+            return;
+        }
+
+        let reads =
+            properties.len() + rest.as_ref().map_or(0, |_| usize::from(properties.is_empty()) + 1);
+        let mut object = self.create_object_ref(init, reads, ctx);
+        let mut excluded = rest.is_some().then(|| ExcludedKeys::new(ctx));
+        for property in properties {
+            let BindingProperty { key, value, .. } = property;
+            let object_expr = object.read(ctx);
+            let member = self.create_property_access(object_expr, key, excluded.as_mut(), ctx);
+            self.push(value, member, ctx);
+        }
+        if let Some(rest) = rest {
+            let value = self.create_object_rest(&mut object, excluded.unwrap(), ctx);
+            self.push(rest.unbox().argument, value, ctx);
+        }
+    }
+
+    fn push_array_pattern(
+        &mut self,
+        pattern: ArrayPattern<'a>,
+        init: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let ArrayPattern { elements, rest, .. } = pattern;
+        let count = elements.len();
+        let kind = if rest.is_some() { ToArrayKind::Rest } else { ToArrayKind::Sliced(count) };
+        let mut array = self.create_array_ref(init, kind, ctx);
+        for (index, element) in elements.into_iter().enumerate() {
+            if let Some(element) = element {
+                let value = create_array_element_access(array.read(ctx), index, ctx);
+                self.push(element, value, ctx);
+            }
+        }
+        if let Some(rest) = rest {
+            let value = create_array_slice(array.read(ctx), count, ctx);
+            self.push(rest.unbox().argument, value, ctx);
+        }
+        array.discard(ctx);
+    }
+}
+
+impl<'a> Destructure<'a> for BindingPatternDestructurer<'a, '_> {
+    fn transform_ctx(&self) -> &TransformCtx<'a> {
+        self.ctx
+    }
+
+    fn create_temp(
+        &mut self,
+        name: &str,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let binding = ctx.generate_uid(name, self.scope_id, kind_to_symbol_flags(self.kind));
+        self.push_declarator(binding.create_binding_pattern(ctx), value, ctx);
+        binding
+    }
+}
+
+/// Destructures assignment targets into a list of assignment expressions.
+///
+/// `({ a, b: [c] } = obj)` -> `a = obj.a, _obj$b = _slicedToArray(obj.b, 1), c = _obj$b[0]`
+///
+/// Temp vars are declared with `var` at top of the enclosing statement block.
+struct AssignmentTargetDestructurer<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    expressions: ArenaVec<'a, Expression<'a>>,
+    /// Temp vars created by this destructurer. They are never reassigned, so can be read multiple times.
+    temps: Vec<SymbolId>,
+}
+
+impl<'a, 'ctx> AssignmentTargetDestructurer<'a, 'ctx> {
+    fn new(transform_ctx: &'ctx TransformCtx<'a>, ctx: &TraverseCtx<'a>) -> Self {
+        Self { ctx: transform_ctx, expressions: ctx.ast.vec(), temps: vec![] }
+    }
+
+    /// Convert into a single expression. If there are multiple expressions, they are wrapped in
+    /// a sequence expression.
+    fn into_expression(mut self, span: Span, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        if self.expressions.len() == 1 {
+            self.expressions.pop().unwrap()
+        } else {
+            ctx.ast.expression_sequence(span, self.expressions)
+        }
+    }
+
+    fn push(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match target {
+            AssignmentTarget::ObjectAssignmentTarget(object) => {
+                self.push_object_target(object.unbox(), value, ctx);
+            }
+            AssignmentTarget::ArrayAssignmentTarget(array) => {
+                self.push_array_target(array.unbox(), value, ctx);
+            }
+            target => {
+                let assignment =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                self.expressions.push(assignment);
+            }
+        }
+    }
+
+    fn push_maybe_default(
+        &mut self,
+        target: AssignmentTargetMaybeDefault<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                let AssignmentTargetWithDefault { binding, init, .. } = target.unbox();
+                let value = self.create_default(value, init, ctx);
+                self.push(binding, value, ctx);
+            }
+            target => self.push(target.into_assignment_target(), value, ctx),
+        }
+    }
+
+    fn push_object_target(
+        &mut self,
+        target: ObjectAssignmentTarget<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let ObjectAssignmentTarget { properties, rest, .. } = target;
+
+        // `({} = obj)` -> `_objectDestructuringEmpty(obj)`
+        if properties.is_empty() && rest.is_none() {
+            let check = self.ctx.helper_call_expr(
+                Helper::ObjectDestructuringEmpty,
+                SPAN,
+                ctx.ast.vec1(Argument::from(value)),
+                ctx,
+            );
+            self.expressions.push(check);
+            return;
+        }
+
+        let reads =
+            properties.len() + rest.as_ref().map_or(0, |_| usize::from(properties.is_empty()) + 1);
+        let mut object = self.create_object_ref(value, reads, ctx);
+        let mut excluded = rest.is_some().then(|| ExcludedKeys::new(ctx));
+        for property in properties {
+            match property {
+                // `({ a } = obj)`, `({ a = 1 } = obj)`
+                AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                    let AssignmentTargetPropertyIdentifier { binding, init, .. } = property.unbox();
+                    let key = PropertyKey::StaticIdentifier(
+                        ctx.ast.alloc_identifier_name(binding.span, binding.name),
+                    );
+                    let object_expr = object.read(ctx);
+                    let member =
+                        self.create_property_access(object_expr, key, excluded.as_mut(), ctx);
+                    let value = match init {
+                        Some(init) => self.create_default(member, init, ctx),
+                        None => member,
+                    };
+                    let target = AssignmentTarget::AssignmentTargetIdentifier(ctx.alloc(binding));
+                    self.push(target, value, ctx);
+                }
+                // `({ a: b } = obj)`, `({ [a]: b = 1 } = obj)`
+                AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                    let AssignmentTargetPropertyProperty { name, binding, .. } = property.unbox();
+                    let object_expr = object.read(ctx);
+                    let member =
+                        self.create_property_access(object_expr, name, excluded.as_mut(), ctx);
+                    self.push_maybe_default(binding, member, ctx);
+                }
+            }
+        }
+        if let Some(rest) = rest {
+            let value = self.create_object_rest(&mut object, excluded.unwrap(), ctx);
+            self.push(rest.unbox().target, value, ctx);
+        }
+    }
+
+    fn push_array_target(
+        &mut self,
+        target: ArrayAssignmentTarget<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let ArrayAssignmentTarget { elements, rest, .. } = target;
+        let count = elements.len();
+        let kind = if rest.is_some() { ToArrayKind::Rest } else { ToArrayKind::Sliced(count) };
+        let mut array = self.create_array_ref(value, kind, ctx);
+        for (index, element) in elements.into_iter().enumerate() {
+            if let Some(element) = element {
+                let value = create_array_element_access(array.read(ctx), index, ctx);
+                self.push_maybe_default(element, value, ctx);
+            }
+        }
+        if let Some(rest) = rest {
+            let value = create_array_slice(array.read(ctx), count, ctx);
+            self.push(rest.unbox().target, value, ctx);
+        }
+        array.discard(ctx);
+    }
+}
+
+impl<'a> Destructure<'a> for AssignmentTargetDestructurer<'a, '_> {
+    fn transform_ctx(&self) -> &TransformCtx<'a> {
+        self.ctx
+    }
+
+    fn create_temp(
+        &mut self,
+        name: &str,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let binding = self.ctx.var_declarations.create_uid_var(name, ctx);
+        self.temps.push(binding.symbol_id);
+        let assignment = create_assignment(&binding, value, ctx);
+        self.expressions.push(assignment);
+        binding
+    }
+
+    fn is_static(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        if let Expression::Identifier(ident) = expr
+            && let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+            && self.temps.contains(&symbol_id)
+        {
+            return true;
+        }
+        is_static_expression(expr, ctx)
+    }
+}
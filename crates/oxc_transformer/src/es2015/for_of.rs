@@ -0,0 +1,488 @@
+//! ES2015: For Of
+//!
+//! This plugin transforms `for...of` loops into `for` loops which use the iterator protocol.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! for (const x of arr) {
+//!   console.log(x);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _iterator = _createForOfIteratorHelper(arr), _step;
+//! try {
+//!   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+//!     const x = _step.value;
+//!     console.log(x);
+//!   }
+//! } catch (err) {
+//!   _iterator.e(err);
+//! } finally {
+//!   _iterator.f();
+//! }
+//! ```
+//!
+//! With `loose` option or `skipForOfIteratorClosing` assumption, the iterator is not closed
+//! on abrupt completion:
+//! ```js
+//! for (var _iterator = _createForOfIteratorHelperLoose(arr), _step; !(_step = _iterator()).done;) {
+//!   const x = _step.value;
+//!   console.log(x);
+//! }
+//! ```
+//!
+//! With `assumeArray` option or `iterableIsArray` assumption, or if the iterated value is an array literal:
+//! ```js
+//! for (var _i = 0, _arr = arr; _i < _arr.length; _i++) {
+//!   const x = _arr[_i];
+//!   console.log(x);
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-for-of](https://babel.dev/docs/babel-plugin-transform-for-of).
+//!
+//! `for await` loops are transformed by the async generator functions transform, not here.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-for-of>
+//! * for...of: <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/for...of>
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+use serde::Deserialize;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::{create_assignment, create_member_callee},
+};
+
+use super::utils::{move_all_bindings, reparent_child_scopes};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ForOfOptions {
+    /// Don't close the iterator when the loop is exited early (e.g. with `break` or `throw`).
+    pub loose: bool,
+    /// Allow array-like objects (e.g. `arguments`) to be iterated, even if they are not iterable.
+    pub allow_array_like: bool,
+    /// Assume all iterated values are arrays, and iterate them with an index.
+    pub assume_array: bool,
+}
+
+pub struct ForOf<'a, 'ctx> {
+    options: ForOfOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ForOf<'a, 'ctx> {
+    pub fn new(options: ForOfOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ForOf<'a, '_> {
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Labeled loops are handled when exiting the outermost label,
+        // as the labels have to be kept on the loop
+        if matches!(ctx.parent(), Ancestor::LabeledStatementBody(_)) {
+            return;
+        }
+
+        let mut loop_stmt = &mut *stmt;
+        while let Statement::LabeledStatement(labeled) = loop_stmt {
+            loop_stmt = &mut labeled.body;
+        }
+        let Statement::ForOfStatement(for_of) = loop_stmt else { return };
+        if for_of.r#await {
+            return;
+        }
+
+        if self.options.assume_array
+            || self.ctx.assumptions.iterable_is_array
+            || matches!(for_of.right, Expression::ArrayExpression(_))
+        {
+            *loop_stmt = Self::transform_array(for_of, ctx);
+        } else if self.options.loose || self.ctx.assumptions.skip_for_of_iterator_closing {
+            *loop_stmt = self.transform_loose(for_of, ctx);
+        } else {
+            self.transform_spec(stmt, ctx);
+        }
+    }
+}
+
+impl<'a> ForOf<'a, '_> {
+    /// `for (const x of arr) {}` ->
+    /// `for (var _i = 0, _arr = arr; _i < _arr.length; _i++) { const x = _arr[_i]; }`
+    fn transform_array(
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let hoist_scope_id = ctx.current_hoist_scope_id();
+        let index = ctx.generate_uid("i", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+        let array = ctx.generate_uid("arr", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // `var _i = 0, _arr = arr`
+        let right = for_of.right.take_in(ctx.ast);
+        let init = ForStatementInit::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec_from_array([
+                Self::create_var_declarator(
+                    &index,
+                    Some(ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal)),
+                    ctx,
+                ),
+                Self::create_var_declarator(&array, Some(right), ctx),
+            ]),
+            false,
+        ));
+        // `_i < _arr.length`
+        let length = create_member_callee(array.create_read_expression(ctx), "length", ctx);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            index.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            length,
+        );
+        // `_i++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            index.create_read_write_simple_target(ctx),
+        );
+        // `_arr[_i]`
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            array.create_read_expression(ctx),
+            index.create_read_expression(ctx),
+            false,
+        ));
+
+        let for_scope_id = ctx.create_child_scope_of_current(ScopeFlags::empty());
+        let body = Self::create_body(for_of, value, for_scope_id, ctx);
+        ctx.ast.statement_for_with_scope_id(
+            for_of.span,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_scope_id,
+        )
+    }
+
+    /// `for (const x of arr) {}` ->
+    /// `for (var _iterator = _createForOfIteratorHelperLoose(arr), _step; !(_step = _iterator()).done;) { const x = _step.value; }`
+    fn transform_loose(
+        &self,
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let hoist_scope_id = ctx.current_hoist_scope_id();
+        let iterator =
+            ctx.generate_uid("iterator", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+        let step = ctx.generate_uid("step", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // `var _iterator = _createForOfIteratorHelperLoose(arr), _step`
+        let helper_call = self.create_iterator_helper_call(
+            Helper::CreateForOfIteratorLoose,
+            for_of.right.take_in(ctx.ast),
+            ctx,
+        );
+        let init = ForStatementInit::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec_from_array([
+                Self::create_var_declarator(&iterator, Some(helper_call), ctx),
+                Self::create_var_declarator(&step, None, ctx),
+            ]),
+            false,
+        ));
+        // `!(_step = _iterator()).done`
+        let next = ctx.ast.expression_call(
+            SPAN,
+            iterator.create_read_expression(ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        let test = Self::create_not_done(&step, next, ctx);
+        // `_step.value`
+        let value = create_member_callee(step.create_read_expression(ctx), "value", ctx);
+
+        let for_scope_id = ctx.create_child_scope_of_current(ScopeFlags::empty());
+        let body = Self::create_body(for_of, value, for_scope_id, ctx);
+        ctx.ast.statement_for_with_scope_id(
+            for_of.span,
+            Some(init),
+            Some(test),
+            None,
+            body,
+            for_scope_id,
+        )
+    }
+
+    /// ```js
+    /// for (const x of arr) {}
+    /// ```
+    /// ->
+    /// ```js
+    /// var _iterator = _createForOfIteratorHelper(arr), _step;
+    /// try {
+    ///   for (_iterator.s(); !(_step = _iterator.n()).done;) { const x = _step.value; }
+    /// } catch (err) {
+    ///   _iterator.e(err);
+    /// } finally {
+    ///   _iterator.f();
+    /// }
+    /// ```
+    ///
+    /// `stmt` is the `for...of` statement, or a labeled statement containing it.
+    fn transform_spec(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let allow_multiple_statements = is_multiple_statements_allowed(ctx);
+        let parent_scope_id = if allow_multiple_statements {
+            ctx.current_scope_id()
+        } else {
+            ctx.create_child_scope_of_current(ScopeFlags::empty())
+        };
+        let hoist_scope_id = ctx.current_hoist_scope_id();
+        let iterator =
+            ctx.generate_uid("iterator", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+        let step = ctx.generate_uid("step", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+        let try_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+
+        let mut loop_stmt = &mut *stmt;
+        while let Statement::LabeledStatement(labeled) = loop_stmt {
+            loop_stmt = &mut labeled.body;
+        }
+        let Statement::ForOfStatement(for_of) = loop_stmt else { unreachable!() };
+
+        // `var _iterator = _createForOfIteratorHelper(arr), _step;`
+        let helper_call = self.create_iterator_helper_call(
+            Helper::CreateForOfIterator,
+            for_of.right.take_in(ctx.ast),
+            ctx,
+        );
+        let declaration = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec_from_array([
+                Self::create_var_declarator(&iterator, Some(helper_call), ctx),
+                Self::create_var_declarator(&step, None, ctx),
+            ]),
+            false,
+        ));
+
+        // `for (_iterator.s(); !(_step = _iterator.n()).done;) { const x = _step.value; }`
+        let init =
+            ForStatementInit::from(Self::create_iterator_method_call(&iterator, "s", None, ctx));
+        let next = Self::create_iterator_method_call(&iterator, "n", None, ctx);
+        let test = Self::create_not_done(&step, next, ctx);
+        let value = create_member_callee(step.create_read_expression(ctx), "value", ctx);
+        let for_scope_id = ctx.create_child_scope(try_scope_id, ScopeFlags::empty());
+        let body = Self::create_body(for_of, value, for_scope_id, ctx);
+        let span = for_of.span;
+        *loop_stmt = ctx.ast.statement_for_with_scope_id(
+            span,
+            Some(init),
+            Some(test),
+            None,
+            body,
+            for_scope_id,
+        );
+
+        // Labels in `stmt` are kept on the `for` statement
+        let block = ctx.ast.block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(stmt.take_in(ctx.ast)),
+            try_scope_id,
+        );
+
+        // `catch (err) { _iterator.e(err); }`
+        let catch_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::CatchClause);
+        let catch_block_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+        let err = ctx.generate_binding(
+            Atom::from("err"),
+            catch_block_scope_id,
+            SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+        );
+        let error_call = Self::create_iterator_method_call(
+            &iterator,
+            "e",
+            Some(err.create_read_expression(ctx)),
+            ctx,
+        );
+        let handler = ctx.ast.catch_clause_with_scope_id(
+            SPAN,
+            Some(ctx.ast.catch_parameter(SPAN, err.create_binding_pattern(ctx), NONE)),
+            ctx.ast.block_statement_with_scope_id(
+                SPAN,
+                ctx.ast.vec1(ctx.ast.statement_expression(SPAN, error_call)),
+                catch_block_scope_id,
+            ),
+            catch_scope_id,
+        );
+
+        // `finally { _iterator.f(); }`
+        let finally_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let finally_call = Self::create_iterator_method_call(&iterator, "f", None, ctx);
+        let finalizer = ctx.ast.block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, finally_call)),
+            finally_scope_id,
+        );
+
+        let try_stmt = ctx.ast.statement_try(span, block, Some(handler), Some(finalizer));
+        if allow_multiple_statements {
+            self.ctx.statement_injector.insert_before(&try_stmt, declaration);
+            *stmt = try_stmt;
+        } else {
+            // `if (x) for (const a of b) {}` -> `if (x) { var _iterator = ...; try { ... } }`
+            *stmt = ctx.ast.statement_block_with_scope_id(
+                SPAN,
+                ctx.ast.vec_from_array([declaration, try_stmt]),
+                parent_scope_id,
+            );
+        }
+    }
+
+    /// Create body of the new `for` statement.
+    ///
+    /// `for (const x of arr) { body }` -> `{ const x = value; body }`
+    ///
+    /// Scope of the `for...of` statement, which contains bindings declared in left side,
+    /// becomes scope of the new body block. Body block of `for...of` is flattened into it,
+    /// unless it declares a binding with same name as left side.
+    fn create_body(
+        for_of: &mut ForOfStatement<'a>,
+        value: Expression<'a>,
+        for_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let body_scope_id = for_of.scope_id();
+        ctx.scoping_mut().change_scope_parent_id(body_scope_id, Some(for_scope_id));
+
+        let mut stmts = ctx.ast.vec();
+        let left_stmt = match &mut for_of.left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                // `const x = value`
+                decl.declarations[0].init = Some(value);
+                Statement::VariableDeclaration(decl.take_in_box(ctx.ast))
+            }
+            left @ match_assignment_target!(ForStatementLeft) => {
+                // `x = value`
+                let target = left.to_assignment_target_mut().take_in(ctx.ast);
+                ctx.ast.statement_expression(
+                    SPAN,
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value),
+                )
+            }
+        };
+        stmts.push(left_stmt);
+
+        match for_of.body.take_in(ctx.ast) {
+            Statement::BlockStatement(block)
+                if Self::can_flatten_block(&block, body_scope_id, ctx) =>
+            {
+                let block_scope_id = block.scope_id();
+                move_all_bindings(block_scope_id, body_scope_id, ctx);
+                reparent_child_scopes(&block.body, body_scope_id, ctx);
+                stmts.extend(block.unbox().body);
+            }
+            Statement::EmptyStatement(_) => {}
+            body => stmts.push(body),
+        }
+
+        ctx.ast.statement_block_with_scope_id(SPAN, stmts, body_scope_id)
+    }
+
+    /// Returns `true` if `block` doesn't declare any binding with same name as bindings in scope `scope_id`.
+    fn can_flatten_block(
+        block: &BlockStatement<'a>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let scoping = ctx.scoping();
+        scoping.iter_bindings_in(block.scope_id()).all(|symbol_id| {
+            scoping.get_binding(scope_id, scoping.symbol_name(symbol_id)).is_none()
+        })
+    }
+
+    /// `_createForOfIteratorHelper(arr)` or `_createForOfIteratorHelper(arr, true)` with `allowArrayLike` option.
+    fn create_iterator_helper_call(
+        &self,
+        helper: Helper,
+        right: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec1(Argument::from(right));
+        if self.options.allow_array_like {
+            arguments.push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+        }
+        self.ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+    }
+
+    /// `_iterator.method(argument)`
+    fn create_iterator_method_call(
+        iterator: &BoundIdentifier<'a>,
+        method: &'static str,
+        argument: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = create_member_callee(iterator.create_read_expression(ctx), method, ctx);
+        let arguments = ctx.ast.vec_from_iter(argument.map(Argument::from));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `!(_step = next).done`
+    fn create_not_done(
+        step: &BoundIdentifier<'a>,
+        next: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let assignment = ctx.ast.expression_parenthesized(SPAN, create_assignment(step, next, ctx));
+        let done = create_member_callee(assignment, "done", ctx);
+        ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, done)
+    }
+
+    fn create_var_declarator(
+        binding: &BoundIdentifier<'a>,
+        init: Option<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(ctx),
+            NONE,
+            init,
+            false,
+        )
+    }
+}
+
+/// Returns `true` if parent of current statement is a statement list.
+fn is_multiple_statements_allowed(ctx: &TraverseCtx<'_>) -> bool {
+    matches!(
+        ctx.parent(),
+        Ancestor::ProgramBody(_)
+            | Ancestor::FunctionBodyStatements(_)
+            | Ancestor::BlockStatementBody(_)
+            | Ancestor::SwitchCaseConsequent(_)
+            | Ancestor::StaticBlockBody(_)
+            | Ancestor::TSModuleBlockBody(_)
+    )
+}
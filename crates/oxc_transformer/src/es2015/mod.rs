@@ -1,29 +1,149 @@
+use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
 
-use crate::{context::TransformCtx, state::TransformState};
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
 
 mod arrow_functions;
+mod block_scoping;
+mod classes;
+mod destructuring;
+mod for_of;
 mod options;
+mod parameters;
+mod regenerator;
+mod spread;
+pub mod utils;
 
 pub use arrow_functions::{ArrowFunctions, ArrowFunctionsOptions};
+use block_scoping::BlockScoping;
+pub use block_scoping::BlockScopingOptions;
+use classes::Classes;
+use destructuring::Destructuring;
+use for_of::ForOf;
+pub use for_of::ForOfOptions;
 pub use options::ES2015Options;
+use parameters::Parameters;
+use regenerator::Regenerator;
+use spread::Spread;
 
 pub struct ES2015<'a, 'ctx> {
-    #[expect(unused)]
     options: ES2015Options,
 
     // Plugins
     #[expect(unused)]
     arrow_functions: ArrowFunctions<'a, 'ctx>,
+    block_scoping: BlockScoping<'a, 'ctx>,
+    classes: Classes<'a, 'ctx>,
+    destructuring: Destructuring<'a, 'ctx>,
+    for_of: ForOf<'a, 'ctx>,
+    parameters: Parameters<'a, 'ctx>,
+    regenerator: Regenerator<'a, 'ctx>,
+    spread: Spread<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> ES2015<'a, 'ctx> {
     pub fn new(options: ES2015Options, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             arrow_functions: ArrowFunctions::new(options.arrow_function.unwrap_or_default(), ctx),
+            block_scoping: BlockScoping::new(options.block_scoping.unwrap_or_default(), ctx),
+            classes: Classes::new(options.spread, ctx),
+            destructuring: Destructuring::new(ctx),
+            for_of: ForOf::new(options.for_of.unwrap_or_default(), ctx),
+            parameters: Parameters::new(
+                options.destructuring,
+                options.arrow_function.is_some(),
+                ctx,
+            ),
+            regenerator: Regenerator::new(ctx),
+            spread: Spread::new(ctx),
             options,
         }
     }
 }
 
-impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a, '_> {}
+impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_program(program, ctx);
+        }
+        if self.options.regenerator {
+            self.regenerator.exit_program(program, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.exit_statement(stmt, ctx);
+        }
+        if self.options.classes {
+            self.classes.exit_statement(stmt, ctx);
+        }
+        if self.options.for_of.is_some() {
+            self.for_of.exit_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.spread {
+            self.spread.exit_expression(expr, ctx);
+        }
+        if self.options.destructuring {
+            self.destructuring.exit_expression(expr, ctx);
+        }
+        if self.options.classes {
+            self.classes.exit_expression(expr, ctx);
+        }
+    }
+
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.destructuring {
+            self.destructuring.exit_variable_declaration(decl, ctx);
+        }
+    }
+
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.exit_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.exit_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.exit_catch_clause(clause, ctx);
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Parameters transform handles destructuring in params itself
+        if self.options.parameters {
+            self.parameters.exit_function(func, ctx);
+        } else if self.options.destructuring {
+            self.destructuring.exit_function(func, ctx);
+        }
+    }
+
+    fn exit_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.parameters {
+            self.parameters.exit_arrow_function_expression(arrow, ctx);
+        } else if self.options.destructuring {
+            self.destructuring.exit_arrow_function_expression(arrow, ctx);
+        }
+    }
+}
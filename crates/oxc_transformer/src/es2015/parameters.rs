@@ -0,0 +1,436 @@
+//! ES2015: Parameters
+//!
+//! This plugin transforms default parameters, destructuring parameters and rest parameters.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function f(a = 1, { b }, ...rest) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! function f() {
+//!   let a = arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : 1;
+//!   let { b } = arguments.length > 1 ? arguments[1] : undefined;
+//!   for (var _len = arguments.length, rest = new Array(_len > 2 ? _len - 2 : 0), _key = 2; _key < _len; _key++) {
+//!     rest[_key - 2] = arguments[_key];
+//!   }
+//! }
+//! ```
+//!
+//! If destructuring transform is also enabled, the `let` declarations created by this plugin
+//! are destructured too.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-parameters](https://babel.dev/docs/babel-plugin-transform-parameters).
+//!
+//! With `ignoreFunctionLength` assumption, and in setters, default parameters are kept as params:
+//! `function f(a = 1) {}` -> `function f(a) { if (a === void 0) { a = 1; } }`.
+//!
+//! Arrow functions can't use `arguments`. So unless arrow functions transform is enabled too
+//! (which converts arrow functions to function expressions), default parameters of arrow functions
+//! are transformed in that way, and rest parameters are not transformed.
+//!
+//! Differences from Babel:
+//! * Babel converts arrow functions with rest or default parameters to function expressions.
+//!   We don't, and rely on arrow functions transform instead.
+//! * Babel wraps body of generator functions, and functions whose parameters are redeclared in the
+//!   body, in an IIFE. We use `var` declarations for redeclared parameters instead, and don't wrap
+//!   generator functions.
+//! * Babel replaces `rest[i]` and `rest.length` with `arguments` accesses where possible.
+//!   We always create an array.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-parameters>
+//! * Default parameters: <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Default_parameters>
+//! * Rest parameters: <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/rest_parameters>
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::create_assignment,
+};
+
+use super::{
+    destructuring::{convert_arrow_expression_body_to_block, create_parameter_declaration},
+    utils::{create_is_undefined, create_number},
+};
+
+pub struct Parameters<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// `true` if destructuring transform is enabled
+    destructuring: bool,
+    /// `true` if arrow functions transform is enabled
+    arrow_functions: bool,
+}
+
+impl<'a, 'ctx> Parameters<'a, 'ctx> {
+    pub fn new(destructuring: bool, arrow_functions: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, destructuring, arrow_functions }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Parameters<'a, '_> {
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = func.scope_id();
+        let Some(body) = &mut func.body else { return };
+        if func.params.rest.is_none() && !has_complex_params(&func.params) {
+            return;
+        }
+        let loose = self.ctx.assumptions.ignore_function_length
+            || ctx.scoping().scope_flags(scope_id).is_set_accessor();
+        self.transform_params(&mut func.params, &mut body.statements, scope_id, loose, true, ctx);
+    }
+
+    fn exit_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let transform_rest = self.arrow_functions && arrow.params.rest.is_some();
+        if !transform_rest && !has_complex_params(&arrow.params) {
+            return;
+        }
+        convert_arrow_expression_body_to_block(arrow, ctx);
+        let scope_id = arrow.scope_id();
+        // `arguments` can only be used if the arrow function is converted to a function expression
+        let loose = self.ctx.assumptions.ignore_function_length || !self.arrow_functions;
+        self.transform_params(
+            &mut arrow.params,
+            &mut arrow.body.statements,
+            scope_id,
+            loose,
+            transform_rest,
+            ctx,
+        );
+    }
+}
+
+impl<'a> Parameters<'a, '_> {
+    /// Move default, destructuring and rest parameters into the function body.
+    ///
+    /// If `loose` is `true`, default parameters are kept as params, and assigned in the body if
+    /// they're `undefined`, instead of reading them from `arguments`.
+    fn transform_params(
+        &self,
+        params: &mut FormalParameters<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        loose: bool,
+        transform_rest: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut stmts = vec![];
+        let param_count = params.items.len();
+
+        // `function f(a = 1, { b }, c) {}`
+        let mut first_optional_index = None;
+        for (index, param) in params.items.iter_mut().enumerate() {
+            if let Some(default) = param.initializer.take() {
+                if loose {
+                    stmts.push(self.create_loose_default(param, default.unbox(), scope_id, ctx));
+                    continue;
+                }
+                first_optional_index.get_or_insert(index);
+                // `let a = arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : 1;`
+                let pattern = param.pattern.take_in(ctx.ast);
+                let init = Self::create_default_argument_access(index, default.unbox(), ctx);
+                stmts.push(self.create_declaration(pattern, init, scope_id, ctx));
+            } else if first_optional_index.is_some() {
+                // `let c = arguments.length > 2 ? arguments[2] : undefined;`
+                let pattern = param.pattern.take_in(ctx.ast);
+                let init = Self::create_optional_argument_access(index, ctx);
+                stmts.push(self.create_declaration(pattern, init, scope_id, ctx));
+            } else if !param.pattern.is_binding_identifier() {
+                // `function f({ b }) {}` -> `function f(_ref) { let { b } = _ref; }`
+                let temp = ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                let pattern =
+                    std::mem::replace(&mut param.pattern, temp.create_binding_pattern(ctx));
+                let init = temp.create_read_expression(ctx);
+                stmts.push(self.create_declaration(pattern, init, scope_id, ctx));
+            }
+        }
+        if let Some(index) = first_optional_index {
+            params.items.truncate(index);
+        }
+
+        // `function f(...rest) {}`
+        if transform_rest && let Some(rest) = params.rest.take() {
+            let pattern = rest.unbox().rest.argument;
+            self.transform_rest(pattern, param_count, scope_id, &mut stmts, ctx);
+        }
+
+        if !stmts.is_empty() {
+            body.splice(0..0, stmts);
+        }
+    }
+
+    /// Transform rest parameter, and push statements to `stmts`.
+    ///
+    /// `start` is the index of the rest parameter.
+    ///
+    /// `function f(a, ...rest) {}` ->
+    /// ```js
+    /// function f(a) {
+    ///   for (var _len = arguments.length, rest = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+    ///     rest[_key - 1] = arguments[_key];
+    ///   }
+    /// }
+    /// ```
+    fn transform_rest(
+        &self,
+        pattern: BindingPattern<'a>,
+        start: usize,
+        scope_id: ScopeId,
+        stmts: &mut Vec<Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `function f(...[a, b]) {}` -> `function f(..._ref) { let [a, b] = _ref; }`
+        let (rest_binding, rest_pattern, declaration) = match pattern {
+            BindingPattern::BindingIdentifier(ident) => (
+                BoundIdentifier::from_binding_ident(&ident),
+                BindingPattern::BindingIdentifier(ident),
+                None,
+            ),
+            pattern => {
+                let temp = ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                let init = temp.create_read_expression(ctx);
+                let declaration = self.create_declaration(pattern, init, scope_id, ctx);
+                (temp.clone(), temp.create_binding_pattern(ctx), Some(declaration))
+            }
+        };
+
+        let flags = SymbolFlags::FunctionScopedVariable;
+        let len = ctx.generate_uid("len", scope_id, flags);
+        let key = ctx.generate_uid("key", scope_id, flags);
+
+        // `_len = arguments.length`
+        let arguments_length = Self::create_arguments_length(ctx);
+        let len_declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            len.create_binding_pattern(ctx),
+            NONE,
+            Some(arguments_length),
+            false,
+        );
+
+        // `rest = new Array(_len > 1 ? _len - 1 : 0)`
+        let array_len = if start == 0 {
+            len.create_read_expression(ctx)
+        } else {
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                len.create_read_expression(ctx),
+                BinaryOperator::GreaterThan,
+                create_number(start, ctx),
+            );
+            let consequent = ctx.ast.expression_binary(
+                SPAN,
+                len.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                create_number(start, ctx),
+            );
+            ctx.ast.expression_conditional(SPAN, test, consequent, create_number(0, ctx))
+        };
+        let array = ctx.create_unbound_ident_expr(SPAN, Atom::from("Array"), ReferenceFlags::Read);
+        let new_array =
+            ctx.ast.expression_new(SPAN, array, NONE, ctx.ast.vec1(Argument::from(array_len)));
+        let rest_declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            rest_pattern,
+            NONE,
+            Some(new_array),
+            false,
+        );
+
+        // `_key = 1`
+        let key_declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            key.create_binding_pattern(ctx),
+            NONE,
+            Some(create_number(start, ctx)),
+            false,
+        );
+
+        let init = ForStatementInit::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec_from_array([len_declarator, rest_declarator, key_declarator]),
+            false,
+        ));
+
+        // `_key < _len`
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            key.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            len.create_read_expression(ctx),
+        );
+
+        // `_key++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            key.create_read_write_simple_target(ctx),
+        );
+
+        // `rest[_key - 1] = arguments[_key];`
+        let array_key = if start == 0 {
+            key.create_read_expression(ctx)
+        } else {
+            ctx.ast.expression_binary(
+                SPAN,
+                key.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                create_number(start, ctx),
+            )
+        };
+        let target = AssignmentTarget::from(ctx.ast.member_expression_computed(
+            SPAN,
+            rest_binding.create_read_expression(ctx),
+            array_key,
+            false,
+        ));
+        let value = Self::create_arguments_access(key.create_read_expression(ctx), ctx);
+        let assignment =
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+
+        let for_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        let block_scope_id = ctx.create_child_scope(for_scope_id, ScopeFlags::empty());
+        let body = ctx.ast.statement_block_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment)),
+            block_scope_id,
+        );
+        let for_stmt = ctx.ast.statement_for_with_scope_id(
+            SPAN,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_scope_id,
+        );
+
+        stmts.push(for_stmt);
+        stmts.extend(declaration);
+    }
+
+    /// `function f(a = 1) {}` -> `function f(a) { if (a === void 0) { a = 1; } }`
+    /// `function f({ a } = {}) {}` -> `function f(_ref) { let { a } = _ref === void 0 ? {} : _ref; }`
+    fn create_loose_default(
+        &self,
+        param: &mut FormalParameter<'a>,
+        default: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        if let BindingPattern::BindingIdentifier(ident) = &param.pattern {
+            let binding = BoundIdentifier::from_binding_ident(ident);
+            let test = create_is_undefined(binding.create_read_expression(ctx), ctx);
+            let assignment = create_assignment(&binding, default, ctx);
+            let block_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+            let consequent = ctx.ast.statement_block_with_scope_id(
+                SPAN,
+                ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment)),
+                block_scope_id,
+            );
+            return ctx.ast.statement_if(SPAN, test, consequent, None);
+        }
+
+        let temp = ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+        let pattern = std::mem::replace(&mut param.pattern, temp.create_binding_pattern(ctx));
+        let test = create_is_undefined(temp.create_read_expression(ctx), ctx);
+        let alternate = temp.create_read_expression(ctx);
+        let init = ctx.ast.expression_conditional(SPAN, test, default, alternate);
+        self.create_declaration(pattern, init, scope_id, ctx)
+    }
+
+    /// `let <pattern> = <init>;`
+    fn create_declaration(
+        &self,
+        pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        create_parameter_declaration(pattern, init, scope_id, self.destructuring, self.ctx, ctx)
+    }
+
+    /// `arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : default`
+    fn create_default_argument_access(
+        index: usize,
+        default: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let has_argument = Self::create_has_argument(index, ctx);
+        let argument = Self::create_arguments_access(create_number(index, ctx), ctx);
+        let undefined =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("undefined"), ReferenceFlags::Read);
+        let is_defined =
+            ctx.ast.expression_binary(SPAN, argument, BinaryOperator::StrictInequality, undefined);
+        let test = ctx.ast.expression_logical(SPAN, has_argument, LogicalOperator::And, is_defined);
+        let consequent = Self::create_arguments_access(create_number(index, ctx), ctx);
+        ctx.ast.expression_conditional(SPAN, test, consequent, default)
+    }
+
+    /// `arguments.length > 1 ? arguments[1] : undefined`
+    fn create_optional_argument_access(index: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let test = Self::create_has_argument(index, ctx);
+        let consequent = Self::create_arguments_access(create_number(index, ctx), ctx);
+        let alternate =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("undefined"), ReferenceFlags::Read);
+        ctx.ast.expression_conditional(SPAN, test, consequent, alternate)
+    }
+
+    /// `arguments.length > index`
+    fn create_has_argument(index: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let length = Self::create_arguments_length(ctx);
+        ctx.ast.expression_binary(
+            SPAN,
+            length,
+            BinaryOperator::GreaterThan,
+            create_number(index, ctx),
+        )
+    }
+
+    /// `arguments.length`
+    fn create_arguments_length(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let arguments = Self::create_arguments(ctx);
+        let property = ctx.ast.identifier_name(SPAN, "length");
+        Expression::from(ctx.ast.member_expression_static(SPAN, arguments, property, false))
+    }
+
+    /// `arguments[key]`
+    fn create_arguments_access(key: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let arguments = Self::create_arguments(ctx);
+        Expression::from(ctx.ast.member_expression_computed(SPAN, arguments, key, false))
+    }
+
+    /// `arguments`
+    fn create_arguments(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        ctx.create_unbound_ident_expr(SPAN, Atom::from("arguments"), ReferenceFlags::Read)
+    }
+}
+
+/// Returns `true` if any of the params has a default value or is a destructuring pattern.
+fn has_complex_params(params: &FormalParameters<'_>) -> bool {
+    params
+        .items
+        .iter()
+        .any(|param| param.initializer.is_some() || !param.pattern.is_binding_identifier())
+}
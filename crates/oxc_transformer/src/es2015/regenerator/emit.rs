@@ -0,0 +1,1373 @@
+//! Conversion of a generator function's body into a state machine.
+//!
+//! Statements are "exploded" into a flat list, in which every location that control can jump to is
+//! marked. The list is then split at marked locations into `case`s of a `switch` statement, which
+//! is run in a loop. `_context.next` holds the location to resume at.
+//!
+//! ```js
+//! while (x) {
+//!   yield x;
+//! }
+//! ```
+//! ->
+//! ```js
+//! while (1) switch (_context.prev = _context.next) {
+//!   case 0:
+//!     if (!x) {
+//!       _context.next = 5;
+//!       break;
+//!     }
+//!     _context.next = 3;
+//!     return x;
+//!   case 3:
+//!     _context.next = 0;
+//!     break;
+//!   case 5:
+//!   case "end":
+//!     return _context.stop();
+//! }
+//! ```
+//!
+//! Locations are not known until they are marked, so they are emitted as placeholders,
+//! which are replaced with their final values once the whole body has been exploded.
+//!
+//! Based on [regenerator-transform's `Emitter`](https://github.com/facebook/regenerator/blob/v0.15.2/packages/transform/src/emit.js).
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_traverse::{BoundIdentifier, MaybeBoundIdentifier};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    utils::ast_builder::create_member_callee,
+};
+
+use super::{
+    super::utils::{create_number, move_all_bindings, reparent_child_scopes},
+    runtime_property,
+};
+
+/// A location in the exploded statement list.
+#[derive(Debug, Clone, Copy)]
+struct Loc(usize);
+
+/// A `_context.tN` temporary variable.
+#[derive(Debug, Clone, Copy)]
+struct Temp(u32);
+
+struct TryEntry {
+    first_loc: Loc,
+    catch_loc: Option<Loc>,
+    /// Location of `finally` block, and location after the `try` statement
+    finally_locs: Option<(Loc, Loc)>,
+}
+
+/// An enclosing statement which `break` or `continue` can jump out of.
+struct LeapEntry<'a> {
+    break_loc: Loc,
+    continue_loc: Option<Loc>,
+    label: Option<Atom<'a>>,
+    /// Labeled statements are only targets of labeled `break`s
+    is_labeled_statement: bool,
+}
+
+pub(super) struct Emitter<'a, 'ctx, 'v> {
+    transform_ctx: &'ctx TransformCtx<'a>,
+    ctx: &'v mut TraverseCtx<'a>,
+    context: BoundIdentifier<'a>,
+    /// Scope of the `switch` statement, which all exploded statements end up in
+    switch_scope_id: ScopeId,
+    listing: Vec<Statement<'a>>,
+    /// Indexes in `listing` which are targets of jumps
+    marked: FxHashSet<usize>,
+    loc_values: Vec<Option<usize>>,
+    final_loc: Loc,
+    next_temp_id: u32,
+    try_entries: Vec<TryEntry>,
+    leap_entries: Vec<LeapEntry<'a>>,
+}
+
+impl<'a, 'ctx, 'v> Emitter<'a, 'ctx, 'v> {
+    pub fn new(
+        context: BoundIdentifier<'a>,
+        switch_scope_id: ScopeId,
+        transform_ctx: &'ctx TransformCtx<'a>,
+        ctx: &'v mut TraverseCtx<'a>,
+    ) -> Self {
+        let mut marked = FxHashSet::default();
+        marked.insert(0);
+        Self {
+            transform_ctx,
+            ctx,
+            context,
+            switch_scope_id,
+            listing: vec![],
+            marked,
+            loc_values: vec![None],
+            final_loc: Loc(0),
+            next_temp_id: 0,
+            try_entries: vec![],
+            leap_entries: vec![],
+        }
+    }
+
+    pub fn explode_statements(&mut self, stmts: ArenaVec<'a, Statement<'a>>) {
+        for stmt in stmts {
+            self.explode_statement(stmt, None);
+        }
+    }
+
+    /// Build the dispatch loop, and the list of `try` locations to pass to `regeneratorRuntime().wrap`.
+    ///
+    /// `while (1) switch (_context.prev = _context.next) { ... }`
+    pub fn finish(mut self) -> (Statement<'a>, Option<Expression<'a>>) {
+        let listing = std::mem::take(&mut self.listing);
+        let final_index = listing.len();
+        self.loc_values[self.final_loc.0] = Some(final_index);
+
+        let mut cases = self.ctx.ast.vec();
+        let mut current: Option<(usize, ArenaVec<'a, Statement<'a>>)> = None;
+        let mut already_ended = false;
+        for (index, stmt) in listing.into_iter().enumerate() {
+            if self.marked.contains(&index) {
+                if let Some((index, consequent)) = current.take() {
+                    cases.push(self.create_case(index, consequent));
+                }
+                current = Some((index, self.ctx.ast.vec()));
+                already_ended = false;
+            }
+            if already_ended {
+                // Unreachable statement
+                ReferenceDeleter { ctx: &mut *self.ctx }.visit_statement(&stmt);
+                continue;
+            }
+            already_ended = matches!(
+                stmt,
+                Statement::BreakStatement(_)
+                    | Statement::ContinueStatement(_)
+                    | Statement::ReturnStatement(_)
+                    | Statement::ThrowStatement(_)
+            );
+            current.as_mut().unwrap().1.push(stmt);
+        }
+        if let Some((index, consequent)) = current.take() {
+            cases.push(self.create_case(index, consequent));
+        }
+
+        // `case 5: case "end": return _context.stop();`
+        cases.push(self.create_case(final_index, self.ctx.ast.vec()));
+        let stop = self.context_call("stop", self.ctx.ast.vec());
+        let end = self.ctx.ast.expression_string_literal(SPAN, "end", None);
+        let consequent = self.ctx.ast.vec1(self.ctx.ast.statement_return(SPAN, Some(stop)));
+        cases.push(self.ctx.ast.switch_case(SPAN, Some(end), consequent));
+
+        // `_context.prev = _context.next`
+        let prev = self.context_property_target("prev");
+        let next = self.context_property("next");
+        let discriminant =
+            self.ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, prev, next);
+        let mut switch = self.ctx.ast.statement_switch_with_scope_id(
+            SPAN,
+            discriminant,
+            cases,
+            self.switch_scope_id,
+        );
+        LocResolver { loc_values: &self.loc_values }.visit_statement(&mut switch);
+        let dispatch_loop = self.ctx.ast.statement_while(SPAN, create_number(1, self.ctx), switch);
+
+        (dispatch_loop, self.create_try_locs_list())
+    }
+
+    fn create_case(
+        &mut self,
+        index: usize,
+        consequent: ArenaVec<'a, Statement<'a>>,
+    ) -> SwitchCase<'a> {
+        reparent_child_scopes(&consequent, self.switch_scope_id, self.ctx);
+        let test = create_number(index, self.ctx);
+        self.ctx.ast.switch_case(SPAN, Some(test), consequent)
+    }
+
+    /// `[[0, 5], [1, , 8, 10]]`
+    fn create_try_locs_list(&self) -> Option<Expression<'a>> {
+        if self.try_entries.is_empty() {
+            return None;
+        }
+        let ast = self.ctx.ast;
+        let entries = ast.vec_from_iter(self.try_entries.iter().map(|entry| {
+            let mut locs = ast.vec_with_capacity(4);
+            locs.push(ArrayExpressionElement::from(self.resolved_loc(entry.first_loc)));
+            locs.push(match entry.catch_loc {
+                Some(loc) => ArrayExpressionElement::from(self.resolved_loc(loc)),
+                None => ast.array_expression_element_elision(SPAN),
+            });
+            if let Some((finally_loc, after_loc)) = entry.finally_locs {
+                locs.push(ArrayExpressionElement::from(self.resolved_loc(finally_loc)));
+                locs.push(ArrayExpressionElement::from(self.resolved_loc(after_loc)));
+            } else if entry.catch_loc.is_none() {
+                locs.pop();
+            }
+            ArrayExpressionElement::from(ast.expression_array(SPAN, locs))
+        }));
+        Some(ast.expression_array(SPAN, entries))
+    }
+
+    fn resolved_loc(&self, loc: Loc) -> Expression<'a> {
+        create_number(self.loc_values[loc.0].unwrap(), self.ctx)
+    }
+}
+
+// Locations, temporary variables, and emitting statements
+impl<'a> Emitter<'a, '_, '_> {
+    /// Create a new location, which has not been marked yet.
+    fn loc(&mut self) -> Loc {
+        self.loc_values.push(None);
+        Loc(self.loc_values.len() - 1)
+    }
+
+    /// Create a location pointing at the next statement to be emitted, without marking it.
+    fn unmarked_current_loc(&mut self) -> Loc {
+        self.loc_values.push(Some(self.listing.len()));
+        Loc(self.loc_values.len() - 1)
+    }
+
+    /// Set `loc` to point at the next statement to be emitted.
+    fn mark(&mut self, loc: Loc) {
+        let index = self.listing.len();
+        debug_assert!(self.loc_values[loc.0].is_none_or(|value| value == index));
+        self.loc_values[loc.0] = Some(index);
+        self.marked.insert(index);
+    }
+
+    /// Placeholder for `loc`, replaced with its value in [`Emitter::finish`].
+    fn loc_expr(&self, loc: Loc) -> Expression<'a> {
+        #[expect(clippy::cast_precision_loss)]
+        let value = -((loc.0 + 1) as f64);
+        self.ctx.ast.expression_numeric_literal(SPAN, value, None, NumberBase::Decimal)
+    }
+
+    fn emit(&mut self, stmt: Statement<'a>) {
+        self.listing.push(stmt);
+    }
+
+    fn emit_expression(&mut self, expr: Expression<'a>) {
+        let stmt = self.ctx.ast.statement_expression(expr.span(), expr);
+        self.emit(stmt);
+    }
+
+    fn emit_assign(&mut self, target: AssignmentTarget<'a>, value: Expression<'a>) {
+        let assignment =
+            self.ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        self.emit_expression(assignment);
+    }
+
+    fn make_temp(&mut self) -> Temp {
+        let temp = Temp(self.next_temp_id);
+        self.next_temp_id += 1;
+        temp
+    }
+
+    fn temp_name(&self, temp: Temp) -> Atom<'a> {
+        self.ctx.ast.atom(&format!("t{}", temp.0))
+    }
+
+    /// `_context.t0`
+    fn temp_expr(&mut self, temp: Temp) -> Expression<'a> {
+        let property = self.ctx.ast.identifier_name(SPAN, self.temp_name(temp));
+        let object = self.context.create_read_expression(self.ctx);
+        Expression::from(self.ctx.ast.member_expression_static(SPAN, object, property, false))
+    }
+
+    fn temp_target(&mut self, temp: Temp) -> AssignmentTarget<'a> {
+        let property = self.ctx.ast.identifier_name(SPAN, self.temp_name(temp));
+        let object = self.context.create_read_expression(self.ctx);
+        AssignmentTarget::from(SimpleAssignmentTarget::from(
+            self.ctx.ast.member_expression_static(SPAN, object, property, false),
+        ))
+    }
+
+    /// `_context.t0 = value;`
+    fn emit_assign_temp(&mut self, temp: Temp, value: Expression<'a>) {
+        let target = self.temp_target(temp);
+        self.emit_assign(target, value);
+    }
+
+    /// `_context.name`
+    fn context_property(&mut self, name: &'static str) -> Expression<'a> {
+        create_member_callee(self.context.create_read_expression(self.ctx), name, self.ctx)
+    }
+
+    fn context_property_target(&mut self, name: &'static str) -> AssignmentTarget<'a> {
+        let property = self.ctx.ast.identifier_name(SPAN, Atom::from(name));
+        let object = self.context.create_read_expression(self.ctx);
+        AssignmentTarget::from(SimpleAssignmentTarget::from(
+            self.ctx.ast.member_expression_static(SPAN, object, property, false),
+        ))
+    }
+
+    /// `_context.name(...arguments)`
+    fn context_call(
+        &mut self,
+        name: &'static str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        let callee = self.context_property(name);
+        self.ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `_context.next = loc; break;`
+    fn jump(&mut self, loc: Loc) {
+        let loc = self.loc_expr(loc);
+        self.jump_to_expression(loc);
+    }
+
+    fn jump_to_expression(&mut self, loc: Expression<'a>) {
+        let target = self.context_property_target("next");
+        self.emit_assign(target, loc);
+        let stmt = self.ctx.ast.statement_break(SPAN, None);
+        self.emit(stmt);
+    }
+
+    /// `if (test) { _context.next = loc; break; }`
+    fn jump_if(&mut self, test: Expression<'a>, loc: Loc) {
+        let target = self.context_property_target("next");
+        let assignment = self.ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            target,
+            self.loc_expr(loc),
+        );
+        let body = self.ctx.ast.vec_from_array([
+            self.ctx.ast.statement_expression(SPAN, assignment),
+            self.ctx.ast.statement_break(SPAN, None),
+        ]);
+        let scope_id = self.ctx.create_child_scope(self.switch_scope_id, ScopeFlags::empty());
+        let block = self.ctx.ast.statement_block_with_scope_id(SPAN, body, scope_id);
+        let stmt = self.ctx.ast.statement_if(SPAN, test, block, None);
+        self.emit(stmt);
+    }
+
+    /// `if (!test) { _context.next = loc; break; }`
+    fn jump_if_not(&mut self, test: Expression<'a>, loc: Loc) {
+        let test = match test {
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                unary.unbox().argument
+            }
+            test => self.ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, test),
+        };
+        self.jump_if(test, loc);
+    }
+
+    /// `_context.prev = loc;`
+    fn update_context_prev_loc(&mut self, loc: Loc) {
+        let target = self.context_property_target("prev");
+        let loc = self.loc_expr(loc);
+        self.emit_assign(target, loc);
+    }
+
+    /// `return _context.abrupt("break", loc);`
+    fn emit_abrupt_completion(&mut self, kind: &'static str, value: Option<Expression<'a>>) {
+        let mut arguments = self.ctx.ast.vec_with_capacity(2);
+        arguments.push(Argument::from(self.ctx.ast.expression_string_literal(SPAN, kind, None)));
+        if let Some(value) = value {
+            arguments.push(Argument::from(value));
+        }
+        let call = self.context_call("abrupt", arguments);
+        let stmt = self.ctx.ast.statement_return(SPAN, Some(call));
+        self.emit(stmt);
+    }
+
+    fn find_leap_loc(&self, label: Option<&LabelIdentifier<'a>>, is_break: bool) -> Option<Loc> {
+        self.leap_entries.iter().rev().find_map(|entry| {
+            let loc = if is_break { Some(entry.break_loc) } else { entry.continue_loc };
+            match label {
+                Some(label) => loc.filter(|_| entry.label.is_some_and(|name| name == label.name)),
+                None => loc.filter(|_| !entry.is_labeled_statement),
+            }
+        })
+    }
+
+    fn with_leap_entry(&mut self, entry: LeapEntry<'a>, f: impl FnOnce(&mut Self)) {
+        self.leap_entries.push(entry);
+        f(self);
+        self.leap_entries.pop();
+    }
+
+    fn error(&self, message: &'static str, span: Span) {
+        self.transform_ctx.error(OxcDiagnostic::error(message).with_label(span));
+    }
+}
+
+// Statements
+impl<'a> Emitter<'a, '_, '_> {
+    fn explode_statement(&mut self, stmt: Statement<'a>, label: Option<Atom<'a>>) {
+        // Blocks are always flattened
+        if let Statement::BlockStatement(block) = stmt {
+            let block = block.unbox();
+            move_all_bindings(block.scope_id(), self.switch_scope_id, self.ctx);
+            self.explode_statements(block.body);
+            return;
+        }
+
+        if !contains_leap(|finder| finder.visit_statement(&stmt)) {
+            self.emit(stmt);
+            return;
+        }
+
+        match stmt {
+            Statement::ExpressionStatement(stmt) => {
+                self.explode_expression_ignoring_result(stmt.unbox().expression);
+            }
+            Statement::LabeledStatement(stmt) => {
+                let LabeledStatement { label, body, .. } = stmt.unbox();
+                let after = self.loc();
+                let entry = LeapEntry {
+                    break_loc: after,
+                    continue_loc: None,
+                    label: Some(label.name.into()),
+                    is_labeled_statement: true,
+                };
+                self.with_leap_entry(entry, |emitter| {
+                    emitter.explode_statement(body, Some(label.name.into()));
+                });
+                self.mark(after);
+            }
+            Statement::WhileStatement(stmt) => {
+                let WhileStatement { test, body, .. } = stmt.unbox();
+                let before = self.loc();
+                let after = self.loc();
+                self.mark(before);
+                let test = self.explode_expression(test);
+                self.jump_if_not(test, after);
+                let entry = LeapEntry {
+                    break_loc: after,
+                    continue_loc: Some(before),
+                    label,
+                    is_labeled_statement: false,
+                };
+                self.with_leap_entry(entry, |emitter| emitter.explode_statement(body, None));
+                self.jump(before);
+                self.mark(after);
+            }
+            Statement::DoWhileStatement(stmt) => {
+                let DoWhileStatement { body, test, .. } = stmt.unbox();
+                let first = self.loc();
+                let test_loc = self.loc();
+                let after = self.loc();
+                self.mark(first);
+                let entry = LeapEntry {
+                    break_loc: after,
+                    continue_loc: Some(test_loc),
+                    label,
+                    is_labeled_statement: false,
+                };
+                self.with_leap_entry(entry, |emitter| emitter.explode_statement(body, None));
+                self.mark(test_loc);
+                let test = self.explode_expression(test);
+                self.jump_if(test, first);
+                self.mark(after);
+            }
+            Statement::ForStatement(stmt) => {
+                let stmt = stmt.unbox();
+                move_all_bindings(stmt.scope_id(), self.switch_scope_id, self.ctx);
+                let ForStatement { init, test, update, body, .. } = stmt;
+                let head = self.loc();
+                let update_loc = self.loc();
+                let after = self.loc();
+                if let Some(init) = init {
+                    match init {
+                        ForStatementInit::VariableDeclaration(decl) => {
+                            // Declarations are hoisted before exploding, so this is unreachable
+                            self.emit(Statement::VariableDeclaration(decl));
+                        }
+                        init => self.explode_expression_ignoring_result(init.into_expression()),
+                    }
+                }
+                self.mark(head);
+                if let Some(test) = test {
+                    let test = self.explode_expression(test);
+                    self.jump_if_not(test, after);
+                }
+                let entry = LeapEntry {
+                    break_loc: after,
+                    continue_loc: Some(update_loc),
+                    label,
+                    is_labeled_statement: false,
+                };
+                self.with_leap_entry(entry, |emitter| emitter.explode_statement(body, None));
+                self.mark(update_loc);
+                if let Some(update) = update {
+                    self.explode_expression_ignoring_result(update);
+                }
+                self.jump(head);
+                self.mark(after);
+            }
+            Statement::ForInStatement(stmt) => {
+                let stmt = stmt.unbox();
+                move_all_bindings(stmt.scope_id(), self.switch_scope_id, self.ctx);
+                let ForInStatement { left, right, body, .. } = stmt;
+                let head = self.loc();
+                let after = self.loc();
+
+                // `_context.t0 = regeneratorRuntime().keys(right);`
+                let key_iter_next_fn = self.make_temp();
+                let right = self.explode_expression(right);
+                let keys = runtime_property("keys", self.transform_ctx, self.ctx);
+                let keys = self.ctx.ast.expression_call(
+                    SPAN,
+                    keys,
+                    NONE,
+                    self.ctx.ast.vec1(Argument::from(right)),
+                    false,
+                );
+                self.emit_assign_temp(key_iter_next_fn, keys);
+
+                // `if ((_context.t1 = _context.t0()).done) { _context.next = after; break; }`
+                self.mark(head);
+                let key_info = self.make_temp();
+                let next_key = self.ctx.ast.expression_call(
+                    SPAN,
+                    self.temp_expr(key_iter_next_fn),
+                    NONE,
+                    self.ctx.ast.vec(),
+                    false,
+                );
+                let next_key = self.ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    self.temp_target(key_info),
+                    next_key,
+                );
+                let next_key = self.ctx.ast.expression_parenthesized(SPAN, next_key);
+                let done = create_member_callee(next_key, "done", self.ctx);
+                self.jump_if(done, after);
+
+                // `left = _context.t1.value;`
+                let left = match left {
+                    ForStatementLeft::VariableDeclaration(decl) => {
+                        // Declarations are hoisted before exploding, so this is unreachable
+                        self.error("Unexpected declaration in generator function.", decl.span);
+                        return;
+                    }
+                    left => left.into_assignment_target(),
+                };
+                let value = create_member_callee(self.temp_expr(key_info), "value", self.ctx);
+                self.emit_assign(left, value);
+
+                let entry = LeapEntry {
+                    break_loc: after,
+                    continue_loc: Some(head),
+                    label,
+                    is_labeled_statement: false,
+                };
+                self.with_leap_entry(entry, |emitter| emitter.explode_statement(body, None));
+                self.jump(head);
+                self.mark(after);
+            }
+            Statement::BreakStatement(stmt) => {
+                let loc = self.find_leap_loc(stmt.label.as_ref(), true);
+                self.emit_jump_completion("break", loc, stmt.span);
+            }
+            Statement::ContinueStatement(stmt) => {
+                let loc = self.find_leap_loc(stmt.label.as_ref(), false);
+                self.emit_jump_completion("continue", loc, stmt.span);
+            }
+            Statement::SwitchStatement(stmt) => self.explode_switch_statement(stmt.unbox()),
+            Statement::IfStatement(stmt) => {
+                let IfStatement { test, consequent, alternate, .. } = stmt.unbox();
+                let else_loc = alternate.as_ref().map(|_| self.loc());
+                let after = self.loc();
+                let test = self.explode_expression(test);
+                self.jump_if_not(test, else_loc.unwrap_or(after));
+                self.explode_statement(consequent, None);
+                if let Some(alternate) = alternate {
+                    self.jump(after);
+                    self.mark(else_loc.unwrap());
+                    self.explode_statement(alternate, None);
+                }
+                self.mark(after);
+            }
+            Statement::ReturnStatement(stmt) => {
+                let value = stmt.unbox().argument.map(|argument| self.explode_expression(argument));
+                self.emit_abrupt_completion("return", value);
+            }
+            Statement::TryStatement(stmt) => self.explode_try_statement(stmt.unbox()),
+            Statement::ThrowStatement(stmt) => {
+                let ThrowStatement { span, argument } = stmt.unbox();
+                let argument = self.explode_expression(argument);
+                let stmt = self.ctx.ast.statement_throw(span, argument);
+                self.emit(stmt);
+            }
+            stmt => {
+                self.error("Cannot transform this statement in a generator function.", stmt.span());
+                self.emit(stmt);
+            }
+        }
+    }
+
+    /// `return _context.abrupt("break", loc);`
+    fn emit_jump_completion(&mut self, kind: &'static str, loc: Option<Loc>, span: Span) {
+        let Some(loc) = loc else {
+            self.error("Cannot find target of this statement.", span);
+            return;
+        };
+        let loc = self.loc_expr(loc);
+        self.emit_abrupt_completion(kind, Some(loc));
+    }
+
+    /// ```js
+    /// switch (x) {
+    ///   case a: foo();
+    ///   default: bar();
+    /// }
+    /// ```
+    /// ->
+    /// ```js
+    /// _context.t0 = x;
+    /// _context.next = _context.t0 === a ? 1 : 2;
+    /// break;
+    /// case 1: foo();
+    /// case 2: bar();
+    /// ```
+    fn explode_switch_statement(&mut self, stmt: SwitchStatement<'a>) {
+        move_all_bindings(stmt.scope_id(), self.switch_scope_id, self.ctx);
+        let SwitchStatement { discriminant, cases, .. } = stmt;
+
+        // Always save the discriminant into a temporary variable, in case the tests overwrite
+        // values like `_context.sent`
+        let temp = self.make_temp();
+        let discriminant = self.explode_expression(discriminant);
+        self.emit_assign_temp(temp, discriminant);
+
+        let after = self.loc();
+        let default_loc = self.loc();
+        let mut condition = self.loc_expr(default_loc);
+        let mut case_locs = vec![default_loc; cases.len()];
+        let mut cases = cases.into_iter().collect::<Vec<_>>();
+        for (case, case_loc) in cases.iter_mut().zip(&mut case_locs).rev() {
+            if let Some(test) = case.test.take() {
+                *case_loc = self.loc();
+                let test = self.ctx.ast.expression_binary(
+                    SPAN,
+                    self.temp_expr(temp),
+                    BinaryOperator::StrictEquality,
+                    test,
+                );
+                condition = self.ctx.ast.expression_conditional(
+                    SPAN,
+                    test,
+                    self.loc_expr(*case_loc),
+                    condition,
+                );
+            }
+        }
+        let condition = self.explode_expression(condition);
+        self.jump_to_expression(condition);
+
+        let entry = LeapEntry {
+            break_loc: after,
+            continue_loc: None,
+            label: None,
+            is_labeled_statement: false,
+        };
+        self.with_leap_entry(entry, |emitter| {
+            for (case, case_loc) in cases.into_iter().zip(case_locs) {
+                emitter.mark(case_loc);
+                emitter.explode_statements(case.consequent);
+            }
+        });
+        self.mark(after);
+        if self.loc_values[default_loc.0].is_none() {
+            self.mark(default_loc);
+        }
+    }
+
+    fn explode_try_statement(&mut self, stmt: TryStatement<'a>) {
+        let TryStatement { block, handler, finalizer, .. } = stmt;
+        let after = self.loc();
+        let catch_loc = handler.as_ref().map(|_| self.loc());
+        let finally_loc = finalizer.as_ref().map(|_| self.loc());
+        let first_loc = self.unmarked_current_loc();
+        self.try_entries.push(TryEntry {
+            first_loc,
+            catch_loc,
+            finally_locs: finally_loc.map(|finally_loc| (finally_loc, after)),
+        });
+        self.update_context_prev_loc(first_loc);
+
+        self.explode_statement(Statement::BlockStatement(block), None);
+
+        if let (Some(handler), Some(catch_loc)) = (handler, catch_loc) {
+            // If there is a `finally` block, the `catch` block is emitted before it, so jump over it
+            self.jump(finally_loc.unwrap_or(after));
+            self.mark(catch_loc);
+            self.update_context_prev_loc(catch_loc);
+
+            // `_context.t0 = _context["catch"](first_loc);`
+            let safe_param = self.make_temp();
+            let catch_property = self.ctx.ast.expression_string_literal(SPAN, "catch", None);
+            let callee = Expression::from(self.ctx.ast.member_expression_computed(
+                SPAN,
+                self.context.create_read_expression(self.ctx),
+                catch_property,
+                false,
+            ));
+            let arguments = self.ctx.ast.vec1(Argument::from(self.loc_expr(first_loc)));
+            let catch_call = self.ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            self.emit_assign_temp(safe_param, catch_call);
+
+            let catch_scope_id = handler.scope_id();
+            let CatchClause { param, mut body, .. } = handler.unbox();
+            if let Some(param) = param {
+                match &param.pattern {
+                    BindingPattern::BindingIdentifier(ident) => {
+                        let symbol_id = ident.symbol_id();
+                        CatchParamReplacer { emitter: self, symbol_id, temp: safe_param }
+                            .visit_block_statement(&mut body);
+                        let scope_id = self.ctx.scoping().symbol_scope_id(symbol_id);
+                        self.ctx.scoping_mut().remove_binding(scope_id, &ident.name);
+                    }
+                    pattern => {
+                        self.error(
+                            "Cannot transform destructuring catch parameter in a generator function.",
+                            pattern.span(),
+                        );
+                    }
+                }
+            }
+            move_all_bindings(catch_scope_id, self.switch_scope_id, self.ctx);
+            self.explode_statement(Statement::BlockStatement(body), None);
+        }
+
+        if let Some(finalizer) = finalizer {
+            let finally_loc = finally_loc.unwrap();
+            self.mark(finally_loc);
+            self.update_context_prev_loc(finally_loc);
+            self.explode_statement(Statement::BlockStatement(finalizer), None);
+            // `return _context.finish(finally_loc);`
+            let arguments = self.ctx.ast.vec1(Argument::from(self.loc_expr(finally_loc)));
+            let finish = self.context_call("finish", arguments);
+            let stmt = self.ctx.ast.statement_return(SPAN, Some(finish));
+            self.emit(stmt);
+        }
+
+        self.mark(after);
+    }
+}
+
+// Expressions
+impl<'a> Emitter<'a, '_, '_> {
+    fn explode_expression(&mut self, expr: Expression<'a>) -> Expression<'a> {
+        self.explode_expression_impl(expr, false).unwrap()
+    }
+
+    fn explode_expression_ignoring_result(&mut self, expr: Expression<'a>) {
+        self.explode_expression_impl(expr, true);
+    }
+
+    /// Explode an expression.
+    ///
+    /// Returns the value of the expression, or `None` if `ignore_result` is `true`.
+    /// In that case, the expression is emitted as a statement if it has any side effects.
+    fn explode_expression_impl(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        if !contains_leap(|finder| finder.visit_expression(&expr)) {
+            return self.finish_expression(expr, ignore_result);
+        }
+
+        match expr {
+            Expression::ParenthesizedExpression(paren) => {
+                self.explode_expression_impl(paren.unbox().expression, ignore_result)
+            }
+            match_member_expression!(Expression) => {
+                let member = self.explode_member_expression(expr.into_member_expression());
+                self.finish_expression(Expression::from(member), ignore_result)
+            }
+            Expression::CallExpression(call) => {
+                let call = self.explode_call_expression(call.unbox());
+                self.finish_expression(call, ignore_result)
+            }
+            Expression::NewExpression(new_expr) => {
+                let NewExpression { span, callee, arguments, .. } = new_expr.unbox();
+                let callee = self.explode_via_temp(None, callee);
+                let arguments = self.explode_arguments(arguments);
+                let new_expr = self.ctx.ast.expression_new(span, callee, NONE, arguments);
+                self.finish_expression(new_expr, ignore_result)
+            }
+            Expression::ObjectExpression(object) => {
+                let ObjectExpression { span, properties } = object.unbox();
+                let properties = self.ctx.ast.vec_from_iter(properties.into_iter().map(
+                    |property| match property {
+                        ObjectPropertyKind::ObjectProperty(mut property)
+                            if property.kind == PropertyKind::Init && !property.method =>
+                        {
+                            if property.computed
+                                && let Some(key) = property.key.as_expression_mut()
+                            {
+                                let key_expr = key.take_in(self.ctx.ast);
+                                *key = self.explode_via_temp(None, key_expr);
+                            }
+                            let value = property.value.take_in(self.ctx.ast);
+                            property.value = self.explode_via_temp(None, value);
+                            ObjectPropertyKind::ObjectProperty(property)
+                        }
+                        ObjectPropertyKind::SpreadProperty(mut spread) => {
+                            let argument = spread.argument.take_in(self.ctx.ast);
+                            spread.argument = self.explode_via_temp(None, argument);
+                            ObjectPropertyKind::SpreadProperty(spread)
+                        }
+                        property @ ObjectPropertyKind::ObjectProperty(_) => property,
+                    },
+                ));
+                let object = self.ctx.ast.expression_object(span, properties);
+                self.finish_expression(object, ignore_result)
+            }
+            Expression::ArrayExpression(array) => {
+                let ArrayExpression { span, elements } = array.unbox();
+                let elements =
+                    self.ctx.ast.vec_from_iter(elements.into_iter().map(|element| match element {
+                        ArrayExpressionElement::Elision(_) => element,
+                        ArrayExpressionElement::SpreadElement(mut spread) => {
+                            let argument = spread.argument.take_in(self.ctx.ast);
+                            spread.argument = self.explode_via_temp(None, argument);
+                            ArrayExpressionElement::SpreadElement(spread)
+                        }
+                        element => ArrayExpressionElement::from(
+                            self.explode_via_temp(None, element.into_expression()),
+                        ),
+                    }));
+                let array = self.ctx.ast.expression_array(span, elements);
+                self.finish_expression(array, ignore_result)
+            }
+            Expression::TemplateLiteral(template) => {
+                let TemplateLiteral { span, quasis, expressions } = template.unbox();
+                let expressions = self.ctx.ast.vec_from_iter(
+                    expressions.into_iter().map(|expr| self.explode_via_temp(None, expr)),
+                );
+                let template = self.ctx.ast.expression_template_literal(span, quasis, expressions);
+                self.finish_expression(template, ignore_result)
+            }
+            Expression::SequenceExpression(sequence) => {
+                let mut expressions = sequence.unbox().expressions;
+                let last = expressions.pop().unwrap();
+                for expr in expressions {
+                    self.explode_expression_ignoring_result(expr);
+                }
+                self.explode_expression_impl(last, ignore_result)
+            }
+            Expression::LogicalExpression(logical) => {
+                let LogicalExpression { left, operator, right, .. } = logical.unbox();
+                let after = self.loc();
+                let result = (!ignore_result).then(|| self.make_temp());
+                let left = self.explode_via_temp(result, left);
+                match operator {
+                    LogicalOperator::And => self.jump_if_not(left, after),
+                    LogicalOperator::Or => self.jump_if(left, after),
+                    LogicalOperator::Coalesce => {
+                        let test = self.ctx.ast.expression_binary(
+                            SPAN,
+                            left,
+                            BinaryOperator::Inequality,
+                            self.ctx.ast.expression_null_literal(SPAN),
+                        );
+                        self.jump_if(test, after);
+                    }
+                }
+                self.explode_into_temp(result, right);
+                self.mark(after);
+                result.map(|result| self.temp_expr(result))
+            }
+            Expression::ConditionalExpression(conditional) => {
+                let ConditionalExpression { test, consequent, alternate, .. } = conditional.unbox();
+                let else_loc = self.loc();
+                let after = self.loc();
+                let test = self.explode_expression(test);
+                self.jump_if_not(test, else_loc);
+                let result = (!ignore_result).then(|| self.make_temp());
+                self.explode_into_temp(result, consequent);
+                self.jump(after);
+                self.mark(else_loc);
+                self.explode_into_temp(result, alternate);
+                self.mark(after);
+                result.map(|result| self.temp_expr(result))
+            }
+            Expression::UnaryExpression(unary) => {
+                let UnaryExpression { span, operator, argument } = unary.unbox();
+                // Argument is not broken up, to keep e.g. `delete a[b]` intact
+                let argument = self.explode_expression(argument);
+                let unary = self.ctx.ast.expression_unary(span, operator, argument);
+                self.finish_expression(unary, ignore_result)
+            }
+            Expression::BinaryExpression(binary) => {
+                let BinaryExpression { span, left, operator, right } = binary.unbox();
+                let left = self.explode_via_temp(None, left);
+                let right = self.explode_via_temp(None, right);
+                let binary = self.ctx.ast.expression_binary(span, left, operator, right);
+                self.finish_expression(binary, ignore_result)
+            }
+            Expression::AssignmentExpression(assignment) => {
+                let assignment = self.explode_assignment_expression(assignment.unbox());
+                self.finish_expression(assignment, ignore_result)
+            }
+            Expression::UpdateExpression(update) => {
+                let UpdateExpression { span, operator, prefix, argument } = update.unbox();
+                let argument = self.explode_simple_assignment_target(argument);
+                let update = self.ctx.ast.expression_update(span, operator, prefix, argument);
+                self.finish_expression(update, ignore_result)
+            }
+            Expression::YieldExpression(yield_expr) => {
+                let YieldExpression { span, delegate, argument } = yield_expr.unbox();
+                let after = self.loc();
+                let argument = argument.map(|argument| self.explode_expression(argument));
+                if delegate && let Some(argument) = argument {
+                    // `return _context.delegateYield(argument, "t0", after);`
+                    let result = self.make_temp();
+                    let result_name = self.temp_name(result);
+                    let arguments = self.ctx.ast.vec_from_array([
+                        Argument::from(argument),
+                        Argument::from(self.ctx.ast.expression_string_literal(
+                            SPAN,
+                            result_name,
+                            None,
+                        )),
+                        Argument::from(self.loc_expr(after)),
+                    ]);
+                    let delegate_yield = self.context_call("delegateYield", arguments);
+                    let stmt = self.ctx.ast.statement_return(span, Some(delegate_yield));
+                    self.emit(stmt);
+                    self.mark(after);
+                    return (!ignore_result).then(|| self.temp_expr(result));
+                }
+
+                // `_context.next = after; return argument;`
+                let target = self.context_property_target("next");
+                let loc = self.loc_expr(after);
+                self.emit_assign(target, loc);
+                let stmt = self.ctx.ast.statement_return(span, argument);
+                self.emit(stmt);
+                self.mark(after);
+                (!ignore_result).then(|| self.context_property("sent"))
+            }
+            expr => {
+                self.error(
+                    "Cannot transform this expression containing `yield` in a generator function.",
+                    expr.span(),
+                );
+                self.finish_expression(expr, ignore_result)
+            }
+        }
+    }
+
+    fn finish_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        if ignore_result {
+            self.emit_expression(expr);
+            None
+        } else {
+            Some(expr)
+        }
+    }
+
+    /// Explode `expr`, and assign the result to a temporary variable (unless it's a literal).
+    ///
+    /// Used for subexpressions of an expression containing a `yield`, so that their side effects
+    /// happen in the correct order relative to the `yield`. It's hard to prove whether a
+    /// subexpression evaluates to the same value before and after the `yield`,
+    /// so a temporary variable is required.
+    fn explode_via_temp(&mut self, temp: Option<Temp>, expr: Expression<'a>) -> Expression<'a> {
+        let result = self.explode_expression(expr);
+        let temp = match temp {
+            Some(temp) => temp,
+            None if !result.is_literal() => self.make_temp(),
+            None => return result,
+        };
+        self.emit_assign_temp(temp, result);
+        self.temp_expr(temp)
+    }
+
+    /// Explode `expr`, and assign the result to `temp`.
+    /// If there is no `temp`, the result is not used.
+    fn explode_into_temp(&mut self, temp: Option<Temp>, expr: Expression<'a>) {
+        match temp {
+            Some(temp) => {
+                let result = self.explode_expression(expr);
+                self.emit_assign_temp(temp, result);
+            }
+            None => {
+                self.explode_expression_impl(expr, true);
+            }
+        }
+    }
+
+    fn explode_arguments(
+        &mut self,
+        arguments: ArenaVec<'a, Argument<'a>>,
+    ) -> ArenaVec<'a, Argument<'a>> {
+        self.ctx.ast.vec_from_iter(arguments.into_iter().map(|argument| match argument {
+            Argument::SpreadElement(mut spread) => {
+                let argument = spread.argument.take_in(self.ctx.ast);
+                spread.argument = self.explode_via_temp(None, argument);
+                Argument::SpreadElement(spread)
+            }
+            argument => Argument::from(self.explode_via_temp(None, argument.into_expression())),
+        }))
+    }
+
+    fn explode_member_expression(&mut self, member: MemberExpression<'a>) -> MemberExpression<'a> {
+        match member {
+            MemberExpression::ComputedMemberExpression(mut member) => {
+                let object = member.object.take_in(self.ctx.ast);
+                member.object = self.explode_expression(object);
+                let property = member.expression.take_in(self.ctx.ast);
+                member.expression = self.explode_via_temp(None, property);
+                MemberExpression::ComputedMemberExpression(member)
+            }
+            MemberExpression::StaticMemberExpression(mut member) => {
+                let object = member.object.take_in(self.ctx.ast);
+                member.object = self.explode_expression(object);
+                MemberExpression::StaticMemberExpression(member)
+            }
+            MemberExpression::PrivateFieldExpression(mut member) => {
+                let object = member.object.take_in(self.ctx.ast);
+                member.object = self.explode_expression(object);
+                MemberExpression::PrivateFieldExpression(member)
+            }
+        }
+    }
+
+    fn explode_call_expression(&mut self, call: CallExpression<'a>) -> Expression<'a> {
+        let CallExpression { span, callee, arguments, optional, .. } = call;
+        let has_leaping_arguments = arguments
+            .iter()
+            .any(|argument| contains_leap(|finder| finder.visit_argument(argument)));
+
+        if !has_leaping_arguments {
+            let callee = if callee.is_member_expression() {
+                self.explode_expression(callee)
+            } else {
+                self.explode_unqualified_callee(callee)
+            };
+            return self.ctx.ast.expression_call(span, callee, NONE, arguments, optional);
+        }
+
+        let (callee, this_arg) = if callee.is_member_expression() {
+            // Callee has to be evaluated before the arguments, but the object must still be
+            // bound to `this` for the call. `a.b(yield c)` -> `_context.t0.b.call(_context.t0, ...)`
+            let this_temp = self.make_temp();
+            let callee = match callee.into_member_expression() {
+                MemberExpression::ComputedMemberExpression(mut member) => {
+                    let object = member.object.take_in(self.ctx.ast);
+                    member.object = self.explode_via_temp(Some(this_temp), object);
+                    let property = member.expression.take_in(self.ctx.ast);
+                    member.expression = self.explode_via_temp(None, property);
+                    MemberExpression::ComputedMemberExpression(member)
+                }
+                MemberExpression::StaticMemberExpression(mut member) => {
+                    let object = member.object.take_in(self.ctx.ast);
+                    member.object = self.explode_via_temp(Some(this_temp), object);
+                    MemberExpression::StaticMemberExpression(member)
+                }
+                MemberExpression::PrivateFieldExpression(mut member) => {
+                    let object = member.object.take_in(self.ctx.ast);
+                    member.object = self.explode_via_temp(Some(this_temp), object);
+                    MemberExpression::PrivateFieldExpression(member)
+                }
+            };
+            let callee = create_member_callee(Expression::from(callee), "call", self.ctx);
+            (callee, Some(this_temp))
+        } else {
+            (self.explode_unqualified_callee(callee), None)
+        };
+
+        let mut arguments = self.explode_arguments(arguments);
+        if let Some(this_temp) = this_arg {
+            arguments.insert(0, Argument::from(self.temp_expr(this_temp)));
+        }
+        self.ctx.ast.expression_call(span, callee, NONE, arguments, optional)
+    }
+
+    /// Explode callee which is not a member expression.
+    ///
+    /// If it becomes a member expression (i.e. `_context.t0`), it's called with `(0, _context.t0)(...)`,
+    /// so it does not receive `_context` as `this`.
+    fn explode_unqualified_callee(&mut self, callee: Expression<'a>) -> Expression<'a> {
+        let callee = self.explode_via_temp(None, callee);
+        if callee.is_member_expression() {
+            let expressions = self.ctx.ast.vec_from_array([create_number(0, self.ctx), callee]);
+            self.ctx.ast.expression_sequence(SPAN, expressions)
+        } else {
+            callee
+        }
+    }
+
+    fn explode_assignment_expression(
+        &mut self,
+        assignment: AssignmentExpression<'a>,
+    ) -> Expression<'a> {
+        let AssignmentExpression { span, operator, left, right } = assignment;
+        if operator == AssignmentOperator::Assign {
+            let left = match left {
+                left @ match_simple_assignment_target!(AssignmentTarget) => AssignmentTarget::from(
+                    self.explode_simple_assignment_target(left.into_simple_assignment_target()),
+                ),
+                left => {
+                    if contains_leap(|finder| finder.visit_assignment_target(&left)) {
+                        self.error(
+                            "Cannot transform destructuring assignment containing `yield` in a generator function.",
+                            left.span(),
+                        );
+                    }
+                    left
+                }
+            };
+            let right = self.explode_expression(right);
+            return self.ctx.ast.expression_assignment(span, operator, left, right);
+        }
+
+        // `x += yield y` -> `_context.t0 = x; x = _context.t0 += _context.sent;`
+        // so the left-hand side is read before the `yield`.
+        let mut left = self.explode_simple_assignment_target(left.into_simple_assignment_target());
+        let current_value = self.duplicate_simple_assignment_target(&mut left);
+        let temp = self.make_temp();
+        self.emit_assign_temp(temp, current_value);
+        let right = self.explode_expression(right);
+        let update =
+            self.ctx.ast.expression_assignment(span, operator, self.temp_target(temp), right);
+        self.ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(left),
+            update,
+        )
+    }
+
+    fn explode_simple_assignment_target(
+        &mut self,
+        target: SimpleAssignmentTarget<'a>,
+    ) -> SimpleAssignmentTarget<'a> {
+        if !contains_leap(|finder| finder.visit_simple_assignment_target(&target)) {
+            return target;
+        }
+        match target {
+            match_member_expression!(SimpleAssignmentTarget) => SimpleAssignmentTarget::from(
+                self.explode_member_expression(target.into_member_expression()),
+            ),
+            target => {
+                self.error(
+                    "Cannot transform assignment containing `yield` in a generator function.",
+                    target.span(),
+                );
+                target
+            }
+        }
+    }
+
+    /// Create an expression reading the current value of `target`.
+    ///
+    /// Objects and computed properties of member expressions are stored in temporary variables
+    /// if evaluating them twice may have side effects.
+    fn duplicate_simple_assignment_target(
+        &mut self,
+        target: &mut SimpleAssignmentTarget<'a>,
+    ) -> Expression<'a> {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                MaybeBoundIdentifier::from_identifier_reference(ident, self.ctx)
+                    .create_read_expression(self.ctx)
+            }
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                let object = self.duplicate_expression(&mut member.object);
+                Expression::from(self.ctx.ast.member_expression_static(
+                    member.span,
+                    object,
+                    member.property.clone_in(self.ctx.ast.allocator),
+                    false,
+                ))
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => {
+                let object = self.duplicate_expression(&mut member.object);
+                let property = self.duplicate_expression(&mut member.expression);
+                Expression::from(self.ctx.ast.member_expression_computed(
+                    member.span,
+                    object,
+                    property,
+                    false,
+                ))
+            }
+            SimpleAssignmentTarget::PrivateFieldExpression(member) => {
+                let object = self.duplicate_expression(&mut member.object);
+                Expression::from(self.ctx.ast.member_expression_private_field_expression(
+                    member.span,
+                    object,
+                    member.field.clone_in(self.ctx.ast.allocator),
+                    false,
+                ))
+            }
+            target => {
+                self.error(
+                    "Cannot transform assignment containing `yield` in a generator function.",
+                    target.span(),
+                );
+                self.ctx.ast.void_0(SPAN)
+            }
+        }
+    }
+
+    /// Create a copy of `expr`. If `expr` may have side effects, it's replaced by a temporary variable.
+    fn duplicate_expression(&mut self, expr: &mut Expression<'a>) -> Expression<'a> {
+        match expr {
+            Expression::Identifier(ident) => {
+                MaybeBoundIdentifier::from_identifier_reference(ident, self.ctx)
+                    .create_read_expression(self.ctx)
+            }
+            Expression::ThisExpression(this) => self.ctx.ast.expression_this(this.span),
+            Expression::StringLiteral(_) | Expression::NumericLiteral(_) => {
+                expr.clone_in(self.ctx.ast.allocator)
+            }
+            _ => {
+                let temp = self.make_temp();
+                let value = expr.take_in(self.ctx.ast);
+                self.emit_assign_temp(temp, value);
+                *expr = self.temp_expr(temp);
+                self.temp_expr(temp)
+            }
+        }
+    }
+}
+
+/// Returns `true` if the node visited by `visit` contains a `yield`, or a statement which
+/// jumps out of it (`break`, `continue`, `return` or `throw`).
+///
+/// Nested functions are not entered.
+fn contains_leap(visit: impl FnOnce(&mut LeapFinder)) -> bool {
+    let mut finder = LeapFinder { found: false };
+    visit(&mut finder);
+    finder.found
+}
+
+struct LeapFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for LeapFinder {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if !self.found {
+            walk::walk_expression(self, expr);
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        if !self.found {
+            walk::walk_statement(self, stmt);
+        }
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _block: &StaticBlock<'a>) {}
+
+    fn visit_yield_expression(&mut self, _expr: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_break_statement(&mut self, _stmt: &BreakStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_continue_statement(&mut self, _stmt: &ContinueStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_return_statement(&mut self, _stmt: &ReturnStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_throw_statement(&mut self, _stmt: &ThrowStatement<'a>) {
+        self.found = true;
+    }
+}
+
+/// Replaces references to a `catch` parameter with the temporary variable holding the caught error.
+struct CatchParamReplacer<'e, 'a, 'ctx, 'v> {
+    emitter: &'e mut Emitter<'a, 'ctx, 'v>,
+    symbol_id: SymbolId,
+    temp: Temp,
+}
+
+impl CatchParamReplacer<'_, '_, '_, '_> {
+    fn is_param(&self, ident: &IdentifierReference<'_>) -> bool {
+        self.emitter.ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+            == Some(self.symbol_id)
+    }
+}
+
+impl<'a> VisitMut<'a> for CatchParamReplacer<'_, 'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr
+            && self.is_param(ident)
+        {
+            self.emitter.ctx.delete_reference_for_identifier(ident);
+            *expr = self.emitter.temp_expr(self.temp);
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target
+            && self.is_param(ident)
+        {
+            self.emitter.ctx.delete_reference_for_identifier(ident);
+            let AssignmentTarget::StaticMemberExpression(member) =
+                self.emitter.temp_target(self.temp)
+            else {
+                unreachable!()
+            };
+            *target = SimpleAssignmentTarget::StaticMemberExpression(member);
+            return;
+        }
+        walk_mut::walk_simple_assignment_target(self, target);
+    }
+}
+
+/// Replaces location placeholders with their final values.
+struct LocResolver<'l> {
+    loc_values: &'l [Option<usize>],
+}
+
+impl<'a> VisitMut<'a> for LocResolver<'_> {
+    fn visit_numeric_literal(&mut self, lit: &mut NumericLiteral<'a>) {
+        if lit.value < 0.0 && lit.raw.is_none() {
+            #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let index = (-lit.value) as usize - 1;
+            #[expect(clippy::cast_precision_loss)]
+            let value = self.loc_values[index].unwrap() as f64;
+            lit.value = value;
+        }
+    }
+}
+
+/// Deletes references in unreachable statements, which are removed.
+struct ReferenceDeleter<'a, 'v> {
+    ctx: &'v mut TraverseCtx<'a>,
+}
+
+impl<'a> Visit<'a> for ReferenceDeleter<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.ctx.delete_reference_for_identifier(ident);
+    }
+}
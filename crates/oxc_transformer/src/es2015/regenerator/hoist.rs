@@ -0,0 +1,216 @@
+//! Hoisting of declarations out of a generator function's body.
+//!
+//! The body of a generator is moved into a function which is called each time the generator is resumed,
+//! so bindings declared in the body have to be declared in the outer function instead.
+//!
+//! * `var a = 1;` -> `a = 1;`
+//! * `for (let i = 0; ;)` -> `for (i = 0; ;)`
+//! * `for (const k in obj)` -> `for (k in obj)`
+//! * `function foo() {}` -> `foo = function _foo() {};` (moved to start of the statement list)
+//!
+//! Based on [regenerator-transform's `hoist`](https://github.com/facebook/regenerator/blob/v0.15.2/packages/transform/src/hoist.js).
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_traverse::BoundIdentifier;
+
+use crate::context::TraverseCtx;
+
+use super::super::utils::binding_pattern_to_assignment_target;
+
+pub(super) struct Hoister<'a, 'v> {
+    ctx: &'v mut TraverseCtx<'a>,
+    function_scope_id: ScopeId,
+    param_symbols: FxHashSet<SymbolId>,
+    /// Bindings to be declared with `var` in the outer function
+    pub vars: Vec<BindingIdentifier<'a>>,
+    /// Bindings which had to be renamed when moved to the function scope
+    pub renamed_symbols: FxHashMap<SymbolId, Atom<'a>>,
+}
+
+impl<'a, 'v> Hoister<'a, 'v> {
+    pub fn new(func: &Function<'a>, ctx: &'v mut TraverseCtx<'a>) -> Self {
+        let mut param_symbols = FxHashSet::default();
+        func.params.bound_names(&mut |ident| {
+            param_symbols.insert(ident.symbol_id());
+        });
+        Self {
+            ctx,
+            function_scope_id: func.scope_id(),
+            param_symbols,
+            vars: vec![],
+            renamed_symbols: FxHashMap::default(),
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for Hoister<'a, '_> {
+    // Declarations in nested functions and classes are not hoisted
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &mut ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        let mut function_assignments = vec![];
+        stmts.retain_mut(|stmt| match stmt {
+            Statement::FunctionDeclaration(func) => {
+                let func = func.take_in_box(self.ctx.ast);
+                function_assignments.push(self.hoist_function(func));
+                false
+            }
+            Statement::VariableDeclaration(decl) => {
+                match self.hoist_declaration(decl) {
+                    Some(expr) => *stmt = self.ctx.ast.statement_expression(decl.span, expr),
+                    None => return false,
+                }
+                self.visit_statement(stmt);
+                true
+            }
+            _ => {
+                self.visit_statement(stmt);
+                true
+            }
+        });
+        if !function_assignments.is_empty() {
+            stmts.splice(0..0, function_assignments);
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        match stmt {
+            // Declarations which are not in a statement list. e.g. `if (x) var a = 1;`
+            Statement::FunctionDeclaration(func) => {
+                let func = func.take_in_box(self.ctx.ast);
+                *stmt = self.hoist_function(func);
+            }
+            Statement::VariableDeclaration(decl) => {
+                *stmt = match self.hoist_declaration(decl) {
+                    Some(expr) => self.ctx.ast.statement_expression(decl.span, expr),
+                    None => self.ctx.ast.statement_empty(decl.span),
+                };
+            }
+            _ => {}
+        }
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init {
+            stmt.init = self.hoist_declaration(decl).map(ForStatementInit::from);
+        }
+        walk_mut::walk_for_statement(self, stmt);
+    }
+
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if let ForStatementLeft::VariableDeclaration(decl) = left {
+            let declarator = decl.declarations.pop().unwrap();
+            declarator.id.bound_names(&mut |ident| self.hoist_binding(ident));
+            *left = ForStatementLeft::from(binding_pattern_to_assignment_target(
+                declarator.id,
+                self.ctx,
+            ));
+        }
+        walk_mut::walk_for_statement_left(self, left);
+    }
+}
+
+impl<'a> Hoister<'a, '_> {
+    /// `var a = 1, b, c = 2` -> `a = 1, c = 2`
+    ///
+    /// Returns `None` if no declarators have an initializer.
+    fn hoist_declaration(&mut self, decl: &mut VariableDeclaration<'a>) -> Option<Expression<'a>> {
+        let mut expressions = self.ctx.ast.vec();
+        for declarator in decl.declarations.take_in(self.ctx.ast) {
+            declarator.id.bound_names(&mut |ident| self.hoist_binding(ident));
+            if let Some(init) = declarator.init {
+                let target = binding_pattern_to_assignment_target(declarator.id, self.ctx);
+                expressions.push(self.ctx.ast.expression_assignment(
+                    declarator.span,
+                    AssignmentOperator::Assign,
+                    target,
+                    init,
+                ));
+            }
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, expressions)),
+        }
+    }
+
+    /// `function foo() {}` -> `foo = function _foo() {}`
+    fn hoist_function(&mut self, mut func: ArenaBox<'a, Function<'a>>) -> Statement<'a> {
+        let id = func.id.take().unwrap();
+        self.hoist_binding(&id);
+        let binding = BoundIdentifier::from_binding_ident(&id);
+        let binding = match self.renamed_symbols.get(&binding.symbol_id) {
+            Some(&name) => BoundIdentifier::new(name, binding.symbol_id),
+            None => binding,
+        };
+
+        // Give the function expression its own name, so references to `foo` inside it still refer to the hoisted binding
+        let new_id = self.ctx.generate_uid(&id.name, func.scope_id(), SymbolFlags::Function);
+        func.id = Some(new_id.create_binding_identifier(self.ctx));
+        func.r#type = FunctionType::FunctionExpression;
+
+        let span = func.span;
+        let assignment = self.ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            binding.create_spanned_write_target(id.span, self.ctx),
+            Expression::FunctionExpression(func),
+        );
+        self.ctx.ast.statement_expression(span, assignment)
+    }
+
+    /// Move a binding to the function scope, and record it to be declared with `var`.
+    fn hoist_binding(&mut self, ident: &BindingIdentifier<'a>) {
+        let symbol_id = ident.symbol_id();
+        if self.param_symbols.contains(&symbol_id)
+            || self.vars.iter().any(|var| var.symbol_id() == symbol_id)
+        {
+            return;
+        }
+
+        let scoping = self.ctx.scoping_mut();
+        let flags = scoping.symbol_flags_mut(symbol_id);
+        flags.remove(
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable | SymbolFlags::Function,
+        );
+        flags.insert(SymbolFlags::FunctionScopedVariable);
+
+        let mut name = Atom::from(ident.name);
+        let scope_id = self.ctx.scoping().symbol_scope_id(symbol_id);
+        if scope_id != self.function_scope_id {
+            if self.is_name_taken(&name, scope_id) {
+                name = self.ctx.generate_uid_name(&name);
+                self.ctx.scoping_mut().rename_symbol(symbol_id, scope_id, &name);
+                self.renamed_symbols.insert(symbol_id, name);
+            }
+            let scoping = self.ctx.scoping_mut();
+            scoping.move_binding(scope_id, self.function_scope_id, &name);
+            scoping.set_symbol_scope_id(symbol_id, self.function_scope_id);
+        }
+
+        self.vars.push(self.ctx.ast.binding_identifier_with_symbol_id(ident.span, name, symbol_id));
+    }
+
+    /// Returns `true` if moving a binding named `name` out of scope `scope_id` may clash with another
+    /// binding, or shadow a reference to a global.
+    fn is_name_taken(&self, name: &str, scope_id: ScopeId) -> bool {
+        let scoping = self.ctx.scoping();
+        scoping
+            .scope_ancestors(scope_id)
+            .skip(1)
+            .any(|scope_id| scoping.get_binding(scope_id, name).is_some())
+            || scoping.root_unresolved_references().contains_key(name)
+    }
+}
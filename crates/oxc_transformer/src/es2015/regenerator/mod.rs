@@ -0,0 +1,526 @@
+//! ES2015: Regenerator
+//!
+//! This plugin transforms generator functions into state machines, driven by `regeneratorRuntime`.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! Combined with async-to-generator and async-generator-functions transforms, which convert
+//! async functions into generators, this allows async functions to run in ES5 engines.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function* gen() {
+//!   var x = yield 1;
+//!   return x;
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _marked = /*#__PURE__*/ babelHelpers.regeneratorRuntime().mark(gen);
+//! function gen() {
+//!   var x;
+//!   return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+//!     while (1) switch (_context.prev = _context.next) {
+//!       case 0:
+//!         _context.next = 2;
+//!         return 1;
+//!       case 2:
+//!         x = _context.sent;
+//!         return _context.abrupt("return", x);
+//!       case 4:
+//!       case "end":
+//!         return _context.stop();
+//!     }
+//!   }, _marked);
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-regenerator](https://babel.dev/docs/babel-plugin-transform-regenerator),
+//! which uses [regenerator-transform](https://github.com/facebook/regenerator/tree/v0.15.2/packages/transform).
+//!
+//! The transform runs on the whole program at once in `exit_program`, after all other transforms,
+//! because async-to-generator and async-generator-functions transforms create generator functions.
+//! Generator functions are transformed innermost first. For each function:
+//!
+//! 1. [`Hoister`] converts declarations in the function body to assignments, and collects the bindings,
+//!    which are declared with `var` in the outer function.
+//! 2. References to `arguments` are replaced with `_args`, and use of `this` is recorded.
+//! 3. [`Emitter`] explodes the body into a state machine, which is wrapped in an inner function
+//!    passed to `regeneratorRuntime().wrap`.
+//!
+//! Function declarations are marked with `var _marked = regeneratorRuntime().mark(gen)` at top of
+//! the enclosing block, and function expressions are wrapped in `regeneratorRuntime().mark(...)`.
+//!
+//! Differences from Babel:
+//! * Async functions are not transformed directly with `regeneratorRuntime().async`.
+//!   They need to be converted to generators by async-to-generator transform first.
+//! * Generator class methods are passed to `regeneratorRuntime().wrap` with `null` as the outer function.
+//! * `yield` inside `for...of`, `with`, optional chains, and destructuring is not supported.
+//!   These need to be transformed by other plugins first.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-regenerator>
+//! * regenerator-transform: <https://github.com/facebook/regenerator/tree/v0.15.2/packages/transform>
+//! * Generator functions: <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/function*>
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::create_member_callee,
+};
+
+use super::utils::SymbolRenamer;
+
+mod emit;
+mod hoist;
+
+use emit::Emitter;
+use hoist::Hoister;
+
+pub struct Regenerator<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Regenerator<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Regenerator<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        GeneratorFunctionTransformer::new(self.ctx, ctx).visit_program(program);
+    }
+}
+
+/// `regeneratorRuntime().name`
+fn runtime_property<'a>(
+    name: &'static str,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let runtime =
+        transform_ctx.helper_call_expr(Helper::RegeneratorRuntime, SPAN, ctx.ast.vec(), ctx);
+    create_member_callee(runtime, name, ctx)
+}
+
+/// How a generator function is referred to, to be passed to `regeneratorRuntime().wrap`.
+#[derive(Clone, Copy)]
+enum FunctionKind {
+    /// Function declaration, marked with `var _marked = regeneratorRuntime().mark(gen)`
+    Declaration,
+    /// Function expression, wrapped in `regeneratorRuntime().mark(...)`
+    Expression,
+    /// Class method, which cannot be marked
+    Method,
+}
+
+/// Transforms all generator functions in the program.
+struct GeneratorFunctionTransformer<'a, 'ctx, 'v> {
+    transform_ctx: &'ctx TransformCtx<'a>,
+    ctx: &'v mut TraverseCtx<'a>,
+    /// Stack of scopes which `var` declarations are hoisted to
+    hoist_scopes: Vec<ScopeId>,
+    /// Stack of `_marked` declarators to insert at top of statement lists
+    marked_declarators: Vec<ArenaVec<'a, VariableDeclarator<'a>>>,
+    /// Functions which are values of class methods
+    methods: FxHashSet<ScopeId>,
+    /// Transformed function expressions, to be wrapped in `regeneratorRuntime().mark(...)`
+    functions_to_mark: FxHashSet<ScopeId>,
+}
+
+impl<'a, 'ctx, 'v> GeneratorFunctionTransformer<'a, 'ctx, 'v> {
+    fn new(transform_ctx: &'ctx TransformCtx<'a>, ctx: &'v mut TraverseCtx<'a>) -> Self {
+        Self {
+            transform_ctx,
+            ctx,
+            hoist_scopes: vec![],
+            marked_declarators: vec![],
+            methods: FxHashSet::default(),
+            functions_to_mark: FxHashSet::default(),
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for GeneratorFunctionTransformer<'a, '_, '_> {
+    fn visit_program(&mut self, program: &mut Program<'a>) {
+        self.hoist_scopes.push(program.scope_id());
+        self.marked_declarators.push(self.ctx.ast.vec());
+        walk_mut::walk_program(self, program);
+        self.insert_marked_declaration(&mut program.body);
+        self.hoist_scopes.pop();
+    }
+
+    fn visit_function_body(&mut self, body: &mut FunctionBody<'a>) {
+        self.marked_declarators.push(self.ctx.ast.vec());
+        walk_mut::walk_function_body(self, body);
+        self.insert_marked_declaration(&mut body.statements);
+    }
+
+    fn visit_block_statement(&mut self, block: &mut BlockStatement<'a>) {
+        self.marked_declarators.push(self.ctx.ast.vec());
+        walk_mut::walk_block_statement(self, block);
+        self.insert_marked_declaration(&mut block.body);
+    }
+
+    fn visit_static_block(&mut self, block: &mut StaticBlock<'a>) {
+        self.hoist_scopes.push(block.scope_id());
+        self.marked_declarators.push(self.ctx.ast.vec());
+        walk_mut::walk_static_block(self, block);
+        self.insert_marked_declaration(&mut block.body);
+        self.hoist_scopes.pop();
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.hoist_scopes.push(arrow.scope_id());
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.hoist_scopes.pop();
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.hoist_scopes.push(func.scope_id());
+        walk_mut::walk_function(self, func, flags);
+        self.hoist_scopes.pop();
+
+        if !func.generator || func.r#async || func.body.is_none() {
+            return;
+        }
+        let kind = if self.methods.remove(&func.scope_id()) {
+            FunctionKind::Method
+        } else if func.is_declaration() {
+            FunctionKind::Declaration
+        } else {
+            self.functions_to_mark.insert(func.scope_id());
+            FunctionKind::Expression
+        };
+        self.transform_function(func, kind);
+    }
+
+    fn visit_method_definition(&mut self, method: &mut MethodDefinition<'a>) {
+        self.methods.insert(method.value.scope_id());
+        walk_mut::walk_method_definition(self, method);
+    }
+
+    fn visit_object_property(&mut self, property: &mut ObjectProperty<'a>) {
+        // `{ *foo() {} }` -> `{ foo: regeneratorRuntime().mark(function _callee() {}) }`
+        if property.method
+            && matches!(&property.value, Expression::FunctionExpression(func) if func.generator && !func.r#async)
+        {
+            property.method = false;
+        }
+        walk_mut::walk_object_property(self, property);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_mut::walk_expression(self, expr);
+
+        if let Expression::FunctionExpression(func) = expr
+            && self.functions_to_mark.remove(&func.scope_id())
+        {
+            // `function _callee() {}` -> `/*#__PURE__*/ regeneratorRuntime().mark(function _callee() {})`
+            let func = expr.take_in(self.ctx.ast);
+            *expr = self.create_mark_call(func);
+        }
+    }
+}
+
+impl<'a> GeneratorFunctionTransformer<'a, '_, '_> {
+    /// `regeneratorRuntime().mark(func)`
+    fn create_mark_call(&mut self, func: Expression<'a>) -> Expression<'a> {
+        let callee = runtime_property("mark", self.transform_ctx, self.ctx);
+        let arguments = self.ctx.ast.vec1(Argument::from(func));
+        self.ctx.ast.expression_call_with_pure(SPAN, callee, NONE, arguments, false, true)
+    }
+
+    /// Insert `var _marked = regeneratorRuntime().mark(gen);` at top of `stmts`.
+    fn insert_marked_declaration(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        let declarators = self.marked_declarators.pop().unwrap();
+        if !declarators.is_empty() {
+            let declaration = self.ctx.ast.declaration_variable(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarators,
+                false,
+            );
+            stmts.insert(0, Statement::from(declaration));
+        }
+    }
+
+    /// ```js
+    /// function gen() {
+    ///   var x, _args = arguments;
+    ///   return regeneratorRuntime().wrap(function gen$(_context) {
+    ///     while (1) switch (_context.prev = _context.next) { ... }
+    ///   }, _marked, this, tryLocsList);
+    /// }
+    /// ```
+    fn transform_function(&mut self, func: &mut Function<'a>, kind: FunctionKind) {
+        let function_scope_id = func.scope_id();
+
+        let outer_function = self.create_outer_function_expression(func, kind);
+        let inner_name = if let Some(id) = &func.id {
+            self.ctx.ast.atom(&format!("{}$", id.name))
+        } else {
+            let name = self.ctx.generate_uid_name("callee");
+            self.ctx.ast.atom(&format!("{name}$"))
+        };
+
+        // Convert declarations into assignments
+        let mut hoister = Hoister::new(func, self.ctx);
+        let body = func.body.as_mut().unwrap();
+        hoister.visit_function_body(body);
+        let vars = hoister.vars;
+        let renamed_symbols = hoister.renamed_symbols;
+        if !renamed_symbols.is_empty() {
+            SymbolRenamer::new(renamed_symbols, self.ctx).visit_function_body(body);
+        }
+
+        let inner_scope_id = self.ctx.create_child_scope(function_scope_id, ScopeFlags::Function);
+        let switch_scope_id = self.ctx.create_child_scope(inner_scope_id, ScopeFlags::empty());
+
+        // Remaining bindings in function scope are declared in statements which are moved into
+        // the state machine. e.g. classes.
+        let mut outer_symbols = vars.iter().map(BindingIdentifier::symbol_id).collect::<Vec<_>>();
+        func.params.bound_names(&mut |ident| outer_symbols.push(ident.symbol_id()));
+        if let Some(id) = &func.id {
+            outer_symbols.push(id.symbol_id());
+        }
+        self.move_bindings_except(function_scope_id, switch_scope_id, &outer_symbols);
+
+        // Replace `arguments` with `_args`, and find out whether `this` is used
+        let mut replacer = ThisArgumentsReplacer {
+            function_scope_id,
+            args: None,
+            uses_this: false,
+            ctx: self.ctx,
+        };
+        replacer.visit_function_body(body);
+        let ThisArgumentsReplacer { args, uses_this, .. } = replacer;
+
+        // Explode body
+        let context =
+            self.ctx.generate_uid("context", inner_scope_id, SymbolFlags::FunctionScopedVariable);
+        let stmts = body.statements.take_in(self.ctx.ast);
+        let mut emitter =
+            Emitter::new(context.clone(), switch_scope_id, self.transform_ctx, self.ctx);
+        emitter.explode_statements(stmts);
+        let (dispatch_loop, try_locs_list) = emitter.finish();
+
+        // `function gen$(_context) { while (1) switch (...) { ... } }`
+        let inner_id = self.ctx.generate_binding(inner_name, inner_scope_id, SymbolFlags::Function);
+        let params = self.ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            self.ctx.ast.vec1(
+                self.ctx.ast.plain_formal_parameter(SPAN, context.create_binding_pattern(self.ctx)),
+            ),
+            NONE,
+        );
+        let inner_body = self.ctx.ast.alloc_function_body(
+            SPAN,
+            self.ctx.ast.vec(),
+            self.ctx.ast.vec1(dispatch_loop),
+        );
+        let inner_function = self.ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            FunctionType::FunctionExpression,
+            Some(inner_id.create_binding_identifier(self.ctx)),
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(inner_body),
+            inner_scope_id,
+            false,
+            false,
+        );
+
+        // `regeneratorRuntime().wrap(gen$, _marked, this, tryLocsList)`
+        let mut arguments = self.ctx.ast.vec_with_capacity(4);
+        arguments.push(Argument::from(inner_function));
+        arguments.push(Argument::from(outer_function));
+        if uses_this {
+            arguments.push(Argument::from(self.ctx.ast.expression_this(SPAN)));
+        } else if try_locs_list.is_some() {
+            arguments.push(Argument::from(self.ctx.ast.expression_null_literal(SPAN)));
+        }
+        if let Some(try_locs_list) = try_locs_list {
+            arguments.push(Argument::from(try_locs_list));
+        }
+        let wrap = runtime_property("wrap", self.transform_ctx, self.ctx);
+        let wrap = self.ctx.ast.expression_call(SPAN, wrap, NONE, arguments, false);
+
+        // `var x, _args = arguments;`
+        let mut stmts = self.ctx.ast.vec_with_capacity(2);
+        let mut declarators = self.ctx.ast.vec_from_iter(vars.into_iter().map(|ident| {
+            let pattern = self.ctx.ast.binding_pattern_binding_identifier_with_symbol_id(
+                ident.span,
+                ident.name,
+                ident.symbol_id(),
+            );
+            self.ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                pattern,
+                NONE,
+                None,
+                false,
+            )
+        }));
+        if let Some(args) = args {
+            let init = self.ctx.create_unbound_ident_expr(
+                SPAN,
+                Atom::from("arguments"),
+                oxc_semantic::ReferenceFlags::Read,
+            );
+            declarators.push(self.ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                args.create_binding_pattern(self.ctx),
+                NONE,
+                Some(init),
+                false,
+            ));
+        }
+        if !declarators.is_empty() {
+            stmts.push(Statement::from(self.ctx.ast.declaration_variable(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarators,
+                false,
+            )));
+        }
+        stmts.push(self.ctx.ast.statement_return(SPAN, Some(wrap)));
+        body.statements = stmts;
+        func.generator = false;
+    }
+
+    /// Get expression referring to the function, to pass to `regeneratorRuntime().wrap`.
+    ///
+    /// * Function declaration: `_marked`, declared as `var _marked = regeneratorRuntime().mark(gen)`.
+    /// * Function expression: the function's name. `_callee` is added if it has no name.
+    /// * Class method: `null`.
+    fn create_outer_function_expression(
+        &mut self,
+        func: &mut Function<'a>,
+        kind: FunctionKind,
+    ) -> Expression<'a> {
+        match kind {
+            FunctionKind::Declaration => {
+                let hoist_scope_id = *self.hoist_scopes.last().unwrap();
+                let id = if let Some(id) = &func.id {
+                    BoundIdentifier::from_binding_ident(id)
+                } else {
+                    // `export default function* () {}`
+                    let id = self.ctx.generate_uid("callee", hoist_scope_id, SymbolFlags::Function);
+                    func.id = Some(id.create_binding_identifier(self.ctx));
+                    id
+                };
+                let marked = self.ctx.generate_uid(
+                    "marked",
+                    hoist_scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                );
+                let id = id.create_read_expression(self.ctx);
+                let mark_call = self.create_mark_call(id);
+                let declarator = self.ctx.ast.variable_declarator(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    marked.create_binding_pattern(self.ctx),
+                    NONE,
+                    Some(mark_call),
+                    false,
+                );
+                self.marked_declarators.last_mut().unwrap().push(declarator);
+                marked.create_read_expression(self.ctx)
+            }
+            FunctionKind::Expression => {
+                let id = if let Some(id) = &func.id {
+                    BoundIdentifier::from_binding_ident(id)
+                } else {
+                    let id =
+                        self.ctx.generate_uid("callee", func.scope_id(), SymbolFlags::Function);
+                    func.id = Some(id.create_binding_identifier(self.ctx));
+                    id
+                };
+                id.create_read_expression(self.ctx)
+            }
+            FunctionKind::Method => self.ctx.ast.expression_null_literal(SPAN),
+        }
+    }
+
+    /// Move all bindings in scope `from` to scope `to`, except `excluded`.
+    fn move_bindings_except(&mut self, from: ScopeId, to: ScopeId, excluded: &[SymbolId]) {
+        let symbol_ids = self
+            .ctx
+            .scoping()
+            .iter_bindings_in(from)
+            .filter(|symbol_id| !excluded.contains(symbol_id))
+            .collect::<Vec<_>>();
+        let scoping = self.ctx.scoping_mut();
+        for symbol_id in symbol_ids {
+            let name = scoping.symbol_name(symbol_id).to_string();
+            scoping.move_binding(from, to, &name);
+            scoping.set_symbol_scope_id(symbol_id, to);
+        }
+    }
+}
+
+/// Replaces `arguments` with `_args`, and records whether `this` is used.
+///
+/// `this` and `arguments` in arrow functions refer to those of the generator function,
+/// so arrow functions are entered.
+struct ThisArgumentsReplacer<'a, 'v> {
+    function_scope_id: ScopeId,
+    args: Option<BoundIdentifier<'a>>,
+    uses_this: bool,
+    ctx: &'v mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for ThisArgumentsReplacer<'a, '_> {
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+
+    fn visit_this_expression(&mut self, _this: &mut ThisExpression) {
+        self.uses_this = true;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if ident.name != "arguments" {
+            return;
+        }
+        let reference_id = ident.reference_id();
+        if self.ctx.scoping().get_reference(reference_id).symbol_id().is_some() {
+            return;
+        }
+        let function_scope_id = self.function_scope_id;
+        let args = self.args.get_or_insert_with(|| {
+            self.ctx.generate_uid("args", function_scope_id, SymbolFlags::FunctionScopedVariable)
+        });
+        let scoping = self.ctx.scoping_mut();
+        scoping.get_reference_mut(reference_id).set_symbol_id(args.symbol_id);
+        scoping.delete_root_unresolved_reference(&ident.name, reference_id);
+        scoping.add_resolved_reference(args.symbol_id, reference_id);
+        ident.name = args.name.into();
+    }
+}
@@ -0,0 +1,303 @@
+//! ES2015: Spread
+//!
+//! This plugin transforms spread elements in array literals, function calls and `new` expressions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! [a, ...b];
+//! obj.f(a, ...b);
+//! new C(...b);
+//! ```
+//!
+//! Output:
+//! ```js
+//! [a].concat(_toConsumableArray(b));
+//! obj.f.apply(obj, [a].concat(_toConsumableArray(b)));
+//! _construct(C, _toConsumableArray(b));
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-spread](https://babel.dev/docs/babel-plugin-transform-spread).
+//!
+//! Supports the `iterableIsArray` and `arrayLikeIsIterable` assumptions.
+//!
+//! `super(...args)` is not transformed. Classes transform handles it, and transforms the spread
+//! elements of the array of arguments it creates.
+//! Calls in optional chains (`a?.b(...c)`) are not transformed either.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-spread>
+//! * Spread syntax: <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Spread_syntax>
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_span::SPAN;
+use oxc_traverse::{MaybeBoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::{create_assignment, create_member_callee},
+};
+
+use super::utils::{ToArrayKind, create_to_array, is_arguments, is_static_expression};
+
+pub struct Spread<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Spread<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Spread<'a, '_> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::ArrayExpression(array) => {
+                if array.elements.iter().any(ArrayExpressionElement::is_spread) {
+                    *expr = self.transform_array_expression(array, ctx);
+                }
+            }
+            Expression::CallExpression(call) => {
+                if call.arguments.iter().any(Argument::is_spread)
+                    && !matches!(call.callee, Expression::Super(_))
+                    && !is_optional_chain(&call.callee)
+                    && !call.optional
+                {
+                    self.transform_call_expression(call, ctx);
+                }
+            }
+            Expression::NewExpression(new_expr) => {
+                if new_expr.arguments.iter().any(Argument::is_spread) {
+                    *expr = self.transform_new_expression(new_expr, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Spread<'a, '_> {
+    /// * `[...a]` -> `_toConsumableArray(a)`
+    /// * `[a, ...b, c]` -> `[a].concat(_toConsumableArray(b), [c])`
+    pub(super) fn transform_array_expression(
+        &self,
+        array: &mut ArrayExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let elements = array.elements.take_in(ctx.ast);
+        // `[...a]` -> `_toConsumableArray(a)`.
+        // With `iterableIsArray` assumption, `a` is used as is, so it has to be copied with `[].concat(a)`.
+        let is_single_converted_spread = elements.len() == 1
+            && matches!(&elements[0], ArrayExpressionElement::SpreadElement(spread)
+                if !self.ctx.assumptions.iterable_is_array
+                    || matches!(&spread.argument, Expression::ArrayExpression(_))
+                    || matches!(&spread.argument, Expression::Identifier(ident) if is_arguments(ident, ctx)));
+        let mut parts = self.build_parts(elements, ctx);
+        if is_single_converted_spread {
+            return parts.pop().unwrap();
+        }
+
+        let first = if matches!(parts.first(), Some(Expression::ArrayExpression(_))) {
+            parts.remove(0)
+        } else {
+            ctx.ast.expression_array(SPAN, ctx.ast.vec())
+        };
+        Self::create_concat(first, parts, array.span, ctx)
+    }
+
+    /// * `f(...a)` -> `f.apply(void 0, _toConsumableArray(a))`
+    /// * `obj.f(a, ...b)` -> `obj.f.apply(obj, [a].concat(_toConsumableArray(b)))`
+    /// * `foo().f(...a)` -> `(_foo = foo()).f.apply(_foo, _toConsumableArray(a))`
+    fn transform_call_expression(&self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let arguments = call.arguments.take_in(ctx.ast);
+        let arguments = match arguments.first() {
+            // `f(...arguments)` -> `f.apply(void 0, arguments)`
+            Some(Argument::SpreadElement(spread))
+                if arguments.len() == 1
+                    && matches!(&spread.argument, Expression::Identifier(ident) if is_arguments(ident, ctx)) =>
+            {
+                let Some(Argument::SpreadElement(spread)) = arguments.into_iter().next() else {
+                    unreachable!()
+                };
+                spread.unbox().argument
+            }
+            _ => {
+                let elements =
+                    ctx.ast.vec_from_iter(arguments.into_iter().map(|argument| match argument {
+                        Argument::SpreadElement(spread) => {
+                            ArrayExpressionElement::SpreadElement(spread)
+                        }
+                        argument => ArrayExpressionElement::from(argument.into_expression()),
+                    }));
+                let mut parts = self.build_parts(elements, ctx);
+                let first = parts.remove(0);
+                if parts.is_empty() { first } else { Self::create_concat(first, parts, SPAN, ctx) }
+            }
+        };
+
+        let this_arg = match &mut call.callee {
+            Expression::StaticMemberExpression(member) => {
+                self.memoize_object(&mut member.object, ctx)
+            }
+            Expression::ComputedMemberExpression(member) => {
+                self.memoize_object(&mut member.object, ctx)
+            }
+            Expression::PrivateFieldExpression(member) => {
+                self.memoize_object(&mut member.object, ctx)
+            }
+            _ => ctx.ast.void_0(SPAN),
+        };
+
+        let callee = call.callee.take_in(ctx.ast);
+        call.callee = create_member_callee(callee, "apply", ctx);
+        call.arguments =
+            ctx.ast.vec_from_array([Argument::from(this_arg), Argument::from(arguments)]);
+    }
+
+    /// `new C(a, ...b)` -> `_construct(C, [a].concat(_toConsumableArray(b)))`
+    fn transform_new_expression(
+        &self,
+        new_expr: &mut NewExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let elements = ctx.ast.vec_from_iter(new_expr.arguments.take_in(ctx.ast).into_iter().map(
+            |argument| match argument {
+                Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+                argument => ArrayExpressionElement::from(argument.into_expression()),
+            },
+        ));
+        let mut parts = self.build_parts(elements, ctx);
+        let first = parts.remove(0);
+        let arguments =
+            if parts.is_empty() { first } else { Self::create_concat(first, parts, SPAN, ctx) };
+
+        let callee = new_expr.callee.take_in(ctx.ast);
+        let arguments = ctx.ast.vec_from_array([Argument::from(callee), Argument::from(arguments)]);
+        self.ctx.helper_call_expr(Helper::Construct, new_expr.span, arguments, ctx)
+    }
+
+    /// Split elements into groups of non-spread elements, and spread arguments converted to arrays.
+    ///
+    /// `a, b, ...c, d` -> `[a, b]`, `_toConsumableArray(c)`, `[d]`
+    fn build_parts(
+        &self,
+        elements: ArenaVec<'a, ArrayExpressionElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Expression<'a>> {
+        let mut parts = vec![];
+        let mut group = ctx.ast.vec();
+        for element in elements {
+            match element {
+                ArrayExpressionElement::SpreadElement(spread) => {
+                    if !group.is_empty() {
+                        let group = std::mem::replace(&mut group, ctx.ast.vec());
+                        parts.push(ctx.ast.expression_array(SPAN, group));
+                    }
+                    let argument = spread.unbox().argument;
+                    parts.push(self.create_spread_literal(argument, ctx));
+                }
+                element => group.push(element),
+            }
+        }
+        if !group.is_empty() {
+            parts.push(ctx.ast.expression_array(SPAN, group));
+        }
+        parts
+    }
+
+    /// Convert spread argument to an array.
+    ///
+    /// With `iterableIsArray` assumption, `argument` is used as is, unless it's `arguments`.
+    fn create_spread_literal(
+        &self,
+        argument: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if self.ctx.assumptions.iterable_is_array
+            && !matches!(&argument, Expression::Identifier(ident) if is_arguments(ident, ctx))
+        {
+            return argument;
+        }
+        create_to_array(argument, ToArrayKind::Spread, self.ctx, ctx)
+    }
+
+    /// `first.concat(a, b)`
+    fn create_concat(
+        first: Expression<'a>,
+        rest: Vec<Expression<'a>>,
+        span: Span,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = create_member_callee(first, "concat", ctx);
+        let arguments = ctx.ast.vec_from_iter(rest.into_iter().map(Argument::from));
+        ctx.ast.expression_call(span, callee, NONE, arguments, false)
+    }
+
+    /// Get `this` value for calling a method of `object`.
+    ///
+    /// * `obj` -> `obj` (if `obj` is never reassigned)
+    /// * `super` -> `this`
+    /// * `foo()` -> `_foo`, and `object` is replaced with `(_foo = foo())`
+    fn memoize_object(
+        &self,
+        object: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if is_static_expression(object, ctx) || matches!(object, Expression::Super(_)) {
+            return match object {
+                Expression::Identifier(ident) => {
+                    let binding = MaybeBoundIdentifier::from_identifier_reference(ident, ctx);
+                    binding.create_read_expression(ctx)
+                }
+                _ => ctx.ast.expression_this(SPAN),
+            };
+        }
+        let binding = self.ctx.var_declarations.create_uid_var_based_on_node(object, ctx);
+        let value = object.take_in(ctx.ast);
+        *object = create_assignment(&binding, value, ctx);
+        binding.create_read_expression(ctx)
+    }
+}
+
+/// Returns `true` if `expr` is part of an optional chain. e.g. `a?.b`.
+fn is_optional_chain(expr: &Expression<'_>) -> bool {
+    let mut expr = expr;
+    loop {
+        match expr {
+            Expression::StaticMemberExpression(member) => {
+                if member.optional {
+                    return true;
+                }
+                expr = &member.object;
+            }
+            Expression::ComputedMemberExpression(member) => {
+                if member.optional {
+                    return true;
+                }
+                expr = &member.object;
+            }
+            Expression::PrivateFieldExpression(member) => {
+                if member.optional {
+                    return true;
+                }
+                expr = &member.object;
+            }
+            Expression::CallExpression(call) => {
+                if call.optional {
+                    return true;
+                }
+                expr = &call.callee;
+            }
+            _ => return false,
+        }
+    }
+}
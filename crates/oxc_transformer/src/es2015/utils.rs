@@ -0,0 +1,291 @@
+//! ES2015: Utility functions shared by ES2015 transforms.
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolId};
+use oxc_span::SPAN;
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    utils::ast_builder::create_member_callee,
+};
+
+/// How an iterable should be converted to an array.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum ToArrayKind {
+    /// `toConsumableArray(expr)` - all elements are needed, e.g. `[...expr]`.
+    Spread,
+    /// `slicedToArray(expr, n)` - only the first `n` elements are needed, e.g. `[a, b] = expr`.
+    Sliced(usize),
+    /// `toArray(expr)` - all elements are needed, e.g. `[a, ...b] = expr`.
+    Rest,
+}
+
+/// Convert an iterable to an array.
+///
+/// * `[a, b]` -> `[a, b]`
+/// * `arguments` -> `Array.prototype.slice.call(arguments)`
+/// * `expr` -> `toConsumableArray(expr)` / `slicedToArray(expr, n)` / `toArray(expr)`
+///
+/// With `arrayLikeIsIterable` assumption, helper is wrapped with `maybeArrayLike`:
+/// `expr` -> `maybeArrayLike(toConsumableArray, expr)`.
+pub(super) fn create_to_array<'a>(
+    expr: Expression<'a>,
+    kind: ToArrayKind,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    match &expr {
+        Expression::ArrayExpression(_) => return expr,
+        Expression::Identifier(ident) if is_arguments(ident, ctx) => {
+            return create_slice_call_of_arguments(expr, ctx);
+        }
+        _ => {}
+    }
+
+    let (helper, count) = match kind {
+        ToArrayKind::Spread => (Helper::ToConsumableArray, None),
+        ToArrayKind::Sliced(count) => (Helper::SlicedToArray, Some(count)),
+        ToArrayKind::Rest => (Helper::ToArray, None),
+    };
+
+    let mut arguments = ctx.ast.vec_with_capacity(3);
+    let helper = if transform_ctx.assumptions.array_like_is_iterable {
+        arguments.push(Argument::from(transform_ctx.helper_load(helper, ctx)));
+        Helper::MaybeArrayLike
+    } else {
+        helper
+    };
+    arguments.push(Argument::from(expr));
+    if let Some(count) = count {
+        arguments.push(Argument::from(create_number(count, ctx)));
+    }
+    transform_ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+}
+
+/// Returns `true` if `ident` is a reference to the `arguments` object of a function.
+pub(super) fn is_arguments(ident: &IdentifierReference<'_>, ctx: &TraverseCtx<'_>) -> bool {
+    ident.name == "arguments"
+        && ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none()
+}
+
+/// `arguments` -> `Array.prototype.slice.call(arguments)`
+fn create_slice_call_of_arguments<'a>(
+    arguments: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let array = ctx.create_unbound_ident_expr(SPAN, Atom::from("Array"), ReferenceFlags::Read);
+    let prototype = create_member_callee(array, "prototype", ctx);
+    let slice = create_member_callee(prototype, "slice", ctx);
+    let callee = create_member_callee(slice, "call", ctx);
+    ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(Argument::from(arguments)), false)
+}
+
+/// Returns `true` if `expr` can be read multiple times without re-evaluation changing its value.
+///
+/// i.e. `this`, or a reference to a binding which is never reassigned.
+pub(super) fn is_static_expression(expr: &Expression<'_>, ctx: &TraverseCtx<'_>) -> bool {
+    match expr {
+        Expression::ThisExpression(_) => true,
+        Expression::Identifier(ident) => {
+            let reference = ctx.scoping().get_reference(ident.reference_id());
+            reference
+                .symbol_id()
+                .is_some_and(|symbol_id| !ctx.scoping().symbol_is_mutated(symbol_id))
+        }
+        _ => false,
+    }
+}
+
+/// `value` -> `value === void 0`
+pub(super) fn create_is_undefined<'a>(
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    ctx.ast.expression_binary(SPAN, value, BinaryOperator::StrictEquality, ctx.ast.void_0(SPAN))
+}
+
+/// Create a numeric literal expression.
+pub(super) fn create_number<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    #[expect(clippy::cast_precision_loss)]
+    let value = value as f64;
+    ctx.ast.expression_numeric_literal(SPAN, value, None, NumberBase::Decimal)
+}
+
+/// Move all bindings in scope `from` to scope `to`.
+pub(super) fn move_all_bindings(from: ScopeId, to: ScopeId, ctx: &mut TraverseCtx<'_>) {
+    let symbol_ids = ctx.scoping().iter_bindings_in(from).collect::<Vec<_>>();
+    let scoping = ctx.scoping_mut();
+    for symbol_id in symbol_ids {
+        let name = scoping.symbol_name(symbol_id).to_string();
+        scoping.move_binding(from, to, &name);
+        scoping.set_symbol_scope_id(symbol_id, to);
+    }
+}
+
+/// Set parent of the outermost scopes within `stmts` to `parent_scope_id`.
+///
+/// Used when statements are moved into another scope. e.g. `{ () => {} }` -> `function () { () => {} }`.
+pub(super) fn reparent_child_scopes<'a>(
+    stmts: &[Statement<'a>],
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let mut collector = ChildScopeCollector::default();
+    for stmt in stmts {
+        collector.visit_statement(stmt);
+    }
+    for scope_id in collector.scope_ids {
+        ctx.scoping_mut().change_scope_parent_id(scope_id, Some(parent_scope_id));
+    }
+}
+
+/// Collects the outermost scopes in visited nodes.
+#[derive(Default)]
+struct ChildScopeCollector {
+    depth: usize,
+    scope_ids: Vec<ScopeId>,
+}
+
+impl Visit<'_> for ChildScopeCollector {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            self.scope_ids.push(scope_id.get().unwrap());
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+/// Convert a binding pattern to an assignment target.
+///
+/// `{ a, b: [c = 1] }` -> `{ a, b: [c = 1] }`
+pub fn binding_pattern_to_assignment_target<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTarget<'a> {
+    match pattern {
+        BindingPattern::BindingIdentifier(ident) => {
+            BoundIdentifier::from_binding_ident(&ident).create_spanned_write_target(ident.span, ctx)
+        }
+        BindingPattern::ObjectPattern(object) => {
+            let ObjectPattern { span, properties, rest } = object.unbox();
+            let properties = ctx.ast.vec_from_iter(
+                properties
+                    .into_iter()
+                    .map(|property| binding_property_to_target_property(property, ctx)),
+            );
+            let rest = rest.map(|rest| binding_rest_to_assignment_target_rest(rest.unbox(), ctx));
+            AssignmentTarget::from(
+                ctx.ast.assignment_target_pattern_object_assignment_target(span, properties, rest),
+            )
+        }
+        BindingPattern::ArrayPattern(array) => {
+            let ArrayPattern { span, elements, rest } = array.unbox();
+            let elements = ctx.ast.vec_from_iter(elements.into_iter().map(|element| {
+                element.map(|element| binding_pattern_to_maybe_default(element, ctx))
+            }));
+            let rest = rest.map(|rest| binding_rest_to_assignment_target_rest(rest.unbox(), ctx));
+            AssignmentTarget::from(
+                ctx.ast.assignment_target_pattern_array_assignment_target(span, elements, rest),
+            )
+        }
+        BindingPattern::AssignmentPattern(_) => unreachable!(),
+    }
+}
+
+/// `[a = 1]` -> `[a = 1]`
+fn binding_pattern_to_maybe_default<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTargetMaybeDefault<'a> {
+    match pattern {
+        BindingPattern::AssignmentPattern(assign) => {
+            let AssignmentPattern { span, left, right } = assign.unbox();
+            let binding = binding_pattern_to_assignment_target(left, ctx);
+            ctx.ast.assignment_target_maybe_default_assignment_target_with_default(
+                span, binding, right,
+            )
+        }
+        pattern => {
+            AssignmentTargetMaybeDefault::from(binding_pattern_to_assignment_target(pattern, ctx))
+        }
+    }
+}
+
+/// `{ a, b: c }` -> `{ a, b: c }`
+fn binding_property_to_target_property<'a>(
+    property: BindingProperty<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTargetProperty<'a> {
+    let BindingProperty { span, key, value, shorthand, computed } = property;
+    if shorthand {
+        let (ident, init) = match value {
+            BindingPattern::BindingIdentifier(ident) => (ident, None),
+            BindingPattern::AssignmentPattern(assign) => {
+                let AssignmentPattern { left, right, .. } = assign.unbox();
+                let BindingPattern::BindingIdentifier(ident) = left else { unreachable!() };
+                (ident, Some(right))
+            }
+            _ => unreachable!(),
+        };
+        let binding = BoundIdentifier::from_binding_ident(&ident)
+            .create_spanned_write_reference(ident.span, ctx);
+        return ctx
+            .ast
+            .assignment_target_property_assignment_target_property_identifier(span, binding, init);
+    }
+    let binding = binding_pattern_to_maybe_default(value, ctx);
+    ctx.ast.assignment_target_property_assignment_target_property_property(
+        span, key, binding, computed,
+    )
+}
+
+/// `...rest` -> `...rest`
+fn binding_rest_to_assignment_target_rest<'a>(
+    rest: BindingRestElement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> oxc_allocator::Box<'a, AssignmentTargetRest<'a>> {
+    let target = binding_pattern_to_assignment_target(rest.argument, ctx);
+    ctx.ast.alloc_assignment_target_rest(rest.span, target)
+}
+
+/// Renames bindings and references to them.
+pub(super) struct SymbolRenamer<'a, 'v> {
+    renamed_symbols: FxHashMap<SymbolId, Atom<'a>>,
+    ctx: &'v TraverseCtx<'a>,
+}
+
+impl<'a, 'v> SymbolRenamer<'a, 'v> {
+    pub(super) fn new(
+        renamed_symbols: FxHashMap<SymbolId, Atom<'a>>,
+        ctx: &'v TraverseCtx<'a>,
+    ) -> Self {
+        Self { renamed_symbols, ctx }
+    }
+}
+
+impl<'a> VisitMut<'a> for SymbolRenamer<'a, '_> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(new_name) = self.renamed_symbols.get(&ident.symbol_id()) {
+            ident.name = (*new_name).into();
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if let Some(symbol_id) = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+            && let Some(new_name) = self.renamed_symbols.get(&symbol_id)
+        {
+            ident.name = (*new_name).into();
+        }
+    }
+}
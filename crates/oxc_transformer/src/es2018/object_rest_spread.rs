@@ -62,7 +62,15 @@ pub struct ObjectRestSpread<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
-    pub fn new(options: ObjectRestSpreadOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+    /// Unsupported options are only reported if the plugin is enabled (`options` is `Some`).
+    pub fn new(options: Option<ObjectRestSpreadOptions>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let Some(options) = options else {
+            return Self {
+                ctx,
+                options: ObjectRestSpreadOptions::default(),
+                excluded_variable_declarators: vec![],
+            };
+        };
         if options.loose {
             ctx.error(OxcDiagnostic::error(
                 "Option `loose` is not implemented for object-rest-spread.",
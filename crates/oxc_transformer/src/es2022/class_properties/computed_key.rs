@@ -4,7 +4,7 @@
 use oxc_allocator::TakeIn;
 use oxc_ast::ast::*;
 
-use crate::context::TraverseCtx;
+use crate::{context::TraverseCtx, utils::reparent_child_scopes};
 
 use super::ClassProperties;
 
@@ -36,6 +36,7 @@ impl<'a> ClassProperties<'a, '_> {
         //    or class contains a static block which is being transformed
         //    (static blocks are always evaluated after computed keys, regardless of order)
         let original_key = key.take_in(ctx.ast);
+        reparent_child_scopes(&original_key, ctx.current_scope_id(), ctx);
         let (assignment, temp_var) = self.ctx.create_computed_key_temp_var(original_key, ctx);
         self.insert_before.push(assignment);
         method.key = PropertyKey::from(temp_var);
@@ -67,6 +68,7 @@ impl<'a> ClassProperties<'a, '_> {
         if self.ctx.key_needs_temp_var(&original_key, ctx) {
             let (assignment, ident) = self.ctx.create_computed_key_temp_var(original_key, ctx);
             if is_static {
+                reparent_child_scopes(&assignment, ctx.current_scope_id(), ctx);
                 self.insert_before.push(assignment);
             } else {
                 *key = assignment;
@@ -95,14 +97,16 @@ impl<'a> ClassProperties<'a, '_> {
     pub(super) fn extract_computed_key(
         &mut self,
         prop: &mut PropertyDefinition<'a>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
         let Some(key) = prop.key.as_expression_mut() else {
             return;
         };
 
         if self.ctx.key_needs_temp_var(key, ctx) {
-            self.insert_before.push(key.take_in(ctx.ast));
+            let key = key.take_in(ctx.ast);
+            reparent_child_scopes(&key, ctx.current_scope_id(), ctx);
+            self.insert_before.push(key);
         }
     }
 
@@ -125,7 +129,7 @@ impl<'a> ClassProperties<'a, '_> {
     pub(super) fn extract_instance_prop_computed_key(
         &mut self,
         prop: &mut PropertyDefinition<'a>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
         // Exit if computed key is not an assignment (wasn't processed in 1st pass)
         if !matches!(&prop.key, PropertyKey::AssignmentExpression(_)) {
@@ -158,6 +162,7 @@ impl<'a> ClassProperties<'a, '_> {
 
         // Extract assignment from computed key and insert before class
         let assignment = prop.key.take_in(ctx.ast).into_expression();
+        reparent_child_scopes(&assignment, ctx.current_scope_id(), ctx);
         self.insert_before.push(assignment);
     }
 }
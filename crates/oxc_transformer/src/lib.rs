@@ -38,6 +38,7 @@ mod regexp;
 mod typescript;
 
 mod decorator;
+mod modules;
 mod plugins;
 
 use common::Common;
@@ -53,6 +54,7 @@ use es2021::ES2021;
 use es2022::ES2022;
 use es2026::ES2026;
 use jsx::Jsx;
+use modules::Modules;
 use regexp::RegExp;
 use rustc_hash::FxHashMap;
 use state::TransformState;
@@ -63,7 +65,7 @@ pub use crate::{
     common::helper_loader::{Helper, HelperLoaderMode, HelperLoaderOptions},
     compiler_assumptions::CompilerAssumptions,
     decorator::DecoratorOptions,
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{ArrowFunctionsOptions, BlockScopingOptions, ES2015Options, ForOfOptions},
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::ES2018Options,
@@ -163,6 +165,7 @@ impl<'a> Transformer<'a> {
             x2_es2017: ES2017::new(self.env.es2017, &self.ctx),
             x3_es2015: ES2015::new(self.env.es2015, &self.ctx),
             x4_regexp: RegExp::new(self.env.regexp, &self.ctx),
+            x5_modules: Modules::new(&self.ctx),
         };
 
         let state = TransformState::default();
@@ -187,9 +190,9 @@ struct TransformerImpl<'a, 'ctx> {
     x2_es2018: ES2018<'a, 'ctx>,
     x2_es2017: ES2017<'a, 'ctx>,
     x2_es2016: ES2016<'a, 'ctx>,
-    #[expect(unused)]
     x3_es2015: ES2015<'a, 'ctx>,
     x4_regexp: RegExp<'a, 'ctx>,
+    x5_modules: Modules<'a, 'ctx>,
     common: Common<'a, 'ctx>,
 }
 
@@ -201,6 +204,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.plugins.enter_program(program, ctx);
         self.x1_jsx.enter_program(program, ctx);
         self.x2_es2026.enter_program(program, ctx);
+        self.x5_modules.enter_program(program, ctx);
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -212,7 +216,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2022.exit_program(program, ctx);
         self.x2_es2020.exit_program(program, ctx);
         self.x2_es2018.exit_program(program, ctx);
+        self.x3_es2015.exit_program(program, ctx);
         self.common.exit_program(program, ctx);
+        // Must run after `Common`, so imports and helpers it inserts are transformed too
+        self.x5_modules.exit_program(program, ctx);
     }
 
     // ALPHASORT
@@ -236,6 +243,14 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2018.enter_variable_declaration(decl, ctx);
     }
 
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.exit_variable_declaration(decl, ctx);
+    }
+
     fn enter_variable_declarator(
         &mut self,
         decl: &mut VariableDeclarator<'a>,
@@ -349,6 +364,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_expression(expr, ctx);
         }
+        self.decorator.enter_expression(expr, ctx);
         self.plugins.enter_expression(expr, ctx);
         self.x2_es2022.enter_expression(expr, ctx);
         self.x2_es2021.enter_expression(expr, ctx);
@@ -356,6 +372,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2018.enter_expression(expr, ctx);
         self.x2_es2016.enter_expression(expr, ctx);
         self.x4_regexp.enter_expression(expr, ctx);
+        self.x5_modules.enter_expression(expr, ctx);
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -364,6 +381,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2022.exit_expression(expr, ctx);
         self.x2_es2018.exit_expression(expr, ctx);
         self.x2_es2017.exit_expression(expr, ctx);
+        self.x3_es2015.exit_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -425,6 +443,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x1_jsx.exit_function(func, ctx);
         self.x2_es2018.exit_function(func, ctx);
         self.x2_es2017.exit_function(func, ctx);
+        self.x3_es2015.exit_function(func, ctx);
         self.common.exit_function(func, ctx);
     }
 
@@ -546,6 +565,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_statements(stmts, ctx);
         }
+        self.decorator.enter_statements(stmts, ctx);
     }
 
     fn exit_arrow_function_expression(
@@ -553,6 +573,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         arrow: &mut ArrowFunctionExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        self.x3_es2015.exit_arrow_function_expression(arrow, ctx);
         self.common.exit_arrow_function_expression(arrow, ctx);
 
         // Some plugins may add new statements to the ArrowFunctionExpression's body,
@@ -604,6 +625,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.decorator.exit_statement(stmt, ctx);
         self.x2_es2018.exit_statement(stmt, ctx);
         self.x2_es2017.exit_statement(stmt, ctx);
+        self.x3_es2015.exit_statement(stmt, ctx);
     }
 
     fn enter_tagged_template_expression(
@@ -667,6 +689,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2018.enter_for_of_statement(stmt, ctx);
     }
 
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_for_of_statement(stmt, ctx);
+    }
+
     fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_in_statement(stmt, ctx);
@@ -674,6 +700,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2018.enter_for_in_statement(stmt, ctx);
     }
 
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_for_in_statement(stmt, ctx);
+    }
+
     fn enter_try_statement(&mut self, stmt: &mut TryStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x2_es2026.enter_try_statement(stmt, ctx);
     }
@@ -683,6 +713,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2018.enter_catch_clause(clause, ctx);
     }
 
+    fn exit_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_catch_clause(clause, ctx);
+    }
+
     fn enter_import_declaration(
         &mut self,
         node: &mut ImportDeclaration<'a>,
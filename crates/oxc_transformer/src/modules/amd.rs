@@ -0,0 +1,62 @@
+//! ES Modules to AMD
+//!
+//! > This plugin is included in `preset-env`, when `modules` is `"amd"`
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo from "foo";
+//! export default foo(import.meta.url);
+//! ```
+//!
+//! Output:
+//! ```js
+//! define(["exports", "module", "foo"], function (_exports, _module, _foo) {
+//!   "use strict";
+//!   Object.defineProperty(_exports, "__esModule", { value: true });
+//!   _foo = _interopRequireDefault(_foo);
+//!   _exports.default = (0, _foo.default)(new URL(_module.uri, document.baseURI).href);
+//! });
+//! ```
+//!
+//! See [`factory`](super::factory) for how the module body is transformed.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-modules-amd](https://babeljs.io/docs/babel-plugin-transform-modules-amd).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.28.4/packages/babel-plugin-transform-modules-amd>
+//! * AMD specification: <https://github.com/amdjs/amdjs-api/blob/master/AMD.md>
+
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::ReferenceFlags;
+use oxc_span::SPAN;
+
+use crate::context::TraverseCtx;
+
+use super::factory::Factory;
+
+/// `define(["exports", "foo"], function (_exports, _foo) { ... });`
+pub fn wrap<'a>(program: &mut Program<'a>, factory: Factory<'a>, ctx: &mut TraverseCtx<'a>) {
+    let Factory { function, dependencies, has_require, has_exports, has_module } = factory;
+
+    let special_dependencies =
+        [(has_require, "require"), (has_exports, "exports"), (has_module, "module")]
+            .into_iter()
+            .filter(|&(has, _)| has)
+            .map(|(_, name)| Atom::from(name));
+    let names = special_dependencies.chain(dependencies.iter().map(|dependency| dependency.source));
+    let elements = ctx.ast.vec_from_iter(names.map(|name| {
+        ArrayExpressionElement::from(ctx.ast.expression_string_literal(SPAN, name, None))
+    }));
+
+    let callee = ctx.create_unbound_ident_expr(SPAN, Atom::from("define"), ReferenceFlags::Read);
+    let arguments = ctx.ast.vec_from_array([
+        Argument::from(ctx.ast.expression_array(SPAN, elements)),
+        Argument::from(function),
+    ]);
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    program.body = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call));
+}
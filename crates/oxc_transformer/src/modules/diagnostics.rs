@@ -0,0 +1,19 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+#[cold]
+pub fn dynamic_import_unsupported(format: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Dynamic `import()` is not supported in {format} modules."))
+        .with_label(span)
+}
+
+#[cold]
+pub fn import_meta_unsupported(format: &str, span: Span) -> OxcDiagnostic {
+    let diagnostic =
+        OxcDiagnostic::warn(format!("`import.meta` is not supported in {format} modules."));
+    if format == "AMD" {
+        diagnostic.with_help("Only `import.meta.url` is supported.").with_label(span)
+    } else {
+        diagnostic.with_label(span)
+    }
+}
@@ -0,0 +1,1022 @@
+//! Module factory, shared by AMD and UMD transforms.
+//!
+//! Module body is moved into a factory function, which receives the `exports` object and the
+//! module's dependencies as parameters.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo, { bar } from "foo";
+//! export const x = foo(bar);
+//! ```
+//!
+//! Output:
+//! ```js
+//! function (_exports, _foo) {
+//!   "use strict";
+//!   Object.defineProperty(_exports, "__esModule", { value: true });
+//!   Object.defineProperty(_exports, "x", { enumerable: true, get: function () { return x; } });
+//!   _foo = _interopRequireWildcard(_foo);
+//!   const x = (0, _foo.default)(_foo.bar);
+//! }
+//! ```
+//!
+//! Exports are defined as getters, and references to imports are replaced with property accesses
+//! on the dependency, so that both imports and exports are live bindings.
+//!
+//! Top level `this` is replaced with `void 0`.
+//!
+//! In AMD, `import()` is transformed to an async `require` call, and `import.meta.url` is transformed
+//! to the URL of the module. UMD doesn't support either of them.
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{SPAN, Span};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    Helper,
+    context::{TransformCtx, TraverseCtx},
+};
+
+use super::{
+    create_define_getter, create_define_property, create_function_expression, create_getter,
+    create_member_expression, create_object_property, create_property_key, diagnostics,
+    module_name, move_root_bindings, take_directives,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FactoryFormat {
+    Amd,
+    Umd,
+}
+
+/// A module the module being transformed depends on.
+pub struct Dependency<'a> {
+    pub source: Atom<'a>,
+    /// Parameter of the factory function which receives the module
+    pub binding: BoundIdentifier<'a>,
+    interop: Option<Helper>,
+    /// `export * from "source"`
+    export_all: bool,
+}
+
+/// Module body transformed into a factory function.
+pub struct Factory<'a> {
+    /// Factory function expression
+    pub function: Expression<'a>,
+    pub dependencies: Vec<Dependency<'a>>,
+    /// `true` if factory has `require` parameter. AMD only.
+    pub has_require: bool,
+    /// `true` if factory has `exports` parameter
+    pub has_exports: bool,
+    /// `true` if factory has `module` parameter. AMD only.
+    pub has_module: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ImportName<'a> {
+    Default,
+    Named(Atom<'a>),
+    Namespace,
+}
+
+impl<'a> ImportName<'a> {
+    fn new(name: Atom<'a>) -> Self {
+        if name == "default" { Self::Default } else { Self::Named(name) }
+    }
+}
+
+enum ExportValue<'a> {
+    /// Binding declared in this module
+    Local(BoundIdentifier<'a>),
+    /// Binding imported from a dependency
+    Import(usize, ImportName<'a>),
+}
+
+/// Determine which interop helper is required for each module imported in `program`.
+///
+/// * Namespace import, or default import combined with named imports: `interopRequireWildcard`.
+/// * Default import only: `interopRequireDefault`.
+/// * Named imports only: no interop.
+pub fn collect_interop<'a>(program: &Program<'a>) -> FxHashMap<Atom<'a>, Helper> {
+    #[derive(Default)]
+    struct Usage {
+        namespace: bool,
+        default: bool,
+        named: bool,
+    }
+
+    let mut usages = FxHashMap::<Atom<'a>, Usage>::default();
+    for stmt in &program.body {
+        match stmt {
+            Statement::ImportDeclaration(decl) => {
+                let usage = usages.entry(decl.source.value).or_default();
+                for specifier in decl.specifiers.iter().flatten() {
+                    match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                            if specifier.imported.name() == "default" {
+                                usage.default = true;
+                            } else {
+                                usage.named = true;
+                            }
+                        }
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                            usage.default = true;
+                        }
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {
+                            usage.namespace = true;
+                        }
+                    }
+                }
+            }
+            Statement::ExportNamedDeclaration(decl) => {
+                let Some(source) = &decl.source else { continue };
+                let usage = usages.entry(source.value).or_default();
+                for specifier in &decl.specifiers {
+                    if specifier.local.name() == "default" {
+                        usage.default = true;
+                    } else {
+                        usage.named = true;
+                    }
+                }
+            }
+            Statement::ExportAllDeclaration(decl) if decl.exported.is_some() => {
+                usages.entry(decl.source.value).or_default().namespace = true;
+            }
+            _ => {}
+        }
+    }
+
+    usages
+        .into_iter()
+        .filter_map(|(source, usage)| {
+            let helper = if usage.namespace || (usage.default && usage.named) {
+                Helper::InteropRequireWildcard
+            } else if usage.default {
+                Helper::InteropRequireDefault
+            } else {
+                return None;
+            };
+            Some((source, helper))
+        })
+        .collect()
+}
+
+/// Transform module body into a factory function.
+///
+/// `interop` is the result of [`collect_interop`] in `enter_program`. Imports added by other
+/// transforms are not included in it, and don't need interop as they point to ES modules.
+pub fn transform<'a>(
+    program: &mut Program<'a>,
+    format: FactoryFormat,
+    interop: &FxHashMap<Atom<'a>, Helper>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Factory<'a> {
+    let root_scope_id = program.scope_id();
+    let scope_id = ctx.insert_scope_below_statements(
+        &program.body,
+        ScopeFlags::Function | ScopeFlags::StrictMode,
+    );
+
+    move_root_bindings(root_scope_id, scope_id, ctx);
+
+    let has_exports = program.body.iter().any(|stmt| {
+        matches!(
+            stmt,
+            Statement::ExportNamedDeclaration(_)
+                | Statement::ExportDefaultDeclaration(_)
+                | Statement::ExportAllDeclaration(_)
+        )
+    });
+    let exports_binding = has_exports
+        .then(|| ctx.generate_uid("exports", scope_id, SymbolFlags::FunctionScopedVariable));
+
+    let mut collector = ModuleCollector {
+        scope_id,
+        interop,
+        dependencies: vec![],
+        dependency_indexes: FxHashMap::default(),
+        imports: FxHashMap::default(),
+        exports: vec![],
+    };
+
+    let body = program.body.take_in(ctx.ast);
+    // Imports are hoisted, so collect them first. They may be re-exported by an earlier statement.
+    for stmt in &body {
+        if let Statement::ImportDeclaration(decl) = stmt {
+            collector.collect_import(decl, ctx);
+        }
+    }
+
+    let mut statements = ctx.ast.vec_with_capacity(body.len());
+    for stmt in body {
+        match stmt {
+            Statement::ImportDeclaration(_) => {}
+            Statement::ExportNamedDeclaration(decl) => {
+                if let Some(declaration) = collector.collect_export_named(decl.unbox(), ctx) {
+                    statements.push(Statement::from(declaration));
+                }
+            }
+            Statement::ExportDefaultDeclaration(decl) => {
+                let exports_binding = exports_binding.as_ref().unwrap();
+                statements.push(collector.collect_export_default(
+                    decl.unbox(),
+                    exports_binding,
+                    ctx,
+                ));
+            }
+            Statement::ExportAllDeclaration(decl) => collector.collect_export_all(&decl, ctx),
+            _ => statements.push(stmt),
+        }
+    }
+
+    let ModuleCollector { dependencies, imports, exports, .. } = collector;
+
+    let mut body = ctx.ast.vec();
+    if let Some(exports_binding) = &exports_binding {
+        create_exports(exports_binding, &exports, &dependencies, scope_id, &mut body, ctx);
+    }
+    for dependency in &dependencies {
+        if let Some(helper) = dependency.interop {
+            // `_foo = _interopRequireDefault(_foo);`
+            let arguments =
+                ctx.ast.vec1(Argument::from(dependency.binding.create_read_expression(ctx)));
+            let value = transform_ctx.helper_call_expr(helper, SPAN, arguments, ctx);
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                dependency.binding.create_write_target(ctx),
+                value,
+            );
+            body.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+    }
+    if let Some(exports_binding) = &exports_binding {
+        let export_names = create_export_names(&exports, &dependencies, scope_id, &mut body, ctx);
+        for dependency in dependencies.iter().filter(|dependency| dependency.export_all) {
+            body.push(create_export_all(
+                &dependency.binding,
+                exports_binding,
+                export_names.as_ref(),
+                scope_id,
+                ctx,
+            ));
+        }
+    }
+    body.extend(statements);
+
+    let mut rewriter = ModuleBodyRewriter {
+        format,
+        imports: &imports,
+        dependencies: &dependencies,
+        scope_stack: vec![scope_id],
+        this_depth: 0,
+        reparent_scopes_to: None,
+        require_binding: None,
+        module_binding: None,
+        transform_ctx,
+        ctx,
+    };
+    rewriter.visit_statements(&mut body);
+    let ModuleBodyRewriter { require_binding, module_binding, .. } = rewriter;
+
+    let mut params = vec![];
+    params.extend(require_binding.as_ref());
+    params.extend(exports_binding.as_ref());
+    params.extend(module_binding.as_ref());
+    params.extend(dependencies.iter().map(|dependency| &dependency.binding));
+
+    let directives = take_directives(program, ctx);
+    let function = create_function_expression(&params, directives, body, scope_id, ctx);
+
+    Factory {
+        function,
+        dependencies,
+        has_require: require_binding.is_some(),
+        has_exports,
+        has_module: module_binding.is_some(),
+    }
+}
+
+/// Collects imports and exports of the module.
+struct ModuleCollector<'a, 'i> {
+    /// Factory function scope
+    scope_id: ScopeId,
+    interop: &'i FxHashMap<Atom<'a>, Helper>,
+    dependencies: Vec<Dependency<'a>>,
+    dependency_indexes: FxHashMap<Atom<'a>, usize>,
+    /// Imported bindings, mapped to index of their dependency and imported name
+    imports: FxHashMap<SymbolId, (usize, ImportName<'a>)>,
+    /// Exported name and value, in order of appearance
+    exports: Vec<(Atom<'a>, ExportValue<'a>)>,
+}
+
+impl<'a> ModuleCollector<'a, '_> {
+    fn get_dependency(&mut self, source: &StringLiteral<'a>, ctx: &mut TraverseCtx<'a>) -> usize {
+        *self.dependency_indexes.entry(source.value).or_insert_with(|| {
+            let binding = ctx.generate_uid(
+                &module_name(&source.value),
+                self.scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            self.dependencies.push(Dependency {
+                source: source.value,
+                binding,
+                interop: self.interop.get(&source.value).copied(),
+                export_all: false,
+            });
+            self.dependencies.len() - 1
+        })
+    }
+
+    /// `import foo, { bar } from "foo"`
+    fn collect_import(&mut self, decl: &ImportDeclaration<'a>, ctx: &mut TraverseCtx<'a>) {
+        let index = self.get_dependency(&decl.source, ctx);
+        for specifier in decl.specifiers.iter().flatten() {
+            let name = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    ImportName::new(specifier.imported.name())
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => ImportName::Default,
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => ImportName::Namespace,
+            };
+            let local = specifier.local();
+            ctx.scoping_mut().remove_binding(self.scope_id, &local.name);
+            self.imports.insert(local.symbol_id(), (index, name));
+        }
+    }
+
+    /// * `export const x = 1;` -> `const x = 1;`
+    /// * `export { x, y as z };` -> removed
+    /// * `export { x } from "foo";` -> removed
+    fn collect_export_named(
+        &mut self,
+        decl: ExportNamedDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Declaration<'a>> {
+        if let Some(declaration) = decl.declaration {
+            declaration.bound_names(&mut |ident| {
+                let binding = BoundIdentifier::from_binding_ident(ident);
+                self.exports.push((binding.name, ExportValue::Local(binding)));
+            });
+            return Some(declaration);
+        }
+
+        if let Some(source) = &decl.source {
+            let index = self.get_dependency(source, ctx);
+            for specifier in &decl.specifiers {
+                let name = ImportName::new(specifier.local.name());
+                self.exports.push((specifier.exported.name(), ExportValue::Import(index, name)));
+            }
+            return None;
+        }
+
+        for specifier in &decl.specifiers {
+            let ModuleExportName::IdentifierReference(local) = &specifier.local else {
+                continue;
+            };
+            let symbol_id = ctx.scoping().get_reference(local.reference_id()).symbol_id();
+            ctx.delete_reference_for_identifier(local);
+            let Some(symbol_id) = symbol_id else { continue };
+            let value = match self.imports.get(&symbol_id) {
+                Some(&(index, name)) => ExportValue::Import(index, name),
+                None => ExportValue::Local(BoundIdentifier::new(local.name.into(), symbol_id)),
+            };
+            self.exports.push((specifier.exported.name(), value));
+        }
+        None
+    }
+
+    /// * `export default function foo() {}` -> `function foo() {}`
+    /// * `export default function () {}` -> `function _default() {}`
+    /// * `export default expr;` -> `_exports.default = expr;`
+    fn collect_export_default(
+        &mut self,
+        decl: ExportDefaultDeclaration<'a>,
+        exports_binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let default_name = Atom::from("default");
+        match decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                let binding = self.get_or_create_id(&mut func.id, SymbolFlags::Function, ctx);
+                self.exports.push((default_name, ExportValue::Local(binding)));
+                Statement::FunctionDeclaration(func)
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                let binding = self.get_or_create_id(&mut class.id, SymbolFlags::Class, ctx);
+                self.exports.push((default_name, ExportValue::Local(binding)));
+                Statement::ClassDeclaration(class)
+            }
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {
+                ctx.ast.statement_empty(SPAN)
+            }
+            declaration => {
+                let value = declaration.into_expression();
+                let object = exports_binding.create_read_expression(ctx);
+                let target = SimpleAssignmentTarget::from(
+                    create_member_expression(object, default_name, ctx).into_member_expression(),
+                );
+                let assignment = ctx.ast.expression_assignment(
+                    decl.span,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    value,
+                );
+                ctx.ast.statement_expression(decl.span, assignment)
+            }
+        }
+    }
+
+    fn get_or_create_id(
+        &self,
+        id: &mut Option<BindingIdentifier<'a>>,
+        flags: SymbolFlags,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if let Some(id) = id {
+            BoundIdentifier::from_binding_ident(id)
+        } else {
+            let binding = ctx.generate_uid("default", self.scope_id, flags);
+            *id = Some(binding.create_binding_identifier(ctx));
+            binding
+        }
+    }
+
+    /// `export * from "foo"` or `export * as ns from "foo"`
+    fn collect_export_all(&mut self, decl: &ExportAllDeclaration<'a>, ctx: &mut TraverseCtx<'a>) {
+        let index = self.get_dependency(&decl.source, ctx);
+        if let Some(exported) = &decl.exported {
+            self.exports.push((exported.name(), ExportValue::Import(index, ImportName::Namespace)));
+        } else {
+            self.dependencies[index].export_all = true;
+        }
+    }
+}
+
+/// `_foo`, `_foo.default` or `_foo.name`
+fn create_import_value<'a>(
+    dependency: &Dependency<'a>,
+    name: ImportName<'a>,
+    span: Span,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let object = dependency.binding.create_spanned_read_expression(span, ctx);
+    match name {
+        ImportName::Namespace => object,
+        ImportName::Default => create_member_expression(object, Atom::from("default"), ctx),
+        ImportName::Named(name) => create_member_expression(object, name, ctx),
+    }
+}
+
+/// ```js
+/// Object.defineProperty(_exports, "__esModule", { value: true });
+/// Object.defineProperty(_exports, "x", { enumerable: true, get: function () { return x; } });
+/// ```
+fn create_exports<'a>(
+    exports_binding: &BoundIdentifier<'a>,
+    exports: &[(Atom<'a>, ExportValue<'a>)],
+    dependencies: &[Dependency<'a>],
+    scope_id: ScopeId,
+    body: &mut ArenaVec<'a, Statement<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let descriptor = ctx.ast.expression_object(
+        SPAN,
+        ctx.ast.vec1(create_object_property(
+            "value",
+            ctx.ast.expression_boolean_literal(SPAN, true),
+            ctx,
+        )),
+    );
+    let object = exports_binding.create_read_expression(ctx);
+    let name = ctx.ast.expression_string_literal(SPAN, Atom::from("__esModule"), None);
+    body.push(create_define_property(object, name, descriptor, ctx));
+
+    for (exported, value) in exports {
+        let value = match value {
+            ExportValue::Local(binding) => binding.create_read_expression(ctx),
+            ExportValue::Import(index, name) => {
+                create_import_value(&dependencies[*index], *name, SPAN, ctx)
+            }
+        };
+        let getter = create_getter(value, scope_id, ctx);
+        let object = exports_binding.create_read_expression(ctx);
+        let name = ctx.ast.expression_string_literal(SPAN, *exported, None);
+        body.push(create_define_getter(object, name, getter, ctx));
+    }
+}
+
+/// `var _exportNames = { x: true, y: true };`
+///
+/// Only created when module has both `export * from "foo"` and its own exports, so `export *`
+/// doesn't overwrite them.
+fn create_export_names<'a>(
+    exports: &[(Atom<'a>, ExportValue<'a>)],
+    dependencies: &[Dependency<'a>],
+    scope_id: ScopeId,
+    body: &mut ArenaVec<'a, Statement<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Option<BoundIdentifier<'a>> {
+    if exports.is_empty() || !dependencies.iter().any(|dependency| dependency.export_all) {
+        return None;
+    }
+
+    let binding = ctx.generate_uid("exportNames", scope_id, SymbolFlags::FunctionScopedVariable);
+    let properties = ctx.ast.vec_from_iter(exports.iter().map(|(exported, _)| {
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            create_property_key(*exported, ctx),
+            ctx.ast.expression_boolean_literal(SPAN, true),
+            false,
+            false,
+            false,
+        )
+    }));
+    let init = ctx.ast.expression_object(SPAN, properties);
+    let declarator = ctx.ast.variable_declarator(
+        SPAN,
+        VariableDeclarationKind::Var,
+        binding.create_binding_pattern(ctx),
+        NONE,
+        Some(init),
+        false,
+    );
+    body.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        VariableDeclarationKind::Var,
+        ctx.ast.vec1(declarator),
+        false,
+    )));
+    Some(binding)
+}
+
+/// ```js
+/// Object.keys(_foo).forEach(function (key) {
+///   if (key === "default" || key === "__esModule") return;
+///   if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+///   if (key in _exports && _exports[key] === _foo[key]) return;
+///   Object.defineProperty(_exports, key, { enumerable: true, get: function () { return _foo[key]; } });
+/// });
+/// ```
+fn create_export_all<'a>(
+    dependency: &BoundIdentifier<'a>,
+    exports_binding: &BoundIdentifier<'a>,
+    export_names: Option<&BoundIdentifier<'a>>,
+    scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let callback_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::Function);
+    let key = ctx.generate_binding(
+        Atom::from("key"),
+        callback_scope_id,
+        SymbolFlags::FunctionScopedVariable,
+    );
+
+    let mut statements = ctx.ast.vec_with_capacity(4);
+
+    // `if (key === "default" || key === "__esModule") return;`
+    let is_default = create_strict_equals(
+        key.create_read_expression(ctx),
+        ctx.ast.expression_string_literal(SPAN, Atom::from("default"), None),
+        ctx,
+    );
+    let is_es_module = create_strict_equals(
+        key.create_read_expression(ctx),
+        ctx.ast.expression_string_literal(SPAN, Atom::from("__esModule"), None),
+        ctx,
+    );
+    let test = ctx.ast.expression_logical(SPAN, is_default, LogicalOperator::Or, is_es_module);
+    statements.push(create_if_return(test, ctx));
+
+    // `if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;`
+    if let Some(export_names) = export_names {
+        let object =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+        let callee = ["prototype", "hasOwnProperty", "call"]
+            .into_iter()
+            .fold(object, |object, name| create_member_expression(object, Atom::from(name), ctx));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(export_names.create_read_expression(ctx)),
+            Argument::from(key.create_read_expression(ctx)),
+        ]);
+        let test = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        statements.push(create_if_return(test, ctx));
+    }
+
+    // `if (key in _exports && _exports[key] === _foo[key]) return;`
+    let key_in_exports = ctx.ast.expression_binary(
+        SPAN,
+        key.create_read_expression(ctx),
+        BinaryOperator::In,
+        exports_binding.create_read_expression(ctx),
+    );
+    let exported = create_computed_member(exports_binding, &key, ctx);
+    let imported = create_computed_member(dependency, &key, ctx);
+    let is_same = create_strict_equals(exported, imported, ctx);
+    let test = ctx.ast.expression_logical(SPAN, key_in_exports, LogicalOperator::And, is_same);
+    statements.push(create_if_return(test, ctx));
+
+    // `Object.defineProperty(_exports, key, { enumerable: true, get: function () { return _foo[key]; } });`
+    let value = create_computed_member(dependency, &key, ctx);
+    let getter = create_getter(value, callback_scope_id, ctx);
+    let object = exports_binding.create_read_expression(ctx);
+    let name = key.create_read_expression(ctx);
+    statements.push(create_define_getter(object, name, getter, ctx));
+
+    let callback =
+        create_function_expression(&[&key], ctx.ast.vec(), statements, callback_scope_id, ctx);
+
+    // `Object.keys(_foo).forEach(callback);`
+    let object = ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+    let keys = create_member_expression(object, Atom::from("keys"), ctx);
+    let arguments = ctx.ast.vec1(Argument::from(dependency.create_read_expression(ctx)));
+    let keys = ctx.ast.expression_call(SPAN, keys, NONE, arguments, false);
+    let callee = create_member_expression(keys, Atom::from("forEach"), ctx);
+    let arguments = ctx.ast.vec1(Argument::from(callback));
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    ctx.ast.statement_expression(SPAN, call)
+}
+
+/// `left === right`
+fn create_strict_equals<'a>(
+    left: Expression<'a>,
+    right: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictEquality, right)
+}
+
+/// `object[key]`
+fn create_computed_member<'a>(
+    object: &BoundIdentifier<'a>,
+    key: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let object = object.create_read_expression(ctx);
+    let key = key.create_read_expression(ctx);
+    Expression::from(ctx.ast.member_expression_computed(SPAN, object, key, false))
+}
+
+/// `if (test) return;`
+fn create_if_return<'a>(test: Expression<'a>, ctx: &TraverseCtx<'a>) -> Statement<'a> {
+    ctx.ast.statement_if(SPAN, test, ctx.ast.statement_return(SPAN, None), None)
+}
+
+/// Visitor which rewrites references to imports, top level `this`, `import()` and `import.meta`.
+struct ModuleBodyRewriter<'a, 'm, 'ctx, 'c> {
+    format: FactoryFormat,
+    imports: &'m FxHashMap<SymbolId, (usize, ImportName<'a>)>,
+    dependencies: &'m [Dependency<'a>],
+    scope_stack: Vec<ScopeId>,
+    /// Number of functions / class property initializers `this` is nested in
+    this_depth: u32,
+    /// Scopes entered at this depth of `scope_stack` are re-parented to this scope
+    reparent_scopes_to: Option<(usize, ScopeId)>,
+    /// AMD `require`, created on demand for `import()`
+    require_binding: Option<BoundIdentifier<'a>>,
+    /// AMD `module`, created on demand for `import.meta.url`
+    module_binding: Option<BoundIdentifier<'a>>,
+    transform_ctx: &'ctx TransformCtx<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for ModuleBodyRewriter<'a, '_, '_, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let scope_id = scope_id.get().unwrap();
+        if let Some((depth, parent_id)) = self.reparent_scopes_to
+            && depth == self.scope_stack.len()
+        {
+            self.ctx.scoping_mut().change_scope_parent_id(scope_id, Some(parent_id));
+        }
+        self.scope_stack.push(scope_id);
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) => {
+                if let Some(value) = self.import_value(ident) {
+                    *expr = value;
+                }
+                return;
+            }
+            Expression::ThisExpression(this) => {
+                if self.this_depth == 0 {
+                    *expr = self.ctx.ast.void_0(this.span);
+                }
+                return;
+            }
+            Expression::ImportExpression(_) if self.format == FactoryFormat::Amd => {
+                self.transform_dynamic_import(expr);
+                return;
+            }
+            Expression::ImportExpression(import) => {
+                self.transform_ctx
+                    .error(diagnostics::dynamic_import_unsupported("UMD", import.span));
+            }
+            Expression::StaticMemberExpression(member)
+                if self.format == FactoryFormat::Amd
+                    && member.property.name == "url"
+                    && matches!(&member.object, Expression::MetaProperty(meta) if is_import_meta(meta)) =>
+            {
+                *expr = self.create_module_url(member.span);
+                return;
+            }
+            Expression::MetaProperty(meta) if is_import_meta(meta) => {
+                let format = match self.format {
+                    FactoryFormat::Amd => "AMD",
+                    FactoryFormat::Umd => "UMD",
+                };
+                self.transform_ctx.error(diagnostics::import_meta_unsupported(format, meta.span));
+            }
+            _ => {}
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    /// `foo()` -> `(0, _foo.foo)()`, so `this` is not the dependency.
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        let is_import = self.is_member_import(&call.callee);
+        walk_mut::walk_call_expression(self, call);
+        if is_import {
+            call.callee = self.wrap_in_sequence(call.callee.take_in(self.ctx.ast));
+        }
+    }
+
+    /// ``foo`x` `` -> ``(0, _foo.foo)`x` ``, so `this` is not the dependency.
+    fn visit_tagged_template_expression(&mut self, expr: &mut TaggedTemplateExpression<'a>) {
+        let is_import = self.is_member_import(&expr.tag);
+        walk_mut::walk_tagged_template_expression(self, expr);
+        if is_import {
+            expr.tag = self.wrap_in_sequence(expr.tag.take_in(self.ctx.ast));
+        }
+    }
+
+    /// `{ foo }` -> `{ foo: _foo.foo }`
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        walk_mut::walk_object_property(self, prop);
+        if prop.shorthand && !matches!(prop.value, Expression::Identifier(_)) {
+            prop.shorthand = false;
+        }
+    }
+
+    /// `<Foo />` -> `<_foo.Foo />`
+    fn visit_jsx_element_name(&mut self, name: &mut JSXElementName<'a>) {
+        if let JSXElementName::IdentifierReference(ident) = name
+            && let Some(object) = self.create_jsx_object(ident)
+        {
+            *name = match object {
+                JSXMemberExpressionObject::IdentifierReference(ident) => {
+                    JSXElementName::IdentifierReference(ident)
+                }
+                JSXMemberExpressionObject::MemberExpression(member) => {
+                    JSXElementName::MemberExpression(member)
+                }
+                JSXMemberExpressionObject::ThisExpression(_) => unreachable!(),
+            };
+            return;
+        }
+        walk_mut::walk_jsx_element_name(self, name);
+    }
+
+    /// `<foo.Bar />` -> `<_foo.foo.Bar />`
+    fn visit_jsx_member_expression_object(&mut self, object: &mut JSXMemberExpressionObject<'a>) {
+        if let JSXMemberExpressionObject::IdentifierReference(ident) = object
+            && let Some(new_object) = self.create_jsx_object(ident)
+        {
+            *object = new_object;
+            return;
+        }
+        walk_mut::walk_jsx_member_expression_object(self, object);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.this_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.this_depth -= 1;
+    }
+
+    fn visit_static_block(&mut self, block: &mut StaticBlock<'a>) {
+        self.this_depth += 1;
+        walk_mut::walk_static_block(self, block);
+        self.this_depth -= 1;
+    }
+
+    fn visit_property_definition(&mut self, prop: &mut PropertyDefinition<'a>) {
+        self.visit_decorators(&mut prop.decorators);
+        self.visit_property_key(&mut prop.key);
+        if let Some(value) = &mut prop.value {
+            self.this_depth += 1;
+            self.visit_expression(value);
+            self.this_depth -= 1;
+        }
+    }
+
+    fn visit_accessor_property(&mut self, prop: &mut AccessorProperty<'a>) {
+        self.visit_decorators(&mut prop.decorators);
+        self.visit_property_key(&mut prop.key);
+        if let Some(value) = &mut prop.value {
+            self.this_depth += 1;
+            self.visit_expression(value);
+            self.this_depth -= 1;
+        }
+    }
+}
+
+impl<'a, 'm> ModuleBodyRewriter<'a, 'm, '_, '_> {
+    fn lookup_import(
+        &self,
+        ident: &IdentifierReference<'a>,
+    ) -> Option<(&'m Dependency<'a>, ImportName<'a>)> {
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        let &(index, name) = self.imports.get(&symbol_id)?;
+        let dependencies = self.dependencies;
+        Some((&dependencies[index], name))
+    }
+
+    /// `foo` -> `_foo.foo`, if `foo` is an import.
+    fn import_value(&mut self, ident: &IdentifierReference<'a>) -> Option<Expression<'a>> {
+        let (dependency, name) = self.lookup_import(ident)?;
+        self.ctx.delete_reference_for_identifier(ident);
+        Some(create_import_value(dependency, name, ident.span, self.ctx))
+    }
+
+    /// Check if `expr` is a reference to a default or named import.
+    fn is_member_import(&self, expr: &Expression<'a>) -> bool {
+        matches!(
+            expr,
+            Expression::Identifier(ident)
+                if matches!(self.lookup_import(ident), Some((_, ImportName::Default | ImportName::Named(_))))
+        )
+    }
+
+    /// `expr` -> `(0, expr)`
+    fn wrap_in_sequence(&self, expr: Expression<'a>) -> Expression<'a> {
+        let zero = self.ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal);
+        self.ctx.ast.expression_sequence(SPAN, self.ctx.ast.vec_from_array([zero, expr]))
+    }
+
+    /// `Foo` -> `_foo.Foo` in JSX, if `Foo` is an import.
+    fn create_jsx_object(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+    ) -> Option<JSXMemberExpressionObject<'a>> {
+        let (dependency, name) = self.lookup_import(ident)?;
+        self.ctx.delete_reference_for_identifier(ident);
+        let object = dependency.binding.create_spanned_read_reference(ident.span, self.ctx);
+        let object = JSXMemberExpressionObject::IdentifierReference(self.ctx.ast.alloc(object));
+        let property = match name {
+            ImportName::Namespace => return Some(object),
+            ImportName::Default => Atom::from("default"),
+            ImportName::Named(name) => name,
+        };
+        let property = self.ctx.ast.jsx_identifier(SPAN, property);
+        Some(JSXMemberExpressionObject::MemberExpression(
+            self.ctx.ast.alloc_jsx_member_expression(ident.span, object, property),
+        ))
+    }
+
+    /// ```js
+    /// import(source)
+    /// // ->
+    /// new Promise(function (_resolve, _reject) {
+    ///   _require([source], function (_imported) {
+    ///     _resolve(_interopRequireWildcard(_imported));
+    ///   }, _reject);
+    /// })
+    /// ```
+    fn transform_dynamic_import(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ImportExpression(import) = expr.take_in(self.ctx.ast) else {
+            unreachable!()
+        };
+        let ImportExpression { span, mut source, .. } = import.unbox();
+
+        let parent_scope_id = *self.scope_stack.last().unwrap();
+        let executor_scope_id = self.ctx.create_child_scope(parent_scope_id, ScopeFlags::Function);
+        let callback_scope_id =
+            self.ctx.create_child_scope(executor_scope_id, ScopeFlags::Function);
+
+        // `source` is moved into executor function
+        let previous = self.reparent_scopes_to.replace((self.scope_stack.len(), executor_scope_id));
+        self.visit_expression(&mut source);
+        self.reparent_scopes_to = previous;
+
+        let require_binding = self.require_binding();
+        let resolve = self.ctx.generate_uid(
+            "resolve",
+            executor_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let reject =
+            self.ctx.generate_uid("reject", executor_scope_id, SymbolFlags::FunctionScopedVariable);
+        let imported = self.ctx.generate_uid(
+            "imported",
+            callback_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+
+        // `function (_imported) { _resolve(_interopRequireWildcard(_imported)); }`
+        let arguments =
+            self.ctx.ast.vec1(Argument::from(imported.create_read_expression(self.ctx)));
+        let namespace = self.transform_ctx.helper_call_expr(
+            Helper::InteropRequireWildcard,
+            SPAN,
+            arguments,
+            self.ctx,
+        );
+        let callee = resolve.create_read_expression(self.ctx);
+        let arguments = self.ctx.ast.vec1(Argument::from(namespace));
+        let call = self.ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        let statements = self.ctx.ast.vec1(self.ctx.ast.statement_expression(SPAN, call));
+        let callback = create_function_expression(
+            &[&imported],
+            self.ctx.ast.vec(),
+            statements,
+            callback_scope_id,
+            self.ctx,
+        );
+
+        // `_require([source], callback, _reject);`
+        let dependencies = self
+            .ctx
+            .ast
+            .expression_array(SPAN, self.ctx.ast.vec1(ArrayExpressionElement::from(source)));
+        let callee = require_binding.create_read_expression(self.ctx);
+        let arguments = self.ctx.ast.vec_from_array([
+            Argument::from(dependencies),
+            Argument::from(callback),
+            Argument::from(reject.create_read_expression(self.ctx)),
+        ]);
+        let call = self.ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        let statements = self.ctx.ast.vec1(self.ctx.ast.statement_expression(SPAN, call));
+        let executor = create_function_expression(
+            &[&resolve, &reject],
+            self.ctx.ast.vec(),
+            statements,
+            executor_scope_id,
+            self.ctx,
+        );
+
+        // `new Promise(executor)`
+        let promise =
+            self.ctx.create_unbound_ident_expr(SPAN, Atom::from("Promise"), ReferenceFlags::Read);
+        let arguments = self.ctx.ast.vec1(Argument::from(executor));
+        *expr = self.ctx.ast.expression_new(span, promise, NONE, arguments);
+    }
+
+    /// `import.meta.url` -> `new URL(_module.uri, document.baseURI).href`
+    fn create_module_url(&mut self, span: Span) -> Expression<'a> {
+        let module_binding = self.module_binding();
+        let uri = create_member_expression(
+            module_binding.create_read_expression(self.ctx),
+            Atom::from("uri"),
+            self.ctx,
+        );
+        let document =
+            self.ctx.create_unbound_ident_expr(SPAN, Atom::from("document"), ReferenceFlags::Read);
+        let base_uri = create_member_expression(document, Atom::from("baseURI"), self.ctx);
+        let url = self.ctx.create_unbound_ident_expr(SPAN, Atom::from("URL"), ReferenceFlags::Read);
+        let arguments =
+            self.ctx.ast.vec_from_array([Argument::from(uri), Argument::from(base_uri)]);
+        let url = self.ctx.ast.expression_new(SPAN, url, NONE, arguments);
+        let property = self.ctx.ast.identifier_name(SPAN, Atom::from("href"));
+        Expression::from(self.ctx.ast.member_expression_static(span, url, property, false))
+    }
+
+    fn require_binding(&mut self) -> BoundIdentifier<'a> {
+        let scope_id = self.scope_stack[0];
+        self.require_binding
+            .get_or_insert_with(|| {
+                self.ctx.generate_uid("require", scope_id, SymbolFlags::FunctionScopedVariable)
+            })
+            .clone()
+    }
+
+    fn module_binding(&mut self) -> BoundIdentifier<'a> {
+        let scope_id = self.scope_stack[0];
+        self.module_binding
+            .get_or_insert_with(|| {
+                self.ctx.generate_uid("module", scope_id, SymbolFlags::FunctionScopedVariable)
+            })
+            .clone()
+    }
+}
+
+fn is_import_meta(meta: &MetaProperty) -> bool {
+    meta.meta.name == "import" && meta.property.name == "meta"
+}
@@ -0,0 +1,275 @@
+//! Module Transforms
+//!
+//! Transform ES modules to other module formats:
+//!
+//! * AMD: [`amd`]
+//! * UMD: [`umd`]
+//! * SystemJS: [`systemjs`]
+//!
+//! AMD and UMD share the same module body, a factory function which receives the module's
+//! dependencies as parameters (see [`factory`]). SystemJS has its own module body, using setters for
+//! imports and an `_export` function for exports.
+//!
+//! These transforms run after all other transforms, including `Common`'s `exit_program`,
+//! so imports and helpers inserted by other transforms are transformed too.
+//!
+//! ## References:
+//! * Babel AMD plugin: <https://github.com/babel/babel/tree/v7.28.4/packages/babel-plugin-transform-modules-amd>
+//! * Babel UMD plugin: <https://github.com/babel/babel/tree/v7.28.4/packages/babel-plugin-transform-modules-umd>
+//! * Babel SystemJS plugin: <https://github.com/babel/babel/tree/v7.28.4/packages/babel-plugin-transform-modules-systemjs>
+//! * SystemJS module format: <https://github.com/systemjs/systemjs/blob/main/docs/system-register.md>
+
+use std::path::Path;
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId};
+use oxc_span::SPAN;
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::{BoundIdentifier, Traverse, ast_operations::to_identifier};
+
+use crate::{
+    Helper, Module,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+mod amd;
+mod diagnostics;
+mod factory;
+mod systemjs;
+mod umd;
+
+use factory::FactoryFormat;
+
+pub struct Modules<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Interop helper for each module imported in source, keyed by module source.
+    /// Only used for AMD and UMD.
+    interop: FxHashMap<Atom<'a>, Helper>,
+}
+
+impl<'a, 'ctx> Modules<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, interop: FxHashMap::default() }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.ctx.source_type.is_module()
+            && matches!(self.ctx.module, Module::Amd | Module::Umd | Module::SystemJs)
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Modules<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_enabled() || self.ctx.module.is_systemjs() {
+            return;
+        }
+
+        // Helpers are inserted into program by `Common` before this transform's `exit_program`,
+        // so interop helpers must be loaded now
+        self.interop = factory::collect_interop(program);
+        let mut helpers = self.interop.values().copied().collect::<Vec<_>>();
+        helpers.sort_unstable_by_key(|helper| helper.name());
+        helpers.dedup();
+        for helper in helpers {
+            self.ctx.helper_preload(helper, ctx);
+        }
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        match self.ctx.module {
+            Module::Amd => {
+                let factory =
+                    factory::transform(program, FactoryFormat::Amd, &self.interop, self.ctx, ctx);
+                amd::wrap(program, factory, ctx);
+            }
+            Module::Umd => {
+                let factory =
+                    factory::transform(program, FactoryFormat::Umd, &self.interop, self.ctx, ctx);
+                umd::wrap(program, factory, self.ctx, ctx);
+            }
+            Module::SystemJs => systemjs::transform(program, self.ctx, ctx),
+            _ => {}
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `import()` in AMD uses `interopRequireWildcard` helper
+        if matches!(expr, Expression::ImportExpression(_))
+            && self.ctx.module.is_amd()
+            && self.is_enabled()
+        {
+            self.ctx.helper_preload(Helper::InteropRequireWildcard, ctx);
+        }
+    }
+}
+
+/// Move all top level bindings into the scope of the function which module body is moved into.
+fn move_root_bindings(root_scope_id: ScopeId, scope_id: ScopeId, ctx: &mut TraverseCtx<'_>) {
+    let bindings = ctx
+        .scoping()
+        .get_bindings(root_scope_id)
+        .iter()
+        .map(|(name, &symbol_id)| (name.to_string(), symbol_id))
+        .collect::<Vec<_>>();
+    for (name, symbol_id) in bindings {
+        ctx.scoping_mut().move_binding(root_scope_id, scope_id, &name);
+        ctx.scoping_mut().set_symbol_scope_id(symbol_id, scope_id);
+    }
+}
+
+/// Get name of a module from its source, to use for naming bindings.
+///
+/// `"./foo-bar.js"` -> `"fooBar"`, `"@scope/pkg"` -> `"pkg"`.
+fn module_name(source: &str) -> String {
+    let name = Path::new(source).file_stem().and_then(|stem| stem.to_str()).unwrap_or(source);
+    to_identifier(name.to_string())
+}
+
+/// `object.name`, or `object["name"]` if `name` is not a valid identifier.
+fn create_member_expression<'a>(
+    object: Expression<'a>,
+    name: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    if is_identifier_name(&name) {
+        let property = ctx.ast.identifier_name(SPAN, name);
+        Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+    } else {
+        let property = ctx.ast.expression_string_literal(SPAN, name, None);
+        Expression::from(ctx.ast.member_expression_computed(SPAN, object, property, false))
+    }
+}
+
+/// `function (params) { directives; statements }`
+fn create_function_expression<'a>(
+    params: &[&BoundIdentifier<'a>],
+    directives: ArenaVec<'a, Directive<'a>>,
+    statements: ArenaVec<'a, Statement<'a>>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let items = ctx.ast.vec_from_iter(params.iter().map(|binding| {
+        ctx.ast.formal_parameter(
+            SPAN,
+            ctx.ast.vec(),
+            binding.create_binding_pattern(ctx),
+            NONE,
+            NONE,
+            false,
+            None,
+            false,
+            false,
+        )
+    }));
+    let params =
+        ctx.ast.alloc_formal_parameters(SPAN, FormalParameterKind::FormalParameter, items, NONE);
+    let body = ctx.ast.alloc_function_body(SPAN, directives, statements);
+    Expression::FunctionExpression(ctx.ast.alloc_function_with_scope_id(
+        SPAN,
+        FunctionType::FunctionExpression,
+        None,
+        false,
+        false,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        Some(body),
+        scope_id,
+    ))
+}
+
+/// `function () { return value; }`, as a child of `parent_scope_id`.
+fn create_getter<'a>(
+    value: Expression<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::Function);
+    let statements = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value)));
+    create_function_expression(&[], ctx.ast.vec(), statements, scope_id, ctx)
+}
+
+/// `Object.defineProperty(object, "name", { enumerable: true, get: getter });`
+fn create_define_getter<'a>(
+    object: Expression<'a>,
+    name: Expression<'a>,
+    getter: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let properties = ctx.ast.vec_from_array([
+        create_object_property("enumerable", ctx.ast.expression_boolean_literal(SPAN, true), ctx),
+        create_object_property("get", getter, ctx),
+    ]);
+    let descriptor = ctx.ast.expression_object(SPAN, properties);
+    create_define_property(object, name, descriptor, ctx)
+}
+
+/// `Object.defineProperty(object, name, descriptor);`
+fn create_define_property<'a>(
+    object: Expression<'a>,
+    name: Expression<'a>,
+    descriptor: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let object_ident =
+        ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+    let callee = create_member_expression(object_ident, Atom::from("defineProperty"), ctx);
+    let arguments = ctx.ast.vec_from_array([
+        Argument::from(object),
+        Argument::from(name),
+        Argument::from(descriptor),
+    ]);
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    ctx.ast.statement_expression(SPAN, call)
+}
+
+/// `key: value`
+fn create_object_property<'a>(
+    key: &'static str,
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    let key = ctx.ast.property_key_static_identifier(SPAN, Atom::from(key));
+    ctx.ast.object_property_kind_object_property(
+        SPAN,
+        PropertyKind::Init,
+        key,
+        value,
+        false,
+        false,
+        false,
+    )
+}
+
+/// `name`, or `"name"` if `name` is not a valid identifier.
+fn create_property_key<'a>(name: Atom<'a>, ctx: &TraverseCtx<'a>) -> PropertyKey<'a> {
+    if is_identifier_name(&name) {
+        ctx.ast.property_key_static_identifier(SPAN, name)
+    } else {
+        PropertyKey::from(ctx.ast.expression_string_literal(SPAN, name, None))
+    }
+}
+
+/// Move `Directive`s from `Program` into a function body, adding `"use strict"` if not present.
+fn take_directives<'a>(
+    program: &mut Program<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> ArenaVec<'a, Directive<'a>> {
+    let mut directives = program.directives.take_in(ctx.ast);
+    if !directives.iter().any(Directive::is_use_strict) {
+        let use_strict = Atom::from("use strict");
+        let expression = ctx.ast.string_literal(SPAN, use_strict, None);
+        directives.insert(0, ctx.ast.directive(SPAN, expression, use_strict));
+    }
+    directives
+}
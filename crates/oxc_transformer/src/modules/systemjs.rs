@@ -0,0 +1,1009 @@
+//! ES Modules to SystemJS
+//!
+//! > This plugin is included in `preset-env`, when `modules` is `"systemjs"`
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo from "foo";
+//! export let count = foo;
+//! export function increment() { count++; }
+//! ```
+//!
+//! Output:
+//! ```js
+//! System.register(["foo"], function (_export, _context) {
+//!   "use strict";
+//!   var foo, count, _tmp;
+//!   function increment() { _tmp = count++, _export("count", count), _tmp; }
+//!   _export("increment", increment);
+//!   return {
+//!     setters: [function (_foo) {
+//!       foo = _foo.default;
+//!     }],
+//!     execute: function () {
+//!       _export("count", count = foo);
+//!     }
+//!   };
+//! });
+//! ```
+//!
+//! ## Implementation
+//!
+//! Top level function declarations stay in the module function, so they're hoisted and can be
+//! called by other modules before this module is executed. All other top level statements are
+//! moved into `execute` function.
+//!
+//! Top level bindings are declared with `var` in the module function, so they're shared by hoisted
+//! functions, `setters` and `execute`:
+//!
+//! * Imports are assigned in `setters`, which are called every time a dependency's exports change.
+//! * Variable declarations (including `var`s nested in blocks) become assignments.
+//! * Class declarations become assignments of class expressions.
+//!
+//! Every assignment to an exported binding is wrapped in `_export("name", ...)`, so importers
+//! receive its new value.
+//!
+//! `import.meta` is transformed to `_context.meta`, and `import()` to `_context.import()`.
+//! Top level `this` is replaced with `void 0`.
+//!
+//! Implementation based on [@babel/plugin-transform-modules-systemjs](https://babeljs.io/docs/babel-plugin-transform-modules-systemjs).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.28.4/packages/babel-plugin-transform-modules-systemjs>
+//! * System.register format: <https://github.com/systemjs/systemjs/blob/main/docs/system-register.md>
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    es2015::utils::binding_pattern_to_assignment_target,
+};
+
+use super::{
+    create_function_expression, create_member_expression, create_object_property,
+    create_property_key, module_name, move_root_bindings, take_directives,
+};
+
+/// A module the module being transformed depends on.
+struct Dependency<'a> {
+    source: Atom<'a>,
+    /// Parameter of setter function, which receives the module's exports
+    binding: BoundIdentifier<'a>,
+    /// Setter function scope
+    scope_id: ScopeId,
+    /// Setter function body
+    statements: ArenaVec<'a, Statement<'a>>,
+    /// `export * from "source"`
+    export_all: bool,
+}
+
+/// Transform module body to `System.register([...], function (_export, _context) { ... })`.
+pub fn transform<'a>(
+    program: &mut Program<'a>,
+    _transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let root_scope_id = program.scope_id();
+    let scope_id = ctx.insert_scope_below_statements(
+        &program.body,
+        ScopeFlags::Function | ScopeFlags::StrictMode,
+    );
+    move_root_bindings(root_scope_id, scope_id, ctx);
+
+    let export_binding = ctx.generate_uid("export", scope_id, SymbolFlags::FunctionScopedVariable);
+    let context_binding =
+        ctx.generate_uid("context", scope_id, SymbolFlags::FunctionScopedVariable);
+
+    let mut collector = ModuleCollector {
+        scope_id,
+        export_binding: &export_binding,
+        dependencies: vec![],
+        dependency_indexes: FxHashMap::default(),
+        vars: vec![],
+        functions: ctx.ast.vec(),
+        execute: ctx.ast.vec(),
+        exported: FxHashMap::default(),
+        export_names: vec![],
+    };
+    for stmt in program.body.take_in(ctx.ast) {
+        collector.collect_statement(stmt, ctx);
+    }
+    let ModuleCollector {
+        mut dependencies,
+        vars,
+        mut functions,
+        mut execute,
+        exported,
+        export_names,
+        ..
+    } = collector;
+
+    let mut rewriter = ModuleBodyRewriter {
+        exported: &exported,
+        scope_id,
+        export_binding: &export_binding,
+        context_binding: &context_binding,
+        function_depth: 0,
+        this_depth: 0,
+        vars,
+        temp_binding: None,
+        has_top_level_await: false,
+        ctx,
+    };
+    rewriter.visit_statements(&mut execute);
+    rewriter.visit_statements(&mut functions);
+    // Setters only contain assignments to imports, which are wrapped in `_export` if re-exported
+    for dependency in &mut dependencies {
+        rewriter.visit_statements(&mut dependency.statements);
+    }
+    let ModuleBodyRewriter { vars, has_top_level_await, .. } = rewriter;
+
+    let mut body = ctx.ast.vec_with_capacity(functions.len() + 2);
+
+    // `var foo, bar;`
+    if !vars.is_empty() {
+        let declarations = ctx.ast.vec_from_iter(vars.into_iter().map(|ident| {
+            ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                BindingPattern::BindingIdentifier(ctx.ast.alloc(ident)),
+                NONE,
+                None,
+                false,
+            )
+        }));
+        body.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        )));
+    }
+
+    // Hoisted functions, and `_export("foo", foo);` for exported ones
+    let mut function_exports = vec![];
+    for stmt in &functions {
+        if let Statement::FunctionDeclaration(func) = stmt
+            && let Some(id) = &func.id
+            && let Some(names) = exported.get(&id.symbol_id())
+        {
+            let binding = BoundIdentifier::from_binding_ident(id);
+            for &name in names {
+                let value = binding.create_read_expression(ctx);
+                function_exports.push(create_export_call(&export_binding, name, value, ctx));
+            }
+        }
+    }
+    body.extend(functions);
+    body.extend(function_exports.into_iter().map(|expr| ctx.ast.statement_expression(SPAN, expr)));
+
+    // `setters: [function (_foo) { ... }]`
+    let has_export_all = dependencies.iter().any(|dependency| dependency.export_all);
+    let export_names_binding = (has_export_all && !export_names.is_empty())
+        .then(|| create_export_names(&export_names, scope_id, &mut body, ctx));
+    let setters = ctx.ast.vec_from_iter(dependencies.iter_mut().map(|dependency| {
+        let mut statements = dependency.statements.take_in(ctx.ast);
+        if dependency.export_all {
+            create_export_all(
+                dependency,
+                &export_binding,
+                export_names_binding.as_ref(),
+                &mut statements,
+                ctx,
+            );
+        }
+        let setter = create_function_expression(
+            &[&dependency.binding],
+            ctx.ast.vec(),
+            statements,
+            dependency.scope_id,
+            ctx,
+        );
+        ArrayExpressionElement::from(setter)
+    }));
+
+    // `execute: function () { ... }`
+    let execute_scope_id = ctx.insert_scope_below_statements(&execute, ScopeFlags::Function);
+    ctx.scoping_mut().change_scope_parent_id(execute_scope_id, Some(scope_id));
+    let mut execute =
+        create_function_expression(&[], ctx.ast.vec(), execute, execute_scope_id, ctx);
+    if has_top_level_await && let Expression::FunctionExpression(func) = &mut execute {
+        func.r#async = true;
+    }
+
+    // `return { setters: [...], execute: function () { ... } };`
+    let properties = ctx.ast.vec_from_array([
+        create_object_property("setters", ctx.ast.expression_array(SPAN, setters), ctx),
+        create_object_property("execute", execute, ctx),
+    ]);
+    let object = ctx.ast.expression_object(SPAN, properties);
+    body.push(ctx.ast.statement_return(SPAN, Some(object)));
+
+    // `System.register(["foo"], function (_export, _context) { ... });`
+    let directives = take_directives(program, ctx);
+    let function = create_function_expression(
+        &[&export_binding, &context_binding],
+        directives,
+        body,
+        scope_id,
+        ctx,
+    );
+    let sources = ctx.ast.vec_from_iter(dependencies.iter().map(|dependency| {
+        ArrayExpressionElement::from(ctx.ast.expression_string_literal(
+            SPAN,
+            dependency.source,
+            None,
+        ))
+    }));
+    let system = ctx.create_unbound_ident_expr(SPAN, Atom::from("System"), ReferenceFlags::Read);
+    let callee = create_member_expression(system, Atom::from("register"), ctx);
+    let arguments = ctx.ast.vec_from_array([
+        Argument::from(ctx.ast.expression_array(SPAN, sources)),
+        Argument::from(function),
+    ]);
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    program.body = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call));
+}
+
+/// Splits top level statements of the module into hoisted functions, `execute` body and setters.
+struct ModuleCollector<'a, 'b> {
+    /// Module function scope
+    scope_id: ScopeId,
+    export_binding: &'b BoundIdentifier<'a>,
+    dependencies: Vec<Dependency<'a>>,
+    dependency_indexes: FxHashMap<Atom<'a>, usize>,
+    /// Bindings declared with `var` in module function
+    vars: Vec<BindingIdentifier<'a>>,
+    /// Top level function declarations
+    functions: ArenaVec<'a, Statement<'a>>,
+    /// Body of `execute` function
+    execute: ArenaVec<'a, Statement<'a>>,
+    /// Exported names of local bindings
+    exported: FxHashMap<SymbolId, Vec<Atom<'a>>>,
+    /// All names exported explicitly, to exclude them from `export *`
+    export_names: Vec<Atom<'a>>,
+}
+
+impl<'a> ModuleCollector<'a, '_> {
+    fn collect_statement(&mut self, stmt: Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::ImportDeclaration(decl) => self.collect_import(&decl, ctx),
+            Statement::ExportNamedDeclaration(decl) => {
+                let decl = decl.unbox();
+                if let Some(declaration) = decl.declaration {
+                    declaration.bound_names(&mut |ident| {
+                        self.add_export(ident.symbol_id(), ident.name.into());
+                    });
+                    self.collect_statement(Statement::from(declaration), ctx);
+                } else if let Some(source) = &decl.source {
+                    // `export { x as y } from "foo"` -> `_export("y", _foo.x);` in setter
+                    let index = self.get_dependency(source, ctx);
+                    for specifier in &decl.specifiers {
+                        let exported = specifier.exported.name();
+                        self.export_names.push(exported);
+                        let dependency = &self.dependencies[index];
+                        let object = dependency.binding.create_read_expression(ctx);
+                        let value = create_member_expression(object, specifier.local.name(), ctx);
+                        let call = create_export_call(self.export_binding, exported, value, ctx);
+                        let stmt = ctx.ast.statement_expression(SPAN, call);
+                        self.dependencies[index].statements.push(stmt);
+                    }
+                } else {
+                    for specifier in &decl.specifiers {
+                        let ModuleExportName::IdentifierReference(local) = &specifier.local else {
+                            continue;
+                        };
+                        let symbol_id =
+                            ctx.scoping().get_reference(local.reference_id()).symbol_id();
+                        ctx.delete_reference_for_identifier(local);
+                        if let Some(symbol_id) = symbol_id {
+                            self.add_export(symbol_id, specifier.exported.name());
+                        }
+                    }
+                }
+            }
+            Statement::ExportDefaultDeclaration(decl) => {
+                let decl = decl.unbox();
+                match decl.declaration {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                        if func.id.is_none() {
+                            let binding =
+                                ctx.generate_uid("default", self.scope_id, SymbolFlags::Function);
+                            func.id = Some(binding.create_binding_identifier(ctx));
+                        }
+                        let symbol_id = func.id.as_ref().unwrap().symbol_id();
+                        self.add_export(symbol_id, Atom::from("default"));
+                        self.functions.push(Statement::FunctionDeclaration(func));
+                    }
+                    ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                        if class.id.is_none() {
+                            let binding =
+                                ctx.generate_uid("default", self.scope_id, SymbolFlags::Class);
+                            class.id = Some(binding.create_binding_identifier(ctx));
+                        }
+                        let symbol_id = class.id.as_ref().unwrap().symbol_id();
+                        self.add_export(symbol_id, Atom::from("default"));
+                        self.collect_class(class, ctx);
+                    }
+                    ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {}
+                    declaration => {
+                        // `export default expr` -> `_export("default", expr);`
+                        let name = Atom::from("default");
+                        self.export_names.push(name);
+                        let value = declaration.into_expression();
+                        let call = create_export_call(self.export_binding, name, value, ctx);
+                        self.execute.push(ctx.ast.statement_expression(decl.span, call));
+                    }
+                }
+            }
+            Statement::ExportAllDeclaration(decl) => {
+                let index = self.get_dependency(&decl.source, ctx);
+                if let Some(exported) = &decl.exported {
+                    // `export * as ns from "foo"` -> `_export("ns", _foo);` in setter
+                    let exported = exported.name();
+                    self.export_names.push(exported);
+                    let value = self.dependencies[index].binding.create_read_expression(ctx);
+                    let call = create_export_call(self.export_binding, exported, value, ctx);
+                    let stmt = ctx.ast.statement_expression(SPAN, call);
+                    self.dependencies[index].statements.push(stmt);
+                } else {
+                    self.dependencies[index].export_all = true;
+                }
+            }
+            Statement::FunctionDeclaration(_) => self.functions.push(stmt),
+            Statement::ClassDeclaration(class) => self.collect_class(class, ctx),
+            Statement::VariableDeclaration(mut decl)
+                if matches!(
+                    decl.kind,
+                    VariableDeclarationKind::Let | VariableDeclarationKind::Const
+                ) =>
+            {
+                // Converted to `var`, to be hoisted along with other `var`s
+                decl.kind = VariableDeclarationKind::Var;
+                for declarator in &mut decl.declarations {
+                    declarator.kind = VariableDeclarationKind::Var;
+                }
+                self.execute.push(Statement::VariableDeclaration(decl));
+            }
+            _ => self.execute.push(stmt),
+        }
+    }
+
+    fn get_dependency(&mut self, source: &StringLiteral<'a>, ctx: &mut TraverseCtx<'a>) -> usize {
+        *self.dependency_indexes.entry(source.value).or_insert_with(|| {
+            let scope_id = ctx.create_child_scope(self.scope_id, ScopeFlags::Function);
+            let binding = ctx.generate_uid(
+                &module_name(&source.value),
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            self.dependencies.push(Dependency {
+                source: source.value,
+                binding,
+                scope_id,
+                statements: ctx.ast.vec(),
+                export_all: false,
+            });
+            self.dependencies.len() - 1
+        })
+    }
+
+    /// `import foo, { bar } from "foo"` -> `var foo, bar;` and setter:
+    /// `function (_foo) { foo = _foo.default; bar = _foo.bar; }`
+    fn collect_import(&mut self, decl: &ImportDeclaration<'a>, ctx: &mut TraverseCtx<'a>) {
+        let index = self.get_dependency(&decl.source, ctx);
+        for specifier in decl.specifiers.iter().flatten() {
+            let object = self.dependencies[index].binding.create_read_expression(ctx);
+            let value = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    create_member_expression(object, specifier.imported.name(), ctx)
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                    create_member_expression(object, Atom::from("default"), ctx)
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => object,
+            };
+            let local = specifier.local();
+            self.add_var(local, ctx);
+            let binding = BoundIdentifier::from_binding_ident(local);
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                binding.create_write_target(ctx),
+                value,
+            );
+            self.dependencies[index]
+                .statements
+                .push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+    }
+
+    /// `class C {}` -> `var C;` and `C = class {};` in `execute`
+    fn collect_class(&mut self, mut class: ArenaBox<'a, Class<'a>>, ctx: &mut TraverseCtx<'a>) {
+        let id = class.id.take().unwrap();
+        self.add_var(&id, ctx);
+        class.r#type = ClassType::ClassExpression;
+        let span = class.span;
+        let binding = BoundIdentifier::from_binding_ident(&id);
+        let assignment = ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            binding.create_spanned_write_target(id.span, ctx),
+            Expression::ClassExpression(class),
+        );
+        self.execute.push(ctx.ast.statement_expression(span, assignment));
+    }
+
+    fn add_var(&mut self, ident: &BindingIdentifier<'a>, ctx: &mut TraverseCtx<'a>) {
+        *ctx.scoping_mut().symbol_flags_mut(ident.symbol_id()) =
+            SymbolFlags::FunctionScopedVariable;
+        self.vars.push(ctx.ast.binding_identifier_with_symbol_id(
+            ident.span,
+            ident.name,
+            ident.symbol_id(),
+        ));
+    }
+
+    fn add_export(&mut self, symbol_id: SymbolId, name: Atom<'a>) {
+        self.export_names.push(name);
+        self.exported.entry(symbol_id).or_default().push(name);
+    }
+}
+
+/// `_export("name", value)`
+fn create_export_call<'a>(
+    export_binding: &BoundIdentifier<'a>,
+    name: Atom<'a>,
+    value: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let callee = export_binding.create_read_expression(ctx);
+    let arguments = ctx.ast.vec_from_array([
+        Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)),
+        Argument::from(value),
+    ]);
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// `var _exportNames = { x: true, y: true };`
+fn create_export_names<'a>(
+    export_names: &[Atom<'a>],
+    scope_id: ScopeId,
+    body: &mut ArenaVec<'a, Statement<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> BoundIdentifier<'a> {
+    let binding = ctx.generate_uid("exportNames", scope_id, SymbolFlags::FunctionScopedVariable);
+    let properties = ctx.ast.vec_from_iter(export_names.iter().map(|&name| {
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            create_property_key(name, ctx),
+            ctx.ast.expression_boolean_literal(SPAN, true),
+            false,
+            false,
+            false,
+        )
+    }));
+    let init = ctx.ast.expression_object(SPAN, properties);
+    body.push(create_var_declaration(&binding, init, ctx));
+    binding
+}
+
+/// ```js
+/// var _exportObj = {};
+/// for (var _key in _foo) {
+///   if (_key !== "default" && _key !== "__esModule" && !Object.prototype.hasOwnProperty.call(_exportNames, _key)) _exportObj[_key] = _foo[_key];
+/// }
+/// _export(_exportObj);
+/// ```
+fn create_export_all<'a>(
+    dependency: &Dependency<'a>,
+    export_binding: &BoundIdentifier<'a>,
+    export_names: Option<&BoundIdentifier<'a>>,
+    statements: &mut ArenaVec<'a, Statement<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let setter_scope_id = dependency.scope_id;
+    let export_obj =
+        ctx.generate_uid("exportObj", setter_scope_id, SymbolFlags::FunctionScopedVariable);
+    let key = ctx.generate_uid("key", setter_scope_id, SymbolFlags::FunctionScopedVariable);
+
+    let init = ctx.ast.expression_object(SPAN, ctx.ast.vec());
+    statements.push(create_var_declaration(&export_obj, init, ctx));
+
+    // `_key !== "default" && _key !== "__esModule"`
+    let mut create_key_check = |name| {
+        let left = key.create_read_expression(ctx);
+        let right = ctx.ast.expression_string_literal(SPAN, Atom::from(name), None);
+        ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictInequality, right)
+    };
+    let is_not_default = create_key_check("default");
+    let is_not_es_module = create_key_check("__esModule");
+    let mut test =
+        ctx.ast.expression_logical(SPAN, is_not_default, LogicalOperator::And, is_not_es_module);
+    // `&& !Object.prototype.hasOwnProperty.call(_exportNames, _key)`
+    if let Some(export_names) = export_names {
+        let object =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+        let callee = ["prototype", "hasOwnProperty", "call"]
+            .into_iter()
+            .fold(object, |object, name| create_member_expression(object, Atom::from(name), ctx));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(export_names.create_read_expression(ctx)),
+            Argument::from(key.create_read_expression(ctx)),
+        ]);
+        let has_own = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        let not_has_own = ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, has_own);
+        test = ctx.ast.expression_logical(SPAN, test, LogicalOperator::And, not_has_own);
+    }
+
+    // `_exportObj[_key] = _foo[_key];`
+    let target = ctx.ast.member_expression_computed(
+        SPAN,
+        export_obj.create_read_expression(ctx),
+        key.create_read_expression(ctx),
+        false,
+    );
+    let value = Expression::from(ctx.ast.member_expression_computed(
+        SPAN,
+        dependency.binding.create_read_expression(ctx),
+        key.create_read_expression(ctx),
+        false,
+    ));
+    let assignment = ctx.ast.expression_assignment(
+        SPAN,
+        AssignmentOperator::Assign,
+        AssignmentTarget::from(SimpleAssignmentTarget::from(target)),
+        value,
+    );
+    let if_stmt =
+        ctx.ast.statement_if(SPAN, test, ctx.ast.statement_expression(SPAN, assignment), None);
+
+    // `for (var _key in _foo) { ... }`
+    let for_scope_id = ctx.create_child_scope(setter_scope_id, ScopeFlags::empty());
+    let block_scope_id = ctx.create_child_scope(for_scope_id, ScopeFlags::empty());
+    let body = ctx.ast.statement_block_with_scope_id(SPAN, ctx.ast.vec1(if_stmt), block_scope_id);
+    let declarator = ctx.ast.variable_declarator(
+        SPAN,
+        VariableDeclarationKind::Var,
+        key.create_binding_pattern(ctx),
+        NONE,
+        None,
+        false,
+    );
+    let left = ForStatementLeft::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        VariableDeclarationKind::Var,
+        ctx.ast.vec1(declarator),
+        false,
+    ));
+    let right = dependency.binding.create_read_expression(ctx);
+    statements.push(ctx.ast.statement_for_in_with_scope_id(SPAN, left, right, body, for_scope_id));
+
+    // `_export(_exportObj);`
+    let callee = export_binding.create_read_expression(ctx);
+    let arguments = ctx.ast.vec1(Argument::from(export_obj.create_read_expression(ctx)));
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    statements.push(ctx.ast.statement_expression(SPAN, call));
+}
+
+/// `var binding = init;`
+fn create_var_declaration<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let declarator = ctx.ast.variable_declarator(
+        SPAN,
+        VariableDeclarationKind::Var,
+        binding.create_binding_pattern(ctx),
+        NONE,
+        Some(init),
+        false,
+    );
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        VariableDeclarationKind::Var,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// Visitor which hoists top level `var`s, wraps assignments to exported bindings in `_export`,
+/// and rewrites top level `this`, `import()` and `import.meta`.
+struct ModuleBodyRewriter<'a, 'b, 'c> {
+    exported: &'b FxHashMap<SymbolId, Vec<Atom<'a>>>,
+    /// Module function scope
+    scope_id: ScopeId,
+    export_binding: &'b BoundIdentifier<'a>,
+    context_binding: &'b BoundIdentifier<'a>,
+    /// Number of functions the current node is nested in. `var`s are only hoisted when 0.
+    function_depth: u32,
+    /// Number of functions / class property initializers `this` is nested in
+    this_depth: u32,
+    /// Bindings declared with `var` in module function
+    vars: Vec<BindingIdentifier<'a>>,
+    /// Temp var used for postfix updates and destructuring assignments to exported bindings
+    temp_binding: Option<BoundIdentifier<'a>>,
+    has_top_level_await: bool,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for ModuleBodyRewriter<'a, '_, '_> {
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        if self.function_depth > 0 {
+            walk_mut::walk_statements(self, stmts);
+            return;
+        }
+        stmts.retain_mut(|stmt| {
+            if let Statement::VariableDeclaration(decl) = stmt
+                && decl.kind.is_var()
+            {
+                match self.hoist_declaration(decl) {
+                    Some(expr) => *stmt = self.ctx.ast.statement_expression(decl.span, expr),
+                    None => return false,
+                }
+            }
+            self.visit_statement(stmt);
+            true
+        });
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        // Declarations which are not in a statement list. e.g. `if (x) var a = 1;`
+        if self.function_depth == 0
+            && let Statement::VariableDeclaration(decl) = stmt
+            && decl.kind.is_var()
+        {
+            *stmt = match self.hoist_declaration(decl) {
+                Some(expr) => self.ctx.ast.statement_expression(decl.span, expr),
+                None => self.ctx.ast.statement_empty(decl.span),
+            };
+        }
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if self.function_depth == 0
+            && let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init
+            && decl.kind.is_var()
+        {
+            stmt.init = self.hoist_declaration(decl).map(ForStatementInit::from);
+        }
+        walk_mut::walk_for_statement(self, stmt);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        walk_mut::walk_for_in_statement(self, stmt);
+        let scope_id = stmt.scope_id();
+        self.export_loop_targets(&stmt.left, &mut stmt.body, scope_id);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        if stmt.r#await && self.function_depth == 0 {
+            self.has_top_level_await = true;
+        }
+        walk_mut::walk_for_of_statement(self, stmt);
+        let scope_id = stmt.scope_id();
+        self.export_loop_targets(&stmt.left, &mut stmt.body, scope_id);
+    }
+
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if self.function_depth == 0
+            && let ForStatementLeft::VariableDeclaration(decl) = left
+            && decl.kind.is_var()
+        {
+            let declarator = decl.declarations.pop().unwrap();
+            declarator.id.bound_names(&mut |ident| self.hoist_binding(ident));
+            *left = ForStatementLeft::from(binding_pattern_to_assignment_target(
+                declarator.id,
+                self.ctx,
+            ));
+        }
+        walk_mut::walk_for_statement_left(self, left);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(this) => {
+                if self.this_depth == 0 {
+                    *expr = self.ctx.ast.void_0(this.span);
+                }
+                return;
+            }
+            // `import.meta` -> `_context.meta`
+            Expression::MetaProperty(meta)
+                if meta.meta.name == "import" && meta.property.name == "meta" =>
+            {
+                let span = meta.span;
+                let object = self.context_binding.create_read_expression(self.ctx);
+                let property = self.ctx.ast.identifier_name(SPAN, Atom::from("meta"));
+                *expr = Expression::from(
+                    self.ctx.ast.member_expression_static(span, object, property, false),
+                );
+                return;
+            }
+            Expression::AwaitExpression(_) if self.function_depth == 0 => {
+                self.has_top_level_await = true;
+            }
+            _ => {}
+        }
+
+        walk_mut::walk_expression(self, expr);
+
+        match expr {
+            // `import(source)` -> `_context.import(source)`
+            Expression::ImportExpression(import) => {
+                let span = import.span;
+                let source = import.source.take_in(self.ctx.ast);
+                let object = self.context_binding.create_read_expression(self.ctx);
+                let callee = create_member_expression(object, Atom::from("import"), self.ctx);
+                let arguments = self.ctx.ast.vec1(Argument::from(source));
+                *expr = self.ctx.ast.expression_call(span, callee, NONE, arguments, false);
+            }
+            Expression::AssignmentExpression(_) => self.export_assignment(expr),
+            Expression::UpdateExpression(_) => self.export_update(expr),
+            _ => {}
+        }
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.function_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_static_block(&mut self, block: &mut StaticBlock<'a>) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk_mut::walk_static_block(self, block);
+        self.function_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_property_definition(&mut self, prop: &mut PropertyDefinition<'a>) {
+        self.visit_decorators(&mut prop.decorators);
+        self.visit_property_key(&mut prop.key);
+        if let Some(value) = &mut prop.value {
+            self.function_depth += 1;
+            self.this_depth += 1;
+            self.visit_expression(value);
+            self.function_depth -= 1;
+            self.this_depth -= 1;
+        }
+    }
+
+    fn visit_accessor_property(&mut self, prop: &mut AccessorProperty<'a>) {
+        self.visit_decorators(&mut prop.decorators);
+        self.visit_property_key(&mut prop.key);
+        if let Some(value) = &mut prop.value {
+            self.function_depth += 1;
+            self.this_depth += 1;
+            self.visit_expression(value);
+            self.function_depth -= 1;
+            self.this_depth -= 1;
+        }
+    }
+}
+
+impl<'a, 'b> ModuleBodyRewriter<'a, 'b, '_> {
+    /// `var a = 1, b, c = 2` -> `a = 1, c = 2`
+    ///
+    /// Returns `None` if no declarators have an initializer.
+    fn hoist_declaration(&mut self, decl: &mut VariableDeclaration<'a>) -> Option<Expression<'a>> {
+        let mut expressions = self.ctx.ast.vec();
+        for declarator in decl.declarations.take_in(self.ctx.ast) {
+            declarator.id.bound_names(&mut |ident| self.hoist_binding(ident));
+            if let Some(init) = declarator.init {
+                let target = binding_pattern_to_assignment_target(declarator.id, self.ctx);
+                expressions.push(self.ctx.ast.expression_assignment(
+                    declarator.span,
+                    AssignmentOperator::Assign,
+                    target,
+                    init,
+                ));
+            }
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, expressions)),
+        }
+    }
+
+    /// Record a binding to be declared with `var` in module function.
+    fn hoist_binding(&mut self, ident: &BindingIdentifier<'a>) {
+        let symbol_id = ident.symbol_id();
+        if self.vars.iter().any(|var| var.symbol_id() == symbol_id) {
+            return;
+        }
+        let flags = self.ctx.scoping_mut().symbol_flags_mut(symbol_id);
+        flags.remove(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable);
+        flags.insert(SymbolFlags::FunctionScopedVariable);
+        self.vars.push(
+            self.ctx.ast.binding_identifier_with_symbol_id(ident.span, ident.name, symbol_id),
+        );
+    }
+
+    fn exported_names(&self, ident: &IdentifierReference<'a>) -> Option<&'b [Atom<'a>]> {
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        let exported = self.exported;
+        exported.get(&symbol_id).map(Vec::as_slice)
+    }
+
+    /// `value` -> `_export("b", _export("a", value))`
+    fn wrap_in_export_calls(
+        &mut self,
+        names: &[Atom<'a>],
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        names.iter().fold(value, |value, &name| {
+            create_export_call(self.export_binding, name, value, self.ctx)
+        })
+    }
+
+    /// `_export("a", a), _export("b", b)` for each exported binding assigned in `target`
+    fn create_export_calls_for_target(
+        &mut self,
+        target: &AssignmentTarget<'a>,
+    ) -> Vec<Expression<'a>> {
+        let mut collector = WriteReferenceCollector { ctx: self.ctx, references: vec![] };
+        collector.visit_assignment_target(target);
+        let references = collector.references;
+
+        let mut calls = vec![];
+        for (name, symbol_id) in references {
+            let Some(names) = self.exported.get(&symbol_id) else { continue };
+            let binding = BoundIdentifier::new(name, symbol_id);
+            for &exported in names {
+                let value = binding.create_read_expression(self.ctx);
+                calls.push(create_export_call(self.export_binding, exported, value, self.ctx));
+            }
+        }
+        calls
+    }
+
+    fn temp_binding(&mut self) -> BoundIdentifier<'a> {
+        if let Some(binding) = &self.temp_binding {
+            return binding.clone();
+        }
+        let binding =
+            self.ctx.generate_uid("tmp", self.scope_id, SymbolFlags::FunctionScopedVariable);
+        self.vars.push(binding.create_binding_identifier(self.ctx));
+        self.temp_binding = Some(binding.clone());
+        binding
+    }
+
+    /// * `a = 1` -> `_export("a", a = 1)`
+    /// * `[a, b] = arr` -> `(_tmp = [a, b] = arr, _export("a", a), _export("b", b), _tmp)`
+    fn export_assignment(&mut self, expr: &mut Expression<'a>) {
+        let Expression::AssignmentExpression(assign) = expr else { unreachable!() };
+        match &assign.left {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                let Some(names) = self.exported_names(ident) else { return };
+                let value = expr.take_in(self.ctx.ast);
+                *expr = self.wrap_in_export_calls(names, value);
+            }
+            AssignmentTarget::ArrayAssignmentTarget(_)
+            | AssignmentTarget::ObjectAssignmentTarget(_) => {
+                let calls = self.create_export_calls_for_target(&assign.left);
+                if calls.is_empty() {
+                    return;
+                }
+                let span = assign.span;
+                let temp = self.temp_binding();
+                let value = expr.take_in(self.ctx.ast);
+                let mut expressions = self.ctx.ast.vec_with_capacity(calls.len() + 2);
+                expressions.push(self.ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    temp.create_write_target(self.ctx),
+                    value,
+                ));
+                expressions.extend(calls);
+                expressions.push(temp.create_read_expression(self.ctx));
+                *expr = self.ctx.ast.expression_sequence(span, expressions);
+            }
+            _ => {}
+        }
+    }
+
+    /// * `++a` -> `_export("a", ++a)`
+    /// * `a++` -> `(_tmp = a++, _export("a", a), _tmp)`
+    fn export_update(&mut self, expr: &mut Expression<'a>) {
+        let Expression::UpdateExpression(update) = expr else { unreachable!() };
+        let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument else {
+            return;
+        };
+        let Some(names) = self.exported_names(ident) else { return };
+
+        if update.prefix {
+            let value = expr.take_in(self.ctx.ast);
+            *expr = self.wrap_in_export_calls(names, value);
+            return;
+        }
+
+        let span = update.span;
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id().unwrap();
+        let binding = BoundIdentifier::new(ident.name.into(), symbol_id);
+        let temp = self.temp_binding();
+        let value = expr.take_in(self.ctx.ast);
+        let mut expressions = self.ctx.ast.vec_with_capacity(names.len() + 2);
+        expressions.push(self.ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            temp.create_write_target(self.ctx),
+            value,
+        ));
+        for &name in names {
+            let value = binding.create_read_expression(self.ctx);
+            expressions.push(create_export_call(self.export_binding, name, value, self.ctx));
+        }
+        expressions.push(temp.create_read_expression(self.ctx));
+        *expr = self.ctx.ast.expression_sequence(span, expressions);
+    }
+
+    /// `for (a of arr) body` -> `for (a of arr) { _export("a", a); body }`
+    fn export_loop_targets(
+        &mut self,
+        left: &ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        for_scope_id: ScopeId,
+    ) {
+        let Some(target) = left.as_assignment_target() else { return };
+        let calls = self.create_export_calls_for_target(target);
+        if calls.is_empty() {
+            return;
+        }
+
+        let stmts = calls.into_iter().map(|call| self.ctx.ast.statement_expression(SPAN, call));
+        if let Statement::BlockStatement(block) = body {
+            block.body.splice(0..0, stmts);
+        } else {
+            let stmts = stmts.collect::<Vec<_>>();
+            let scope_id = self.ctx.insert_scope_below_statement_from_scope_id(
+                body,
+                for_scope_id,
+                ScopeFlags::empty(),
+            );
+            let mut block_body = self.ctx.ast.vec_from_iter(stmts);
+            block_body.push(body.take_in(self.ctx.ast));
+            *body = self.ctx.ast.statement_block_with_scope_id(SPAN, block_body, scope_id);
+        }
+    }
+}
+
+/// Collects identifiers which are written to in an assignment target.
+struct WriteReferenceCollector<'a, 'c> {
+    ctx: &'c TraverseCtx<'a>,
+    references: Vec<(Atom<'a>, SymbolId)>,
+}
+
+impl<'a> Visit<'a> for WriteReferenceCollector<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.ctx.scoping().get_reference(ident.reference_id());
+        if reference.is_write()
+            && let Some(symbol_id) = reference.symbol_id()
+        {
+            self.references.push((ident.name.into(), symbol_id));
+        }
+    }
+}
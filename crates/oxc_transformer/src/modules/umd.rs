@@ -0,0 +1,261 @@
+//! ES Modules to UMD
+//!
+//! > This plugin is included in `preset-env`, when `modules` is `"umd"`
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo from "foo";
+//! export default foo;
+//! ```
+//!
+//! Output:
+//! ```js
+//! (function (global, factory) {
+//!   if (typeof define === "function" && define.amd) {
+//!     define(["exports", "foo"], factory);
+//!   } else if (typeof exports !== "undefined") {
+//!     factory(exports, require("foo"));
+//!   } else {
+//!     var mod = { exports: {} };
+//!     factory(mod.exports, global.foo);
+//!     global.input = mod.exports;
+//!   }
+//! })(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function (_exports, _foo) {
+//!   "use strict";
+//!   Object.defineProperty(_exports, "__esModule", { value: true });
+//!   _foo = _interopRequireDefault(_foo);
+//!   _exports.default = _foo.default;
+//! });
+//! ```
+//!
+//! See [`factory`](super::factory) for how the module body is transformed.
+//!
+//! When loaded as a browser global, dependencies are read from, and the module's exports are
+//! written to, properties of the global object named after the module's file name.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-modules-umd](https://babeljs.io/docs/babel-plugin-transform-modules-umd).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.28.4/packages/babel-plugin-transform-modules-umd>
+//! * UMD patterns: <https://github.com/umdjs/umd>
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, ast_operations::to_identifier};
+
+use crate::context::{TransformCtx, TraverseCtx};
+
+use super::{create_function_expression, create_member_expression, factory::Factory, module_name};
+
+/// Wrap module factory in UMD wrapper.
+pub fn wrap<'a>(
+    program: &mut Program<'a>,
+    factory: Factory<'a>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let Factory { function, dependencies, has_exports, .. } = factory;
+
+    let root_scope_id = program.scope_id();
+    let scope_id = ctx.create_child_scope(root_scope_id, ScopeFlags::Function);
+    let global =
+        ctx.generate_binding(Atom::from("global"), scope_id, SymbolFlags::FunctionScopedVariable);
+    let factory =
+        ctx.generate_binding(Atom::from("factory"), scope_id, SymbolFlags::FunctionScopedVariable);
+
+    // `if (typeof define === "function" && define.amd) { define(["exports", "foo"], factory); }`
+    let is_amd = {
+        let is_function =
+            create_typeof_check("define", BinaryOperator::StrictEquality, "function", ctx);
+        let define =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("define"), ReferenceFlags::Read);
+        let amd = create_member_expression(define, Atom::from("amd"), ctx);
+        ctx.ast.expression_logical(SPAN, is_function, LogicalOperator::And, amd)
+    };
+    let amd_block = {
+        let names = has_exports
+            .then(|| Atom::from("exports"))
+            .into_iter()
+            .chain(dependencies.iter().map(|dependency| dependency.source));
+        let elements = ctx.ast.vec_from_iter(names.map(|name| {
+            ArrayExpressionElement::from(ctx.ast.expression_string_literal(SPAN, name, None))
+        }));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(ctx.ast.expression_array(SPAN, elements)),
+            Argument::from(factory.create_read_expression(ctx)),
+        ]);
+        let call = create_global_call("define", arguments, ctx);
+        create_block(ctx.ast.vec1(call), scope_id, ctx)
+    };
+
+    // `else if (typeof exports !== "undefined") { factory(exports, require("foo")); }`
+    let is_commonjs =
+        create_typeof_check("exports", BinaryOperator::StrictInequality, "undefined", ctx);
+    let commonjs_block = {
+        let mut arguments = ctx.ast.vec_with_capacity(dependencies.len() + 1);
+        if has_exports {
+            arguments.push(Argument::from(ctx.create_unbound_ident_expr(
+                SPAN,
+                Atom::from("exports"),
+                ReferenceFlags::Read,
+            )));
+        }
+        for dependency in &dependencies {
+            let source = ctx.ast.expression_string_literal(SPAN, dependency.source, None);
+            let callee =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("require"), ReferenceFlags::Read);
+            let require_arguments = ctx.ast.vec1(Argument::from(source));
+            let require = ctx.ast.expression_call(SPAN, callee, NONE, require_arguments, false);
+            arguments.push(Argument::from(require));
+        }
+        let call = create_factory_call(&factory, arguments, ctx);
+        create_block(ctx.ast.vec1(call), scope_id, ctx)
+    };
+
+    // ```js
+    // else {
+    //   var mod = { exports: {} };
+    //   factory(mod.exports, global.foo);
+    //   global.input = mod.exports;
+    // }
+    // ```
+    let global_block = {
+        let mut statements = ctx.ast.vec_with_capacity(3);
+        let mut arguments = ctx.ast.vec_with_capacity(dependencies.len() + 1);
+        let module = has_exports.then(|| {
+            let module = ctx.generate_binding(
+                Atom::from("mod"),
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let exports = ctx.ast.expression_object(SPAN, ctx.ast.vec());
+            let init = ctx.ast.expression_object(
+                SPAN,
+                ctx.ast.vec1(super::create_object_property("exports", exports, ctx)),
+            );
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                module.create_binding_pattern(ctx),
+                NONE,
+                Some(init),
+                false,
+            );
+            statements.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            )));
+            arguments.push(Argument::from(create_module_exports(&module, ctx)));
+            module
+        });
+        for dependency in &dependencies {
+            let name = ctx.ast.atom(&module_name(&dependency.source));
+            let object = global.create_read_expression(ctx);
+            arguments.push(Argument::from(create_member_expression(object, name, ctx)));
+        }
+        statements.push(create_factory_call(&factory, arguments, ctx));
+        if let Some(module) = module {
+            let name = ctx.ast.atom(&to_identifier(transform_ctx.filename.clone()));
+            let object = global.create_read_expression(ctx);
+            let target = create_member_expression(object, name, ctx).into_member_expression();
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(target),
+                create_module_exports(&module, ctx),
+            );
+            statements.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+        create_block(statements, scope_id, ctx)
+    };
+
+    let commonjs_if = ctx.ast.statement_if(SPAN, is_commonjs, commonjs_block, Some(global_block));
+    let amd_if = ctx.ast.statement_if(SPAN, is_amd, amd_block, Some(commonjs_if));
+    let wrapper = create_function_expression(
+        &[&global, &factory],
+        ctx.ast.vec(),
+        ctx.ast.vec1(amd_if),
+        scope_id,
+        ctx,
+    );
+
+    // `typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this`
+    let this = ctx.ast.expression_this(SPAN);
+    let is_self = create_typeof_check("self", BinaryOperator::StrictInequality, "undefined", ctx);
+    let self_ident = ctx.create_unbound_ident_expr(SPAN, Atom::from("self"), ReferenceFlags::Read);
+    let self_or_this = ctx.ast.expression_conditional(SPAN, is_self, self_ident, this);
+    let is_global_this =
+        create_typeof_check("globalThis", BinaryOperator::StrictInequality, "undefined", ctx);
+    let global_this =
+        ctx.create_unbound_ident_expr(SPAN, Atom::from("globalThis"), ReferenceFlags::Read);
+    let global_object =
+        ctx.ast.expression_conditional(SPAN, is_global_this, global_this, self_or_this);
+
+    let callee = ctx.ast.expression_parenthesized(SPAN, wrapper);
+    let arguments =
+        ctx.ast.vec_from_array([Argument::from(global_object), Argument::from(function)]);
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    program.body = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call));
+}
+
+/// `typeof name <operator> "type"`
+fn create_typeof_check<'a>(
+    name: &'static str,
+    operator: BinaryOperator,
+    type_name: &'static str,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let ident = ctx.create_unbound_ident_expr(SPAN, Atom::from(name), ReferenceFlags::Read);
+    let type_of = ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, ident);
+    let type_name = ctx.ast.expression_string_literal(SPAN, Atom::from(type_name), None);
+    ctx.ast.expression_binary(SPAN, type_of, operator, type_name)
+}
+
+/// `name(...arguments);`
+fn create_global_call<'a>(
+    name: &'static str,
+    arguments: ArenaVec<'a, Argument<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let callee = ctx.create_unbound_ident_expr(SPAN, Atom::from(name), ReferenceFlags::Read);
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    ctx.ast.statement_expression(SPAN, call)
+}
+
+/// `factory(...arguments);`
+fn create_factory_call<'a>(
+    factory: &BoundIdentifier<'a>,
+    arguments: ArenaVec<'a, Argument<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let callee = factory.create_read_expression(ctx);
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    ctx.ast.statement_expression(SPAN, call)
+}
+
+/// `mod.exports`
+fn create_module_exports<'a>(
+    module: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let object = module.create_read_expression(ctx);
+    create_member_expression(object, Atom::from("exports"), ctx)
+}
+
+/// `{ statements }`, as a child scope of `parent_scope_id`.
+fn create_block<'a>(
+    statements: ArenaVec<'a, Statement<'a>>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+    ctx.ast.statement_block_with_scope_id(SPAN, statements, scope_id)
+}
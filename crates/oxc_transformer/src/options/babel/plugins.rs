@@ -1,8 +1,11 @@
 use serde::Deserialize;
 
 use crate::{
-    DecoratorOptions, TypeScriptOptions, es2015::ArrowFunctionsOptions,
-    es2018::ObjectRestSpreadOptions, es2022::ClassPropertiesOptions, jsx::JsxOptions,
+    DecoratorOptions, TypeScriptOptions,
+    es2015::{ArrowFunctionsOptions, BlockScopingOptions, ForOfOptions},
+    es2018::ObjectRestSpreadOptions,
+    es2022::ClassPropertiesOptions,
+    jsx::JsxOptions,
     plugins::StyledComponentsOptions,
 };
 
@@ -43,6 +46,9 @@ pub struct BabelPlugins {
     pub react_display_name: bool,
     // modules
     pub modules_commonjs: bool,
+    pub modules_amd: bool,
+    pub modules_umd: bool,
+    pub modules_systemjs: bool,
     // regexp
     pub sticky_flag: bool,
     pub unicode_flag: bool,
@@ -55,6 +61,13 @@ pub struct BabelPlugins {
     pub set_notation: bool,
     // ES2015
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    pub block_scoping: Option<BlockScopingOptions>,
+    pub classes: bool,
+    pub destructuring: bool,
+    pub for_of: Option<ForOfOptions>,
+    pub parameters: bool,
+    pub regenerator: bool,
+    pub spread: bool,
     // ES2016
     pub exponentiation_operator: bool,
     // ES2017
@@ -128,6 +141,9 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-react-jsx-self" => p.react_jsx_self = true,
                 "transform-react-jsx-source" => p.react_jsx_source = true,
                 "transform-modules-commonjs" => p.modules_commonjs = true,
+                "transform-modules-amd" => p.modules_amd = true,
+                "transform-modules-umd" => p.modules_umd = true,
+                "transform-modules-systemjs" => p.modules_systemjs = true,
                 "transform-sticky-regex" => p.sticky_flag = true,
                 "transform-unicode-regex" => p.unicode_flag = true,
                 "transform-dotall-regex" => p.dot_all_flag = true,
@@ -142,6 +158,18 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "transform-block-scoping" => {
+                    p.block_scoping =
+                        entry.value::<BlockScopingOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-classes" => p.classes = true,
+                "transform-destructuring" => p.destructuring = true,
+                "transform-for-of" => {
+                    p.for_of = entry.value::<ForOfOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-parameters" => p.parameters = true,
+                "transform-regenerator" => p.regenerator = true,
+                "transform-spread" => p.spread = true,
                 "transform-exponentiation-operator" => p.exponentiation_operator = true,
                 "transform-async-to-generator" => p.async_to_generator = true,
                 "transform-object-rest-spread" => {
@@ -1,7 +1,7 @@
 use serde::Deserialize;
 
 use crate::{
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{ArrowFunctionsOptions, BlockScopingOptions, ES2015Options, ForOfOptions},
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::{ES2018Options, ObjectRestSpreadOptions},
@@ -68,6 +68,28 @@ impl EnvOptions {
                 } else {
                     None
                 },
+                // Turned off because it is not ready.
+                block_scoping: if include_unfinished_plugins {
+                    Some(BlockScopingOptions::default())
+                } else {
+                    None
+                },
+                // Turned off because it is not ready.
+                classes: include_unfinished_plugins,
+                // Turned off because it is not ready.
+                destructuring: include_unfinished_plugins,
+                // Turned off because it is not ready.
+                for_of: if include_unfinished_plugins {
+                    Some(ForOfOptions::default())
+                } else {
+                    None
+                },
+                // Turned off because it is not ready.
+                parameters: include_unfinished_plugins,
+                // Turned off because it is not ready.
+                regenerator: include_unfinished_plugins,
+                // Turned off because it is not ready.
+                spread: include_unfinished_plugins,
             },
             es2016: ES2016Options { exponentiation_operator: true },
             es2017: ES2017Options { async_to_generator: true },
@@ -145,6 +167,13 @@ impl From<EngineTargets> for EnvOptions {
             },
             es2015: ES2015Options {
                 arrow_function: o.has_feature(ES2015ArrowFunctions).then(Default::default),
+                block_scoping: o.has_feature(ES2015BlockScoping).then(Default::default),
+                classes: o.has_feature(ES2015Classes),
+                destructuring: o.has_feature(ES2015Destructuring),
+                for_of: o.has_feature(ES2015ForOf).then(Default::default),
+                parameters: o.has_feature(ES2015Parameters),
+                regenerator: o.has_feature(ES2015Regenerator),
+                spread: o.has_feature(ES2015Spread),
             },
             es2016: ES2016Options {
                 exponentiation_operator: o.has_feature(ES2016ExponentiationOperator),
@@ -79,7 +79,11 @@ impl TransformOptions {
             cwd: PathBuf::new(),
             assumptions: CompilerAssumptions::default(),
             typescript: TypeScriptOptions::default(),
-            decorator: DecoratorOptions { legacy: true, emit_decorator_metadata: true },
+            decorator: DecoratorOptions {
+                legacy: true,
+                standard: false,
+                emit_decorator_metadata: true,
+            },
             jsx: JsxOptions {
                 development: true,
                 refresh: Some(ReactRefreshOptions::default()),
@@ -158,6 +162,12 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let decorator = DecoratorOptions {
             legacy: options.plugins.legacy_decorator.is_some(),
+            standard: options.plugins.legacy_decorator.is_none()
+                && options
+                    .plugins
+                    .proposal_decorators
+                    .as_ref()
+                    .is_some_and(|o| o.version == "2023-11"),
             emit_decorator_metadata: options
                 .plugins
                 .legacy_decorator
@@ -204,6 +214,13 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let es2015 = ES2015Options {
             arrow_function: options.plugins.arrow_function.or(env.es2015.arrow_function),
+            block_scoping: options.plugins.block_scoping.or(env.es2015.block_scoping),
+            classes: options.plugins.classes || env.es2015.classes,
+            destructuring: options.plugins.destructuring || env.es2015.destructuring,
+            for_of: options.plugins.for_of.or(env.es2015.for_of),
+            parameters: options.plugins.parameters || env.es2015.parameters,
+            regenerator: options.plugins.regenerator || env.es2015.regenerator,
+            spread: options.plugins.spread || env.es2015.spread,
         };
 
         let es2016 = ES2016Options {
@@ -1 +1,42 @@
+use std::cell::Cell;
+
+use oxc_ast::ast::Expression;
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ScopeFlags, ScopeId};
+
+use crate::context::TraverseCtx;
+
 pub mod ast_builder;
+
+/// Set the parent scope of the outermost scopes within `expr`.
+pub fn reparent_child_scopes(
+    expr: &Expression<'_>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'_>,
+) {
+    let mut collector = ChildScopeCollector::default();
+    collector.visit_expression(expr);
+    for scope_id in collector.scope_ids {
+        ctx.scoping_mut().change_scope_parent_id(scope_id, Some(parent_scope_id));
+    }
+}
+
+/// Collects the outermost scopes in visited nodes.
+#[derive(Default)]
+struct ChildScopeCollector {
+    depth: usize,
+    scope_ids: Vec<ScopeId>,
+}
+
+impl Visit<'_> for ChildScopeCollector {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            self.scope_ids.push(scope_id.get().unwrap());
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
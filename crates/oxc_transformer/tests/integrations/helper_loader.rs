@@ -0,0 +1,36 @@
+use oxc_transformer::{ESTarget, HelperLoaderMode, TransformOptions};
+
+use crate::test;
+
+fn inline_options(target: ESTarget) -> TransformOptions {
+    let mut options = TransformOptions::from(target);
+    options.helper_loader.mode = HelperLoaderMode::Inline;
+    options
+}
+
+#[test]
+fn inline_helpers() {
+    let options = inline_options(ESTarget::ES2016);
+    let result = test("({ ...a }); ({ ...b }); async function f() {}", &options).unwrap();
+    assert!(!result.contains("import "), "{result}");
+    // Each helper is inlined once, including helpers only used by other helpers
+    for helper in ["_objectSpread", "_asyncToGenerator", "_defineProperty", "_toPropertyKey"] {
+        assert_eq!(result.matches(&format!("function {helper}(")).count(), 1, "{result}");
+    }
+    assert_eq!(result.matches("_objectSpread(").count(), 3, "{result}");
+}
+
+#[test]
+fn inline_helpers_avoid_name_clashes() {
+    let options = inline_options(ESTarget::ES2016);
+    let result =
+        test("const _objectSpread = 1, _ownKeys = 2; ({ ...a, _objectSpread });", &options)
+            .unwrap();
+    assert!(result.contains("const _objectSpread = 1, _ownKeys = 2;"), "{result}");
+    assert!(result.contains("function _objectSpread2("), "{result}");
+    assert!(result.contains("function _ownKeys2("), "{result}");
+    assert!(
+        result.contains("_objectSpread2(_objectSpread2({}, a), {}, { _objectSpread })"),
+        "{result}"
+    );
+}
@@ -1,4 +1,5 @@
 mod es_target;
+mod helper_loader;
 mod targets;
 
 use std::path::Path;
@@ -14,6 +14,7 @@ fn targets() {
         ("a ?? b"),
         ("a ||= b"),
         "1n ** 2n",
+        "class A {}",
     ];
 
     // Test no transformation for default targets.
@@ -35,3 +36,14 @@ fn targets() {
         assert_eq!(test(case, &options), test(case, &options_node));
     }
 }
+
+#[test]
+fn classes() {
+    let options = TransformOptions {
+        env: EnvOptions::from_browserslist_query("chrome 40").unwrap(),
+        ..TransformOptions::default()
+    };
+    let result = test("class A extends B { foo() {} }", &options).unwrap();
+    assert!(!result.contains("class A"), "{result}");
+    assert!(result.contains("_inherits(A, _B)"), "{result}");
+}
@@ -139,6 +139,13 @@ export interface MinifyOptions {
   mangle?: boolean | MangleOptions
   codegen?: boolean | CodegenOptions
   sourcemap?: boolean
+  /**
+   * Source map of the input code, when it was generated by another tool.
+   *
+   * When provided together with `sourcemap: true`, the returned source map
+   * is composed with it, so that it points at the original sources.
+   */
+  inputSourceMap?: SourceMap
 }
 
 export interface MinifyResult {
@@ -18,7 +18,7 @@ use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_minifier::Minifier;
-use oxc_napi::OxcError;
+use oxc_napi::{OxcError, parse_input_source_map};
 use oxc_parser::Parser;
 use oxc_sourcemap::napi::SourceMap;
 use oxc_span::SourceType;
@@ -35,7 +35,7 @@ pub struct MinifyResult {
 
 fn minify_impl(filename: &str, source_text: &str, options: Option<MinifyOptions>) -> MinifyResult {
     use oxc_codegen::CodegenOptions;
-    let options = options.unwrap_or_default();
+    let mut options = options.unwrap_or_default();
 
     let minifier_options = match oxc_minifier::MinifierOptions::try_from(&options) {
         Ok(options) => options,
@@ -51,6 +51,21 @@ fn minify_impl(filename: &str, source_text: &str, options: Option<MinifyOptions>
         }
     };
 
+    let input_source_map = match options.input_source_map.take().map(parse_input_source_map) {
+        Some(Ok(map)) => Some(map),
+        Some(Err(error)) => {
+            return MinifyResult {
+                errors: OxcError::from_diagnostics(
+                    filename,
+                    source_text,
+                    vec![OxcDiagnostic::error(error)],
+                ),
+                ..MinifyResult::default()
+            };
+        }
+        None => None,
+    };
+
     let allocator = Allocator::default();
 
     let source_type = if options.module == Some(true) {
@@ -73,6 +88,7 @@ fn minify_impl(filename: &str, source_text: &str, options: Option<MinifyOptions>
 
     if options.sourcemap == Some(true) {
         codegen_options.source_map_path = Some(PathBuf::from(&filename));
+        codegen_options.input_source_map = input_source_map;
     }
 
     let ret = Codegen::new().with_options(codegen_options).with_scoping(scoping).build(&program);
@@ -226,6 +226,8 @@ impl From<&MangleOptions> for oxc_minifier::MangleOptions {
                 None => default.keep_names,
             },
             debug: o.debug.unwrap_or(default.debug),
+            properties: default.properties,
+            name_cache: default.name_cache,
         }
     }
 }
@@ -287,6 +289,12 @@ pub struct MinifyOptions {
     pub codegen: Option<Either<bool, CodegenOptions>>,
 
     pub sourcemap: Option<bool>,
+
+    /// Source map of the input code, when it was generated by another tool.
+    ///
+    /// When provided together with `sourcemap: true`, the returned source map
+    /// is composed with it, so that it points at the original sources.
+    pub input_source_map: Option<oxc_sourcemap::napi::SourceMap>,
 }
 
 impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
@@ -18,6 +18,25 @@ describe("simple", () => {
     });
   });
 
+  it("composes with input source map", () => {
+    const original = minifySync("original.js", code, {
+      compress: false,
+      mangle: false,
+      sourcemap: true,
+    });
+    const ret = minifySync("intermediate.js", original.code, {
+      sourcemap: true,
+      inputSourceMap: original.map,
+    });
+    expect(ret.errors.length).toBe(0);
+    expect(ret.map).toMatchObject({
+      names: ["bar"],
+      sources: ["original.js"],
+      sourcesContent: [code],
+      version: 3,
+    });
+  });
+
   it("can turn off everything", () => {
     const ret = minifySync("test.js", code, {
       compress: false,
@@ -49,7 +49,9 @@ export interface ArrowFunctionsOptions {
 }
 
 export interface CompilerAssumptions {
+  arrayLikeIsIterable?: boolean
   ignoreFunctionLength?: boolean
+  iterableIsArray?: boolean
   noDocumentAll?: boolean
   objectRestNoSymbols?: boolean
   pureGetters?: boolean
@@ -95,6 +97,7 @@ export interface CompilerAssumptions {
    * to `true`.
    */
   setPublicClassFields?: boolean
+  skipForOfIteratorClosing?: boolean
 }
 
 export interface DecoratorOptions {
@@ -108,6 +111,15 @@ export interface DecoratorOptions {
    * @default false
    */
   legacy?: boolean
+  /**
+   * Enables transforming standard decorators, following the 2023-11 version of the TC39 decorators proposal.
+   *
+   * Has no effect when `legacy` is true.
+   *
+   * @see https://github.com/tc39/proposal-decorators
+   * @default false
+   */
+  standard?: boolean
   /**
    * Enables emitting decorator metadata.
    *
@@ -123,6 +135,48 @@ export interface DecoratorOptions {
 export interface Es2015Options {
   /** Transform arrow functions into function expressions. */
   arrowFunction?: ArrowFunctionsOptions
+  /**
+   * Transform `let` and `const` declarations into `var` declarations.
+   *
+   * @default false
+   */
+  blockScoping?: boolean
+  /**
+   * Transform classes into constructor functions.
+   *
+   * @default false
+   */
+  classes?: boolean
+  /**
+   * Transform destructuring patterns.
+   *
+   * @default false
+   */
+  destructuring?: boolean
+  /**
+   * Transform `for...of` loops.
+   *
+   * @default false
+   */
+  forOf?: boolean
+  /**
+   * Transform default, destructuring and rest parameters.
+   *
+   * @default false
+   */
+  parameters?: boolean
+  /**
+   * Transform generator functions into state machines, using `regeneratorRuntime`.
+   *
+   * @default false
+   */
+  regenerator?: boolean
+  /**
+   * Transform spread elements in arrays, calls and `new` expressions.
+   *
+   * @default false
+   */
+  spread?: boolean
 }
 
 export declare const enum HelperMode {
@@ -146,7 +200,19 @@ export declare const enum HelperMode {
    * babelHelpers.helperName(...arguments);
    * ```
    */
-  External = 'External'
+  External = 'External',
+  /**
+   * Inline mode: Helper functions are inserted at top of the file.
+   * Output does not depend on a runtime package.
+   *
+   * Example:
+   *
+   * ```js
+   * function _helperName(...arguments) { ... }
+   * _helperName(...arguments);
+   * ```
+   */
+  Inline = 'Inline'
 }
 
 export interface Helpers {
@@ -459,6 +525,14 @@ export interface TransformOptions {
    * @see {@link SourceMap}
    */
   sourcemap?: boolean
+  /**
+   * Source map of the input code, when it was generated by another tool
+   * (e.g. a Vue SFC compiler).
+   *
+   * When provided together with `sourcemap: true`, the returned source map
+   * is composed with it, so that it points at the original sources.
+   */
+  inputSourceMap?: SourceMap
   /** Set assumptions in order to produce smaller output. */
   assumptions?: CompilerAssumptions
   /** Configure how TypeScript is transformed. */
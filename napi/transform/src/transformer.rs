@@ -27,7 +27,7 @@ use oxc::{
         ReplaceGlobalDefinesConfig,
     },
 };
-use oxc_napi::{OxcError, get_source_type};
+use oxc_napi::{OxcError, get_source_type, parse_input_source_map};
 use oxc_sourcemap::napi::SourceMap;
 
 use crate::IsolatedDeclarationsOptions;
@@ -107,6 +107,13 @@ pub struct TransformOptions {
     /// @see {@link SourceMap}
     pub sourcemap: Option<bool>,
 
+    /// Source map of the input code, when it was generated by another tool
+    /// (e.g. a Vue SFC compiler).
+    ///
+    /// When provided together with `sourcemap: true`, the returned source map
+    /// is composed with it, so that it points at the original sources.
+    pub input_source_map: Option<SourceMap>,
+
     /// Set assumptions in order to produce smaller output.
     pub assumptions: Option<CompilerAssumptions>,
 
@@ -196,7 +203,9 @@ impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
 #[napi(object)]
 #[derive(Default, Debug)]
 pub struct CompilerAssumptions {
+    pub array_like_is_iterable: Option<bool>,
     pub ignore_function_length: Option<bool>,
+    pub iterable_is_array: Option<bool>,
     pub no_document_all: Option<bool>,
     pub object_rest_no_symbols: Option<bool>,
     pub pure_getters: Option<bool>,
@@ -240,15 +249,20 @@ pub struct CompilerAssumptions {
     /// set both `set_public_class_fields` and [`crate::TypeScriptOptions::remove_class_fields_without_initializer`]
     /// to `true`.
     pub set_public_class_fields: Option<bool>,
+    pub skip_for_of_iterator_closing: Option<bool>,
 }
 
 impl From<CompilerAssumptions> for oxc::transformer::CompilerAssumptions {
     fn from(value: CompilerAssumptions) -> Self {
         let ops = oxc::transformer::CompilerAssumptions::default();
         Self {
+            array_like_is_iterable: value
+                .array_like_is_iterable
+                .unwrap_or(ops.array_like_is_iterable),
             ignore_function_length: value
                 .ignore_function_length
                 .unwrap_or(ops.ignore_function_length),
+            iterable_is_array: value.iterable_is_array.unwrap_or(ops.iterable_is_array),
             no_document_all: value.no_document_all.unwrap_or(ops.no_document_all),
             object_rest_no_symbols: value
                 .object_rest_no_symbols
@@ -257,6 +271,9 @@ impl From<CompilerAssumptions> for oxc::transformer::CompilerAssumptions {
             set_public_class_fields: value
                 .set_public_class_fields
                 .unwrap_or(ops.set_public_class_fields),
+            skip_for_of_iterator_closing: value
+                .skip_for_of_iterator_closing
+                .unwrap_or(ops.skip_for_of_iterator_closing),
             ..ops
         }
     }
@@ -376,6 +393,14 @@ pub struct DecoratorOptions {
     /// @default false
     pub legacy: Option<bool>,
 
+    /// Enables transforming standard decorators, following the 2023-11 version of the TC39 decorators proposal.
+    ///
+    /// Has no effect when `legacy` is true.
+    ///
+    /// @see https://github.com/tc39/proposal-decorators
+    /// @default false
+    pub standard: Option<bool>,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
@@ -390,6 +415,7 @@ impl From<DecoratorOptions> for oxc::transformer::DecoratorOptions {
     fn from(options: DecoratorOptions) -> Self {
         oxc::transformer::DecoratorOptions {
             legacy: options.legacy.unwrap_or_default(),
+            standard: options.standard.unwrap_or_default(),
             emit_decorator_metadata: options.emit_decorator_metadata.unwrap_or_default(),
         }
     }
@@ -646,11 +672,48 @@ impl From<ArrowFunctionsOptions> for oxc::transformer::ArrowFunctionsOptions {
 pub struct Es2015Options {
     /// Transform arrow functions into function expressions.
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    /// Transform `let` and `const` declarations into `var` declarations.
+    ///
+    /// @default false
+    pub block_scoping: Option<bool>,
+    /// Transform classes into constructor functions.
+    ///
+    /// @default false
+    pub classes: Option<bool>,
+    /// Transform destructuring patterns.
+    ///
+    /// @default false
+    pub destructuring: Option<bool>,
+    /// Transform `for...of` loops.
+    ///
+    /// @default false
+    pub for_of: Option<bool>,
+    /// Transform default, destructuring and rest parameters.
+    ///
+    /// @default false
+    pub parameters: Option<bool>,
+    /// Transform generator functions into state machines, using `regeneratorRuntime`.
+    ///
+    /// @default false
+    pub regenerator: Option<bool>,
+    /// Transform spread elements in arrays, calls and `new` expressions.
+    ///
+    /// @default false
+    pub spread: Option<bool>,
 }
 
 impl From<Es2015Options> for oxc::transformer::ES2015Options {
     fn from(options: Es2015Options) -> Self {
-        oxc::transformer::ES2015Options { arrow_function: options.arrow_function.map(Into::into) }
+        oxc::transformer::ES2015Options {
+            arrow_function: options.arrow_function.map(Into::into),
+            block_scoping: options.block_scoping.unwrap_or_default().then(Default::default),
+            classes: options.classes.unwrap_or_default(),
+            destructuring: options.destructuring.unwrap_or_default(),
+            for_of: options.for_of.unwrap_or_default().then(Default::default),
+            parameters: options.parameters.unwrap_or_default(),
+            regenerator: options.regenerator.unwrap_or_default(),
+            spread: options.spread.unwrap_or_default(),
+        }
     }
 }
 
@@ -681,6 +744,16 @@ pub enum HelperMode {
     /// babelHelpers.helperName(...arguments);
     /// ```
     External,
+    /// Inline mode: Helper functions are inserted at top of the file.
+    /// Output does not depend on a runtime package.
+    ///
+    /// Example:
+    ///
+    /// ```js
+    /// function _helperName(...arguments) { ... }
+    /// _helperName(...arguments);
+    /// ```
+    Inline,
 }
 
 impl From<Helpers> for HelperLoaderOptions {
@@ -697,6 +770,7 @@ impl From<HelperMode> for HelperLoaderMode {
         match value {
             HelperMode::Runtime => Self::Runtime,
             HelperMode::External => Self::External,
+            HelperMode::Inline => Self::Inline,
         }
     }
 }
@@ -707,6 +781,7 @@ struct Compiler {
     isolated_declaration_options: Option<oxc::isolated_declarations::IsolatedDeclarationsOptions>,
 
     sourcemap: bool,
+    input_source_map: Option<oxc::sourcemap::SourceMap>,
 
     printed: String,
     printed_sourcemap: Option<SourceMap>,
@@ -733,6 +808,13 @@ impl Compiler {
 
         let sourcemap = options.as_ref().and_then(|o| o.sourcemap).unwrap_or_default();
 
+        let input_source_map = options
+            .as_mut()
+            .and_then(|options| options.input_source_map.take())
+            .map(parse_input_source_map)
+            .transpose()
+            .map_err(|err| vec![OxcDiagnostic::error(err)])?;
+
         let define = options
             .as_mut()
             .and_then(|options| options.define.take())
@@ -778,6 +860,7 @@ impl Compiler {
             transform_options,
             isolated_declaration_options,
             sourcemap,
+            input_source_map,
             printed: String::default(),
             printed_sourcemap: None,
             declaration: None,
@@ -799,6 +882,10 @@ impl CompilerInterface for Compiler {
         self.sourcemap
     }
 
+    fn input_source_map(&self) -> Option<oxc::sourcemap::SourceMap> {
+        self.input_source_map.clone()
+    }
+
     fn transform_options(&self) -> Option<&oxc::transformer::TransformOptions> {
         Some(&self.transform_options)
     }
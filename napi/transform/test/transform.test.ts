@@ -146,6 +146,18 @@ describe("helpers", () => {
       objectSpread2: "@oxc-project/runtime/helpers/objectSpread2",
     });
   });
+
+  test("Inline", () => {
+    const code = `({ ...x }); ({ ...y })`;
+    const ret = transformSync("test.mjs", code, {
+      target: "es2015",
+      helpers: { mode: HelperMode.Inline },
+    });
+    expect(ret.code).not.toContain("import");
+    expect(ret.code.match(/function _objectSpread\(/g)).toHaveLength(1);
+    expect(ret.code).toContain("function _defineProperty(");
+    expect(ret.code).toContain("_objectSpread({}, x);\n_objectSpread({}, y);\n");
+  });
 });
 
 describe("modules", () => {
@@ -67,6 +67,7 @@ fn bench_pipeline(criterion: &mut Criterion) {
                         .with_options(codegen_options)
                         .with_scoping(Some(mangler_ret.scoping))
                         .with_private_member_mappings(Some(mangler_ret.class_private_mappings))
+                        .with_property_mappings(Some(mangler_ret.property_mappings))
                         .build(&program)
                 });
             });
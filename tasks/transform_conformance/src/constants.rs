@@ -36,6 +36,13 @@ pub const PLUGINS: &[&str] = &[
     "babel-plugin-transform-exponentiation-operator",
     // ES2015
     "babel-plugin-transform-arrow-functions",
+    "babel-plugin-transform-classes",
+    "babel-plugin-transform-spread",
+    "babel-plugin-transform-destructuring",
+    "babel-plugin-transform-parameters",
+    "babel-plugin-transform-block-scoping",
+    "babel-plugin-transform-for-of",
+    "babel-plugin-transform-regenerator",
     // "babel-plugin-transform-function-name",
     // "babel-plugin-transform-shorthand-properties",
     // "babel-plugin-transform-sticky-regex",
@@ -46,6 +53,10 @@ pub const PLUGINS: &[&str] = &[
     // "babel-plugin-transform-new-target",
     // // ES3
     // "babel-plugin-transform-property-literals",
+    // Modules
+    "babel-plugin-transform-modules-amd",
+    "babel-plugin-transform-modules-umd",
+    "babel-plugin-transform-modules-systemjs",
     // TypeScript
     "babel-preset-typescript",
     "babel-plugin-transform-typescript",
@@ -56,8 +67,8 @@ pub const PLUGINS: &[&str] = &[
     "babel-plugin-transform-react-jsx-self",
     "babel-plugin-transform-react-jsx-source",
     "babel-plugin-transform-react-jsx-development",
-    // // Proposal
-    // "babel-plugin-proposal-decorators",
+    // Proposal
+    "babel-plugin-proposal-decorators",
     // RegExp tests ported from esbuild + a few additions
     "regexp",
     // Legacy decorators, tests almost ported from TypeScript
@@ -69,10 +80,7 @@ pub const PLUGINS: &[&str] = &[
 
 pub const PLUGINS_NOT_SUPPORTED_YET: &[&str] = &[
     "proposal-decorators",
-    "transform-classes",
-    "transform-destructuring",
     "transform-modules-commonjs",
-    "transform-parameters",
     "transform-property-literals",
     "transform-react-constant-elements",
 ];
@@ -0,0 +1,10 @@
+class Foo {
+  @dec
+  accessor a = 1;
+
+  @dec
+  static accessor #b = 2;
+
+  @dec
+  #method() {}
+}
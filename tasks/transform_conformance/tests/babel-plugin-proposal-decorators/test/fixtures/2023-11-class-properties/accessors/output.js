@@ -0,0 +1,50 @@
+var _Foo;
+let _initProto, _init_a, _init_extra_a, _init_b, _init_extra_b, _get_b, _set_b, _call_method;
+var _A = /* @__PURE__ */ new WeakMap();
+var _Foo_brand = /* @__PURE__ */ new WeakSet();
+class Foo {
+	get a() {
+		return babelHelpers.classPrivateFieldGet2(_A, this);
+	}
+	set a(v) {
+		babelHelpers.classPrivateFieldSet2(_A, this, v);
+	}
+	constructor() {
+		babelHelpers.classPrivateMethodInitSpec(this, _Foo_brand);
+		babelHelpers.classPrivateFieldInitSpec(this, _A, (_initProto(this), _init_a(this, 1)));
+		_init_extra_a(this);
+	}
+}
+_Foo = Foo;
+function _get_b2() {
+	return _get_b();
+}
+function _set_b2(v) {
+	_set_b(v);
+}
+function _get_method() {
+	return _call_method;
+}
+[_init_b, _get_b, _set_b, _init_extra_b, _init_a, _init_extra_a, _call_method, _initProto] = babelHelpers.applyDecs2311(_Foo, [], [
+	[
+		dec,
+		1,
+		"a"
+	],
+	[
+		dec,
+		9,
+		"b",
+		(o) => babelHelpers.assertClassBrand(_Foo, o, _B)._,
+		(o, v) => _B._ = babelHelpers.assertClassBrand(_Foo, o, v)
+	],
+	[
+		dec,
+		2,
+		"method",
+		function() {}
+	]
+], 0, (_) => _Foo_brand.has(babelHelpers.checkInRHS(_))).e;
+var _B = { _: _init_b(2) };
+_init_extra_b();
+
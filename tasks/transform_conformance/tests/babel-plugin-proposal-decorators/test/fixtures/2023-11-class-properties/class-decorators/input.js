@@ -0,0 +1,8 @@
+@dec
+class Foo {
+  static a = 1;
+
+  static {
+    Foo.b = 2;
+  }
+}
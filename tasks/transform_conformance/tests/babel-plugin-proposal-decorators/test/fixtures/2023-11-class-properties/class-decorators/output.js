@@ -0,0 +1,11 @@
+var _Class, _Foo2, _A;
+let _Foo, _initClass, _ref;
+let Foo = (new (_A = /* @__PURE__ */ new WeakMap(), _ref = (_Foo2 = class Foo {}, [_Foo, _initClass] = babelHelpers.applyDecs2311(_Foo2, [dec], []).c, _Foo2), _Class = class extends babelHelpers.identity {
+	constructor() {
+		var _super = (..._args) => (super(..._args), babelHelpers.defineProperty(this, "a", 1), babelHelpers.classPrivateFieldInitSpec(this, _A, (() => {
+			_Foo.b = 2;
+		})()), this);
+		_super(_Foo), _initClass();
+	}
+}, babelHelpers.defineProperty(_Class, _ref, void 0), _Class)(), _Foo);
+
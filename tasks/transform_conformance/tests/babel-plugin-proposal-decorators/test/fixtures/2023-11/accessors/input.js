@@ -0,0 +1,18 @@
+class Foo {
+  accessor a = 1;
+
+  @dec
+  accessor b = 2;
+
+  @dec
+  accessor #c = 3;
+
+  @dec
+  static accessor d = 4;
+
+  static accessor #e;
+
+  accessor [key()] = 5;
+
+  #A = 6;
+}
@@ -0,0 +1,69 @@
+let _init_b, _init_extra_b, _init_c, _init_extra_c, _get_c, _set_c, _init_d, _init_extra_d, _computedKey;
+_computedKey = babelHelpers.toPropertyKey(key());
+class Foo {
+	static {
+		[_init_d, _init_extra_d, _init_b, _init_extra_b, _init_c, _get_c, _set_c, _init_extra_c] = babelHelpers.applyDecs2311(this, [], [
+			[
+				dec,
+				1,
+				"b"
+			],
+			[
+				dec,
+				1,
+				"c",
+				(o) => o.#D,
+				(o, v) => o.#D = v
+			],
+			[
+				dec,
+				9,
+				"d"
+			]
+		], 0, (_) => #c in _).e;
+	}
+	#B = 1;
+	get a() {
+		return this.#B;
+	}
+	set a(v) {
+		this.#B = v;
+	}
+	#C = _init_b(this, 2);
+	get b() {
+		return this.#C;
+	}
+	set b(v) {
+		this.#C = v;
+	}
+	#D = (_init_extra_b(this), _init_c(this, 3));
+	get #c() {
+		return _get_c(this);
+	}
+	set #c(v) {
+		_set_c(this, v);
+	}
+	static #E = _init_d(4);
+	static get d() {
+		return this.#E;
+	}
+	static set d(v) {
+		this.#E = v;
+	}
+	static #F = (_init_extra_d(), void 0);
+	static get #e() {
+		return this.#F;
+	}
+	static set #e(v) {
+		this.#F = v;
+	}
+	#G = (_init_extra_c(this), 5);
+	get [_computedKey]() {
+		return this.#G;
+	}
+	set [_computedKey](v) {
+		this.#G = v;
+	}
+	#A = 6;
+}
+
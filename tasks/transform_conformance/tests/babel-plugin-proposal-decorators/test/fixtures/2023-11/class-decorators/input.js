@@ -0,0 +1,11 @@
+@dec
+class Foo {
+  static create() {
+    return new Foo();
+  }
+}
+
+@a @b.c @d()
+class Bar {}
+
+new Foo();
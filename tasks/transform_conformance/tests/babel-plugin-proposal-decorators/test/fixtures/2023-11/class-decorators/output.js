@@ -0,0 +1,24 @@
+let _Foo, _initClass, _dec, _Bar, _initClass2;
+let Foo = (class Foo {
+	static {
+		[_Foo, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+	}
+	static create() {
+		return new _Foo();
+	}
+}, _initClass(), _Foo);
+_dec = d();
+let Bar = (class Bar {
+	static {
+		[_Bar, _initClass2] = babelHelpers.applyDecs2311(this, [
+			void 0,
+			a,
+			b,
+			b.c,
+			void 0,
+			_dec
+		], [], 1).c;
+	}
+}, _initClass2(), _Bar);
+new Foo();
+
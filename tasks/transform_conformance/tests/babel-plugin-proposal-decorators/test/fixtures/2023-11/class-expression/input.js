@@ -0,0 +1,12 @@
+const Foo = @dec class {
+  @obj.dec
+  method() {}
+
+  @dec()
+  [key()] = 1;
+};
+
+const Bar = class Bar {
+  @dec
+  static accessor x = Bar;
+};
@@ -0,0 +1,39 @@
+let _initProto, _dec, _computedKey, _init_computedKey, _init_extra_computedKey, _Class, _initClass, _init_x, _init_extra_x;
+const Foo = (_dec = dec(), _computedKey = babelHelpers.toPropertyKey(key()), class {
+	static {
+		({e: [_init_computedKey, _init_extra_computedKey, _initProto], c: [_Class, _initClass]} = babelHelpers.applyDecs2311(this, [dec], [[
+			[obj, obj.dec],
+			18,
+			"method"
+		], [
+			_dec,
+			0,
+			_computedKey
+		]]));
+	}
+	method() {}
+	[_computedKey] = (_initProto(this), _init_computedKey(this, 1));
+	constructor() {
+		_init_extra_computedKey(this);
+	}
+}, _initClass(), _Class);
+const Bar = class Bar {
+	static {
+		[_init_x, _init_extra_x] = babelHelpers.applyDecs2311(this, [], [[
+			dec,
+			9,
+			"x"
+		]]).e;
+	}
+	static #A = _init_x(Bar);
+	static get x() {
+		return this.#A;
+	}
+	static set x(v) {
+		this.#A = v;
+	}
+	static {
+		_init_extra_x();
+	}
+};
+
@@ -0,0 +1,18 @@
+class Foo extends Base {
+  @dec
+  method() {}
+
+  constructor() {
+    if (cond) {
+      super(1);
+    } else {
+      super(2);
+    }
+  }
+}
+
+@dec
+class Bar extends getBase() {
+  @dec
+  field = 1;
+}
@@ -0,0 +1,33 @@
+let _initProto, _init_field, _init_extra_field, _Bar, _initClass, _super;
+class Foo extends Base {
+	static {
+		[_initProto] = babelHelpers.applyDecs2311(this, [], [[
+			dec,
+			2,
+			"method"
+		]], 0, void 0, Base).e;
+	}
+	method() {}
+	constructor() {
+		if (cond) {
+			_initProto(super(1));
+		} else {
+			_initProto(super(2));
+		}
+	}
+}
+let Bar = (class Bar extends (_super = getBase()) {
+	static {
+		({e: [_init_field, _init_extra_field], c: [_Bar, _initClass]} = babelHelpers.applyDecs2311(this, [dec], [[
+			dec,
+			0,
+			"field"
+		]], 0, void 0, _super));
+	}
+	field = _init_field(this, 1);
+	constructor(..._args) {
+		super(..._args);
+		_init_extra_field(this);
+	}
+}, _initClass(), _Bar);
+
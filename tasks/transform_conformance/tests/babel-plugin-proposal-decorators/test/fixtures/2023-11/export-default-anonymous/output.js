@@ -0,0 +1,7 @@
+let _Class, _initClass;
+export default (class {
+	static {
+		[_Class, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+	}
+}, _initClass(), _Class);
+
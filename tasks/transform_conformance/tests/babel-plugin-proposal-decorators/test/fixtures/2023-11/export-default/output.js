@@ -0,0 +1,8 @@
+let _Foo, _initClass;
+let Foo = (class Foo {
+	static {
+		[_Foo, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+	}
+}, _initClass(), _Foo);
+export { Foo as default };
+
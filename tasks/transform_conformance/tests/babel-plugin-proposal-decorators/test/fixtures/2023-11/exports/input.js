@@ -0,0 +1,7 @@
+@dec
+export class Foo {}
+
+export @dec class Bar {
+  @dec
+  method() {}
+}
@@ -0,0 +1,20 @@
+let _Foo, _initClass, _initProto, _Bar, _initClass2;
+export let Foo = (class Foo {
+	static {
+		[_Foo, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+	}
+}, _initClass(), _Foo);
+export let Bar = (class Bar {
+	static {
+		({e: [_initProto], c: [_Bar, _initClass2]} = babelHelpers.applyDecs2311(this, [dec], [[
+			dec,
+			2,
+			"method"
+		]]));
+	}
+	method() {}
+	constructor() {
+		_initProto(this);
+	}
+}, _initClass2(), _Bar);
+
@@ -0,0 +1,20 @@
+class Foo {
+  @dec
+  a = 1;
+
+  @dec
+  b;
+
+  c = 2;
+
+  @dec
+  #d = 3;
+
+  @dec
+  static e = 4;
+
+  @dec
+  static #f = 5;
+
+  static g = 6;
+}
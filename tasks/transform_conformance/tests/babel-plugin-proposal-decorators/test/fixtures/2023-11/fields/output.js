@@ -0,0 +1,47 @@
+let _init_a, _init_extra_a, _init_b, _init_extra_b, _init_d, _init_extra_d, _init_e, _init_extra_e, _init_f, _init_extra_f;
+class Foo {
+	static {
+		[_init_e, _init_extra_e, _init_f, _init_extra_f, _init_a, _init_extra_a, _init_b, _init_extra_b, _init_d, _init_extra_d] = babelHelpers.applyDecs2311(this, [], [
+			[
+				dec,
+				0,
+				"a"
+			],
+			[
+				dec,
+				0,
+				"b"
+			],
+			[
+				dec,
+				0,
+				"d",
+				(o) => o.#d,
+				(o, v) => o.#d = v
+			],
+			[
+				dec,
+				8,
+				"e"
+			],
+			[
+				dec,
+				8,
+				"f",
+				(o) => o.#f,
+				(o, v) => o.#f = v
+			]
+		], 0, (_) => #d in _).e;
+	}
+	a = _init_a(this, 1);
+	b = (_init_extra_a(this), _init_b(this));
+	c = (_init_extra_b(this), 2);
+	#d = _init_d(this, 3);
+	static e = _init_e(4);
+	static #f = (_init_extra_e(), _init_f(5));
+	static g = (_init_extra_f(), 6);
+	constructor() {
+		_init_extra_d(this);
+	}
+}
+
@@ -0,0 +1,31 @@
+class Foo {
+  @dec
+  method() {}
+
+  @dec
+  static staticMethod() {}
+
+  @dec
+  get value() {
+    return 1;
+  }
+
+  @dec
+  set value(v) {}
+
+  @dec
+  #privateMethod() {
+    return this;
+  }
+
+  @dec
+  get #privateValue() {
+    return 1;
+  }
+
+  @dec
+  set #privateValue(v) {}
+
+  @dec
+  static #staticPrivateMethod() {}
+}
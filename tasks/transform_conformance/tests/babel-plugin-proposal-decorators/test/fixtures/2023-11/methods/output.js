@@ -0,0 +1,78 @@
+let _initProto, _initStatic, _call_privateMethod, _call_privateValue, _call_privateValue2, _call_staticPrivateMethod;
+class Foo {
+	static {
+		[_call_staticPrivateMethod, _call_privateMethod, _call_privateValue, _call_privateValue2, _initProto, _initStatic] = babelHelpers.applyDecs2311(this, [], [
+			[
+				dec,
+				2,
+				"method"
+			],
+			[
+				dec,
+				10,
+				"staticMethod"
+			],
+			[
+				dec,
+				3,
+				"value"
+			],
+			[
+				dec,
+				4,
+				"value"
+			],
+			[
+				dec,
+				2,
+				"privateMethod",
+				function() {
+					return this;
+				}
+			],
+			[
+				dec,
+				3,
+				"privateValue",
+				function() {
+					return 1;
+				}
+			],
+			[
+				dec,
+				4,
+				"privateValue",
+				function(v) {}
+			],
+			[
+				dec,
+				10,
+				"staticPrivateMethod",
+				function() {}
+			]
+		], 0, (_) => #privateMethod in _).e;
+		_initStatic(this);
+	}
+	method() {}
+	static staticMethod() {}
+	get value() {
+		return 1;
+	}
+	set value(v) {}
+	get #privateMethod() {
+		return _call_privateMethod;
+	}
+	get #privateValue() {
+		return _call_privateValue(this);
+	}
+	set #privateValue(v) {
+		_call_privateValue2(this, v);
+	}
+	static get #staticPrivateMethod() {
+		return _call_staticPrivateMethod;
+	}
+	constructor() {
+		_initProto(this);
+	}
+}
+
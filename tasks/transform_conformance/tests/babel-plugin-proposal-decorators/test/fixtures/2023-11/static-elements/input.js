@@ -0,0 +1,13 @@
+@dec
+class Foo {
+  static a = Foo;
+
+  static {
+    this.b = 1;
+  }
+
+  @dec
+  static c = 2;
+
+  static method() {}
+}
@@ -0,0 +1,22 @@
+let _init_c, _init_extra_c, _Foo, _initClass;
+let Foo = (new class extends babelHelpers.identity {
+	static [class Foo {
+		static {
+			({e: [_init_c, _init_extra_c], c: [_Foo, _initClass]} = babelHelpers.applyDecs2311(this, [dec], [[
+				dec,
+				8,
+				"c"
+			]]));
+		}
+		static method() {}
+	}];
+	a = _Foo;
+	#A = (() => {
+		this.b = 1;
+	})();
+	c = _init_c(2);
+	constructor() {
+		super(_Foo), _init_extra_c(), _initClass();
+	}
+}(), _Foo);
+
@@ -0,0 +1,6 @@
+let a = 1;
+const b = 2;
+function f() {
+  let c = a + b;
+  return c;
+}
@@ -0,0 +1,7 @@
+var a = 1;
+var b = 2;
+function f() {
+	var c = a + b;
+	return c;
+}
+
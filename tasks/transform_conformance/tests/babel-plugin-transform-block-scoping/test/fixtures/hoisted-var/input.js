@@ -0,0 +1,7 @@
+for (let i = 0; i < 3; i++) {
+  var x = i, y;
+  var { a, b: [c = 1] } = obj;
+  for (var k in obj);
+  fns.push(() => i);
+}
+console.log(x, a, c, k);
@@ -0,0 +1,12 @@
+var _loop = function(i) {
+	x = i;
+	({a, b: [c = 1]} = obj);
+	for (k in obj);
+	fns.push(() => i);
+};
+for (var i = 0; i < 3; i++) {
+	var x, y, a, c, k;
+	_loop(i);
+}
+console.log(x, a, c, k);
+
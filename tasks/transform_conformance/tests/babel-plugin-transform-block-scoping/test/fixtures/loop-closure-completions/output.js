@@ -0,0 +1,30 @@
+function f() {
+	var _loop2 = function(i) {
+		var _loop = function(j) {
+			fns.push(() => i + j);
+			if (j === 1) return 0;
+			if (j === 2) return 1;
+			if (j === 3) return 2;
+			if (j === 4) return 3;
+			if (j === 5) return { v: j };
+			switch (j) {
+				case 6: break;
+			}
+		}, _ret;
+		for (var j = 0; j < 10; j++) {
+			_ret = _loop(j);
+			if (_ret === 0) continue;
+			if (_ret === 1) break;
+			if (_ret === 2) return 0;
+			if (_ret === 3) return 1;
+			if (_ret) return { v: _ret.v };
+		}
+	}, _ret2;
+	outer: for (var i = 0; i < 10; i++) {
+		_ret2 = _loop2(i);
+		if (_ret2 === 0) continue;
+		if (_ret2 === 1) break;
+		if (_ret2) return _ret2.v;
+	}
+}
+
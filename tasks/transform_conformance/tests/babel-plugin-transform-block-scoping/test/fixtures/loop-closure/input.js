@@ -0,0 +1,14 @@
+const fns = [];
+for (let i = 0; i < 3; i++) {
+  const j = i * 2;
+  fns.push(() => i + j);
+}
+for (const key in obj) {
+  fns.push(function () {
+    return key;
+  });
+}
+while (cond()) {
+  let x = next();
+  fns.push(() => x);
+}
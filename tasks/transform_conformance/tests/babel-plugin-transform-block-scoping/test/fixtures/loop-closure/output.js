@@ -0,0 +1,24 @@
+var fns = [];
+var _loop = function(i) {
+	var j = i * 2;
+	fns.push(() => i + j);
+};
+for (var i = 0; i < 3; i++) {
+	_loop(i);
+}
+var _loop2 = function(key) {
+	fns.push(function() {
+		return key;
+	});
+};
+for (var key in obj) {
+	_loop2(key);
+}
+var _loop3 = function() {
+	var x = next();
+	fns.push(() => x);
+};
+while (cond()) {
+	_loop3();
+}
+
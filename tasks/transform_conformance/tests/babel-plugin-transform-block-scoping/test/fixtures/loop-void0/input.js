@@ -0,0 +1,5 @@
+for (let i = 0; i < 3; i++) {
+  let x;
+  if (i) x = i;
+  console.log(x);
+}
@@ -0,0 +1,6 @@
+for (var i = 0; i < 3; i++) {
+	var x = void 0;
+	if (i) x = i;
+	console.log(x);
+}
+
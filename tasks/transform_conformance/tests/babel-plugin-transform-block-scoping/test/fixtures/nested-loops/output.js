@@ -0,0 +1,12 @@
+var _loop2 = function(i) {
+	var _loop = function(j) {
+		fns.push(() => i + j);
+	};
+	for (var j = 0; j < 3; j++) {
+		_loop(j);
+	}
+};
+for (var i = 0; i < 3; i++) {
+	_loop2(i);
+}
+
@@ -0,0 +1,15 @@
+let a = 1;
+{
+  let a = 2;
+  console.log(a);
+}
+function f(b) {
+  if (b) {
+    const b = 3;
+    return b;
+  }
+  {
+    let undef = 1;
+  }
+  return undef;
+}
@@ -0,0 +1,16 @@
+var a = 1;
+{
+	var _a = 2;
+	console.log(_a);
+}
+function f(b) {
+	if (b) {
+		var _b = 3;
+		return _b;
+	}
+	{
+		var _undef = 1;
+	}
+	return undef;
+}
+
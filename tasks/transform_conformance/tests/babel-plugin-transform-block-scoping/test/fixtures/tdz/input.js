@@ -0,0 +1,6 @@
+a;
+a = 1;
+let a = 2;
+function f() {
+  return a;
+}
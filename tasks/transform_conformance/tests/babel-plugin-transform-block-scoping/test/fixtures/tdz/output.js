@@ -0,0 +1,7 @@
+babelHelpers.tdz("a");
+babelHelpers.tdz("a"), a = 1;
+var a = 2;
+function f() {
+	return a;
+}
+
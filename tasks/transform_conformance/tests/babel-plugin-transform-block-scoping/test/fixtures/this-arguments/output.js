@@ -0,0 +1,9 @@
+function f() {
+	var _this = this, _arguments = arguments, _loop = function(i) {
+		_this.fns.push(() => i + _arguments[0]);
+	};
+	for (var i = 0; i < 3; i++) {
+		_loop(i);
+	}
+}
+
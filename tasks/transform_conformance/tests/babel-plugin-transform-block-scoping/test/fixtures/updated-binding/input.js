@@ -0,0 +1,5 @@
+for (let i = 0; i < 10; i++) {
+  fns.push(() => i);
+  i += 1;
+  if (i > 5) continue;
+}
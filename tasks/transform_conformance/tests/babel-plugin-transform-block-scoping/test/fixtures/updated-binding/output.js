@@ -0,0 +1,11 @@
+var _loop = function(_i) {
+	fns.push(() => _i);
+	_i += 1;
+	if (_i > 5) return i = _i, 0;
+	i = _i;
+}, _ret;
+for (var i = 0; i < 10; i++) {
+	_ret = _loop(i);
+	if (_ret === 0) continue;
+}
+
@@ -0,0 +1,3 @@
+for (const x of items) {
+  fns.push(() => x);
+}
@@ -0,0 +1,15 @@
+var _iterator = babelHelpers.createForOfIteratorHelper(items), _step;
+try {
+	var _loop = function() {
+		var x = _step.value;
+		fns.push(() => x);
+	};
+	for (_iterator.s(); !(_step = _iterator.n()).done;) {
+		_loop();
+	}
+} catch (err) {
+	_iterator.e(err);
+} finally {
+	_iterator.f();
+}
+
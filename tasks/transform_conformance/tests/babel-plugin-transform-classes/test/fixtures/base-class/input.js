@@ -0,0 +1,7 @@
+class A {
+  constructor(x) {
+    this.x = x;
+  }
+}
+
+class B {}
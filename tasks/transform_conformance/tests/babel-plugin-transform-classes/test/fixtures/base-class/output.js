@@ -0,0 +1,8 @@
+let A = /* @__PURE__ */ babelHelpers.createClass(function A(x) {
+	babelHelpers.classCallCheck(this, A);
+	this.x = x;
+});
+let B = /* @__PURE__ */ babelHelpers.createClass(function B() {
+	babelHelpers.classCallCheck(this, B);
+});
+
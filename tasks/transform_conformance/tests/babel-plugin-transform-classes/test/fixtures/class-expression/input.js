@@ -0,0 +1,19 @@
+let A = class {
+  method() {
+    return A;
+  }
+};
+
+let B = class Named extends A {
+  method() {
+    return Named;
+  }
+};
+
+let C = class Named2 {
+  constructor() {
+    Named2;
+  }
+};
+
+foo(class extends B {});
@@ -0,0 +1,37 @@
+let A = /* @__PURE__ */ function() {
+	function _class() {
+		babelHelpers.classCallCheck(this, _class);
+	}
+	return babelHelpers.createClass(_class, [{
+		key: "method",
+		value: function method() {
+			return A;
+		}
+	}]);
+}();
+let B = /* @__PURE__ */ function(_A) {
+	function Named() {
+		babelHelpers.classCallCheck(this, Named);
+		return babelHelpers.callSuper(this, Named, arguments);
+	}
+	babelHelpers.inherits(Named, _A);
+	return babelHelpers.createClass(Named, [{
+		key: "method",
+		value: function method() {
+			return Named;
+		}
+	}]);
+}(A);
+let C = /* @__PURE__ */ babelHelpers.createClass(function Named2() {
+	babelHelpers.classCallCheck(this, Named2);
+	Named2;
+});
+foo(/* @__PURE__ */ function(_B) {
+	function _class2() {
+		babelHelpers.classCallCheck(this, _class2);
+		return babelHelpers.callSuper(this, _class2, arguments);
+	}
+	babelHelpers.inherits(_class2, _B);
+	return babelHelpers.createClass(_class2);
+}(B));
+
@@ -0,0 +1,14 @@
+class A extends B {
+  constructor(x) {
+    super();
+    if (x) {
+      return;
+    }
+    if (!x) {
+      return { x };
+    }
+    function inner() {
+      return this;
+    }
+  }
+}
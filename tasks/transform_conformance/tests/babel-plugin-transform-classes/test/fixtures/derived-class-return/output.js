@@ -0,0 +1,20 @@
+let A = /* @__PURE__ */ function(_B) {
+	function A(x) {
+		var _this;
+		babelHelpers.classCallCheck(this, A);
+		_this = babelHelpers.callSuper(this, A);
+		if (x) {
+			return babelHelpers.assertThisInitialized(_this);
+		}
+		if (!x) {
+			return babelHelpers.possibleConstructorReturn(_this, { x });
+		}
+		function inner() {
+			return this;
+		}
+		return _this;
+	}
+	babelHelpers.inherits(A, _B);
+	return babelHelpers.createClass(A);
+}(B);
+
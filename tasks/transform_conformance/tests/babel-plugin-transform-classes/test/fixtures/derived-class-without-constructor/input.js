@@ -0,0 +1,5 @@
+class A extends B {}
+
+class C extends foo.bar() {
+  method() {}
+}
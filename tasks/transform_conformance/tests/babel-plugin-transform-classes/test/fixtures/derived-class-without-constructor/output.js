@@ -0,0 +1,20 @@
+let A = /* @__PURE__ */ function(_B) {
+	function A() {
+		babelHelpers.classCallCheck(this, A);
+		return babelHelpers.callSuper(this, A, arguments);
+	}
+	babelHelpers.inherits(A, _B);
+	return babelHelpers.createClass(A);
+}(B);
+let C = /* @__PURE__ */ function(_foo$bar) {
+	function C() {
+		babelHelpers.classCallCheck(this, C);
+		return babelHelpers.callSuper(this, C, arguments);
+	}
+	babelHelpers.inherits(C, _foo$bar);
+	return babelHelpers.createClass(C, [{
+		key: "method",
+		value: function method() {}
+	}]);
+}(foo.bar());
+
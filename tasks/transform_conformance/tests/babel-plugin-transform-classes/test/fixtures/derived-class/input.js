@@ -0,0 +1,24 @@
+class A extends B {
+  constructor(x) {
+    super(x);
+    this.y = x;
+    this.z = () => this;
+  }
+}
+
+class C extends B {
+  constructor() {
+    super();
+    foo(this);
+  }
+}
+
+class D extends B {
+  constructor(x) {
+    if (x) {
+      super(x);
+    } else {
+      super();
+    }
+  }
+}
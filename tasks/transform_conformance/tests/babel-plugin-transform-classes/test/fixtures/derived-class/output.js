@@ -0,0 +1,38 @@
+let A = /* @__PURE__ */ function(_B) {
+	function A(x) {
+		var _this;
+		babelHelpers.classCallCheck(this, A);
+		_this = babelHelpers.callSuper(this, A, [x]);
+		_this.y = x;
+		_this.z = () => babelHelpers.assertThisInitialized(_this);
+		return _this;
+	}
+	babelHelpers.inherits(A, _B);
+	return babelHelpers.createClass(A);
+}(B);
+let C = /* @__PURE__ */ function(_B2) {
+	function C() {
+		var _this2;
+		babelHelpers.classCallCheck(this, C);
+		_this2 = babelHelpers.callSuper(this, C);
+		foo(babelHelpers.assertThisInitialized(_this2));
+		return _this2;
+	}
+	babelHelpers.inherits(C, _B2);
+	return babelHelpers.createClass(C);
+}(B);
+let D = /* @__PURE__ */ function(_B3) {
+	function D(x) {
+		var _this3;
+		babelHelpers.classCallCheck(this, D);
+		if (x) {
+			_this3 = babelHelpers.callSuper(this, D, [x]);
+		} else {
+			_this3 = babelHelpers.callSuper(this, D);
+		}
+		return babelHelpers.assertThisInitialized(_this3);
+	}
+	babelHelpers.inherits(D, _B3);
+	return babelHelpers.createClass(D);
+}(B);
+
@@ -0,0 +1,10 @@
+export default /* @__PURE__ */ (function() {
+	function _default() {
+		babelHelpers.classCallCheck(this, _default);
+	}
+	return babelHelpers.createClass(_default, [{
+		key: "method",
+		value: function method() {}
+	}]);
+})();
+
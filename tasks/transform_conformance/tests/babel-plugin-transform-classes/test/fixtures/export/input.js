@@ -0,0 +1,7 @@
+export class A {
+  method() {
+    return A;
+  }
+}
+
+export default class B extends A {}
@@ -0,0 +1,21 @@
+export let A = /* @__PURE__ */ function() {
+	function A() {
+		babelHelpers.classCallCheck(this, A);
+	}
+	return babelHelpers.createClass(A, [{
+		key: "method",
+		value: function method() {
+			return A;
+		}
+	}]);
+}();
+let B = /* @__PURE__ */ function(_A) {
+	function B() {
+		babelHelpers.classCallCheck(this, B);
+		return babelHelpers.callSuper(this, B, arguments);
+	}
+	babelHelpers.inherits(B, _A);
+	return babelHelpers.createClass(B);
+}(A);
+export { B as default };
+
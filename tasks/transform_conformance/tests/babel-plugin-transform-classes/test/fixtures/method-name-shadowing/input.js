@@ -0,0 +1,10 @@
+class A {
+  foo() {
+    return foo;
+  }
+  bar() {
+    return A;
+  }
+  delete() {}
+  arguments() {}
+}
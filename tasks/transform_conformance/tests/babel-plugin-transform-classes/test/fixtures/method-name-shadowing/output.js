@@ -0,0 +1,28 @@
+let A = /* @__PURE__ */ function() {
+	function A() {
+		babelHelpers.classCallCheck(this, A);
+	}
+	return babelHelpers.createClass(A, [
+		{
+			key: "foo",
+			value: function() {
+				return foo;
+			}
+		},
+		{
+			key: "bar",
+			value: function bar() {
+				return A;
+			}
+		},
+		{
+			key: "delete",
+			value: function() {}
+		},
+		{
+			key: "arguments",
+			value: function() {}
+		}
+	]);
+}();
+
@@ -0,0 +1,13 @@
+class A {
+  foo() {}
+  get bar() {
+    return 1;
+  }
+  set bar(v) {}
+  static baz() {}
+  static get qux() {}
+  ["computed"]() {}
+  [Symbol.iterator]() {}
+  "string-key"() {}
+  123() {}
+}
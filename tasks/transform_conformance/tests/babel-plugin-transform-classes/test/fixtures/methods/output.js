@@ -0,0 +1,41 @@
+let A = /* @__PURE__ */ function() {
+	function A() {
+		babelHelpers.classCallCheck(this, A);
+	}
+	return babelHelpers.createClass(A, [
+		{
+			key: "foo",
+			value: function foo() {}
+		},
+		{
+			key: "bar",
+			get: function() {
+				return 1;
+			},
+			set: function(v) {}
+		},
+		{
+			key: "computed",
+			value: function() {}
+		},
+		{
+			key: Symbol.iterator,
+			value: function() {}
+		},
+		{
+			key: "string-key",
+			value: function() {}
+		},
+		{
+			key: 123,
+			value: function() {}
+		}
+	], [{
+		key: "baz",
+		value: function baz() {}
+	}, {
+		key: "qux",
+		get: function() {}
+	}]);
+}();
+
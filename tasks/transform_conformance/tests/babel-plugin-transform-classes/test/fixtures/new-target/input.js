@@ -0,0 +1,12 @@
+class A {
+  constructor() {
+    new.target;
+    () => new.target;
+    function inner() {
+      new.target;
+    }
+  }
+  method() {
+    return new.target;
+  }
+}
@@ -0,0 +1,17 @@
+let A = /* @__PURE__ */ function() {
+	function A() {
+		babelHelpers.classCallCheck(this, A);
+		this.constructor;
+		() => this.constructor;
+		function inner() {
+			new.target;
+		}
+	}
+	return babelHelpers.createClass(A, [{
+		key: "method",
+		value: function method() {
+			return void 0;
+		}
+	}]);
+}();
+
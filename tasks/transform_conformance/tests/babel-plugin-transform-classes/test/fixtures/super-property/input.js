@@ -0,0 +1,23 @@
+class A extends B {
+  foo() {
+    super.foo;
+    super["bar"];
+    super.foo();
+    super[bar](1, 2);
+    super.foo = 1;
+    super.foo += 1;
+    super.foo ||= 1;
+    super.foo++;
+    ++super[foo];
+  }
+  static bar() {
+    super.foo;
+    super.foo();
+    super.foo = 1;
+    const obj = {
+      method() {
+        return super.foo;
+      },
+    };
+  }
+}
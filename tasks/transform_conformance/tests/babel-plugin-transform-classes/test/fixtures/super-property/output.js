@@ -0,0 +1,33 @@
+var _super$foo, _super$foo2, _foo, _super$foo3;
+let A = /* @__PURE__ */ function(_B) {
+	function A() {
+		babelHelpers.classCallCheck(this, A);
+		return babelHelpers.callSuper(this, A, arguments);
+	}
+	babelHelpers.inherits(A, _B);
+	return babelHelpers.createClass(A, [{
+		key: "foo",
+		value: function() {
+			babelHelpers.superPropGet(A, "foo", this, 1);
+			babelHelpers.superPropGet(A, "bar", this, 1);
+			babelHelpers.superPropGet(A, "foo", this, 3)([]);
+			babelHelpers.superPropGet(A, bar, this, 3)([1, 2]);
+			babelHelpers.superPropSet(A, "foo", 1, this, 1, 1);
+			babelHelpers.superPropSet(A, "foo", babelHelpers.superPropGet(A, "foo", this, 1) + 1, this, 1, 1);
+			babelHelpers.superPropGet(A, "foo", this, 1) || babelHelpers.superPropSet(A, "foo", 1, this, 1, 1);
+			babelHelpers.superPropSet(A, "foo", (_super$foo = babelHelpers.superPropGet(A, "foo", this, 1), _super$foo2 = _super$foo++, _super$foo), this, 1, 1), _super$foo2;
+			babelHelpers.superPropSet(A, _foo = foo, (_super$foo3 = babelHelpers.superPropGet(A, _foo, this, 1), ++_super$foo3), this, 1, 1);
+		}
+	}], [{
+		key: "bar",
+		value: function bar() {
+			babelHelpers.superPropGet(A, "foo", this);
+			babelHelpers.superPropGet(A, "foo", this, 2)([]);
+			babelHelpers.superPropSet(A, "foo", 1, this, 1);
+			const obj = { method() {
+				return super.foo;
+			} };
+		}
+	}]);
+}(B);
+
@@ -0,0 +1,7 @@
+class A extends B {
+  constructor(a, b) {
+    super(...a);
+    super.method(b, ...a);
+    super.method(...arguments);
+  }
+}
@@ -0,0 +1,12 @@
+let A = /* @__PURE__ */ function(_B) {
+	function A(a, b) {
+		var _this;
+		babelHelpers.classCallCheck(this, A);
+		_this = babelHelpers.callSuper(this, A, babelHelpers.toConsumableArray(a));
+		babelHelpers.superPropGet(A, "method", _this, 1).apply(babelHelpers.assertThisInitialized(_this), [b].concat(babelHelpers.toConsumableArray(a)));
+		babelHelpers.superPropGet(A, "method", _this, 1).apply(babelHelpers.assertThisInitialized(_this), arguments);
+		return _this;
+	}
+	babelHelpers.inherits(A, _B);
+	return babelHelpers.createClass(A);
+}(B);
@@ -0,0 +1,11 @@
+class A {
+  x = 1;
+}
+
+class B {
+  #foo() {}
+}
+
+class C {
+  static {}
+}
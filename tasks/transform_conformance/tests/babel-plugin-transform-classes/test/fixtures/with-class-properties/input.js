@@ -0,0 +1,12 @@
+class A extends B {
+  x = 1;
+  static y = this;
+  #z = 2;
+  method() {
+    return this.#z;
+  }
+}
+
+let C = class {
+  static prop = C;
+};
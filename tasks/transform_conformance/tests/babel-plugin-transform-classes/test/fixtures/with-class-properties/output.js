@@ -0,0 +1,28 @@
+var _A, _Class;
+var _z = /* @__PURE__ */ new WeakMap();
+let A = /* @__PURE__ */ function(_B) {
+	function A() {
+		var _this;
+		babelHelpers.classCallCheck(this, A);
+		for (var _len = arguments.length, _args = new Array(_len), _key = 0; _key < _len; _key++) {
+			_args[_key] = arguments[_key];
+		}
+		_this = babelHelpers.callSuper(this, A, babelHelpers.toConsumableArray(_args));
+		babelHelpers.defineProperty(babelHelpers.assertThisInitialized(_this), "x", 1);
+		babelHelpers.classPrivateFieldInitSpec(babelHelpers.assertThisInitialized(_this), _z, 2);
+		return _this;
+	}
+	babelHelpers.inherits(A, _B);
+	return babelHelpers.createClass(A, [{
+		key: "method",
+		value: function method() {
+			return babelHelpers.classPrivateFieldGet2(_z, this);
+		}
+	}]);
+}(B);
+_A = A;
+babelHelpers.defineProperty(A, "y", _A);
+let C = (_Class = /* @__PURE__ */ babelHelpers.createClass(function _class() {
+	babelHelpers.classCallCheck(this, _class);
+}), babelHelpers.defineProperty(_Class, "prop", C), _Class);
+
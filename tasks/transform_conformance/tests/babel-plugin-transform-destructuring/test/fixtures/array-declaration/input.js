@@ -0,0 +1,3 @@
+const [a, , b = 1] = arr;
+let [c, ...d] = arr;
+var [[e], { f }] = getArr();
@@ -0,0 +1,4 @@
+const _arr = babelHelpers.slicedToArray(arr, 3), a = _arr[0], _arr$ = _arr[2], b = _arr$ === void 0 ? 1 : _arr$;
+let _arr2 = babelHelpers.toArray(arr), c = _arr2[0], d = _arr2.slice(1);
+var _getArr = babelHelpers.slicedToArray(getArr(), 2), _getArr$ = babelHelpers.slicedToArray(_getArr[0], 1), e = _getArr$[0], f = _getArr[1].f;
+
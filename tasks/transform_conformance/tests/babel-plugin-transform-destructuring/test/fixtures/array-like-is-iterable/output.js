@@ -0,0 +1,3 @@
+const _arr = babelHelpers.maybeArrayLike(babelHelpers.slicedToArray, arr, 2), a = _arr[0], b = _arr[1];
+let _arr2 = babelHelpers.maybeArrayLike(babelHelpers.toArray, arr), c = _arr2[0], d = _arr2.slice(1);
+
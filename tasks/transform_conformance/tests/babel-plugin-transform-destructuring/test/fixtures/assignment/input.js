@@ -0,0 +1,7 @@
+let a, b, c, rest;
+({ a, b } = obj);
+[a, b] = [b, a];
+[a, ...rest] = arr;
+({ a = 1, ...rest } = obj);
+x = ({ c } = obj);
+const fn = () => ({ a } = obj);
@@ -0,0 +1,12 @@
+var _obj, _ref, _arr, _obj2, _obj2$a, _obj3;
+let a, b, c, rest;
+_obj = obj, a = _obj.a, b = _obj.b;
+_ref = [b, a], a = _ref[0], b = _ref[1];
+_arr = babelHelpers.toArray(arr), a = _arr[0], rest = _arr.slice(1);
+_obj2 = obj, _obj2$a = _obj2.a, a = _obj2$a === void 0 ? 1 : _obj2$a, rest = babelHelpers.objectWithoutProperties(_obj2, ["a"]);
+x = (_obj3 = obj, c = _obj3.c, _obj3);
+const fn = () => {
+	var _obj4;
+	return _obj4 = obj, a = _obj4.a, _obj4;
+};
+
@@ -0,0 +1 @@
+export const { a, b: [c] } = obj;
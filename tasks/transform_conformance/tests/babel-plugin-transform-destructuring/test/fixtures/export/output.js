@@ -0,0 +1,3 @@
+const _obj = obj, a = _obj.a, _obj$b = babelHelpers.slicedToArray(_obj.b, 1), c = _obj$b[0];
+export { a, c };
+
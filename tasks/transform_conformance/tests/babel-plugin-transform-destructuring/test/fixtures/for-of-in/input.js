@@ -0,0 +1,7 @@
+for (const { a, b } of list) {
+	console.log(a, b);
+}
+for (let [k, v] of map) console.log(k, v);
+let x, y;
+for ({ x, y } of list) {}
+for (var [c] in obj) {}
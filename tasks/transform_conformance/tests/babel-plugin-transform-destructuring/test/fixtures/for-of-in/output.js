@@ -0,0 +1,16 @@
+for (const _ref of list) {
+	const a = _ref.a, b = _ref.b;
+	console.log(a, b);
+}
+for (let _ref2 of map) {
+	let _ref3 = babelHelpers.slicedToArray(_ref2, 2), k = _ref3[0], v = _ref3[1];
+	console.log(k, v);
+}
+let x, y;
+for (var _ref4 of list) {
+	x = _ref4.x, y = _ref4.y;
+}
+for (var _ref5 in obj) {
+	var _ref6 = babelHelpers.slicedToArray(_ref5, 1), c = _ref6[0];
+}
+
@@ -0,0 +1,2 @@
+const [a, b] = arr;
+let [c, ...d] = arr;
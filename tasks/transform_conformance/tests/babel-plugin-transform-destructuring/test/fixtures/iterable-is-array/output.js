@@ -0,0 +1,3 @@
+const _arr = arr, a = _arr[0], b = _arr[1];
+let _arr2 = arr, c = _arr2[0], d = _arr2.slice(1);
+
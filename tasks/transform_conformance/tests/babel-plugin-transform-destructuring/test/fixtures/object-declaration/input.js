@@ -0,0 +1,4 @@
+const { a, b: c, d = 1, e: { f } } = obj;
+let { g, ...rest } = obj;
+var { [key]: h, ...others } = getObj();
+const {} = empty;
@@ -0,0 +1,5 @@
+const _obj = obj, a = _obj.a, c = _obj.b, _obj$d = _obj.d, d = _obj$d === void 0 ? 1 : _obj$d, f = _obj.e.f;
+let _obj2 = obj, g = _obj2.g, rest = babelHelpers.objectWithoutProperties(_obj2, ["g"]);
+var _getObj = getObj(), _key = key, h = _getObj[_key], others = babelHelpers.objectWithoutProperties(_getObj, [_key].map(babelHelpers.toPropertyKey));
+const _empty = babelHelpers.objectDestructuringEmpty(empty);
+
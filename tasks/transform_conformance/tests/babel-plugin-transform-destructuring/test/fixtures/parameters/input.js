@@ -0,0 +1,4 @@
+function f({ a, b }, [c, d]) {
+	return a + b + c + d;
+}
+const g = ({ x }) => x;
@@ -0,0 +1,10 @@
+function f(_ref, _ref2) {
+	let a = _ref.a, b = _ref.b;
+	let _ref3 = babelHelpers.slicedToArray(_ref2, 2), c = _ref3[0], d = _ref3[1];
+	return a + b + c + d;
+}
+const g = (_ref4) => {
+	let x = _ref4.x;
+	return x;
+};
+
@@ -0,0 +1,3 @@
+for (const x of document.querySelectorAll("div")) {
+  console.log(x);
+}
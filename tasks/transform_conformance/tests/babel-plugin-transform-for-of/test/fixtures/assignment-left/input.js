@@ -0,0 +1,5 @@
+let x;
+for (x of items) {
+  console.log(x);
+}
+for (obj.prop of items);
@@ -0,0 +1,5 @@
+for (var _i = 0, _arr = items; _i < _arr.length; _i++) {
+	const x = _arr[_i];
+	console.log(x);
+}
+
@@ -0,0 +1,5 @@
+outer: for (const row of rows) {
+  for (const cell of row) {
+    if (cell) continue outer;
+  }
+}
@@ -0,0 +1,22 @@
+var _iterator2 = babelHelpers.createForOfIteratorHelper(rows), _step2;
+try {
+	outer: for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+		const row = _step2.value;
+		var _iterator = babelHelpers.createForOfIteratorHelper(row), _step;
+		try {
+			for (_iterator.s(); !(_step = _iterator.n()).done;) {
+				const cell = _step.value;
+				if (cell) continue outer;
+			}
+		} catch (err) {
+			_iterator.e(err);
+		} finally {
+			_iterator.f();
+		}
+	}
+} catch (err) {
+	_iterator2.e(err);
+} finally {
+	_iterator2.f();
+}
+
@@ -0,0 +1,5 @@
+for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(items), _step; !(_step = _iterator()).done;) {
+	const x = _step.value;
+	console.log(x);
+}
+
@@ -0,0 +1 @@
+if (cond) for (const x of items) console.log(x);
@@ -0,0 +1,9 @@
+for (const x of items) {
+  console.log(x);
+}
+
+function f() {
+  for (let [a, b] of pairs()) {
+    use(a, b);
+  }
+}
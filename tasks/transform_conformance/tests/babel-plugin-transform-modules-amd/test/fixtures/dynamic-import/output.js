@@ -0,0 +1,23 @@
+define([
+	"require",
+	"exports",
+	"module"
+], function(_require, _exports, _module) {
+	"use strict";
+	Object.defineProperty(_exports, "__esModule", { value: true });
+	Object.defineProperty(_exports, "load", {
+		enumerable: true,
+		get: function() {
+			return load;
+		}
+	});
+	async function load() {
+		const mod = await new Promise(function(_resolve, _reject) {
+			_require(["lazy"], function(_imported) {
+				_resolve(babelHelpers.interopRequireWildcard(_imported));
+			}, _reject);
+		});
+		return [mod, new URL(_module.uri, document.baseURI).href];
+	}
+});
+
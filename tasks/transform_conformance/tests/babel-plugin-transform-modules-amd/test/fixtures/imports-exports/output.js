@@ -0,0 +1,75 @@
+define([
+	"exports",
+	"foo",
+	"./ns-mod.js",
+	"def",
+	"named",
+	"star",
+	"re"
+], function(_exports, _foo, _nsMod, _def, _named, _star, _re) {
+	"use strict";
+	Object.defineProperty(_exports, "__esModule", { value: true });
+	Object.defineProperty(_exports, "renamed", {
+		enumerable: true,
+		get: function() {
+			return _named.named;
+		}
+	});
+	Object.defineProperty(_exports, "y", {
+		enumerable: true,
+		get: function() {
+			return _re.x;
+		}
+	});
+	Object.defineProperty(_exports, "a", {
+		enumerable: true,
+		get: function() {
+			return a;
+		}
+	});
+	Object.defineProperty(_exports, "f", {
+		enumerable: true,
+		get: function() {
+			return f;
+		}
+	});
+	Object.defineProperty(_exports, "default", {
+		enumerable: true,
+		get: function() {
+			return _default;
+		}
+	});
+	_foo = babelHelpers.interopRequireWildcard(_foo);
+	_nsMod = babelHelpers.interopRequireWildcard(_nsMod);
+	_def = babelHelpers.interopRequireDefault(_def);
+	var _exportNames = {
+		renamed: true,
+		y: true,
+		a: true,
+		f: true,
+		default: true
+	};
+	Object.keys(_star).forEach(function(key) {
+		if (key === "default" || key === "__esModule") return;
+		if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+		if (key in _exports && _exports[key] === _star[key]) return;
+		Object.defineProperty(_exports, key, {
+			enumerable: true,
+			get: function() {
+				return _star[key];
+			}
+		});
+	});
+	const a = (0, _foo.default)(_foo.bar, void 0);
+	function f() {
+		return this;
+	}
+	class _default {}
+	(0, _named.named)`t`;
+	({
+		def: _def.default,
+		ns: _nsMod
+	});
+	_nsMod.x();
+});
+
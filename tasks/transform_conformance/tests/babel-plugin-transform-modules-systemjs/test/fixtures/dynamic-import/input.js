@@ -0,0 +1,4 @@
+export async function load() {
+  const mod = await import("lazy");
+  return [mod, import.meta.url];
+}
@@ -0,0 +1,13 @@
+System.register([], function(_export, _context) {
+	"use strict";
+	async function load() {
+		const mod = await _context.import("lazy");
+		return [mod, _context.meta.url];
+	}
+	_export("load", load);
+	return {
+		setters: [],
+		execute: function() {}
+	};
+});
+
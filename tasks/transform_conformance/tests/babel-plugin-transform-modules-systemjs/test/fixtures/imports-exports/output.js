@@ -0,0 +1,60 @@
+System.register([
+	"foo",
+	"./ns-mod.js",
+	"def",
+	"named",
+	"star",
+	"re"
+], function(_export, _context) {
+	"use strict";
+	var foo, bar, ns, def, named, _default, a;
+	function f() {
+		return this;
+	}
+	_export("f", f);
+	var _exportNames = {
+		renamed: true,
+		y: true,
+		a: true,
+		f: true,
+		default: true
+	};
+	return {
+		setters: [
+			function(_foo) {
+				foo = _foo.default;
+				bar = _foo.bar;
+			},
+			function(_nsMod) {
+				ns = _nsMod;
+			},
+			function(_def) {
+				def = _def.default;
+			},
+			function(_named) {
+				_export("renamed", named = _named.named);
+			},
+			function(_star) {
+				var _exportObj = {};
+				for (var _key in _star) {
+					if (_key !== "default" && _key !== "__esModule" && !Object.prototype.hasOwnProperty.call(_exportNames, _key)) _exportObj[_key] = _star[_key];
+				}
+				_export(_exportObj);
+			},
+			function(_re) {
+				_export("y", _re.x);
+			}
+		],
+		execute: function() {
+			_export("a", a = foo(bar, void 0));
+			_export("default", _default = class {});
+			named`t`;
+			({
+				def,
+				ns
+			});
+			ns.x();
+		}
+	};
+});
+
@@ -0,0 +1,13 @@
+import { x } from "dep";
+export let count = 0, other;
+export { count as alias };
+export var [p, q] = [1, 2];
+export function inc() { count++; ++count; count += 1; [count, other] = [1, 2]; }
+for (count of [1, 2]) console.log(count);
+for (var i = 0; i < 2; i++) { var inner = i; }
+if (x) var cond = 1;
+class C { static s = this; m() { return this; } }
+export { C };
+await Promise.resolve();
+{ let blockScoped = 1; }
+const fn = () => this;
@@ -0,0 +1,41 @@
+System.register(["dep"], function(_export, _context) {
+	"use strict";
+	var x, C, count, other, p, q, _tmp, i, inner, cond, fn;
+	function inc() {
+		_tmp = count++, _export("count", count), _export("alias", count), _tmp;
+		_export("alias", _export("count", ++count));
+		_export("alias", _export("count", count += 1));
+		_tmp = [count, other] = [1, 2], _export("count", count), _export("alias", count), _export("other", other), _tmp;
+	}
+	_export("inc", inc);
+	return {
+		setters: [function(_dep) {
+			x = _dep.x;
+		}],
+		execute: async function() {
+			_export("alias", _export("count", count = 0));
+			_tmp = [p, q] = [1, 2], _export("p", p), _export("q", q), _tmp;
+			for (count of [1, 2]) {
+				_export("count", count);
+				_export("alias", count);
+				console.log(count);
+			}
+			for (i = 0; i < 2; i++) {
+				inner = i;
+			}
+			if (x) cond = 1;
+			_export("C", C = class {
+				static s = this;
+				m() {
+					return this;
+				}
+			});
+			await Promise.resolve();
+			{
+				let blockScoped = 1;
+			}
+			fn = () => void 0;
+		}
+	};
+});
+
@@ -0,0 +1,13 @@
+import foo, { bar } from "foo";
+import * as ns from "./ns-mod.js";
+import def from "def";
+import { named } from "named";
+export { named as renamed };
+export * from "star";
+export { x as y } from "re";
+export const a = foo(bar, this);
+export function f() { return this; }
+export default class {}
+named`t`;
+({ def, ns });
+ns.x();
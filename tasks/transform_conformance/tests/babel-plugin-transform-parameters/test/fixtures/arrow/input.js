@@ -0,0 +1 @@
+const f = (a = 1, ...rest) => a + rest.length;
@@ -0,0 +1,7 @@
+const f = (a, ...rest) => {
+	if (a === void 0) {
+		a = 1;
+	}
+	return a + rest.length;
+};
+
@@ -0,0 +1,6 @@
+function f(a) {
+	let b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : 1;
+	let c = arguments.length > 2 ? arguments[2] : undefined;
+	return a + b + c;
+}
+
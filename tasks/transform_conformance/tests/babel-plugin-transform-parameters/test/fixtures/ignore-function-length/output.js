@@ -0,0 +1,8 @@
+function f(a, b, _ref) {
+	if (b === void 0) {
+		b = 1;
+	}
+	let { c } = _ref === void 0 ? {} : _ref;
+	return a + b + c;
+}
+
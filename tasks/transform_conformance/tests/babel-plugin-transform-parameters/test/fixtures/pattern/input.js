@@ -0,0 +1,4 @@
+function f({ a } = {}, [b] = []) {
+	return a + b;
+}
+function g(a, ...[b, c]) {}
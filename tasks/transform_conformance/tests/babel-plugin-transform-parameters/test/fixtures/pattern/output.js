@@ -0,0 +1,12 @@
+function f() {
+	let { a } = arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : {};
+	let [b] = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : [];
+	return a + b;
+}
+function g(a) {
+	for (var _len = arguments.length, _ref = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+		_ref[_key - 1] = arguments[_key];
+	}
+	let [b, c] = _ref;
+}
+
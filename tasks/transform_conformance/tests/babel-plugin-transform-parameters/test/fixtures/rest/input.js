@@ -0,0 +1,6 @@
+function f(...args) {
+	return args;
+}
+function g(a, b, ...rest) {
+	return rest;
+}
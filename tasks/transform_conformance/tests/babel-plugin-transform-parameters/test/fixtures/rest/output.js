@@ -0,0 +1,13 @@
+function f() {
+	for (var _len = arguments.length, args = new Array(_len), _key = 0; _key < _len; _key++) {
+		args[_key] = arguments[_key];
+	}
+	return args;
+}
+function g(a, b) {
+	for (var _len2 = arguments.length, rest = new Array(_len2 > 2 ? _len2 - 2 : 0), _key2 = 2; _key2 < _len2; _key2++) {
+		rest[_key2 - 2] = arguments[_key2];
+	}
+	return rest;
+}
+
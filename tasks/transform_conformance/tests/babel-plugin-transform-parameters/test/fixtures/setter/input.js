@@ -0,0 +1,5 @@
+const obj = {
+	set value(v = 1) {
+		this._v = v;
+	}
+};
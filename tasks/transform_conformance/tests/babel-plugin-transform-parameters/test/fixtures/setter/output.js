@@ -0,0 +1,7 @@
+const obj = { set value(v) {
+	if (v === void 0) {
+		v = 1;
+	}
+	this._v = v;
+} };
+
@@ -0,0 +1,3 @@
+function f({ a }, b = a, ...[c]) {
+	return a + b + c;
+}
@@ -0,0 +1,10 @@
+function f(_ref) {
+	let a = _ref.a;
+	let b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : a;
+	for (var _len = arguments.length, _ref2 = new Array(_len > 2 ? _len - 2 : 0), _key = 2; _key < _len; _key++) {
+		_ref2[_key - 2] = arguments[_key];
+	}
+	let _ref3 = babelHelpers.slicedToArray(_ref2, 1), c = _ref3[0];
+	return a + b + c;
+}
+
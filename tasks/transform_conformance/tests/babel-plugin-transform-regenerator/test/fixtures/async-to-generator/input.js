@@ -0,0 +1,3 @@
+async function foo() {
+  await bar();
+}
@@ -0,0 +1,4 @@
+function* gen() {
+  var x = yield 1;
+  return x;
+}
@@ -0,0 +1,17 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+	var x;
+	return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0:
+				_context.next = 2;
+				return 1;
+			case 2:
+				x = _context.sent;
+				return _context.abrupt("return", x);
+			case 4:
+			case "end": return _context.stop();
+		}
+	}, _marked);
+}
+
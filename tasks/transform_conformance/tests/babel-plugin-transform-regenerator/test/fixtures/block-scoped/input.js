@@ -0,0 +1,9 @@
+function* gen() {
+  let a = 1;
+  {
+    let a = 2;
+    yield a;
+  }
+  const b = yield a;
+  return b;
+}
@@ -0,0 +1,22 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+	var a, _a, b;
+	return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0:
+				a = 1;
+				_a = 2;
+				_context.next = 4;
+				return _a;
+			case 4:
+				_context.next = 6;
+				return a;
+			case 6:
+				b = _context.sent;
+				return _context.abrupt("return", b);
+			case 8:
+			case "end": return _context.stop();
+		}
+	}, _marked);
+}
+
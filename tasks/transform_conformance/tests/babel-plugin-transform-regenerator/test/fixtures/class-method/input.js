@@ -0,0 +1,8 @@
+class A {
+  *gen() {
+    yield this;
+  }
+  static *[Symbol.iterator]() {
+    yield 1;
+  }
+}
@@ -0,0 +1,25 @@
+class A {
+	gen() {
+		return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+			while (1) switch (_context.prev = _context.next) {
+				case 0:
+					_context.next = 2;
+					return this;
+				case 2:
+				case "end": return _context.stop();
+			}
+		}, null, this);
+	}
+	static [Symbol.iterator]() {
+		return babelHelpers.regeneratorRuntime().wrap(function _callee2$(_context2) {
+			while (1) switch (_context2.prev = _context2.next) {
+				case 0:
+					_context2.next = 2;
+					return 1;
+				case 2:
+				case "end": return _context2.stop();
+			}
+		}, null);
+	}
+}
+
@@ -0,0 +1,8 @@
+function* inner() {
+  yield 1;
+}
+
+function* outer() {
+  var result = yield* inner();
+  yield result;
+}
@@ -0,0 +1,7 @@
+var gen = function* () {
+  yield 1;
+};
+
+var named = function* named() {
+  yield named;
+};
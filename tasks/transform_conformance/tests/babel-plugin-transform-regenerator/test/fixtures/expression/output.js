@@ -0,0 +1,23 @@
+var gen = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function _callee() {
+	return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0:
+				_context.next = 2;
+				return 1;
+			case 2:
+			case "end": return _context.stop();
+		}
+	}, _callee);
+});
+var named = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function named() {
+	return babelHelpers.regeneratorRuntime().wrap(function named$(_context2) {
+		while (1) switch (_context2.prev = _context2.next) {
+			case 0:
+				_context2.next = 2;
+				return named;
+			case 2:
+			case "end": return _context2.stop();
+		}
+	}, named);
+});
+
@@ -0,0 +1,10 @@
+function* gen(obj) {
+  var a = foo(yield 1, bar);
+  obj.method(yield 2);
+  var b = (yield 3) || (yield 4);
+  var c = (yield 5) ? x : yield 6;
+  obj.count += yield 7;
+  var d = [yield 8, ...(yield 9)];
+  var e = { key: yield 10 };
+  return a + b + c + d + e;
+}
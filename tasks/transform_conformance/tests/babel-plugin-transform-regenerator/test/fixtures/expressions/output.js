@@ -0,0 +1,74 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(obj) {
+	var a, b, c, d, e;
+	return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0:
+				_context.t0 = foo;
+				_context.next = 3;
+				return 1;
+			case 3:
+				_context.t1 = _context.sent;
+				_context.t2 = bar;
+				a = (0, _context.t0)(_context.t1, _context.t2);
+				_context.t3 = obj;
+				_context.next = 9;
+				return 2;
+			case 9:
+				_context.t4 = _context.sent;
+				_context.t3.method.call(_context.t3, _context.t4);
+				_context.next = 13;
+				return 3;
+			case 13:
+				_context.t5 = _context.sent;
+				if (_context.t5) {
+					_context.next = 18;
+					break;
+				}
+				_context.next = 17;
+				return 4;
+			case 17: _context.t5 = _context.sent;
+			case 18:
+				b = _context.t5;
+				_context.next = 21;
+				return 5;
+			case 21:
+				if (!_context.sent) {
+					_context.next = 25;
+					break;
+				}
+				_context.t6 = x;
+				_context.next = 28;
+				break;
+			case 25:
+				_context.next = 27;
+				return 6;
+			case 27: _context.t6 = _context.sent;
+			case 28:
+				c = _context.t6;
+				_context.t7 = obj.count;
+				_context.next = 32;
+				return 7;
+			case 32:
+				obj.count = _context.t7 += _context.sent;
+				_context.next = 35;
+				return 8;
+			case 35:
+				_context.t8 = _context.sent;
+				_context.next = 38;
+				return 9;
+			case 38:
+				_context.t9 = _context.sent;
+				d = [_context.t8, ..._context.t9];
+				_context.next = 42;
+				return 10;
+			case 42:
+				_context.t10 = _context.sent;
+				e = { key: _context.t10 };
+				return _context.abrupt("return", a + b + c + d + e);
+			case 45:
+			case "end": return _context.stop();
+		}
+	}, _marked);
+}
+
@@ -0,0 +1,14 @@
+function* range(n) {
+  for (var i = 0; i < n; i++) {
+    if (i === 2) continue;
+    if (i === 5) break;
+    yield i;
+  }
+  var j = 0;
+  while (j < n) {
+    yield j++;
+  }
+  do {
+    yield j;
+  } while (--j);
+}
@@ -0,0 +1,53 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(range);
+function range(n) {
+	var i, j;
+	return babelHelpers.regeneratorRuntime().wrap(function range$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0: i = 0;
+			case 1:
+				if (!(i < n)) {
+					_context.next = 11;
+					break;
+				}
+				if (!(i === 2)) {
+					_context.next = 4;
+					break;
+				}
+				return _context.abrupt("continue", 8);
+			case 4:
+				if (!(i === 5)) {
+					_context.next = 6;
+					break;
+				}
+				return _context.abrupt("break", 11);
+			case 6:
+				_context.next = 8;
+				return i;
+			case 8:
+				i++;
+				_context.next = 1;
+				break;
+			case 11: j = 0;
+			case 12:
+				if (!(j < n)) {
+					_context.next = 17;
+					break;
+				}
+				_context.next = 15;
+				return j++;
+			case 15:
+				_context.next = 12;
+				break;
+			case 17:
+				_context.next = 19;
+				return j;
+			case 19: if (--j) {
+				_context.next = 17;
+				break;
+			}
+			case 20:
+			case "end": return _context.stop();
+		}
+	}, _marked);
+}
+
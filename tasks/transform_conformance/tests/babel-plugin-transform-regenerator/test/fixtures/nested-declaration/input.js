@@ -0,0 +1,10 @@
+function* outer() {
+  function* inner() {
+    yield 1;
+  }
+  function helper() {
+    return 2;
+  }
+  yield* inner();
+  return helper();
+}
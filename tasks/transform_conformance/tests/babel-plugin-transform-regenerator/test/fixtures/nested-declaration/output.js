@@ -0,0 +1,29 @@
+var _marked2 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(outer);
+function outer() {
+	var _marked, inner, helper;
+	return babelHelpers.regeneratorRuntime().wrap(function outer$(_context2) {
+		while (1) switch (_context2.prev = _context2.next) {
+			case 0:
+				inner = function _inner() {
+					return babelHelpers.regeneratorRuntime().wrap(function inner$(_context) {
+						while (1) switch (_context.prev = _context.next) {
+							case 0:
+								_context.next = 2;
+								return 1;
+							case 2:
+							case "end": return _context.stop();
+						}
+					}, _marked);
+				};
+				helper = function _helper() {
+					return 2;
+				};
+				_marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(inner);
+				return _context2.delegateYield(inner(), "t0", 4);
+			case 4: return _context2.abrupt("return", helper());
+			case 5:
+			case "end": return _context2.stop();
+		}
+	}, _marked2);
+}
+
@@ -0,0 +1,6 @@
+function* empty() {}
+
+function* noYield() {
+  "use strict";
+  foo();
+}
@@ -0,0 +1,20 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(empty), _marked2 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(noYield);
+function empty() {
+	return babelHelpers.regeneratorRuntime().wrap(function empty$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0:
+			case "end": return _context.stop();
+		}
+	}, _marked);
+}
+function noYield() {
+	"use strict";
+	return babelHelpers.regeneratorRuntime().wrap(function noYield$(_context2) {
+		while (1) switch (_context2.prev = _context2.next) {
+			case 0: foo();
+			case 1:
+			case "end": return _context2.stop();
+		}
+	}, _marked2);
+}
+
@@ -0,0 +1,24 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(x) {
+	return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0:
+				_context.t0 = x;
+				_context.next = _context.t0 === 1 ? 3 : _context.t0 === 2 ? 6 : 8;
+				break;
+			case 3:
+				_context.next = 5;
+				return "one";
+			case 5: return _context.abrupt("break", 10);
+			case 6:
+				_context.next = 8;
+				return "two";
+			case 8:
+				_context.next = 10;
+				return "other";
+			case 10:
+			case "end": return _context.stop();
+		}
+	}, _marked);
+}
+
@@ -0,0 +1,8 @@
+var obj = {
+  *gen() {
+    yield this.value;
+    yield arguments.length;
+    var f = () => arguments[0];
+    yield f();
+  }
+};
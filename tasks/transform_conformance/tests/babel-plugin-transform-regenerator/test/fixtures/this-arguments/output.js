@@ -0,0 +1,20 @@
+var obj = { gen: /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function _callee() {
+	var f, _args = arguments;
+	return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0:
+				_context.next = 2;
+				return this.value;
+			case 2:
+				_context.next = 4;
+				return _args.length;
+			case 4:
+				f = () => _args[0];
+				_context.next = 7;
+				return f();
+			case 7:
+			case "end": return _context.stop();
+		}
+	}, _callee, this);
+}) };
+
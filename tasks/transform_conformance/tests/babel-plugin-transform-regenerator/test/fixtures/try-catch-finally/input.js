@@ -0,0 +1,15 @@
+function* gen() {
+  try {
+    yield 1;
+  } catch (e) {
+    yield e;
+    e = null;
+  } finally {
+    yield 2;
+  }
+  try {
+    yield 3;
+  } finally {
+    cleanup();
+  }
+}
@@ -0,0 +1,46 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+	return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0:
+				_context.prev = 0;
+				_context.next = 3;
+				return 1;
+			case 3:
+				_context.next = 10;
+				break;
+			case 5:
+				_context.prev = 5;
+				_context.t0 = _context["catch"](0);
+				_context.next = 9;
+				return _context.t0;
+			case 9: _context.t0 = null;
+			case 10:
+				_context.prev = 10;
+				_context.next = 13;
+				return 2;
+			case 13: return _context.finish(10);
+			case 14:
+				_context.prev = 14;
+				_context.next = 17;
+				return 3;
+			case 17:
+				_context.prev = 17;
+				cleanup();
+				return _context.finish(17);
+			case 20:
+			case "end": return _context.stop();
+		}
+	}, _marked, null, [[
+		0,
+		5,
+		10,
+		14
+	], [
+		14,
+		,
+		17,
+		20
+	]]);
+}
+
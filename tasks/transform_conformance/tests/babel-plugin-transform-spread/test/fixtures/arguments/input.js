@@ -0,0 +1,9 @@
+function a() {
+  return [...arguments];
+}
+function b() {
+  return f(...arguments);
+}
+function c() {
+  return f(0, ...arguments);
+}
@@ -0,0 +1,10 @@
+function a() {
+	return Array.prototype.slice.call(arguments);
+}
+function b() {
+	return f.apply(void 0, arguments);
+}
+function c() {
+	return f.apply(void 0, [0].concat(Array.prototype.slice.call(arguments)));
+}
+
@@ -0,0 +1,3 @@
+const a = document.querySelectorAll("div");
+[...a];
+f(0, ...a);
@@ -0,0 +1,4 @@
+const a = document.querySelectorAll("div");
+babelHelpers.maybeArrayLike(babelHelpers.toConsumableArray, a);
+f.apply(void 0, [0].concat(babelHelpers.maybeArrayLike(babelHelpers.toConsumableArray, a)));
+
@@ -0,0 +1,6 @@
+const a = [1, 2], c = [3];
+[...a];
+[0, ...a];
+[...a, 3, ...c];
+[0, , ...a, 4];
+[...[1, 2]];
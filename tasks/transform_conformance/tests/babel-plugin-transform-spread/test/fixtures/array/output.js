@@ -0,0 +1,7 @@
+const a = [1, 2], c = [3];
+babelHelpers.toConsumableArray(a);
+[0].concat(babelHelpers.toConsumableArray(a));
+[].concat(babelHelpers.toConsumableArray(a), [3], babelHelpers.toConsumableArray(c));
+[0, ,].concat(babelHelpers.toConsumableArray(a), [4]);
+[1, 2];
+
@@ -0,0 +1,9 @@
+const args = [1, 2];
+f(...args);
+f(0, ...args, 3);
+obj.method(...args);
+obj.nested.method(0, ...args);
+obj[key](...args);
+foo().bar(...args);
+console.log(...args);
+this.method(...args);
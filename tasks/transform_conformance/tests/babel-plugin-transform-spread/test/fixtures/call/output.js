@@ -0,0 +1,11 @@
+var _obj, _obj$nested, _obj2, _foo, _console;
+const args = [1, 2];
+f.apply(void 0, babelHelpers.toConsumableArray(args));
+f.apply(void 0, [0].concat(babelHelpers.toConsumableArray(args), [3]));
+(_obj = obj).method.apply(_obj, babelHelpers.toConsumableArray(args));
+(_obj$nested = obj.nested).method.apply(_obj$nested, [0].concat(babelHelpers.toConsumableArray(args)));
+(_obj2 = obj)[key].apply(_obj2, babelHelpers.toConsumableArray(args));
+(_foo = foo()).bar.apply(_foo, babelHelpers.toConsumableArray(args));
+(_console = console).log.apply(_console, babelHelpers.toConsumableArray(args));
+this.method.apply(this, babelHelpers.toConsumableArray(args));
+
@@ -0,0 +1,8 @@
+const a = [1, 2], b = [3];
+[...a];
+[0, ...a, ...b];
+f(...a);
+new Foo(...a);
+function g() {
+  return [...arguments];
+}
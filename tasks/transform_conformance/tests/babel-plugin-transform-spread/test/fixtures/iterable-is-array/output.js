@@ -0,0 +1,9 @@
+const a = [1, 2], b = [3];
+[].concat(a);
+[0].concat(a, b);
+f.apply(void 0, a);
+babelHelpers.construct(Foo, a);
+function g() {
+	return Array.prototype.slice.call(arguments);
+}
+
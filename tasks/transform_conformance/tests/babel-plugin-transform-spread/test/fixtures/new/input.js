@@ -0,0 +1,4 @@
+const args = [1, 2];
+new Foo(...args);
+new Foo(0, ...args, 3);
+new foo.Bar(...args);
@@ -0,0 +1,5 @@
+const args = [1, 2];
+babelHelpers.construct(Foo, babelHelpers.toConsumableArray(args));
+babelHelpers.construct(Foo, [0].concat(babelHelpers.toConsumableArray(args), [3]));
+babelHelpers.construct(foo.Bar, babelHelpers.toConsumableArray(args));
+
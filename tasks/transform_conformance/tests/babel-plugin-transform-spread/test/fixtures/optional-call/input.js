@@ -0,0 +1,3 @@
+const args = [1, 2];
+f?.(...args);
+obj?.method(...args);
@@ -0,0 +1,5 @@
+class A extends B {
+  method(...args) {
+    super.method(...args);
+  }
+}
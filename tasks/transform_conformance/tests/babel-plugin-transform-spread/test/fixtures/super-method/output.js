@@ -0,0 +1,6 @@
+class A extends B {
+	method(...args) {
+		super.method.apply(this, babelHelpers.toConsumableArray(args));
+	}
+}
+
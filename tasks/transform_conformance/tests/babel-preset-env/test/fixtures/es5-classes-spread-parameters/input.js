@@ -0,0 +1,14 @@
+class A extends B {
+  constructor(a = 1, ...rest) {
+    super(a, ...rest);
+    this.items = [...rest, a];
+  }
+
+  method(first, ...others) {
+    return super.method(first, ...others);
+  }
+
+  static create(...args) {
+    return new A(...args);
+  }
+}
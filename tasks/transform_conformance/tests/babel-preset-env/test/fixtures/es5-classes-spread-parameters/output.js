@@ -0,0 +1,31 @@
+var A = /* @__PURE__ */ function(_B) {
+	function A() {
+		var _this;
+		babelHelpers.classCallCheck(this, A);
+		var a = arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : 1;
+		for (var _len = arguments.length, rest = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+			rest[_key - 1] = arguments[_key];
+		}
+		_this = babelHelpers.callSuper(this, A, [a].concat(babelHelpers.toConsumableArray(rest)));
+		_this.items = [].concat(babelHelpers.toConsumableArray(rest), [a]);
+		return _this;
+	}
+	babelHelpers.inherits(A, _B);
+	return babelHelpers.createClass(A, [{
+		key: "method",
+		value: function method(first) {
+			for (var _len2 = arguments.length, others = new Array(_len2 > 1 ? _len2 - 1 : 0), _key2 = 1; _key2 < _len2; _key2++) {
+				others[_key2 - 1] = arguments[_key2];
+			}
+			return babelHelpers.superPropGet(A, "method", this, 1).apply(this, [first].concat(babelHelpers.toConsumableArray(others)));
+		}
+	}], [{
+		key: "create",
+		value: function create() {
+			for (var _len3 = arguments.length, args = new Array(_len3), _key3 = 0; _key3 < _len3; _key3++) {
+				args[_key3] = arguments[_key3];
+			}
+			return babelHelpers.construct(A, babelHelpers.toConsumableArray(args));
+		}
+	}]);
+}(B);